@@ -0,0 +1,31 @@
+use biome_diagnostics::Severity;
+use biome_rowan::SendNode;
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::RuleDiagnostic;
+
+/// Definition of an analyzer plugin.
+///
+/// Plugins are loaded from the user configuration and are executed by the
+/// [Analyzer](crate::Analyzer) alongside the built-in rules, once all the
+/// analysis phases have run. Their diagnostics are reported like the ones
+/// emitted by regular lint rules.
+pub trait AnalyzerPlugin: Debug + Send + Sync {
+    /// Runs the plugin against the root of the file being analyzed, and
+    /// returns the diagnostics for every match.
+    ///
+    /// The root node is type-erased, so plugins that target a specific
+    /// language are expected to return an empty list when they can't downcast
+    /// it to their own language.
+    fn evaluate(&self, root: SendNode, path: &Path) -> Vec<RuleDiagnostic>;
+
+    /// The severity of the diagnostics emitted by this plugin.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+}
+
+/// List of analyzer plugins, shared between all the files of a workspace.
+pub type AnalyzerPluginVec = Vec<Arc<dyn AnalyzerPlugin>>;
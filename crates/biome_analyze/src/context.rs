@@ -2,6 +2,7 @@ use crate::options::{JsxRuntime, PreferredQuote};
 use crate::RuleMetadata;
 use crate::{registry::RuleRoot, FromServices, Queryable, Rule, RuleKey, ServiceBag};
 use biome_diagnostics::{Error, Result};
+use biome_rowan::TextRange;
 use std::ops::Deref;
 use std::path::Path;
 
@@ -18,6 +19,7 @@ pub struct RuleContext<'a, R: Rule> {
     options: &'a R::Options,
     preferred_quote: &'a PreferredQuote,
     jsx_runtime: Option<JsxRuntime>,
+    range: Option<TextRange>,
 }
 
 impl<'a, R> RuleContext<'a, R>
@@ -34,6 +36,7 @@ where
         options: &'a R::Options,
         preferred_quote: &'a PreferredQuote,
         jsx_runtime: Option<JsxRuntime>,
+        range: Option<TextRange>,
     ) -> Result<Self, Error> {
         let rule_key = RuleKey::rule::<R>();
         Ok(Self {
@@ -46,6 +49,7 @@ where
             options,
             preferred_quote,
             jsx_runtime,
+            range,
         })
     }
 
@@ -136,6 +140,12 @@ where
         self.jsx_runtime.expect("jsx_runtime should be provided")
     }
 
+    /// Returns the text range the analysis is restricted to, e.g. the
+    /// selection of the editor when the analyzer computes code actions
+    pub fn range(&self) -> Option<TextRange> {
+        self.range
+    }
+
     /// Checks whether the provided text belongs to globals
     pub fn is_global(&self, text: &str) -> bool {
         self.globals.contains(&text)
@@ -117,6 +117,18 @@ impl AnalyzerDiagnostic {
         }
     }
 
+    /// Adds the given tags to the diagnostic
+    pub(crate) fn with_tags(mut self, tags: DiagnosticTags) -> Self {
+        self.kind = match self.kind {
+            DiagnosticKind::Rule(mut rule_diagnostic) => {
+                rule_diagnostic.tags |= tags;
+                DiagnosticKind::Rule(rule_diagnostic)
+            }
+            DiagnosticKind::Raw(error) => DiagnosticKind::Raw(error.with_tags(tags)),
+        };
+        self
+    }
+
     /// It adds a code suggestion, use this API to tell the user that a rule can benefit from
     /// a automatic code fix.
     pub fn add_code_suggestion(mut self, suggestion: CodeSuggestionAdvice<MarkupBuf>) -> Self {
@@ -4,8 +4,10 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
+use std::sync::Arc;
 use tracing::trace;
 
+mod analyzer_plugin;
 mod categories;
 pub mod context;
 mod diagnostics;
@@ -23,6 +25,7 @@ mod visitor;
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
 
+pub use crate::analyzer_plugin::{AnalyzerPlugin, AnalyzerPluginVec};
 pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder, RuleCategory,
     SourceActionKind,
@@ -47,13 +50,14 @@ pub use crate::syntax::{Ast, SyntaxVisitor};
 pub use crate::visitor::{NodeVisitor, Visitor, VisitorContext, VisitorFinishContext};
 pub use suppression_action::{ApplySuppression, SuppressionAction};
 
+use crate::signals::SuppressedSignal;
 use biome_console::markup;
 use biome_diagnostics::{
     category, Applicability, Diagnostic, DiagnosticExt, DiagnosticTags, Severity,
 };
 use biome_rowan::{
-    AstNode, BatchMutation, Direction, Language, SyntaxElement, SyntaxToken, TextLen, TextRange,
-    TextSize, TokenAtOffset, TriviaPiece, TriviaPieceKind, WalkEvent,
+    AstNode, BatchMutation, Direction, Language, SyntaxElement, SyntaxToken, SyntaxTriviaPiece,
+    TextLen, TextRange, TextSize, TokenAtOffset, TriviaPiece, TriviaPieceKind, WalkEvent,
 };
 
 /// The analyzer is the main entry point into the `biome_analyze` infrastructure.
@@ -75,6 +79,8 @@ pub struct Analyzer<'analyzer, L: Language, Matcher, Break, Diag> {
     suppression_action: Box<dyn SuppressionAction<Language = L>>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: SignalHandler<'analyzer, L, Break>,
+    /// List of plugins to run once all the phases have completed
+    plugins: AnalyzerPluginVec,
 }
 
 pub struct AnalyzerContext<'a, L: Language> {
@@ -86,7 +92,7 @@ pub struct AnalyzerContext<'a, L: Language> {
 
 impl<'analyzer, L, Matcher, Break, Diag> Analyzer<'analyzer, L, Matcher, Break, Diag>
 where
-    L: Language,
+    L: Language + 'static,
     Matcher: QueryMatcher<L>,
     Diag: Diagnostic + Clone + Send + Sync + 'static,
 {
@@ -106,6 +112,7 @@ where
             parse_suppression_comment,
             suppression_action,
             emit_signal,
+            plugins: Vec::new(),
         }
     }
 
@@ -118,6 +125,11 @@ where
         self.phases.entry(phase).or_default().push(visitor);
     }
 
+    /// Registers the [AnalyzerPlugin]s to be executed after all the phases
+    pub fn add_plugins(&mut self, plugins: AnalyzerPluginVec) {
+        self.plugins.extend(plugins);
+    }
+
     pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
@@ -126,6 +138,7 @@ where
             parse_suppression_comment,
             mut emit_signal,
             suppression_action,
+            plugins,
         } = self;
 
         let mut line_index = 0;
@@ -173,6 +186,14 @@ where
             }
         }
 
+        if !plugins.is_empty() {
+            if let ControlFlow::Break(br) =
+                run_plugins(&plugins, &ctx, &mut line_suppressions, &mut emit_signal)
+            {
+                return Some(br);
+            }
+        }
+
         for suppression in line_suppressions {
             if suppression.did_suppress_signal {
                 continue;
@@ -184,6 +205,13 @@ where
                     suppression.comment_span,
                     "Suppression comment has no effect. Remove the suppression or make sure you are suppressing the correct rule.",
                 )
+            })
+            .with_action(|| {
+                remove_suppression_comments(
+                    &ctx.root,
+                    suppression.text_range,
+                    parse_suppression_comment,
+                )
             });
 
             if let ControlFlow::Break(br) = (emit_signal)(&signal) {
@@ -432,6 +460,9 @@ where
             // hit, otherwise emit the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
+                if self.options.suppressed_signals && range_match(self.range, entry.text_range) {
+                    (self.emit_signal)(&SuppressedSignal::new(&*entry.signal))?;
+                }
             } else if range_match(self.range, entry.text_range) {
                 (self.emit_signal)(&*entry.signal)?;
             }
@@ -637,6 +668,55 @@ where
     }
 }
 
+/// Runs the given plugins on the root of the file, and emits their diagnostics.
+///
+/// Plugins don't have a rule name that suppression comments could refer to,
+/// so their diagnostics are only suppressed by comments that suppress all the
+/// lint rules, such as `// biome-ignore lint: <explanation>`.
+///
+/// A diagnostic without a span, such as the one a plugin reports when it fails
+/// to run, belongs to the whole file: it's always emitted and can't be suppressed.
+fn run_plugins<L: Language + 'static, Break>(
+    plugins: &[Arc<dyn AnalyzerPlugin>],
+    ctx: &AnalyzerContext<L>,
+    line_suppressions: &mut [LineSuppression],
+    emit_signal: &mut SignalHandler<'_, L, Break>,
+) -> ControlFlow<Break> {
+    let Some(root) = ctx.root.syntax().as_send() else {
+        return ControlFlow::Continue(());
+    };
+
+    for plugin in plugins {
+        let severity = plugin.severity();
+        for diagnostic in plugin.evaluate(root.clone(), &ctx.options.file_path) {
+            if let Some(span) = diagnostic.span {
+                if !range_match(ctx.range, span) {
+                    continue;
+                }
+            }
+
+            let suppression = diagnostic.span.and_then(|span| {
+                line_suppressions.iter_mut().find(|suppression| {
+                    suppression.suppress_all && suppression.text_range.contains(span.start())
+                })
+            });
+
+            let signal = DiagnosticSignal::new(|| diagnostic.clone().with_severity(severity));
+            if let Some(suppression) = suppression {
+                suppression.did_suppress_signal = true;
+                if ctx.options.suppressed_signals {
+                    (emit_signal)(&SuppressedSignal::new(&signal))?;
+                }
+                continue;
+            }
+
+            (emit_signal)(&signal)?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
 fn create_suppression_comment_action<L: Language>(
     token: &SyntaxToken<L>,
 ) -> Option<AnalyzerAction<L>> {
@@ -776,6 +856,134 @@ fn update_suppression<L: Language>(
     })
 }
 
+/// Creates the action that removes the suppression comments found in `range`,
+/// along with the whitespace and the line break that would be left on their
+/// lines
+fn remove_suppression_comments<L: Language, D>(
+    root: &L::Root,
+    range: TextRange,
+    parse_suppression_comment: SuppressionParser<D>,
+) -> Option<AnalyzerAction<L>> {
+    let mut mutation = BatchMutation::new(root.syntax().clone());
+    let mut token = root.syntax().token_at_offset(range.start()).right_biased();
+    let mut has_changes = false;
+
+    while let Some(old_token) = token {
+        if old_token.text_range().start() >= range.end() {
+            break;
+        }
+        token = old_token.next_token();
+
+        let leading_trivia: Vec<_> = old_token.leading_trivia().pieces().collect();
+        let trailing_trivia: Vec<_> = old_token.trailing_trivia().pieces().collect();
+        let is_suppression = |piece: &SyntaxTriviaPiece<L>| {
+            piece.is_comments()
+                && range.contains_range(piece.text_range())
+                && (parse_suppression_comment)(piece.text())
+                    .iter()
+                    .any(Result::is_ok)
+        };
+        let leading_removals = trivia_removals(&leading_trivia, true, is_suppression);
+        let trailing_removals = trivia_removals(&trailing_trivia, false, is_suppression);
+        if !leading_removals.contains(&true) && !trailing_removals.contains(&true) {
+            continue;
+        }
+
+        let kept_pieces = |pieces: &[SyntaxTriviaPiece<L>], removals: &[bool]| {
+            pieces
+                .iter()
+                .zip(removals)
+                .filter(|(_, removed)| !**removed)
+                .map(|(piece, _)| (piece.kind(), piece.text().to_string()))
+                .collect::<Vec<_>>()
+        };
+        let leading_pieces = kept_pieces(&leading_trivia, &leading_removals);
+        let trailing_pieces = kept_pieces(&trailing_trivia, &trailing_removals);
+
+        let new_token = old_token
+            .clone()
+            .detach()
+            .with_leading_trivia(
+                leading_pieces
+                    .iter()
+                    .map(|(kind, text)| (*kind, text.as_str())),
+            )
+            .with_trailing_trivia(
+                trailing_pieces
+                    .iter()
+                    .map(|(kind, text)| (*kind, text.as_str())),
+            );
+        mutation.replace_token_discard_trivia(old_token, new_token);
+        has_changes = true;
+    }
+
+    if !has_changes {
+        return None;
+    }
+
+    // The comment can look unused only because its rule is disabled by the
+    // configuration, an override or a nested configuration
+    Some(AnalyzerAction {
+        rule_name: None,
+        category: ActionCategory::QuickFix,
+        applicability: Applicability::MaybeIncorrect,
+        message: markup! {
+            "Remove the unused suppression comment"
+        }
+        .to_owned(),
+        mutation,
+    })
+}
+
+/// Marks the pieces of `trivia` to remove with the comments matching
+/// `is_removed`. A comment on its own line is removed with its whole line,
+/// otherwise only the whitespace that separates it from the code goes away.
+fn trivia_removals<L: Language>(
+    trivia: &[SyntaxTriviaPiece<L>],
+    is_leading: bool,
+    is_removed: impl Fn(&SyntaxTriviaPiece<L>) -> bool,
+) -> Vec<bool> {
+    let mut removals = vec![false; trivia.len()];
+    for (index, piece) in trivia.iter().enumerate() {
+        if !is_removed(piece) {
+            continue;
+        }
+
+        let mut start = index;
+        while start > 0 && trivia[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        let mut end = index + 1;
+        while end < trivia.len() && trivia[end].is_whitespace() {
+            end += 1;
+        }
+
+        // The leading trivia starts a line, and the trailing trivia ends one
+        let starts_line = if start == 0 {
+            is_leading
+        } else {
+            trivia[start - 1].is_newline()
+        };
+        let ends_line = if end == trivia.len() {
+            !is_leading
+        } else {
+            trivia[end].is_newline()
+        };
+
+        let removed = if starts_line && ends_line {
+            start..(end + 1).min(trivia.len())
+        } else if starts_line {
+            index..end
+        } else {
+            start..index + 1
+        };
+        for removal in &mut removals[removed] {
+            *removal = true;
+        }
+    }
+    removals
+}
+
 /// Payload received by the function responsible to mark a suppression comment
 pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     /// The possible offset found in the [TextRange] of the emitted diagnostic
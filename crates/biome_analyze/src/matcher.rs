@@ -27,6 +27,8 @@ pub struct MatchQueryParams<'phase, 'query, L: Language> {
     pub signal_queue: &'query mut BinaryHeap<SignalEntry<'phase, L>>,
     pub suppression_action: &'phase dyn SuppressionAction<Language = L>,
     pub options: &'phase AnalyzerOptions,
+    /// The text range the analysis is restricted to
+    pub range: Option<TextRange>,
 }
 
 /// Wrapper type for a [QueryMatch]
@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use crate::{FixKind, Rule, RuleKey};
+use crate::{AnalyzerPluginVec, FixKind, Rule, RuleKey};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -67,6 +67,9 @@ pub struct AnalyzerConfiguration {
 
     /// Indicates the type of runtime or transformation used for interpreting JSX.
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// A list of plugins that are executed alongside the lint rules
+    pub plugins: AnalyzerPluginVec,
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -77,6 +80,10 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// Whether the signals silenced by suppression comments should still be
+    /// emitted, with their diagnostic tagged as suppressed
+    pub suppressed_signals: bool,
 }
 
 impl AnalyzerOptions {
@@ -114,6 +121,10 @@ impl AnalyzerOptions {
     pub fn preferred_quote(&self) -> &PreferredQuote {
         &self.configuration.preferred_quote
     }
+
+    pub fn plugins(&self) -> &AnalyzerPluginVec {
+        &self.configuration.plugins
+    }
 }
 
 #[derive(Debug, Default)]
@@ -410,6 +410,7 @@ impl<L: Language + Default> RegistryRule<L> {
                 &options,
                 preferred_quote,
                 jsx_runtime,
+                params.range,
             ) {
                 Ok(ctx) => ctx,
                 Err(error) => return Err(error),
@@ -429,6 +430,7 @@ impl<L: Language + Default> RegistryRule<L> {
                     params.services,
                     params.suppression_action,
                     params.options,
+                    params.range,
                 ));
 
                 params.signal_queue.push(SignalEntry {
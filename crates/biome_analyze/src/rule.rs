@@ -544,6 +544,49 @@ macro_rules! declare_source_rule {
     };
 }
 
+/// This macro is used to declare an analyzer rule type, and implement the
+/// [RuleMeta] trait for it. The refactor rules act on the selection of the
+/// editor, see [RuleContext::range](crate::context::RuleContext::range)
+///  # Example
+///
+/// The macro itself expect the following syntax:
+///
+/// ```rust,ignore
+///use biome_analyze::declare_refactor_rule;
+///
+/// declare_refactor_rule! {
+///     /// Documentation
+///     pub(crate) ExampleRule {
+///         version: "next",
+///         name: "ruleName",
+///         language: "js",
+///     }
+/// }
+/// ```
+///
+/// Check [crate](module documentation) for a better
+/// understanding of how the macro works
+#[macro_export]
+macro_rules! declare_refactor_rule {
+    ( $( #[doc = $doc:literal] )+ $vis:vis $id:ident {
+        version: $version:literal,
+        name: $name:tt,
+        language: $language:literal,
+        $( $key:ident: $value:expr, )*
+    } ) => {
+        biome_analyze::declare_rule!(
+            $( #[doc = $doc] )*
+            $vis $id {
+                version: $version,
+                name: $name,
+                language: $language,
+                fix_kind: biome_analyze::FixKind::None,
+                $( $key: $value, )*
+            }
+        );
+    };
+}
+
 /// A rule group is a collection of rules under a given name, serving as a
 /// "namespace" for lint rules and allowing the entire set of rules to be
 /// disabled at once
@@ -922,7 +965,7 @@ pub trait Rule: RuleMeta + Sized {
 }
 
 /// Diagnostic object returned by a single analysis rule
-#[derive(Debug, Diagnostic)]
+#[derive(Clone, Debug, Diagnostic)]
 pub struct RuleDiagnostic {
     #[category]
     pub(crate) category: &'static Category,
@@ -937,7 +980,7 @@ pub struct RuleDiagnostic {
     pub(crate) rule_advice: RuleAdvice,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 /// It contains possible advices to show when printing a diagnostic that belong to the rule
 pub struct RuleAdvice {
     pub(crate) details: Vec<Detail>,
@@ -946,7 +989,7 @@ pub struct RuleAdvice {
     pub(crate) code_suggestion_list: Vec<CodeSuggestionAdvice<MarkupBuf>>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct SuggestionList {
     pub(crate) message: MarkupBuf,
     pub(crate) list: Vec<MarkupBuf>,
@@ -988,7 +1031,7 @@ impl Advices for RuleAdvice {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Detail {
     pub log_category: LogCategory,
     pub message: MarkupBuf,
@@ -7,8 +7,10 @@ use crate::{
     AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag, SuppressionAction,
 };
 use biome_console::MarkupBuf;
-use biome_diagnostics::{advice::CodeSuggestionAdvice, Applicability, CodeSuggestion, Error};
-use biome_rowan::{BatchMutation, Language};
+use biome_diagnostics::{
+    advice::CodeSuggestionAdvice, Applicability, CodeSuggestion, DiagnosticTags, Error,
+};
+use biome_rowan::{BatchMutation, Language, TextRange};
 use std::borrow::Cow;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
@@ -99,6 +101,35 @@ where
     }
 }
 
+/// Signal emitted in place of a signal silenced by a suppression comment, when
+/// [AnalyzerOptions::suppressed_signals] is enabled. It emits the diagnostic
+/// of the silenced signal tagged with [DiagnosticTags::SUPPRESSED], and no action.
+pub(crate) struct SuppressedSignal<'a, L: Language> {
+    signal: &'a dyn AnalyzerSignal<L>,
+}
+
+impl<'a, L: Language> SuppressedSignal<'a, L> {
+    pub(crate) fn new(signal: &'a dyn AnalyzerSignal<L>) -> Self {
+        Self { signal }
+    }
+}
+
+impl<L: Language> AnalyzerSignal<L> for SuppressedSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        self.signal
+            .diagnostic()
+            .map(|diagnostic| diagnostic.with_tags(DiagnosticTags::SUPPRESSED))
+    }
+
+    fn actions(&self) -> AnalyzerActionIter<L> {
+        AnalyzerActionIter::new(vec![])
+    }
+
+    fn transformations(&self) -> AnalyzerTransformationIter<L> {
+        AnalyzerTransformationIter::new(vec![])
+    }
+}
+
 /// Code Action object returned by the analyzer, generated from a [crate::RuleAction]
 /// with additional information about the rule injected by the analyzer
 ///
@@ -312,6 +343,8 @@ pub(crate) struct RuleSignal<'phase, R: Rule> {
     suppression_action: &'phase dyn SuppressionAction<Language = RuleLanguage<R>>,
     /// A list of strings that are considered "globals" inside the analyzer
     options: &'phase AnalyzerOptions,
+    /// The text range the analysis is restricted to
+    range: Option<TextRange>,
 }
 
 impl<'phase, R> RuleSignal<'phase, R>
@@ -328,6 +361,7 @@ where
         >,
 
         options: &'phase AnalyzerOptions,
+        range: Option<TextRange>,
     ) -> Self {
         Self {
             root,
@@ -336,6 +370,7 @@ where
             services,
             suppression_action,
             options,
+            range,
         }
     }
 }
@@ -357,6 +392,7 @@ where
             &options,
             preferred_quote,
             self.options.jsx_runtime(),
+            self.range,
         )
         .ok()?;
 
@@ -388,6 +424,7 @@ where
             &options,
             self.options.preferred_quote(),
             self.options.jsx_runtime(),
+            self.range,
         )
         .ok();
         if let Some(ctx) = ctx {
@@ -434,6 +471,7 @@ where
             &options,
             self.options.preferred_quote(),
             self.options.jsx_runtime(),
+            self.range,
         )
         .ok();
         if let Some(ctx) = ctx {
@@ -29,6 +29,7 @@ impl<'phase, 'query, L: Language> VisitorContext<'phase, 'query, L> {
             signal_queue: self.signal_queue,
             suppression_action: self.suppression_action,
             options: self.options,
+            range: self.range,
         })
     }
 }
@@ -51,7 +51,7 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|github|junit|summary|gitlab|sarif|checkstyle"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -84,6 +84,18 @@ pub struct CliOptions {
     )]
     /// The level of diagnostics to show. In order, from the lowest to the most important: info, warn, error. Passing `--diagnostic-level=error` will cause Biome to print only diagnostics that contain only errors.
     pub diagnostic_level: Severity,
+
+    /// Reuse the results of the previous runs for the files that didn't change. The results are stored inside `node_modules/.cache/biome`.
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
+    /// Only report the diagnostics that aren't recorded in the given baseline file. The baseline file is created with `--write-baseline`.
+    #[bpaf(long("baseline"), argument("PATH"), optional)]
+    pub baseline: Option<String>,
+
+    /// Record the diagnostics emitted by the command in the given baseline file, instead of reporting them.
+    #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+    pub write_baseline: Option<String>,
 }
 
 impl CliOptions {
@@ -133,6 +145,10 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports diagnostics using the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Reports diagnostics grouped by file using the [Checkstyle](https://checkstyle.org/) XML format.
+    Checkstyle,
 }
 
 impl CliReporter {
@@ -152,6 +168,8 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "sarif" => Ok(Self::Sarif),
+            "checkstyle" => Ok(Self::Checkstyle),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -169,6 +187,8 @@ impl Display for CliReporter {
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Sarif => f.write_str("sarif"),
+            CliReporter::Checkstyle => f.write_str("checkstyle"),
         }
     }
 }
@@ -29,6 +29,7 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -154,4 +155,18 @@ impl CommandRunner for CheckCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.watch && self.stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "watch",
+                "stdin-file-path",
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,15 +1,26 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::CACHE_DIRECTORY;
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
 use std::fs::{create_dir, remove_dir_all};
+use std::io::ErrorKind;
 use std::path::PathBuf;
 
 /// Runs the clean command
-pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
+pub fn clean(cli_session: CliSession) -> Result<(), CliDiagnostic> {
     let logs_path = biome_env()
         .biome_log_path
         .value()
         .map_or(default_biome_log_path(), PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
-    Ok(())
+
+    let fs = &cli_session.app.fs;
+    let cache_path = fs
+        .working_directory()
+        .unwrap_or_default()
+        .join(CACHE_DIRECTORY);
+    match fs.remove_dir_all(&cache_path) {
+        Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+        _ => Ok(()),
+    }
 }
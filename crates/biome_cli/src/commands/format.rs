@@ -31,6 +31,7 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
 }
 
 impl LoadEditorConfig for FormatCommandPayload {
@@ -204,4 +205,18 @@ impl CommandRunner for FormatCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.watch && self.stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "watch",
+                "stdin-file-path",
+            ));
+        }
+        Ok(())
+    }
 }
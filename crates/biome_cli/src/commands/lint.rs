@@ -34,6 +34,7 @@ pub(crate) struct LintCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
     pub(crate) javascript_linter: Option<PartialJavascriptLinter>,
     pub(crate) json_linter: Option<PartialJsonLinter>,
     pub(crate) css_linter: Option<PartialCssLinter>,
@@ -148,4 +149,18 @@ impl CommandRunner for LintCommandPayload {
         })
         .set_report(cli_options))
     }
+
+    fn should_watch(&self) -> bool {
+        self.watch
+    }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.watch && self.stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "watch",
+                "stdin-file-path",
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
 use crate::changed::{get_changed_files, get_staged_files};
 use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg};
 use crate::diagnostics::{DeprecatedArgument, DeprecatedConfigurationFile};
+use crate::execute::cache::configuration_hash;
+use crate::execute::watch::watch;
 use crate::execute::Stdin;
 use crate::logging::LoggingKind;
 use crate::{
@@ -176,6 +178,10 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Watch the files, and process them again when they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -261,6 +267,9 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Watch the files, and process them again when they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -322,6 +331,10 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Watch the files, and process them again when they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -453,9 +466,16 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Applies the rewrites of the pattern to the files.
+        ///
+        /// Without this option, the rewrites are only printed as a diff.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// Patterns that contain rewrites, such as `$old => $new`, are
+        /// printed as a diff, and applied to the files when `--write` is used.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -795,6 +815,7 @@ fn check_fix_incompatible_arguments(options: FixFileModeOptions) -> Result<(), C
 ///
 /// Optional methods:
 /// - [CommandRunner::check_incompatible_arguments]
+/// - [CommandRunner::should_watch]
 pub(crate) trait CommandRunner: Sized {
     const COMMAND_NAME: &'static str;
 
@@ -806,6 +827,9 @@ pub(crate) trait CommandRunner: Sized {
         let workspace = &*session.app.workspace;
         self.check_incompatible_arguments()?;
         let (execution, paths) = self.configure_workspace(fs, console, workspace, cli_options)?;
+        if self.should_watch() {
+            return watch(self, execution, session, cli_options, paths);
+        }
         execute_mode(execution, session, cli_options, paths)
     }
 
@@ -834,7 +858,7 @@ pub(crate) trait CommandRunner: Sized {
         }
         let configuration_path = loaded_configuration.directory_path.clone();
         let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
-        let vcs_base_path = configuration_path.or(fs.working_directory());
+        let vcs_base_path = configuration_path.clone().or(fs.working_directory());
         let (vcs_base_path, gitignore_matches) =
             configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
         let paths = self.get_files_to_process(fs, &configuration)?;
@@ -845,6 +869,19 @@ pub(crate) trait CommandRunner: Sized {
 
         let manifest_data = resolve_manifest(fs)?;
 
+        // The workspace falls back to its directory when there's no configuration file
+        let configuration_hash = cli_options.cache.then(|| {
+            configuration_hash(
+                &**fs,
+                &configuration,
+                configuration_path
+                    .clone()
+                    .or(fs.working_directory())
+                    .as_deref(),
+                manifest_data.as_ref().map(|(_, content)| content.as_str()),
+            )
+        });
+
         if let Some(manifest_data) = manifest_data {
             workspace.set_manifest_for_project(manifest_data.into())?;
         }
@@ -853,9 +890,13 @@ pub(crate) trait CommandRunner: Sized {
             configuration,
             vcs_base_path,
             gitignore_matches,
+            configuration_directory: configuration_path,
         })?;
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let mut execution = self.get_execution(cli_options, console, workspace)?;
+        if let Some(configuration_hash) = configuration_hash {
+            execution = execution.with_cache(configuration_hash);
+        }
         Ok((execution, paths))
     }
 
@@ -922,6 +963,11 @@ pub(crate) trait CommandRunner: Sized {
         Ok(())
     }
 
+    /// Whether the command should keep running, and process again the files that change.
+    fn should_watch(&self) -> bool {
+        false
+    }
+
     /// Checks whether the configuration has errors.
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
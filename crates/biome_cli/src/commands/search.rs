@@ -18,6 +18,7 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) pattern: String,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
+    pub(crate) write: bool,
 }
 
 impl CommandRunner for SearchCommandPayload {
@@ -53,7 +54,7 @@ impl CommandRunner for SearchCommandPayload {
     }
 
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -69,6 +70,7 @@ impl CommandRunner for SearchCommandPayload {
             .pattern_id;
         Ok(Execution::new(TraversalMode::Search {
             pattern,
+            write: self.should_write(),
             stdin: self.get_stdin(_console)?,
         })
         .set_report(cli_options))
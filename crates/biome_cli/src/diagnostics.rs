@@ -54,6 +54,8 @@ pub enum CliDiagnostic {
     NoFilesWereProcessed(NoFilesWereProcessed),
     /// Errors thrown when running the `biome migrate` command
     MigrateError(MigrationDiagnostic),
+    /// Emitted when the baseline file can't be read or written
+    Baseline(BaselineDiagnostic),
     /// Emitted during the reporting phase
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
@@ -247,6 +249,21 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "baseline",
+	severity = Error,
+	message(
+		message("The baseline file "<Emphasis>{self.path}</Emphasis>" can't be used: "{{&self.reason}}),
+		description = "The baseline file {path} can't be used: {reason}"
+	)
+)]
+pub struct BaselineDiagnostic {
+    #[location(resource)]
+    pub path: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -444,6 +461,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file at `path` can't be read or written
+    pub fn baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Baseline(BaselineDiagnostic {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Return by the help command when it is called with a subcommand it doesn't know
     pub fn new_unknown_help(command: impl Into<String>) -> Self {
         Self::UnknownCommandHelp(UnknownCommandHelp {
@@ -0,0 +1,255 @@
+//! Baseline of the diagnostics of a project.
+//!
+//! A baseline records the diagnostics that exist at a given time, so they can
+//! be ignored by the following runs, which then only report the new
+//! diagnostics. Each diagnostic is identified by a [Fingerprint] that doesn't
+//! depend on its line number, so a diagnostic stays in the baseline when the
+//! code around it is moved.
+
+use crate::CliDiagnostic;
+use biome_diagnostics::{Error, Resource};
+use biome_fs::{FileSystem, OpenOptions};
+use path_absolutize::Absolutize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The algorithm used to compute the [Fingerprint::code_hash] of the
+/// diagnostics: the 64-bit FNV-1a hash of the lines of code.
+///
+/// The hashes are stored in baseline files that are shared across machines
+/// and Biome versions, so the algorithm must never change. A new algorithm
+/// must use a new name.
+const HASH_ALGORITHM: &str = "fnv1a64";
+
+/// Content of a baseline file
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BaselineFile {
+    /// The algorithm of the hashes of the fingerprints
+    hash_algorithm: String,
+    /// The fingerprints of the diagnostics. A fingerprint appears as many
+    /// times as the number of diagnostics it identifies.
+    diagnostics: Vec<Fingerprint>,
+}
+
+/// Identifies a diagnostic, independently of its position in the file
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Fingerprint {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    category: String,
+    /// The path of the file, relative to the working directory
+    path: String,
+    /// The hash of the lines of code where the diagnostic was emitted, ignoring
+    /// their indentation
+    code_hash: String,
+}
+
+/// The baseline used by a command
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    /// The number of diagnostics that are identified by each fingerprint
+    fingerprints: Mutex<BTreeMap<Fingerprint, usize>>,
+    /// When set, the diagnostics are recorded and written to this file,
+    /// instead of being matched against the existing fingerprints
+    write_path: Option<PathBuf>,
+    working_directory: Option<PathBuf>,
+}
+
+impl Baseline {
+    /// Loads the baseline file at `path`
+    pub(crate) fn load(fs: &dyn FileSystem, path: &str) -> Result<Self, CliDiagnostic> {
+        let content = fs
+            .read_file_from_path(&PathBuf::from(path))
+            .map_err(|_| CliDiagnostic::baseline(path, "the file can't be read."))?;
+        let baseline_file: BaselineFile = serde_json::from_str(&content)
+            .map_err(|error| CliDiagnostic::baseline(path, error.to_string()))?;
+        if baseline_file.hash_algorithm != HASH_ALGORITHM {
+            return Err(CliDiagnostic::baseline(
+                path,
+                format!(
+                    "the hash algorithm {} isn't supported, the file must be written again with --write-baseline.",
+                    baseline_file.hash_algorithm
+                ),
+            ));
+        }
+
+        let mut fingerprints = BTreeMap::new();
+        for fingerprint in baseline_file.diagnostics {
+            *fingerprints.entry(fingerprint).or_default() += 1;
+        }
+
+        Ok(Self {
+            fingerprints: Mutex::new(fingerprints),
+            write_path: None,
+            working_directory: fs.working_directory(),
+        })
+    }
+
+    /// Creates an empty baseline, that records the diagnostics and writes them
+    /// to the file at `path` when it's saved
+    pub(crate) fn new_for_write(fs: &dyn FileSystem, path: &str) -> Self {
+        Self {
+            fingerprints: Mutex::default(),
+            write_path: Some(PathBuf::from(path)),
+            working_directory: fs.working_directory(),
+        }
+    }
+
+    /// Returns `true` if the diagnostic is part of the baseline, and so it
+    /// shouldn't be reported.
+    ///
+    /// When the baseline is being written, all the diagnostics are recorded.
+    pub(crate) fn contains(&self, diagnostic: &Error, path: &str, source_code: &str) -> bool {
+        let Some(fingerprint) = self.fingerprint(diagnostic, path, source_code) else {
+            return false;
+        };
+
+        let mut fingerprints = self.fingerprints.lock().unwrap();
+        if self.write_path.is_some() {
+            *fingerprints.entry(fingerprint).or_default() += 1;
+            return true;
+        }
+
+        match fingerprints.get_mut(&fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Writes the recorded diagnostics to the baseline file, if the baseline
+    /// is being written
+    pub(crate) fn save(self, fs: &dyn FileSystem) -> Result<(), CliDiagnostic> {
+        let Some(write_path) = self.write_path else {
+            return Ok(());
+        };
+
+        let diagnostics = self
+            .fingerprints
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .flat_map(|(fingerprint, count)| std::iter::repeat(fingerprint).take(count))
+            .collect();
+        let baseline_file = BaselineFile {
+            hash_algorithm: HASH_ALGORITHM.to_string(),
+            diagnostics,
+        };
+        let content = serde_json::to_string_pretty(&baseline_file).map_err(|error| {
+            CliDiagnostic::baseline(write_path.display().to_string(), error.to_string())
+        })?;
+
+        let mut file = fs.open_with_options(
+            &write_path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(content.as_bytes())?;
+        Ok(())
+    }
+
+    fn fingerprint(
+        &self,
+        diagnostic: &Error,
+        path: &str,
+        source_code: &str,
+    ) -> Option<Fingerprint> {
+        let category = diagnostic.category()?;
+        let path = match diagnostic.location().resource {
+            Some(Resource::File(path)) => path,
+            _ => path,
+        };
+
+        let code = diagnostic
+            .location()
+            .span
+            .and_then(|span| {
+                // Expand the range to the whole lines, so the fingerprint
+                // doesn't depend on the exact position of the diagnostic
+                let start = source_code
+                    .get(..usize::from(span.start()))?
+                    .rfind('\n')
+                    .map_or(0, |index| index + 1);
+                let end = source_code
+                    .get(usize::from(span.end())..)?
+                    .find('\n')
+                    .map_or(source_code.len(), |index| usize::from(span.end()) + index);
+                source_code.get(start..end)
+            })
+            .unwrap_or_default();
+
+        Some(Fingerprint {
+            category: category.name().to_string(),
+            path: self.relativize(path),
+            code_hash: code_hash(code),
+        })
+    }
+
+    /// Returns the path relative to the working directory, using `/` as
+    /// separator, so the baseline can be shared across machines
+    fn relativize(&self, path: &str) -> String {
+        let relative_path = self
+            .working_directory
+            .as_deref()
+            .and_then(|working_directory| {
+                let absolute_path = Path::new(path).absolutize().ok()?;
+                absolute_path
+                    .strip_prefix(working_directory)
+                    .ok()
+                    .map(|path| path.display().to_string())
+            })
+            .unwrap_or_else(|| path.to_string());
+
+        let relative_path = relative_path.replace('\\', "/");
+        match relative_path.strip_prefix("./") {
+            Some(relative_path) => relative_path.to_string(),
+            None => relative_path,
+        }
+    }
+}
+
+/// Hashes the lines of `code` with [HASH_ALGORITHM], ignoring their
+/// indentation
+fn code_hash(code: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (index, line) in code.lines().enumerate() {
+        let separator = if index == 0 { "" } else { "\n" };
+        for byte in separator.bytes().chain(line.trim().bytes()) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::code_hash;
+
+    #[test]
+    fn code_hash_is_stable() {
+        assert_eq!(code_hash(""), "cbf29ce484222325");
+        assert_eq!(code_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(code_hash("foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn code_hash_ignores_the_indentation() {
+        assert_eq!(
+            code_hash("if (a) {\n  b();\n}"),
+            code_hash("  if (a) {\n\tb();\n  }")
+        );
+        assert_ne!(code_hash("a\nb"), code_hash("ab"));
+    }
+}
@@ -0,0 +1,440 @@
+//! Persistent cache of the results of the analysis of the files.
+//!
+//! The cache is stored on disk, inside [CACHE_DIRECTORY], and allows commands
+//! to skip the files that didn't change since the previous run. Each command
+//! has its own cache file, and the commands that modify the files use a
+//! different one, where only the files that were left untouched are stored.
+//!
+//! Every entry is keyed by the hash of the content of the file, while the
+//! whole cache is invalidated when anything else that can influence the
+//! results changes: the version of Biome, the configuration, the manifest of
+//! the project, the plugins, or the arguments of the command.
+//!
+//! The results of the JavaScript files can also depend on the other modules
+//! of the project, so their key includes the hash of all the modules, see
+//! [module_hash].
+
+use crate::execute::process_file::{DiffKind, Message};
+use crate::execute::{Execution, TraversalMode};
+use crate::VERSION;
+use biome_analyze::RuleFilter;
+use biome_configuration::PartialConfiguration;
+use biome_diagnostics::serde::Diagnostic as SerdeDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_service::workspace::FixFileMode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use xxhash_rust::xxh3::Xxh3;
+
+/// The directory where the cache is stored, relative to the working directory
+pub(crate) const CACHE_DIRECTORY: &str = "node_modules/.cache/biome";
+
+/// The algorithm of the hashes stored in the cache: the 128-bit XXH3 hash.
+///
+/// The cache files are discarded when they were written with another
+/// algorithm, so a new algorithm must use a new name.
+const HASH_ALGORITHM: &str = "xxh3-128";
+
+/// Computes the hashes of the cache with [HASH_ALGORITHM].
+///
+/// Every value is prefixed with its length, so that the hashes don't depend
+/// on the platform or on the [std::hash::Hash] implementations of the
+/// standard library.
+struct CacheHasher(Xxh3);
+
+impl CacheHasher {
+    fn new() -> Self {
+        Self(Xxh3::new())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.0.update(&(bytes.len() as u64).to_le_bytes());
+        self.0.update(bytes);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_bytes(value.as_bytes());
+    }
+
+    fn write_option_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.write_bool(true);
+                self.write_str(value);
+            }
+            None => self.write_bool(false),
+        }
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.0.update(&[u8::from(value)]);
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.0.update(&value.to_le_bytes());
+    }
+
+    fn finish(&self) -> u128 {
+        self.0.digest128()
+    }
+}
+
+/// Computes the hash of the configuration, the manifest and the plugins of the
+/// project.
+///
+/// Relative plugin paths are resolved against `configuration_directory`, the
+/// directory of the configuration file, like the workspace does when it loads
+/// them.
+pub(crate) fn configuration_hash(
+    fs: &dyn FileSystem,
+    configuration: &PartialConfiguration,
+    configuration_directory: Option<&Path>,
+    manifest: Option<&str>,
+) -> u128 {
+    let mut hasher = CacheHasher::new();
+    hasher.write_str(VERSION);
+    hasher.write_str(&serde_json::to_string(configuration).unwrap_or_default());
+    hasher.write_option_str(manifest);
+
+    let base_path = configuration_directory
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    for plugin in configuration
+        .plugins
+        .iter()
+        .flat_map(|plugins| plugins.iter())
+    {
+        hasher.write_option_str(
+            fs.read_file_from_path(&base_path.join(plugin.path()))
+                .ok()
+                .as_deref(),
+        );
+    }
+
+    hasher.finish()
+}
+
+/// Computes the hash of the content of a file
+pub(crate) fn content_hash(content: &str) -> u128 {
+    let mut hasher = CacheHasher::new();
+    hasher.write_str(content);
+    hasher.finish()
+}
+
+/// Computes the hash of a module of the module graph. The hashes of the
+/// modules are combined with a XOR, so that they don't depend on the order in
+/// which the modules are indexed.
+pub(crate) fn module_hash(path: &Path, content: &str) -> u128 {
+    let mut hasher = CacheHasher::new();
+    hasher.write_bytes(path.as_os_str().as_encoded_bytes());
+    hasher.write_str(content);
+    hasher.finish()
+}
+
+/// Returns the key of the cache for the given execution, or [None] when the
+/// execution can't use the cache.
+pub(crate) fn cache_key(execution: &Execution, configuration_hash: u128) -> Option<u128> {
+    if execution.as_stdin_file().is_some() {
+        return None;
+    }
+
+    let mut hasher = CacheHasher::new();
+    hasher.write_u128(configuration_hash);
+    hasher.write_bool(execution.reports_suppressed_diagnostics());
+    match execution.traversal_mode() {
+        TraversalMode::Check { fix_file_mode, .. } => {
+            hasher.write_option_str(fix_file_mode.map(fix_file_mode_name));
+        }
+        TraversalMode::CI { .. } => {}
+        TraversalMode::Lint {
+            fix_file_mode,
+            only,
+            skip,
+            ..
+        } => {
+            hasher.write_option_str(fix_file_mode.map(fix_file_mode_name));
+            for selectors in [only, skip] {
+                hasher.write_u128(selectors.len() as u128);
+                for selector in selectors {
+                    hasher.write_str(&RuleFilter::from(selector).to_string());
+                }
+            }
+        }
+        TraversalMode::Format {
+            ignore_errors,
+            write,
+            ..
+        } => {
+            hasher.write_bool(*ignore_errors);
+            hasher.write_bool(*write);
+        }
+        TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => return None,
+    }
+
+    Some(hasher.finish())
+}
+
+fn fix_file_mode_name(fix_file_mode: FixFileMode) -> &'static str {
+    match fix_file_mode {
+        FixFileMode::SafeFixes => "safeFixes",
+        FixFileMode::SafeAndUnsafeFixes => "safeAndUnsafeFixes",
+        FixFileMode::RemoveUnusedSuppressions => "removeUnusedSuppressions",
+    }
+}
+
+/// Stores the hashes as hexadecimal strings, because most JSON parsers can't
+/// read 128-bit integers
+mod hex_hash {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(hash: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{hash:032x}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<u128, D::Error> {
+        let hash = String::deserialize(deserializer)?;
+        u128::from_str_radix(&hash, 16).map_err(D::Error::custom)
+    }
+}
+
+/// Content of a cache file
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    /// The algorithm of the hashes of the cache, see [HASH_ALGORITHM]
+    hash_algorithm: String,
+    #[serde(with = "hex_hash")]
+    key: u128,
+    files: BTreeMap<PathBuf, CachedFile>,
+}
+
+/// The results of the analysis of a file
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CachedFile {
+    /// The hash of the content of the file that was analyzed
+    #[serde(with = "hex_hash")]
+    content_hash: u128,
+    /// The diagnostics emitted for the file
+    diagnostics: Vec<SerdeDiagnostic>,
+    /// The changes that the command would apply to the file, e.g. when it
+    /// isn't formatted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    diffs: Vec<CachedDiff>,
+}
+
+/// A change that the command would apply to a file, see [Message::Diff]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDiff {
+    /// The new content of the file
+    new: String,
+    diff_kind: DiffKind,
+}
+
+impl CachedFile {
+    /// Returns the messages emitted for the file named `name`, whose content
+    /// is `content`
+    pub(crate) fn into_messages(self, name: String, content: String) -> Vec<Message> {
+        let mut messages = Vec::with_capacity(self.diffs.len() + 1);
+        if !self.diagnostics.is_empty() {
+            messages.push(Message::Diagnostics {
+                name: name.clone(),
+                content: content.clone(),
+                diagnostics: self.diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics: 0,
+            });
+        }
+        messages.extend(self.diffs.into_iter().map(|diff| Message::Diff {
+            file_name: name.clone(),
+            old: content.clone(),
+            new: diff.new,
+            diff_kind: diff.diff_kind,
+        }));
+        messages
+    }
+}
+
+/// The cache of the results of the analysis of the files processed by a command
+#[derive(Debug)]
+pub(crate) struct AnalysisCache {
+    /// The path of the file where the cache is persisted
+    path: PathBuf,
+    /// The key used to invalidate the cache, see [cache_key]
+    key: u128,
+    files: Mutex<BTreeMap<PathBuf, CachedFile>>,
+}
+
+impl AnalysisCache {
+    /// Loads the cache of the command of the given execution.
+    ///
+    /// The cache is empty when it doesn't exist yet, can't be read, or was
+    /// created with a different key or hash algorithm.
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution, key: u128) -> Self {
+        // The commands that modify the files only store the files they didn't
+        // change, so they use their own cache instead of invalidating the
+        // cache of the command that only reads them
+        let file_name = if execution.is_write() {
+            format!("{}-write.json", execution.traversal_mode())
+        } else {
+            format!("{}.json", execution.traversal_mode())
+        };
+        let path = fs
+            .working_directory()
+            .unwrap_or_default()
+            .join(CACHE_DIRECTORY)
+            .join(file_name);
+
+        let files = fs
+            .read_file_from_path(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| {
+                cache_file.hash_algorithm == HASH_ALGORITHM && cache_file.key == key
+            })
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+
+        Self {
+            path,
+            key,
+            files: Mutex::new(files),
+        }
+    }
+
+    /// Returns the results of the analysis of the file at `path`, if its
+    /// content didn't change since they were stored.
+    pub(crate) fn get(&self, path: &Path, content_hash: u128) -> Option<CachedFile> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(path)
+            .filter(|cached_file| cached_file.content_hash == content_hash)
+            .cloned()
+    }
+
+    pub(crate) fn insert(&self, path: PathBuf, cached_file: CachedFile) {
+        self.files.lock().unwrap().insert(path, cached_file);
+    }
+
+    /// Writes the cache on disk, dropping the files that don't exist anymore
+    pub(crate) fn save(self, fs: &dyn FileSystem) -> io::Result<()> {
+        let mut files = self.files.into_inner().unwrap();
+        files.retain(|path, _| fs.path_exists(path));
+
+        let content = serde_json::to_string(&CacheFile {
+            hash_algorithm: HASH_ALGORITHM.to_string(),
+            key: self.key,
+            files,
+        })?;
+
+        if let Some(parent) = self.path.parent() {
+            fs.create_dir_all(parent)?;
+        }
+        let mut file = fs.open_with_options(
+            &self.path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(content.as_bytes())
+    }
+}
+
+/// Records the messages emitted while a file is processed, so the results can
+/// be stored in the cache.
+///
+/// Only the diagnostics and the diffs can be replayed from the cache. Files
+/// that emit any other message (errors, skipped fixes, etc.) aren't cached.
+#[derive(Debug, Default)]
+pub(crate) struct CacheRecorder {
+    diagnostics: Vec<SerdeDiagnostic>,
+    diffs: Vec<CachedDiff>,
+    has_uncacheable_messages: bool,
+}
+
+impl CacheRecorder {
+    /// Records `message`, and returns the message that should be sent to the
+    /// display thread
+    pub(crate) fn record(&mut self, message: Message) -> Message {
+        match message {
+            Message::Diagnostics {
+                name,
+                content,
+                diagnostics,
+                skipped_diagnostics: 0,
+            } => {
+                self.diagnostics
+                    .extend(diagnostics.iter().map(SerdeDiagnostic::new));
+
+                Message::Diagnostics {
+                    name,
+                    content,
+                    diagnostics,
+                    skipped_diagnostics: 0,
+                }
+            }
+            Message::Diff {
+                file_name,
+                old,
+                new,
+                diff_kind,
+            } => {
+                self.diffs.push(CachedDiff {
+                    new: new.clone(),
+                    diff_kind,
+                });
+
+                Message::Diff {
+                    file_name,
+                    old,
+                    new,
+                    diff_kind,
+                }
+            }
+            // The lint sends this message even when it doesn't skip any fix
+            message @ Message::SkippedFixes {
+                skipped_suggested_fixes: 0,
+            } => message,
+            message => {
+                self.has_uncacheable_messages = true;
+                message
+            }
+        }
+    }
+
+    /// Returns the entry of the cache for the file with the given content
+    /// hash, if its results can be cached
+    pub(crate) fn finish(self, content_hash: u128) -> Option<CachedFile> {
+        (!self.has_uncacheable_messages).then_some(CachedFile {
+            content_hash,
+            diagnostics: self.diagnostics,
+            diffs: self.diffs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::content_hash;
+
+    #[test]
+    fn content_hash_is_stable() {
+        assert_eq!(
+            format!("{:032x}", content_hash("")),
+            "2c0a8a99dc147d5445c3b49d035665b2"
+        );
+        assert_eq!(
+            format!("{:032x}", content_hash("foobar")),
+            "45da03d869ab99fa53846ae89d368299"
+        );
+    }
+}
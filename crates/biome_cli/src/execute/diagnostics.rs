@@ -80,6 +80,19 @@ pub(crate) struct AssistsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would rewrite the file as follows:"
+)]
+pub(crate) struct SearchDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -1,18 +1,23 @@
+pub(crate) mod baseline;
+pub(crate) mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
+pub(crate) mod watch;
 
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{traverse, TraverseResult};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
@@ -41,6 +46,9 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The key of the persistent cache of the analysis results, if the cache is enabled
+    cache_key: Option<u128>,
 }
 
 impl Execution {
@@ -54,12 +62,19 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache_key: None,
         }
     }
 
     pub fn report_mode(&self) -> &ReportMode {
         &self.report_mode
     }
+
+    /// Whether the diagnostics silenced by suppression comments are reported,
+    /// which is only the case of the SARIF reporter
+    pub(crate) fn reports_suppressed_diagnostics(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Sarif)
+    }
 }
 
 impl Execution {
@@ -191,10 +206,11 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
+        /// Whether the rewrites of the pattern should be written on file
+        write: bool,
+
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -229,6 +245,10 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Reports information in the [Checkstyle](https://checkstyle.org/) XML format.
+    Checkstyle,
 }
 
 impl Default for ReportMode {
@@ -251,6 +271,8 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Checkstyle => Self::Checkstyle,
         }
     }
 }
@@ -261,6 +283,7 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache_key: None,
         }
     }
 
@@ -281,6 +304,7 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            cache_key: None,
         }
     }
 
@@ -290,6 +314,18 @@ impl Execution {
         self
     }
 
+    /// Enables the persistent cache of the analysis results, if the execution supports it.
+    ///
+    /// The `configuration_hash` is computed by [cache::configuration_hash].
+    pub(crate) fn with_cache(mut self, configuration_hash: u128) -> Self {
+        self.cache_key = cache::cache_key(&self, configuration_hash);
+        self
+    }
+
+    pub(crate) fn cache_key(&self) -> Option<u128> {
+        self.cache_key
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -374,8 +410,10 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -407,7 +445,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 }
@@ -430,6 +468,21 @@ pub fn execute_mode(
 
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = execution.as_stdin_file() {
+        // The diagnostics of the content aren't reported, so they can't be
+        // matched against a baseline
+        if cli_options.write_baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "stdin-file-path",
+                "write-baseline",
+            ));
+        }
+        if cli_options.baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "stdin-file-path",
+                "baseline",
+            ));
+        }
+
         let biome_path = BiomePath::new(stdin.as_path());
         std_in::run(
             session,
@@ -570,6 +623,31 @@ pub fn execute_mode(
                 };
                 reporter.write(&mut JunitReporterVisitor::new(console))?;
             }
+            ReportMode::Sarif => {
+                let reporter = SarifReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut SarifReporterVisitor::new(
+                    console,
+                    session.app.fs.borrow().working_directory(),
+                ))?;
+            }
+            ReportMode::Checkstyle => {
+                let reporter = CheckstyleReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut CheckstyleReporterVisitor(console))?;
+            }
         }
 
         // Processing emitted error diagnostics, exit with a non-zero code
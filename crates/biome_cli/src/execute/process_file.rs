@@ -6,16 +6,22 @@ mod organize_imports;
 mod search;
 pub(crate) mod workspace_file;
 
+use crate::execute::cache::{content_hash, AnalysisCache, CacheRecorder, CachedFile};
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error};
 use biome_fs::BiomePath;
-use biome_service::workspace::{FeatureKind, SupportKind, SupportsFeatureParams};
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::workspace::{
+    FeatureKind, FileFeaturesResult, SupportKind, SupportsFeatureParams,
+};
 use check::check_file;
 use format::format;
 use lint::lint;
 use search::search;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -70,11 +76,12 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
     Assists,
+    Search,
 }
 
 impl<D> From<D> for Message
@@ -102,6 +109,8 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// Records the messages of the file, when its results are stored in the cache
+    cache_recorder: Option<RefCell<CacheRecorder>>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -110,8 +119,38 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
         Self {
             _p: PhantomData,
             inner: t,
+            cache_recorder: None,
+        }
+    }
+
+    fn with_cache_recorder(mut self) -> Self {
+        self.cache_recorder = Some(RefCell::new(CacheRecorder::default()));
+        self
+    }
+
+    /// Send a message to the display thread, recording it if the results of
+    /// the file are going to be cached
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        match &self.cache_recorder {
+            Some(recorder) => self.inner.push_message(recorder.borrow_mut().record(msg)),
+            None => self.inner.push_message(msg),
         }
     }
+
+    /// Records a message that is returned to the traversal instead of being
+    /// sent to the display thread
+    fn record_message(&self, msg: Message) -> Message {
+        match &self.cache_recorder {
+            Some(recorder) => recorder.borrow_mut().record(msg),
+            None => msg,
+        }
+    }
+
+    /// Returns the entry of the cache for the processed file, if its results can be cached
+    fn into_cached_file(self, content_hash: u128) -> Option<CachedFile> {
+        self.cache_recorder?.into_inner().finish(content_hash)
+    }
 }
 
 impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
@@ -206,27 +245,83 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             };
         }
 
-        let shared_context = &SharedTraversalOptions::new(ctx);
-
-        match ctx.execution.traversal_mode {
-            TraversalMode::Lint { .. } => {
-                // the unsupported case should be handled already at this point
-                lint(shared_context, biome_path)
-            }
-            TraversalMode::Format { .. } => {
-                // the unsupported case should be handled already at this point
-                format(shared_context, biome_path)
-            }
-            TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-                check_file(shared_context, biome_path, &file_features)
-            }
-            TraversalMode::Migrate { .. } => {
-                unreachable!("The migration should not be called for this file")
-            }
-            TraversalMode::Search { ref pattern, .. } => {
-                // the unsupported case should be handled already at this point
-                search(shared_context, biome_path, pattern)
-            }
+        match ctx.cache {
+            Some(cache) => process_file_with_cache(ctx, biome_path, &file_features, cache),
+            None => process_file_by_mode(
+                &SharedTraversalOptions::new(ctx),
+                biome_path,
+                &file_features,
+            ),
         }
     })
 }
+
+/// Replays the results stored in the cache if the file didn't change since
+/// the previous run, otherwise processes the file and stores its results
+fn process_file_with_cache(
+    ctx: &TraversalOptions,
+    biome_path: &BiomePath,
+    file_features: &FileFeaturesResult,
+    cache: &AnalysisCache,
+) -> FileResult {
+    let content = ctx.fs.read_file_from_path(biome_path)?;
+    let mut content_hash = content_hash(&content);
+    if DocumentFileSource::from_path(biome_path)
+        .to_js_file_source()
+        .is_some()
+    {
+        content_hash ^= ctx.module_graph_hash.get().copied().unwrap_or_default();
+    }
+
+    if let Some(cached_file) = cache.get(biome_path, content_hash) {
+        for message in cached_file.into_messages(biome_path.display().to_string(), content) {
+            ctx.push_message(message);
+        }
+        return Ok(FileStatus::Unchanged);
+    }
+
+    let shared_context = SharedTraversalOptions::new(ctx).with_cache_recorder();
+    let result = match process_file_by_mode(&shared_context, biome_path, file_features) {
+        // The formatter returns the diff of the file instead of sending it
+        Ok(FileStatus::Message(message @ Message::Diff { .. })) => {
+            Ok(FileStatus::Message(shared_context.record_message(message)))
+        }
+        result => result,
+    };
+    // The files changed by the command are processed again by the next run,
+    // which then stores the results of their new content
+    if let Ok(FileStatus::Unchanged | FileStatus::Message(Message::Diff { .. })) = result {
+        if let Some(cached_file) = shared_context.into_cached_file(content_hash) {
+            cache.insert(biome_path.to_path_buf(), cached_file);
+        }
+    }
+
+    result
+}
+
+fn process_file_by_mode<'ctx>(
+    shared_context: &'ctx SharedTraversalOptions<'ctx, '_>,
+    biome_path: &BiomePath,
+    file_features: &'ctx FileFeaturesResult,
+) -> FileResult {
+    match shared_context.execution.traversal_mode {
+        TraversalMode::Lint { .. } => {
+            // the unsupported case should be handled already at this point
+            lint(shared_context, biome_path)
+        }
+        TraversalMode::Format { .. } => {
+            // the unsupported case should be handled already at this point
+            format(shared_context, biome_path)
+        }
+        TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
+            check_file(shared_context, biome_path, file_features)
+        }
+        TraversalMode::Migrate { .. } => {
+            unreachable!("The migration should not be called for this file")
+        }
+        TraversalMode::Search { ref pattern, .. } => {
+            // the unsupported case should be handled already at this point
+            search(shared_context, biome_path, pattern)
+        }
+    }
+}
@@ -32,6 +32,7 @@ pub(crate) fn format_with_guard<'ctx>(
                     max_diagnostics,
                     Vec::new(),
                     Vec::new(),
+                    false,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
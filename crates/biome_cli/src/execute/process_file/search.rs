@@ -1,6 +1,9 @@
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::PatternId;
 use std::path::Path;
@@ -15,7 +18,7 @@ pub(crate) fn search<'ctx>(
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
@@ -34,8 +37,8 @@ pub(crate) fn search_with_guard<'ctx>(
             let matches_len = result.matches.len();
 
             let search_results = Message::Diagnostics {
-                name: file_name,
-                content: input,
+                name: file_name.clone(),
+                content: input.clone(),
                 diagnostics: result
                     .matches
                     .into_iter()
@@ -44,7 +47,32 @@ pub(crate) fn search_with_guard<'ctx>(
                 skipped_diagnostics: 0,
             };
 
-            Ok(FileStatus::SearchResult(matches_len, search_results))
+            let output = match result.rewrite {
+                Some(rewrite) => rewrite.new_string(&input),
+                None => return Ok(FileStatus::SearchResult(matches_len, search_results)),
+            };
+            if output == input {
+                return Ok(FileStatus::SearchResult(matches_len, search_results));
+            }
+
+            let should_write = match ctx.execution.traversal_mode {
+                TraversalMode::Search { write, .. } => write,
+                _ => false,
+            };
+            if should_write {
+                ctx.increment_matches(matches_len);
+                ctx.push_message(search_results);
+                workspace_file.update_file(output)?;
+                Ok(FileStatus::Changed)
+            } else {
+                ctx.push_message(Message::Diff {
+                    file_name,
+                    old: input,
+                    new: output,
+                    diff_kind: DiffKind::Search,
+                });
+                Ok(FileStatus::SearchResult(matches_len, search_results))
+            }
         },
     )
 }
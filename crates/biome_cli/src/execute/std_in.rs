@@ -10,9 +10,9 @@ use biome_fs::BiomePath;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
     ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
-    OpenFileParams, OrganizeImportsParams, SupportsFeatureParams,
+    OpenFileParams, OrganizeImportsParams, PatternId, SearchPatternParams, SupportsFeatureParams,
 };
-use biome_service::WorkspaceError;
+use biome_service::{Workspace, WorkspaceError};
 use std::borrow::Cow;
 
 pub(crate) fn run<'a>(
@@ -199,15 +199,60 @@ pub(crate) fn run<'a>(
                 });
             }
         }
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+    } else if let TraversalMode::Search { pattern, write, .. } = mode.traversal_mode() {
+        let result = if *write {
+            rewrite_with_pattern(workspace, &biome_path, pattern, content, &mut version)
+        } else {
+            Ok(Cow::Borrowed(content))
+        };
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        let output = result?;
+        console.append(markup! {{output}});
     } else {
         console.append(markup! {{content}});
     }
     Ok(())
 }
+
+/// Applies the rewrites of `pattern` to `content`, returning the new content
+///
+/// The rewritten content is sent to the workspace as a new `version` of the
+/// file, like the fixes applied by the other modes.
+fn rewrite_with_pattern<'a>(
+    workspace: &dyn Workspace,
+    biome_path: &BiomePath,
+    pattern: &PatternId,
+    content: &'a str,
+    version: &mut i32,
+) -> Result<Cow<'a, str>, WorkspaceError> {
+    workspace.open_file(OpenFileParams {
+        path: biome_path.clone(),
+        version: *version,
+        content: content.into(),
+        document_file_source: None,
+    })?;
+    let result = workspace.search_pattern(SearchPatternParams {
+        path: biome_path.clone(),
+        pattern: pattern.clone(),
+    })?;
+
+    let Some(rewrite) = result.rewrite else {
+        return Ok(Cow::Borrowed(content));
+    };
+    let output = rewrite.new_string(content);
+    if output != content {
+        *version += 1;
+        workspace.change_file(ChangeFileParams {
+            content: output.clone(),
+            path: biome_path.clone(),
+            version: *version,
+        })?;
+    }
+
+    Ok(Cow::Owned(output))
+}
@@ -1,10 +1,12 @@
+use super::baseline::Baseline;
+use super::cache::{module_hash, AnalysisCache};
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
-    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic, SearchDiffDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
@@ -13,16 +15,20 @@ use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::workspace::{
+    DropPatternParams, IsPathIgnoredParams, UpdateModuleGraphParams, UsesModuleGraphParams,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use std::collections::BTreeSet;
 use std::sync::atomic::AtomicU32;
-use std::sync::RwLock;
+use std::sync::{OnceLock, RwLock};
 use std::{
     env::current_dir,
-    ffi::OsString,
+    ffi::{OsStr, OsString},
     panic::catch_unwind,
     path::PathBuf,
     sync::{
@@ -84,13 +90,30 @@ pub(crate) fn traverse(
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
 
+    let baseline = match (&cli_options.write_baseline, &cli_options.baseline) {
+        (Some(path), _) => Some(Baseline::new_for_write(fs, path)),
+        (None, Some(path)) => Some(Baseline::load(fs, path)?),
+        (None, None) => None,
+    };
+
     let max_diagnostics = execution.get_max_diagnostics();
-    let remaining_diagnostics = AtomicU32::new(max_diagnostics);
+    // The diagnostics of the baseline aren't printed, so the files must emit
+    // all their diagnostics for them to be matched
+    let remaining_diagnostics = AtomicU32::new(if baseline.is_some() {
+        u32::MAX
+    } else {
+        max_diagnostics
+    });
+
+    let cache = execution
+        .cache_key()
+        .map(|key| AnalysisCache::load(fs, execution, key));
 
     let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(baseline.as_ref());
 
     let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
@@ -115,6 +138,8 @@ pub(crate) fn traverse(
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
                 evaluated_paths: RwLock::default(),
+                cache: cache.as_ref(),
+                module_graph_hash: OnceLock::new(),
             },
         );
         // wait for the main thread to finish
@@ -123,6 +148,12 @@ pub(crate) fn traverse(
         (elapsed, evaluated_paths, diagnostics)
     });
 
+    if let Some(cache) = cache {
+        if let Err(error) = cache.save(fs) {
+            tracing::warn!("Failed to save the cache of the analysis: {error}");
+        }
+    }
+
     // Make sure patterns are always cleaned up at the end of traversal.
     if let TraversalMode::Search { pattern, .. } = execution.traversal_mode() {
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
@@ -138,6 +169,12 @@ pub(crate) fn traverse(
     let skipped = skipped.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
     let diagnostics_not_printed = printer.not_printed_diagnostics();
+
+    // The printer borrows the baseline, so it's saved once the printer is done
+    if let Some(baseline) = baseline {
+        baseline.save(fs)?;
+    }
+
     Ok(TraverseResult {
         summary: TraversalSummary {
             changed,
@@ -183,6 +220,9 @@ fn traverse_inputs(
     }));
 
     let paths = ctx.evaluated_paths();
+    if ctx.execution.is_check() || ctx.execution.is_ci() || ctx.execution.is_lint() {
+        update_module_graph(ctx, &paths);
+    }
     let dome = Dome::new(paths);
     let mut iter = dome.iter();
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
@@ -202,6 +242,73 @@ fn traverse_inputs(
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Indexes the imports and the exports of the JavaScript files that are
+/// going to be analyzed, so that the lint rules can query the other modules of
+/// the project while the files are processed.
+///
+/// The `package.json`, `tsconfig.json` and `jsconfig.json` files are indexed
+/// too, to resolve the import specifiers. The ones of the working directory are
+/// always indexed, because they apply to the analyzed files even when they
+/// aren't analyzed.
+///
+/// Nothing is indexed when none of the rules enabled for the files query the
+/// module graph.
+fn update_module_graph(ctx: &TraversalOptions, paths: &BTreeSet<BiomePath>) {
+    let (only, skip) = match ctx.execution.traversal_mode() {
+        TraversalMode::Lint { only, skip, .. } => (only.clone(), skip.clone()),
+        _ => (Vec::new(), Vec::new()),
+    };
+    let uses_module_graph = paths.par_iter().any(|path| {
+        DocumentFileSource::from_path(path)
+            .to_js_file_source()
+            .is_some()
+            && ctx
+                .workspace
+                .uses_module_graph(UsesModuleGraphParams {
+                    path: path.clone(),
+                    only: only.clone(),
+                    skip: skip.clone(),
+                })
+                .unwrap_or_default()
+    });
+    if !uses_module_graph {
+        return;
+    }
+
+    let config_paths = MODULE_GRAPH_CONFIG_FILES
+        .iter()
+        .map(|file_name| BiomePath::new(*file_name))
+        .filter(|path| !paths.contains(path));
+    let module_graph_hash = paths
+        .iter()
+        .cloned()
+        .chain(config_paths)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .filter(|path| {
+            DocumentFileSource::from_path(path)
+                .to_js_file_source()
+                .is_some()
+                || path
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|file_name| MODULE_GRAPH_CONFIG_FILES.contains(&file_name))
+        })
+        .filter_map(|path| {
+            let content = ctx.fs.read_file_from_path(&path).ok()?;
+            let hash = module_hash(&path, &content);
+            ctx.workspace
+                .update_module_graph(UpdateModuleGraphParams { path, content })
+                .ok()?;
+            Some(hash)
+        })
+        .reduce(|| 0, |left, right| left ^ right);
+    let _ = ctx.module_graph_hash.set(module_graph_hash);
+}
+
+/// The files that configure the resolution of the imports of the modules
+const MODULE_GRAPH_CONFIG_FILES: &[&str] = &["package.json", "tsconfig.json", "jsconfig.json"];
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -222,6 +329,8 @@ struct DiagnosticsPrinter<'ctx> {
     verbose: bool,
     /// The diagnostic level the console thread should print
     diagnostic_level: Severity,
+    /// The baseline of the diagnostics that shouldn't be reported
+    baseline: Option<&'ctx Baseline>,
 
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
@@ -236,6 +345,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             remaining_diagnostics: AtomicU32::new(0),
             execution,
             diagnostic_level: Severity::Hint,
+            baseline: None,
             verbose: false,
             max_diagnostics: 20,
             not_printed_diagnostics: AtomicU32::new(0),
@@ -259,6 +369,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, value: Option<&'ctx Baseline>) -> Self {
+        self.baseline = value;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -290,6 +405,13 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         false
     }
 
+    /// Checks if the diagnostic emitted for the file `name` is recorded in the
+    /// baseline, in which case it shouldn't be reported
+    fn is_baselined(&self, diagnostic: &Error, name: &str, content: &str) -> bool {
+        self.baseline
+            .is_some_and(|baseline| baseline.contains(diagnostic, name, content))
+    }
+
     /// Count the diagnostic, and then returns a boolean that tells if it should be printed
     fn should_print(&self) -> bool {
         let printed_diagnostics = self.printed_diagnostics.load(Ordering::Relaxed);
@@ -384,6 +506,17 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
                             }
+                            // The suppressed diagnostics are listed by the reporter, but
+                            // they aren't errors nor warnings
+                            if diag.tags().is_suppressed() {
+                                let diag =
+                                    diag.with_file_path(&name).with_file_source_code(&content);
+                                diagnostics_to_print.push(diag);
+                                continue;
+                            }
+                            if self.is_baselined(&diag, &name, &content) {
+                                continue;
+                            }
 
                             if severity == Severity::Error {
                                 self.errors.fetch_add(1, Ordering::Relaxed);
@@ -401,6 +534,17 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
                             }
+                            // The suppressed diagnostics are listed by the reporter, but
+                            // they aren't errors nor warnings
+                            if diag.tags().is_suppressed() {
+                                let diag =
+                                    diag.with_file_path(&name).with_file_source_code(&content);
+                                diagnostics_to_print.push(diag);
+                                continue;
+                            }
+                            if self.is_baselined(&diag, &name, &content) {
+                                continue;
+                            }
                             if severity == Severity::Error {
                                 self.errors.fetch_add(1, Ordering::Relaxed);
                             }
@@ -424,14 +568,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // A diff is an error in CI mode and in format check mode.
+                    // The rewrites of a search are only a preview of `--write`.
+                    let is_search = matches!(diff_kind, DiffKind::Search);
+                    let is_error =
+                        !is_search && (self.execution.is_ci() || !self.execution.is_format_write());
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if is_search {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -485,6 +634,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Search => {
+                                    let diag = SearchDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -527,6 +689,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Search => {
+                                    let diag = SearchDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         }
                     }
@@ -563,6 +738,13 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
 
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+
+    /// The persistent cache of the analysis results, if enabled
+    pub(crate) cache: Option<&'ctx AnalysisCache>,
+
+    /// The hash of the modules indexed in the module graph, which invalidates
+    /// the cached results of the JavaScript files when another module changes
+    pub(crate) module_graph_hash: OnceLock<u128>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
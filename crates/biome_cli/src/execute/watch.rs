@@ -0,0 +1,613 @@
+//! Watch mode of the traversal commands.
+//!
+//! The files are processed once, then the command keeps running and processes
+//! again only the files that the file system reports as changed, reusing the
+//! workspace of the session. When a configuration file changes, the
+//! configuration is loaded again and all the files are processed again, and
+//! so are they when a file is removed, once it's removed from the module graph.
+
+use crate::cli_options::CliOptions;
+use crate::commands::CommandRunner;
+use crate::execute::{execute_mode, Execution};
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::{Error, PrintDiagnostic};
+use biome_fs::{
+    BiomePath, ConfigName, FileSystem, PathInterner, TraversalContext, TraversalScope,
+    DEFAULT_IGNORE,
+};
+use biome_service::workspace::{IsPathIgnoredParams, RemoveFromModuleGraphParams};
+use biome_service::{App, DynRef, WorkspaceRef};
+use crossbeam::channel::{unbounded, Receiver};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use path_absolutize::Absolutize;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The time to wait for more events after a change, so a burst of changes
+/// (e.g. a `git checkout`) is processed at once
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(100);
+
+/// The files that require to load the configuration again when they change
+const CONFIGURATION_FILES: &[&str] = &[".editorconfig", ".gitignore", ".ignore", "package.json"];
+
+/// Processes the files once, and then again every time they change, until the
+/// process is stopped.
+pub(crate) fn watch(
+    command: &mut impl CommandRunner,
+    execution: Execution,
+    session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    if cli_options.write_baseline.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "write-baseline",
+        ));
+    }
+
+    let (sender, receiver) = unbounded();
+    let watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        let _ = sender.send(event);
+    })
+    .map_err(watch_error)?;
+
+    watch_events(
+        command,
+        execution,
+        session,
+        cli_options,
+        paths,
+        watcher,
+        &receiver,
+    )
+}
+
+/// Processes the files once, and then again every time `receiver` reports
+/// that they changed, until the channel is disconnected.
+fn watch_events(
+    command: &mut impl CommandRunner,
+    mut execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    mut paths: Vec<OsString>,
+    watcher: impl Watcher,
+    receiver: &Receiver<notify::Result<Event>>,
+) -> Result<(), CliDiagnostic> {
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let mut roots = watched_roots(&working_directory, &paths);
+    let mut directories = WatchedDirectories::new(watcher);
+    // The configuration files are inside the working directory
+    directories.watch_path(&working_directory)?;
+    directories.watch_roots(&*session.app.fs, &roots)?;
+
+    // The following runs only process the changed files, which can all be
+    // ignored or unsupported
+    let mut incremental_options = cli_options.clone();
+    incremental_options.no_errors_on_unmatched = true;
+
+    run(&mut session, &execution, cli_options, paths.clone());
+    while let Some(changes) = next_changes(&*session.app.fs, receiver) {
+        if changes
+            .changed
+            .iter()
+            .chain(&changes.removed)
+            .any(|path| is_configuration_file(path))
+        {
+            let fs = &session.app.fs;
+            let console = &mut *session.app.console;
+            let workspace = &*session.app.workspace;
+            match command.configure_workspace(fs, console, workspace, cli_options) {
+                Ok((new_execution, new_paths)) => {
+                    execution = new_execution;
+                    paths = new_paths;
+                    roots = watched_roots(&working_directory, &paths);
+                    directories.unwatch_outside(&working_directory, &roots);
+                    directories.watch_roots(&*session.app.fs, &roots)?;
+                    run(&mut session, &execution, cli_options, paths.clone());
+                }
+                Err(error) => print_error(&mut session, cli_options, error),
+            }
+            continue;
+        }
+
+        for path in &changes.changed {
+            if session.app.fs.path_is_dir(path) {
+                directories.watch_roots(&*session.app.fs, std::slice::from_ref(path))?;
+            }
+        }
+
+        // The diagnostics of a removed file are stale, and so can be the ones
+        // of the files that import it, so all the files are processed again
+        if changes
+            .removed
+            .iter()
+            .any(|path| roots.iter().any(|root| path.starts_with(root)))
+        {
+            directories.unwatch_removed(&changes.removed);
+            // The traversal only inserts the files it finds in the module graph, it
+            // doesn't remove the ones that are gone
+            for path in &changes.removed {
+                let result =
+                    session
+                        .app
+                        .workspace
+                        .remove_from_module_graph(RemoveFromModuleGraphParams {
+                            path: BiomePath::new(path),
+                        });
+                if let Err(error) = result {
+                    tracing::warn!(
+                        "Failed to remove {} from the module graph: {error}",
+                        path.display()
+                    );
+                }
+            }
+            run(&mut session, &execution, cli_options, paths.clone());
+            continue;
+        }
+
+        let workspace = &*session.app.workspace;
+        let changed_paths: Vec<OsString> = changes
+            .changed
+            .into_iter()
+            .filter(|path| roots.iter().any(|root| path.starts_with(root)))
+            .filter(|path| {
+                !workspace
+                    .is_path_ignored(IsPathIgnoredParams {
+                        biome_path: BiomePath::new(path),
+                        features: execution.to_feature(),
+                    })
+                    .unwrap_or(true)
+            })
+            .map(PathBuf::into_os_string)
+            .collect();
+        if !changed_paths.is_empty() {
+            run(
+                &mut session,
+                &execution,
+                &incremental_options,
+                changed_paths,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the files and directories to watch, which are the ones passed to
+/// the command, or the working directory
+fn watched_roots(working_directory: &Path, paths: &[OsString]) -> Vec<PathBuf> {
+    if paths.is_empty() {
+        vec![working_directory.to_path_buf()]
+    } else {
+        paths
+            .iter()
+            .map(|path| absolutize(Path::new(path)))
+            .collect()
+    }
+}
+
+/// Runs the command on `paths`, reusing the file system, the console and the
+/// workspace of the session
+fn run(
+    session: &mut CliSession,
+    execution: &Execution,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) {
+    let result = execute_mode(
+        execution.clone(),
+        CliSession {
+            app: App::new(
+                DynRef::Borrowed(&mut *session.app.fs),
+                &mut *session.app.console,
+                WorkspaceRef::Borrowed(&*session.app.workspace),
+            ),
+        },
+        cli_options,
+        paths,
+    );
+    if let Err(error) = result {
+        print_error(session, cli_options, error);
+    }
+
+    session.app.console.log(markup! {
+        <Dim>"Watching for file changes..."</Dim>
+    });
+}
+
+fn print_error(session: &mut CliSession, cli_options: &CliOptions, error: CliDiagnostic) {
+    if cli_options.verbose {
+        session
+            .app
+            .console
+            .error(markup! {{PrintDiagnostic::verbose(&error)}});
+    } else {
+        session
+            .app
+            .console
+            .error(markup! {{PrintDiagnostic::simple(&error)}});
+    }
+}
+
+/// The paths reported by the watcher after a burst of events
+#[derive(Debug, Default)]
+struct Changes {
+    /// The files and directories that were created or modified
+    changed: BTreeSet<PathBuf>,
+    /// The files and directories that were removed, or renamed to another path
+    removed: BTreeSet<PathBuf>,
+}
+
+/// Waits for the next changes, and returns the paths that were created,
+/// modified or removed. Returns [None] when the watcher stopped.
+fn next_changes(
+    fs: &dyn FileSystem,
+    receiver: &Receiver<notify::Result<Event>>,
+) -> Option<Changes> {
+    let mut changes = Changes::default();
+    while changes.changed.is_empty() && changes.removed.is_empty() {
+        let mut events = vec![receiver.recv().ok()?];
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE_DELAY) {
+            events.push(event);
+        }
+
+        for event in events {
+            match event {
+                Ok(event) => {
+                    if !matches!(
+                        event.kind,
+                        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                    ) {
+                        continue;
+                    }
+                    for path in event.paths {
+                        if is_default_ignored(&path) {
+                            continue;
+                        }
+                        // A rename is reported as a modification of both paths,
+                        // and the events of a burst can be out of date
+                        if fs.path_exists(&path) {
+                            changes.removed.remove(&path);
+                            changes.changed.insert(path);
+                        } else {
+                            changes.changed.remove(&path);
+                            changes.removed.insert(path);
+                        }
+                    }
+                }
+                Err(error) => tracing::warn!("Failed to watch the files: {error}"),
+            }
+        }
+    }
+
+    Some(changes)
+}
+
+/// The directories registered to the watcher. The directories are watched
+/// one by one, so the ones that the traversal always ignores aren't watched.
+struct WatchedDirectories<W> {
+    watcher: W,
+    directories: BTreeSet<PathBuf>,
+}
+
+impl<W: Watcher> WatchedDirectories<W> {
+    fn new(watcher: W) -> Self {
+        Self {
+            watcher,
+            directories: BTreeSet::new(),
+        }
+    }
+
+    fn watch_path(&mut self, path: &Path) -> Result<(), CliDiagnostic> {
+        if !self.directories.contains(path) {
+            self.watcher
+                .watch(path, RecursiveMode::NonRecursive)
+                .map_err(watch_error)?;
+            self.directories.insert(path.to_path_buf());
+        }
+        Ok(())
+    }
+
+    /// Watches the files and directories in `roots`, including their
+    /// subdirectories
+    fn watch_roots(&mut self, fs: &dyn FileSystem, roots: &[PathBuf]) -> Result<(), CliDiagnostic> {
+        let (interner, _) = PathInterner::new();
+        let context = DirectoriesContext {
+            fs,
+            interner,
+            directories: Mutex::new(Vec::new()),
+        };
+        fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+            for root in roots {
+                if !is_default_ignored(root) {
+                    scope.evaluate(&context, root.clone());
+                }
+            }
+        }));
+
+        for directory in context.directories.into_inner().unwrap() {
+            self.watch_path(&directory)?;
+        }
+        // A file passed to the command is watched by itself
+        for root in roots {
+            if fs.path_is_file(root) {
+                self.watch_path(root)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stops watching the directories that aren't inside `roots` anymore
+    fn unwatch_outside(&mut self, working_directory: &Path, roots: &[PathBuf]) {
+        let watcher = &mut self.watcher;
+        self.directories.retain(|directory| {
+            let is_watched = directory == working_directory
+                || roots.iter().any(|root| directory.starts_with(root));
+            if !is_watched {
+                let _ = watcher.unwatch(directory);
+            }
+            is_watched
+        });
+    }
+
+    /// Forgets the directories that were removed, that the watcher stopped
+    /// watching on its own
+    fn unwatch_removed(&mut self, removed: &BTreeSet<PathBuf>) {
+        self.directories
+            .retain(|directory| !removed.iter().any(|path| directory.starts_with(path)));
+    }
+}
+
+/// Collects the directories found by a traversal of the file system
+struct DirectoriesContext<'fs> {
+    fs: &'fs dyn FileSystem,
+    interner: PathInterner,
+    directories: Mutex<Vec<PathBuf>>,
+}
+
+impl TraversalContext for DirectoriesContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        // The directories that can't be read aren't watched, and the command
+        // reports the error when it processes them
+        tracing::debug!("Failed to read a watched directory: {error:?}");
+    }
+
+    fn can_handle(&self, path: &BiomePath) -> bool {
+        let is_dir = self.fs.path_is_dir(path);
+        if is_dir {
+            self.directories.lock().unwrap().push(path.to_path_buf());
+        }
+        is_dir
+    }
+
+    fn handle_path(&self, _path: BiomePath) {}
+
+    fn store_path(&self, _path: BiomePath) {}
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        BTreeSet::new()
+    }
+}
+
+fn is_configuration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| {
+            ConfigName::file_names().contains(&file_name)
+                || CONFIGURATION_FILES.contains(&file_name)
+        })
+}
+
+/// Returns `true` if the path is inside a directory that the traversal always
+/// ignores, e.g. `node_modules`
+fn is_default_ignored(path: &Path) -> bool {
+    path.components()
+        .any(|component| DEFAULT_IGNORE.contains(&component.as_os_str().as_encoded_bytes()))
+}
+
+fn absolutize(path: &Path) -> PathBuf {
+    path.absolutize()
+        .map_or_else(|_| path.to_path_buf(), |path| path.to_path_buf())
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::other(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_console::{BufferConsole, Console};
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_fs::MemoryFileSystem;
+    use biome_service::workspace::{self, UpdateModuleGraphParams, Workspace};
+    use bpaf::Parser;
+    use notify::event::{CreateKind, ModifyKind, RemoveKind};
+    use notify::NullWatcher;
+
+    use crate::cli_options::cli_options;
+    use crate::commands::lint::LintCommandPayload;
+
+    use super::*;
+
+    fn lint_command() -> LintCommandPayload {
+        LintCommandPayload {
+            apply: false,
+            apply_unsafe: false,
+            write: false,
+            fix: false,
+            unsafe_: false,
+            linter_configuration: None,
+            vcs_configuration: None,
+            files_configuration: None,
+            paths: vec![OsString::from("/project")],
+            only: Vec::new(),
+            skip: Vec::new(),
+            stdin_file_path: None,
+            staged: false,
+            changed: false,
+            since: None,
+            watch: true,
+            javascript_linter: None,
+            json_linter: None,
+            css_linter: None,
+            graphql_linter: None,
+        }
+    }
+
+    /// Runs `command` in watch mode, and sends `events` to it once the files
+    /// were processed a first time
+    fn watch_lint(
+        mut command: LintCommandPayload,
+        workspace: &dyn Workspace,
+        fs: MemoryFileSystem,
+        events: Vec<Event>,
+    ) -> Vec<String> {
+        let mut console = BufferConsole::default();
+        let cli_options = cli_options()
+            .to_options()
+            .run_inner(bpaf::Args::from(&[] as &[&str]))
+            .unwrap();
+
+        let (sender, receiver) = unbounded();
+        for event in events {
+            sender.send(Ok(event)).unwrap();
+        }
+        // The watch stops once all the events were processed
+        drop(sender);
+
+        let result = {
+            let console: &mut dyn Console = &mut console;
+            let session = CliSession {
+                app: App::new(
+                    DynRef::Owned(Box::new(fs)),
+                    console,
+                    WorkspaceRef::Borrowed(workspace),
+                ),
+            };
+            let fs = &session.app.fs;
+            let console = &mut *session.app.console;
+            let workspace = &*session.app.workspace;
+            let (execution, paths) = command
+                .configure_workspace(fs, console, workspace, &cli_options)
+                .unwrap();
+            watch_events(
+                &mut command,
+                execution,
+                session,
+                &cli_options,
+                paths,
+                NullWatcher,
+                &receiver,
+            )
+        };
+        assert!(result.is_ok(), "watch returned {result:?}");
+
+        console
+            .out_buffer
+            .iter()
+            .map(|message| {
+                let mut buffer = Vec::new();
+                let mut write = biome_console::fmt::Termcolor(NoColor::new(&mut buffer));
+                let mut fmt = biome_console::fmt::Formatter::new(&mut write);
+                fmt.write_markup(markup! {{message.content}}).unwrap();
+                String::from_utf8(buffer).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn processes_the_changed_files_again() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/project/a.js"), "debugger;".as_bytes());
+        fs.insert(PathBuf::from("/project/b.js"), "debugger;".as_bytes());
+
+        let messages = watch_lint(
+            lint_command(),
+            &*workspace::server(),
+            fs,
+            vec![
+                Event::new(EventKind::Modify(ModifyKind::Any))
+                    .add_path(PathBuf::from("/project/a.js")),
+                Event::new(EventKind::Create(CreateKind::File))
+                    .add_path(PathBuf::from("/project/node_modules/c.js")),
+            ],
+        );
+
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked"))
+            .collect();
+        assert_eq!(summaries.len(), 2, "{messages:#?}");
+        assert!(summaries[0].starts_with("Checked 2 files"), "{messages:#?}");
+        assert!(summaries[1].starts_with("Checked 1 file "), "{messages:#?}");
+    }
+
+    #[test]
+    fn processes_all_the_files_again_when_a_file_is_removed() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/project/a.js"), "debugger;".as_bytes());
+        fs.insert(PathBuf::from("/project/b.js"), "debugger;".as_bytes());
+
+        let messages = watch_lint(
+            lint_command(),
+            &*workspace::server(),
+            fs,
+            vec![Event::new(EventKind::Remove(RemoveKind::File))
+                .add_path(PathBuf::from("/project/c.js"))],
+        );
+
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked"))
+            .collect();
+        assert_eq!(summaries.len(), 2, "{messages:#?}");
+        assert!(summaries[0].starts_with("Checked 2 files"), "{messages:#?}");
+        assert!(summaries[1].starts_with("Checked 2 files"), "{messages:#?}");
+    }
+
+    #[test]
+    fn removes_the_removed_files_from_the_module_graph() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/a.js"),
+            "import \"./b.js\";".as_bytes(),
+        );
+
+        // The module `b.js` was indexed before it was removed
+        let workspace = workspace::server();
+        workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                path: BiomePath::new("/project/b.js"),
+                content: "import \"./a.js\";".to_string(),
+            })
+            .unwrap();
+
+        let mut command = lint_command();
+        command.only = vec!["nursery/noImportCycles".parse().unwrap()];
+        let messages = watch_lint(
+            command,
+            &*workspace,
+            fs,
+            vec![Event::new(EventKind::Remove(RemoveKind::File))
+                .add_path(PathBuf::from("/project/b.js"))],
+        );
+
+        let summaries: Vec<_> = messages
+            .iter()
+            .filter(|message| message.starts_with("Checked"))
+            .collect();
+        assert_eq!(summaries.len(), 2, "{messages:#?}");
+        assert!(summaries[0].contains("Found 1 warning."), "{messages:#?}");
+        assert!(!summaries[1].contains("Found"), "{messages:#?}");
+    }
+}
@@ -105,6 +105,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
             } => run_command(
                 self,
                 &cli_options,
@@ -124,6 +125,7 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
                 },
             ),
             BiomeCommand::Lint {
@@ -143,6 +145,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
                 css_linter,
                 javascript_linter,
                 json_linter,
@@ -166,6 +169,7 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
                     css_linter,
                     javascript_linter,
                     json_linter,
@@ -212,6 +216,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
             } => run_command(
                 self,
                 &cli_options,
@@ -230,6 +235,7 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -263,6 +269,7 @@ impl<'app> CliSession<'app> {
                 pattern,
                 stdin_file_path,
                 vcs_configuration,
+                write,
             } => run_command(
                 self,
                 &cli_options,
@@ -272,6 +279,7 @@ impl<'app> CliSession<'app> {
                     pattern,
                     stdin_file_path,
                     vcs_configuration,
+                    write,
                 },
             ),
             BiomeCommand::RunServer {
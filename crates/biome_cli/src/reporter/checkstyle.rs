@@ -0,0 +1,183 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl<'a> ReporterVisitor for CheckstyleReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let diagnostics = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    payload.verbose
+                } else {
+                    true
+                }
+            });
+
+        // Checkstyle groups the errors by file
+        let mut files: BTreeMap<&str, Vec<CheckstyleError>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            if let Some(Resource::File(path)) = diagnostic.location().resource {
+                files
+                    .entry(path)
+                    .or_default()
+                    .push(CheckstyleError::from_diagnostic(diagnostic)?);
+            }
+        }
+
+        let mut output = String::new();
+        write_checkstyle(&mut output, &files).map_err(io::Error::other)?;
+
+        self.0.log(markup! {
+            {output}
+        });
+
+        Ok(())
+    }
+}
+
+/// An `<error>` element of a Checkstyle report
+struct CheckstyleError {
+    /// The line of the diagnostic, starting at 1
+    line: Option<usize>,
+    /// The column of the diagnostic, starting at 1
+    column: Option<usize>,
+    severity: &'static str,
+    message: String,
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    source: Option<&'static str>,
+}
+
+impl CheckstyleError {
+    fn from_diagnostic(diagnostic: &Error) -> io::Result<Self> {
+        let location = diagnostic.location();
+        let start = match (location.span, location.source_code) {
+            (Some(span), Some(source_code)) => {
+                Some(SourceFile::new(source_code).location(span.start())?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            line: start.as_ref().map(|start| start.line_number.get()),
+            column: start.as_ref().map(|start| start.column_number.get()),
+            severity: match diagnostic.severity() {
+                Severity::Hint | Severity::Information => "info",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            source: diagnostic.category().map(|category| category.name()),
+        })
+    }
+}
+
+fn write_checkstyle(
+    output: &mut String,
+    files: &BTreeMap<&str, Vec<CheckstyleError>>,
+) -> std::fmt::Result {
+    writeln!(output, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(output, r#"<checkstyle version="4.3">"#)?;
+    for (path, errors) in files {
+        writeln!(output, r#"  <file name="{}">"#, XmlEscape(path))?;
+        for error in errors {
+            write!(output, "    <error")?;
+            if let Some(line) = error.line {
+                write!(output, r#" line="{line}""#)?;
+            }
+            if let Some(column) = error.column {
+                write!(output, r#" column="{column}""#)?;
+            }
+            write!(
+                output,
+                r#" severity="{}" message="{}""#,
+                error.severity,
+                XmlEscape(&error.message)
+            )?;
+            if let Some(source) = error.source {
+                write!(output, r#" source="{}""#, XmlEscape(source))?;
+            }
+            writeln!(output, " />")?;
+        }
+        writeln!(output, "  </file>")?;
+    }
+    write!(output, "</checkstyle>")
+}
+
+/// Escapes the characters that aren't allowed inside XML attributes, and
+/// the line breaks and tabs that the parsers would normalize to spaces.
+/// The characters that XML 1.0 doesn't allow at all, such as most control
+/// characters, are replaced with U+FFFD.
+struct XmlEscape<'a>(&'a str);
+
+impl std::fmt::Display for XmlEscape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for char in self.0.chars() {
+            match char {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                '\n' => f.write_str("&#10;")?,
+                '\r' => f.write_str("&#13;")?,
+                '\t' => f.write_str("&#9;")?,
+                '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => {
+                    f.write_char(char::REPLACEMENT_CHARACTER)?
+                }
+                char => f.write_char(char)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XmlEscape;
+
+    #[test]
+    fn escapes_the_characters_of_attributes() {
+        assert_eq!(
+            XmlEscape("<a href=\"b\">'&'</a>").to_string(),
+            "&lt;a href=&quot;b&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;"
+        );
+        assert_eq!(XmlEscape("a\r\n\tb").to_string(), "a&#13;&#10;&#9;b");
+    }
+
+    #[test]
+    fn replaces_the_characters_disallowed_in_xml() {
+        assert_eq!(
+            XmlEscape("a\u{0}b\u{1B}[31mc\u{FFFF}").to_string(),
+            "a\u{FFFD}b\u{FFFD}[31mc\u{FFFD}"
+        );
+    }
+}
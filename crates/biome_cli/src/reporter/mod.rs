@@ -1,7 +1,9 @@
+pub(crate) mod checkstyle;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
 pub(crate) mod junit;
+pub(crate) mod sarif;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
@@ -0,0 +1,555 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_console::fmt::{Display, Formatter, Termcolor};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{
+    Advices, Category, Error, LogCategory, PrintDescription, Resource, Severity, Visit,
+};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::documentation::Doc;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use path_absolutize::Absolutize;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+/// The base URI of the paths of the results, relative to the working directory
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+pub(crate) struct SarifReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    working_directory: Option<PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, working_directory: Option<PathBuf>) -> Self {
+        Self {
+            console,
+            working_directory,
+        }
+    }
+
+    /// Returns the path of the file relative to the working directory, if possible
+    fn relativize(&self, path: &str) -> Option<String> {
+        let resolved = Path::new(path).absolutize().ok()?;
+        let relativized = resolved
+            .strip_prefix(self.working_directory.as_deref()?)
+            .ok()?;
+        relativized.to_str().map(str::to_string)
+    }
+}
+
+impl<'a> ReporterVisitor for SarifReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let mut rules = SarifRules::default();
+        let mut results = Vec::new();
+
+        let diagnostics = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    payload.verbose
+                } else {
+                    true
+                }
+            });
+
+        for diagnostic in diagnostics {
+            let rule_index = diagnostic
+                .category()
+                .map(|category| rules.index_of(category));
+            results.push(self.to_result(diagnostic, rule_index)?);
+        }
+
+        let original_uri_base_ids = self.working_directory.as_deref().map(|path| {
+            BTreeMap::from([(
+                SOURCE_ROOT,
+                SarifArtifactLocation {
+                    uri: directory_uri(path),
+                    uri_base_id: None,
+                },
+            )])
+        });
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifToolComponent {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules: rules.descriptors,
+                    },
+                },
+                original_uri_base_ids,
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        };
+
+        let serialized = serde_json::to_string_pretty(&log)?;
+        self.console.log(markup!({ serialized }));
+        Ok(())
+    }
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    fn to_result(&self, diagnostic: &Error, rule_index: Option<usize>) -> io::Result<SarifResult> {
+        let location = diagnostic.location();
+        let artifact_location = match location.resource {
+            Some(Resource::File(path)) => Some(match self.relativize(path) {
+                Some(relative_path) => SarifArtifactLocation {
+                    uri: to_uri(&relative_path),
+                    uri_base_id: self.working_directory.as_ref().map(|_| SOURCE_ROOT),
+                },
+                None => SarifArtifactLocation {
+                    uri: to_uri(path),
+                    uri_base_id: None,
+                },
+            }),
+            _ => None,
+        };
+
+        let source_file = location.source_code.map(SourceFile::new);
+        let region = match (&source_file, location.span) {
+            (Some(source_file), Some(span)) => Some(SarifRegion::new(source_file, span)?),
+            _ => None,
+        };
+
+        let fixes = match (&artifact_location, location.source_code, &source_file) {
+            (Some(artifact_location), Some(source_code), Some(source_file)) => {
+                let mut visitor = FixesVisitor {
+                    source: source_code.text,
+                    source_file,
+                    artifact_location,
+                    description: None,
+                    fixes: Vec::new(),
+                };
+                diagnostic.advices(&mut visitor)?;
+                visitor.fixes
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(SarifResult {
+            rule_id: diagnostic.category().map(|category| category.name()),
+            rule_index,
+            level: match diagnostic.severity() {
+                Severity::Hint | Severity::Information => "note",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            },
+            message: SarifMessage {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations: artifact_location
+                .map(|artifact_location| SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location,
+                        region,
+                    },
+                })
+                .into_iter()
+                .collect(),
+            fixes,
+            suppressions: if diagnostic.tags().is_suppressed() {
+                vec![SarifSuppression { kind: "inSource" }]
+            } else {
+                Vec::new()
+            },
+        })
+    }
+}
+
+/// The rules of the diagnostics emitted during a run, in the order they were
+/// encountered
+#[derive(Default)]
+struct SarifRules {
+    indices: BTreeMap<&'static str, usize>,
+    descriptors: Vec<SarifReportingDescriptor>,
+}
+
+impl SarifRules {
+    /// Returns the index of the rule of the given category, registering it
+    /// if it's the first time it's encountered
+    fn index_of(&mut self, category: &'static Category) -> usize {
+        if let Some(index) = self.indices.get(category.name()) {
+            return *index;
+        }
+
+        let index = self.descriptors.len();
+        self.indices.insert(category.name(), index);
+        self.descriptors
+            .push(SarifReportingDescriptor::from_category(category));
+        index
+    }
+}
+
+/// Collects the code suggestions of a diagnostic as SARIF fixes
+struct FixesVisitor<'a> {
+    source: &'a str,
+    source_file: &'a SourceFile<'a>,
+    artifact_location: &'a SarifArtifactLocation,
+    /// The message of the last log, used as description of the following code suggestion
+    description: Option<String>,
+    fixes: Vec<SarifFix>,
+}
+
+impl<'a> Visit for FixesVisitor<'a> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn Display) -> io::Result<()> {
+        self.description = Some(markup_to_string(text));
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        let description = self.description.take();
+        // The diff doesn't apply to the source code, e.g. the diagnostic was
+        // emitted for the script of a Vue file
+        let Some(edits) = replacements(diff, self.source) else {
+            return Ok(());
+        };
+
+        let mut replacements = Vec::with_capacity(edits.len());
+        for (deleted_range, inserted_content) in edits {
+            replacements.push(SarifReplacement {
+                deleted_region: SarifRegion::new(self.source_file, deleted_range)?,
+                inserted_content: (!inserted_content.is_empty()).then_some(SarifArtifactContent {
+                    text: inserted_content,
+                }),
+            });
+        }
+
+        self.fixes.push(SarifFix {
+            description: description.map(|text| SarifMessage { text }),
+            artifact_changes: vec![SarifArtifactChange {
+                artifact_location: self.artifact_location.clone(),
+                replacements,
+            }],
+        });
+        Ok(())
+    }
+
+    fn record_group(&mut self, _title: &dyn Display, advice: &dyn Advices) -> io::Result<()> {
+        advice.record(self)
+    }
+}
+
+/// Computes the ranges of `source` that are replaced by `diff`, along with
+/// their new content.
+///
+/// Returns [None] if `diff` wasn't computed from `source`.
+fn replacements(diff: &TextEdit, source: &str) -> Option<Vec<(TextRange, String)>> {
+    let mut replacements = Vec::new();
+    let mut current: Option<(TextRange, String)> = None;
+    let mut position = TextSize::from(0);
+
+    for op in diff {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                replacements.extend(current.take());
+                position += range.len();
+            }
+            CompressedOp::EqualLines { line_count } => {
+                replacements.extend(current.take());
+                let input = source.get(usize::from(position)..)?;
+                let line_break_count = line_count.get() as usize + 1;
+                for line in input.split_inclusive('\n').take(line_break_count) {
+                    position += TextSize::of(line);
+                }
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                let (_, inserted) =
+                    current.get_or_insert_with(|| (TextRange::empty(position), String::new()));
+                inserted.push_str(diff.get_text(*range));
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let (deleted, _) =
+                    current.get_or_insert_with(|| (TextRange::empty(position), String::new()));
+                position += range.len();
+                *deleted = TextRange::new(deleted.start(), position);
+            }
+        }
+    }
+    replacements.extend(current);
+
+    (position == TextSize::of(source)).then_some(replacements)
+}
+
+fn markup_to_string(text: &dyn Display) -> String {
+    let mut buffer = Vec::new();
+    let mut write = Termcolor(NoColor::new(&mut buffer));
+    let mut fmt = Formatter::new(&mut write);
+    // SAFETY: Writing to a Vec never fails
+    fmt.write_markup(markup!({ text })).unwrap();
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+/// The characters that are percent-encoded in the paths of the URIs: all the
+/// characters that RFC 3986 doesn't allow in the segments of a path, except
+/// the separator of the segments
+const URI_PATH: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'!')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')')
+    .remove(b'*')
+    .remove(b'+')
+    .remove(b',')
+    .remove(b';')
+    .remove(b'=')
+    .remove(b':')
+    .remove(b'@')
+    .remove(b'/');
+
+/// Converts a path to the format used by URIs
+fn to_uri(path: &str) -> String {
+    utf8_percent_encode(&path.replace('\\', "/"), URI_PATH).to_string()
+}
+
+/// Returns the `file://` URI of a directory, which must end with a slash
+fn directory_uri(path: &Path) -> String {
+    let mut uri = to_uri(&path.display().to_string());
+    if !uri.starts_with('/') {
+        uri.insert(0, '/');
+    }
+    if !uri.ends_with('/') {
+        uri.push('/');
+    }
+    format!("file://{uri}")
+}
+
+/// The root object of a SARIF file.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    /// The absolute URIs that the relative paths of the results are resolved against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_uri_base_ids: Option<BTreeMap<&'static str, SarifArtifactLocation>>,
+    /// Columns are counted in characters
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifReportingDescriptor>,
+}
+
+/// The metadata of a rule
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReportingDescriptor {
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    full_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_configuration: Option<SarifReportingConfiguration>,
+}
+
+impl SarifReportingDescriptor {
+    fn from_category(category: &'static Category) -> Self {
+        let metadata = category
+            .name()
+            .strip_prefix("lint/")
+            .and_then(|name| name.rsplit('/').next())
+            .and_then(|name| match Doc::from_str(name) {
+                Ok(Doc::Rule(metadata)) => Some(metadata),
+                _ => None,
+            });
+
+        let docs = metadata.as_ref().map(|metadata| {
+            metadata
+                .docs
+                .lines()
+                .map(str::trim_start)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_string()
+        });
+
+        Self {
+            id: category.name(),
+            name: metadata.as_ref().map(|metadata| metadata.name),
+            short_description: docs.as_ref().and_then(|docs| {
+                docs.split("\n\n").next().map(|summary| SarifMessage {
+                    text: summary.replace('\n', " "),
+                })
+            }),
+            full_description: docs.map(|text| SarifMessage { text }),
+            help_uri: category.link(),
+            default_configuration: metadata.map(|metadata| SarifReportingConfiguration {
+                enabled: metadata.recommended,
+            }),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifReportingConfiguration {
+    /// Whether the rule is enabled by default, i.e. it's recommended
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+    /// The suppression comments that silence the result. An empty list tells
+    /// that the result isn't suppressed.
+    suppressions: Vec<SarifSuppression>,
+}
+
+#[derive(Serialize)]
+struct SarifSuppression {
+    /// Always `inSource`, since the results are suppressed by comments
+    kind: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+/// A range of the source code. Lines and columns start at 1, and the end column
+/// is exclusive.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn new(source_file: &SourceFile, range: TextRange) -> io::Result<Self> {
+        let start = source_file.location(range.start())?;
+        let end = source_file.location(range.end())?;
+        Ok(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifArtifactContent>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
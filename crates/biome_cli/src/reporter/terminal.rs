@@ -117,7 +117,12 @@ impl<'a> ReporterVisitor for ConsoleReporterVisitor<'a> {
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
             if execution.is_search() {
-                self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
+                // The matches are printed as code frames, and the rewrites as diffs
+                if diagnostic.location().span.is_some() {
+                    self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
+                } else {
+                    self.0.log(markup! {{PrintDiagnostic::simple(diagnostic)}});
+                }
                 continue;
             }
 
@@ -153,7 +158,12 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 
 impl<'a> fmt::Display for SummaryDetail<'a> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
-        if let TraversalMode::Search { .. } = self.0 {
+        if let TraversalMode::Search { write, .. } = self.0 {
+            if *write && self.1 > 0 {
+                fmt.write_markup(markup! {
+                    " Rewrote "{Files(self.1)}"."
+                })?;
+            }
             return Ok(());
         }
 
@@ -97,6 +97,21 @@ const { some } = Astro.props
 ---
 <div>{some}</div>"#;
 
+const ASTRO_FILE_SCRIPT_AND_STYLES: &str = r#"---
+debugger;
+---
+<div>
+  <style>
+    a { colr: red; }
+  </style>
+</div>
+<style>
+b { widht: 1px; }
+</style>
+"#;
+
+const CSS_LINTER_ENABLED: &str = r#"{ "css": { "linter": { "enabled": true } } }"#;
+
 #[test]
 fn format_astro_files() {
     let mut fs = MemoryFileSystem::default();
@@ -720,3 +735,34 @@ fn astro_global_object() {
         result,
     ));
 }
+
+#[test]
+fn lint_astro_script_and_styles() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), CSS_LINTER_ENABLED.as_bytes());
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(
+        astro_file_path.into(),
+        ASTRO_FILE_SCRIPT_AND_STYLES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), astro_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_astro_script_and_styles",
+        fs,
+        console,
+        result,
+    ));
+}
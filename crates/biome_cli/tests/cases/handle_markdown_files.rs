@@ -0,0 +1,205 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MARKDOWN_FILE_UNFORMATTED: &str = r#"Title
+=====
+
+* Some *emphasis* and __strong__ text
+
+| Name | Value |
+|:-|-:|
+| a | 1 |
+
+```js
+const   a = {b:1}
+```
+
+```css
+a{color:red}
+```
+
+```ts
+const a: = 1;
+```
+"#;
+
+const MARKDOWN_FILE_FORMATTED: &str = r#"# Title
+
+- Some _emphasis_ and **strong** text
+
+| Name | Value |
+| :--- | ----: |
+| a    |     1 |
+
+```js
+const a = { b: 1 };
+```
+
+```css
+a {
+	color: red;
+}
+```
+
+```ts
+const a: = 1;
+```
+"#;
+
+const MARKDOWN_FORMATTER_ENABLED: &str = r#"{ "markdown": { "formatter": { "enabled": true } } }"#;
+
+#[test]
+fn format_markdown_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), MARKDOWN_FORMATTER_ENABLED.as_bytes());
+
+    let markdown_file_path = Path::new("file.md");
+    fs.insert(
+        markdown_file_path.into(),
+        MARKDOWN_FILE_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), markdown_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, markdown_file_path, MARKDOWN_FILE_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_markdown_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), MARKDOWN_FORMATTER_ENABLED.as_bytes());
+
+    let markdown_file_path = Path::new("file.md");
+    fs.insert(
+        markdown_file_path.into(),
+        MARKDOWN_FILE_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                markdown_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, markdown_file_path, MARKDOWN_FILE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_format_markdown_code_blocks_with_disabled_formatter() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), MARKDOWN_FORMATTER_ENABLED.as_bytes());
+
+    let markdown_file_path = Path::new("file.md");
+    fs.insert(
+        markdown_file_path.into(),
+        "```css\na{color:red}\n```\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--css-formatter-enabled=false",
+                markdown_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, markdown_file_path, "```css\na{color:red}\n```\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_format_markdown_code_blocks_with_disabled_formatter",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_format_markdown_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let markdown_file_path = Path::new("file.md");
+    fs.insert(
+        markdown_file_path.into(),
+        MARKDOWN_FILE_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                markdown_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, markdown_file_path, MARKDOWN_FILE_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_format_markdown_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -76,6 +76,44 @@ const foo = "";
 </script>
 <div></div>"#;
 
+const SVELTE_FILE_SCRIPTS_AND_STYLES: &str = r#"<style>
+a { colr: red; }
+</style>
+<svelte:head>
+  <script>
+    debugger;
+  </script>
+</svelte:head>
+<script context="module">
+debugger;
+</script>
+<script>
+debugger;
+</script>
+"#;
+
+const SVELTE_FILE_TEMPLATE_ASSIGNMENTS_BEFORE: &str = r#"<script>
+let count = 0;
+let query = "";
+let title = "Counter";
+</script>
+<h1>{title}</h1>
+<button on:click={() => count++}>{count}</button>
+<input bind:value={query} />
+"#;
+
+const SVELTE_FILE_TEMPLATE_ASSIGNMENTS_AFTER: &str = r#"<script>
+let count = 0;
+let query = "";
+const title = "Counter";
+</script>
+<h1>{title}</h1>
+<button on:click={() => count++}>{count}</button>
+<input bind:value={query} />
+"#;
+
+const CSS_LINTER_ENABLED: &str = r#"{ "css": { "linter": { "enabled": true } } }"#;
+
 #[test]
 fn sorts_imports_check() {
     let mut fs = MemoryFileSystem::default();
@@ -575,3 +613,75 @@ fn check_stdin_write_unsafe_successfully() {
         result,
     ));
 }
+
+#[test]
+fn lint_svelte_scripts_and_styles() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), CSS_LINTER_ENABLED.as_bytes());
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_FILE_SCRIPTS_AND_STYLES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_scripts_and_styles",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_template_assignments_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_FILE_TEMPLATE_ASSIGNMENTS_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        svelte_file_path,
+        SVELTE_FILE_TEMPLATE_ASSIGNMENTS_AFTER,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_template_assignments_write",
+        fs,
+        console,
+        result,
+    ));
+}
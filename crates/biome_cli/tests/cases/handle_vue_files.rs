@@ -141,6 +141,50 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_FILE_SCRIPTS_AND_STYLES: &str = r#"<style>
+a { colr: red; }
+</style>
+<template>
+  <Child />
+</template>
+<script>
+import Child from "./Child.vue";
+debugger;
+</script>
+<script setup>
+debugger;
+</script>
+<style>
+b { widht: 1px; }
+</style>
+"#;
+
+const VUE_FILE_TEMPLATE_ASSIGNMENTS_BEFORE: &str = r#"<script setup>
+let count = 0;
+let query = "";
+let title = "Counter";
+</script>
+<template>
+  <h1>{{ title }}</h1>
+  <button @click="count++">{{ count }}</button>
+  <input v-model="query" />
+</template>
+"#;
+
+const VUE_FILE_TEMPLATE_ASSIGNMENTS_AFTER: &str = r#"<script setup>
+let count = 0;
+let query = "";
+const title = "Counter";
+</script>
+<template>
+  <h1>{{ title }}</h1>
+  <button @click="count++">{{ count }}</button>
+  <input v-model="query" />
+</template>
+"#;
+
+const CSS_LINTER_ENABLED: &str = r#"{ "css": { "linter": { "enabled": true } } }"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -913,3 +957,68 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn lint_vue_scripts_and_styles() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), CSS_LINTER_ENABLED.as_bytes());
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_FILE_SCRIPTS_AND_STYLES.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), vue_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_scripts_and_styles",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_template_assignments_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_FILE_TEMPLATE_ASSIGNMENTS_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_TEMPLATE_ASSIGNMENTS_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_template_assignments_write",
+        fs,
+        console,
+        result,
+    ));
+}
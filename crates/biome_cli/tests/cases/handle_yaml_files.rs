@@ -0,0 +1,85 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const YAML_FILE_UNFORMATTED: &str = "key:   value\nnested:\n    child: 1\nlist:\n-   a\n";
+
+const YAML_FILE_FORMATTED: &str = "key: value\nnested:\n  child: 1\nlist:\n  - a\n";
+
+#[test]
+fn does_not_format_yaml_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let yaml_file_path = Path::new("file.yaml");
+    fs.insert(yaml_file_path.into(), YAML_FILE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                yaml_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, yaml_file_path, YAML_FILE_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_format_yaml_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_yaml_files_when_enabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{ "yaml": { "formatter": { "enabled": true } } }"#.as_bytes(),
+    );
+
+    let yaml_file_path = Path::new("file.yaml");
+    fs.insert(yaml_file_path.into(), YAML_FILE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                yaml_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, yaml_file_path, YAML_FILE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files_when_enabled",
+        fs,
+        console,
+        result,
+    ));
+}
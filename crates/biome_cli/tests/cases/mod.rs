@@ -11,15 +11,19 @@ mod editorconfig;
 mod graphql;
 mod handle_astro_files;
 mod handle_css_files;
+mod handle_markdown_files;
 mod handle_svelte_files;
 mod handle_vue_files;
+mod handle_yaml_files;
 mod included_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
 mod protected_files;
+mod reporter_checkstyle;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_sarif;
 mod reporter_summary;
 mod unknown_files;
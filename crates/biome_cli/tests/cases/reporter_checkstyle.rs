@@ -0,0 +1,63 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MAIN_1: &str = r#"import { z} from "z"
+import { z, b , a} from "lodash"
+
+a ==b
+
+debugger 
+
+let f;
+		let f;"#;
+
+const MAIN_2: &str = r#"import { z} from "z"
+import { z, b , a} from "lodash"
+
+a ==b
+
+debugger 
+
+let f;
+		let f;"#;
+
+#[test]
+fn reports_diagnostics_checkstyle_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("main.ts");
+    fs.insert(file_path1.into(), MAIN_1.as_bytes());
+
+    let file_path2 = Path::new("index.ts");
+    fs.insert(file_path2.into(), MAIN_2.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=checkstyle",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diagnostics_checkstyle_lint_command",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,144 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MAIN: &str = r#"debugger;
+a == b;
+"#;
+
+const SUPPRESSED: &str = r#"// biome-ignore lint/suspicious/noDebugger: reason
+debugger;
+a == b;
+"#;
+
+#[test]
+fn reports_diagnostics_sarif_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.ts");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diagnostics_sarif_lint_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_percent_encoded_uris_sarif_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/my file%.ts");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_percent_encoded_uris_sarif_lint_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_suppressed_diagnostics_sarif_lint_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.ts");
+    fs.insert(file_path.into(), SUPPRESSED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_suppressed_diagnostics_sarif_lint_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_no_results_sarif_check_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.ts");
+    fs.insert(file_path.into(), "a === b;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_no_results_sarif_check_command",
+        fs,
+        console,
+        result,
+    ));
+}
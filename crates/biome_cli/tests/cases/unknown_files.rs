@@ -11,7 +11,7 @@ fn should_print_a_diagnostic_unknown_file() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path1 = Path::new("format.yml");
+    let file_path1 = Path::new("format.txt");
     fs.insert(file_path1.into(), "".as_bytes());
 
     let file_path2 = Path::new("format.js");
@@ -52,7 +52,7 @@ fn should_not_print_a_diagnostic_unknown_file_because_ignored() {
         r#"{ "files": { "ignoreUnknown": true } }"#.as_bytes(),
     );
 
-    let file_path1 = Path::new("format.yml");
+    let file_path1 = Path::new("format.txt");
     fs.insert(file_path1.into(), "".as_bytes());
 
     let file_path2 = Path::new("format.js");
@@ -15,9 +15,9 @@ use crate::configs::{
     CONFIG_LINTER_UPGRADE_DIAGNOSTIC, CONFIG_RECOMMENDED_GROUP,
 };
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
-use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
+use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR, UNFORMATTED};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_fs::{ErrorEntry, FileSystem, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -52,6 +52,9 @@ const APPLY_SUGGESTED_AFTER: &str = "const a = 4;\nconsole.log(a);\n";
 const NO_DEBUGGER_BEFORE: &str = "debugger;\n";
 const NO_DEBUGGER_AFTER: &str = "debugger;\n";
 
+const CACHE_PATH: &str = "node_modules/.cache/biome/check.json";
+const WRITE_CACHE_PATH: &str = "node_modules/.cache/biome/check-write.json";
+
 const UPGRADE_SEVERITY_CODE: &str = r#"if(!cond) { exprA(); } else { exprB() }"#;
 
 const NURSERY_UNSTABLE: &str = r#"if(a = b) {}"#;
@@ -3419,3 +3422,345 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+#[test]
+fn cache_stores_results_of_the_analysis() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), NO_DEBUGGER_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache_path = Path::new(CACHE_PATH);
+    let cache = fs
+        .read_file_from_path(&cache_path.to_path_buf())
+        .expect("the cache to be written");
+    let cache: serde_json::Value = serde_json::from_str(&cache).unwrap();
+    let diagnostics = cache["files"]["check.js"]["diagnostics"]
+        .as_array()
+        .expect("the cache to contain the file");
+    assert!(
+        !diagnostics.is_empty(),
+        "the cache doesn't contain the diagnostics of the file: {cache}"
+    );
+
+    // The key of the cache depends on the version of Biome
+    fs.remove(cache_path);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_stores_results_of_the_analysis",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_replays_diagnostics_of_unchanged_files() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), NO_DEBUGGER_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // Drops the diagnostics stored in the cache without changing its key or
+    // the hash of the file: the next run only passes if it replays them
+    // instead of analyzing the file again
+    let cache_path = Path::new(CACHE_PATH);
+    let cache = fs
+        .read_file_from_path(&cache_path.to_path_buf())
+        .expect("the cache to be written");
+    let mut cache: serde_json::Value = serde_json::from_str(&cache).unwrap();
+    cache["files"]["check.js"]["diagnostics"] = serde_json::Value::Array(Vec::new());
+    fs.insert(cache_path.into(), cache.to_string());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(cache_path);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_replays_diagnostics_of_unchanged_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_replays_diffs_of_unformatted_files() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // Changes the diff stored in the cache without changing its key or the
+    // hash of the file: the next run only prints it if it replays the diff
+    // instead of formatting the file again
+    let cache_path = Path::new(CACHE_PATH);
+    let cache = fs
+        .read_file_from_path(&cache_path.to_path_buf())
+        .expect("the cache to be written");
+    let mut cache: serde_json::Value = serde_json::from_str(&cache).unwrap();
+    cache["files"]["check.js"]["diffs"][0]["new"] = serde_json::Value::from("replayed();\n");
+    fs.insert(cache_path.into(), cache.to_string());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(cache_path);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_replays_diffs_of_unformatted_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_stores_unchanged_files_when_writing() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let args = [
+        ("check"),
+        "--write",
+        "--cache",
+        file_path.as_os_str().to_str().unwrap(),
+    ];
+    let cached_files = |fs: &MemoryFileSystem| {
+        let cache = fs
+            .read_file_from_path(&PathBuf::from(WRITE_CACHE_PATH))
+            .expect("the cache to be written");
+        let cache: serde_json::Value = serde_json::from_str(&cache).unwrap();
+        cache["files"].clone()
+    };
+
+    // The file is fixed and formatted, so its results aren't stored: they
+    // don't match its new content
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(args.as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, FIX_AFTER);
+    assert!(
+        cached_files(&fs).get("check.js").is_none(),
+        "the cache contains the changed file"
+    );
+
+    // The file is left untouched, so the next runs can skip it
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(args.as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, FIX_AFTER);
+    assert!(
+        cached_files(&fs).get("check.js").is_some(),
+        "the cache doesn't contain the unchanged file"
+    );
+    assert!(
+        fs.read_file_from_path(&PathBuf::from(CACHE_PATH)).is_err(),
+        "the cache of the command that doesn't write the files was written"
+    );
+
+    fs.remove(Path::new(WRITE_CACHE_PATH));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_stores_unchanged_files_when_writing",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_ignores_results_of_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), NO_DEBUGGER_BEFORE.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--cache",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Path::new(CACHE_PATH));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_ignores_results_of_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_ignores_results_of_changed_plugins() {
+    let mut console = BufferConsole::default();
+
+    // The workspace reads the plugins from the disk, relative to the
+    // configuration file, which isn't in the working directory
+    let root_path = temp_dir().join("check_cache_ignores_results_of_changed_plugins");
+    let config_path = root_path.join("config");
+    let _ = remove_dir_all(&root_path);
+    create_dir_all(&config_path).unwrap();
+
+    let file_path = root_path.join("check.js");
+    let plugin_path = config_path.join("plugin.grit");
+    File::create(&file_path)
+        .unwrap()
+        .write_all(b"bar();\n")
+        .unwrap();
+    File::create(config_path.join("biome.json"))
+        .unwrap()
+        .write_all(br#"{ "plugins": ["plugin.grit"] }"#)
+        .unwrap();
+    File::create(&plugin_path)
+        .unwrap()
+        .write_all(b"`foo()`")
+        .unwrap();
+
+    let args = [
+        "check",
+        "--cache",
+        "--config-path",
+        config_path.to_str().unwrap(),
+        file_path.to_str().unwrap(),
+    ];
+
+    let first_result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut BufferConsole::default(),
+        Args::from(args.as_slice()),
+    );
+
+    File::create(&plugin_path)
+        .unwrap()
+        .write_all(b"`bar()`")
+        .unwrap();
+
+    let second_result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut console,
+        Args::from(args.as_slice()),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(first_result.is_ok(), "run_cli returned {first_result:?}");
+    assert!(second_result.is_err(), "run_cli returned {second_result:?}");
+}
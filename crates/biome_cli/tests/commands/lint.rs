@@ -18,7 +18,7 @@ use crate::configs::{
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_fs::{ErrorEntry, FileSystem, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -1585,6 +1585,66 @@ a == b;",
     ));
 }
 
+#[test]
+fn write_unsafe_removes_unused_suppression_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    const SOURCE: &str = "// biome-ignore lint/suspicious/noDebugger: not needed anymore
+const a = 1;
+// biome-ignore lint/suspicious/noDoubleEquals: test
+a == b;
+";
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("lint"), "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The comment can look unused because its rule is disabled by the
+    // configuration, so its removal is an unsafe fix
+    assert_file_contents(&fs, file_path, SOURCE);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write",
+                "--unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The used suppression comment is kept
+    assert_file_contents(
+        &fs,
+        file_path,
+        "const a = 1;
+// biome-ignore lint/suspicious/noDoubleEquals: test
+a == b;
+",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_unsafe_removes_unused_suppression_comments",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn print_verbose() {
     let mut fs = MemoryFileSystem::default();
@@ -4327,3 +4387,441 @@ fn should_error_if_unchanged_files_only_with_changed_flag() {
         result,
     ));
 }
+
+/// Runs the `lint` command on `check.js`, with the given baseline arguments
+fn lint_with_baseline(
+    fs: &mut MemoryFileSystem,
+    args: &[&str],
+) -> Result<(), biome_cli::CliDiagnostic> {
+    let mut console = BufferConsole::default();
+    let mut arguments = vec!["lint"];
+    arguments.extend_from_slice(args);
+    arguments.push("check.js");
+
+    run_cli(
+        DynRef::Borrowed(fs),
+        &mut console,
+        Args::from(arguments.as_slice()),
+    )
+}
+
+#[test]
+fn write_baseline_records_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--write-baseline=baseline.json"]);
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let baseline = fs
+        .read_file_from_path(&PathBuf::from("baseline.json"))
+        .expect("the baseline to be written");
+    assert!(
+        baseline.contains(r#""hashAlgorithm": "fnv1a64""#),
+        "the baseline doesn't record the hash algorithm: {baseline}"
+    );
+    assert!(
+        baseline.contains(r#""path": "check.js""#),
+        "the baseline doesn't contain the diagnostic: {baseline}"
+    );
+}
+
+#[test]
+fn baseline_ignores_moved_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--write-baseline=baseline.json"]);
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = format!("\n\n    {LINT_ERROR}");
+    fs.insert(Path::new("check.js").into(), content.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--baseline=baseline.json"]);
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn baseline_reports_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--write-baseline=baseline.json"]);
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = format!("{LINT_ERROR}{LINT_ERROR}");
+    fs.insert(Path::new("check.js").into(), content.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--baseline=baseline.json"]);
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn baseline_unsupported_hash_algorithm() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("check.js").into(), LINT_ERROR.as_bytes());
+    fs.insert(
+        Path::new("baseline.json").into(),
+        r#"{ "hashAlgorithm": "sip13", "diagnostics": [] }"#.as_bytes(),
+    );
+
+    let result = lint_with_baseline(&mut fs, &["--baseline=baseline.json"]);
+    assert!(
+        matches!(result, Err(biome_cli::CliDiagnostic::Baseline(_))),
+        "run_cli returned {result:?}"
+    );
+}
+
+#[test]
+fn baseline_missing_file() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("check.js").into(), LINT_ERROR.as_bytes());
+
+    let result = lint_with_baseline(&mut fs, &["--baseline=baseline.json"]);
+    assert!(
+        matches!(result, Err(biome_cli::CliDiagnostic::Baseline(_))),
+        "run_cli returned {result:?}"
+    );
+}
+
+#[test]
+fn should_error_if_baseline_is_used_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    console.in_buffer.push(LINT_ERROR.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=baseline.json",
+                "--stdin-file-path=file.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_baseline_is_used_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_write_baseline_is_used_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    console.in_buffer.push(LINT_ERROR.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=baseline.json",
+                "--stdin-file-path=file.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(
+        fs.read_file_from_path(&PathBuf::from("baseline.json"))
+            .is_err(),
+        "the baseline was written"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_write_baseline_is_used_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_watch_is_used_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--watch", "--stdin-file-path=file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_watch_is_used_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_watch_is_used_with_write_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--watch",
+                "--write-baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_watch_is_used_with_write_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_imports_across_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let exports_path = Path::new("exports.js");
+    fs.insert(exports_path.into(), "export const a = 1;".as_bytes());
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        r#"import { a, b } from "./exports.js";"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--only=nursery/noUnresolvedImports",
+                exports_path.as_os_str().to_str().unwrap(),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_imports_across_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reports_diagnostics_of_plugins() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // The workspace reads the plugins from the disk
+    let root_path = temp_dir().join("lint_reports_diagnostics_of_plugins");
+    let _ = remove_dir_all(&root_path);
+    create_dir_all(&root_path).unwrap();
+
+    let file_path = root_path.join("file.js");
+    File::create(&file_path)
+        .unwrap()
+        .write_all(b"console.log(\"hello\");\nfoo();\n")
+        .unwrap();
+    File::create(root_path.join("biome.json"))
+        .unwrap()
+        .write_all(
+            br#"{
+    "plugins": [
+        "noConsoleLog.grit",
+        { "path": "noFoo.grit", "level": "warn", "message": "Don't call foo." }
+    ]
+}"#,
+        )
+        .unwrap();
+    File::create(root_path.join("noConsoleLog.grit"))
+        .unwrap()
+        .write_all(b"`console.log($message)`")
+        .unwrap();
+    File::create(root_path.join("noFoo.grit"))
+        .unwrap()
+        .write_all(b"`foo()`")
+        .unwrap();
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--config-path",
+                root_path.to_str().unwrap(),
+                file_path.to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_diagnostics_of_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_loads_plugins_of_extended_configurations_once() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // The workspace reads the plugins from the disk
+    let root_path = temp_dir().join("lint_loads_plugins_of_extended_configurations_once");
+    let _ = remove_dir_all(&root_path);
+    create_dir_all(root_path.join("shared")).unwrap();
+
+    let file_path = root_path.join("file.js");
+    File::create(&file_path)
+        .unwrap()
+        .write_all(b"console.log(\"hello\");\nfoo();\n")
+        .unwrap();
+    // The plugin `noFoo.grit` is listed by both configuration files, and the extending one wins
+    File::create(root_path.join("biome.json"))
+        .unwrap()
+        .write_all(
+            br#"{
+    "extends": ["./shared/biome.json"],
+    "plugins": ["./shared/noFoo.grit"]
+}"#,
+        )
+        .unwrap();
+    File::create(root_path.join("shared/biome.json"))
+        .unwrap()
+        .write_all(
+            br#"{
+    "plugins": [
+        "noConsoleLog.grit",
+        { "path": "noFoo.grit", "level": "warn", "message": "Don't call foo." }
+    ]
+}"#,
+        )
+        .unwrap();
+    File::create(root_path.join("shared/noConsoleLog.grit"))
+        .unwrap()
+        .write_all(b"`console.log($message)`")
+        .unwrap();
+    File::create(root_path.join("shared/noFoo.grit"))
+        .unwrap()
+        .write_all(b"`foo()`")
+        .unwrap();
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--config-path",
+                root_path.to_str().unwrap(),
+                file_path.to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_loads_plugins_of_extended_configurations_once",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reports_failures_of_plugins() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // The workspace reads the plugins from the disk
+    let root_path = temp_dir().join("lint_reports_failures_of_plugins");
+    let _ = remove_dir_all(&root_path);
+    create_dir_all(&root_path).unwrap();
+
+    let file_path = root_path.join("file.js");
+    File::create(&file_path)
+        .unwrap()
+        .write_all(b"console.log(\"hello\");\n")
+        .unwrap();
+    File::create(root_path.join("biome.json"))
+        .unwrap()
+        .write_all(br#"{ "plugins": ["broken.grit"] }"#)
+        .unwrap();
+    // `capitalize()` fails at runtime when it's called without an argument
+    File::create(root_path.join("broken.grit"))
+        .unwrap()
+        .write_all(b"`console.log($message)` where { $name = capitalize() }")
+        .unwrap();
+
+    let result = run_cli(
+        DynRef::Owned(Box::new(OsFileSystem::new(root_path.clone()))),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--config-path",
+                root_path.to_str().unwrap(),
+                file_path.to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    remove_dir_all(root_path).unwrap();
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_failures_of_plugins",
+        fs,
+        console,
+        result,
+    ));
+}
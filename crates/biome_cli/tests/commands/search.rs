@@ -0,0 +1,160 @@
+use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const PATTERN: &str = "`console.log($message)` => `logger.info($message)`";
+
+const SOURCE: &str = r#"console.log("hello");
+foo();
+console.log("world");
+"#;
+
+const REWRITTEN: &str = r#"logger.info("hello");
+foo();
+logger.info("world");
+"#;
+
+#[test]
+fn search_write_applies_the_rewrites() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                "--write",
+                PATTERN,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITTEN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_write_applies_the_rewrites",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_prints_the_rewrites_as_a_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), SOURCE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), PATTERN, file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, SOURCE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_prints_the_rewrites_as_a_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_stdin_write_prints_the_rewritten_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(SOURCE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                "--write",
+                "--stdin-file-path",
+                "file.js",
+                PATTERN,
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, REWRITTEN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_stdin_write_prints_the_rewritten_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_stdin_prints_the_code_unchanged() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(SOURCE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), "--stdin-file-path", "file.js", PATTERN].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, SOURCE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_stdin_prints_the_code_unchanged",
+        fs,
+        console,
+        result,
+    ));
+}
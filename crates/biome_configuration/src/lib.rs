@@ -11,9 +11,12 @@ pub mod generated;
 pub mod graphql;
 pub mod javascript;
 pub mod json;
+pub mod markdown;
 pub mod organize_imports;
 mod overrides;
+pub mod plugins;
 pub mod vcs;
+pub mod yaml;
 
 use crate::analyzer::assists::{
     partial_assists_configuration, AssistsConfiguration, PartialAssistsConfiguration,
@@ -54,15 +57,24 @@ pub use json::{
     partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
     PartialJsonFormatter,
 };
+pub use markdown::{
+    partial_markdown_configuration, MarkdownConfiguration, MarkdownFormatter,
+    PartialMarkdownConfiguration, PartialMarkdownFormatter,
+};
 pub use overrides::{
     OverrideAssistsConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
 };
+pub use plugins::{PluginConfiguration, PluginWithOptions, Plugins};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
 use std::path::PathBuf;
 use vcs::VcsClientKind;
+pub use yaml::{
+    partial_yaml_configuration, PartialYamlConfiguration, PartialYamlFormatter, YamlConfiguration,
+    YamlFormatter,
+};
 
 pub const VERSION: &str = match option_env!("BIOME_VERSION") {
     Some(version) => version,
@@ -128,6 +140,14 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_graphql_configuration), optional))]
     pub graphql: GraphqlConfiguration,
 
+    /// Specific configuration for the YAML language
+    #[partial(type, bpaf(external(partial_yaml_configuration), optional))]
+    pub yaml: YamlConfiguration,
+
+    /// Specific configuration for the Markdown language
+    #[partial(type, bpaf(external(partial_markdown_configuration), optional))]
+    pub markdown: MarkdownConfiguration,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
@@ -135,6 +155,14 @@ pub struct Configuration {
     /// Specific configuration for assists
     #[partial(type, bpaf(external(partial_assists_configuration), optional))]
     pub assists: AssistsConfiguration,
+
+    /// A list of paths to GritQL plugins, whose matches are reported as lint diagnostics.
+    ///
+    /// The diagnostics of all the plugins have the same `plugin` category, so they can't be
+    /// selected per plugin. They are only suppressed by the comments that suppress all the
+    /// lint rules, such as `// biome-ignore lint: <explanation>`.
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    pub plugins: Plugins,
 }
 
 impl PartialConfiguration {
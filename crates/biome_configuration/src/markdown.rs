@@ -0,0 +1,74 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct MarkdownConfiguration {
+    /// Markdown formatter options
+    #[partial(type, bpaf(external(partial_markdown_formatter), optional))]
+    pub formatter: MarkdownFormatter,
+}
+
+/// Options that changes how the Markdown formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct MarkdownFormatter {
+    /// Control the formatter for Markdown files.
+    #[partial(bpaf(long("markdown-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: Option<bool>,
+
+    /// The size of the indentation applied to Markdown files. Default to 2.
+    #[partial(bpaf(long("markdown-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to Markdown files.
+    #[partial(bpaf(
+        long("markdown-formatter-line-ending"),
+        argument("lf|crlf|cr"),
+        optional
+    ))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to Markdown files. Defaults to 80.
+    #[partial(bpaf(long("markdown-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+}
+
+impl Default for MarkdownFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+        }
+    }
+}
+
+impl PartialMarkdownFormatter {
+    pub fn get_formatter_configuration(&self) -> MarkdownFormatter {
+        MarkdownFormatter {
+            enabled: self.enabled,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+        }
+    }
+}
+
+#[test]
+fn default_markdown_formatter() {
+    let markdown_configuration = MarkdownFormatter::default();
+
+    assert_eq!(markdown_configuration.enabled, Some(false));
+    assert_eq!(markdown_configuration.indent_width, None);
+    assert_eq!(markdown_configuration.line_ending, None);
+    assert_eq!(markdown_configuration.line_width, None);
+}
@@ -0,0 +1,147 @@
+use crate::RulePlainConfiguration;
+use biome_deserialize::{
+    Deserializable, DeserializableType, DeserializableValue, DeserializationDiagnostic, Merge,
+};
+use biome_deserialize_macros::Deserializable;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Plugins(pub Vec<PluginConfiguration>);
+
+impl Plugins {
+    pub fn iter(&self) -> impl Iterator<Item = &PluginConfiguration> {
+        self.0.iter()
+    }
+
+    /// Returns the plugins with their paths relative to `base_path` instead of the directory of
+    /// the configuration file that lists them.
+    ///
+    /// It's used for the configuration files listed in `extends`, whose plugins are resolved from
+    /// the directory of the configuration file that extends them.
+    pub fn with_base_path(self, base_path: &Path) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|plugin| plugin.with_base_path(base_path))
+                .collect(),
+        )
+    }
+}
+
+impl Merge for Plugins {
+    /// Appends the plugins of `other`. A plugin of `other` whose path resolves to the path of a
+    /// plugin of `self` replaces it, so that a plugin isn't loaded twice.
+    fn merge_with(&mut self, other: Self) {
+        for plugin in other.0 {
+            let path = normalize_path(plugin.path());
+            match self
+                .0
+                .iter_mut()
+                .find(|existing| normalize_path(existing.path()) == path)
+            {
+                Some(existing) => *existing = plugin,
+                None => self.0.push(plugin),
+            }
+        }
+    }
+}
+
+/// Returns the path without its `.` components and with its `..` components applied, so that
+/// the paths of the same plugin compare equal.
+fn normalize_path(path: &str) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in Path::new(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
+pub enum PluginConfiguration {
+    /// The path of the plugin, relative to the configuration file
+    Path(String),
+    /// The path of the plugin, along with the options of its diagnostics
+    WithOptions(PluginWithOptions),
+}
+
+impl PluginConfiguration {
+    /// The path of the plugin, relative to the configuration file
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Path(path) => path,
+            Self::WithOptions(options) => &options.path,
+        }
+    }
+
+    /// Returns the plugin with its path relative to `base_path`
+    fn with_base_path(self, base_path: &Path) -> Self {
+        let path = base_path.join(self.path()).to_string_lossy().into_owned();
+        match self {
+            Self::Path(_) => Self::Path(path),
+            Self::WithOptions(options) => Self::WithOptions(PluginWithOptions { path, ..options }),
+        }
+    }
+
+    /// The severity of the diagnostics emitted by the plugin, if configured
+    pub fn level(&self) -> Option<RulePlainConfiguration> {
+        match self {
+            Self::Path(_) => None,
+            Self::WithOptions(options) => options.level,
+        }
+    }
+
+    /// The message of the diagnostics emitted by the plugin, if configured
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Path(_) => None,
+            Self::WithOptions(options) => options.message.as_deref(),
+        }
+    }
+}
+
+impl Deserializable for PluginConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Deserializable::deserialize(value, name, diagnostics).map(Self::Path)
+        } else {
+            Deserializable::deserialize(value, name, diagnostics).map(Self::WithOptions)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginWithOptions {
+    /// The path of the plugin, relative to the configuration file
+    #[deserializable(required)]
+    pub path: String,
+
+    /// The severity of the diagnostics emitted by the plugin. Defaults to `error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<RulePlainConfiguration>,
+
+    /// A message that replaces the default message of the diagnostics emitted by the plugin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
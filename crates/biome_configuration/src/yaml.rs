@@ -0,0 +1,81 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{BracketSpacing, IndentWidth, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlConfiguration {
+    /// YAML formatter options
+    #[partial(type, bpaf(external(partial_yaml_formatter), optional))]
+    pub formatter: YamlFormatter,
+}
+
+/// Options that changes how the YAML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlFormatter {
+    /// Control the formatter for YAML files.
+    #[partial(bpaf(long("yaml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: Option<bool>,
+
+    /// The size of the indentation applied to YAML files. Default to 2.
+    #[partial(bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to YAML files.
+    #[partial(bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[partial(bpaf(long("yaml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// Whether to insert spaces around brackets in flow mappings. Defaults to true.
+    #[partial(bpaf(
+        long("yaml-formatter-bracket-spacing"),
+        argument("true|false"),
+        optional
+    ))]
+    pub bracket_spacing: Option<BracketSpacing>,
+}
+
+impl Default for YamlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            bracket_spacing: Default::default(),
+        }
+    }
+}
+
+impl PartialYamlFormatter {
+    pub fn get_formatter_configuration(&self) -> YamlFormatter {
+        YamlFormatter {
+            enabled: self.enabled,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+            bracket_spacing: self.bracket_spacing,
+        }
+    }
+}
+
+#[test]
+fn default_yaml_formatter() {
+    let yaml_configuration = YamlFormatter::default();
+
+    assert_eq!(yaml_configuration.enabled, Some(false));
+    assert_eq!(yaml_configuration.indent_width, None);
+    assert_eq!(yaml_configuration.line_ending, None);
+    assert_eq!(yaml_configuration.line_width, None);
+    assert_eq!(yaml_configuration.bracket_spacing, None);
+}
@@ -773,6 +773,83 @@ pub const FUNCTION_KEYWORDS: [&str; 685] = [
     "xywh",
 ];
 
+/// List of the global functions of Sass that aren't CSS value functions.
+/// See the [built-in modules](https://sass-lang.com/documentation/modules/) of Sass.
+pub const SASS_FUNCTION_KEYWORDS: [&str; 72] = [
+    "adjust-color",
+    "adjust-hue",
+    "alpha",
+    "append",
+    "blue",
+    "call",
+    "ceil",
+    "change-color",
+    "comparable",
+    "complement",
+    "content-exists",
+    "darken",
+    "desaturate",
+    "fade-in",
+    "fade-out",
+    "feature-exists",
+    "floor",
+    "function-exists",
+    "get-function",
+    "global-variable-exists",
+    "green",
+    "hue",
+    "ie-hex-str",
+    "if",
+    "index",
+    "inspect",
+    "is-bracketed",
+    "is-superselector",
+    "join",
+    "keywords",
+    "length",
+    "lighten",
+    "lightness",
+    "list-separator",
+    "map-get",
+    "map-has-key",
+    "map-keys",
+    "map-merge",
+    "map-remove",
+    "map-values",
+    "mix",
+    "mixin-exists",
+    "nth",
+    "opacify",
+    "percentage",
+    "quote",
+    "random",
+    "red",
+    "saturation",
+    "scale-color",
+    "selector-append",
+    "selector-extend",
+    "selector-nest",
+    "selector-parse",
+    "selector-replace",
+    "selector-unify",
+    "set-nth",
+    "simple-selectors",
+    "str-index",
+    "str-insert",
+    "str-length",
+    "str-slice",
+    "to-lower-case",
+    "to-upper-case",
+    "transparentize",
+    "type-of",
+    "unique-id",
+    "unit",
+    "unitless",
+    "unquote",
+    "variable-exists",
+    "zip",
+];
+
 // These are the ones that can have single-colon notation
 pub const LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS: [&str; 4] =
     ["before", "after", "first-line", "first-letter"];
@@ -5735,7 +5812,7 @@ mod tests {
         KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES,
         LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES, MATH_ML_TAGS, MEDIA_FEATURE_NAMES,
         RESET_TO_INITIAL_PROPERTIES_BY_BORDER, RESET_TO_INITIAL_PROPERTIES_BY_FONT,
-        SHORTHAND_PROPERTIES, SVG_TAGS,
+        SASS_FUNCTION_KEYWORDS, SHORTHAND_PROPERTIES, SVG_TAGS,
     };
 
     #[test]
@@ -5752,6 +5829,13 @@ mod tests {
         assert!(!has_duplicates);
     }
 
+    #[test]
+    fn test_sass_function_keywords_order() {
+        for items in SASS_FUNCTION_KEYWORDS.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
     #[test]
     fn test_known_properties_order() {
         for items in KNOWN_PROPERTIES.windows(2) {
@@ -10,9 +10,9 @@ pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    MetadataRegistry, RuleCategory, RuleRegistry, SuppressionKind,
 };
-use biome_css_syntax::CssLanguage;
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::{category, Error};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
@@ -31,13 +31,14 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    file_source: CssFileSource,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, file_source, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +52,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    file_source: CssFileSource,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -99,7 +101,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -118,6 +120,12 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if filter.categories.contains(RuleCategory::Lint) {
+        analyzer.add_plugins(options.plugins().clone());
+    }
+
+    services.insert_service(file_source);
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -135,7 +143,7 @@ mod tests {
     use biome_console::fmt::{Formatter, Termcolor};
     use biome_console::{markup, Markup};
     use biome_css_parser::{parse_css, CssParserOptions};
-    use biome_css_syntax::TextRange;
+    use biome_css_syntax::{CssFileSource, TextRange};
     use biome_diagnostics::termcolor::NoColor;
     use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use std::slice;
@@ -186,6 +194,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            CssFileSource::css(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,16 +2,19 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::CssFunction;
+use biome_css_syntax::{CssFileSource, CssFunction, ScssQualifiedName};
 use biome_rowan::{AstNode, TextRange};
 
-use crate::utils::{is_custom_function, is_function_keyword};
+use crate::utils::{is_custom_function, is_function_keyword, is_sass_function_keyword};
 
 declare_lint_rule! {
     /// Disallow unknown CSS value functions.
     ///
     /// This rule ignores double-dashed custom functions, e.g. `--custom-function()`.
     ///
+    /// In SCSS files, the global functions of Sass, e.g. `darken()`, and the functions of the modules
+    /// loaded with `@use`, e.g. `math.div()`, are also ignored.
+    ///
     /// Data sources of known CSS value functions are:
     /// - MDN reference on [CSS value functions](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Functions)
     /// - MDN reference on [CSS reference](https://developer.mozilla.org/en-US/docs/Web/CSS/Reference)
@@ -65,6 +68,16 @@ impl Rule for NoUnknownFunction {
             return None;
         }
 
+        // The members of the Sass modules, e.g. `math.div()`, are defined by the modules
+        if node.parent::<ScssQualifiedName>().is_some() {
+            return None;
+        }
+
+        if ctx.source_type::<CssFileSource>().is_scss() && is_sass_function_keyword(&function_name)
+        {
+            return None;
+        }
+
         Some(NoUnknownFunctionState {
             function_name,
             span: node.name().ok()?.range(),
@@ -2,7 +2,7 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::CssGenericProperty;
+use biome_css_syntax::{CssDeclaration, CssGenericProperty};
 use biome_rowan::{AstNode, TextRange};
 use biome_string_case::StrOnlyExtension;
 
@@ -20,6 +20,9 @@ declare_lint_rule! {
     /// - custom variables e.g. `--custom-property`
     /// - vendor-prefixed properties (e.g., `-moz-align-self,` `-webkit-align-self`)
     ///
+    /// In SCSS files, the names of nested properties are combined with the names of their declarations,
+    /// so `font: { family: serif; }` is checked as `font-family`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -73,7 +76,14 @@ impl Rule for NoUnknownProperty {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
-        let property_name = node.name().ok()?.text();
+        let mut property_name = node.name().ok()?.text();
+        // Take into account the names of the enclosing SCSS nested properties
+        if let Some(prefix) = node
+            .parent::<CssDeclaration>()
+            .and_then(|declaration| declaration.scss_nesting_prefix())
+        {
+            property_name.insert_str(0, &prefix);
+        }
         let property_name_lower = property_name.to_lowercase_cow();
         if !property_name_lower.starts_with("--")
             // Ignore `composes` property.
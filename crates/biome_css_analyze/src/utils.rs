@@ -10,8 +10,9 @@ use crate::keywords::{
     LOGICAL_COMBINATIONS_PSEUDO_CLASSES, LONGHAND_SUB_PROPERTIES_OF_SHORTHAND_PROPERTIES,
     MATH_ML_TAGS, MEDIA_FEATURE_NAMES, OTHER_PSEUDO_CLASSES, OTHER_PSEUDO_ELEMENTS,
     RESET_TO_INITIAL_PROPERTIES_BY_BORDER, RESET_TO_INITIAL_PROPERTIES_BY_FONT,
-    RESOURCE_STATE_PSEUDO_CLASSES, SHADOW_TREE_PSEUDO_ELEMENTS, SHORTHAND_PROPERTIES, SVG_TAGS,
-    SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXES, VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
+    RESOURCE_STATE_PSEUDO_CLASSES, SASS_FUNCTION_KEYWORDS, SHADOW_TREE_PSEUDO_ELEMENTS,
+    SHORTHAND_PROPERTIES, SVG_TAGS, SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXES,
+    VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
 };
 use biome_css_syntax::{AnyCssGenericComponentValue, AnyCssValue, CssGenericComponentValueList};
 use biome_rowan::{AstNode, SyntaxNodeCast};
@@ -116,6 +117,13 @@ pub fn is_function_keyword(value: &str) -> bool {
         .is_ok()
 }
 
+/// Check if the value is a global function of Sass.
+pub fn is_sass_function_keyword(value: &str) -> bool {
+    SASS_FUNCTION_KEYWORDS
+        .binary_search(&value.to_lowercase_cow().as_ref())
+        .is_ok()
+}
+
 /// Check if the value is a double-dashed custom function.
 pub fn is_custom_function(value: &str) -> bool {
     value.starts_with("--")
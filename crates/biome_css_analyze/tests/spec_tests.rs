@@ -12,7 +12,7 @@ use biome_test_utils::{
 use std::ops::Deref;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{css,scss,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{css,json,jsonc}", crate::run_suppression_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
@@ -50,6 +50,8 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             css_modules: true,
             ..CssParserOptions::default()
         }
+    } else if file_name.ends_with(".scss") {
+        CssParserOptions::default().allow_scss()
     } else {
         CssParserOptions::default()
     };
@@ -117,7 +119,7 @@ pub(crate) fn analyze_and_snap(
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, source_type, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
@@ -157,7 +157,7 @@ pub fn css_charset_at_rule(
         ],
     ))
 }
-pub fn css_class_selector(dot_token: SyntaxToken, name: CssCustomIdentifier) -> CssClassSelector {
+pub fn css_class_selector(dot_token: SyntaxToken, name: AnyCssSelectorName) -> CssClassSelector {
     CssClassSelector::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_CLASS_SELECTOR,
         [
@@ -737,7 +737,7 @@ pub fn css_generic_property(
         ],
     ))
 }
-pub fn css_id_selector(hash_token: SyntaxToken, name: CssCustomIdentifier) -> CssIdSelector {
+pub fn css_id_selector(hash_token: SyntaxToken, name: AnyCssSelectorName) -> CssIdSelector {
     CssIdSelector::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_ID_SELECTOR,
         [
@@ -2281,6 +2281,549 @@ pub fn css_value_at_rule_named_import_specifier(
         ],
     ))
 }
+pub fn scss_argument(value: CssComponentValueList) -> ScssArgumentBuilder {
+    ScssArgumentBuilder { value, name: None }
+}
+pub struct ScssArgumentBuilder {
+    value: CssComponentValueList,
+    name: Option<ScssArgumentName>,
+}
+impl ScssArgumentBuilder {
+    pub fn with_name(mut self, name: ScssArgumentName) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn build(self) -> ScssArgument {
+        ScssArgument::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_ARGUMENT,
+            [
+                self.name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_argument_name(name: ScssIdentifier, colon_token: SyntaxToken) -> ScssArgumentName {
+    ScssArgumentName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_NAME,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+        ],
+    ))
+}
+pub fn scss_arguments(
+    l_paren_token: SyntaxToken,
+    items: ScssArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssArguments {
+    ScssArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_content_at_rule(content_token: SyntaxToken) -> ScssContentAtRuleBuilder {
+    ScssContentAtRuleBuilder {
+        content_token,
+        semicolon_token: None,
+    }
+}
+pub struct ScssContentAtRuleBuilder {
+    content_token: SyntaxToken,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssContentAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssContentAtRule {
+        ScssContentAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_CONTENT_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.content_token)),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_extend_at_rule(
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+) -> ScssExtendAtRuleBuilder {
+    ScssExtendAtRuleBuilder {
+        extend_token,
+        selectors,
+        semicolon_token: None,
+    }
+}
+pub struct ScssExtendAtRuleBuilder {
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssExtendAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssExtendAtRule {
+        ScssExtendAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_EXTEND_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.extend_token)),
+                Some(SyntaxElement::Node(self.selectors.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_forward_as_clause(
+    as_token: SyntaxToken,
+    prefix: CssIdentifier,
+    star_token: SyntaxToken,
+) -> ScssForwardAsClause {
+    ScssForwardAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(prefix.into_syntax())),
+            Some(SyntaxElement::Token(star_token)),
+        ],
+    ))
+}
+pub fn scss_forward_at_rule(
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssForwardAtRuleBuilder {
+    ScssForwardAtRuleBuilder {
+        forward_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        visibility: None,
+        configuration: None,
+    }
+}
+pub struct ScssForwardAtRuleBuilder {
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssForwardAsClause>,
+    visibility: Option<ScssForwardVisibilityClause>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssForwardAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssForwardAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_visibility(mut self, visibility: ScssForwardVisibilityClause) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssForwardAtRule {
+        ScssForwardAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_FORWARD_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.forward_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.visibility
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_forward_visibility_clause(
+    modifier_token: SyntaxToken,
+    members: ScssForwardMemberList,
+) -> ScssForwardVisibilityClause {
+    ScssForwardVisibilityClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_VISIBILITY_CLAUSE,
+        [
+            Some(SyntaxElement::Token(modifier_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_include_at_rule(
+    include_token: SyntaxToken,
+    name: CssIdentifier,
+) -> ScssIncludeAtRuleBuilder {
+    ScssIncludeAtRuleBuilder {
+        include_token,
+        name,
+        arguments: None,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssIncludeAtRuleBuilder {
+    include_token: SyntaxToken,
+    name: CssIdentifier,
+    arguments: Option<ScssArguments>,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssIncludeAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssIncludeAtRule {
+        ScssIncludeAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_INCLUDE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.include_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_interpolated_identifier(
+    items: ScssInterpolatedIdentifierItemList,
+) -> ScssInterpolatedIdentifier {
+    ScssInterpolatedIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn scss_interpolation(
+    hash_l_curly_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_curly_token: SyntaxToken,
+) -> ScssInterpolation {
+    ScssInterpolation::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATION,
+        [
+            Some(SyntaxElement::Token(hash_l_curly_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression(
+    l_paren_token: SyntaxToken,
+    pairs: ScssMapExpressionPairList,
+    r_paren_token: SyntaxToken,
+) -> ScssMapExpression {
+    ScssMapExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(pairs.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression_pair(
+    key: AnyCssValue,
+    colon_token: SyntaxToken,
+    value: CssComponentValueList,
+) -> ScssMapExpressionPair {
+    ScssMapExpressionPair::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR,
+        [
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_mixin_at_rule(
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssMixinAtRuleBuilder {
+    ScssMixinAtRuleBuilder {
+        mixin_token,
+        name,
+        block,
+        parameters: None,
+    }
+}
+pub struct ScssMixinAtRuleBuilder {
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+    parameters: Option<ScssParameters>,
+}
+impl ScssMixinAtRuleBuilder {
+    pub fn with_parameters(mut self, parameters: ScssParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> ScssMixinAtRule {
+        ScssMixinAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_MIXIN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.mixin_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_module_configuration(
+    with_token: SyntaxToken,
+    value: ScssMapExpression,
+) -> ScssModuleConfiguration {
+    ScssModuleConfiguration::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MODULE_CONFIGURATION,
+        [
+            Some(SyntaxElement::Token(with_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_nesting_declaration(
+    name: CssIdentifier,
+    colon_token: SyntaxToken,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssNestingDeclaration {
+    ScssNestingDeclaration::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_NESTING_DECLARATION,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameter(name: ScssIdentifier) -> ScssParameterBuilder {
+    ScssParameterBuilder {
+        name,
+        default_value: None,
+    }
+}
+pub struct ScssParameterBuilder {
+    name: ScssIdentifier,
+    default_value: Option<ScssParameterDefaultValue>,
+}
+impl ScssParameterBuilder {
+    pub fn with_default_value(mut self, default_value: ScssParameterDefaultValue) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn build(self) -> ScssParameter {
+        ScssParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: CssComponentValueList,
+) -> ScssParameterDefaultValue {
+    ScssParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameters(
+    l_paren_token: SyntaxToken,
+    items: ScssParameterList,
+    r_paren_token: SyntaxToken,
+) -> ScssParameters {
+    ScssParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_placeholder_selector(
+    percent_token: SyntaxToken,
+    name: AnyCssSelectorName,
+) -> ScssPlaceholderSelector {
+    ScssPlaceholderSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR,
+        [
+            Some(SyntaxElement::Token(percent_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_qualified_name(
+    module: CssIdentifier,
+    dot_token: SyntaxToken,
+    member: AnyScssModuleMember,
+) -> ScssQualifiedName {
+    ScssQualifiedName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_QUALIFIED_NAME,
+        [
+            Some(SyntaxElement::Node(module.into_syntax())),
+            Some(SyntaxElement::Token(dot_token)),
+            Some(SyntaxElement::Node(member.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_all_namespace(star_token: SyntaxToken) -> ScssUseAllNamespace {
+    ScssUseAllNamespace::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_ALL_NAMESPACE,
+        [Some(SyntaxElement::Token(star_token))],
+    ))
+}
+pub fn scss_use_as_clause(
+    as_token: SyntaxToken,
+    namespace: AnyScssUseNamespace,
+) -> ScssUseAsClause {
+    ScssUseAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(namespace.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_at_rule(
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssUseAtRuleBuilder {
+    ScssUseAtRuleBuilder {
+        use_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        configuration: None,
+    }
+}
+pub struct ScssUseAtRuleBuilder {
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssUseAsClause>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssUseAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssUseAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssUseAtRule {
+        ScssUseAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_USE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.use_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
 pub fn css_bracketed_value_list<I>(items: I) -> CssBracketedValueList
 where
     I: IntoIterator<Item = AnyCssCustomIdentifier>,
@@ -2770,6 +3313,114 @@ where
         }),
     ))
 }
+pub fn scss_argument_list<I, S>(items: I, separators: S) -> ScssArgumentList
+where
+    I: IntoIterator<Item = ScssArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_forward_member_list<I, S>(items: I, separators: S) -> ScssForwardMemberList
+where
+    I: IntoIterator<Item = AnyScssForwardMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssForwardMemberList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_interpolated_identifier_item_list<I>(items: I) -> ScssInterpolatedIdentifierItemList
+where
+    I: IntoIterator<Item = AnyScssInterpolatedIdentifierItem>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssInterpolatedIdentifierItemList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn scss_map_expression_pair_list<I, S>(items: I, separators: S) -> ScssMapExpressionPairList
+where
+    I: IntoIterator<Item = ScssMapExpressionPair>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssMapExpressionPairList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_parameter_list<I, S>(items: I, separators: S) -> ScssParameterList
+where
+    I: IntoIterator<Item = ScssParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bogus<I>(slots: I) -> CssBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
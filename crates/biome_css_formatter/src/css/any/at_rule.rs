@@ -30,6 +30,12 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssUnknownBlockAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssUnknownValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssContentAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssExtendAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssForwardAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIncludeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssMixinAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssUseAtRule(node) => node.format().fmt(f),
         }
     }
 }
@@ -20,6 +20,10 @@ impl FormatRule<AnyCssValue> for FormatAnyCssValue {
             AnyCssValue::CssRatio(node) => node.format().fmt(f),
             AnyCssValue::CssString(node) => node.format().fmt(f),
             AnyCssValue::CssUnicodeRange(node) => node.format().fmt(f),
+            AnyCssValue::ScssIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssInterpolatedIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssMapExpression(node) => node.format().fmt(f),
+            AnyCssValue::ScssQualifiedName(node) => node.format().fmt(f),
         }
     }
 }
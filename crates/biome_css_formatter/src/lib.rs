@@ -4,6 +4,7 @@ mod css;
 mod cst;
 mod generated;
 mod prelude;
+mod scss;
 mod separated;
 mod utils;
 
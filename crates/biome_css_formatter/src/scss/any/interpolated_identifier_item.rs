@@ -0,0 +1,20 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssInterpolatedIdentifierItem;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssInterpolatedIdentifierItem;
+impl FormatRule<AnyScssInterpolatedIdentifierItem> for FormatAnyScssInterpolatedIdentifierItem {
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyScssInterpolatedIdentifierItem,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(node) => node.format().fmt(f),
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(node) => node.format().fmt(f),
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssArguments, ScssArgumentsFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssArguments;
+impl FormatNodeRule<ScssArguments> for FormatScssArguments {
+    fn fmt_fields(&self, node: &ScssArguments, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssArgumentsFields {
+            l_paren_token,
+            items,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&items.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForwardVisibilityClause, ScssForwardVisibilityClauseFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForwardVisibilityClause;
+impl FormatNodeRule<ScssForwardVisibilityClause> for FormatScssForwardVisibilityClause {
+    fn fmt_fields(
+        &self,
+        node: &ScssForwardVisibilityClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let ScssForwardVisibilityClauseFields { modifier, members } = node.as_fields();
+
+        write!(
+            f,
+            [
+                modifier.format(),
+                space(),
+                group(&indent(&members.format()))
+            ]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssInterpolatedIdentifierItemList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssInterpolatedIdentifierItemList;
+impl FormatRule<ScssInterpolatedIdentifierItemList> for FormatScssInterpolatedIdentifierItemList {
+    type Context = CssFormatContext;
+    fn fmt(
+        &self,
+        node: &ScssInterpolatedIdentifierItemList,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssMapExpressionPairList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssMapExpressionPairList;
+impl FormatRule<ScssMapExpressionPairList> for FormatScssMapExpressionPairList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssMapExpressionPairList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod block_like;
 pub(crate) mod component_value_list;
+pub(crate) mod scss_control;
 pub(crate) mod string_utils;
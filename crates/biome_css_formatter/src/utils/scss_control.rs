@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use biome_css_syntax::{AnyCssAtRule, CssAtRule, CssLanguage, CssSyntaxNode};
+use biome_formatter::{write, FormatWithRule};
+use biome_rowan::AstNode;
+
+/// Whether the node is the SCSS control at-rule `@<name>`, e.g. `@if` or
+/// `@else`, which is parsed as an unknown block at-rule
+fn is_scss_control_at_rule(node: &CssSyntaxNode, name: &str) -> bool {
+    CssAtRule::cast_ref(node)
+        .and_then(|at_rule| match at_rule.rule().ok()? {
+            AnyCssAtRule::CssUnknownBlockAtRule(rule) => rule.name().ok()?.value_token().ok(),
+            _ => None,
+        })
+        .is_some_and(|token| token.text_trimmed() == name)
+}
+
+/// Joins the items of a list with hard lines, except the `@else` at-rules
+/// that follow an `@if` at-rule, which stay on the line of the closing brace
+/// of the previous at-rule: `} @else {`
+pub(crate) fn join_items_with_hardline<Item, Items>(
+    items: Items,
+    f: &mut CssFormatter,
+) -> FormatResult<()>
+where
+    Item: AstNode<Language = CssLanguage> + AsFormat<CssFormatContext>,
+    for<'a> Item::Format<'a>: FormatWithRule<CssFormatContext, Item = Item>,
+    Items: IntoIterator<Item = Item>,
+{
+    // This is one of the few cases where we _do_ want to respect empty
+    // lines from the input, so we can use `join_nodes_with_hardline`.
+    let mut join = f.join_nodes_with_hardline();
+
+    let mut items = items.into_iter().peekable();
+    while let Some(item) = items.next() {
+        let mut else_items = Vec::new();
+        if is_scss_control_at_rule(item.syntax(), "if") {
+            while let Some(else_item) =
+                items.next_if(|next| is_scss_control_at_rule(next.syntax(), "else"))
+            {
+                else_items.push(else_item);
+            }
+        }
+
+        join.entry(
+            item.syntax(),
+            &format_with(|f| {
+                write!(f, [format_or_verbatim(item.format())])?;
+                for else_item in &else_items {
+                    write!(f, [space(), format_or_verbatim(else_item.format())])?;
+                }
+                Ok(())
+            }),
+        );
+    }
+
+    join.finish()
+}
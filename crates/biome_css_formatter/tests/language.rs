@@ -12,7 +12,13 @@ use biome_service::{
 
 #[derive(Default)]
 pub struct CssTestFormatLanguage {
-    _source_type: CssFileSource,
+    source_type: CssFileSource,
+}
+
+impl CssTestFormatLanguage {
+    pub fn new(source_type: CssFileSource) -> Self {
+        CssTestFormatLanguage { source_type }
+    }
 }
 
 impl TestFormatLanguage for CssTestFormatLanguage {
@@ -21,7 +27,7 @@ impl TestFormatLanguage for CssTestFormatLanguage {
     type FormatLanguage = CssFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let options = CssParserOptions::default()
+        let options = CssParserOptions::from(&self.source_type)
             .allow_wrong_line_comments()
             .allow_css_modules();
 
@@ -1,5 +1,6 @@
 use biome_configuration::{PartialConfiguration, PartialCssConfiguration, PartialCssFormatter};
 use biome_css_formatter::{context::CssFormatOptions, CssFormatLanguage};
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_service::workspace::UpdateSettingsParams;
 use std::path::Path;
@@ -41,6 +42,7 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         vcs_base_path: None,
         gitignore_matches: vec![],
         workspace_directory: None,
+        configuration_directory: None,
     };
 
     let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
@@ -48,8 +50,10 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
+    let source_type = CssFileSource::try_from(test_file.input_file().as_path()).unwrap_or_default();
+
     let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(
         test_file,
@@ -5,4 +5,8 @@ mod formatter {
     mod css_module {
         tests_macros::gen_tests! {"tests/specs/css/**/*.css", crate::spec_test::run, ""}
     }
+
+    mod scss_module {
+        tests_macros::gen_tests! {"tests/specs/scss/**/*.scss", crate::spec_test::run, ""}
+    }
 }
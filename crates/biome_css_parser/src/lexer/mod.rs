@@ -324,6 +324,7 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            DOL if self.options.is_scss_enabled() => self.consume_byte(T![$]),
             UNI if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
                 self.consume_metavariable(GRIT_METAVARIABLE)
             }
@@ -334,6 +335,10 @@ impl<'src> CssLexer<'src> {
             COL => self.consume_col(),
             AT_ => self.consume_byte(T![@]),
             SEM => self.consume_byte(T![;]),
+            HAS if self.options.is_scss_enabled() && self.peek_byte() == Some(b'{') => {
+                self.advance(1);
+                self.consume_byte(T!["#{"])
+            }
             HAS => self.consume_byte(T![#]),
             PNO => self.consume_byte(T!['(']),
             PNC => self.consume_byte(T![')']),
@@ -922,6 +927,16 @@ impl<'src> CssLexer<'src> {
             b"value" => VALUE_KW,
             b"as" => AS_KW,
             b"composes" => COMPOSES_KW,
+            // SCSS keywords
+            b"mixin" if self.options.is_scss_enabled() => MIXIN_KW,
+            b"include" if self.options.is_scss_enabled() => INCLUDE_KW,
+            b"content" if self.options.is_scss_enabled() => CONTENT_KW,
+            b"extend" if self.options.is_scss_enabled() => EXTEND_KW,
+            b"use" if self.options.is_scss_enabled() => USE_KW,
+            b"forward" if self.options.is_scss_enabled() => FORWARD_KW,
+            b"with" if self.options.is_scss_enabled() => WITH_KW,
+            b"show" if self.options.is_scss_enabled() => SHOW_KW,
+            b"hide" if self.options.is_scss_enabled() => HIDE_KW,
             _ => IDENT,
         }
     }
@@ -1093,7 +1108,9 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.options.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.options.allow_wrong_line_comments || self.options.is_scss_enabled() =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
@@ -1216,6 +1233,14 @@ impl<'src> CssLexer<'src> {
             return self.consume_identifier();
         }
 
+        // In SCSS, a `-` directly followed by an interpolation is the start of an identifier: `&-#{$name}`
+        if self.options.is_scss_enabled()
+            && self.peek_byte() == Some(b'#')
+            && self.byte_at(2) == Some(b'{')
+        {
+            return self.consume_byte(IDENT);
+        }
+
         self.consume_byte(T![-])
     }
 
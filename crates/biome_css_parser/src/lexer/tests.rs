@@ -14,8 +14,8 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let options = CssParserOptions::default().allow_wrong_line_comments().allow_css_modules();
+    (@options $options:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let options = $options;
         let mut lexer = CssLexer::from_str($src).with_options(options);
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
@@ -64,6 +64,13 @@ macro_rules! assert_lex {
 
         assert_eq!($src, new_str, "Failed to reconstruct input");
     }};
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(
+            @options CssParserOptions::default().allow_wrong_line_comments().allow_css_modules(),
+            $src,
+            $($kind:$len,)*
+        )
+    };
 }
 
 // This is for testing if the lexer is truly lossless
@@ -391,6 +398,69 @@ fn identifier() {
     }
 }
 
+#[test]
+fn scss_variable() {
+    assert_lex! {
+        @options CssParserOptions::default().allow_scss(),
+        "$primary-color: #333",
+        DOLLAR:1,
+        IDENT:13,
+        COLON:1,
+        WHITESPACE:1,
+        HASH:1,
+        CSS_NUMBER_LITERAL:3
+    }
+}
+
+#[test]
+fn scss_keywords() {
+    assert_lex! {
+        @options CssParserOptions::default().allow_scss(),
+        "mixin include",
+        MIXIN_KW:5,
+        WHITESPACE:1,
+        INCLUDE_KW:7
+    }
+
+    // The SCSS keywords are identifiers in plain CSS
+    assert_lex! {
+        "mixin include",
+        IDENT:5,
+        WHITESPACE:1,
+        IDENT:7
+    }
+}
+
+#[test]
+fn scss_line_comments() {
+    assert_lex! {
+        @options CssParserOptions::default().allow_scss(),
+        "// comment",
+        COMMENT:10
+    }
+}
+
+#[test]
+fn scss_interpolation() {
+    assert_lex! {
+        @options CssParserOptions::default().allow_scss(),
+        ".icon-#{$name}",
+        DOT:1,
+        IDENT:5,
+        HASH_L_CURLY:2,
+        DOLLAR:1,
+        IDENT:4,
+        R_CURLY:1
+    }
+
+    // The interpolation is only lexed in SCSS files
+    assert_lex! {
+        "#{",
+        HASH:1,
+        L_CURLY:1
+    }
+}
+
 #[test]
 fn wrong_line_comments() {
     assert_lex! {
@@ -1,7 +1,7 @@
 use crate::lexer::CssReLexContext;
 use crate::state::CssParserState;
 use crate::token_source::{CssTokenSource, CssTokenSourceCheckpoint};
-use biome_css_syntax::CssSyntaxKind;
+use biome_css_syntax::{CssFileSource, CssSyntaxKind};
 use biome_parser::diagnostic::merge_diagnostics;
 use biome_parser::event::Event;
 use biome_parser::token_source::Trivia;
@@ -33,6 +33,10 @@ pub struct CssParserOptions {
     /// Enables parsing of Grit metavariables.
     /// Defaults to `false`.
     pub grit_metavariables: bool,
+
+    /// Enables parsing of SCSS specific features.
+    /// Defaults to `false`.
+    pub scss: bool,
 }
 
 impl CssParserOptions {
@@ -54,6 +58,12 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of SCSS variables, mixins, modules and nested properties.
+    pub fn allow_scss(mut self) -> Self {
+        self.scss = true;
+        self
+    }
+
     /// Checks if parsing of CSS Modules features is disabled.
     pub fn is_css_modules_disabled(&self) -> bool {
         !self.css_modules
@@ -63,6 +73,22 @@ impl CssParserOptions {
     pub fn is_metavariable_enabled(&self) -> bool {
         self.grit_metavariables
     }
+
+    /// Checks if parsing of SCSS features is enabled.
+    pub fn is_scss_enabled(&self) -> bool {
+        self.scss
+    }
+}
+
+impl From<&CssFileSource> for CssParserOptions {
+    fn from(file_source: &CssFileSource) -> Self {
+        let options = Self::default();
+        if file_source.is_scss() {
+            options.allow_scss()
+        } else {
+            options
+        }
+    }
 }
 
 impl<'source> CssParser<'source> {
@@ -43,6 +43,10 @@ use crate::syntax::at_rule::supports::parse_supports_at_rule;
 use crate::syntax::at_rule::unknown::{is_at_unknown_at_rule, parse_unknown_at_rule};
 use crate::syntax::at_rule::value::parse_value_at_rule;
 use crate::syntax::parse_error::expected_any_at_rule;
+use crate::syntax::scss::{
+    parse_scss_content_at_rule, parse_scss_extend_at_rule, parse_scss_forward_at_rule,
+    parse_scss_include_at_rule, parse_scss_mixin_at_rule, parse_scss_use_at_rule,
+};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
@@ -99,6 +103,13 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         T![document] => parse_document_at_rule(p),
         T![property] => parse_property_at_rule(p),
         T![value] => parse_value_at_rule(p),
+        // The SCSS at-rules are unknown at-rules in plain CSS
+        T![mixin] if p.options().is_scss_enabled() => parse_scss_mixin_at_rule(p),
+        T![include] if p.options().is_scss_enabled() => parse_scss_include_at_rule(p),
+        T![content] if p.options().is_scss_enabled() => parse_scss_content_at_rule(p),
+        T![extend] if p.options().is_scss_enabled() => parse_scss_extend_at_rule(p),
+        T![use] if p.options().is_scss_enabled() => parse_scss_use_at_rule(p),
+        T![forward] if p.options().is_scss_enabled() => parse_scss_forward_at_rule(p),
         _ if is_at_unknown_at_rule(p) => parse_unknown_at_rule(p),
         _ => Absent,
     }
@@ -2,6 +2,10 @@ use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_nesting_declaration, parse_scss_declaration,
+    parse_scss_nesting_declaration,
+};
 use crate::syntax::{
     is_at_declaration, is_at_metavariable, is_at_nested_qualified_rule,
     parse_declaration_with_semicolon, parse_metavariable, parse_nested_qualified_rule, try_parse,
@@ -38,6 +42,7 @@ fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
     is_at_at_rule(p)
         || is_at_nested_qualified_rule(p)
         || is_at_declaration(p)
+        || is_at_scss_declaration(p)
         || is_at_metavariable(p)
 }
 
@@ -61,6 +66,10 @@ impl ParseNodeList for DeclarationOrRuleList {
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
         if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_scss_nesting_declaration(p) {
+            parse_scss_nesting_declaration(p)
         } else if is_at_declaration(p) {
             // if we are at a declaration,
             // we still can have a nested qualified rule or a declaration
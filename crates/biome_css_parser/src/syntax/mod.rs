@@ -3,6 +3,7 @@ mod block;
 mod css_modules;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
 mod value;
 
@@ -14,6 +15,12 @@ use crate::syntax::parse_error::{expected_any_rule, expected_non_css_wide_keywor
 use crate::syntax::property::color::{is_at_color, parse_color};
 use crate::syntax::property::unicode_range::{is_at_unicode_range, parse_unicode_range};
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_identifier, is_at_scss_interpolation,
+    is_at_scss_map_expression, is_at_scss_qualified_name, parse_scss_declaration,
+    parse_scss_identifier, parse_scss_map_expression, parse_scss_qualified_name,
+    parse_scss_regular_interpolated_identifier,
+};
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{is_at_relative_selector, RelativeSelectorList};
 use crate::syntax::selector::SelectorList;
@@ -51,7 +58,7 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_at_rule(p) || is_at_qualified_rule(p) || is_at_scss_declaration(p)
 }
 
 struct RuleListParseRecovery {
@@ -84,6 +91,8 @@ impl ParseNodeList for RuleList {
             parse_at_rule(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else {
             Absent
         }
@@ -279,19 +288,24 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
         || is_at_ratio(p)
         || is_at_color(p)
         || is_at_bracketed_value(p)
+        || is_at_scss_identifier(p)
+        || is_at_scss_map_expression(p)
+        || is_at_scss_interpolation(p)
         || is_at_metavariable(p)
 }
 
 #[inline]
 pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
-    if is_at_any_function(p) {
+    if is_at_scss_qualified_name(p) {
+        parse_scss_qualified_name(p)
+    } else if is_at_any_function(p) {
         parse_any_function(p)
     } else if is_at_dashed_identifier(p) {
         parse_dashed_identifier(p)
     } else if is_at_unicode_range(p) {
         parse_unicode_range(p)
-    } else if is_at_identifier(p) {
-        parse_regular_identifier(p)
+    } else if is_at_identifier(p) || is_at_scss_interpolation(p) {
+        parse_scss_regular_interpolated_identifier(p)
     } else if p.at(CSS_STRING_LITERAL) {
         parse_string(p)
     } else if is_at_any_dimension(p) {
@@ -304,6 +318,10 @@ pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
         parse_color(p)
     } else if is_at_bracketed_value(p) {
         parse_bracketed_value(p)
+    } else if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_scss_map_expression(p) {
+        parse_scss_map_expression(p)
     } else if is_at_metavariable(p) {
         parse_metavariable(p)
     } else {
@@ -7,6 +7,9 @@ use crate::syntax::css_modules::{
     composes_not_allowed, expected_classes_list, expected_composes_import_source,
 };
 use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::scss::{
+    is_at_scss_interpolated_identifier, parse_scss_regular_interpolated_identifier,
+};
 use crate::syntax::{
     is_at_any_value, is_at_dashed_identifier, is_at_identifier, is_at_string, parse_any_value,
     parse_custom_identifier_with_keywords, parse_dashed_identifier, parse_regular_identifier,
@@ -159,7 +162,7 @@ const END_OF_COMPOSES_CLASS_TOKEN_SET: TokenSet<CssSyntaxKind> =
 
 #[inline]
 fn is_at_generic_property(p: &mut CssParser) -> bool {
-    is_at_identifier(p) && p.nth_at(1, T![:])
+    (is_at_identifier(p) && p.nth_at(1, T![:])) || is_at_scss_interpolated_identifier(p)
 }
 
 #[inline]
@@ -173,7 +176,7 @@ fn parse_generic_property(p: &mut CssParser) -> ParsedSyntax {
     if is_at_dashed_identifier(p) {
         parse_dashed_identifier(p).ok();
     } else {
-        parse_regular_identifier(p).ok();
+        parse_scss_regular_interpolated_identifier(p).ok();
     }
 
     p.expect(T![:]);
@@ -184,7 +187,7 @@ fn parse_generic_property(p: &mut CssParser) -> ParsedSyntax {
 }
 const END_OF_PROPERTY_VALUE_TOKEN_SET: TokenSet<CssSyntaxKind> = token_set!(T!['}'], T![;]);
 
-struct GenericComponentValueList;
+pub(crate) struct GenericComponentValueList;
 
 impl ParseNodeList for GenericComponentValueList {
     type Kind = CssSyntaxKind;
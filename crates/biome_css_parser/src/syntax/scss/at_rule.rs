@@ -0,0 +1,430 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::{expected_identifier, expected_selector, expected_string};
+use crate::syntax::scss::parse_error::{
+    expected_scss_argument, expected_scss_forward_member, expected_scss_map_expression,
+    expected_scss_variable,
+};
+use crate::syntax::scss::{
+    is_at_scss_identifier, parse_scss_identifier, parse_scss_map_expression,
+};
+use crate::syntax::selector::SelectorList;
+use crate::syntax::{
+    is_at_any_value, is_at_identifier, parse_regular_identifier, parse_string,
+    CssComponentValueList,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+/// Parses an SCSS `@mixin` at-rule.
+/// For details, see [@mixin and @include](https://sass-lang.com/documentation/at-rules/mixin/).
+///
+/// ```scss
+/// @mixin theme($theme: DarkGray) {
+///   background: $theme;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![mixin]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![mixin]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_MIXIN_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+const SCSS_PARAMETER_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T!['{'], T![;]];
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_PARAMETER_RECOVERY_SET),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        CssComponentValueList.parse_list(p);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT_VALUE);
+    }
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
+
+/// Parses an SCSS `@include` at-rule, with its optional arguments and content block.
+/// For details, see [@mixin and @include](https://sass-lang.com/documentation/at-rules/mixin/).
+///
+/// ```scss
+/// @include theme($theme: DarkRed);
+/// @include hover {
+///   color: red;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![include]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![include]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else {
+        expect_optional_semicolon(p);
+    }
+
+    Present(m.complete(p, SCSS_INCLUDE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_ARGUMENTS))
+}
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_PARAMETER_RECOVERY_SET),
+            expected_scss_argument,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_any_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    // A keyword argument, such as `$theme: DarkRed`
+    if is_at_scss_identifier(p) && p.nth_at(2, T![:]) {
+        let name = p.start();
+        parse_scss_identifier(p).ok();
+        p.bump(T![:]);
+        name.complete(p, SCSS_ARGUMENT_NAME);
+    }
+
+    CssComponentValueList.parse_list(p);
+
+    Present(m.complete(p, SCSS_ARGUMENT))
+}
+
+/// Parses an SCSS `@content` at-rule, which includes the content block passed
+/// to a mixin.
+/// For details, see [Content Blocks](https://sass-lang.com/documentation/at-rules/mixin/#content-blocks).
+#[inline]
+pub(crate) fn parse_scss_content_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![content]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![content]);
+    expect_optional_semicolon(p);
+
+    Present(m.complete(p, SCSS_CONTENT_AT_RULE))
+}
+
+/// Parses an SCSS `@extend` at-rule.
+/// For details, see [@extend](https://sass-lang.com/documentation/at-rules/extend/).
+///
+/// ```scss
+/// .error--serious {
+///   @extend .error;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_extend_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![extend]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![extend]);
+    if p.at_ts(token_set![T![;], T!['}']]) {
+        p.error(expected_selector(p, p.cur_range()));
+    }
+    SelectorList::default()
+        .with_end_kind_ts(token_set![T![;], T!['}']])
+        .with_recovery_ts(token_set![T![;], T!['}']])
+        .parse_list(p);
+    expect_optional_semicolon(p);
+
+    Present(m.complete(p, SCSS_EXTEND_AT_RULE))
+}
+
+/// Parses an SCSS `@use` at-rule, which loads the members of another module.
+/// For details, see [@use](https://sass-lang.com/documentation/at-rules/use/).
+///
+/// ```scss
+/// @use "sass:math" as m;
+/// @use "library" with ($black: #222);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_use_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![use]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![use]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_use_as_clause(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_USE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_use_as_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+    if p.at(T![*]) {
+        let namespace = p.start();
+        p.bump(T![*]);
+        namespace.complete(p, SCSS_USE_ALL_NAMESPACE);
+    } else {
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    Present(m.complete(p, SCSS_USE_AS_CLAUSE))
+}
+
+#[inline]
+fn parse_scss_module_configuration(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![with]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![with]);
+    parse_scss_map_expression(p).or_add_diagnostic(p, expected_scss_map_expression);
+
+    Present(m.complete(p, SCSS_MODULE_CONFIGURATION))
+}
+
+/// Parses an SCSS `@forward` at-rule, which makes the members of another
+/// module available to the modules that load this one.
+/// For details, see [@forward](https://sass-lang.com/documentation/at-rules/forward/).
+///
+/// ```scss
+/// @forward "src/list" as list-*;
+/// @forward "src/list" hide list-reset, $horizontal-list-gap;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_forward_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![forward]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![forward]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+    parse_scss_forward_as_clause(p).ok();
+    parse_scss_forward_visibility_clause(p).ok();
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_FORWARD_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_forward_as_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![as]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![as]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    p.expect(T![*]);
+
+    Present(m.complete(p, SCSS_FORWARD_AS_CLAUSE))
+}
+
+const SCSS_FORWARD_VISIBILITY_SET: TokenSet<CssSyntaxKind> = token_set![T![show], T![hide]];
+
+#[inline]
+fn parse_scss_forward_visibility_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at_ts(SCSS_FORWARD_VISIBILITY_SET) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump_ts(SCSS_FORWARD_VISIBILITY_SET);
+    ScssForwardMemberList.parse_list(p);
+
+    Present(m.complete(p, SCSS_FORWARD_VISIBILITY_CLAUSE))
+}
+
+struct ScssForwardMemberList;
+
+impl ParseSeparatedList for ScssForwardMemberList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_FORWARD_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_scss_identifier(p) {
+            parse_scss_identifier(p)
+        } else if is_at_identifier(p) {
+            parse_regular_identifier(p)
+        } else {
+            Absent
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![;]) || p.at(T![with])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![;]]),
+            expected_scss_forward_member,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+/// The semicolon at the end of the statement-like SCSS at-rules is optional
+/// only for the last item of a block.
+#[inline]
+fn expect_optional_semicolon(p: &mut CssParser) {
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+}
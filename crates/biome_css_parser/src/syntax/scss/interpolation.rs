@@ -0,0 +1,120 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::{is_at_identifier, parse_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{CompletedMarker, Parser};
+
+/// Checks if the parser is at an identifier that starts with an SCSS interpolation or whose
+/// first part is followed by one, such as the property names `#{$property}-top` and `margin-#{$side}`.
+#[inline]
+pub(crate) fn is_at_scss_interpolated_identifier(p: &mut CssParser) -> bool {
+    is_at_scss_interpolation(p) || (is_at_identifier(p) && p.nth_at(1, T!["#{"]))
+}
+
+/// Checks if the parser is at an SCSS interpolation, such as `#{$name}`.
+///
+/// The `#{` token is only produced by the lexer in SCSS files.
+#[inline]
+pub(crate) fn is_at_scss_interpolation(p: &mut CssParser) -> bool {
+    p.at(T!["#{"])
+}
+
+/// Parses an SCSS interpolation, which inserts the value of an expression in a selector,
+/// a property name or a value. The closing `}` is bumped with the lexing context returned
+/// by `context`, because it can end a selector.
+/// For details, see [Interpolation](https://sass-lang.com/documentation/interpolation/).
+///
+/// ```scss
+/// .icon-#{$name} { width: #{$size}px; }
+/// ```
+#[inline]
+fn parse_scss_interpolation(
+    p: &mut CssParser,
+    context: impl Fn(&mut CssParser) -> CssLexContext,
+) -> ParsedSyntax {
+    if !is_at_scss_interpolation(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!["#{"]);
+    GenericComponentValueList.parse_list(p);
+    let context = context(p);
+    p.expect_with_context(T!['}'], context);
+
+    Present(m.complete(p, SCSS_INTERPOLATION))
+}
+
+/// Parses an identifier that can contain SCSS interpolations, such as `icon-#{$name}`
+/// or `#{$property}-top`. The parts of the identifier can't be separated by whitespace.
+///
+/// `parse_identifier` parses the identifiers between the interpolations, and `context`
+/// returns the lexing context of the token that follows each part. An identifier without
+/// interpolations is returned as is.
+pub(crate) fn parse_scss_interpolated_identifier(
+    p: &mut CssParser,
+    parse_identifier: impl Fn(&mut CssParser) -> ParsedSyntax,
+    context: impl Fn(&mut CssParser) -> CssLexContext + Copy,
+) -> ParsedSyntax {
+    let first = if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p, context)
+    } else {
+        parse_identifier(p)
+    };
+
+    let Present(first) = first else {
+        return Absent;
+    };
+
+    if first.kind(p) != SCSS_INTERPOLATION && !is_at_adjacent_scss_interpolation(p, &first) {
+        return Present(first);
+    }
+
+    let mut end = first.range(p).end();
+    let list = first.precede(p);
+
+    loop {
+        let part = if p.cur_range().start() != end {
+            Absent
+        } else if is_at_scss_interpolation(p) {
+            parse_scss_interpolation(p, context)
+        } else if is_at_identifier(p) {
+            parse_identifier(p)
+        } else {
+            Absent
+        };
+
+        match part {
+            Present(part) => end = part.range(p).end(),
+            Absent => break,
+        }
+    }
+
+    let list = list.complete(p, SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST);
+    let m = list.precede(p);
+
+    Present(m.complete(p, SCSS_INTERPOLATED_IDENTIFIER))
+}
+
+/// Checks if the parser is at an interpolation that directly follows `previous`, without whitespace between them.
+#[inline]
+fn is_at_adjacent_scss_interpolation(p: &mut CssParser, previous: &CompletedMarker) -> bool {
+    is_at_scss_interpolation(p) && p.cur_range().start() == previous.range(p).end()
+}
+
+/// Parses an identifier that can contain SCSS interpolations in the regular lexing context,
+/// such as a property name or a value.
+#[inline]
+pub(crate) fn parse_scss_regular_interpolated_identifier(p: &mut CssParser) -> ParsedSyntax {
+    parse_scss_interpolated_identifier(
+        p,
+        |p| parse_identifier(p, CssLexContext::Regular),
+        |_| CssLexContext::Regular,
+    )
+}
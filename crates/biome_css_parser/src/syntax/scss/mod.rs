@@ -0,0 +1,191 @@
+//! The SCSS syntax, parsed when the SCSS parsing is enabled in the [CssParserOptions](crate::CssParserOptions).
+//!
+//! The control and function at-rules, such as `@if`, `@else`, `@each`, `@function` and `@return`,
+//! don't have their own nodes yet and are parsed as unknown at-rules.
+//!
+//! The interpolation `#{...}` is supported in the names of selectors, in property names
+//! and in values.
+
+mod at_rule;
+mod interpolation;
+mod parse_error;
+mod value;
+
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::{is_at_identifier, is_nth_at_identifier, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+pub(crate) use at_rule::{
+    parse_scss_content_at_rule, parse_scss_extend_at_rule, parse_scss_forward_at_rule,
+    parse_scss_include_at_rule, parse_scss_mixin_at_rule, parse_scss_use_at_rule,
+};
+pub(crate) use interpolation::{
+    is_at_scss_interpolated_identifier, is_at_scss_interpolation,
+    parse_scss_interpolated_identifier, parse_scss_regular_interpolated_identifier,
+};
+pub(crate) use value::{
+    is_at_scss_map_expression, is_at_scss_qualified_name, parse_scss_map_expression,
+    parse_scss_qualified_name,
+};
+
+use self::parse_error::expected_scss_variable;
+
+/// Checks if the parser is at an SCSS variable, such as `$primary-color`.
+///
+/// The `$` token is only produced by the lexer in SCSS files.
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![$]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses an SCSS variable, such as `$primary-color`.
+/// For details, see [Variables](https://sass-lang.com/documentation/variables/).
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![$]);
+    parse_regular_identifier(p).ok();
+
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+/// Checks if the parser is at the declaration of an SCSS variable.
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses the declaration of an SCSS variable, with its optional `!default`
+/// and `!global` flags.
+///
+/// ```scss
+/// $primary-color: #333;
+/// $font-stack: Helvetica, sans-serif !default;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).or_add_diagnostic(p, expected_scss_variable);
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    // Like for declarations, the semicolon is optional only for the last
+    // declaration of a block or of the file.
+    if !p.at(T!['}']) && !p.at(EOF) {
+        if p.nth_at(1, T!['}']) {
+            p.eat(T![;]);
+        } else {
+            p.expect(T![;]);
+        }
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+const SCSS_VARIABLE_MODIFIER_SET: TokenSet<CssSyntaxKind> = token_set![T![default], T![global]];
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !is_at_scss_variable_modifier(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![;], T!['}']]),
+            expected_scss_variable,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_variable_modifier(p: &mut CssParser) -> bool {
+    p.at(T![!]) && p.nth_at_ts(1, SCSS_VARIABLE_MODIFIER_SET)
+}
+
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_variable_modifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![!]);
+    p.bump_ts(SCSS_VARIABLE_MODIFIER_SET);
+
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
+
+/// Checks if the parser is at an SCSS nested property, such as `font: { ... }`.
+#[inline]
+pub(crate) fn is_at_scss_nesting_declaration(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && is_at_identifier(p)
+        && p.nth_at(1, T![:])
+        && p.nth_at(2, T!['{'])
+}
+
+/// Parses an SCSS nested property, where the name of the property is the
+/// prefix of the names of the declarations of its block.
+/// For details, see [Nested Properties](https://sass-lang.com/documentation/style-rules/declarations/#nesting).
+///
+/// ```scss
+/// .info-page {
+///   margin: {
+///     left: 10px;
+///   }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_nesting_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_nesting_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump(T![:]);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_NESTING_DECLARATION))
+}
@@ -0,0 +1,27 @@
+use crate::parser::CssParser;
+use biome_css_syntax::TextRange;
+use biome_parser::diagnostic::{expect_one_of, expected_node, ParseDiagnostic};
+use biome_parser::prelude::ToDiagnostic;
+
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS variable", range, p)
+}
+
+pub(crate) fn expected_scss_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS argument", range, p)
+}
+
+pub(crate) fn expected_scss_map_expression(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS map", range, p)
+}
+
+pub(crate) fn expected_scss_map_expression_pair(
+    p: &CssParser,
+    range: TextRange,
+) -> ParseDiagnostic {
+    expected_node("SCSS map pair", range, p)
+}
+
+pub(crate) fn expected_scss_forward_member(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["<identifier>", "SCSS variable"], range).into_diagnostic(p)
+}
@@ -0,0 +1,129 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::scss::parse_error::expected_scss_map_expression_pair;
+use crate::syntax::scss::{is_nth_at_scss_identifier, parse_scss_identifier};
+use crate::syntax::value::function::{is_nth_at_function, parse_function};
+use crate::syntax::{
+    is_at_any_value, is_at_identifier, parse_any_value, parse_regular_identifier,
+    CssComponentValueList,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser};
+
+/// Checks if the parser is at a member of an SCSS module, such as
+/// `math.div(10px, 3)` or `config.$primary-color`.
+#[inline]
+pub(crate) fn is_at_scss_qualified_name(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && is_at_identifier(p)
+        && p.nth_at(1, T![.])
+        && (is_nth_at_function(p, 2) || is_nth_at_scss_identifier(p, 2))
+}
+
+/// Parses a member of an SCSS module loaded with `@use`.
+/// For details, see [Loading Members](https://sass-lang.com/documentation/at-rules/use/#loading-members).
+#[inline]
+pub(crate) fn parse_scss_qualified_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_qualified_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump(T![.]);
+    if p.at(T![$]) {
+        parse_scss_identifier(p).ok();
+    } else {
+        parse_function(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+/// Checks if the parser is at an SCSS map, such as `(key: value)`.
+///
+/// A parenthesized value is only a map when its first key is followed by a colon.
+#[inline]
+pub(crate) fn is_at_scss_map_expression(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && p.at(T!['('])
+        && (p.nth_at(2, T![:]) || (is_nth_at_scss_identifier(p, 1) && p.nth_at(3, T![:])))
+}
+
+/// Parses an SCSS map.
+/// For details, see [Maps](https://sass-lang.com/documentation/values/maps/).
+///
+/// ```scss
+/// $font-weights: ("regular": 400, "medium": 500, "bold": 700);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_map_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_map_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssMapExpressionPairList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_MAP_EXPRESSION))
+}
+
+struct ScssMapExpressionPairList;
+
+impl ParseSeparatedList for ScssMapExpressionPairList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_MAP_EXPRESSION_PAIR_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_map_expression_pair(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;]]),
+            expected_scss_map_expression_pair,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_map_expression_pair(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_any_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_any_value(p).or_add_diagnostic(p, expected_component_value);
+    p.expect(T![:]);
+    CssComponentValueList.parse_list(p);
+
+    Present(m.complete(p, SCSS_MAP_EXPRESSION_PAIR))
+}
@@ -28,6 +28,7 @@ use biome_parser::prelude::ParsedSyntax;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
 use biome_parser::{token_set, CompletedMarker, Parser, ParserProgress, TokenSet};
 
+use super::scss::{is_at_scss_interpolation, parse_scss_interpolated_identifier};
 use super::{is_nth_at_metavariable, parse_metavariable};
 
 /// Determines the lexical context for parsing CSS selectors.
@@ -38,10 +39,14 @@ use super::{is_nth_at_metavariable, parse_metavariable};
 /// around combinators in CSS selectors.
 const SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> =
     COMPLEX_SELECTOR_COMBINATOR_SET.union(token_set![T!['{'], T![,], T![')']]);
+/// In SCSS, the selectors of an `@extend` at-rule end with `;` or `}`.
+const SCSS_SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> = token_set![T![;], T!['}']];
 #[inline]
 fn selector_lex_context(p: &mut CssParser) -> CssLexContext {
     // It's an inverted logic for `is_nth_at_selector(p, 1)`.
-    if p.nth_at_ts(1, SELECTOR_LEX_SET) {
+    if p.nth_at_ts(1, SELECTOR_LEX_SET)
+        || (p.options().is_scss_enabled() && p.nth_at_ts(1, SCSS_SELECTOR_LEX_SET))
+    {
         CssLexContext::Regular
     } else {
         CssLexContext::Selector
@@ -277,6 +282,7 @@ fn is_nth_at_compound_selector(p: &mut CssParser, n: usize) -> bool {
     p.nth_at(n, T![&])
         || is_nth_at_simple_selector(p, n)
         || p.nth_at_ts(n, SubSelectorList::START_SET)
+        || is_nth_at_scss_placeholder_selector(p, n)
 }
 
 /// Parses a compound selector in CSS.
@@ -306,7 +312,10 @@ fn parse_compound_selector(p: &mut CssParser) -> ParsedSyntax {
 /// including type selectors, universal selectors, and attribute selectors.
 #[inline]
 fn is_nth_at_simple_selector(p: &mut CssParser, n: usize) -> bool {
-    is_nth_at_namespace(p, n) || p.nth_at(n, T![*]) || is_nth_at_identifier(p, n)
+    is_nth_at_namespace(p, n)
+        || p.nth_at(n, T![*])
+        || is_nth_at_identifier(p, n)
+        || p.nth_at(n, T!["#{"])
 }
 
 /// Parses a simple selector in CSS.
@@ -320,6 +329,15 @@ fn parse_simple_selector(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
+    // In SCSS, an interpolation can stand for a whole selector: `#{$selector} { ... }`
+    if is_at_scss_interpolation(p) {
+        return parse_scss_interpolated_identifier(
+            p,
+            parse_selector_identifier,
+            selector_lex_context,
+        );
+    }
+
     let namespace = parse_namespace(p);
 
     if p.at(T![*]) {
@@ -410,7 +428,7 @@ impl ParseNodeList for SubSelectorList {
     }
 
     fn is_at_list_end(&self, p: &mut CssParser) -> bool {
-        !p.at_ts(Self::START_SET)
+        !p.at_ts(Self::START_SET) && !is_nth_at_scss_placeholder_selector(p, 0)
     }
 
     fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
@@ -435,6 +453,7 @@ fn parse_sub_selector(p: &mut CssParser) -> ParsedSyntax {
         T!['['] => parse_attribute_selector(p),
         T![:] => parse_pseudo_class_selector(p),
         T![::] => parse_pseudo_element_selector(p),
+        T![%] => parse_scss_placeholder_selector(p),
         _ => Absent,
     }
 }
@@ -453,7 +472,7 @@ pub(crate) fn parse_class_selector(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![.]);
-    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_selector_name(p).or_add_diagnostic(p, expected_identifier);
 
     Present(m.complete(p, CSS_CLASS_SELECTOR))
 }
@@ -472,11 +491,36 @@ pub(crate) fn parse_id_selector(p: &mut CssParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![#]);
-    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_selector_name(p).or_add_diagnostic(p, expected_identifier);
 
     Present(m.complete(p, CSS_ID_SELECTOR))
 }
 
+/// Checks if the current or nth token in the parser is the start of an SCSS
+/// placeholder selector. Placeholder selectors are only parsed in SCSS files.
+#[inline]
+fn is_nth_at_scss_placeholder_selector(p: &mut CssParser, n: usize) -> bool {
+    p.options().is_scss_enabled() && p.nth_at(n, T![%])
+}
+
+/// Parses an SCSS placeholder selector, which starts with a percent ('%')
+/// followed by an identifier. Placeholder selectors are never emitted to the
+/// CSS output, their rules are only used by `@extend`.
+/// For details, see [Placeholder Selectors](https://sass-lang.com/documentation/style-rules/placeholder-selectors/).
+#[inline]
+pub(crate) fn parse_scss_placeholder_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_nth_at_scss_placeholder_selector(p, 0) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![%]);
+    parse_selector_name(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_PLACEHOLDER_SELECTOR))
+}
+
 /// Parses a universal selector ('*') in CSS.
 ///
 /// This function attempts to parse a universal selector from the current position
@@ -506,6 +550,22 @@ fn parse_type_selector(p: &mut CssParser, namespace: ParsedSyntax) -> ParsedSynt
         return Absent;
     }
 
+    // In SCSS, a type selector followed by an interpolation is an interpolated identifier: `h#{$level}`
+    if namespace.is_absent() && p.options().is_scss_enabled() {
+        return parse_scss_interpolated_identifier(
+            p,
+            parse_selector_identifier,
+            selector_lex_context,
+        )
+        .map(|name| {
+            if name.kind(p) == CSS_IDENTIFIER {
+                name.precede(p).complete(p, CSS_TYPE_SELECTOR)
+            } else {
+                name
+            }
+        });
+    }
+
     let m = namespace.precede(p);
 
     parse_selector_identifier(p).or_add_diagnostic(p, expected_identifier);
@@ -524,6 +584,13 @@ fn parse_selector_identifier(p: &mut CssParser) -> ParsedSyntax {
     parse_identifier(p, context)
 }
 
+/// Parses the name of an id, class or placeholder selector, which can contain
+/// SCSS interpolations: `.icon-#{$name}`.
+#[inline]
+fn parse_selector_name(p: &mut CssParser) -> ParsedSyntax {
+    parse_scss_interpolated_identifier(p, parse_selector_custom_identifier, selector_lex_context)
+}
+
 /// Custom identifiers are used for class names and ids in selectors and are
 /// case-sensitive. These are distinguished from regular identifiers in
 /// selectors that are case-insensitive for safety in preserving the casing.
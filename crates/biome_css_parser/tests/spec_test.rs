@@ -2,6 +2,7 @@ use biome_configuration::PartialConfiguration;
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::CssFileSource;
 use biome_deserialize::json::deserialize_from_str;
 use biome_diagnostics::display::PrintDiagnostic;
 use biome_diagnostics::DiagnosticExt;
@@ -40,7 +41,8 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let mut options = CssParserOptions::default()
+    let file_source = CssFileSource::try_from(test_case_path).unwrap_or_default();
+    let mut options = CssParserOptions::from(&file_source)
         // it is an internal option that cannot be configured via options.json
         // TODO: find a way to make it configurable
         .allow_metavariables();
@@ -84,7 +86,8 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    let language = if file_source.is_scss() { "scss" } else { "css" };
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
@@ -1,7 +1,7 @@
 use std::{borrow::Cow, collections::VecDeque};
 
 use biome_css_syntax::{
-    AnyCssSelector, CssDeclarationBlock, CssRelativeSelector, CssSyntaxKind::*,
+    AnyCssSelector, CssDeclaration, CssDeclarationBlock, CssRelativeSelector, CssSyntaxKind::*,
 };
 use biome_rowan::{AstNode, SyntaxNodeCast, SyntaxNodeOptionExt, TextRange};
 
@@ -97,9 +97,13 @@ impl SemanticEventExtractor {
             CSS_DECLARATION => {
                 if let Some(property_name) = node.first_child().and_then(|p| p.first_child()) {
                     if let Some(value) = property_name.next_sibling() {
+                        // SCSS nested properties prefix the names of their declarations
+                        let prefix = CssDeclaration::cast_ref(node)
+                            .and_then(|declaration| declaration.scss_nesting_prefix())
+                            .unwrap_or_default();
                         self.stash.push_back(SemanticEvent::PropertyDeclaration {
                             property: CssProperty {
-                                name: property_name.text_trimmed().to_string(),
+                                name: format!("{prefix}{}", property_name.text_trimmed()),
                                 range: property_name.text_trimmed_range(),
                             },
                             value: CssValue {
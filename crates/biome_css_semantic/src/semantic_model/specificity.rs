@@ -17,6 +17,8 @@ fn evaluate_any_simple_selector(selector: &AnyCssSimpleSelector) -> Specificity
     match selector {
         AnyCssSimpleSelector::CssTypeSelector(_) => TYPE_SPECIFICITY,
         AnyCssSimpleSelector::CssUniversalSelector(_) => ZERO_SPECIFICITY,
+        // The interpolated name of an SCSS selector, such as `h#{$level}`, takes the place of a type selector
+        AnyCssSimpleSelector::ScssInterpolatedIdentifier(_) => TYPE_SPECIFICITY,
     }
 }
 
@@ -124,6 +126,8 @@ fn evaluate_any_subselector(selector: &AnyCssSubSelector) -> Specificity {
         AnyCssSubSelector::CssPseudoClassSelector(s) => evaluate_pseudo_selector(s),
         AnyCssSubSelector::CssPseudoElementSelector(_) => TYPE_SPECIFICITY,
         AnyCssSubSelector::CssBogusSubSelector(_) => ZERO_SPECIFICITY,
+        // Placeholders are replaced by the selectors that extend them.
+        AnyCssSubSelector::ScssPlaceholderSelector(_) => ZERO_SPECIFICITY,
     }
 }
 
@@ -7,15 +7,13 @@ use std::{ffi::OsStr, path::Path};
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct CssFileSource {
-    // Unused until we potentially support postcss/less/sass
-    #[allow(unused)]
     variant: CssVariant,
 }
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Biome supports plain CSS, and aims to be compatible with the latest
+/// Recommendation level standards, and the SCSS syntax of Sass.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -23,6 +21,7 @@ pub struct CssFileSource {
 enum CssVariant {
     #[default]
     Standard,
+    Scss,
 }
 
 impl CssFileSource {
@@ -32,6 +31,16 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -43,6 +52,7 @@ impl CssFileSource {
         // We assume the file extension is normalized to lowercase
         match extension.as_encoded_bytes() {
             b"css" => Ok(Self::css()),
+            b"scss" => Ok(Self::scss()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -56,6 +66,7 @@ impl CssFileSource {
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
         match language_id {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
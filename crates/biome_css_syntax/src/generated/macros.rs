@@ -684,6 +684,117 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRuleNamedImportSpecifier::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_NAME => {
+                    let $pattern = unsafe { $crate::ScssArgumentName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::ScssArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_CONTENT_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssContentAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXTEND_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssExtendAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AS_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssForwardAsClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssForwardAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_VISIBILITY_CLAUSE => {
+                    let $pattern =
+                        unsafe { $crate::ScssForwardVisibilityClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::ScssIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INCLUDE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIncludeAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER => {
+                    let $pattern =
+                        unsafe { $crate::ScssInterpolatedIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATION => {
+                    let $pattern = unsafe { $crate::ScssInterpolation::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION => {
+                    let $pattern = unsafe { $crate::ScssMapExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR => {
+                    let $pattern = unsafe { $crate::ScssMapExpressionPair::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MIXIN_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssMixinAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MODULE_CONFIGURATION => {
+                    let $pattern = unsafe { $crate::ScssModuleConfiguration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_NESTING_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssNestingDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER => {
+                    let $pattern = unsafe { $crate::ScssParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE => {
+                    let $pattern =
+                        unsafe { $crate::ScssParameterDefaultValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETERS => {
+                    let $pattern = unsafe { $crate::ScssParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR => {
+                    let $pattern = unsafe { $crate::ScssPlaceholderSelector::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_QUALIFIED_NAME => {
+                    let $pattern = unsafe { $crate::ScssQualifiedName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_ALL_NAMESPACE => {
+                    let $pattern = unsafe { $crate::ScssUseAllNamespace::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AS_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssUseAsClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssUseAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER => {
+                    let $pattern = unsafe { $crate::ScssVariableModifier::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -919,6 +1030,32 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRulePropertyList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::ScssArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST => {
+                    let $pattern = unsafe { $crate::ScssForwardMemberList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST => {
+                    let $pattern =
+                        unsafe { $crate::ScssInterpolatedIdentifierItemList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST => {
+                    let $pattern =
+                        unsafe { $crate::ScssMapExpressionPairList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::ScssParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST => {
+                    let $pattern = unsafe { $crate::ScssVariableModifierList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
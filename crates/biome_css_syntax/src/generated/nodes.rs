@@ -388,7 +388,7 @@ impl CssClassSelector {
     pub fn dot_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn name(&self) -> SyntaxResult<CssCustomIdentifier> {
+    pub fn name(&self) -> SyntaxResult<AnyCssSelectorName> {
         support::required_node(&self.syntax, 1usize)
     }
 }
@@ -403,7 +403,7 @@ impl Serialize for CssClassSelector {
 #[derive(Serialize)]
 pub struct CssClassSelectorFields {
     pub dot_token: SyntaxResult<SyntaxToken>,
-    pub name: SyntaxResult<CssCustomIdentifier>,
+    pub name: SyntaxResult<AnyCssSelectorName>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssColor {
@@ -2073,7 +2073,7 @@ impl CssIdSelector {
     pub fn hash_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn name(&self) -> SyntaxResult<CssCustomIdentifier> {
+    pub fn name(&self) -> SyntaxResult<AnyCssSelectorName> {
         support::required_node(&self.syntax, 1usize)
     }
 }
@@ -2088,7 +2088,7 @@ impl Serialize for CssIdSelector {
 #[derive(Serialize)]
 pub struct CssIdSelectorFields {
     pub hash_token: SyntaxResult<SyntaxToken>,
-    pub name: SyntaxResult<CssCustomIdentifier>,
+    pub name: SyntaxResult<AnyCssSelectorName>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssIdentifier {
@@ -6615,2164 +6615,4703 @@ pub struct CssValueAtRuleNamedImportSpecifierFields {
     pub as_token: SyntaxResult<SyntaxToken>,
     pub local_name: SyntaxResult<CssIdentifier>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssAtRule {
-    CssBogusAtRule(CssBogusAtRule),
-    CssCharsetAtRule(CssCharsetAtRule),
-    CssColorProfileAtRule(CssColorProfileAtRule),
-    CssContainerAtRule(CssContainerAtRule),
-    CssCounterStyleAtRule(CssCounterStyleAtRule),
-    CssDocumentAtRule(CssDocumentAtRule),
-    CssFontFaceAtRule(CssFontFaceAtRule),
-    CssFontFeatureValuesAtRule(CssFontFeatureValuesAtRule),
-    CssFontPaletteValuesAtRule(CssFontPaletteValuesAtRule),
-    CssImportAtRule(CssImportAtRule),
-    CssKeyframesAtRule(CssKeyframesAtRule),
-    CssLayerAtRule(CssLayerAtRule),
-    CssMediaAtRule(CssMediaAtRule),
-    CssNamespaceAtRule(CssNamespaceAtRule),
-    CssPageAtRule(CssPageAtRule),
-    CssPropertyAtRule(CssPropertyAtRule),
-    CssScopeAtRule(CssScopeAtRule),
-    CssStartingStyleAtRule(CssStartingStyleAtRule),
-    CssSupportsAtRule(CssSupportsAtRule),
-    CssUnknownBlockAtRule(CssUnknownBlockAtRule),
-    CssUnknownValueAtRule(CssUnknownValueAtRule),
-    CssValueAtRule(CssValueAtRule),
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssArgument {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AnyCssAtRule {
-    pub fn as_css_bogus_at_rule(&self) -> Option<&CssBogusAtRule> {
-        match &self {
-            AnyCssAtRule::CssBogusAtRule(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_charset_at_rule(&self) -> Option<&CssCharsetAtRule> {
-        match &self {
-            AnyCssAtRule::CssCharsetAtRule(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_color_profile_at_rule(&self) -> Option<&CssColorProfileAtRule> {
-        match &self {
-            AnyCssAtRule::CssColorProfileAtRule(item) => Some(item),
-            _ => None,
-        }
+impl ScssArgument {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_container_at_rule(&self) -> Option<&CssContainerAtRule> {
-        match &self {
-            AnyCssAtRule::CssContainerAtRule(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssArgumentFields {
+        ScssArgumentFields {
+            name: self.name(),
+            value: self.value(),
         }
     }
-    pub fn as_css_counter_style_at_rule(&self) -> Option<&CssCounterStyleAtRule> {
-        match &self {
-            AnyCssAtRule::CssCounterStyleAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> Option<ScssArgumentName> {
+        support::node(&self.syntax, 0usize)
     }
-    pub fn as_css_document_at_rule(&self) -> Option<&CssDocumentAtRule> {
-        match &self {
-            AnyCssAtRule::CssDocumentAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> CssComponentValueList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_font_face_at_rule(&self) -> Option<&CssFontFaceAtRule> {
-        match &self {
-            AnyCssAtRule::CssFontFaceAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn as_css_font_feature_values_at_rule(&self) -> Option<&CssFontFeatureValuesAtRule> {
-        match &self {
-            AnyCssAtRule::CssFontFeatureValuesAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+#[derive(Serialize)]
+pub struct ScssArgumentFields {
+    pub name: Option<ScssArgumentName>,
+    pub value: CssComponentValueList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssArgumentName {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssArgumentName {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_font_palette_values_at_rule(&self) -> Option<&CssFontPaletteValuesAtRule> {
-        match &self {
-            AnyCssAtRule::CssFontPaletteValuesAtRule(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssArgumentNameFields {
+        ScssArgumentNameFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
         }
     }
-    pub fn as_css_import_at_rule(&self) -> Option<&CssImportAtRule> {
-        match &self {
-            AnyCssAtRule::CssImportAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<ScssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_css_keyframes_at_rule(&self) -> Option<&CssKeyframesAtRule> {
-        match &self {
-            AnyCssAtRule::CssKeyframesAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-    pub fn as_css_layer_at_rule(&self) -> Option<&CssLayerAtRule> {
-        match &self {
-            AnyCssAtRule::CssLayerAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssArgumentName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn as_css_media_at_rule(&self) -> Option<&CssMediaAtRule> {
-        match &self {
-            AnyCssAtRule::CssMediaAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+#[derive(Serialize)]
+pub struct ScssArgumentNameFields {
+    pub name: SyntaxResult<ScssIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssArguments {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssArguments {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_namespace_at_rule(&self) -> Option<&CssNamespaceAtRule> {
-        match &self {
-            AnyCssAtRule::CssNamespaceAtRule(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssArgumentsFields {
+        ScssArgumentsFields {
+            l_paren_token: self.l_paren_token(),
+            items: self.items(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn as_css_page_at_rule(&self) -> Option<&CssPageAtRule> {
-        match &self {
-            AnyCssAtRule::CssPageAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_property_at_rule(&self) -> Option<&CssPropertyAtRule> {
-        match &self {
-            AnyCssAtRule::CssPropertyAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn items(&self) -> ScssArgumentList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_scope_at_rule(&self) -> Option<&CssScopeAtRule> {
-        match &self {
-            AnyCssAtRule::CssScopeAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
-    pub fn as_css_starting_style_at_rule(&self) -> Option<&CssStartingStyleAtRule> {
-        match &self {
-            AnyCssAtRule::CssStartingStyleAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn as_css_supports_at_rule(&self) -> Option<&CssSupportsAtRule> {
-        match &self {
-            AnyCssAtRule::CssSupportsAtRule(item) => Some(item),
-            _ => None,
-        }
+}
+#[derive(Serialize)]
+pub struct ScssArgumentsFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub items: ScssArgumentList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssContentAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssContentAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_unknown_block_at_rule(&self) -> Option<&CssUnknownBlockAtRule> {
-        match &self {
-            AnyCssAtRule::CssUnknownBlockAtRule(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssContentAtRuleFields {
+        ScssContentAtRuleFields {
+            content_token: self.content_token(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-    pub fn as_css_unknown_value_at_rule(&self) -> Option<&CssUnknownValueAtRule> {
-        match &self {
-            AnyCssAtRule::CssUnknownValueAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn content_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_value_at_rule(&self) -> Option<&CssValueAtRule> {
-        match &self {
-            AnyCssAtRule::CssValueAtRule(item) => Some(item),
-            _ => None,
-        }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssAttributeMatcherValue {
-    CssIdentifier(CssIdentifier),
-    CssString(CssString),
-}
-impl AnyCssAttributeMatcherValue {
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssAttributeMatcherValue::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssAttributeMatcherValue::CssString(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssContentAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssComposesImportSource {
-    CssIdentifier(CssIdentifier),
-    CssString(CssString),
+#[derive(Serialize)]
+pub struct ScssContentAtRuleFields {
+    pub content_token: SyntaxResult<SyntaxToken>,
+    pub semicolon_token: Option<SyntaxToken>,
 }
-impl AnyCssComposesImportSource {
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssComposesImportSource::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssComposesImportSource::CssString(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssDeclarationFields {
+        ScssDeclarationFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+            modifiers: self.modifiers(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssCompoundSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssCompoundSelector(CssCompoundSelector),
-}
-impl AnyCssCompoundSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
-        match &self {
-            AnyCssCompoundSelector::CssBogusSelector(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<ScssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
-        match &self {
-            AnyCssCompoundSelector::CssCompoundSelector(item) => Some(item),
-            _ => None,
-        }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssConditionalBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
-    CssRuleBlock(CssRuleBlock),
-}
-impl AnyCssConditionalBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssConditionalBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
-        match &self {
-            AnyCssConditionalBlock::CssDeclarationOrRuleBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn modifiers(&self) -> ScssVariableModifierList {
+        support::list(&self.syntax, 3usize)
     }
-    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
-        match &self {
-            AnyCssConditionalBlock::CssRuleBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 4usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerAndCombinableQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerAndQuery(CssContainerAndQuery),
-}
-impl AnyCssContainerAndCombinableQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
-        match &self {
-            AnyCssContainerAndCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
-        match &self {
-            AnyCssContainerAndCombinableQuery::CssContainerAndQuery(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerOrCombinableQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerOrQuery(CssContainerOrQuery),
+#[derive(Serialize)]
+pub struct ScssDeclarationFields {
+    pub name: SyntaxResult<ScssIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: CssGenericComponentValueList,
+    pub modifiers: ScssVariableModifierList,
+    pub semicolon_token: Option<SyntaxToken>,
 }
-impl AnyCssContainerOrCombinableQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
-        match &self {
-            AnyCssContainerOrCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssExtendAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssExtendAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
-        match &self {
-            AnyCssContainerOrCombinableQuery::CssContainerOrQuery(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssExtendAtRuleFields {
+        ScssExtendAtRuleFields {
+            extend_token: self.extend_token(),
+            selectors: self.selectors(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerAndQuery(CssContainerAndQuery),
-    CssContainerNotQuery(CssContainerNotQuery),
-    CssContainerOrQuery(CssContainerOrQuery),
-}
-impl AnyCssContainerQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
-        match &self {
-            AnyCssContainerQuery::AnyCssContainerQueryInParens(item) => Some(item),
-            _ => None,
-        }
+    pub fn extend_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
-        match &self {
-            AnyCssContainerQuery::CssContainerAndQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn selectors(&self) -> CssSelectorList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_container_not_query(&self) -> Option<&CssContainerNotQuery> {
-        match &self {
-            AnyCssContainerQuery::CssContainerNotQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 2usize)
     }
-    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
-        match &self {
-            AnyCssContainerQuery::CssContainerOrQuery(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssExtendAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerQueryInParens {
-    CssContainerQueryInParens(CssContainerQueryInParens),
-    CssContainerSizeFeatureInParens(CssContainerSizeFeatureInParens),
-    CssContainerStyleQueryInParens(CssContainerStyleQueryInParens),
+#[derive(Serialize)]
+pub struct ScssExtendAtRuleFields {
+    pub extend_token: SyntaxResult<SyntaxToken>,
+    pub selectors: CssSelectorList,
+    pub semicolon_token: Option<SyntaxToken>,
 }
-impl AnyCssContainerQueryInParens {
-    pub fn as_css_container_query_in_parens(&self) -> Option<&CssContainerQueryInParens> {
-        match &self {
-            AnyCssContainerQueryInParens::CssContainerQueryInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssForwardAsClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssForwardAsClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_container_size_feature_in_parens(
-        &self,
-    ) -> Option<&CssContainerSizeFeatureInParens> {
-        match &self {
-            AnyCssContainerQueryInParens::CssContainerSizeFeatureInParens(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssForwardAsClauseFields {
+        ScssForwardAsClauseFields {
+            as_token: self.as_token(),
+            prefix: self.prefix(),
+            star_token: self.star_token(),
         }
     }
-    pub fn as_css_container_style_query_in_parens(
-        &self,
-    ) -> Option<&CssContainerStyleQueryInParens> {
-        match &self {
-            AnyCssContainerQueryInParens::CssContainerStyleQueryInParens(item) => Some(item),
-            _ => None,
-        }
+    pub fn as_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerStyleAndCombinableQuery {
-    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
-    CssContainerStyleInParens(CssContainerStyleInParens),
-}
-impl AnyCssContainerStyleAndCombinableQuery {
-    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
-        match &self {
-            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleAndQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn prefix(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
-        match &self {
-            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleInParens(item) => Some(item),
-            _ => None,
-        }
+    pub fn star_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerStyleInParens {
-    AnyCssContainerStyleQuery(AnyCssContainerStyleQuery),
-    CssDeclaration(CssDeclaration),
-}
-impl AnyCssContainerStyleInParens {
-    pub fn as_any_css_container_style_query(&self) -> Option<&AnyCssContainerStyleQuery> {
-        match &self {
-            AnyCssContainerStyleInParens::AnyCssContainerStyleQuery(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
-        match &self {
-            AnyCssContainerStyleInParens::CssDeclaration(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssForwardAsClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerStyleOrCombinableQuery {
-    CssContainerStyleInParens(CssContainerStyleInParens),
-    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+#[derive(Serialize)]
+pub struct ScssForwardAsClauseFields {
+    pub as_token: SyntaxResult<SyntaxToken>,
+    pub prefix: SyntaxResult<CssIdentifier>,
+    pub star_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyCssContainerStyleOrCombinableQuery {
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
-        match &self {
-            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssForwardAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssForwardAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
-        match &self {
-            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleOrQuery(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssForwardAtRuleFields {
+        ScssForwardAtRuleFields {
+            forward_token: self.forward_token(),
+            url: self.url(),
+            as_clause: self.as_clause(),
+            visibility: self.visibility(),
+            configuration: self.configuration(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssContainerStyleQuery {
-    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
-    CssContainerStyleInParens(CssContainerStyleInParens),
-    CssContainerStyleNotQuery(CssContainerStyleNotQuery),
-    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
-    CssDeclaration(CssDeclaration),
-}
-impl AnyCssContainerStyleQuery {
-    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleAndQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn forward_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleInParens(item) => Some(item),
-            _ => None,
-        }
+    pub fn url(&self) -> SyntaxResult<CssString> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn as_css_container_style_not_query(&self) -> Option<&CssContainerStyleNotQuery> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleNotQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn as_clause(&self) -> Option<ScssForwardAsClause> {
+        support::node(&self.syntax, 2usize)
     }
-    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleOrQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn visibility(&self) -> Option<ScssForwardVisibilityClause> {
+        support::node(&self.syntax, 3usize)
     }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
-        match &self {
-            AnyCssContainerStyleQuery::CssDeclaration(item) => Some(item),
-            _ => None,
-        }
+    pub fn configuration(&self) -> Option<ScssModuleConfiguration> {
+        support::node(&self.syntax, 4usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssCustomIdentifier {
-    CssBogusCustomIdentifier(CssBogusCustomIdentifier),
-    CssCustomIdentifier(CssCustomIdentifier),
-}
-impl AnyCssCustomIdentifier {
-    pub fn as_css_bogus_custom_identifier(&self) -> Option<&CssBogusCustomIdentifier> {
-        match &self {
-            AnyCssCustomIdentifier::CssBogusCustomIdentifier(item) => Some(item),
-            _ => None,
-        }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 5usize)
     }
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
-        match &self {
-            AnyCssCustomIdentifier::CssCustomIdentifier(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssForwardAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationBlock(CssDeclarationBlock),
+#[derive(Serialize)]
+pub struct ScssForwardAtRuleFields {
+    pub forward_token: SyntaxResult<SyntaxToken>,
+    pub url: SyntaxResult<CssString>,
+    pub as_clause: Option<ScssForwardAsClause>,
+    pub visibility: Option<ScssForwardVisibilityClause>,
+    pub configuration: Option<ScssModuleConfiguration>,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyCssDeclarationBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssDeclarationBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssForwardVisibilityClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssForwardVisibilityClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
-        match &self {
-            AnyCssDeclarationBlock::CssDeclarationBlock(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssForwardVisibilityClauseFields {
+        ScssForwardVisibilityClauseFields {
+            modifier: self.modifier(),
+            members: self.members(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationName {
-    CssDashedIdentifier(CssDashedIdentifier),
-    CssIdentifier(CssIdentifier),
-}
-impl AnyCssDeclarationName {
-    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
-        match &self {
-            AnyCssDeclarationName::CssDashedIdentifier(item) => Some(item),
-            _ => None,
-        }
+    pub fn modifier(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssDeclarationName::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
+    pub fn members(&self) -> ScssForwardMemberList {
+        support::list(&self.syntax, 1usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationOrAtRule {
-    CssAtRule(CssAtRule),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
-}
-impl AnyCssDeclarationOrAtRule {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
-        match &self {
-            AnyCssDeclarationOrAtRule::CssAtRule(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
-        match &self {
-            AnyCssDeclarationOrAtRule::CssDeclarationWithSemicolon(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssForwardVisibilityClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationOrAtRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationOrAtRuleBlock(CssDeclarationOrAtRuleBlock),
+#[derive(Serialize)]
+pub struct ScssForwardVisibilityClauseFields {
+    pub modifier: SyntaxResult<SyntaxToken>,
+    pub members: ScssForwardMemberList,
 }
-impl AnyCssDeclarationOrAtRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssDeclarationOrAtRuleBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssIdentifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssIdentifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_declaration_or_at_rule_block(&self) -> Option<&CssDeclarationOrAtRuleBlock> {
-        match &self {
-            AnyCssDeclarationOrAtRuleBlock::CssDeclarationOrAtRuleBlock(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssIdentifierFields {
+        ScssIdentifierFields {
+            dollar_token: self.dollar_token(),
+            name: self.name(),
         }
     }
+    pub fn dollar_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationOrRule {
-    AnyCssRule(AnyCssRule),
-    CssBogus(CssBogus),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
-    CssMetavariable(CssMetavariable),
+impl Serialize for ScssIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyCssDeclarationOrRule {
-    pub fn as_any_css_rule(&self) -> Option<&AnyCssRule> {
-        match &self {
-            AnyCssDeclarationOrRule::AnyCssRule(item) => Some(item),
-            _ => None,
-        }
+#[derive(Serialize)]
+pub struct ScssIdentifierFields {
+    pub dollar_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssIncludeAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssIncludeAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_bogus(&self) -> Option<&CssBogus> {
-        match &self {
-            AnyCssDeclarationOrRule::CssBogus(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssIncludeAtRuleFields {
+        ScssIncludeAtRuleFields {
+            include_token: self.include_token(),
+            name: self.name(),
+            arguments: self.arguments(),
+            block: self.block(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
-        match &self {
-            AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(item) => Some(item),
-            _ => None,
-        }
+    pub fn include_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
-        match &self {
-            AnyCssDeclarationOrRule::CssMetavariable(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn arguments(&self) -> Option<ScssArguments> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn block(&self) -> Option<AnyCssDeclarationOrRuleBlock> {
+        support::node(&self.syntax, 3usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 4usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDeclarationOrRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
-}
-impl AnyCssDeclarationOrRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssDeclarationOrRuleBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
-        match &self {
-            AnyCssDeclarationOrRuleBlock::CssDeclarationOrRuleBlock(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssIncludeAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDimension {
-    CssPercentage(CssPercentage),
-    CssRegularDimension(CssRegularDimension),
-    CssUnknownDimension(CssUnknownDimension),
+#[derive(Serialize)]
+pub struct ScssIncludeAtRuleFields {
+    pub include_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+    pub arguments: Option<ScssArguments>,
+    pub block: Option<AnyCssDeclarationOrRuleBlock>,
+    pub semicolon_token: Option<SyntaxToken>,
 }
-impl AnyCssDimension {
-    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
-        match &self {
-            AnyCssDimension::CssPercentage(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssInterpolatedIdentifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssInterpolatedIdentifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_regular_dimension(&self) -> Option<&CssRegularDimension> {
-        match &self {
-            AnyCssDimension::CssRegularDimension(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssInterpolatedIdentifierFields {
+        ScssInterpolatedIdentifierFields {
+            items: self.items(),
         }
     }
-    pub fn as_css_unknown_dimension(&self) -> Option<&CssUnknownDimension> {
-        match &self {
-            AnyCssDimension::CssUnknownDimension(item) => Some(item),
-            _ => None,
-        }
+    pub fn items(&self) -> ScssInterpolatedIdentifierItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssDocumentMatcher {
-    CssBogusDocumentMatcher(CssBogusDocumentMatcher),
-    CssDocumentCustomMatcher(CssDocumentCustomMatcher),
-    CssUrlFunction(CssUrlFunction),
+impl Serialize for ScssInterpolatedIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyCssDocumentMatcher {
-    pub fn as_css_bogus_document_matcher(&self) -> Option<&CssBogusDocumentMatcher> {
-        match &self {
-            AnyCssDocumentMatcher::CssBogusDocumentMatcher(item) => Some(item),
-            _ => None,
-        }
+#[derive(Serialize)]
+pub struct ScssInterpolatedIdentifierFields {
+    pub items: ScssInterpolatedIdentifierItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssInterpolation {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssInterpolation {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_document_custom_matcher(&self) -> Option<&CssDocumentCustomMatcher> {
-        match &self {
-            AnyCssDocumentMatcher::CssDocumentCustomMatcher(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssInterpolationFields {
+        ScssInterpolationFields {
+            hash_l_curly_token: self.hash_l_curly_token(),
+            value: self.value(),
+            r_curly_token: self.r_curly_token(),
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
-        match &self {
-            AnyCssDocumentMatcher::CssUrlFunction(item) => Some(item),
-            _ => None,
-        }
+    pub fn hash_l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssExpression {
-    CssBinaryExpression(CssBinaryExpression),
-    CssListOfComponentValuesExpression(CssListOfComponentValuesExpression),
-    CssParenthesizedExpression(CssParenthesizedExpression),
-}
-impl AnyCssExpression {
-    pub fn as_css_binary_expression(&self) -> Option<&CssBinaryExpression> {
-        match &self {
-            AnyCssExpression::CssBinaryExpression(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_list_of_component_values_expression(
-        &self,
-    ) -> Option<&CssListOfComponentValuesExpression> {
-        match &self {
-            AnyCssExpression::CssListOfComponentValuesExpression(item) => Some(item),
-            _ => None,
-        }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
-    pub fn as_css_parenthesized_expression(&self) -> Option<&CssParenthesizedExpression> {
-        match &self {
-            AnyCssExpression::CssParenthesizedExpression(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssInterpolation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssFontFamilyName {
-    CssBogusFontFamilyName(CssBogusFontFamilyName),
-    CssFontFamilyName(CssFontFamilyName),
-    CssString(CssString),
+#[derive(Serialize)]
+pub struct ScssInterpolationFields {
+    pub hash_l_curly_token: SyntaxResult<SyntaxToken>,
+    pub value: CssGenericComponentValueList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyCssFontFamilyName {
-    pub fn as_css_bogus_font_family_name(&self) -> Option<&CssBogusFontFamilyName> {
-        match &self {
-            AnyCssFontFamilyName::CssBogusFontFamilyName(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssMapExpression {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssMapExpression {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_font_family_name(&self) -> Option<&CssFontFamilyName> {
-        match &self {
-            AnyCssFontFamilyName::CssFontFamilyName(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssMapExpressionFields {
+        ScssMapExpressionFields {
+            l_paren_token: self.l_paren_token(),
+            pairs: self.pairs(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssFontFamilyName::CssString(item) => Some(item),
-            _ => None,
-        }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssFontFeatureValuesBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssFontFeatureValuesBlock(CssFontFeatureValuesBlock),
-}
-impl AnyCssFontFeatureValuesBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssFontFeatureValuesBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn pairs(&self) -> ScssMapExpressionPairList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_font_feature_values_block(&self) -> Option<&CssFontFeatureValuesBlock> {
-        match &self {
-            AnyCssFontFeatureValuesBlock::CssFontFeatureValuesBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssFontFeatureValuesItem {
-    CssBogusFontFeatureValuesItem(CssBogusFontFeatureValuesItem),
-    CssFontFeatureValuesItem(CssFontFeatureValuesItem),
-}
-impl AnyCssFontFeatureValuesItem {
-    pub fn as_css_bogus_font_feature_values_item(&self) -> Option<&CssBogusFontFeatureValuesItem> {
-        match &self {
-            AnyCssFontFeatureValuesItem::CssBogusFontFeatureValuesItem(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_font_feature_values_item(&self) -> Option<&CssFontFeatureValuesItem> {
-        match &self {
-            AnyCssFontFeatureValuesItem::CssFontFeatureValuesItem(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssMapExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssFunction {
-    CssFunction(CssFunction),
-    CssUrlFunction(CssUrlFunction),
+#[derive(Serialize)]
+pub struct ScssMapExpressionFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub pairs: ScssMapExpressionPairList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyCssFunction {
-    pub fn as_css_function(&self) -> Option<&CssFunction> {
-        match &self {
-            AnyCssFunction::CssFunction(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssMapExpressionPair {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssMapExpressionPair {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
-        match &self {
-            AnyCssFunction::CssUrlFunction(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssMapExpressionPairFields {
+        ScssMapExpressionPairFields {
+            key: self.key(),
+            colon_token: self.colon_token(),
+            value: self.value(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssGenericComponentValue {
-    AnyCssValue(AnyCssValue),
-    CssGenericDelimiter(CssGenericDelimiter),
-}
-impl AnyCssGenericComponentValue {
-    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
-        match &self {
-            AnyCssGenericComponentValue::AnyCssValue(item) => Some(item),
-            _ => None,
-        }
+    pub fn key(&self) -> SyntaxResult<AnyCssValue> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_css_generic_delimiter(&self) -> Option<&CssGenericDelimiter> {
-        match &self {
-            AnyCssGenericComponentValue::CssGenericDelimiter(item) => Some(item),
-            _ => None,
-        }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssImportLayer {
-    CssImportAnonymousLayer(CssImportAnonymousLayer),
-    CssImportNamedLayer(CssImportNamedLayer),
-}
-impl AnyCssImportLayer {
-    pub fn as_css_import_anonymous_layer(&self) -> Option<&CssImportAnonymousLayer> {
-        match &self {
-            AnyCssImportLayer::CssImportAnonymousLayer(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> CssComponentValueList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn as_css_import_named_layer(&self) -> Option<&CssImportNamedLayer> {
-        match &self {
-            AnyCssImportLayer::CssImportNamedLayer(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssMapExpressionPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssImportSupportsCondition {
-    AnyCssSupportsCondition(AnyCssSupportsCondition),
-    CssDeclaration(CssDeclaration),
+#[derive(Serialize)]
+pub struct ScssMapExpressionPairFields {
+    pub key: SyntaxResult<AnyCssValue>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: CssComponentValueList,
 }
-impl AnyCssImportSupportsCondition {
-    pub fn as_any_css_supports_condition(&self) -> Option<&AnyCssSupportsCondition> {
-        match &self {
-            AnyCssImportSupportsCondition::AnyCssSupportsCondition(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssMixinAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssMixinAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
-        match &self {
-            AnyCssImportSupportsCondition::CssDeclaration(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssMixinAtRuleFields {
+        ScssMixinAtRuleFields {
+            mixin_token: self.mixin_token(),
+            name: self.name(),
+            parameters: self.parameters(),
+            block: self.block(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssImportUrl {
-    CssString(CssString),
-    CssUrlFunction(CssUrlFunction),
-}
-impl AnyCssImportUrl {
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssImportUrl::CssString(item) => Some(item),
-            _ => None,
-        }
+    pub fn mixin_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
-        match &self {
-            AnyCssImportUrl::CssUrlFunction(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssKeyframesBlock(CssKeyframesBlock),
-}
-impl AnyCssKeyframesBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssKeyframesBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn parameters(&self) -> Option<ScssParameters> {
+        support::node(&self.syntax, 2usize)
     }
-    pub fn as_css_keyframes_block(&self) -> Option<&CssKeyframesBlock> {
-        match &self {
-            AnyCssKeyframesBlock::CssKeyframesBlock(item) => Some(item),
-            _ => None,
-        }
+    pub fn block(&self) -> SyntaxResult<AnyCssDeclarationOrRuleBlock> {
+        support::required_node(&self.syntax, 3usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesIdentifier {
-    CssCustomIdentifier(CssCustomIdentifier),
-    CssString(CssString),
-}
-impl AnyCssKeyframesIdentifier {
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
-        match &self {
-            AnyCssKeyframesIdentifier::CssCustomIdentifier(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssKeyframesIdentifier::CssString(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssMixinAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesItem {
-    CssBogusKeyframesItem(CssBogusKeyframesItem),
-    CssKeyframesItem(CssKeyframesItem),
+#[derive(Serialize)]
+pub struct ScssMixinAtRuleFields {
+    pub mixin_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+    pub parameters: Option<ScssParameters>,
+    pub block: SyntaxResult<AnyCssDeclarationOrRuleBlock>,
 }
-impl AnyCssKeyframesItem {
-    pub fn as_css_bogus_keyframes_item(&self) -> Option<&CssBogusKeyframesItem> {
-        match &self {
-            AnyCssKeyframesItem::CssBogusKeyframesItem(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssModuleConfiguration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssModuleConfiguration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_keyframes_item(&self) -> Option<&CssKeyframesItem> {
-        match &self {
-            AnyCssKeyframesItem::CssKeyframesItem(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssModuleConfigurationFields {
+        ScssModuleConfigurationFields {
+            with_token: self.with_token(),
+            value: self.value(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesName {
-    AnyCssKeyframesIdentifier(AnyCssKeyframesIdentifier),
-    CssBogusKeyframesName(CssBogusKeyframesName),
-    CssKeyframesScopedName(CssKeyframesScopedName),
-}
-impl AnyCssKeyframesName {
-    pub fn as_any_css_keyframes_identifier(&self) -> Option<&AnyCssKeyframesIdentifier> {
-        match &self {
-            AnyCssKeyframesName::AnyCssKeyframesIdentifier(item) => Some(item),
-            _ => None,
-        }
+    pub fn with_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_bogus_keyframes_name(&self) -> Option<&CssBogusKeyframesName> {
-        match &self {
-            AnyCssKeyframesName::CssBogusKeyframesName(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> SyntaxResult<ScssMapExpression> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn as_css_keyframes_scoped_name(&self) -> Option<&CssKeyframesScopedName> {
-        match &self {
-            AnyCssKeyframesName::CssKeyframesScopedName(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssModuleConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesScope {
-    CssKeyframesScopeFunction(CssKeyframesScopeFunction),
-    CssKeyframesScopePrefix(CssKeyframesScopePrefix),
+#[derive(Serialize)]
+pub struct ScssModuleConfigurationFields {
+    pub with_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<ScssMapExpression>,
 }
-impl AnyCssKeyframesScope {
-    pub fn as_css_keyframes_scope_function(&self) -> Option<&CssKeyframesScopeFunction> {
-        match &self {
-            AnyCssKeyframesScope::CssKeyframesScopeFunction(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssNestingDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssNestingDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_keyframes_scope_prefix(&self) -> Option<&CssKeyframesScopePrefix> {
-        match &self {
-            AnyCssKeyframesScope::CssKeyframesScopePrefix(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssNestingDeclarationFields {
+        ScssNestingDeclarationFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            block: self.block(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssKeyframesSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssKeyframesIdentSelector(CssKeyframesIdentSelector),
-    CssKeyframesPercentageSelector(CssKeyframesPercentageSelector),
-}
-impl AnyCssKeyframesSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
-        match &self {
-            AnyCssKeyframesSelector::CssBogusSelector(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_css_keyframes_ident_selector(&self) -> Option<&CssKeyframesIdentSelector> {
-        match &self {
-            AnyCssKeyframesSelector::CssKeyframesIdentSelector(item) => Some(item),
-            _ => None,
-        }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-    pub fn as_css_keyframes_percentage_selector(&self) -> Option<&CssKeyframesPercentageSelector> {
-        match &self {
-            AnyCssKeyframesSelector::CssKeyframesPercentageSelector(item) => Some(item),
-            _ => None,
-        }
+    pub fn block(&self) -> SyntaxResult<AnyCssDeclarationOrRuleBlock> {
+        support::required_node(&self.syntax, 2usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssLayer {
-    CssBogusLayer(CssBogusLayer),
-    CssLayerDeclaration(CssLayerDeclaration),
-    CssLayerReference(CssLayerReference),
-}
-impl AnyCssLayer {
-    pub fn as_css_bogus_layer(&self) -> Option<&CssBogusLayer> {
-        match &self {
-            AnyCssLayer::CssBogusLayer(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_layer_declaration(&self) -> Option<&CssLayerDeclaration> {
-        match &self {
-            AnyCssLayer::CssLayerDeclaration(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_layer_reference(&self) -> Option<&CssLayerReference> {
-        match &self {
-            AnyCssLayer::CssLayerReference(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssNestingDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaAndCombinableCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
+#[derive(Serialize)]
+pub struct ScssNestingDeclarationFields {
+    pub name: SyntaxResult<CssIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub block: SyntaxResult<AnyCssDeclarationOrRuleBlock>,
 }
-impl AnyCssMediaAndCombinableCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
-        match &self {
-            AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssParameter {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssParameter {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
-        match &self {
-            AnyCssMediaAndCombinableCondition::CssMediaAndCondition(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssParameterFields {
+        ScssParameterFields {
+            name: self.name(),
+            default_value: self.default_value(),
         }
     }
+    pub fn name(&self) -> SyntaxResult<ScssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn default_value(&self) -> Option<ScssParameterDefaultValue> {
+        support::node(&self.syntax, 1usize)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
-    CssMediaNotCondition(CssMediaNotCondition),
-    CssMediaOrCondition(CssMediaOrCondition),
+impl Serialize for ScssParameter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyCssMediaCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
-        match &self {
-            AnyCssMediaCondition::AnyCssMediaInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Serialize)]
+pub struct ScssParameterFields {
+    pub name: SyntaxResult<ScssIdentifier>,
+    pub default_value: Option<ScssParameterDefaultValue>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssParameterDefaultValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssParameterDefaultValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
-        match &self {
-            AnyCssMediaCondition::CssMediaAndCondition(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssParameterDefaultValueFields {
+        ScssParameterDefaultValueFields {
+            colon_token: self.colon_token(),
+            value: self.value(),
         }
     }
-    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
-        match &self {
-            AnyCssMediaCondition::CssMediaNotCondition(item) => Some(item),
-            _ => None,
-        }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
-        match &self {
-            AnyCssMediaCondition::CssMediaOrCondition(item) => Some(item),
-            _ => None,
-        }
+    pub fn value(&self) -> CssComponentValueList {
+        support::list(&self.syntax, 1usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaInParens {
-    CssMediaConditionInParens(CssMediaConditionInParens),
-    CssMediaFeatureInParens(CssMediaFeatureInParens),
-}
-impl AnyCssMediaInParens {
-    pub fn as_css_media_condition_in_parens(&self) -> Option<&CssMediaConditionInParens> {
-        match &self {
-            AnyCssMediaInParens::CssMediaConditionInParens(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_media_feature_in_parens(&self) -> Option<&CssMediaFeatureInParens> {
-        match &self {
-            AnyCssMediaInParens::CssMediaFeatureInParens(item) => Some(item),
-            _ => None,
-        }
+impl Serialize for ScssParameterDefaultValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaOrCombinableCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaOrCondition(CssMediaOrCondition),
+#[derive(Serialize)]
+pub struct ScssParameterDefaultValueFields {
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: CssComponentValueList,
 }
-impl AnyCssMediaOrCombinableCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
-        match &self {
-            AnyCssMediaOrCombinableCondition::AnyCssMediaInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssParameters {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssParameters {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
-        match &self {
-            AnyCssMediaOrCombinableCondition::CssMediaOrCondition(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssParametersFields {
+        ScssParametersFields {
+            l_paren_token: self.l_paren_token(),
+            items: self.items(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaQuery {
-    AnyCssMediaTypeQuery(AnyCssMediaTypeQuery),
-    CssBogusMediaQuery(CssBogusMediaQuery),
-    CssMediaConditionQuery(CssMediaConditionQuery),
-    CssMetavariable(CssMetavariable),
-}
-impl AnyCssMediaQuery {
-    pub fn as_any_css_media_type_query(&self) -> Option<&AnyCssMediaTypeQuery> {
-        match &self {
-            AnyCssMediaQuery::AnyCssMediaTypeQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn as_css_bogus_media_query(&self) -> Option<&CssBogusMediaQuery> {
-        match &self {
-            AnyCssMediaQuery::CssBogusMediaQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn items(&self) -> ScssParameterList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_css_media_condition_query(&self) -> Option<&CssMediaConditionQuery> {
-        match &self {
-            AnyCssMediaQuery::CssMediaConditionQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
-    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
-        match &self {
-            AnyCssMediaQuery::CssMetavariable(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssParameters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaTypeCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
-    CssMediaNotCondition(CssMediaNotCondition),
+#[derive(Serialize)]
+pub struct ScssParametersFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub items: ScssParameterList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyCssMediaTypeCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
-        match &self {
-            AnyCssMediaTypeCondition::AnyCssMediaInParens(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssPlaceholderSelector {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssPlaceholderSelector {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
-        match &self {
-            AnyCssMediaTypeCondition::CssMediaAndCondition(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssPlaceholderSelectorFields {
+        ScssPlaceholderSelectorFields {
+            percent_token: self.percent_token(),
+            name: self.name(),
         }
     }
-    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
-        match &self {
-            AnyCssMediaTypeCondition::CssMediaNotCondition(item) => Some(item),
-            _ => None,
-        }
+    pub fn percent_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssMediaTypeQuery {
-    CssMediaAndTypeQuery(CssMediaAndTypeQuery),
-    CssMediaTypeQuery(CssMediaTypeQuery),
-}
-impl AnyCssMediaTypeQuery {
-    pub fn as_css_media_and_type_query(&self) -> Option<&CssMediaAndTypeQuery> {
-        match &self {
-            AnyCssMediaTypeQuery::CssMediaAndTypeQuery(item) => Some(item),
-            _ => None,
-        }
+    pub fn name(&self) -> SyntaxResult<AnyCssSelectorName> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn as_css_media_type_query(&self) -> Option<&CssMediaTypeQuery> {
-        match &self {
-            AnyCssMediaTypeQuery::CssMediaTypeQuery(item) => Some(item),
-            _ => None,
-        }
+}
+impl Serialize for ScssPlaceholderSelector {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssNamespacePrefix {
-    CssNamedNamespacePrefix(CssNamedNamespacePrefix),
-    CssUniversalNamespacePrefix(CssUniversalNamespacePrefix),
+#[derive(Serialize)]
+pub struct ScssPlaceholderSelectorFields {
+    pub percent_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<AnyCssSelectorName>,
 }
-impl AnyCssNamespacePrefix {
-    pub fn as_css_named_namespace_prefix(&self) -> Option<&CssNamedNamespacePrefix> {
-        match &self {
-            AnyCssNamespacePrefix::CssNamedNamespacePrefix(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssQualifiedName {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssQualifiedName {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_css_universal_namespace_prefix(&self) -> Option<&CssUniversalNamespacePrefix> {
-        match &self {
-            AnyCssNamespacePrefix::CssUniversalNamespacePrefix(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> ScssQualifiedNameFields {
+        ScssQualifiedNameFields {
+            module: self.module(),
+            dot_token: self.dot_token(),
+            member: self.member(),
         }
     }
+    pub fn module(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn dot_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn member(&self) -> SyntaxResult<AnyScssModuleMember> {
+        support::required_node(&self.syntax, 2usize)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssNamespaceUrl {
-    CssString(CssString),
-    CssUrlFunction(CssUrlFunction),
+impl Serialize for ScssQualifiedName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyCssNamespaceUrl {
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssNamespaceUrl::CssString(item) => Some(item),
-            _ => None,
+#[derive(Serialize)]
+pub struct ScssQualifiedNameFields {
+    pub module: SyntaxResult<CssIdentifier>,
+    pub dot_token: SyntaxResult<SyntaxToken>,
+    pub member: SyntaxResult<AnyScssModuleMember>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssUseAllNamespace {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssUseAllNamespace {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssUseAllNamespaceFields {
+        ScssUseAllNamespaceFields {
+            star_token: self.star_token(),
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
-        match &self {
-            AnyCssNamespaceUrl::CssUrlFunction(item) => Some(item),
-            _ => None,
+    pub fn star_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for ScssUseAllNamespace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct ScssUseAllNamespaceFields {
+    pub star_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssUseAsClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssUseAsClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssUseAsClauseFields {
+        ScssUseAsClauseFields {
+            as_token: self.as_token(),
+            namespace: self.namespace(),
         }
     }
+    pub fn as_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn namespace(&self) -> SyntaxResult<AnyScssUseNamespace> {
+        support::required_node(&self.syntax, 1usize)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPageAtRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssPageAtRuleBlock(CssPageAtRuleBlock),
+impl Serialize for ScssUseAsClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyCssPageAtRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssPageAtRuleBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
+#[derive(Serialize)]
+pub struct ScssUseAsClauseFields {
+    pub as_token: SyntaxResult<SyntaxToken>,
+    pub namespace: SyntaxResult<AnyScssUseNamespace>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssUseAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssUseAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssUseAtRuleFields {
+        ScssUseAtRuleFields {
+            use_token: self.use_token(),
+            url: self.url(),
+            as_clause: self.as_clause(),
+            configuration: self.configuration(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-    pub fn as_css_page_at_rule_block(&self) -> Option<&CssPageAtRuleBlock> {
-        match &self {
-            AnyCssPageAtRuleBlock::CssPageAtRuleBlock(item) => Some(item),
-            _ => None,
+    pub fn use_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn url(&self) -> SyntaxResult<CssString> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn as_clause(&self) -> Option<ScssUseAsClause> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn configuration(&self) -> Option<ScssModuleConfiguration> {
+        support::node(&self.syntax, 3usize)
+    }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
+    }
+}
+impl Serialize for ScssUseAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct ScssUseAtRuleFields {
+    pub use_token: SyntaxResult<SyntaxToken>,
+    pub url: SyntaxResult<CssString>,
+    pub as_clause: Option<ScssUseAsClause>,
+    pub configuration: Option<ScssModuleConfiguration>,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssVariableModifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssVariableModifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssVariableModifierFields {
+        ScssVariableModifierFields {
+            excl_token: self.excl_token(),
+            value: self.value(),
         }
     }
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for ScssVariableModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct ScssVariableModifierFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPageAtRuleItem {
-    CssAtRule(CssAtRule),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
-    CssMarginAtRule(CssMarginAtRule),
+pub enum AnyCssAtRule {
+    CssBogusAtRule(CssBogusAtRule),
+    CssCharsetAtRule(CssCharsetAtRule),
+    CssColorProfileAtRule(CssColorProfileAtRule),
+    CssContainerAtRule(CssContainerAtRule),
+    CssCounterStyleAtRule(CssCounterStyleAtRule),
+    CssDocumentAtRule(CssDocumentAtRule),
+    CssFontFaceAtRule(CssFontFaceAtRule),
+    CssFontFeatureValuesAtRule(CssFontFeatureValuesAtRule),
+    CssFontPaletteValuesAtRule(CssFontPaletteValuesAtRule),
+    CssImportAtRule(CssImportAtRule),
+    CssKeyframesAtRule(CssKeyframesAtRule),
+    CssLayerAtRule(CssLayerAtRule),
+    CssMediaAtRule(CssMediaAtRule),
+    CssNamespaceAtRule(CssNamespaceAtRule),
+    CssPageAtRule(CssPageAtRule),
+    CssPropertyAtRule(CssPropertyAtRule),
+    CssScopeAtRule(CssScopeAtRule),
+    CssStartingStyleAtRule(CssStartingStyleAtRule),
+    CssSupportsAtRule(CssSupportsAtRule),
+    CssUnknownBlockAtRule(CssUnknownBlockAtRule),
+    CssUnknownValueAtRule(CssUnknownValueAtRule),
+    CssValueAtRule(CssValueAtRule),
+    ScssContentAtRule(ScssContentAtRule),
+    ScssExtendAtRule(ScssExtendAtRule),
+    ScssForwardAtRule(ScssForwardAtRule),
+    ScssIncludeAtRule(ScssIncludeAtRule),
+    ScssMixinAtRule(ScssMixinAtRule),
+    ScssUseAtRule(ScssUseAtRule),
 }
-impl AnyCssPageAtRuleItem {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+impl AnyCssAtRule {
+    pub fn as_css_bogus_at_rule(&self) -> Option<&CssBogusAtRule> {
         match &self {
-            AnyCssPageAtRuleItem::CssAtRule(item) => Some(item),
+            AnyCssAtRule::CssBogusAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+    pub fn as_css_charset_at_rule(&self) -> Option<&CssCharsetAtRule> {
         match &self {
-            AnyCssPageAtRuleItem::CssDeclarationWithSemicolon(item) => Some(item),
+            AnyCssAtRule::CssCharsetAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_margin_at_rule(&self) -> Option<&CssMarginAtRule> {
+    pub fn as_css_color_profile_at_rule(&self) -> Option<&CssColorProfileAtRule> {
         match &self {
-            AnyCssPageAtRuleItem::CssMarginAtRule(item) => Some(item),
+            AnyCssAtRule::CssColorProfileAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPageSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssPageSelector(CssPageSelector),
-}
-impl AnyCssPageSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+    pub fn as_css_container_at_rule(&self) -> Option<&CssContainerAtRule> {
         match &self {
-            AnyCssPageSelector::CssBogusSelector(item) => Some(item),
+            AnyCssAtRule::CssContainerAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_selector(&self) -> Option<&CssPageSelector> {
+    pub fn as_css_counter_style_at_rule(&self) -> Option<&CssCounterStyleAtRule> {
         match &self {
-            AnyCssPageSelector::CssPageSelector(item) => Some(item),
+            AnyCssAtRule::CssCounterStyleAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPageSelectorPseudo {
-    CssBogusPageSelectorPseudo(CssBogusPageSelectorPseudo),
-    CssPageSelectorPseudo(CssPageSelectorPseudo),
-}
-impl AnyCssPageSelectorPseudo {
-    pub fn as_css_bogus_page_selector_pseudo(&self) -> Option<&CssBogusPageSelectorPseudo> {
+    pub fn as_css_document_at_rule(&self) -> Option<&CssDocumentAtRule> {
         match &self {
-            AnyCssPageSelectorPseudo::CssBogusPageSelectorPseudo(item) => Some(item),
+            AnyCssAtRule::CssDocumentAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_selector_pseudo(&self) -> Option<&CssPageSelectorPseudo> {
+    pub fn as_css_font_face_at_rule(&self) -> Option<&CssFontFaceAtRule> {
         match &self {
-            AnyCssPageSelectorPseudo::CssPageSelectorPseudo(item) => Some(item),
+            AnyCssAtRule::CssFontFaceAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssProperty {
-    CssBogusProperty(CssBogusProperty),
-    CssComposesProperty(CssComposesProperty),
-    CssGenericProperty(CssGenericProperty),
-}
-impl AnyCssProperty {
-    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
+    pub fn as_css_font_feature_values_at_rule(&self) -> Option<&CssFontFeatureValuesAtRule> {
         match &self {
-            AnyCssProperty::CssBogusProperty(item) => Some(item),
+            AnyCssAtRule::CssFontFeatureValuesAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_composes_property(&self) -> Option<&CssComposesProperty> {
+    pub fn as_css_font_palette_values_at_rule(&self) -> Option<&CssFontPaletteValuesAtRule> {
         match &self {
-            AnyCssProperty::CssComposesProperty(item) => Some(item),
+            AnyCssAtRule::CssFontPaletteValuesAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_generic_property(&self) -> Option<&CssGenericProperty> {
+    pub fn as_css_import_at_rule(&self) -> Option<&CssImportAtRule> {
         match &self {
-            AnyCssProperty::CssGenericProperty(item) => Some(item),
+            AnyCssAtRule::CssImportAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPseudoClass {
-    CssBogusPseudoClass(CssBogusPseudoClass),
-    CssPseudoClassFunctionCompoundSelector(CssPseudoClassFunctionCompoundSelector),
-    CssPseudoClassFunctionCompoundSelectorList(CssPseudoClassFunctionCompoundSelectorList),
-    CssPseudoClassFunctionIdentifier(CssPseudoClassFunctionIdentifier),
-    CssPseudoClassFunctionNth(CssPseudoClassFunctionNth),
-    CssPseudoClassFunctionRelativeSelectorList(CssPseudoClassFunctionRelativeSelectorList),
-    CssPseudoClassFunctionSelector(CssPseudoClassFunctionSelector),
-    CssPseudoClassFunctionSelectorList(CssPseudoClassFunctionSelectorList),
-    CssPseudoClassFunctionValueList(CssPseudoClassFunctionValueList),
-    CssPseudoClassIdentifier(CssPseudoClassIdentifier),
-}
-impl AnyCssPseudoClass {
-    pub fn as_css_bogus_pseudo_class(&self) -> Option<&CssBogusPseudoClass> {
+    pub fn as_css_keyframes_at_rule(&self) -> Option<&CssKeyframesAtRule> {
         match &self {
-            AnyCssPseudoClass::CssBogusPseudoClass(item) => Some(item),
+            AnyCssAtRule::CssKeyframesAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_compound_selector(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionCompoundSelector> {
+    pub fn as_css_layer_at_rule(&self) -> Option<&CssLayerAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelector(item) => Some(item),
+            AnyCssAtRule::CssLayerAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_compound_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionCompoundSelectorList> {
+    pub fn as_css_media_at_rule(&self) -> Option<&CssMediaAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelectorList(item) => Some(item),
+            AnyCssAtRule::CssMediaAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_identifier(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionIdentifier> {
+    pub fn as_css_namespace_at_rule(&self) -> Option<&CssNamespaceAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionIdentifier(item) => Some(item),
+            AnyCssAtRule::CssNamespaceAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_nth(&self) -> Option<&CssPseudoClassFunctionNth> {
+    pub fn as_css_page_at_rule(&self) -> Option<&CssPageAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionNth(item) => Some(item),
+            AnyCssAtRule::CssPageAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_relative_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionRelativeSelectorList> {
+    pub fn as_css_property_at_rule(&self) -> Option<&CssPropertyAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionRelativeSelectorList(item) => Some(item),
+            AnyCssAtRule::CssPropertyAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_selector(&self) -> Option<&CssPseudoClassFunctionSelector> {
+    pub fn as_css_scope_at_rule(&self) -> Option<&CssScopeAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionSelector(item) => Some(item),
+            AnyCssAtRule::CssScopeAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionSelectorList> {
+    pub fn as_css_starting_style_at_rule(&self) -> Option<&CssStartingStyleAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionSelectorList(item) => Some(item),
+            AnyCssAtRule::CssStartingStyleAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_value_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionValueList> {
+    pub fn as_css_supports_at_rule(&self) -> Option<&CssSupportsAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionValueList(item) => Some(item),
+            AnyCssAtRule::CssSupportsAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_identifier(&self) -> Option<&CssPseudoClassIdentifier> {
+    pub fn as_css_unknown_block_at_rule(&self) -> Option<&CssUnknownBlockAtRule> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassIdentifier(item) => Some(item),
+            AnyCssAtRule::CssUnknownBlockAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPseudoClassNth {
-    CssPseudoClassNth(CssPseudoClassNth),
-    CssPseudoClassNthIdentifier(CssPseudoClassNthIdentifier),
-    CssPseudoClassNthNumber(CssPseudoClassNthNumber),
-}
-impl AnyCssPseudoClassNth {
-    pub fn as_css_pseudo_class_nth(&self) -> Option<&CssPseudoClassNth> {
+    pub fn as_css_unknown_value_at_rule(&self) -> Option<&CssUnknownValueAtRule> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNth(item) => Some(item),
+            AnyCssAtRule::CssUnknownValueAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_identifier(&self) -> Option<&CssPseudoClassNthIdentifier> {
+    pub fn as_css_value_at_rule(&self) -> Option<&CssValueAtRule> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNthIdentifier(item) => Some(item),
+            AnyCssAtRule::CssValueAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_number(&self) -> Option<&CssPseudoClassNthNumber> {
+    pub fn as_scss_content_at_rule(&self) -> Option<&ScssContentAtRule> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNthNumber(item) => Some(item),
+            AnyCssAtRule::ScssContentAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPseudoClassNthSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssPseudoClassNthSelector(CssPseudoClassNthSelector),
-}
-impl AnyCssPseudoClassNthSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+    pub fn as_scss_extend_at_rule(&self) -> Option<&ScssExtendAtRule> {
         match &self {
-            AnyCssPseudoClassNthSelector::CssBogusSelector(item) => Some(item),
+            AnyCssAtRule::ScssExtendAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_selector(&self) -> Option<&CssPseudoClassNthSelector> {
+    pub fn as_scss_forward_at_rule(&self) -> Option<&ScssForwardAtRule> {
         match &self {
-            AnyCssPseudoClassNthSelector::CssPseudoClassNthSelector(item) => Some(item),
+            AnyCssAtRule::ScssForwardAtRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPseudoElement {
-    CssBogusPseudoElement(CssBogusPseudoElement),
-    CssPseudoElementFunctionIdentifier(CssPseudoElementFunctionIdentifier),
-    CssPseudoElementFunctionSelector(CssPseudoElementFunctionSelector),
-    CssPseudoElementIdentifier(CssPseudoElementIdentifier),
-}
-impl AnyCssPseudoElement {
-    pub fn as_css_bogus_pseudo_element(&self) -> Option<&CssBogusPseudoElement> {
+    pub fn as_scss_include_at_rule(&self) -> Option<&ScssIncludeAtRule> {
         match &self {
-            AnyCssPseudoElement::CssBogusPseudoElement(item) => Some(item),
+            AnyCssAtRule::ScssIncludeAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_element_function_identifier(
-        &self,
-    ) -> Option<&CssPseudoElementFunctionIdentifier> {
+    pub fn as_scss_mixin_at_rule(&self) -> Option<&ScssMixinAtRule> {
         match &self {
-            AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(item) => Some(item),
+            AnyCssAtRule::ScssMixinAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_element_function_selector(
-        &self,
-    ) -> Option<&CssPseudoElementFunctionSelector> {
+    pub fn as_scss_use_at_rule(&self) -> Option<&ScssUseAtRule> {
         match &self {
-            AnyCssPseudoElement::CssPseudoElementFunctionSelector(item) => Some(item),
+            AnyCssAtRule::ScssUseAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_element_identifier(&self) -> Option<&CssPseudoElementIdentifier> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssAttributeMatcherValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl AnyCssAttributeMatcherValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssPseudoElement::CssPseudoElementIdentifier(item) => Some(item),
+            AnyCssAttributeMatcherValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssAttributeMatcherValue::CssString(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssPseudoValue {
+pub enum AnyCssComposesImportSource {
     CssIdentifier(CssIdentifier),
     CssString(CssString),
 }
-impl AnyCssPseudoValue {
+impl AnyCssComposesImportSource {
     pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssPseudoValue::CssIdentifier(item) => Some(item),
+            AnyCssComposesImportSource::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssPseudoValue::CssString(item) => Some(item),
+            AnyCssComposesImportSource::CssString(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssQueryFeature {
-    CssQueryFeatureBoolean(CssQueryFeatureBoolean),
-    CssQueryFeaturePlain(CssQueryFeaturePlain),
-    CssQueryFeatureRange(CssQueryFeatureRange),
-    CssQueryFeatureRangeInterval(CssQueryFeatureRangeInterval),
-    CssQueryFeatureReverseRange(CssQueryFeatureReverseRange),
+pub enum AnyCssCompoundSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssCompoundSelector(CssCompoundSelector),
 }
-impl AnyCssQueryFeature {
-    pub fn as_css_query_feature_boolean(&self) -> Option<&CssQueryFeatureBoolean> {
+impl AnyCssCompoundSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
         match &self {
-            AnyCssQueryFeature::CssQueryFeatureBoolean(item) => Some(item),
+            AnyCssCompoundSelector::CssBogusSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_query_feature_plain(&self) -> Option<&CssQueryFeaturePlain> {
+    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
         match &self {
-            AnyCssQueryFeature::CssQueryFeaturePlain(item) => Some(item),
+            AnyCssCompoundSelector::CssCompoundSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_query_feature_range(&self) -> Option<&CssQueryFeatureRange> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssConditionalBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
+    CssRuleBlock(CssRuleBlock),
+}
+impl AnyCssConditionalBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssQueryFeature::CssQueryFeatureRange(item) => Some(item),
+            AnyCssConditionalBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_query_feature_range_interval(&self) -> Option<&CssQueryFeatureRangeInterval> {
+    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
         match &self {
-            AnyCssQueryFeature::CssQueryFeatureRangeInterval(item) => Some(item),
+            AnyCssConditionalBlock::CssDeclarationOrRuleBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_query_feature_reverse_range(&self) -> Option<&CssQueryFeatureReverseRange> {
+    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
         match &self {
-            AnyCssQueryFeature::CssQueryFeatureReverseRange(item) => Some(item),
+            AnyCssConditionalBlock::CssRuleBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssQueryFeatureValue {
-    AnyCssDimension(AnyCssDimension),
-    AnyCssFunction(AnyCssFunction),
-    CssIdentifier(CssIdentifier),
-    CssNumber(CssNumber),
-    CssRatio(CssRatio),
+pub enum AnyCssContainerAndCombinableQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerAndQuery(CssContainerAndQuery),
 }
-impl AnyCssQueryFeatureValue {
-    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
-        match &self {
-            AnyCssQueryFeatureValue::AnyCssDimension(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
+impl AnyCssContainerAndCombinableQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
         match &self {
-            AnyCssQueryFeatureValue::AnyCssFunction(item) => Some(item),
+            AnyCssContainerAndCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
         match &self {
-            AnyCssQueryFeatureValue::CssIdentifier(item) => Some(item),
+            AnyCssContainerAndCombinableQuery::CssContainerAndQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_number(&self) -> Option<&CssNumber> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssContainerOrCombinableQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerOrQuery(CssContainerOrQuery),
+}
+impl AnyCssContainerOrCombinableQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
         match &self {
-            AnyCssQueryFeatureValue::CssNumber(item) => Some(item),
+            AnyCssContainerOrCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
+    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
         match &self {
-            AnyCssQueryFeatureValue::CssRatio(item) => Some(item),
+            AnyCssContainerOrCombinableQuery::CssContainerOrQuery(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssRelativeSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssRelativeSelector(CssRelativeSelector),
+pub enum AnyCssContainerQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerAndQuery(CssContainerAndQuery),
+    CssContainerNotQuery(CssContainerNotQuery),
+    CssContainerOrQuery(CssContainerOrQuery),
 }
-impl AnyCssRelativeSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+impl AnyCssContainerQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
         match &self {
-            AnyCssRelativeSelector::CssBogusSelector(item) => Some(item),
+            AnyCssContainerQuery::AnyCssContainerQueryInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_relative_selector(&self) -> Option<&CssRelativeSelector> {
+    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
         match &self {
-            AnyCssRelativeSelector::CssRelativeSelector(item) => Some(item),
+            AnyCssContainerQuery::CssContainerAndQuery(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssRule {
-    CssAtRule(CssAtRule),
-    CssBogusRule(CssBogusRule),
-    CssNestedQualifiedRule(CssNestedQualifiedRule),
-    CssQualifiedRule(CssQualifiedRule),
-}
-impl AnyCssRule {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+    pub fn as_css_container_not_query(&self) -> Option<&CssContainerNotQuery> {
         match &self {
-            AnyCssRule::CssAtRule(item) => Some(item),
+            AnyCssContainerQuery::CssContainerNotQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
+    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
         match &self {
-            AnyCssRule::CssBogusRule(item) => Some(item),
+            AnyCssContainerQuery::CssContainerOrQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_nested_qualified_rule(&self) -> Option<&CssNestedQualifiedRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssContainerQueryInParens {
+    CssContainerQueryInParens(CssContainerQueryInParens),
+    CssContainerSizeFeatureInParens(CssContainerSizeFeatureInParens),
+    CssContainerStyleQueryInParens(CssContainerStyleQueryInParens),
+}
+impl AnyCssContainerQueryInParens {
+    pub fn as_css_container_query_in_parens(&self) -> Option<&CssContainerQueryInParens> {
         match &self {
-            AnyCssRule::CssNestedQualifiedRule(item) => Some(item),
+            AnyCssContainerQueryInParens::CssContainerQueryInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_qualified_rule(&self) -> Option<&CssQualifiedRule> {
+    pub fn as_css_container_size_feature_in_parens(
+        &self,
+    ) -> Option<&CssContainerSizeFeatureInParens> {
         match &self {
-            AnyCssRule::CssQualifiedRule(item) => Some(item),
+            AnyCssContainerQueryInParens::CssContainerSizeFeatureInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_query_in_parens(
+        &self,
+    ) -> Option<&CssContainerStyleQueryInParens> {
+        match &self {
+            AnyCssContainerQueryInParens::CssContainerStyleQueryInParens(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssRuleBlock(CssRuleBlock),
+pub enum AnyCssContainerStyleAndCombinableQuery {
+    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
+    CssContainerStyleInParens(CssContainerStyleInParens),
 }
-impl AnyCssRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+impl AnyCssContainerStyleAndCombinableQuery {
+    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
         match &self {
-            AnyCssRuleBlock::CssBogusBlock(item) => Some(item),
+            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleAndQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
         match &self {
-            AnyCssRuleBlock::CssRuleBlock(item) => Some(item),
+            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleInParens(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssScopeRange {
-    CssBogusScopeRange(CssBogusScopeRange),
-    CssScopeRangeEnd(CssScopeRangeEnd),
-    CssScopeRangeInterval(CssScopeRangeInterval),
-    CssScopeRangeStart(CssScopeRangeStart),
+pub enum AnyCssContainerStyleInParens {
+    AnyCssContainerStyleQuery(AnyCssContainerStyleQuery),
+    CssDeclaration(CssDeclaration),
 }
-impl AnyCssScopeRange {
-    pub fn as_css_bogus_scope_range(&self) -> Option<&CssBogusScopeRange> {
+impl AnyCssContainerStyleInParens {
+    pub fn as_any_css_container_style_query(&self) -> Option<&AnyCssContainerStyleQuery> {
         match &self {
-            AnyCssScopeRange::CssBogusScopeRange(item) => Some(item),
+            AnyCssContainerStyleInParens::AnyCssContainerStyleQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_scope_range_end(&self) -> Option<&CssScopeRangeEnd> {
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
         match &self {
-            AnyCssScopeRange::CssScopeRangeEnd(item) => Some(item),
+            AnyCssContainerStyleInParens::CssDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_scope_range_interval(&self) -> Option<&CssScopeRangeInterval> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssContainerStyleOrCombinableQuery {
+    CssContainerStyleInParens(CssContainerStyleInParens),
+    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+}
+impl AnyCssContainerStyleOrCombinableQuery {
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
         match &self {
-            AnyCssScopeRange::CssScopeRangeInterval(item) => Some(item),
+            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_scope_range_start(&self) -> Option<&CssScopeRangeStart> {
+    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
         match &self {
-            AnyCssScopeRange::CssScopeRangeStart(item) => Some(item),
+            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleOrQuery(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssComplexSelector(CssComplexSelector),
-    CssCompoundSelector(CssCompoundSelector),
-    CssMetavariable(CssMetavariable),
+pub enum AnyCssContainerStyleQuery {
+    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
+    CssContainerStyleInParens(CssContainerStyleInParens),
+    CssContainerStyleNotQuery(CssContainerStyleNotQuery),
+    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+    CssDeclaration(CssDeclaration),
 }
-impl AnyCssSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+impl AnyCssContainerStyleQuery {
+    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
         match &self {
-            AnyCssSelector::CssBogusSelector(item) => Some(item),
+            AnyCssContainerStyleQuery::CssContainerStyleAndQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_complex_selector(&self) -> Option<&CssComplexSelector> {
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
         match &self {
-            AnyCssSelector::CssComplexSelector(item) => Some(item),
+            AnyCssContainerStyleQuery::CssContainerStyleInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
+    pub fn as_css_container_style_not_query(&self) -> Option<&CssContainerStyleNotQuery> {
         match &self {
-            AnyCssSelector::CssCompoundSelector(item) => Some(item),
+            AnyCssContainerStyleQuery::CssContainerStyleNotQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
+    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
         match &self {
-            AnyCssSelector::CssMetavariable(item) => Some(item),
+            AnyCssContainerStyleQuery::CssContainerStyleOrQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+        match &self {
+            AnyCssContainerStyleQuery::CssDeclaration(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSimpleSelector {
-    CssTypeSelector(CssTypeSelector),
-    CssUniversalSelector(CssUniversalSelector),
+pub enum AnyCssCustomIdentifier {
+    CssBogusCustomIdentifier(CssBogusCustomIdentifier),
+    CssCustomIdentifier(CssCustomIdentifier),
 }
-impl AnyCssSimpleSelector {
-    pub fn as_css_type_selector(&self) -> Option<&CssTypeSelector> {
+impl AnyCssCustomIdentifier {
+    pub fn as_css_bogus_custom_identifier(&self) -> Option<&CssBogusCustomIdentifier> {
         match &self {
-            AnyCssSimpleSelector::CssTypeSelector(item) => Some(item),
+            AnyCssCustomIdentifier::CssBogusCustomIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_universal_selector(&self) -> Option<&CssUniversalSelector> {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
         match &self {
-            AnyCssSimpleSelector::CssUniversalSelector(item) => Some(item),
+            AnyCssCustomIdentifier::CssCustomIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssStartingStyleBlock {
+pub enum AnyCssDeclarationBlock {
     CssBogusBlock(CssBogusBlock),
     CssDeclarationBlock(CssDeclarationBlock),
-    CssRuleBlock(CssRuleBlock),
 }
-impl AnyCssStartingStyleBlock {
+impl AnyCssDeclarationBlock {
     pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssStartingStyleBlock::CssBogusBlock(item) => Some(item),
+            AnyCssDeclarationBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
         match &self {
-            AnyCssStartingStyleBlock::CssDeclarationBlock(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
-        match &self {
-            AnyCssStartingStyleBlock::CssRuleBlock(item) => Some(item),
+            AnyCssDeclarationBlock::CssDeclarationBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSubSelector {
-    CssAttributeSelector(CssAttributeSelector),
-    CssBogusSubSelector(CssBogusSubSelector),
-    CssClassSelector(CssClassSelector),
-    CssIdSelector(CssIdSelector),
-    CssPseudoClassSelector(CssPseudoClassSelector),
-    CssPseudoElementSelector(CssPseudoElementSelector),
+pub enum AnyCssDeclarationName {
+    CssDashedIdentifier(CssDashedIdentifier),
+    CssIdentifier(CssIdentifier),
+    ScssInterpolatedIdentifier(ScssInterpolatedIdentifier),
 }
-impl AnyCssSubSelector {
-    pub fn as_css_attribute_selector(&self) -> Option<&CssAttributeSelector> {
-        match &self {
-            AnyCssSubSelector::CssAttributeSelector(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_bogus_sub_selector(&self) -> Option<&CssBogusSubSelector> {
+impl AnyCssDeclarationName {
+    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
         match &self {
-            AnyCssSubSelector::CssBogusSubSelector(item) => Some(item),
+            AnyCssDeclarationName::CssDashedIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_class_selector(&self) -> Option<&CssClassSelector> {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssSubSelector::CssClassSelector(item) => Some(item),
+            AnyCssDeclarationName::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_id_selector(&self) -> Option<&CssIdSelector> {
+    pub fn as_scss_interpolated_identifier(&self) -> Option<&ScssInterpolatedIdentifier> {
         match &self {
-            AnyCssSubSelector::CssIdSelector(item) => Some(item),
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_selector(&self) -> Option<&CssPseudoClassSelector> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssDeclarationOrAtRule {
+    CssAtRule(CssAtRule),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+}
+impl AnyCssDeclarationOrAtRule {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
         match &self {
-            AnyCssSubSelector::CssPseudoClassSelector(item) => Some(item),
+            AnyCssDeclarationOrAtRule::CssAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_element_selector(&self) -> Option<&CssPseudoElementSelector> {
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
         match &self {
-            AnyCssSubSelector::CssPseudoElementSelector(item) => Some(item),
+            AnyCssDeclarationOrAtRule::CssDeclarationWithSemicolon(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSupportsAndCombinableCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsAndCondition(CssSupportsAndCondition),
+pub enum AnyCssDeclarationOrAtRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationOrAtRuleBlock(CssDeclarationOrAtRuleBlock),
 }
-impl AnyCssSupportsAndCombinableCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+impl AnyCssDeclarationOrAtRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssSupportsAndCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
+            AnyCssDeclarationOrAtRuleBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
+    pub fn as_css_declaration_or_at_rule_block(&self) -> Option<&CssDeclarationOrAtRuleBlock> {
         match &self {
-            AnyCssSupportsAndCombinableCondition::CssSupportsAndCondition(item) => Some(item),
+            AnyCssDeclarationOrAtRuleBlock::CssDeclarationOrAtRuleBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSupportsCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsAndCondition(CssSupportsAndCondition),
-    CssSupportsNotCondition(CssSupportsNotCondition),
-    CssSupportsOrCondition(CssSupportsOrCondition),
+pub enum AnyCssDeclarationOrRule {
+    AnyCssRule(AnyCssRule),
+    CssBogus(CssBogus),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+    CssMetavariable(CssMetavariable),
+    ScssNestingDeclaration(ScssNestingDeclaration),
 }
-impl AnyCssSupportsCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+impl AnyCssDeclarationOrRule {
+    pub fn as_any_css_rule(&self) -> Option<&AnyCssRule> {
         match &self {
-            AnyCssSupportsCondition::AnyCssSupportsInParens(item) => Some(item),
+            AnyCssDeclarationOrRule::AnyCssRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
+    pub fn as_css_bogus(&self) -> Option<&CssBogus> {
         match &self {
-            AnyCssSupportsCondition::CssSupportsAndCondition(item) => Some(item),
+            AnyCssDeclarationOrRule::CssBogus(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_not_condition(&self) -> Option<&CssSupportsNotCondition> {
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
         match &self {
-            AnyCssSupportsCondition::CssSupportsNotCondition(item) => Some(item),
+            AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
+    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
         match &self {
-            AnyCssSupportsCondition::CssSupportsOrCondition(item) => Some(item),
+            AnyCssDeclarationOrRule::CssMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_nesting_declaration(&self) -> Option<&ScssNestingDeclaration> {
+        match &self {
+            AnyCssDeclarationOrRule::ScssNestingDeclaration(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSupportsInParens {
-    AnyCssValue(AnyCssValue),
-    CssFunction(CssFunction),
-    CssSupportsConditionInParens(CssSupportsConditionInParens),
-    CssSupportsFeatureDeclaration(CssSupportsFeatureDeclaration),
-    CssSupportsFeatureSelector(CssSupportsFeatureSelector),
-}
-impl AnyCssSupportsInParens {
-    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
+pub enum AnyCssDeclarationOrRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
+}
+impl AnyCssDeclarationOrRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssSupportsInParens::AnyCssValue(item) => Some(item),
+            AnyCssDeclarationOrRuleBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_function(&self) -> Option<&CssFunction> {
+    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
         match &self {
-            AnyCssSupportsInParens::CssFunction(item) => Some(item),
+            AnyCssDeclarationOrRuleBlock::CssDeclarationOrRuleBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_condition_in_parens(&self) -> Option<&CssSupportsConditionInParens> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssDimension {
+    CssPercentage(CssPercentage),
+    CssRegularDimension(CssRegularDimension),
+    CssUnknownDimension(CssUnknownDimension),
+}
+impl AnyCssDimension {
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
         match &self {
-            AnyCssSupportsInParens::CssSupportsConditionInParens(item) => Some(item),
+            AnyCssDimension::CssPercentage(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_feature_declaration(&self) -> Option<&CssSupportsFeatureDeclaration> {
+    pub fn as_css_regular_dimension(&self) -> Option<&CssRegularDimension> {
         match &self {
-            AnyCssSupportsInParens::CssSupportsFeatureDeclaration(item) => Some(item),
+            AnyCssDimension::CssRegularDimension(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_feature_selector(&self) -> Option<&CssSupportsFeatureSelector> {
+    pub fn as_css_unknown_dimension(&self) -> Option<&CssUnknownDimension> {
         match &self {
-            AnyCssSupportsInParens::CssSupportsFeatureSelector(item) => Some(item),
+            AnyCssDimension::CssUnknownDimension(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssSupportsOrCombinableCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsOrCondition(CssSupportsOrCondition),
+pub enum AnyCssDocumentMatcher {
+    CssBogusDocumentMatcher(CssBogusDocumentMatcher),
+    CssDocumentCustomMatcher(CssDocumentCustomMatcher),
+    CssUrlFunction(CssUrlFunction),
 }
-impl AnyCssSupportsOrCombinableCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+impl AnyCssDocumentMatcher {
+    pub fn as_css_bogus_document_matcher(&self) -> Option<&CssBogusDocumentMatcher> {
         match &self {
-            AnyCssSupportsOrCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
+            AnyCssDocumentMatcher::CssBogusDocumentMatcher(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
+    pub fn as_css_document_custom_matcher(&self) -> Option<&CssDocumentCustomMatcher> {
         match &self {
-            AnyCssSupportsOrCombinableCondition::CssSupportsOrCondition(item) => Some(item),
+            AnyCssDocumentMatcher::CssDocumentCustomMatcher(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+        match &self {
+            AnyCssDocumentMatcher::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssUnicodeValue {
-    CssBogusUnicodeRangeValue(CssBogusUnicodeRangeValue),
-    CssUnicodeCodepoint(CssUnicodeCodepoint),
-    CssUnicodeRangeInterval(CssUnicodeRangeInterval),
-    CssUnicodeRangeWildcard(CssUnicodeRangeWildcard),
+pub enum AnyCssExpression {
+    CssBinaryExpression(CssBinaryExpression),
+    CssListOfComponentValuesExpression(CssListOfComponentValuesExpression),
+    CssParenthesizedExpression(CssParenthesizedExpression),
 }
-impl AnyCssUnicodeValue {
-    pub fn as_css_bogus_unicode_range_value(&self) -> Option<&CssBogusUnicodeRangeValue> {
+impl AnyCssExpression {
+    pub fn as_css_binary_expression(&self) -> Option<&CssBinaryExpression> {
         match &self {
-            AnyCssUnicodeValue::CssBogusUnicodeRangeValue(item) => Some(item),
+            AnyCssExpression::CssBinaryExpression(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_unicode_codepoint(&self) -> Option<&CssUnicodeCodepoint> {
+    pub fn as_css_list_of_component_values_expression(
+        &self,
+    ) -> Option<&CssListOfComponentValuesExpression> {
         match &self {
-            AnyCssUnicodeValue::CssUnicodeCodepoint(item) => Some(item),
+            AnyCssExpression::CssListOfComponentValuesExpression(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_unicode_range_interval(&self) -> Option<&CssUnicodeRangeInterval> {
+    pub fn as_css_parenthesized_expression(&self) -> Option<&CssParenthesizedExpression> {
         match &self {
-            AnyCssUnicodeValue::CssUnicodeRangeInterval(item) => Some(item),
+            AnyCssExpression::CssParenthesizedExpression(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_unicode_range_wildcard(&self) -> Option<&CssUnicodeRangeWildcard> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssFontFamilyName {
+    CssBogusFontFamilyName(CssBogusFontFamilyName),
+    CssFontFamilyName(CssFontFamilyName),
+    CssString(CssString),
+}
+impl AnyCssFontFamilyName {
+    pub fn as_css_bogus_font_family_name(&self) -> Option<&CssBogusFontFamilyName> {
         match &self {
-            AnyCssUnicodeValue::CssUnicodeRangeWildcard(item) => Some(item),
+            AnyCssFontFamilyName::CssBogusFontFamilyName(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_family_name(&self) -> Option<&CssFontFamilyName> {
+        match &self {
+            AnyCssFontFamilyName::CssFontFamilyName(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssFontFamilyName::CssString(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssUrlModifier {
-    CssBogusUrlModifier(CssBogusUrlModifier),
-    CssFunction(CssFunction),
-    CssIdentifier(CssIdentifier),
+pub enum AnyCssFontFeatureValuesBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssFontFeatureValuesBlock(CssFontFeatureValuesBlock),
 }
-impl AnyCssUrlModifier {
-    pub fn as_css_bogus_url_modifier(&self) -> Option<&CssBogusUrlModifier> {
+impl AnyCssFontFeatureValuesBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssUrlModifier::CssBogusUrlModifier(item) => Some(item),
+            AnyCssFontFeatureValuesBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_feature_values_block(&self) -> Option<&CssFontFeatureValuesBlock> {
+        match &self {
+            AnyCssFontFeatureValuesBlock::CssFontFeatureValuesBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssFontFeatureValuesItem {
+    CssBogusFontFeatureValuesItem(CssBogusFontFeatureValuesItem),
+    CssFontFeatureValuesItem(CssFontFeatureValuesItem),
+}
+impl AnyCssFontFeatureValuesItem {
+    pub fn as_css_bogus_font_feature_values_item(&self) -> Option<&CssBogusFontFeatureValuesItem> {
+        match &self {
+            AnyCssFontFeatureValuesItem::CssBogusFontFeatureValuesItem(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_feature_values_item(&self) -> Option<&CssFontFeatureValuesItem> {
+        match &self {
+            AnyCssFontFeatureValuesItem::CssFontFeatureValuesItem(item) => Some(item),
             _ => None,
         }
     }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssFunction {
+    CssFunction(CssFunction),
+    CssUrlFunction(CssUrlFunction),
+}
+impl AnyCssFunction {
     pub fn as_css_function(&self) -> Option<&CssFunction> {
         match &self {
-            AnyCssUrlModifier::CssFunction(item) => Some(item),
+            AnyCssFunction::CssFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssUrlModifier::CssIdentifier(item) => Some(item),
+            AnyCssFunction::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssUrlValue {
-    CssString(CssString),
-    CssUrlValueRaw(CssUrlValueRaw),
+pub enum AnyCssGenericComponentValue {
+    AnyCssValue(AnyCssValue),
+    CssGenericDelimiter(CssGenericDelimiter),
 }
-impl AnyCssUrlValue {
-    pub fn as_css_string(&self) -> Option<&CssString> {
+impl AnyCssGenericComponentValue {
+    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
         match &self {
-            AnyCssUrlValue::CssString(item) => Some(item),
+            AnyCssGenericComponentValue::AnyCssValue(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
+    pub fn as_css_generic_delimiter(&self) -> Option<&CssGenericDelimiter> {
         match &self {
-            AnyCssUrlValue::CssUrlValueRaw(item) => Some(item),
+            AnyCssGenericComponentValue::CssGenericDelimiter(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssValue {
-    AnyCssDimension(AnyCssDimension),
-    AnyCssFunction(AnyCssFunction),
-    CssBracketedValue(CssBracketedValue),
-    CssColor(CssColor),
-    CssCustomIdentifier(CssCustomIdentifier),
-    CssDashedIdentifier(CssDashedIdentifier),
-    CssIdentifier(CssIdentifier),
-    CssMetavariable(CssMetavariable),
-    CssNumber(CssNumber),
-    CssRatio(CssRatio),
+pub enum AnyCssImportLayer {
+    CssImportAnonymousLayer(CssImportAnonymousLayer),
+    CssImportNamedLayer(CssImportNamedLayer),
+}
+impl AnyCssImportLayer {
+    pub fn as_css_import_anonymous_layer(&self) -> Option<&CssImportAnonymousLayer> {
+        match &self {
+            AnyCssImportLayer::CssImportAnonymousLayer(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_import_named_layer(&self) -> Option<&CssImportNamedLayer> {
+        match &self {
+            AnyCssImportLayer::CssImportNamedLayer(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssImportSupportsCondition {
+    AnyCssSupportsCondition(AnyCssSupportsCondition),
+    CssDeclaration(CssDeclaration),
+}
+impl AnyCssImportSupportsCondition {
+    pub fn as_any_css_supports_condition(&self) -> Option<&AnyCssSupportsCondition> {
+        match &self {
+            AnyCssImportSupportsCondition::AnyCssSupportsCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+        match &self {
+            AnyCssImportSupportsCondition::CssDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssImportUrl {
     CssString(CssString),
-    CssUnicodeRange(CssUnicodeRange),
+    CssUrlFunction(CssUrlFunction),
 }
-impl AnyCssValue {
-    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
+impl AnyCssImportUrl {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssValue::AnyCssDimension(item) => Some(item),
+            AnyCssImportUrl::CssString(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssValue::AnyCssFunction(item) => Some(item),
+            AnyCssImportUrl::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_bracketed_value(&self) -> Option<&CssBracketedValue> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssKeyframesBlock(CssKeyframesBlock),
+}
+impl AnyCssKeyframesBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssValue::CssBracketedValue(item) => Some(item),
+            AnyCssKeyframesBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_block(&self) -> Option<&CssKeyframesBlock> {
+        match &self {
+            AnyCssKeyframesBlock::CssKeyframesBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_color(&self) -> Option<&CssColor> {
-        match &self {
-            AnyCssValue::CssColor(item) => Some(item),
-            _ => None,
-        }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesIdentifier {
+    CssCustomIdentifier(CssCustomIdentifier),
+    CssString(CssString),
+}
+impl AnyCssKeyframesIdentifier {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+        match &self {
+            AnyCssKeyframesIdentifier::CssCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssKeyframesIdentifier::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesItem {
+    CssBogusKeyframesItem(CssBogusKeyframesItem),
+    CssKeyframesItem(CssKeyframesItem),
+}
+impl AnyCssKeyframesItem {
+    pub fn as_css_bogus_keyframes_item(&self) -> Option<&CssBogusKeyframesItem> {
+        match &self {
+            AnyCssKeyframesItem::CssBogusKeyframesItem(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_item(&self) -> Option<&CssKeyframesItem> {
+        match &self {
+            AnyCssKeyframesItem::CssKeyframesItem(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesName {
+    AnyCssKeyframesIdentifier(AnyCssKeyframesIdentifier),
+    CssBogusKeyframesName(CssBogusKeyframesName),
+    CssKeyframesScopedName(CssKeyframesScopedName),
+}
+impl AnyCssKeyframesName {
+    pub fn as_any_css_keyframes_identifier(&self) -> Option<&AnyCssKeyframesIdentifier> {
+        match &self {
+            AnyCssKeyframesName::AnyCssKeyframesIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_keyframes_name(&self) -> Option<&CssBogusKeyframesName> {
+        match &self {
+            AnyCssKeyframesName::CssBogusKeyframesName(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_scoped_name(&self) -> Option<&CssKeyframesScopedName> {
+        match &self {
+            AnyCssKeyframesName::CssKeyframesScopedName(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesScope {
+    CssKeyframesScopeFunction(CssKeyframesScopeFunction),
+    CssKeyframesScopePrefix(CssKeyframesScopePrefix),
+}
+impl AnyCssKeyframesScope {
+    pub fn as_css_keyframes_scope_function(&self) -> Option<&CssKeyframesScopeFunction> {
+        match &self {
+            AnyCssKeyframesScope::CssKeyframesScopeFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_scope_prefix(&self) -> Option<&CssKeyframesScopePrefix> {
+        match &self {
+            AnyCssKeyframesScope::CssKeyframesScopePrefix(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssKeyframesSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssKeyframesIdentSelector(CssKeyframesIdentSelector),
+    CssKeyframesPercentageSelector(CssKeyframesPercentageSelector),
+}
+impl AnyCssKeyframesSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_ident_selector(&self) -> Option<&CssKeyframesIdentSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssKeyframesIdentSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_percentage_selector(&self) -> Option<&CssKeyframesPercentageSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssKeyframesPercentageSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssLayer {
+    CssBogusLayer(CssBogusLayer),
+    CssLayerDeclaration(CssLayerDeclaration),
+    CssLayerReference(CssLayerReference),
+}
+impl AnyCssLayer {
+    pub fn as_css_bogus_layer(&self) -> Option<&CssBogusLayer> {
+        match &self {
+            AnyCssLayer::CssBogusLayer(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_layer_declaration(&self) -> Option<&CssLayerDeclaration> {
+        match &self {
+            AnyCssLayer::CssLayerDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_layer_reference(&self) -> Option<&CssLayerReference> {
+        match &self {
+            AnyCssLayer::CssLayerReference(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaAndCombinableCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
+}
+impl AnyCssMediaAndCombinableCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+        match &self {
+            AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+        match &self {
+            AnyCssMediaAndCombinableCondition::CssMediaAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
+    CssMediaNotCondition(CssMediaNotCondition),
+    CssMediaOrCondition(CssMediaOrCondition),
+}
+impl AnyCssMediaCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+        match &self {
+            AnyCssMediaCondition::AnyCssMediaInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+        match &self {
+            AnyCssMediaCondition::CssMediaAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
+        match &self {
+            AnyCssMediaCondition::CssMediaNotCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
+        match &self {
+            AnyCssMediaCondition::CssMediaOrCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaInParens {
+    CssMediaConditionInParens(CssMediaConditionInParens),
+    CssMediaFeatureInParens(CssMediaFeatureInParens),
+}
+impl AnyCssMediaInParens {
+    pub fn as_css_media_condition_in_parens(&self) -> Option<&CssMediaConditionInParens> {
+        match &self {
+            AnyCssMediaInParens::CssMediaConditionInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_feature_in_parens(&self) -> Option<&CssMediaFeatureInParens> {
+        match &self {
+            AnyCssMediaInParens::CssMediaFeatureInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaOrCombinableCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaOrCondition(CssMediaOrCondition),
+}
+impl AnyCssMediaOrCombinableCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+        match &self {
+            AnyCssMediaOrCombinableCondition::AnyCssMediaInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
+        match &self {
+            AnyCssMediaOrCombinableCondition::CssMediaOrCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaQuery {
+    AnyCssMediaTypeQuery(AnyCssMediaTypeQuery),
+    CssBogusMediaQuery(CssBogusMediaQuery),
+    CssMediaConditionQuery(CssMediaConditionQuery),
+    CssMetavariable(CssMetavariable),
+}
+impl AnyCssMediaQuery {
+    pub fn as_any_css_media_type_query(&self) -> Option<&AnyCssMediaTypeQuery> {
+        match &self {
+            AnyCssMediaQuery::AnyCssMediaTypeQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_media_query(&self) -> Option<&CssBogusMediaQuery> {
+        match &self {
+            AnyCssMediaQuery::CssBogusMediaQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_condition_query(&self) -> Option<&CssMediaConditionQuery> {
+        match &self {
+            AnyCssMediaQuery::CssMediaConditionQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
+        match &self {
+            AnyCssMediaQuery::CssMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaTypeCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
+    CssMediaNotCondition(CssMediaNotCondition),
+}
+impl AnyCssMediaTypeCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+        match &self {
+            AnyCssMediaTypeCondition::AnyCssMediaInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+        match &self {
+            AnyCssMediaTypeCondition::CssMediaAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
+        match &self {
+            AnyCssMediaTypeCondition::CssMediaNotCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssMediaTypeQuery {
+    CssMediaAndTypeQuery(CssMediaAndTypeQuery),
+    CssMediaTypeQuery(CssMediaTypeQuery),
+}
+impl AnyCssMediaTypeQuery {
+    pub fn as_css_media_and_type_query(&self) -> Option<&CssMediaAndTypeQuery> {
+        match &self {
+            AnyCssMediaTypeQuery::CssMediaAndTypeQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_type_query(&self) -> Option<&CssMediaTypeQuery> {
+        match &self {
+            AnyCssMediaTypeQuery::CssMediaTypeQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssNamespacePrefix {
+    CssNamedNamespacePrefix(CssNamedNamespacePrefix),
+    CssUniversalNamespacePrefix(CssUniversalNamespacePrefix),
+}
+impl AnyCssNamespacePrefix {
+    pub fn as_css_named_namespace_prefix(&self) -> Option<&CssNamedNamespacePrefix> {
+        match &self {
+            AnyCssNamespacePrefix::CssNamedNamespacePrefix(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_universal_namespace_prefix(&self) -> Option<&CssUniversalNamespacePrefix> {
+        match &self {
+            AnyCssNamespacePrefix::CssUniversalNamespacePrefix(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssNamespaceUrl {
+    CssString(CssString),
+    CssUrlFunction(CssUrlFunction),
+}
+impl AnyCssNamespaceUrl {
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssNamespaceUrl::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+        match &self {
+            AnyCssNamespaceUrl::CssUrlFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPageAtRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssPageAtRuleBlock(CssPageAtRuleBlock),
+}
+impl AnyCssPageAtRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssPageAtRuleBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_page_at_rule_block(&self) -> Option<&CssPageAtRuleBlock> {
+        match &self {
+            AnyCssPageAtRuleBlock::CssPageAtRuleBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPageAtRuleItem {
+    CssAtRule(CssAtRule),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+    CssMarginAtRule(CssMarginAtRule),
+}
+impl AnyCssPageAtRuleItem {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+        match &self {
+            AnyCssPageAtRuleItem::CssAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+        match &self {
+            AnyCssPageAtRuleItem::CssDeclarationWithSemicolon(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_margin_at_rule(&self) -> Option<&CssMarginAtRule> {
+        match &self {
+            AnyCssPageAtRuleItem::CssMarginAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPageSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssPageSelector(CssPageSelector),
+}
+impl AnyCssPageSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssPageSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_page_selector(&self) -> Option<&CssPageSelector> {
+        match &self {
+            AnyCssPageSelector::CssPageSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPageSelectorPseudo {
+    CssBogusPageSelectorPseudo(CssBogusPageSelectorPseudo),
+    CssPageSelectorPseudo(CssPageSelectorPseudo),
+}
+impl AnyCssPageSelectorPseudo {
+    pub fn as_css_bogus_page_selector_pseudo(&self) -> Option<&CssBogusPageSelectorPseudo> {
+        match &self {
+            AnyCssPageSelectorPseudo::CssBogusPageSelectorPseudo(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_page_selector_pseudo(&self) -> Option<&CssPageSelectorPseudo> {
+        match &self {
+            AnyCssPageSelectorPseudo::CssPageSelectorPseudo(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssProperty {
+    CssBogusProperty(CssBogusProperty),
+    CssComposesProperty(CssComposesProperty),
+    CssGenericProperty(CssGenericProperty),
+}
+impl AnyCssProperty {
+    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
+        match &self {
+            AnyCssProperty::CssBogusProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_composes_property(&self) -> Option<&CssComposesProperty> {
+        match &self {
+            AnyCssProperty::CssComposesProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_generic_property(&self) -> Option<&CssGenericProperty> {
+        match &self {
+            AnyCssProperty::CssGenericProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPseudoClass {
+    CssBogusPseudoClass(CssBogusPseudoClass),
+    CssPseudoClassFunctionCompoundSelector(CssPseudoClassFunctionCompoundSelector),
+    CssPseudoClassFunctionCompoundSelectorList(CssPseudoClassFunctionCompoundSelectorList),
+    CssPseudoClassFunctionIdentifier(CssPseudoClassFunctionIdentifier),
+    CssPseudoClassFunctionNth(CssPseudoClassFunctionNth),
+    CssPseudoClassFunctionRelativeSelectorList(CssPseudoClassFunctionRelativeSelectorList),
+    CssPseudoClassFunctionSelector(CssPseudoClassFunctionSelector),
+    CssPseudoClassFunctionSelectorList(CssPseudoClassFunctionSelectorList),
+    CssPseudoClassFunctionValueList(CssPseudoClassFunctionValueList),
+    CssPseudoClassIdentifier(CssPseudoClassIdentifier),
+}
+impl AnyCssPseudoClass {
+    pub fn as_css_bogus_pseudo_class(&self) -> Option<&CssBogusPseudoClass> {
+        match &self {
+            AnyCssPseudoClass::CssBogusPseudoClass(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_compound_selector(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionCompoundSelector> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_compound_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionCompoundSelectorList> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelectorList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_identifier(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionIdentifier> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_nth(&self) -> Option<&CssPseudoClassFunctionNth> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionNth(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_relative_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionRelativeSelectorList> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionRelativeSelectorList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_selector(&self) -> Option<&CssPseudoClassFunctionSelector> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionSelectorList> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionSelectorList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_function_value_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionValueList> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassFunctionValueList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_identifier(&self) -> Option<&CssPseudoClassIdentifier> {
+        match &self {
+            AnyCssPseudoClass::CssPseudoClassIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPseudoClassNth {
+    CssPseudoClassNth(CssPseudoClassNth),
+    CssPseudoClassNthIdentifier(CssPseudoClassNthIdentifier),
+    CssPseudoClassNthNumber(CssPseudoClassNthNumber),
+}
+impl AnyCssPseudoClassNth {
+    pub fn as_css_pseudo_class_nth(&self) -> Option<&CssPseudoClassNth> {
+        match &self {
+            AnyCssPseudoClassNth::CssPseudoClassNth(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_nth_identifier(&self) -> Option<&CssPseudoClassNthIdentifier> {
+        match &self {
+            AnyCssPseudoClassNth::CssPseudoClassNthIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_nth_number(&self) -> Option<&CssPseudoClassNthNumber> {
+        match &self {
+            AnyCssPseudoClassNth::CssPseudoClassNthNumber(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPseudoClassNthSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssPseudoClassNthSelector(CssPseudoClassNthSelector),
+}
+impl AnyCssPseudoClassNthSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssPseudoClassNthSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_nth_selector(&self) -> Option<&CssPseudoClassNthSelector> {
+        match &self {
+            AnyCssPseudoClassNthSelector::CssPseudoClassNthSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPseudoElement {
+    CssBogusPseudoElement(CssBogusPseudoElement),
+    CssPseudoElementFunctionIdentifier(CssPseudoElementFunctionIdentifier),
+    CssPseudoElementFunctionSelector(CssPseudoElementFunctionSelector),
+    CssPseudoElementIdentifier(CssPseudoElementIdentifier),
+}
+impl AnyCssPseudoElement {
+    pub fn as_css_bogus_pseudo_element(&self) -> Option<&CssBogusPseudoElement> {
+        match &self {
+            AnyCssPseudoElement::CssBogusPseudoElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_function_identifier(
+        &self,
+    ) -> Option<&CssPseudoElementFunctionIdentifier> {
+        match &self {
+            AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_function_selector(
+        &self,
+    ) -> Option<&CssPseudoElementFunctionSelector> {
+        match &self {
+            AnyCssPseudoElement::CssPseudoElementFunctionSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_identifier(&self) -> Option<&CssPseudoElementIdentifier> {
+        match &self {
+            AnyCssPseudoElement::CssPseudoElementIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssPseudoValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl AnyCssPseudoValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssPseudoValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssPseudoValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssQueryFeature {
+    CssQueryFeatureBoolean(CssQueryFeatureBoolean),
+    CssQueryFeaturePlain(CssQueryFeaturePlain),
+    CssQueryFeatureRange(CssQueryFeatureRange),
+    CssQueryFeatureRangeInterval(CssQueryFeatureRangeInterval),
+    CssQueryFeatureReverseRange(CssQueryFeatureReverseRange),
+}
+impl AnyCssQueryFeature {
+    pub fn as_css_query_feature_boolean(&self) -> Option<&CssQueryFeatureBoolean> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeatureBoolean(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_query_feature_plain(&self) -> Option<&CssQueryFeaturePlain> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeaturePlain(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_query_feature_range(&self) -> Option<&CssQueryFeatureRange> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeatureRange(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_query_feature_range_interval(&self) -> Option<&CssQueryFeatureRangeInterval> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeatureRangeInterval(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_query_feature_reverse_range(&self) -> Option<&CssQueryFeatureReverseRange> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeatureReverseRange(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssQueryFeatureValue {
+    AnyCssDimension(AnyCssDimension),
+    AnyCssFunction(AnyCssFunction),
+    CssIdentifier(CssIdentifier),
+    CssNumber(CssNumber),
+    CssRatio(CssRatio),
+}
+impl AnyCssQueryFeatureValue {
+    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
+        match &self {
+            AnyCssQueryFeatureValue::AnyCssDimension(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
+        match &self {
+            AnyCssQueryFeatureValue::AnyCssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssQueryFeatureValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_number(&self) -> Option<&CssNumber> {
+        match &self {
+            AnyCssQueryFeatureValue::CssNumber(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
+        match &self {
+            AnyCssQueryFeatureValue::CssRatio(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssRelativeSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssRelativeSelector(CssRelativeSelector),
+}
+impl AnyCssRelativeSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssRelativeSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_relative_selector(&self) -> Option<&CssRelativeSelector> {
+        match &self {
+            AnyCssRelativeSelector::CssRelativeSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssRule {
+    CssAtRule(CssAtRule),
+    CssBogusRule(CssBogusRule),
+    CssNestedQualifiedRule(CssNestedQualifiedRule),
+    CssQualifiedRule(CssQualifiedRule),
+    ScssDeclaration(ScssDeclaration),
+}
+impl AnyCssRule {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+        match &self {
+            AnyCssRule::CssAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
+        match &self {
+            AnyCssRule::CssBogusRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_nested_qualified_rule(&self) -> Option<&CssNestedQualifiedRule> {
+        match &self {
+            AnyCssRule::CssNestedQualifiedRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_qualified_rule(&self) -> Option<&CssQualifiedRule> {
+        match &self {
+            AnyCssRule::CssQualifiedRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_declaration(&self) -> Option<&ScssDeclaration> {
+        match &self {
+            AnyCssRule::ScssDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssRuleBlock(CssRuleBlock),
+}
+impl AnyCssRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssRuleBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
+        match &self {
+            AnyCssRuleBlock::CssRuleBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssScopeRange {
+    CssBogusScopeRange(CssBogusScopeRange),
+    CssScopeRangeEnd(CssScopeRangeEnd),
+    CssScopeRangeInterval(CssScopeRangeInterval),
+    CssScopeRangeStart(CssScopeRangeStart),
+}
+impl AnyCssScopeRange {
+    pub fn as_css_bogus_scope_range(&self) -> Option<&CssBogusScopeRange> {
+        match &self {
+            AnyCssScopeRange::CssBogusScopeRange(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_scope_range_end(&self) -> Option<&CssScopeRangeEnd> {
+        match &self {
+            AnyCssScopeRange::CssScopeRangeEnd(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_scope_range_interval(&self) -> Option<&CssScopeRangeInterval> {
+        match &self {
+            AnyCssScopeRange::CssScopeRangeInterval(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_scope_range_start(&self) -> Option<&CssScopeRangeStart> {
+        match &self {
+            AnyCssScopeRange::CssScopeRangeStart(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssComplexSelector(CssComplexSelector),
+    CssCompoundSelector(CssCompoundSelector),
+    CssMetavariable(CssMetavariable),
+}
+impl AnyCssSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_complex_selector(&self) -> Option<&CssComplexSelector> {
+        match &self {
+            AnyCssSelector::CssComplexSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
+        match &self {
+            AnyCssSelector::CssCompoundSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
+        match &self {
+            AnyCssSelector::CssMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSelectorName {
+    CssCustomIdentifier(CssCustomIdentifier),
+    ScssInterpolatedIdentifier(ScssInterpolatedIdentifier),
+}
+impl AnyCssSelectorName {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+        match &self {
+            AnyCssSelectorName::CssCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_interpolated_identifier(&self) -> Option<&ScssInterpolatedIdentifier> {
+        match &self {
+            AnyCssSelectorName::ScssInterpolatedIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSimpleSelector {
+    CssTypeSelector(CssTypeSelector),
+    CssUniversalSelector(CssUniversalSelector),
+    ScssInterpolatedIdentifier(ScssInterpolatedIdentifier),
+}
+impl AnyCssSimpleSelector {
+    pub fn as_css_type_selector(&self) -> Option<&CssTypeSelector> {
+        match &self {
+            AnyCssSimpleSelector::CssTypeSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_universal_selector(&self) -> Option<&CssUniversalSelector> {
+        match &self {
+            AnyCssSimpleSelector::CssUniversalSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_interpolated_identifier(&self) -> Option<&ScssInterpolatedIdentifier> {
+        match &self {
+            AnyCssSimpleSelector::ScssInterpolatedIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssStartingStyleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationBlock(CssDeclarationBlock),
+    CssRuleBlock(CssRuleBlock),
+}
+impl AnyCssStartingStyleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssStartingStyleBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
+        match &self {
+            AnyCssStartingStyleBlock::CssDeclarationBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
+        match &self {
+            AnyCssStartingStyleBlock::CssRuleBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSubSelector {
+    CssAttributeSelector(CssAttributeSelector),
+    CssBogusSubSelector(CssBogusSubSelector),
+    CssClassSelector(CssClassSelector),
+    CssIdSelector(CssIdSelector),
+    CssPseudoClassSelector(CssPseudoClassSelector),
+    CssPseudoElementSelector(CssPseudoElementSelector),
+    ScssPlaceholderSelector(ScssPlaceholderSelector),
+}
+impl AnyCssSubSelector {
+    pub fn as_css_attribute_selector(&self) -> Option<&CssAttributeSelector> {
+        match &self {
+            AnyCssSubSelector::CssAttributeSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_sub_selector(&self) -> Option<&CssBogusSubSelector> {
+        match &self {
+            AnyCssSubSelector::CssBogusSubSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_class_selector(&self) -> Option<&CssClassSelector> {
+        match &self {
+            AnyCssSubSelector::CssClassSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_id_selector(&self) -> Option<&CssIdSelector> {
+        match &self {
+            AnyCssSubSelector::CssIdSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_selector(&self) -> Option<&CssPseudoClassSelector> {
+        match &self {
+            AnyCssSubSelector::CssPseudoClassSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_selector(&self) -> Option<&CssPseudoElementSelector> {
+        match &self {
+            AnyCssSubSelector::CssPseudoElementSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_placeholder_selector(&self) -> Option<&ScssPlaceholderSelector> {
+        match &self {
+            AnyCssSubSelector::ScssPlaceholderSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSupportsAndCombinableCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsAndCondition(CssSupportsAndCondition),
+}
+impl AnyCssSupportsAndCombinableCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+        match &self {
+            AnyCssSupportsAndCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
+        match &self {
+            AnyCssSupportsAndCombinableCondition::CssSupportsAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSupportsCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsAndCondition(CssSupportsAndCondition),
+    CssSupportsNotCondition(CssSupportsNotCondition),
+    CssSupportsOrCondition(CssSupportsOrCondition),
+}
+impl AnyCssSupportsCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+        match &self {
+            AnyCssSupportsCondition::AnyCssSupportsInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
+        match &self {
+            AnyCssSupportsCondition::CssSupportsAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_not_condition(&self) -> Option<&CssSupportsNotCondition> {
+        match &self {
+            AnyCssSupportsCondition::CssSupportsNotCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
+        match &self {
+            AnyCssSupportsCondition::CssSupportsOrCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSupportsInParens {
+    AnyCssValue(AnyCssValue),
+    CssFunction(CssFunction),
+    CssSupportsConditionInParens(CssSupportsConditionInParens),
+    CssSupportsFeatureDeclaration(CssSupportsFeatureDeclaration),
+    CssSupportsFeatureSelector(CssSupportsFeatureSelector),
+}
+impl AnyCssSupportsInParens {
+    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
+        match &self {
+            AnyCssSupportsInParens::AnyCssValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
+        match &self {
+            AnyCssSupportsInParens::CssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_condition_in_parens(&self) -> Option<&CssSupportsConditionInParens> {
+        match &self {
+            AnyCssSupportsInParens::CssSupportsConditionInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_feature_declaration(&self) -> Option<&CssSupportsFeatureDeclaration> {
+        match &self {
+            AnyCssSupportsInParens::CssSupportsFeatureDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_feature_selector(&self) -> Option<&CssSupportsFeatureSelector> {
+        match &self {
+            AnyCssSupportsInParens::CssSupportsFeatureSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssSupportsOrCombinableCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsOrCondition(CssSupportsOrCondition),
+}
+impl AnyCssSupportsOrCombinableCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
+        match &self {
+            AnyCssSupportsOrCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
+        match &self {
+            AnyCssSupportsOrCombinableCondition::CssSupportsOrCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssUnicodeValue {
+    CssBogusUnicodeRangeValue(CssBogusUnicodeRangeValue),
+    CssUnicodeCodepoint(CssUnicodeCodepoint),
+    CssUnicodeRangeInterval(CssUnicodeRangeInterval),
+    CssUnicodeRangeWildcard(CssUnicodeRangeWildcard),
+}
+impl AnyCssUnicodeValue {
+    pub fn as_css_bogus_unicode_range_value(&self) -> Option<&CssBogusUnicodeRangeValue> {
+        match &self {
+            AnyCssUnicodeValue::CssBogusUnicodeRangeValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_unicode_codepoint(&self) -> Option<&CssUnicodeCodepoint> {
+        match &self {
+            AnyCssUnicodeValue::CssUnicodeCodepoint(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_unicode_range_interval(&self) -> Option<&CssUnicodeRangeInterval> {
+        match &self {
+            AnyCssUnicodeValue::CssUnicodeRangeInterval(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_unicode_range_wildcard(&self) -> Option<&CssUnicodeRangeWildcard> {
+        match &self {
+            AnyCssUnicodeValue::CssUnicodeRangeWildcard(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssUrlModifier {
+    CssBogusUrlModifier(CssBogusUrlModifier),
+    CssFunction(CssFunction),
+    CssIdentifier(CssIdentifier),
+}
+impl AnyCssUrlModifier {
+    pub fn as_css_bogus_url_modifier(&self) -> Option<&CssBogusUrlModifier> {
+        match &self {
+            AnyCssUrlModifier::CssBogusUrlModifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
+        match &self {
+            AnyCssUrlModifier::CssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssUrlModifier::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssUrlValue {
+    CssString(CssString),
+    CssUrlValueRaw(CssUrlValueRaw),
+}
+impl AnyCssUrlValue {
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssUrlValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
+        match &self {
+            AnyCssUrlValue::CssUrlValueRaw(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssValue {
+    AnyCssDimension(AnyCssDimension),
+    AnyCssFunction(AnyCssFunction),
+    CssBracketedValue(CssBracketedValue),
+    CssColor(CssColor),
+    CssCustomIdentifier(CssCustomIdentifier),
+    CssDashedIdentifier(CssDashedIdentifier),
+    CssIdentifier(CssIdentifier),
+    CssMetavariable(CssMetavariable),
+    CssNumber(CssNumber),
+    CssRatio(CssRatio),
+    CssString(CssString),
+    CssUnicodeRange(CssUnicodeRange),
+    ScssIdentifier(ScssIdentifier),
+    ScssInterpolatedIdentifier(ScssInterpolatedIdentifier),
+    ScssMapExpression(ScssMapExpression),
+    ScssQualifiedName(ScssQualifiedName),
+}
+impl AnyCssValue {
+    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
+        match &self {
+            AnyCssValue::AnyCssDimension(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
+        match &self {
+            AnyCssValue::AnyCssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bracketed_value(&self) -> Option<&CssBracketedValue> {
+        match &self {
+            AnyCssValue::CssBracketedValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_color(&self) -> Option<&CssColor> {
+        match &self {
+            AnyCssValue::CssColor(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+        match &self {
+            AnyCssValue::CssCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
+        match &self {
+            AnyCssValue::CssDashedIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
+        match &self {
+            AnyCssValue::CssMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_number(&self) -> Option<&CssNumber> {
+        match &self {
+            AnyCssValue::CssNumber(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
+        match &self {
+            AnyCssValue::CssRatio(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_unicode_range(&self) -> Option<&CssUnicodeRange> {
+        match &self {
+            AnyCssValue::CssUnicodeRange(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_identifier(&self) -> Option<&ScssIdentifier> {
+        match &self {
+            AnyCssValue::ScssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_interpolated_identifier(&self) -> Option<&ScssInterpolatedIdentifier> {
+        match &self {
+            AnyCssValue::ScssInterpolatedIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_map_expression(&self) -> Option<&ScssMapExpression> {
+        match &self {
+            AnyCssValue::ScssMapExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_qualified_name(&self) -> Option<&ScssQualifiedName> {
+        match &self {
+            AnyCssValue::ScssQualifiedName(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssValueAtRuleClause {
+    CssValueAtRuleDeclarationClause(CssValueAtRuleDeclarationClause),
+    CssValueAtRuleImportClause(CssValueAtRuleImportClause),
+}
+impl AnyCssValueAtRuleClause {
+    pub fn as_css_value_at_rule_declaration_clause(
+        &self,
+    ) -> Option<&CssValueAtRuleDeclarationClause> {
+        match &self {
+            AnyCssValueAtRuleClause::CssValueAtRuleDeclarationClause(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_value_at_rule_import_clause(&self) -> Option<&CssValueAtRuleImportClause> {
+        match &self {
+            AnyCssValueAtRuleClause::CssValueAtRuleImportClause(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssValueAtRuleImportSource {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl AnyCssValueAtRuleImportSource {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssValueAtRuleImportSource::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssValueAtRuleImportSource::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssValueAtRuleImportSpecifier {
+    CssValueAtRuleImportSpecifier(CssValueAtRuleImportSpecifier),
+    CssValueAtRuleNamedImportSpecifier(CssValueAtRuleNamedImportSpecifier),
+}
+impl AnyCssValueAtRuleImportSpecifier {
+    pub fn as_css_value_at_rule_import_specifier(&self) -> Option<&CssValueAtRuleImportSpecifier> {
+        match &self {
+            AnyCssValueAtRuleImportSpecifier::CssValueAtRuleImportSpecifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_value_at_rule_named_import_specifier(
+        &self,
+    ) -> Option<&CssValueAtRuleNamedImportSpecifier> {
+        match &self {
+            AnyCssValueAtRuleImportSpecifier::CssValueAtRuleNamedImportSpecifier(item) => {
+                Some(item)
+            }
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyCssValueAtRuleProperty {
+    CssBogusProperty(CssBogusProperty),
+    CssValueAtRuleGenericProperty(CssValueAtRuleGenericProperty),
+}
+impl AnyCssValueAtRuleProperty {
+    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
+        match &self {
+            AnyCssValueAtRuleProperty::CssBogusProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_value_at_rule_generic_property(&self) -> Option<&CssValueAtRuleGenericProperty> {
+        match &self {
+            AnyCssValueAtRuleProperty::CssValueAtRuleGenericProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyScssForwardMember {
+    CssIdentifier(CssIdentifier),
+    ScssIdentifier(ScssIdentifier),
+}
+impl AnyScssForwardMember {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyScssForwardMember::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_identifier(&self) -> Option<&ScssIdentifier> {
+        match &self {
+            AnyScssForwardMember::ScssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyScssInterpolatedIdentifierItem {
+    CssCustomIdentifier(CssCustomIdentifier),
+    CssIdentifier(CssIdentifier),
+    ScssInterpolation(ScssInterpolation),
+}
+impl AnyScssInterpolatedIdentifierItem {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+        match &self {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_interpolation(&self) -> Option<&ScssInterpolation> {
+        match &self {
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyScssModuleMember {
+    CssFunction(CssFunction),
+    ScssIdentifier(ScssIdentifier),
+}
+impl AnyScssModuleMember {
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
+        match &self {
+            AnyScssModuleMember::CssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_identifier(&self) -> Option<&ScssIdentifier> {
+        match &self {
+            AnyScssModuleMember::ScssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyScssUseNamespace {
+    CssIdentifier(CssIdentifier),
+    ScssUseAllNamespace(ScssUseAllNamespace),
+}
+impl AnyScssUseNamespace {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyScssUseNamespace::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_use_all_namespace(&self) -> Option<&ScssUseAllNamespace> {
+        match &self {
+            AnyScssUseNamespace::ScssUseAllNamespace(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for CssAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAtRule")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("rule", &support::DebugSyntaxResult(self.rule()))
+            .finish()
+    }
+}
+impl From<CssAtRule> for SyntaxNode {
+    fn from(n: CssAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAtRule> for SyntaxElement {
+    fn from(n: CssAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeMatcher {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_MATCHER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeMatcher")
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+            .finish()
+    }
+}
+impl From<CssAttributeMatcher> for SyntaxNode {
+    fn from(n: CssAttributeMatcher) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeMatcher> for SyntaxElement {
+    fn from(n: CssAttributeMatcher) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeMatcherValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_MATCHER_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeMatcherValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeMatcherValue")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
+}
+impl From<CssAttributeMatcherValue> for SyntaxNode {
+    fn from(n: CssAttributeMatcherValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeMatcherValue> for SyntaxElement {
+    fn from(n: CssAttributeMatcherValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeName")
+            .field(
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
+}
+impl From<CssAttributeName> for SyntaxNode {
+    fn from(n: CssAttributeName) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeName> for SyntaxElement {
+    fn from(n: CssAttributeName) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_SELECTOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeSelector")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("matcher", &support::DebugOptionalElement(self.matcher()))
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssAttributeSelector> for SyntaxNode {
+    fn from(n: CssAttributeSelector) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeSelector> for SyntaxElement {
+    fn from(n: CssAttributeSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssBinaryExpression {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BINARY_EXPRESSION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BINARY_EXPRESSION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBinaryExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBinaryExpression")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field(
+                "operator_token",
+                &support::DebugSyntaxResult(self.operator_token()),
+            )
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssBinaryExpression> for SyntaxNode {
+    fn from(n: CssBinaryExpression) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBinaryExpression> for SyntaxElement {
+    fn from(n: CssBinaryExpression) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssBracketedValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BRACKETED_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BRACKETED_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBracketedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBracketedValue")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssBracketedValue> for SyntaxNode {
+    fn from(n: CssBracketedValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBracketedValue> for SyntaxElement {
+    fn from(n: CssBracketedValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssCharsetAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CHARSET_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CHARSET_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssCharsetAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssCharsetAtRule")
+            .field(
+                "charset_token",
+                &support::DebugSyntaxResult(self.charset_token()),
+            )
+            .field("encoding", &support::DebugSyntaxResult(self.encoding()))
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssCharsetAtRule> for SyntaxNode {
+    fn from(n: CssCharsetAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssCharsetAtRule> for SyntaxElement {
+    fn from(n: CssCharsetAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssClassSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CLASS_SELECTOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssClassSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssClassSelector")
+            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
+}
+impl From<CssClassSelector> for SyntaxNode {
+    fn from(n: CssClassSelector) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssClassSelector> for SyntaxElement {
+    fn from(n: CssClassSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssColor {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COLOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssColor")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssColor> for SyntaxNode {
+    fn from(n: CssColor) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssColor> for SyntaxElement {
+    fn from(n: CssColor) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssColorProfileAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR_PROFILE_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COLOR_PROFILE_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssColorProfileAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssColorProfileAtRule")
+            .field(
+                "color_profile_token",
+                &support::DebugSyntaxResult(self.color_profile_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
+            .finish()
+    }
+}
+impl From<CssColorProfileAtRule> for SyntaxNode {
+    fn from(n: CssColorProfileAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssColorProfileAtRule> for SyntaxElement {
+    fn from(n: CssColorProfileAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssComplexSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPLEX_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPLEX_SELECTOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssComplexSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssComplexSelector")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssComplexSelector> for SyntaxNode {
+    fn from(n: CssComplexSelector) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssComplexSelector> for SyntaxElement {
+    fn from(n: CssComplexSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssComposesImportSpecifier {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_IMPORT_SPECIFIER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPOSES_IMPORT_SPECIFIER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssComposesImportSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssComposesImportSpecifier")
+            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
+            .field("source", &support::DebugSyntaxResult(self.source()))
+            .finish()
+    }
+}
+impl From<CssComposesImportSpecifier> for SyntaxNode {
+    fn from(n: CssComposesImportSpecifier) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssComposesImportSpecifier> for SyntaxElement {
+    fn from(n: CssComposesImportSpecifier) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssComposesProperty {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_PROPERTY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPOSES_PROPERTY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssComposesProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssComposesProperty")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<CssComposesProperty> for SyntaxNode {
+    fn from(n: CssComposesProperty) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssComposesProperty> for SyntaxElement {
+    fn from(n: CssComposesProperty) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssComposesPropertyValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_PROPERTY_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPOSES_PROPERTY_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssComposesPropertyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssComposesPropertyValue")
+            .field("classes", &self.classes())
+            .field(
+                "specifier",
+                &support::DebugOptionalElement(self.specifier()),
+            )
+            .finish()
+    }
+}
+impl From<CssComposesPropertyValue> for SyntaxNode {
+    fn from(n: CssComposesPropertyValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssComposesPropertyValue> for SyntaxElement {
+    fn from(n: CssComposesPropertyValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssCompoundSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPOUND_SELECTOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssCompoundSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssCompoundSelector")
+            .field("nesting_selectors", &self.nesting_selectors())
+            .field(
+                "simple_selector",
+                &support::DebugOptionalElement(self.simple_selector()),
+            )
+            .field("sub_selectors", &self.sub_selectors())
+            .finish()
+    }
+}
+impl From<CssCompoundSelector> for SyntaxNode {
+    fn from(n: CssCompoundSelector) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssCompoundSelector> for SyntaxElement {
+    fn from(n: CssCompoundSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerAndQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AND_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_AND_QUERY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerAndQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerAndQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssContainerAndQuery> for SyntaxNode {
+    fn from(n: CssContainerAndQuery) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerAndQuery> for SyntaxElement {
+    fn from(n: CssContainerAndQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerAtRule")
+            .field(
+                "container_token",
+                &support::DebugSyntaxResult(self.container_token()),
+            )
+            .field("name", &support::DebugOptionalElement(self.name()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
+            .finish()
+    }
+}
+impl From<CssContainerAtRule> for SyntaxNode {
+    fn from(n: CssContainerAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerAtRule> for SyntaxElement {
+    fn from(n: CssContainerAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerNotQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_NOT_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_NOT_QUERY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerNotQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerNotQuery")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .finish()
+    }
+}
+impl From<CssContainerNotQuery> for SyntaxNode {
+    fn from(n: CssContainerNotQuery) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerNotQuery> for SyntaxElement {
+    fn from(n: CssContainerNotQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerOrQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_OR_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_OR_QUERY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerOrQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerOrQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssContainerOrQuery> for SyntaxNode {
+    fn from(n: CssContainerOrQuery) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerOrQuery> for SyntaxElement {
+    fn from(n: CssContainerOrQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerQueryInParens {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_QUERY_IN_PARENS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_QUERY_IN_PARENS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerQueryInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerQueryInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssContainerQueryInParens> for SyntaxNode {
+    fn from(n: CssContainerQueryInParens) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerQueryInParens> for SyntaxElement {
+    fn from(n: CssContainerQueryInParens) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerSizeFeatureInParens {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_SIZE_FEATURE_IN_PARENS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerSizeFeatureInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerSizeFeatureInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("feature", &support::DebugSyntaxResult(self.feature()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssContainerSizeFeatureInParens> for SyntaxNode {
+    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerSizeFeatureInParens> for SyntaxElement {
+    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerStyleAndQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_AND_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_AND_QUERY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerStyleAndQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleAndQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssContainerStyleAndQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleAndQuery) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerStyleAndQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleAndQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerStyleInParens {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_IN_PARENS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_IN_PARENS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
-        match &self {
-            AnyCssValue::CssCustomIdentifier(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
-        match &self {
-            AnyCssValue::CssDashedIdentifier(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for CssContainerStyleInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssValue::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerStyleInParens> for SyntaxNode {
+    fn from(n: CssContainerStyleInParens) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_metavariable(&self) -> Option<&CssMetavariable> {
-        match &self {
-            AnyCssValue::CssMetavariable(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerStyleInParens> for SyntaxElement {
+    fn from(n: CssContainerStyleInParens) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_css_number(&self) -> Option<&CssNumber> {
-        match &self {
-            AnyCssValue::CssNumber(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssContainerStyleNotQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_NOT_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_NOT_QUERY
     }
-    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
-        match &self {
-            AnyCssValue::CssRatio(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssValue::CssString(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_unicode_range(&self) -> Option<&CssUnicodeRange> {
-        match &self {
-            AnyCssValue::CssUnicodeRange(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssValueAtRuleClause {
-    CssValueAtRuleDeclarationClause(CssValueAtRuleDeclarationClause),
-    CssValueAtRuleImportClause(CssValueAtRuleImportClause),
-}
-impl AnyCssValueAtRuleClause {
-    pub fn as_css_value_at_rule_declaration_clause(
-        &self,
-    ) -> Option<&CssValueAtRuleDeclarationClause> {
-        match &self {
-            AnyCssValueAtRuleClause::CssValueAtRuleDeclarationClause(item) => Some(item),
-            _ => None,
-        }
+impl std::fmt::Debug for CssContainerStyleNotQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleNotQuery")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .finish()
     }
-    pub fn as_css_value_at_rule_import_clause(&self) -> Option<&CssValueAtRuleImportClause> {
-        match &self {
-            AnyCssValueAtRuleClause::CssValueAtRuleImportClause(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerStyleNotQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleNotQuery) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssValueAtRuleImportSource {
-    CssIdentifier(CssIdentifier),
-    CssString(CssString),
+impl From<CssContainerStyleNotQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleNotQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssValueAtRuleImportSource {
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssValueAtRuleImportSource::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssContainerStyleOrQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_OR_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_OR_QUERY
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssValueAtRuleImportSource::CssString(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssValueAtRuleImportSpecifier {
-    CssValueAtRuleImportSpecifier(CssValueAtRuleImportSpecifier),
-    CssValueAtRuleNamedImportSpecifier(CssValueAtRuleNamedImportSpecifier),
+impl std::fmt::Debug for CssContainerStyleOrQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleOrQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
 }
-impl AnyCssValueAtRuleImportSpecifier {
-    pub fn as_css_value_at_rule_import_specifier(&self) -> Option<&CssValueAtRuleImportSpecifier> {
-        match &self {
-            AnyCssValueAtRuleImportSpecifier::CssValueAtRuleImportSpecifier(item) => Some(item),
-            _ => None,
-        }
+impl From<CssContainerStyleOrQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleOrQuery) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_value_at_rule_named_import_specifier(
-        &self,
-    ) -> Option<&CssValueAtRuleNamedImportSpecifier> {
-        match &self {
-            AnyCssValueAtRuleImportSpecifier::CssValueAtRuleNamedImportSpecifier(item) => {
-                Some(item)
-            }
-            _ => None,
+}
+impl From<CssContainerStyleOrQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleOrQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerStyleQueryInParens {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_QUERY_IN_PARENS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_QUERY_IN_PARENS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCssValueAtRuleProperty {
-    CssBogusProperty(CssBogusProperty),
-    CssValueAtRuleGenericProperty(CssValueAtRuleGenericProperty),
+impl std::fmt::Debug for CssContainerStyleQueryInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleQueryInParens")
+            .field(
+                "style_token",
+                &support::DebugSyntaxResult(self.style_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
 }
-impl AnyCssValueAtRuleProperty {
-    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
-        match &self {
-            AnyCssValueAtRuleProperty::CssBogusProperty(item) => Some(item),
-            _ => None,
-        }
+impl From<CssContainerStyleQueryInParens> for SyntaxNode {
+    fn from(n: CssContainerStyleQueryInParens) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_value_at_rule_generic_property(&self) -> Option<&CssValueAtRuleGenericProperty> {
-        match &self {
-            AnyCssValueAtRuleProperty::CssValueAtRuleGenericProperty(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerStyleQueryInParens> for SyntaxElement {
+    fn from(n: CssContainerStyleQueryInParens) -> SyntaxElement {
+        n.syntax.into()
     }
 }
-impl AstNode for CssAtRule {
+impl AstNode for CssCounterStyleAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COUNTER_STYLE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_AT_RULE
+        kind == CSS_COUNTER_STYLE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8788,30 +11327,34 @@ impl AstNode for CssAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAtRule {
+impl std::fmt::Debug for CssCounterStyleAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAtRule")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
-            .field("rule", &support::DebugSyntaxResult(self.rule()))
+        f.debug_struct("CssCounterStyleAtRule")
+            .field(
+                "counter_style_token",
+                &support::DebugSyntaxResult(self.counter_style_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssAtRule> for SyntaxNode {
-    fn from(n: CssAtRule) -> SyntaxNode {
+impl From<CssCounterStyleAtRule> for SyntaxNode {
+    fn from(n: CssCounterStyleAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAtRule> for SyntaxElement {
-    fn from(n: CssAtRule) -> SyntaxElement {
+impl From<CssCounterStyleAtRule> for SyntaxElement {
+    fn from(n: CssCounterStyleAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeMatcher {
+impl AstNode for CssCustomIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_MATCHER
+        kind == CSS_CUSTOM_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8827,31 +11370,32 @@ impl AstNode for CssAttributeMatcher {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeMatcher {
+impl std::fmt::Debug for CssCustomIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMatcher")
-            .field("operator", &support::DebugSyntaxResult(self.operator()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
-            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+        f.debug_struct("CssCustomIdentifier")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssAttributeMatcher> for SyntaxNode {
-    fn from(n: CssAttributeMatcher) -> SyntaxNode {
+impl From<CssCustomIdentifier> for SyntaxNode {
+    fn from(n: CssCustomIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeMatcher> for SyntaxElement {
-    fn from(n: CssAttributeMatcher) -> SyntaxElement {
+impl From<CssCustomIdentifier> for SyntaxElement {
+    fn from(n: CssCustomIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeMatcherValue {
+impl AstNode for CssDashedIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DASHED_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_MATCHER_VALUE
+        kind == CSS_DASHED_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8867,29 +11411,32 @@ impl AstNode for CssAttributeMatcherValue {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeMatcherValue {
+impl std::fmt::Debug for CssDashedIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMatcherValue")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssDashedIdentifier")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssAttributeMatcherValue> for SyntaxNode {
-    fn from(n: CssAttributeMatcherValue) -> SyntaxNode {
+impl From<CssDashedIdentifier> for SyntaxNode {
+    fn from(n: CssDashedIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeMatcherValue> for SyntaxElement {
-    fn from(n: CssAttributeMatcherValue) -> SyntaxElement {
+impl From<CssDashedIdentifier> for SyntaxElement {
+    fn from(n: CssDashedIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeName {
+impl AstNode for CssDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_NAME
+        kind == CSS_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8905,33 +11452,33 @@ impl AstNode for CssAttributeName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeName {
+impl std::fmt::Debug for CssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeName")
+        f.debug_struct("CssDeclaration")
+            .field("property", &support::DebugSyntaxResult(self.property()))
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "important",
+                &support::DebugOptionalElement(self.important()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssAttributeName> for SyntaxNode {
-    fn from(n: CssAttributeName) -> SyntaxNode {
+impl From<CssDeclaration> for SyntaxNode {
+    fn from(n: CssDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeName> for SyntaxElement {
-    fn from(n: CssAttributeName) -> SyntaxElement {
+impl From<CssDeclaration> for SyntaxElement {
+    fn from(n: CssDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeSelector {
+impl AstNode for CssDeclarationBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_SELECTOR
+        kind == CSS_DECLARATION_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8947,38 +11494,37 @@ impl AstNode for CssAttributeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeSelector {
+impl std::fmt::Debug for CssDeclarationBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeSelector")
+        f.debug_struct("CssDeclarationBlock")
             .field(
-                "l_brack_token",
-                &support::DebugSyntaxResult(self.l_brack_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("matcher", &support::DebugOptionalElement(self.matcher()))
+            .field("declarations", &self.declarations())
             .field(
-                "r_brack_token",
-                &support::DebugSyntaxResult(self.r_brack_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssAttributeSelector> for SyntaxNode {
-    fn from(n: CssAttributeSelector) -> SyntaxNode {
+impl From<CssDeclarationBlock> for SyntaxNode {
+    fn from(n: CssDeclarationBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeSelector> for SyntaxElement {
-    fn from(n: CssAttributeSelector) -> SyntaxElement {
+impl From<CssDeclarationBlock> for SyntaxElement {
+    fn from(n: CssDeclarationBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssBinaryExpression {
+impl AstNode for CssDeclarationImportant {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BINARY_EXPRESSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_IMPORTANT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BINARY_EXPRESSION
+        kind == CSS_DECLARATION_IMPORTANT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8994,34 +11540,33 @@ impl AstNode for CssBinaryExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBinaryExpression {
+impl std::fmt::Debug for CssDeclarationImportant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBinaryExpression")
-            .field("left", &support::DebugSyntaxResult(self.left()))
+        f.debug_struct("CssDeclarationImportant")
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
             .field(
-                "operator_token",
-                &support::DebugSyntaxResult(self.operator_token()),
+                "important_token",
+                &support::DebugSyntaxResult(self.important_token()),
             )
-            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssBinaryExpression> for SyntaxNode {
-    fn from(n: CssBinaryExpression) -> SyntaxNode {
+impl From<CssDeclarationImportant> for SyntaxNode {
+    fn from(n: CssDeclarationImportant) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBinaryExpression> for SyntaxElement {
-    fn from(n: CssBinaryExpression) -> SyntaxElement {
+impl From<CssDeclarationImportant> for SyntaxElement {
+    fn from(n: CssDeclarationImportant) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssBracketedValue {
+impl AstNode for CssDeclarationOrAtRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BRACKETED_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BRACKETED_VALUE
+        kind == CSS_DECLARATION_OR_AT_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9037,37 +11582,37 @@ impl AstNode for CssBracketedValue {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBracketedValue {
+impl std::fmt::Debug for CssDeclarationOrAtRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBracketedValue")
+        f.debug_struct("CssDeclarationOrAtRuleBlock")
             .field(
-                "l_brack_token",
-                &support::DebugSyntaxResult(self.l_brack_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
             .field("items", &self.items())
             .field(
-                "r_brack_token",
-                &support::DebugSyntaxResult(self.r_brack_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssBracketedValue> for SyntaxNode {
-    fn from(n: CssBracketedValue) -> SyntaxNode {
+impl From<CssDeclarationOrAtRuleBlock> for SyntaxNode {
+    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBracketedValue> for SyntaxElement {
-    fn from(n: CssBracketedValue) -> SyntaxElement {
+impl From<CssDeclarationOrAtRuleBlock> for SyntaxElement {
+    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCharsetAtRule {
+impl AstNode for CssDeclarationOrRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CHARSET_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CHARSET_AT_RULE
+        kind == CSS_DECLARATION_OR_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9083,37 +11628,37 @@ impl AstNode for CssCharsetAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCharsetAtRule {
+impl std::fmt::Debug for CssDeclarationOrRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCharsetAtRule")
+        f.debug_struct("CssDeclarationOrRuleBlock")
             .field(
-                "charset_token",
-                &support::DebugSyntaxResult(self.charset_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("encoding", &support::DebugSyntaxResult(self.encoding()))
+            .field("items", &self.items())
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssCharsetAtRule> for SyntaxNode {
-    fn from(n: CssCharsetAtRule) -> SyntaxNode {
+impl From<CssDeclarationOrRuleBlock> for SyntaxNode {
+    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCharsetAtRule> for SyntaxElement {
-    fn from(n: CssCharsetAtRule) -> SyntaxElement {
+impl From<CssDeclarationOrRuleBlock> for SyntaxElement {
+    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssClassSelector {
+impl AstNode for CssDeclarationWithSemicolon {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_WITH_SEMICOLON as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CLASS_SELECTOR
+        kind == CSS_DECLARATION_WITH_SEMICOLON
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9129,30 +11674,36 @@ impl AstNode for CssClassSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssClassSelector {
+impl std::fmt::Debug for CssDeclarationWithSemicolon {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssClassSelector")
-            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssDeclarationWithSemicolon")
+            .field(
+                "declaration",
+                &support::DebugSyntaxResult(self.declaration()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssClassSelector> for SyntaxNode {
-    fn from(n: CssClassSelector) -> SyntaxNode {
+impl From<CssDeclarationWithSemicolon> for SyntaxNode {
+    fn from(n: CssDeclarationWithSemicolon) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssClassSelector> for SyntaxElement {
-    fn from(n: CssClassSelector) -> SyntaxElement {
+impl From<CssDeclarationWithSemicolon> for SyntaxElement {
+    fn from(n: CssDeclarationWithSemicolon) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssColor {
+impl AstNode for CssDocumentAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COLOR
+        kind == CSS_DOCUMENT_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9168,33 +11719,34 @@ impl AstNode for CssColor {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssColor {
+impl std::fmt::Debug for CssDocumentAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssColor")
-            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+        f.debug_struct("CssDocumentAtRule")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "document_token",
+                &support::DebugSyntaxResult(self.document_token()),
             )
+            .field("matchers", &self.matchers())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssColor> for SyntaxNode {
-    fn from(n: CssColor) -> SyntaxNode {
+impl From<CssDocumentAtRule> for SyntaxNode {
+    fn from(n: CssDocumentAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssColor> for SyntaxElement {
-    fn from(n: CssColor) -> SyntaxElement {
+impl From<CssDocumentAtRule> for SyntaxElement {
+    fn from(n: CssDocumentAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssColorProfileAtRule {
+impl AstNode for CssDocumentCustomMatcher {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR_PROFILE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_CUSTOM_MATCHER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COLOR_PROFILE_AT_RULE
+        kind == CSS_DOCUMENT_CUSTOM_MATCHER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9210,34 +11762,38 @@ impl AstNode for CssColorProfileAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssColorProfileAtRule {
+impl std::fmt::Debug for CssDocumentCustomMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssColorProfileAtRule")
+        f.debug_struct("CssDocumentCustomMatcher")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "color_profile_token",
-                &support::DebugSyntaxResult(self.color_profile_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssColorProfileAtRule> for SyntaxNode {
-    fn from(n: CssColorProfileAtRule) -> SyntaxNode {
+impl From<CssDocumentCustomMatcher> for SyntaxNode {
+    fn from(n: CssDocumentCustomMatcher) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssColorProfileAtRule> for SyntaxElement {
-    fn from(n: CssColorProfileAtRule) -> SyntaxElement {
+impl From<CssDocumentCustomMatcher> for SyntaxElement {
+    fn from(n: CssDocumentCustomMatcher) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssComplexSelector {
+impl AstNode for CssFontFaceAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPLEX_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FACE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPLEX_SELECTOR
+        kind == CSS_FONT_FACE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9253,31 +11809,33 @@ impl AstNode for CssComplexSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssComplexSelector {
+impl std::fmt::Debug for CssFontFaceAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssComplexSelector")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssFontFaceAtRule")
+            .field(
+                "font_face_token",
+                &support::DebugSyntaxResult(self.font_face_token()),
+            )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssComplexSelector> for SyntaxNode {
-    fn from(n: CssComplexSelector) -> SyntaxNode {
+impl From<CssFontFaceAtRule> for SyntaxNode {
+    fn from(n: CssFontFaceAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssComplexSelector> for SyntaxElement {
-    fn from(n: CssComplexSelector) -> SyntaxElement {
+impl From<CssFontFaceAtRule> for SyntaxElement {
+    fn from(n: CssFontFaceAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssComposesImportSpecifier {
+impl AstNode for CssFontFamilyName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_IMPORT_SPECIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOSES_IMPORT_SPECIFIER
+        kind == CSS_FONT_FAMILY_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9293,30 +11851,29 @@ impl AstNode for CssComposesImportSpecifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssComposesImportSpecifier {
+impl std::fmt::Debug for CssFontFamilyName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssComposesImportSpecifier")
-            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
-            .field("source", &support::DebugSyntaxResult(self.source()))
+        f.debug_struct("CssFontFamilyName")
+            .field("names", &self.names())
             .finish()
     }
 }
-impl From<CssComposesImportSpecifier> for SyntaxNode {
-    fn from(n: CssComposesImportSpecifier) -> SyntaxNode {
+impl From<CssFontFamilyName> for SyntaxNode {
+    fn from(n: CssFontFamilyName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssComposesImportSpecifier> for SyntaxElement {
-    fn from(n: CssComposesImportSpecifier) -> SyntaxElement {
+impl From<CssFontFamilyName> for SyntaxElement {
+    fn from(n: CssFontFamilyName) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssComposesProperty {
+impl AstNode for CssFontFeatureValuesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_PROPERTY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOSES_PROPERTY
+        kind == CSS_FONT_FEATURE_VALUES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9332,34 +11889,34 @@ impl AstNode for CssComposesProperty {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssComposesProperty {
+impl std::fmt::Debug for CssFontFeatureValuesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssComposesProperty")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssFontFeatureValuesAtRule")
             .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
+                "font_feature_values_token",
+                &support::DebugSyntaxResult(self.font_feature_values_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("names", &self.names())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssComposesProperty> for SyntaxNode {
-    fn from(n: CssComposesProperty) -> SyntaxNode {
+impl From<CssFontFeatureValuesAtRule> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssComposesProperty> for SyntaxElement {
-    fn from(n: CssComposesProperty) -> SyntaxElement {
+impl From<CssFontFeatureValuesAtRule> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssComposesPropertyValue {
+impl AstNode for CssFontFeatureValuesBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_PROPERTY_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOSES_PROPERTY_VALUE
+        kind == CSS_FONT_FEATURE_VALUES_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9375,33 +11932,37 @@ impl AstNode for CssComposesPropertyValue {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssComposesPropertyValue {
+impl std::fmt::Debug for CssFontFeatureValuesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssComposesPropertyValue")
-            .field("classes", &self.classes())
+        f.debug_struct("CssFontFeatureValuesBlock")
             .field(
-                "specifier",
-                &support::DebugOptionalElement(self.specifier()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssComposesPropertyValue> for SyntaxNode {
-    fn from(n: CssComposesPropertyValue) -> SyntaxNode {
+impl From<CssFontFeatureValuesBlock> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssComposesPropertyValue> for SyntaxElement {
-    fn from(n: CssComposesPropertyValue) -> SyntaxElement {
+impl From<CssFontFeatureValuesBlock> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCompoundSelector {
+impl AstNode for CssFontFeatureValuesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOUND_SELECTOR
+        kind == CSS_FONT_FEATURE_VALUES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9417,34 +11978,31 @@ impl AstNode for CssCompoundSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCompoundSelector {
+impl std::fmt::Debug for CssFontFeatureValuesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCompoundSelector")
-            .field("nesting_selectors", &self.nesting_selectors())
-            .field(
-                "simple_selector",
-                &support::DebugOptionalElement(self.simple_selector()),
-            )
-            .field("sub_selectors", &self.sub_selectors())
+        f.debug_struct("CssFontFeatureValuesItem")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssCompoundSelector> for SyntaxNode {
-    fn from(n: CssCompoundSelector) -> SyntaxNode {
+impl From<CssFontFeatureValuesItem> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCompoundSelector> for SyntaxElement {
-    fn from(n: CssCompoundSelector) -> SyntaxElement {
+impl From<CssFontFeatureValuesItem> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerAndQuery {
+impl AstNode for CssFontPaletteValuesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AND_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_PALETTE_VALUES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_AND_QUERY
+        kind == CSS_FONT_PALETTE_VALUES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9460,31 +12018,34 @@ impl AstNode for CssContainerAndQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerAndQuery {
+impl std::fmt::Debug for CssFontPaletteValuesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerAndQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssFontPaletteValuesAtRule")
+            .field(
+                "font_palette_values_token",
+                &support::DebugSyntaxResult(self.font_palette_values_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerAndQuery> for SyntaxNode {
-    fn from(n: CssContainerAndQuery) -> SyntaxNode {
+impl From<CssFontPaletteValuesAtRule> for SyntaxNode {
+    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerAndQuery> for SyntaxElement {
-    fn from(n: CssContainerAndQuery) -> SyntaxElement {
+impl From<CssFontPaletteValuesAtRule> for SyntaxElement {
+    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerAtRule {
+impl AstNode for CssFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_AT_RULE
+        kind == CSS_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9500,35 +12061,38 @@ impl AstNode for CssContainerAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerAtRule {
+impl std::fmt::Debug for CssFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerAtRule")
+        f.debug_struct("CssFunction")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "container_token",
-                &support::DebugSyntaxResult(self.container_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("name", &support::DebugOptionalElement(self.name()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerAtRule> for SyntaxNode {
-    fn from(n: CssContainerAtRule) -> SyntaxNode {
+impl From<CssFunction> for SyntaxNode {
+    fn from(n: CssFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerAtRule> for SyntaxElement {
-    fn from(n: CssContainerAtRule) -> SyntaxElement {
+impl From<CssFunction> for SyntaxElement {
+    fn from(n: CssFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerNotQuery {
+impl AstNode for CssGenericDelimiter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_NOT_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_DELIMITER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_NOT_QUERY
+        kind == CSS_GENERIC_DELIMITER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9544,30 +12108,29 @@ impl AstNode for CssContainerNotQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerNotQuery {
+impl std::fmt::Debug for CssGenericDelimiter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerNotQuery")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssGenericDelimiter")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssContainerNotQuery> for SyntaxNode {
-    fn from(n: CssContainerNotQuery) -> SyntaxNode {
+impl From<CssGenericDelimiter> for SyntaxNode {
+    fn from(n: CssGenericDelimiter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerNotQuery> for SyntaxElement {
-    fn from(n: CssContainerNotQuery) -> SyntaxElement {
+impl From<CssGenericDelimiter> for SyntaxElement {
+    fn from(n: CssGenericDelimiter) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerOrQuery {
+impl AstNode for CssGenericProperty {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_OR_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_PROPERTY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_OR_QUERY
+        kind == CSS_GENERIC_PROPERTY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9583,31 +12146,34 @@ impl AstNode for CssContainerOrQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerOrQuery {
+impl std::fmt::Debug for CssGenericProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerOrQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssGenericProperty")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &self.value())
             .finish()
     }
 }
-impl From<CssContainerOrQuery> for SyntaxNode {
-    fn from(n: CssContainerOrQuery) -> SyntaxNode {
+impl From<CssGenericProperty> for SyntaxNode {
+    fn from(n: CssGenericProperty) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerOrQuery> for SyntaxElement {
-    fn from(n: CssContainerOrQuery) -> SyntaxElement {
+impl From<CssGenericProperty> for SyntaxElement {
+    fn from(n: CssGenericProperty) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerQueryInParens {
+impl AstNode for CssIdSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_QUERY_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ID_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_QUERY_IN_PARENS
+        kind == CSS_ID_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9623,37 +12189,30 @@ impl AstNode for CssContainerQueryInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerQueryInParens {
+impl std::fmt::Debug for CssIdSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerQueryInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("query", &support::DebugSyntaxResult(self.query()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssIdSelector")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssContainerQueryInParens> for SyntaxNode {
-    fn from(n: CssContainerQueryInParens) -> SyntaxNode {
+impl From<CssIdSelector> for SyntaxNode {
+    fn from(n: CssIdSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerQueryInParens> for SyntaxElement {
-    fn from(n: CssContainerQueryInParens) -> SyntaxElement {
+impl From<CssIdSelector> for SyntaxElement {
+    fn from(n: CssIdSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerSizeFeatureInParens {
+impl AstNode for CssIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_SIZE_FEATURE_IN_PARENS
+        kind == CSS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9669,37 +12228,32 @@ impl AstNode for CssContainerSizeFeatureInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerSizeFeatureInParens {
+impl std::fmt::Debug for CssIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerSizeFeatureInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("feature", &support::DebugSyntaxResult(self.feature()))
+        f.debug_struct("CssIdentifier")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<CssContainerSizeFeatureInParens> for SyntaxNode {
-    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxNode {
+impl From<CssIdentifier> for SyntaxNode {
+    fn from(n: CssIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerSizeFeatureInParens> for SyntaxElement {
-    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxElement {
+impl From<CssIdentifier> for SyntaxElement {
+    fn from(n: CssIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleAndQuery {
+impl AstNode for CssImportAnonymousLayer {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_AND_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_ANONYMOUS_LAYER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_AND_QUERY
+        kind == CSS_IMPORT_ANONYMOUS_LAYER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9715,31 +12269,32 @@ impl AstNode for CssContainerStyleAndQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleAndQuery {
+impl std::fmt::Debug for CssImportAnonymousLayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleAndQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssImportAnonymousLayer")
+            .field(
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerStyleAndQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleAndQuery) -> SyntaxNode {
+impl From<CssImportAnonymousLayer> for SyntaxNode {
+    fn from(n: CssImportAnonymousLayer) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleAndQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleAndQuery) -> SyntaxElement {
+impl From<CssImportAnonymousLayer> for SyntaxElement {
+    fn from(n: CssImportAnonymousLayer) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleInParens {
+impl AstNode for CssImportAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_IN_PARENS
+        kind == CSS_IMPORT_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9755,37 +12310,40 @@ impl AstNode for CssContainerStyleInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleInParens {
+impl std::fmt::Debug for CssImportAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleInParens")
+        f.debug_struct("CssImportAtRule")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "import_token",
+                &support::DebugSyntaxResult(self.import_token()),
             )
-            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field("layer", &support::DebugOptionalElement(self.layer()))
+            .field("supports", &support::DebugOptionalElement(self.supports()))
+            .field("media", &self.media())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssContainerStyleInParens> for SyntaxNode {
-    fn from(n: CssContainerStyleInParens) -> SyntaxNode {
+impl From<CssImportAtRule> for SyntaxNode {
+    fn from(n: CssImportAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleInParens> for SyntaxElement {
-    fn from(n: CssContainerStyleInParens) -> SyntaxElement {
+impl From<CssImportAtRule> for SyntaxElement {
+    fn from(n: CssImportAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleNotQuery {
+impl AstNode for CssImportNamedLayer {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_NOT_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_NAMED_LAYER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_NOT_QUERY
+        kind == CSS_IMPORT_NAMED_LAYER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9801,30 +12359,41 @@ impl AstNode for CssContainerStyleNotQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleNotQuery {
+impl std::fmt::Debug for CssImportNamedLayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleNotQuery")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssImportNamedLayer")
+            .field(
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("name", &self.name())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerStyleNotQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleNotQuery) -> SyntaxNode {
+impl From<CssImportNamedLayer> for SyntaxNode {
+    fn from(n: CssImportNamedLayer) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleNotQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleNotQuery) -> SyntaxElement {
+impl From<CssImportNamedLayer> for SyntaxElement {
+    fn from(n: CssImportNamedLayer) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleOrQuery {
+impl AstNode for CssImportSupports {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_OR_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_SUPPORTS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_OR_QUERY
+        kind == CSS_IMPORT_SUPPORTS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9840,31 +12409,41 @@ impl AstNode for CssContainerStyleOrQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleOrQuery {
+impl std::fmt::Debug for CssImportSupports {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleOrQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssImportSupports")
+            .field(
+                "supports_token",
+                &support::DebugSyntaxResult(self.supports_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerStyleOrQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleOrQuery) -> SyntaxNode {
+impl From<CssImportSupports> for SyntaxNode {
+    fn from(n: CssImportSupports) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleOrQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleOrQuery) -> SyntaxElement {
+impl From<CssImportSupports> for SyntaxElement {
+    fn from(n: CssImportSupports) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleQueryInParens {
+impl AstNode for CssKeyframesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_QUERY_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_QUERY_IN_PARENS
+        kind == CSS_KEYFRAMES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9880,41 +12459,34 @@ impl AstNode for CssContainerStyleQueryInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleQueryInParens {
+impl std::fmt::Debug for CssKeyframesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleQueryInParens")
-            .field(
-                "style_token",
-                &support::DebugSyntaxResult(self.style_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssKeyframesAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "keyframes_token",
+                &support::DebugSyntaxResult(self.keyframes_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerStyleQueryInParens> for SyntaxNode {
-    fn from(n: CssContainerStyleQueryInParens) -> SyntaxNode {
+impl From<CssKeyframesAtRule> for SyntaxNode {
+    fn from(n: CssKeyframesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleQueryInParens> for SyntaxElement {
-    fn from(n: CssContainerStyleQueryInParens) -> SyntaxElement {
+impl From<CssKeyframesAtRule> for SyntaxElement {
+    fn from(n: CssKeyframesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCounterStyleAtRule {
+impl AstNode for CssKeyframesBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COUNTER_STYLE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COUNTER_STYLE_AT_RULE
+        kind == CSS_KEYFRAMES_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9930,34 +12502,37 @@ impl AstNode for CssCounterStyleAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCounterStyleAtRule {
+impl std::fmt::Debug for CssKeyframesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCounterStyleAtRule")
+        f.debug_struct("CssKeyframesBlock")
             .field(
-                "counter_style_token",
-                &support::DebugSyntaxResult(self.counter_style_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssCounterStyleAtRule> for SyntaxNode {
-    fn from(n: CssCounterStyleAtRule) -> SyntaxNode {
+impl From<CssKeyframesBlock> for SyntaxNode {
+    fn from(n: CssKeyframesBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCounterStyleAtRule> for SyntaxElement {
-    fn from(n: CssCounterStyleAtRule) -> SyntaxElement {
+impl From<CssKeyframesBlock> for SyntaxElement {
+    fn from(n: CssKeyframesBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCustomIdentifier {
+impl AstNode for CssKeyframesIdentSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_IDENT_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CUSTOM_IDENTIFIER
+        kind == CSS_KEYFRAMES_IDENT_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9971,34 +12546,31 @@ impl AstNode for CssCustomIdentifier {
     }
     fn into_syntax(self) -> SyntaxNode {
         self.syntax
-    }
-}
-impl std::fmt::Debug for CssCustomIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCustomIdentifier")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+    }
+}
+impl std::fmt::Debug for CssKeyframesIdentSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssKeyframesIdentSelector")
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssCustomIdentifier> for SyntaxNode {
-    fn from(n: CssCustomIdentifier) -> SyntaxNode {
+impl From<CssKeyframesIdentSelector> for SyntaxNode {
+    fn from(n: CssKeyframesIdentSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCustomIdentifier> for SyntaxElement {
-    fn from(n: CssCustomIdentifier) -> SyntaxElement {
+impl From<CssKeyframesIdentSelector> for SyntaxElement {
+    fn from(n: CssKeyframesIdentSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDashedIdentifier {
+impl AstNode for CssKeyframesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DASHED_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DASHED_IDENTIFIER
+        kind == CSS_KEYFRAMES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10014,32 +12586,30 @@ impl AstNode for CssDashedIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDashedIdentifier {
+impl std::fmt::Debug for CssKeyframesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDashedIdentifier")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("CssKeyframesItem")
+            .field("selectors", &self.selectors())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssDashedIdentifier> for SyntaxNode {
-    fn from(n: CssDashedIdentifier) -> SyntaxNode {
+impl From<CssKeyframesItem> for SyntaxNode {
+    fn from(n: CssKeyframesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDashedIdentifier> for SyntaxElement {
-    fn from(n: CssDashedIdentifier) -> SyntaxElement {
+impl From<CssKeyframesItem> for SyntaxElement {
+    fn from(n: CssKeyframesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclaration {
+impl AstNode for CssKeyframesPercentageSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_PERCENTAGE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION
+        kind == CSS_KEYFRAMES_PERCENTAGE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10055,33 +12625,29 @@ impl AstNode for CssDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclaration {
+impl std::fmt::Debug for CssKeyframesPercentageSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclaration")
-            .field("property", &support::DebugSyntaxResult(self.property()))
-            .field(
-                "important",
-                &support::DebugOptionalElement(self.important()),
-            )
+        f.debug_struct("CssKeyframesPercentageSelector")
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssDeclaration> for SyntaxNode {
-    fn from(n: CssDeclaration) -> SyntaxNode {
+impl From<CssKeyframesPercentageSelector> for SyntaxNode {
+    fn from(n: CssKeyframesPercentageSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclaration> for SyntaxElement {
-    fn from(n: CssDeclaration) -> SyntaxElement {
+impl From<CssKeyframesPercentageSelector> for SyntaxElement {
+    fn from(n: CssKeyframesPercentageSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationBlock {
+impl AstNode for CssKeyframesScopeFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPE_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_BLOCK
+        kind == CSS_KEYFRAMES_SCOPE_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10097,37 +12663,38 @@ impl AstNode for CssDeclarationBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationBlock {
+impl std::fmt::Debug for CssKeyframesScopeFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationBlock")
+        f.debug_struct("CssKeyframesScopeFunction")
+            .field("scope", &support::DebugSyntaxResult(self.scope()))
             .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("declarations", &self.declarations())
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationBlock> for SyntaxNode {
-    fn from(n: CssDeclarationBlock) -> SyntaxNode {
+impl From<CssKeyframesScopeFunction> for SyntaxNode {
+    fn from(n: CssKeyframesScopeFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationBlock> for SyntaxElement {
-    fn from(n: CssDeclarationBlock) -> SyntaxElement {
+impl From<CssKeyframesScopeFunction> for SyntaxElement {
+    fn from(n: CssKeyframesScopeFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationImportant {
+impl AstNode for CssKeyframesScopePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_IMPORTANT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_IMPORTANT
+        kind == CSS_KEYFRAMES_SCOPE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10143,33 +12710,30 @@ impl AstNode for CssDeclarationImportant {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationImportant {
+impl std::fmt::Debug for CssKeyframesScopePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationImportant")
-            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-            .field(
-                "important_token",
-                &support::DebugSyntaxResult(self.important_token()),
-            )
+        f.debug_struct("CssKeyframesScopePrefix")
+            .field("scope", &support::DebugSyntaxResult(self.scope()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssDeclarationImportant> for SyntaxNode {
-    fn from(n: CssDeclarationImportant) -> SyntaxNode {
+impl From<CssKeyframesScopePrefix> for SyntaxNode {
+    fn from(n: CssKeyframesScopePrefix) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationImportant> for SyntaxElement {
-    fn from(n: CssDeclarationImportant) -> SyntaxElement {
+impl From<CssKeyframesScopePrefix> for SyntaxElement {
+    fn from(n: CssKeyframesScopePrefix) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationOrAtRuleBlock {
+impl AstNode for CssKeyframesScopedName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPED_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_AT_RULE_BLOCK
+        kind == CSS_KEYFRAMES_SCOPED_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10185,37 +12749,33 @@ impl AstNode for CssDeclarationOrAtRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationOrAtRuleBlock {
+impl std::fmt::Debug for CssKeyframesScopedName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationOrAtRuleBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
+        f.debug_struct("CssKeyframesScopedName")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("scope", &support::DebugSyntaxResult(self.scope()))
             .finish()
     }
 }
-impl From<CssDeclarationOrAtRuleBlock> for SyntaxNode {
-    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxNode {
+impl From<CssKeyframesScopedName> for SyntaxNode {
+    fn from(n: CssKeyframesScopedName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationOrAtRuleBlock> for SyntaxElement {
-    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxElement {
+impl From<CssKeyframesScopedName> for SyntaxElement {
+    fn from(n: CssKeyframesScopedName) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationOrRuleBlock {
+impl AstNode for CssLayerAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_RULE_BLOCK
+        kind == CSS_LAYER_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10231,37 +12791,33 @@ impl AstNode for CssDeclarationOrRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationOrRuleBlock {
+impl std::fmt::Debug for CssLayerAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationOrRuleBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
+        f.debug_struct("CssLayerAtRule")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
             )
+            .field("layer", &support::DebugSyntaxResult(self.layer()))
             .finish()
     }
 }
-impl From<CssDeclarationOrRuleBlock> for SyntaxNode {
-    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxNode {
+impl From<CssLayerAtRule> for SyntaxNode {
+    fn from(n: CssLayerAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationOrRuleBlock> for SyntaxElement {
-    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxElement {
+impl From<CssLayerAtRule> for SyntaxElement {
+    fn from(n: CssLayerAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationWithSemicolon {
+impl AstNode for CssLayerDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_WITH_SEMICOLON as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_WITH_SEMICOLON
+        kind == CSS_LAYER_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10277,36 +12833,30 @@ impl AstNode for CssDeclarationWithSemicolon {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationWithSemicolon {
+impl std::fmt::Debug for CssLayerDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationWithSemicolon")
-            .field(
-                "declaration",
-                &support::DebugSyntaxResult(self.declaration()),
-            )
-            .field(
-                "semicolon_token",
-                &support::DebugOptionalElement(self.semicolon_token()),
-            )
+        f.debug_struct("CssLayerDeclaration")
+            .field("references", &self.references())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssDeclarationWithSemicolon> for SyntaxNode {
-    fn from(n: CssDeclarationWithSemicolon) -> SyntaxNode {
+impl From<CssLayerDeclaration> for SyntaxNode {
+    fn from(n: CssLayerDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationWithSemicolon> for SyntaxElement {
-    fn from(n: CssDeclarationWithSemicolon) -> SyntaxElement {
+impl From<CssLayerDeclaration> for SyntaxElement {
+    fn from(n: CssLayerDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDocumentAtRule {
+impl AstNode for CssLayerReference {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DOCUMENT_AT_RULE
+        kind == CSS_LAYER_REFERENCE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10322,34 +12872,34 @@ impl AstNode for CssDocumentAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDocumentAtRule {
+impl std::fmt::Debug for CssLayerReference {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDocumentAtRule")
+        f.debug_struct("CssLayerReference")
+            .field("references", &self.references())
             .field(
-                "document_token",
-                &support::DebugSyntaxResult(self.document_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
-            .field("matchers", &self.matchers())
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssDocumentAtRule> for SyntaxNode {
-    fn from(n: CssDocumentAtRule) -> SyntaxNode {
+impl From<CssLayerReference> for SyntaxNode {
+    fn from(n: CssLayerReference) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDocumentAtRule> for SyntaxElement {
-    fn from(n: CssDocumentAtRule) -> SyntaxElement {
+impl From<CssLayerReference> for SyntaxElement {
+    fn from(n: CssLayerReference) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDocumentCustomMatcher {
+impl AstNode for CssListOfComponentValuesExpression {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_CUSTOM_MATCHER as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DOCUMENT_CUSTOM_MATCHER
+        kind == CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10365,38 +12915,29 @@ impl AstNode for CssDocumentCustomMatcher {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDocumentCustomMatcher {
+impl std::fmt::Debug for CssListOfComponentValuesExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDocumentCustomMatcher")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("value", &support::DebugSyntaxResult(self.value()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssListOfComponentValuesExpression")
+            .field("css_component_value_list", &self.css_component_value_list())
             .finish()
     }
 }
-impl From<CssDocumentCustomMatcher> for SyntaxNode {
-    fn from(n: CssDocumentCustomMatcher) -> SyntaxNode {
+impl From<CssListOfComponentValuesExpression> for SyntaxNode {
+    fn from(n: CssListOfComponentValuesExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDocumentCustomMatcher> for SyntaxElement {
-    fn from(n: CssDocumentCustomMatcher) -> SyntaxElement {
+impl From<CssListOfComponentValuesExpression> for SyntaxElement {
+    fn from(n: CssListOfComponentValuesExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFaceAtRule {
+impl AstNode for CssMarginAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FACE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MARGIN_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FACE_AT_RULE
+        kind == CSS_MARGIN_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10412,33 +12953,31 @@ impl AstNode for CssFontFaceAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFaceAtRule {
+impl std::fmt::Debug for CssMarginAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFaceAtRule")
-            .field(
-                "font_face_token",
-                &support::DebugSyntaxResult(self.font_face_token()),
-            )
+        f.debug_struct("CssMarginAtRule")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssFontFaceAtRule> for SyntaxNode {
-    fn from(n: CssFontFaceAtRule) -> SyntaxNode {
+impl From<CssMarginAtRule> for SyntaxNode {
+    fn from(n: CssMarginAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFaceAtRule> for SyntaxElement {
-    fn from(n: CssFontFaceAtRule) -> SyntaxElement {
+impl From<CssMarginAtRule> for SyntaxElement {
+    fn from(n: CssMarginAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFamilyName {
+impl AstNode for CssMediaAndCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FAMILY_NAME
+        kind == CSS_MEDIA_AND_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10454,29 +12993,31 @@ impl AstNode for CssFontFamilyName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFamilyName {
+impl std::fmt::Debug for CssMediaAndCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFamilyName")
-            .field("names", &self.names())
+        f.debug_struct("CssMediaAndCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssFontFamilyName> for SyntaxNode {
-    fn from(n: CssFontFamilyName) -> SyntaxNode {
+impl From<CssMediaAndCondition> for SyntaxNode {
+    fn from(n: CssMediaAndCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFamilyName> for SyntaxElement {
-    fn from(n: CssFontFamilyName) -> SyntaxElement {
+impl From<CssMediaAndCondition> for SyntaxElement {
+    fn from(n: CssMediaAndCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesAtRule {
+impl AstNode for CssMediaAndTypeQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_TYPE_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_AT_RULE
+        kind == CSS_MEDIA_AND_TYPE_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10492,34 +13033,31 @@ impl AstNode for CssFontFeatureValuesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesAtRule {
+impl std::fmt::Debug for CssMediaAndTypeQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesAtRule")
-            .field(
-                "font_feature_values_token",
-                &support::DebugSyntaxResult(self.font_feature_values_token()),
-            )
-            .field("names", &self.names())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssMediaAndTypeQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssFontFeatureValuesAtRule> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxNode {
+impl From<CssMediaAndTypeQuery> for SyntaxNode {
+    fn from(n: CssMediaAndTypeQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesAtRule> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxElement {
+impl From<CssMediaAndTypeQuery> for SyntaxElement {
+    fn from(n: CssMediaAndTypeQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesBlock {
+impl AstNode for CssMediaAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_BLOCK
+        kind == CSS_MEDIA_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10535,37 +13073,34 @@ impl AstNode for CssFontFeatureValuesBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesBlock {
+impl std::fmt::Debug for CssMediaAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
+        f.debug_struct("CssMediaAtRule")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "media_token",
+                &support::DebugSyntaxResult(self.media_token()),
             )
+            .field("queries", &self.queries())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssFontFeatureValuesBlock> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesBlock) -> SyntaxNode {
+impl From<CssMediaAtRule> for SyntaxNode {
+    fn from(n: CssMediaAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesBlock> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesBlock) -> SyntaxElement {
+impl From<CssMediaAtRule> for SyntaxElement {
+    fn from(n: CssMediaAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesItem {
+impl AstNode for CssMediaConditionInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_ITEM
+        kind == CSS_MEDIA_CONDITION_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10581,31 +13116,37 @@ impl AstNode for CssFontFeatureValuesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesItem {
+impl std::fmt::Debug for CssMediaConditionInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesItem")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssMediaConditionInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssFontFeatureValuesItem> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesItem) -> SyntaxNode {
+impl From<CssMediaConditionInParens> for SyntaxNode {
+    fn from(n: CssMediaConditionInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesItem> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesItem) -> SyntaxElement {
+impl From<CssMediaConditionInParens> for SyntaxElement {
+    fn from(n: CssMediaConditionInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontPaletteValuesAtRule {
+impl AstNode for CssMediaConditionQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_PALETTE_VALUES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_PALETTE_VALUES_AT_RULE
+        kind == CSS_MEDIA_CONDITION_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10621,34 +13162,29 @@ impl AstNode for CssFontPaletteValuesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontPaletteValuesAtRule {
+impl std::fmt::Debug for CssMediaConditionQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontPaletteValuesAtRule")
-            .field(
-                "font_palette_values_token",
-                &support::DebugSyntaxResult(self.font_palette_values_token()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssMediaConditionQuery")
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
             .finish()
     }
 }
-impl From<CssFontPaletteValuesAtRule> for SyntaxNode {
-    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxNode {
+impl From<CssMediaConditionQuery> for SyntaxNode {
+    fn from(n: CssMediaConditionQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontPaletteValuesAtRule> for SyntaxElement {
-    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxElement {
+impl From<CssMediaConditionQuery> for SyntaxElement {
+    fn from(n: CssMediaConditionQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFunction {
+impl AstNode for CssMediaFeatureInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FUNCTION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_FEATURE_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FUNCTION
+        kind == CSS_MEDIA_FEATURE_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10664,15 +13200,14 @@ impl AstNode for CssFunction {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFunction {
+impl std::fmt::Debug for CssMediaFeatureInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFunction")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssMediaFeatureInParens")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("items", &self.items())
+            .field("feature", &support::DebugSyntaxResult(self.feature()))
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -10680,22 +13215,22 @@ impl std::fmt::Debug for CssFunction {
             .finish()
     }
 }
-impl From<CssFunction> for SyntaxNode {
-    fn from(n: CssFunction) -> SyntaxNode {
+impl From<CssMediaFeatureInParens> for SyntaxNode {
+    fn from(n: CssMediaFeatureInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFunction> for SyntaxElement {
-    fn from(n: CssFunction) -> SyntaxElement {
+impl From<CssMediaFeatureInParens> for SyntaxElement {
+    fn from(n: CssMediaFeatureInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssGenericDelimiter {
+impl AstNode for CssMediaNotCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_DELIMITER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_NOT_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_GENERIC_DELIMITER
+        kind == CSS_MEDIA_NOT_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10711,29 +13246,30 @@ impl AstNode for CssGenericDelimiter {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssGenericDelimiter {
+impl std::fmt::Debug for CssMediaNotCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssGenericDelimiter")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssMediaNotCondition")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
             .finish()
     }
 }
-impl From<CssGenericDelimiter> for SyntaxNode {
-    fn from(n: CssGenericDelimiter) -> SyntaxNode {
+impl From<CssMediaNotCondition> for SyntaxNode {
+    fn from(n: CssMediaNotCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssGenericDelimiter> for SyntaxElement {
-    fn from(n: CssGenericDelimiter) -> SyntaxElement {
+impl From<CssMediaNotCondition> for SyntaxElement {
+    fn from(n: CssMediaNotCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssGenericProperty {
+impl AstNode for CssMediaOrCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_PROPERTY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_OR_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_GENERIC_PROPERTY
+        kind == CSS_MEDIA_OR_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10749,34 +13285,31 @@ impl AstNode for CssGenericProperty {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssGenericProperty {
+impl std::fmt::Debug for CssMediaOrCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssGenericProperty")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
-            )
-            .field("value", &self.value())
+        f.debug_struct("CssMediaOrCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssGenericProperty> for SyntaxNode {
-    fn from(n: CssGenericProperty) -> SyntaxNode {
+impl From<CssMediaOrCondition> for SyntaxNode {
+    fn from(n: CssMediaOrCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssGenericProperty> for SyntaxElement {
-    fn from(n: CssGenericProperty) -> SyntaxElement {
+impl From<CssMediaOrCondition> for SyntaxElement {
+    fn from(n: CssMediaOrCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssIdSelector {
+impl AstNode for CssMediaType {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ID_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ID_SELECTOR
+        kind == CSS_MEDIA_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10792,30 +13325,29 @@ impl AstNode for CssIdSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssIdSelector {
+impl std::fmt::Debug for CssMediaType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssIdSelector")
-            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssMediaType")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssIdSelector> for SyntaxNode {
-    fn from(n: CssIdSelector) -> SyntaxNode {
+impl From<CssMediaType> for SyntaxNode {
+    fn from(n: CssMediaType) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssIdSelector> for SyntaxElement {
-    fn from(n: CssIdSelector) -> SyntaxElement {
+impl From<CssMediaType> for SyntaxElement {
+    fn from(n: CssMediaType) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssIdentifier {
+impl AstNode for CssMediaTypeQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IDENTIFIER
+        kind == CSS_MEDIA_TYPE_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10831,32 +13363,30 @@ impl AstNode for CssIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssIdentifier {
+impl std::fmt::Debug for CssMediaTypeQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssIdentifier")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("CssMediaTypeQuery")
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+            .field("ty", &support::DebugSyntaxResult(self.ty()))
             .finish()
     }
 }
-impl From<CssIdentifier> for SyntaxNode {
-    fn from(n: CssIdentifier) -> SyntaxNode {
+impl From<CssMediaTypeQuery> for SyntaxNode {
+    fn from(n: CssMediaTypeQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssIdentifier> for SyntaxElement {
-    fn from(n: CssIdentifier) -> SyntaxElement {
+impl From<CssMediaTypeQuery> for SyntaxElement {
+    fn from(n: CssMediaTypeQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportAnonymousLayer {
+impl AstNode for CssMetavariable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_ANONYMOUS_LAYER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_METAVARIABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_ANONYMOUS_LAYER
+        kind == CSS_METAVARIABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10872,32 +13402,32 @@ impl AstNode for CssImportAnonymousLayer {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportAnonymousLayer {
+impl std::fmt::Debug for CssMetavariable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportAnonymousLayer")
+        f.debug_struct("CssMetavariable")
             .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<CssImportAnonymousLayer> for SyntaxNode {
-    fn from(n: CssImportAnonymousLayer) -> SyntaxNode {
+impl From<CssMetavariable> for SyntaxNode {
+    fn from(n: CssMetavariable) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportAnonymousLayer> for SyntaxElement {
-    fn from(n: CssImportAnonymousLayer) -> SyntaxElement {
+impl From<CssMetavariable> for SyntaxElement {
+    fn from(n: CssMetavariable) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportAtRule {
+impl AstNode for CssNamedNamespacePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMED_NAMESPACE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_AT_RULE
+        kind == CSS_NAMED_NAMESPACE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10913,40 +13443,29 @@ impl AstNode for CssImportAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportAtRule {
+impl std::fmt::Debug for CssNamedNamespacePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportAtRule")
-            .field(
-                "import_token",
-                &support::DebugSyntaxResult(self.import_token()),
-            )
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("layer", &support::DebugOptionalElement(self.layer()))
-            .field("supports", &support::DebugOptionalElement(self.supports()))
-            .field("media", &self.media())
-            .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
-            )
+        f.debug_struct("CssNamedNamespacePrefix")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssImportAtRule> for SyntaxNode {
-    fn from(n: CssImportAtRule) -> SyntaxNode {
+impl From<CssNamedNamespacePrefix> for SyntaxNode {
+    fn from(n: CssNamedNamespacePrefix) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportAtRule> for SyntaxElement {
-    fn from(n: CssImportAtRule) -> SyntaxElement {
+impl From<CssNamedNamespacePrefix> for SyntaxElement {
+    fn from(n: CssNamedNamespacePrefix) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportNamedLayer {
+impl AstNode for CssNamespace {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_NAMED_LAYER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_NAMED_LAYER
+        kind == CSS_NAMESPACE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10962,41 +13481,33 @@ impl AstNode for CssImportNamedLayer {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportNamedLayer {
+impl std::fmt::Debug for CssNamespace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportNamedLayer")
-            .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("name", &self.name())
+        f.debug_struct("CssNamespace")
+            .field("prefix", &support::DebugOptionalElement(self.prefix()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "bitwise_or_token",
+                &support::DebugSyntaxResult(self.bitwise_or_token()),
             )
             .finish()
     }
 }
-impl From<CssImportNamedLayer> for SyntaxNode {
-    fn from(n: CssImportNamedLayer) -> SyntaxNode {
+impl From<CssNamespace> for SyntaxNode {
+    fn from(n: CssNamespace) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportNamedLayer> for SyntaxElement {
-    fn from(n: CssImportNamedLayer) -> SyntaxElement {
+impl From<CssNamespace> for SyntaxElement {
+    fn from(n: CssNamespace) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportSupports {
+impl AstNode for CssNamespaceAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_SUPPORTS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_SUPPORTS
+        kind == CSS_NAMESPACE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11012,41 +13523,38 @@ impl AstNode for CssImportSupports {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportSupports {
+impl std::fmt::Debug for CssNamespaceAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportSupports")
-            .field(
-                "supports_token",
-                &support::DebugSyntaxResult(self.supports_token()),
-            )
+        f.debug_struct("CssNamespaceAtRule")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "namespace_token",
+                &support::DebugSyntaxResult(self.namespace_token()),
             )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field("prefix", &support::DebugOptionalElement(self.prefix()))
+            .field("url", &support::DebugSyntaxResult(self.url()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssImportSupports> for SyntaxNode {
-    fn from(n: CssImportSupports) -> SyntaxNode {
+impl From<CssNamespaceAtRule> for SyntaxNode {
+    fn from(n: CssNamespaceAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportSupports> for SyntaxElement {
-    fn from(n: CssImportSupports) -> SyntaxElement {
+impl From<CssNamespaceAtRule> for SyntaxElement {
+    fn from(n: CssNamespaceAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesAtRule {
+impl AstNode for CssNestedQualifiedRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_QUALIFIED_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_AT_RULE
+        kind == CSS_NESTED_QUALIFIED_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11062,34 +13570,30 @@ impl AstNode for CssKeyframesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesAtRule {
+impl std::fmt::Debug for CssNestedQualifiedRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesAtRule")
-            .field(
-                "keyframes_token",
-                &support::DebugSyntaxResult(self.keyframes_token()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssNestedQualifiedRule")
+            .field("prelude", &self.prelude())
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssKeyframesAtRule> for SyntaxNode {
-    fn from(n: CssKeyframesAtRule) -> SyntaxNode {
+impl From<CssNestedQualifiedRule> for SyntaxNode {
+    fn from(n: CssNestedQualifiedRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesAtRule> for SyntaxElement {
-    fn from(n: CssKeyframesAtRule) -> SyntaxElement {
+impl From<CssNestedQualifiedRule> for SyntaxElement {
+    fn from(n: CssNestedQualifiedRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesBlock {
+impl AstNode for CssNestedSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_BLOCK
+        kind == CSS_NESTED_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11105,37 +13609,29 @@ impl AstNode for CssKeyframesBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesBlock {
+impl std::fmt::Debug for CssNestedSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+        f.debug_struct("CssNestedSelector")
+            .field("amp_token", &support::DebugSyntaxResult(self.amp_token()))
             .finish()
     }
 }
-impl From<CssKeyframesBlock> for SyntaxNode {
-    fn from(n: CssKeyframesBlock) -> SyntaxNode {
+impl From<CssNestedSelector> for SyntaxNode {
+    fn from(n: CssNestedSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesBlock> for SyntaxElement {
-    fn from(n: CssKeyframesBlock) -> SyntaxElement {
+impl From<CssNestedSelector> for SyntaxElement {
+    fn from(n: CssNestedSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesIdentSelector {
+impl AstNode for CssNthOffset {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_IDENT_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NTH_OFFSET as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_IDENT_SELECTOR
+        kind == CSS_NTH_OFFSET
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11151,29 +13647,30 @@ impl AstNode for CssKeyframesIdentSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesIdentSelector {
+impl std::fmt::Debug for CssNthOffset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesIdentSelector")
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssNthOffset")
+            .field("sign", &support::DebugSyntaxResult(self.sign()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssKeyframesIdentSelector> for SyntaxNode {
-    fn from(n: CssKeyframesIdentSelector) -> SyntaxNode {
+impl From<CssNthOffset> for SyntaxNode {
+    fn from(n: CssNthOffset) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesIdentSelector> for SyntaxElement {
-    fn from(n: CssKeyframesIdentSelector) -> SyntaxElement {
+impl From<CssNthOffset> for SyntaxElement {
+    fn from(n: CssNthOffset) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesItem {
+impl AstNode for CssNumber {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NUMBER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_ITEM
+        kind == CSS_NUMBER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11189,30 +13686,32 @@ impl AstNode for CssKeyframesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesItem {
+impl std::fmt::Debug for CssNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesItem")
-            .field("selectors", &self.selectors())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssNumber")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssKeyframesItem> for SyntaxNode {
-    fn from(n: CssKeyframesItem) -> SyntaxNode {
+impl From<CssNumber> for SyntaxNode {
+    fn from(n: CssNumber) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesItem> for SyntaxElement {
-    fn from(n: CssKeyframesItem) -> SyntaxElement {
+impl From<CssNumber> for SyntaxElement {
+    fn from(n: CssNumber) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesPercentageSelector {
+impl AstNode for CssPageAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_PERCENTAGE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_PERCENTAGE_SELECTOR
+        kind == CSS_PAGE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11228,29 +13727,31 @@ impl AstNode for CssKeyframesPercentageSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesPercentageSelector {
+impl std::fmt::Debug for CssPageAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesPercentageSelector")
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssPageAtRule")
+            .field("page_token", &support::DebugSyntaxResult(self.page_token()))
+            .field("selectors", &self.selectors())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssKeyframesPercentageSelector> for SyntaxNode {
-    fn from(n: CssKeyframesPercentageSelector) -> SyntaxNode {
+impl From<CssPageAtRule> for SyntaxNode {
+    fn from(n: CssPageAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesPercentageSelector> for SyntaxElement {
-    fn from(n: CssKeyframesPercentageSelector) -> SyntaxElement {
+impl From<CssPageAtRule> for SyntaxElement {
+    fn from(n: CssPageAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesScopeFunction {
+impl AstNode for CssPageAtRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPE_FUNCTION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_SCOPE_FUNCTION
+        kind == CSS_PAGE_AT_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11266,38 +13767,37 @@ impl AstNode for CssKeyframesScopeFunction {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesScopeFunction {
+impl std::fmt::Debug for CssPageAtRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesScopeFunction")
-            .field("scope", &support::DebugSyntaxResult(self.scope()))
+        f.debug_struct("CssPageAtRuleBlock")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("items", &self.items())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssKeyframesScopeFunction> for SyntaxNode {
-    fn from(n: CssKeyframesScopeFunction) -> SyntaxNode {
+impl From<CssPageAtRuleBlock> for SyntaxNode {
+    fn from(n: CssPageAtRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesScopeFunction> for SyntaxElement {
-    fn from(n: CssKeyframesScopeFunction) -> SyntaxElement {
+impl From<CssPageAtRuleBlock> for SyntaxElement {
+    fn from(n: CssPageAtRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesScopePrefix {
+impl AstNode for CssPageSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPE_PREFIX as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_SCOPE_PREFIX
+        kind == CSS_PAGE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11313,30 +13813,30 @@ impl AstNode for CssKeyframesScopePrefix {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesScopePrefix {
+impl std::fmt::Debug for CssPageSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesScopePrefix")
-            .field("scope", &support::DebugSyntaxResult(self.scope()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssPageSelector")
+            .field("ty", &support::DebugOptionalElement(self.ty()))
+            .field("pseudos", &self.pseudos())
             .finish()
     }
 }
-impl From<CssKeyframesScopePrefix> for SyntaxNode {
-    fn from(n: CssKeyframesScopePrefix) -> SyntaxNode {
+impl From<CssPageSelector> for SyntaxNode {
+    fn from(n: CssPageSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesScopePrefix> for SyntaxElement {
-    fn from(n: CssKeyframesScopePrefix) -> SyntaxElement {
+impl From<CssPageSelector> for SyntaxElement {
+    fn from(n: CssPageSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesScopedName {
+impl AstNode for CssPageSelectorPseudo {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SCOPED_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_SCOPED_NAME
+        kind == CSS_PAGE_SELECTOR_PSEUDO
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11352,33 +13852,33 @@ impl AstNode for CssKeyframesScopedName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesScopedName {
+impl std::fmt::Debug for CssPageSelectorPseudo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesScopedName")
+        f.debug_struct("CssPageSelectorPseudo")
             .field(
                 "colon_token",
                 &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("scope", &support::DebugSyntaxResult(self.scope()))
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssKeyframesScopedName> for SyntaxNode {
-    fn from(n: CssKeyframesScopedName) -> SyntaxNode {
+impl From<CssPageSelectorPseudo> for SyntaxNode {
+    fn from(n: CssPageSelectorPseudo) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesScopedName> for SyntaxElement {
-    fn from(n: CssKeyframesScopedName) -> SyntaxElement {
+impl From<CssPageSelectorPseudo> for SyntaxElement {
+    fn from(n: CssPageSelectorPseudo) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerAtRule {
+impl AstNode for CssParameter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_AT_RULE
+        kind == CSS_PARAMETER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11394,33 +13894,32 @@ impl AstNode for CssLayerAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerAtRule {
+impl std::fmt::Debug for CssParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerAtRule")
+        f.debug_struct("CssParameter")
             .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
+                "any_css_expression",
+                &support::DebugSyntaxResult(self.any_css_expression()),
             )
-            .field("layer", &support::DebugSyntaxResult(self.layer()))
             .finish()
     }
 }
-impl From<CssLayerAtRule> for SyntaxNode {
-    fn from(n: CssLayerAtRule) -> SyntaxNode {
+impl From<CssParameter> for SyntaxNode {
+    fn from(n: CssParameter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerAtRule> for SyntaxElement {
-    fn from(n: CssLayerAtRule) -> SyntaxElement {
+impl From<CssParameter> for SyntaxElement {
+    fn from(n: CssParameter) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerDeclaration {
+impl AstNode for CssParenthesizedExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARENTHESIZED_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_DECLARATION
+        kind == CSS_PARENTHESIZED_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11436,30 +13935,40 @@ impl AstNode for CssLayerDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerDeclaration {
+impl std::fmt::Debug for CssParenthesizedExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerDeclaration")
-            .field("references", &self.references())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssParenthesizedExpression")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field(
+                "expression",
+                &support::DebugOptionalElement(self.expression()),
+            )
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssLayerDeclaration> for SyntaxNode {
-    fn from(n: CssLayerDeclaration) -> SyntaxNode {
+impl From<CssParenthesizedExpression> for SyntaxNode {
+    fn from(n: CssParenthesizedExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerDeclaration> for SyntaxElement {
-    fn from(n: CssLayerDeclaration) -> SyntaxElement {
+impl From<CssParenthesizedExpression> for SyntaxElement {
+    fn from(n: CssParenthesizedExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerReference {
+impl AstNode for CssPercentage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_REFERENCE
+        kind == CSS_PERCENTAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11475,34 +13984,36 @@ impl AstNode for CssLayerReference {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerReference {
+impl std::fmt::Debug for CssPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerReference")
-            .field("references", &self.references())
+        f.debug_struct("CssPercentage")
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .field(
+                "percent_token",
+                &support::DebugSyntaxResult(self.percent_token()),
             )
             .finish()
     }
 }
-impl From<CssLayerReference> for SyntaxNode {
-    fn from(n: CssLayerReference) -> SyntaxNode {
+impl From<CssPercentage> for SyntaxNode {
+    fn from(n: CssPercentage) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerReference> for SyntaxElement {
-    fn from(n: CssLayerReference) -> SyntaxElement {
+impl From<CssPercentage> for SyntaxElement {
+    fn from(n: CssPercentage) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssListOfComponentValuesExpression {
+impl AstNode for CssPropertyAtRule {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PROPERTY_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
+        kind == CSS_PROPERTY_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11518,29 +14029,35 @@ impl AstNode for CssListOfComponentValuesExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssListOfComponentValuesExpression {
+impl std::fmt::Debug for CssPropertyAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssListOfComponentValuesExpression")
-            .field("css_component_value_list", &self.css_component_value_list())
+        f.debug_struct("CssPropertyAtRule")
+            .field(
+                "property_token",
+                &support::DebugSyntaxResult(self.property_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssListOfComponentValuesExpression> for SyntaxNode {
-    fn from(n: CssListOfComponentValuesExpression) -> SyntaxNode {
+impl From<CssPropertyAtRule> for SyntaxNode {
+    fn from(n: CssPropertyAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssListOfComponentValuesExpression> for SyntaxElement {
-    fn from(n: CssListOfComponentValuesExpression) -> SyntaxElement {
+impl From<CssPropertyAtRule> for SyntaxElement {
+    fn from(n: CssPropertyAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMarginAtRule {
+impl AstNode for CssPseudoClassFunctionCompoundSelector {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MARGIN_AT_RULE as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MARGIN_AT_RULE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11556,31 +14073,39 @@ impl AstNode for CssMarginAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMarginAtRule {
+impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMarginAtRule")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+        f.debug_struct("CssPseudoClassFunctionCompoundSelector")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMarginAtRule> for SyntaxNode {
-    fn from(n: CssMarginAtRule) -> SyntaxNode {
+impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMarginAtRule> for SyntaxElement {
-    fn from(n: CssMarginAtRule) -> SyntaxElement {
+impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAndCondition {
+impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_CONDITION as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AND_CONDITION
+        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11596,31 +14121,38 @@ impl AstNode for CssMediaAndCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAndCondition {
+impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAndCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssPseudoClassFunctionCompoundSelectorList")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("compound_selectors", &self.compound_selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaAndCondition> for SyntaxNode {
-    fn from(n: CssMediaAndCondition) -> SyntaxNode {
+impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAndCondition> for SyntaxElement {
-    fn from(n: CssMediaAndCondition) -> SyntaxElement {
+impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAndTypeQuery {
+impl AstNode for CssPseudoClassFunctionIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_TYPE_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AND_TYPE_QUERY
+        kind == CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11636,31 +14168,38 @@ impl AstNode for CssMediaAndTypeQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAndTypeQuery {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAndTypeQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+impl std::fmt::Debug for CssPseudoClassFunctionIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssPseudoClassFunctionIdentifier")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaAndTypeQuery> for SyntaxNode {
-    fn from(n: CssMediaAndTypeQuery) -> SyntaxNode {
+impl From<CssPseudoClassFunctionIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAndTypeQuery> for SyntaxElement {
-    fn from(n: CssMediaAndTypeQuery) -> SyntaxElement {
+impl From<CssPseudoClassFunctionIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAtRule {
+impl AstNode for CssPseudoClassFunctionNth {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_NTH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AT_RULE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_NTH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11676,34 +14215,39 @@ impl AstNode for CssMediaAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAtRule {
+impl std::fmt::Debug for CssPseudoClassFunctionNth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAtRule")
+        f.debug_struct("CssPseudoClassFunctionNth")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "media_token",
-                &support::DebugSyntaxResult(self.media_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("queries", &self.queries())
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssMediaAtRule> for SyntaxNode {
-    fn from(n: CssMediaAtRule) -> SyntaxNode {
+impl From<CssPseudoClassFunctionNth> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionNth) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAtRule> for SyntaxElement {
-    fn from(n: CssMediaAtRule) -> SyntaxElement {
+impl From<CssPseudoClassFunctionNth> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionNth) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaConditionInParens {
+impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_IN_PARENS as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_CONDITION_IN_PARENS
+        kind == CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11719,14 +14263,15 @@ impl AstNode for CssMediaConditionInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaConditionInParens {
+impl std::fmt::Debug for CssPseudoClassFunctionRelativeSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaConditionInParens")
+        f.debug_struct("CssPseudoClassFunctionRelativeSelectorList")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field("relative_selectors", &self.relative_selectors())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -11734,22 +14279,22 @@ impl std::fmt::Debug for CssMediaConditionInParens {
             .finish()
     }
 }
-impl From<CssMediaConditionInParens> for SyntaxNode {
-    fn from(n: CssMediaConditionInParens) -> SyntaxNode {
+impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaConditionInParens> for SyntaxElement {
-    fn from(n: CssMediaConditionInParens) -> SyntaxElement {
+impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaConditionQuery {
+impl AstNode for CssPseudoClassFunctionSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_CONDITION_QUERY
+        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11765,29 +14310,39 @@ impl AstNode for CssMediaConditionQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaConditionQuery {
+impl std::fmt::Debug for CssPseudoClassFunctionSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaConditionQuery")
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("CssPseudoClassFunctionSelector")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaConditionQuery> for SyntaxNode {
-    fn from(n: CssMediaConditionQuery) -> SyntaxNode {
+impl From<CssPseudoClassFunctionSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaConditionQuery> for SyntaxElement {
-    fn from(n: CssMediaConditionQuery) -> SyntaxElement {
+impl From<CssPseudoClassFunctionSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaFeatureInParens {
+impl AstNode for CssPseudoClassFunctionSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_FEATURE_IN_PARENS as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_FEATURE_IN_PARENS
+        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11803,14 +14358,15 @@ impl AstNode for CssMediaFeatureInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaFeatureInParens {
+impl std::fmt::Debug for CssPseudoClassFunctionSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaFeatureInParens")
+        f.debug_struct("CssPseudoClassFunctionSelectorList")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("feature", &support::DebugSyntaxResult(self.feature()))
+            .field("selectors", &self.selectors())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -11818,22 +14374,22 @@ impl std::fmt::Debug for CssMediaFeatureInParens {
             .finish()
     }
 }
-impl From<CssMediaFeatureInParens> for SyntaxNode {
-    fn from(n: CssMediaFeatureInParens) -> SyntaxNode {
+impl From<CssPseudoClassFunctionSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaFeatureInParens> for SyntaxElement {
-    fn from(n: CssMediaFeatureInParens) -> SyntaxElement {
+impl From<CssPseudoClassFunctionSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaNotCondition {
+impl AstNode for CssPseudoClassFunctionValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_NOT_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_NOT_CONDITION
+        kind == CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11849,30 +14405,38 @@ impl AstNode for CssMediaNotCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaNotCondition {
+impl std::fmt::Debug for CssPseudoClassFunctionValueList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaNotCondition")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("CssPseudoClassFunctionValueList")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("values", &self.values())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaNotCondition> for SyntaxNode {
-    fn from(n: CssMediaNotCondition) -> SyntaxNode {
+impl From<CssPseudoClassFunctionValueList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaNotCondition> for SyntaxElement {
-    fn from(n: CssMediaNotCondition) -> SyntaxElement {
+impl From<CssPseudoClassFunctionValueList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaOrCondition {
+impl AstNode for CssPseudoClassIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_OR_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_OR_CONDITION
+        kind == CSS_PSEUDO_CLASS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11888,31 +14452,29 @@ impl AstNode for CssMediaOrCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaOrCondition {
+impl std::fmt::Debug for CssPseudoClassIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaOrCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssPseudoClassIdentifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssMediaOrCondition> for SyntaxNode {
-    fn from(n: CssMediaOrCondition) -> SyntaxNode {
+impl From<CssPseudoClassIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaOrCondition> for SyntaxElement {
-    fn from(n: CssMediaOrCondition) -> SyntaxElement {
+impl From<CssPseudoClassIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaType {
+impl AstNode for CssPseudoClassNth {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_TYPE
+        kind == CSS_PSEUDO_CLASS_NTH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11928,29 +14490,35 @@ impl AstNode for CssMediaType {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaType {
+impl std::fmt::Debug for CssPseudoClassNth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaType")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssPseudoClassNth")
+            .field("sign", &support::DebugOptionalElement(self.sign()))
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .field(
+                "symbol_token",
+                &support::DebugSyntaxResult(self.symbol_token()),
+            )
+            .field("offset", &support::DebugOptionalElement(self.offset()))
             .finish()
     }
 }
-impl From<CssMediaType> for SyntaxNode {
-    fn from(n: CssMediaType) -> SyntaxNode {
+impl From<CssPseudoClassNth> for SyntaxNode {
+    fn from(n: CssPseudoClassNth) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaType> for SyntaxElement {
-    fn from(n: CssMediaType) -> SyntaxElement {
+impl From<CssPseudoClassNth> for SyntaxElement {
+    fn from(n: CssPseudoClassNth) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaTypeQuery {
+impl AstNode for CssPseudoClassNthIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_TYPE_QUERY
+        kind == CSS_PSEUDO_CLASS_NTH_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11966,30 +14534,29 @@ impl AstNode for CssMediaTypeQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaTypeQuery {
+impl std::fmt::Debug for CssPseudoClassNthIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaTypeQuery")
-            .field("modifier", &support::DebugOptionalElement(self.modifier()))
-            .field("ty", &support::DebugSyntaxResult(self.ty()))
+        f.debug_struct("CssPseudoClassNthIdentifier")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssMediaTypeQuery> for SyntaxNode {
-    fn from(n: CssMediaTypeQuery) -> SyntaxNode {
+impl From<CssPseudoClassNthIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaTypeQuery> for SyntaxElement {
-    fn from(n: CssMediaTypeQuery) -> SyntaxElement {
+impl From<CssPseudoClassNthIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMetavariable {
+impl AstNode for CssPseudoClassNthNumber {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_METAVARIABLE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_NUMBER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_METAVARIABLE
+        kind == CSS_PSEUDO_CLASS_NTH_NUMBER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12005,32 +14572,30 @@ impl AstNode for CssMetavariable {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMetavariable {
+impl std::fmt::Debug for CssPseudoClassNthNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMetavariable")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("CssPseudoClassNthNumber")
+            .field("sign", &support::DebugOptionalElement(self.sign()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssMetavariable> for SyntaxNode {
-    fn from(n: CssMetavariable) -> SyntaxNode {
+impl From<CssPseudoClassNthNumber> for SyntaxNode {
+    fn from(n: CssPseudoClassNthNumber) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMetavariable> for SyntaxElement {
-    fn from(n: CssMetavariable) -> SyntaxElement {
+impl From<CssPseudoClassNthNumber> for SyntaxElement {
+    fn from(n: CssPseudoClassNthNumber) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamedNamespacePrefix {
+impl AstNode for CssPseudoClassNthSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMED_NAMESPACE_PREFIX as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMED_NAMESPACE_PREFIX
+        kind == CSS_PSEUDO_CLASS_NTH_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12046,29 +14611,33 @@ impl AstNode for CssNamedNamespacePrefix {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamedNamespacePrefix {
+impl std::fmt::Debug for CssPseudoClassNthSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamedNamespacePrefix")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssPseudoClassNthSelector")
+            .field("nth", &support::DebugSyntaxResult(self.nth()))
+            .field(
+                "of_selector",
+                &support::DebugOptionalElement(self.of_selector()),
+            )
             .finish()
     }
 }
-impl From<CssNamedNamespacePrefix> for SyntaxNode {
-    fn from(n: CssNamedNamespacePrefix) -> SyntaxNode {
+impl From<CssPseudoClassNthSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassNthSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamedNamespacePrefix> for SyntaxElement {
-    fn from(n: CssNamedNamespacePrefix) -> SyntaxElement {
+impl From<CssPseudoClassNthSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassNthSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamespace {
+impl AstNode for CssPseudoClassOfNthSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMESPACE
+        kind == CSS_PSEUDO_CLASS_OF_NTH_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12084,33 +14653,30 @@ impl AstNode for CssNamespace {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamespace {
+impl std::fmt::Debug for CssPseudoClassOfNthSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamespace")
-            .field("prefix", &support::DebugOptionalElement(self.prefix()))
-            .field(
-                "bitwise_or_token",
-                &support::DebugSyntaxResult(self.bitwise_or_token()),
-            )
+        f.debug_struct("CssPseudoClassOfNthSelector")
+            .field("of_token", &support::DebugSyntaxResult(self.of_token()))
+            .field("selectors", &self.selectors())
             .finish()
     }
 }
-impl From<CssNamespace> for SyntaxNode {
-    fn from(n: CssNamespace) -> SyntaxNode {
+impl From<CssPseudoClassOfNthSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamespace> for SyntaxElement {
-    fn from(n: CssNamespace) -> SyntaxElement {
+impl From<CssPseudoClassOfNthSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamespaceAtRule {
+impl AstNode for CssPseudoClassSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMESPACE_AT_RULE
+        kind == CSS_PSEUDO_CLASS_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12126,38 +14692,33 @@ impl AstNode for CssNamespaceAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamespaceAtRule {
+impl std::fmt::Debug for CssPseudoClassSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamespaceAtRule")
-            .field(
-                "namespace_token",
-                &support::DebugSyntaxResult(self.namespace_token()),
-            )
-            .field("prefix", &support::DebugOptionalElement(self.prefix()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
+        f.debug_struct("CssPseudoClassSelector")
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("class", &support::DebugSyntaxResult(self.class()))
             .finish()
     }
 }
-impl From<CssNamespaceAtRule> for SyntaxNode {
-    fn from(n: CssNamespaceAtRule) -> SyntaxNode {
+impl From<CssPseudoClassSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamespaceAtRule> for SyntaxElement {
-    fn from(n: CssNamespaceAtRule) -> SyntaxElement {
+impl From<CssPseudoClassSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNestedQualifiedRule {
+impl AstNode for CssPseudoElementFunctionIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_QUALIFIED_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NESTED_QUALIFIED_RULE
+        kind == CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12173,30 +14734,38 @@ impl AstNode for CssNestedQualifiedRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNestedQualifiedRule {
+impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNestedQualifiedRule")
-            .field("prelude", &self.prelude())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssPseudoElementFunctionIdentifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssNestedQualifiedRule> for SyntaxNode {
-    fn from(n: CssNestedQualifiedRule) -> SyntaxNode {
+impl From<CssPseudoElementFunctionIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNestedQualifiedRule> for SyntaxElement {
-    fn from(n: CssNestedQualifiedRule) -> SyntaxElement {
+impl From<CssPseudoElementFunctionIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNestedSelector {
+impl AstNode for CssPseudoElementFunctionSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NESTED_SELECTOR
+        kind == CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12212,29 +14781,38 @@ impl AstNode for CssNestedSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNestedSelector {
+impl std::fmt::Debug for CssPseudoElementFunctionSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNestedSelector")
-            .field("amp_token", &support::DebugSyntaxResult(self.amp_token()))
+        f.debug_struct("CssPseudoElementFunctionSelector")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssNestedSelector> for SyntaxNode {
-    fn from(n: CssNestedSelector) -> SyntaxNode {
+impl From<CssPseudoElementFunctionSelector> for SyntaxNode {
+    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNestedSelector> for SyntaxElement {
-    fn from(n: CssNestedSelector) -> SyntaxElement {
+impl From<CssPseudoElementFunctionSelector> for SyntaxElement {
+    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNthOffset {
+impl AstNode for CssPseudoElementIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NTH_OFFSET as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NTH_OFFSET
+        kind == CSS_PSEUDO_ELEMENT_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12250,30 +14828,29 @@ impl AstNode for CssNthOffset {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNthOffset {
+impl std::fmt::Debug for CssPseudoElementIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNthOffset")
-            .field("sign", &support::DebugSyntaxResult(self.sign()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssPseudoElementIdentifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssNthOffset> for SyntaxNode {
-    fn from(n: CssNthOffset) -> SyntaxNode {
+impl From<CssPseudoElementIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoElementIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNthOffset> for SyntaxElement {
-    fn from(n: CssNthOffset) -> SyntaxElement {
+impl From<CssPseudoElementIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoElementIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNumber {
+impl AstNode for CssPseudoElementSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NUMBER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NUMBER
+        kind == CSS_PSEUDO_ELEMENT_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12289,32 +14866,33 @@ impl AstNode for CssNumber {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNumber {
+impl std::fmt::Debug for CssPseudoElementSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNumber")
+        f.debug_struct("CssPseudoElementSelector")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "double_colon_token",
+                &support::DebugSyntaxResult(self.double_colon_token()),
             )
+            .field("element", &support::DebugSyntaxResult(self.element()))
             .finish()
     }
 }
-impl From<CssNumber> for SyntaxNode {
-    fn from(n: CssNumber) -> SyntaxNode {
+impl From<CssPseudoElementSelector> for SyntaxNode {
+    fn from(n: CssPseudoElementSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNumber> for SyntaxElement {
-    fn from(n: CssNumber) -> SyntaxElement {
+impl From<CssPseudoElementSelector> for SyntaxElement {
+    fn from(n: CssPseudoElementSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageAtRule {
+impl AstNode for CssQualifiedRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUALIFIED_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_AT_RULE
+        kind == CSS_QUALIFIED_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12330,31 +14908,30 @@ impl AstNode for CssPageAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageAtRule {
+impl std::fmt::Debug for CssQualifiedRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageAtRule")
-            .field("page_token", &support::DebugSyntaxResult(self.page_token()))
-            .field("selectors", &self.selectors())
+        f.debug_struct("CssQualifiedRule")
+            .field("prelude", &self.prelude())
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPageAtRule> for SyntaxNode {
-    fn from(n: CssPageAtRule) -> SyntaxNode {
+impl From<CssQualifiedRule> for SyntaxNode {
+    fn from(n: CssQualifiedRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageAtRule> for SyntaxElement {
-    fn from(n: CssPageAtRule) -> SyntaxElement {
+impl From<CssQualifiedRule> for SyntaxElement {
+    fn from(n: CssQualifiedRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageAtRuleBlock {
+impl AstNode for CssQueryFeatureBoolean {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_BOOLEAN as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_AT_RULE_BLOCK
+        kind == CSS_QUERY_FEATURE_BOOLEAN
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12370,37 +14947,29 @@ impl AstNode for CssPageAtRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageAtRuleBlock {
+impl std::fmt::Debug for CssQueryFeatureBoolean {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageAtRuleBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+        f.debug_struct("CssQueryFeatureBoolean")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssPageAtRuleBlock> for SyntaxNode {
-    fn from(n: CssPageAtRuleBlock) -> SyntaxNode {
+impl From<CssQueryFeatureBoolean> for SyntaxNode {
+    fn from(n: CssQueryFeatureBoolean) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageAtRuleBlock> for SyntaxElement {
-    fn from(n: CssPageAtRuleBlock) -> SyntaxElement {
+impl From<CssQueryFeatureBoolean> for SyntaxElement {
+    fn from(n: CssQueryFeatureBoolean) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageSelector {
+impl AstNode for CssQueryFeaturePlain {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_PLAIN as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR
+        kind == CSS_QUERY_FEATURE_PLAIN
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12416,30 +14985,34 @@ impl AstNode for CssPageSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageSelector {
+impl std::fmt::Debug for CssQueryFeaturePlain {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageSelector")
-            .field("ty", &support::DebugOptionalElement(self.ty()))
-            .field("pseudos", &self.pseudos())
+        f.debug_struct("CssQueryFeaturePlain")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssPageSelector> for SyntaxNode {
-    fn from(n: CssPageSelector) -> SyntaxNode {
+impl From<CssQueryFeaturePlain> for SyntaxNode {
+    fn from(n: CssQueryFeaturePlain) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageSelector> for SyntaxElement {
-    fn from(n: CssPageSelector) -> SyntaxElement {
+impl From<CssQueryFeaturePlain> for SyntaxElement {
+    fn from(n: CssQueryFeaturePlain) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageSelectorPseudo {
+impl AstNode for CssQueryFeatureRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR_PSEUDO
+        kind == CSS_QUERY_FEATURE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12455,33 +15028,31 @@ impl AstNode for CssPageSelectorPseudo {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageSelectorPseudo {
+impl std::fmt::Debug for CssQueryFeatureRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageSelectorPseudo")
-            .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssQueryFeatureRange")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPageSelectorPseudo> for SyntaxNode {
-    fn from(n: CssPageSelectorPseudo) -> SyntaxNode {
+impl From<CssQueryFeatureRange> for SyntaxNode {
+    fn from(n: CssQueryFeatureRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageSelectorPseudo> for SyntaxElement {
-    fn from(n: CssPageSelectorPseudo) -> SyntaxElement {
+impl From<CssQueryFeatureRange> for SyntaxElement {
+    fn from(n: CssQueryFeatureRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssParameter {
+impl AstNode for CssQueryFeatureRangeComparison {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_COMPARISON as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PARAMETER
+        kind == CSS_QUERY_FEATURE_RANGE_COMPARISON
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12497,32 +15068,29 @@ impl AstNode for CssParameter {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssParameter {
+impl std::fmt::Debug for CssQueryFeatureRangeComparison {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssParameter")
-            .field(
-                "any_css_expression",
-                &support::DebugSyntaxResult(self.any_css_expression()),
-            )
+        f.debug_struct("CssQueryFeatureRangeComparison")
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
             .finish()
     }
 }
-impl From<CssParameter> for SyntaxNode {
-    fn from(n: CssParameter) -> SyntaxNode {
+impl From<CssQueryFeatureRangeComparison> for SyntaxNode {
+    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssParameter> for SyntaxElement {
-    fn from(n: CssParameter) -> SyntaxElement {
+impl From<CssQueryFeatureRangeComparison> for SyntaxElement {
+    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssParenthesizedExpression {
+impl AstNode for CssQueryFeatureRangeInterval {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARENTHESIZED_EXPRESSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_INTERVAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PARENTHESIZED_EXPRESSION
+        kind == CSS_QUERY_FEATURE_RANGE_INTERVAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12538,40 +15106,39 @@ impl AstNode for CssParenthesizedExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssParenthesizedExpression {
+impl std::fmt::Debug for CssQueryFeatureRangeInterval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssParenthesizedExpression")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
+        f.debug_struct("CssQueryFeatureRangeInterval")
+            .field("left", &support::DebugSyntaxResult(self.left()))
             .field(
-                "expression",
-                &support::DebugOptionalElement(self.expression()),
+                "left_comparison",
+                &support::DebugSyntaxResult(self.left_comparison()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "right_comparison",
+                &support::DebugSyntaxResult(self.right_comparison()),
             )
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssParenthesizedExpression> for SyntaxNode {
-    fn from(n: CssParenthesizedExpression) -> SyntaxNode {
+impl From<CssQueryFeatureRangeInterval> for SyntaxNode {
+    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssParenthesizedExpression> for SyntaxElement {
-    fn from(n: CssParenthesizedExpression) -> SyntaxElement {
+impl From<CssQueryFeatureRangeInterval> for SyntaxElement {
+    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPercentage {
+impl AstNode for CssQueryFeatureReverseRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_REVERSE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PERCENTAGE
+        kind == CSS_QUERY_FEATURE_REVERSE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12587,36 +15154,31 @@ impl AstNode for CssPercentage {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPercentage {
+impl std::fmt::Debug for CssQueryFeatureReverseRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPercentage")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .field(
-                "percent_token",
-                &support::DebugSyntaxResult(self.percent_token()),
-            )
+        f.debug_struct("CssQueryFeatureReverseRange")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPercentage> for SyntaxNode {
-    fn from(n: CssPercentage) -> SyntaxNode {
+impl From<CssQueryFeatureReverseRange> for SyntaxNode {
+    fn from(n: CssQueryFeatureReverseRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPercentage> for SyntaxElement {
-    fn from(n: CssPercentage) -> SyntaxElement {
+impl From<CssQueryFeatureReverseRange> for SyntaxElement {
+    fn from(n: CssQueryFeatureReverseRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPropertyAtRule {
+impl AstNode for CssRatio {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PROPERTY_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PROPERTY_AT_RULE
+        kind == CSS_RATIO
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12632,35 +15194,37 @@ impl AstNode for CssPropertyAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPropertyAtRule {
+impl std::fmt::Debug for CssRatio {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPropertyAtRule")
+        f.debug_struct("CssRatio")
+            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
             .field(
-                "property_token",
-                &support::DebugSyntaxResult(self.property_token()),
+                "slash_token",
+                &support::DebugSyntaxResult(self.slash_token()),
+            )
+            .field(
+                "denominator",
+                &support::DebugSyntaxResult(self.denominator()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPropertyAtRule> for SyntaxNode {
-    fn from(n: CssPropertyAtRule) -> SyntaxNode {
+impl From<CssRatio> for SyntaxNode {
+    fn from(n: CssRatio) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPropertyAtRule> for SyntaxElement {
-    fn from(n: CssPropertyAtRule) -> SyntaxElement {
+impl From<CssRatio> for SyntaxElement {
+    fn from(n: CssRatio) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionCompoundSelector {
+impl AstNode for CssRegularDimension {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_REGULAR_DIMENSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR
+        kind == CSS_REGULAR_DIMENSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12676,39 +15240,33 @@ impl AstNode for CssPseudoClassFunctionCompoundSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelector {
+impl std::fmt::Debug for CssRegularDimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionCompoundSelector")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssRegularDimension")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
+            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxNode {
+impl From<CssRegularDimension> for SyntaxNode {
+    fn from(n: CssRegularDimension) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxElement {
+impl From<CssRegularDimension> for SyntaxElement {
+    fn from(n: CssRegularDimension) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
+impl AstNode for CssRelativeSelector {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST
+        kind == CSS_RELATIVE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12724,38 +15282,33 @@ impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelectorList {
+impl std::fmt::Debug for CssRelativeSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionCompoundSelectorList")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("compound_selectors", &self.compound_selectors())
+        f.debug_struct("CssRelativeSelector")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "combinator",
+                &support::DebugOptionalElement(self.combinator()),
             )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxNode {
+impl From<CssRelativeSelector> for SyntaxNode {
+    fn from(n: CssRelativeSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxElement {
+impl From<CssRelativeSelector> for SyntaxElement {
+    fn from(n: CssRelativeSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionIdentifier {
+impl AstNode for CssRoot {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER
+        kind == CSS_ROOT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12771,38 +15324,34 @@ impl AstNode for CssPseudoClassFunctionIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionIdentifier {
+impl std::fmt::Debug for CssRoot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionIdentifier")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+        f.debug_struct("CssRoot")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
             )
+            .field("rules", &self.rules())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxNode {
+impl From<CssRoot> for SyntaxNode {
+    fn from(n: CssRoot) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxElement {
+impl From<CssRoot> for SyntaxElement {
+    fn from(n: CssRoot) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionNth {
+impl AstNode for CssRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_NTH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_NTH
+        kind == CSS_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12818,39 +15367,37 @@ impl AstNode for CssPseudoClassFunctionNth {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionNth {
+impl std::fmt::Debug for CssRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionNth")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssRuleBlock")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field("rules", &self.rules())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionNth> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionNth) -> SyntaxNode {
+impl From<CssRuleBlock> for SyntaxNode {
+    fn from(n: CssRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionNth> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionNth) -> SyntaxElement {
+impl From<CssRuleBlock> for SyntaxElement {
+    fn from(n: CssRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
+impl AstNode for CssScopeAtRule {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST
+        kind == CSS_SCOPE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12866,38 +15413,34 @@ impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionRelativeSelectorList {
+impl std::fmt::Debug for CssScopeAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionRelativeSelectorList")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("relative_selectors", &self.relative_selectors())
+        f.debug_struct("CssScopeAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "scope_token",
+                &support::DebugSyntaxResult(self.scope_token()),
             )
+            .field("range", &support::DebugOptionalElement(self.range()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxNode {
+impl From<CssScopeAtRule> for SyntaxNode {
+    fn from(n: CssScopeAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxElement {
+impl From<CssScopeAtRule> for SyntaxElement {
+    fn from(n: CssScopeAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionSelector {
+impl AstNode for CssScopeEdge {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_EDGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR
+        kind == CSS_SCOPE_EDGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12913,15 +15456,14 @@ impl AstNode for CssPseudoClassFunctionSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionSelector {
+impl std::fmt::Debug for CssScopeEdge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionSelector")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssScopeEdge")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field("selectors", &self.selectors())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -12929,23 +15471,22 @@ impl std::fmt::Debug for CssPseudoClassFunctionSelector {
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxNode {
+impl From<CssScopeEdge> for SyntaxNode {
+    fn from(n: CssScopeEdge) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxElement {
+impl From<CssScopeEdge> for SyntaxElement {
+    fn from(n: CssScopeEdge) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionSelectorList {
+impl AstNode for CssScopeRangeEnd {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_END as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST
+        kind == CSS_SCOPE_RANGE_END
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12961,38 +15502,30 @@ impl AstNode for CssPseudoClassFunctionSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionSelectorList {
+impl std::fmt::Debug for CssScopeRangeEnd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionSelectorList")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selectors", &self.selectors())
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssScopeRangeEnd")
+            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
+            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxNode {
+impl From<CssScopeRangeEnd> for SyntaxNode {
+    fn from(n: CssScopeRangeEnd) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxElement {
+impl From<CssScopeRangeEnd> for SyntaxElement {
+    fn from(n: CssScopeRangeEnd) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionValueList {
+impl AstNode for CssScopeRangeInterval {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_INTERVAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST
+        kind == CSS_SCOPE_RANGE_INTERVAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13008,38 +15541,31 @@ impl AstNode for CssPseudoClassFunctionValueList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionValueList {
+impl std::fmt::Debug for CssScopeRangeInterval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionValueList")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("values", &self.values())
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssScopeRangeInterval")
+            .field("start", &support::DebugSyntaxResult(self.start()))
+            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
+            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionValueList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxNode {
+impl From<CssScopeRangeInterval> for SyntaxNode {
+    fn from(n: CssScopeRangeInterval) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionValueList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxElement {
+impl From<CssScopeRangeInterval> for SyntaxElement {
+    fn from(n: CssScopeRangeInterval) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassIdentifier {
+impl AstNode for CssScopeRangeStart {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_START as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_IDENTIFIER
+        kind == CSS_SCOPE_RANGE_START
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13055,29 +15581,29 @@ impl AstNode for CssPseudoClassIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassIdentifier {
+impl std::fmt::Debug for CssScopeRangeStart {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssScopeRangeStart")
+            .field("start", &support::DebugSyntaxResult(self.start()))
             .finish()
     }
 }
-impl From<CssPseudoClassIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassIdentifier) -> SyntaxNode {
+impl From<CssScopeRangeStart> for SyntaxNode {
+    fn from(n: CssScopeRangeStart) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassIdentifier) -> SyntaxElement {
+impl From<CssScopeRangeStart> for SyntaxElement {
+    fn from(n: CssScopeRangeStart) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNth {
+impl AstNode for CssStartingStyleAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STARTING_STYLE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH
+        kind == CSS_STARTING_STYLE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13093,35 +15619,33 @@ impl AstNode for CssPseudoClassNth {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNth {
+impl std::fmt::Debug for CssStartingStyleAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNth")
-            .field("sign", &support::DebugOptionalElement(self.sign()))
-            .field("value", &support::DebugOptionalElement(self.value()))
+        f.debug_struct("CssStartingStyleAtRule")
             .field(
-                "symbol_token",
-                &support::DebugSyntaxResult(self.symbol_token()),
+                "starting_style_token",
+                &support::DebugSyntaxResult(self.starting_style_token()),
             )
-            .field("offset", &support::DebugOptionalElement(self.offset()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPseudoClassNth> for SyntaxNode {
-    fn from(n: CssPseudoClassNth) -> SyntaxNode {
+impl From<CssStartingStyleAtRule> for SyntaxNode {
+    fn from(n: CssStartingStyleAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNth> for SyntaxElement {
-    fn from(n: CssPseudoClassNth) -> SyntaxElement {
+impl From<CssStartingStyleAtRule> for SyntaxElement {
+    fn from(n: CssStartingStyleAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthIdentifier {
+impl AstNode for CssString {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STRING as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_IDENTIFIER
+        kind == CSS_STRING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13137,29 +15661,32 @@ impl AstNode for CssPseudoClassNthIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthIdentifier {
+impl std::fmt::Debug for CssString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthIdentifier")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssString")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssPseudoClassNthIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxNode {
+impl From<CssString> for SyntaxNode {
+    fn from(n: CssString) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxElement {
+impl From<CssString> for SyntaxElement {
+    fn from(n: CssString) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthNumber {
+impl AstNode for CssSupportsAndCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_NUMBER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AND_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_NUMBER
+        kind == CSS_SUPPORTS_AND_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13175,30 +15702,31 @@ impl AstNode for CssPseudoClassNthNumber {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthNumber {
+impl std::fmt::Debug for CssSupportsAndCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthNumber")
-            .field("sign", &support::DebugOptionalElement(self.sign()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssSupportsAndCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPseudoClassNthNumber> for SyntaxNode {
-    fn from(n: CssPseudoClassNthNumber) -> SyntaxNode {
+impl From<CssSupportsAndCondition> for SyntaxNode {
+    fn from(n: CssSupportsAndCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthNumber> for SyntaxElement {
-    fn from(n: CssPseudoClassNthNumber) -> SyntaxElement {
+impl From<CssSupportsAndCondition> for SyntaxElement {
+    fn from(n: CssSupportsAndCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthSelector {
+impl AstNode for CssSupportsAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_SELECTOR
+        kind == CSS_SUPPORTS_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13214,33 +15742,34 @@ impl AstNode for CssPseudoClassNthSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthSelector {
+impl std::fmt::Debug for CssSupportsAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthSelector")
-            .field("nth", &support::DebugSyntaxResult(self.nth()))
+        f.debug_struct("CssSupportsAtRule")
             .field(
-                "of_selector",
-                &support::DebugOptionalElement(self.of_selector()),
+                "supports_token",
+                &support::DebugSyntaxResult(self.supports_token()),
             )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPseudoClassNthSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassNthSelector) -> SyntaxNode {
+impl From<CssSupportsAtRule> for SyntaxNode {
+    fn from(n: CssSupportsAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassNthSelector) -> SyntaxElement {
+impl From<CssSupportsAtRule> for SyntaxElement {
+    fn from(n: CssSupportsAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassOfNthSelector {
+impl AstNode for CssSupportsConditionInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_CONDITION_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_OF_NTH_SELECTOR
+        kind == CSS_SUPPORTS_CONDITION_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13256,30 +15785,37 @@ impl AstNode for CssPseudoClassOfNthSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassOfNthSelector {
+impl std::fmt::Debug for CssSupportsConditionInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassOfNthSelector")
-            .field("of_token", &support::DebugSyntaxResult(self.of_token()))
-            .field("selectors", &self.selectors())
+        f.debug_struct("CssSupportsConditionInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssPseudoClassOfNthSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxNode {
+impl From<CssSupportsConditionInParens> for SyntaxNode {
+    fn from(n: CssSupportsConditionInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassOfNthSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxElement {
+impl From<CssSupportsConditionInParens> for SyntaxElement {
+    fn from(n: CssSupportsConditionInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassSelector {
+impl AstNode for CssSupportsFeatureDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_SELECTOR
+        kind == CSS_SUPPORTS_FEATURE_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13295,33 +15831,40 @@ impl AstNode for CssPseudoClassSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassSelector {
+impl std::fmt::Debug for CssSupportsFeatureDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassSelector")
+        f.debug_struct("CssSupportsFeatureDeclaration")
             .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field(
+                "declaration",
+                &support::DebugSyntaxResult(self.declaration()),
+            )
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("class", &support::DebugSyntaxResult(self.class()))
             .finish()
     }
 }
-impl From<CssPseudoClassSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassSelector) -> SyntaxNode {
+impl From<CssSupportsFeatureDeclaration> for SyntaxNode {
+    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassSelector) -> SyntaxElement {
+impl From<CssSupportsFeatureDeclaration> for SyntaxElement {
+    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementFunctionIdentifier {
+impl AstNode for CssSupportsFeatureSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER
+        kind == CSS_SUPPORTS_FEATURE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13337,15 +15880,18 @@ impl AstNode for CssPseudoElementFunctionIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
+impl std::fmt::Debug for CssSupportsFeatureSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementFunctionIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssSupportsFeatureSelector")
+            .field(
+                "selector_token",
+                &support::DebugSyntaxResult(self.selector_token()),
+            )
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -13353,22 +15899,22 @@ impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
             .finish()
     }
 }
-impl From<CssPseudoElementFunctionIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxNode {
+impl From<CssSupportsFeatureSelector> for SyntaxNode {
+    fn from(n: CssSupportsFeatureSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementFunctionIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxElement {
+impl From<CssSupportsFeatureSelector> for SyntaxElement {
+    fn from(n: CssSupportsFeatureSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementFunctionSelector {
+impl AstNode for CssSupportsNotCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_NOT_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR
+        kind == CSS_SUPPORTS_NOT_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13384,38 +15930,30 @@ impl AstNode for CssPseudoElementFunctionSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementFunctionSelector {
+impl std::fmt::Debug for CssSupportsNotCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementFunctionSelector")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssSupportsNotCondition")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
             .finish()
     }
 }
-impl From<CssPseudoElementFunctionSelector> for SyntaxNode {
-    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxNode {
+impl From<CssSupportsNotCondition> for SyntaxNode {
+    fn from(n: CssSupportsNotCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementFunctionSelector> for SyntaxElement {
-    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxElement {
+impl From<CssSupportsNotCondition> for SyntaxElement {
+    fn from(n: CssSupportsNotCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementIdentifier {
+impl AstNode for CssSupportsOrCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_OR_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_IDENTIFIER
+        kind == CSS_SUPPORTS_OR_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13431,29 +15969,31 @@ impl AstNode for CssPseudoElementIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementIdentifier {
+impl std::fmt::Debug for CssSupportsOrCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssSupportsOrCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPseudoElementIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoElementIdentifier) -> SyntaxNode {
+impl From<CssSupportsOrCondition> for SyntaxNode {
+    fn from(n: CssSupportsOrCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoElementIdentifier) -> SyntaxElement {
+impl From<CssSupportsOrCondition> for SyntaxElement {
+    fn from(n: CssSupportsOrCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementSelector {
+impl AstNode for CssTypeSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_TYPE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_SELECTOR
+        kind == CSS_TYPE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13469,33 +16009,33 @@ impl AstNode for CssPseudoElementSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementSelector {
+impl std::fmt::Debug for CssTypeSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementSelector")
+        f.debug_struct("CssTypeSelector")
             .field(
-                "double_colon_token",
-                &support::DebugSyntaxResult(self.double_colon_token()),
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
             )
-            .field("element", &support::DebugSyntaxResult(self.element()))
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
             .finish()
     }
 }
-impl From<CssPseudoElementSelector> for SyntaxNode {
-    fn from(n: CssPseudoElementSelector) -> SyntaxNode {
+impl From<CssTypeSelector> for SyntaxNode {
+    fn from(n: CssTypeSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementSelector> for SyntaxElement {
-    fn from(n: CssPseudoElementSelector) -> SyntaxElement {
+impl From<CssTypeSelector> for SyntaxElement {
+    fn from(n: CssTypeSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQualifiedRule {
+impl AstNode for CssUnicodeCodepoint {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUALIFIED_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_CODEPOINT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUALIFIED_RULE
+        kind == CSS_UNICODE_CODEPOINT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13511,30 +16051,32 @@ impl AstNode for CssQualifiedRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQualifiedRule {
+impl std::fmt::Debug for CssUnicodeCodepoint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQualifiedRule")
-            .field("prelude", &self.prelude())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssUnicodeCodepoint")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssQualifiedRule> for SyntaxNode {
-    fn from(n: CssQualifiedRule) -> SyntaxNode {
+impl From<CssUnicodeCodepoint> for SyntaxNode {
+    fn from(n: CssUnicodeCodepoint) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQualifiedRule> for SyntaxElement {
-    fn from(n: CssQualifiedRule) -> SyntaxElement {
+impl From<CssUnicodeCodepoint> for SyntaxElement {
+    fn from(n: CssUnicodeCodepoint) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureBoolean {
+impl AstNode for CssUnicodeRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_BOOLEAN as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_BOOLEAN
+        kind == CSS_UNICODE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13550,29 +16092,33 @@ impl AstNode for CssQueryFeatureBoolean {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureBoolean {
+impl std::fmt::Debug for CssUnicodeRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureBoolean")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssUnicodeRange")
+            .field(
+                "prefix_token",
+                &support::DebugSyntaxResult(self.prefix_token()),
+            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssQueryFeatureBoolean> for SyntaxNode {
-    fn from(n: CssQueryFeatureBoolean) -> SyntaxNode {
+impl From<CssUnicodeRange> for SyntaxNode {
+    fn from(n: CssUnicodeRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureBoolean> for SyntaxElement {
-    fn from(n: CssQueryFeatureBoolean) -> SyntaxElement {
+impl From<CssUnicodeRange> for SyntaxElement {
+    fn from(n: CssUnicodeRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeaturePlain {
+impl AstNode for CssUnicodeRangeInterval {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_PLAIN as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE_INTERVAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_PLAIN
+        kind == CSS_UNICODE_RANGE_INTERVAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13588,34 +16134,34 @@ impl AstNode for CssQueryFeaturePlain {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeaturePlain {
+impl std::fmt::Debug for CssUnicodeRangeInterval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeaturePlain")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssUnicodeRangeInterval")
+            .field("start", &support::DebugSyntaxResult(self.start()))
             .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
+                "minus_token",
+                &support::DebugSyntaxResult(self.minus_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssQueryFeaturePlain> for SyntaxNode {
-    fn from(n: CssQueryFeaturePlain) -> SyntaxNode {
+impl From<CssUnicodeRangeInterval> for SyntaxNode {
+    fn from(n: CssUnicodeRangeInterval) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeaturePlain> for SyntaxElement {
-    fn from(n: CssQueryFeaturePlain) -> SyntaxElement {
+impl From<CssUnicodeRangeInterval> for SyntaxElement {
+    fn from(n: CssUnicodeRangeInterval) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRange {
+impl AstNode for CssUnicodeRangeWildcard {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE_WILDCARD as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE
+        kind == CSS_UNICODE_RANGE_WILDCARD
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13631,31 +16177,32 @@ impl AstNode for CssQueryFeatureRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRange {
+impl std::fmt::Debug for CssUnicodeRangeWildcard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRange")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssUnicodeRangeWildcard")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssQueryFeatureRange> for SyntaxNode {
-    fn from(n: CssQueryFeatureRange) -> SyntaxNode {
+impl From<CssUnicodeRangeWildcard> for SyntaxNode {
+    fn from(n: CssUnicodeRangeWildcard) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRange> for SyntaxElement {
-    fn from(n: CssQueryFeatureRange) -> SyntaxElement {
+impl From<CssUnicodeRangeWildcard> for SyntaxElement {
+    fn from(n: CssUnicodeRangeWildcard) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRangeComparison {
+impl AstNode for CssUniversalNamespacePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_COMPARISON as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_NAMESPACE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE_COMPARISON
+        kind == CSS_UNIVERSAL_NAMESPACE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13671,29 +16218,29 @@ impl AstNode for CssQueryFeatureRangeComparison {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRangeComparison {
+impl std::fmt::Debug for CssUniversalNamespacePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRangeComparison")
-            .field("operator", &support::DebugSyntaxResult(self.operator()))
+        f.debug_struct("CssUniversalNamespacePrefix")
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssQueryFeatureRangeComparison> for SyntaxNode {
-    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxNode {
+impl From<CssUniversalNamespacePrefix> for SyntaxNode {
+    fn from(n: CssUniversalNamespacePrefix) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRangeComparison> for SyntaxElement {
-    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxElement {
+impl From<CssUniversalNamespacePrefix> for SyntaxElement {
+    fn from(n: CssUniversalNamespacePrefix) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRangeInterval {
+impl AstNode for CssUniversalSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_INTERVAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE_INTERVAL
+        kind == CSS_UNIVERSAL_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13709,39 +16256,33 @@ impl AstNode for CssQueryFeatureRangeInterval {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRangeInterval {
+impl std::fmt::Debug for CssUniversalSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRangeInterval")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field(
-                "left_comparison",
-                &support::DebugSyntaxResult(self.left_comparison()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssUniversalSelector")
             .field(
-                "right_comparison",
-                &support::DebugSyntaxResult(self.right_comparison()),
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
             )
-            .field("right", &support::DebugSyntaxResult(self.right()))
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssQueryFeatureRangeInterval> for SyntaxNode {
-    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxNode {
+impl From<CssUniversalSelector> for SyntaxNode {
+    fn from(n: CssUniversalSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRangeInterval> for SyntaxElement {
-    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxElement {
+impl From<CssUniversalSelector> for SyntaxElement {
+    fn from(n: CssUniversalSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureReverseRange {
+impl AstNode for CssUnknownBlockAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_REVERSE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_BLOCK_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_REVERSE_RANGE
+        kind == CSS_UNKNOWN_BLOCK_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13757,31 +16298,31 @@ impl AstNode for CssQueryFeatureReverseRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureReverseRange {
+impl std::fmt::Debug for CssUnknownBlockAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureReverseRange")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssUnknownBlockAtRule")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("components", &support::DebugSyntaxResult(self.components()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssQueryFeatureReverseRange> for SyntaxNode {
-    fn from(n: CssQueryFeatureReverseRange) -> SyntaxNode {
+impl From<CssUnknownBlockAtRule> for SyntaxNode {
+    fn from(n: CssUnknownBlockAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureReverseRange> for SyntaxElement {
-    fn from(n: CssQueryFeatureReverseRange) -> SyntaxElement {
+impl From<CssUnknownBlockAtRule> for SyntaxElement {
+    fn from(n: CssUnknownBlockAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRatio {
+impl AstNode for CssUnknownDimension {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_DIMENSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RATIO
+        kind == CSS_UNKNOWN_DIMENSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13797,37 +16338,33 @@ impl AstNode for CssRatio {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRatio {
+impl std::fmt::Debug for CssUnknownDimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRatio")
-            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
-            .field(
-                "slash_token",
-                &support::DebugSyntaxResult(self.slash_token()),
-            )
+        f.debug_struct("CssUnknownDimension")
             .field(
-                "denominator",
-                &support::DebugSyntaxResult(self.denominator()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
+            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssRatio> for SyntaxNode {
-    fn from(n: CssRatio) -> SyntaxNode {
+impl From<CssUnknownDimension> for SyntaxNode {
+    fn from(n: CssUnknownDimension) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRatio> for SyntaxElement {
-    fn from(n: CssRatio) -> SyntaxElement {
+impl From<CssUnknownDimension> for SyntaxElement {
+    fn from(n: CssUnknownDimension) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRegularDimension {
+impl AstNode for CssUnknownValueAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_REGULAR_DIMENSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_VALUE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_REGULAR_DIMENSION
+        kind == CSS_UNKNOWN_VALUE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13843,33 +16380,34 @@ impl AstNode for CssRegularDimension {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRegularDimension {
+impl std::fmt::Debug for CssUnknownValueAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRegularDimension")
+        f.debug_struct("CssUnknownValueAtRule")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("components", &support::DebugSyntaxResult(self.components()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
-            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssRegularDimension> for SyntaxNode {
-    fn from(n: CssRegularDimension) -> SyntaxNode {
+impl From<CssUnknownValueAtRule> for SyntaxNode {
+    fn from(n: CssUnknownValueAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRegularDimension> for SyntaxElement {
-    fn from(n: CssRegularDimension) -> SyntaxElement {
+impl From<CssUnknownValueAtRule> for SyntaxElement {
+    fn from(n: CssUnknownValueAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRelativeSelector {
+impl AstNode for CssUrlFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RELATIVE_SELECTOR
+        kind == CSS_URL_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13885,33 +16423,39 @@ impl AstNode for CssRelativeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRelativeSelector {
+impl std::fmt::Debug for CssUrlFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRelativeSelector")
+        f.debug_struct("CssUrlFunction")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "combinator",
-                &support::DebugOptionalElement(self.combinator()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .field("modifiers", &self.modifiers())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssRelativeSelector> for SyntaxNode {
-    fn from(n: CssRelativeSelector) -> SyntaxNode {
+impl From<CssUrlFunction> for SyntaxNode {
+    fn from(n: CssUrlFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRelativeSelector> for SyntaxElement {
-    fn from(n: CssRelativeSelector) -> SyntaxElement {
+impl From<CssUrlFunction> for SyntaxElement {
+    fn from(n: CssUrlFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRoot {
+impl AstNode for CssUrlValueRaw {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ROOT
+        kind == CSS_URL_VALUE_RAW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13927,34 +16471,32 @@ impl AstNode for CssRoot {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRoot {
+impl std::fmt::Debug for CssUrlValueRaw {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRoot")
+        f.debug_struct("CssUrlValueRaw")
             .field(
-                "bom_token",
-                &support::DebugOptionalElement(self.bom_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
-            .field("rules", &self.rules())
-            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
             .finish()
     }
 }
-impl From<CssRoot> for SyntaxNode {
-    fn from(n: CssRoot) -> SyntaxNode {
+impl From<CssUrlValueRaw> for SyntaxNode {
+    fn from(n: CssUrlValueRaw) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRoot> for SyntaxElement {
-    fn from(n: CssRoot) -> SyntaxElement {
+impl From<CssUrlValueRaw> for SyntaxElement {
+    fn from(n: CssUrlValueRaw) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRuleBlock {
+impl AstNode for CssValueAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RULE_BLOCK
+        kind == CSS_VALUE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13970,37 +16512,37 @@ impl AstNode for CssRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRuleBlock {
+impl std::fmt::Debug for CssValueAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRuleBlock")
+        f.debug_struct("CssValueAtRule")
             .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
-            .field("rules", &self.rules())
+            .field("clause", &support::DebugSyntaxResult(self.clause()))
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssRuleBlock> for SyntaxNode {
-    fn from(n: CssRuleBlock) -> SyntaxNode {
+impl From<CssValueAtRule> for SyntaxNode {
+    fn from(n: CssValueAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRuleBlock> for SyntaxElement {
-    fn from(n: CssRuleBlock) -> SyntaxElement {
+impl From<CssValueAtRule> for SyntaxElement {
+    fn from(n: CssValueAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeAtRule {
+impl AstNode for CssValueAtRuleDeclarationClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_DECLARATION_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_AT_RULE
+        kind == CSS_VALUE_AT_RULE_DECLARATION_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14016,34 +16558,29 @@ impl AstNode for CssScopeAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeAtRule {
+impl std::fmt::Debug for CssValueAtRuleDeclarationClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeAtRule")
-            .field(
-                "scope_token",
-                &support::DebugSyntaxResult(self.scope_token()),
-            )
-            .field("range", &support::DebugOptionalElement(self.range()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssValueAtRuleDeclarationClause")
+            .field("properties", &self.properties())
             .finish()
     }
 }
-impl From<CssScopeAtRule> for SyntaxNode {
-    fn from(n: CssScopeAtRule) -> SyntaxNode {
+impl From<CssValueAtRuleDeclarationClause> for SyntaxNode {
+    fn from(n: CssValueAtRuleDeclarationClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeAtRule> for SyntaxElement {
-    fn from(n: CssScopeAtRule) -> SyntaxElement {
+impl From<CssValueAtRuleDeclarationClause> for SyntaxElement {
+    fn from(n: CssValueAtRuleDeclarationClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeEdge {
+impl AstNode for CssValueAtRuleGenericProperty {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_EDGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_GENERIC_PROPERTY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_EDGE
+        kind == CSS_VALUE_AT_RULE_GENERIC_PROPERTY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14059,37 +16596,34 @@ impl AstNode for CssScopeEdge {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeEdge {
+impl std::fmt::Debug for CssValueAtRuleGenericProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeEdge")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selectors", &self.selectors())
+        f.debug_struct("CssValueAtRuleGenericProperty")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssScopeEdge> for SyntaxNode {
-    fn from(n: CssScopeEdge) -> SyntaxNode {
+impl From<CssValueAtRuleGenericProperty> for SyntaxNode {
+    fn from(n: CssValueAtRuleGenericProperty) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeEdge> for SyntaxElement {
-    fn from(n: CssScopeEdge) -> SyntaxElement {
+impl From<CssValueAtRuleGenericProperty> for SyntaxElement {
+    fn from(n: CssValueAtRuleGenericProperty) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeEnd {
+impl AstNode for CssValueAtRuleImportClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_END as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_IMPORT_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_END
+        kind == CSS_VALUE_AT_RULE_IMPORT_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14105,30 +16639,31 @@ impl AstNode for CssScopeRangeEnd {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeEnd {
+impl std::fmt::Debug for CssValueAtRuleImportClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeEnd")
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field("end", &support::DebugSyntaxResult(self.end()))
+        f.debug_struct("CssValueAtRuleImportClause")
+            .field("specifiers", &self.specifiers())
+            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
+            .field("source", &support::DebugSyntaxResult(self.source()))
             .finish()
     }
 }
-impl From<CssScopeRangeEnd> for SyntaxNode {
-    fn from(n: CssScopeRangeEnd) -> SyntaxNode {
+impl From<CssValueAtRuleImportClause> for SyntaxNode {
+    fn from(n: CssValueAtRuleImportClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeEnd> for SyntaxElement {
-    fn from(n: CssScopeRangeEnd) -> SyntaxElement {
+impl From<CssValueAtRuleImportClause> for SyntaxElement {
+    fn from(n: CssValueAtRuleImportClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeInterval {
+impl AstNode for CssValueAtRuleImportSpecifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_INTERVAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_IMPORT_SPECIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_INTERVAL
+        kind == CSS_VALUE_AT_RULE_IMPORT_SPECIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14144,31 +16679,30 @@ impl AstNode for CssScopeRangeInterval {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeInterval {
+impl std::fmt::Debug for CssValueAtRuleImportSpecifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeInterval")
-            .field("start", &support::DebugSyntaxResult(self.start()))
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field("end", &support::DebugSyntaxResult(self.end()))
+        f.debug_struct("CssValueAtRuleImportSpecifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssScopeRangeInterval> for SyntaxNode {
-    fn from(n: CssScopeRangeInterval) -> SyntaxNode {
+impl From<CssValueAtRuleImportSpecifier> for SyntaxNode {
+    fn from(n: CssValueAtRuleImportSpecifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeInterval> for SyntaxElement {
-    fn from(n: CssScopeRangeInterval) -> SyntaxElement {
+impl From<CssValueAtRuleImportSpecifier> for SyntaxElement {
+    fn from(n: CssValueAtRuleImportSpecifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeStart {
+impl AstNode for CssValueAtRuleNamedImportSpecifier {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_START as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_START
+        kind == CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14184,29 +16718,31 @@ impl AstNode for CssScopeRangeStart {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeStart {
+impl std::fmt::Debug for CssValueAtRuleNamedImportSpecifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeStart")
-            .field("start", &support::DebugSyntaxResult(self.start()))
+        f.debug_struct("CssValueAtRuleNamedImportSpecifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("as_token", &support::DebugSyntaxResult(self.as_token()))
+            .field("local_name", &support::DebugSyntaxResult(self.local_name()))
             .finish()
     }
 }
-impl From<CssScopeRangeStart> for SyntaxNode {
-    fn from(n: CssScopeRangeStart) -> SyntaxNode {
+impl From<CssValueAtRuleNamedImportSpecifier> for SyntaxNode {
+    fn from(n: CssValueAtRuleNamedImportSpecifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeStart> for SyntaxElement {
-    fn from(n: CssScopeRangeStart) -> SyntaxElement {
+impl From<CssValueAtRuleNamedImportSpecifier> for SyntaxElement {
+    fn from(n: CssValueAtRuleNamedImportSpecifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssStartingStyleAtRule {
+impl AstNode for ScssArgument {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STARTING_STYLE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_ARGUMENT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_STARTING_STYLE_AT_RULE
+        kind == SCSS_ARGUMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14222,33 +16758,30 @@ impl AstNode for CssStartingStyleAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssStartingStyleAtRule {
+impl std::fmt::Debug for ScssArgument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssStartingStyleAtRule")
-            .field(
-                "starting_style_token",
-                &support::DebugSyntaxResult(self.starting_style_token()),
-            )
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("ScssArgument")
+            .field("name", &support::DebugOptionalElement(self.name()))
+            .field("value", &self.value())
             .finish()
     }
 }
-impl From<CssStartingStyleAtRule> for SyntaxNode {
-    fn from(n: CssStartingStyleAtRule) -> SyntaxNode {
+impl From<ScssArgument> for SyntaxNode {
+    fn from(n: ScssArgument) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssStartingStyleAtRule> for SyntaxElement {
-    fn from(n: CssStartingStyleAtRule) -> SyntaxElement {
+impl From<ScssArgument> for SyntaxElement {
+    fn from(n: ScssArgument) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssString {
+impl AstNode for ScssArgumentName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STRING as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_ARGUMENT_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_STRING
+        kind == SCSS_ARGUMENT_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14264,32 +16797,33 @@ impl AstNode for CssString {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssString {
+impl std::fmt::Debug for ScssArgumentName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssString")
+        f.debug_struct("ScssArgumentName")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
             .finish()
     }
 }
-impl From<CssString> for SyntaxNode {
-    fn from(n: CssString) -> SyntaxNode {
+impl From<ScssArgumentName> for SyntaxNode {
+    fn from(n: ScssArgumentName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssString> for SyntaxElement {
-    fn from(n: CssString) -> SyntaxElement {
+impl From<ScssArgumentName> for SyntaxElement {
+    fn from(n: ScssArgumentName) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsAndCondition {
+impl AstNode for ScssArguments {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AND_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_ARGUMENTS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_AND_CONDITION
+        kind == SCSS_ARGUMENTS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14305,31 +16839,37 @@ impl AstNode for CssSupportsAndCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsAndCondition {
+impl std::fmt::Debug for ScssArguments {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsAndCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("ScssArguments")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssSupportsAndCondition> for SyntaxNode {
-    fn from(n: CssSupportsAndCondition) -> SyntaxNode {
+impl From<ScssArguments> for SyntaxNode {
+    fn from(n: ScssArguments) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsAndCondition> for SyntaxElement {
-    fn from(n: CssSupportsAndCondition) -> SyntaxElement {
+impl From<ScssArguments> for SyntaxElement {
+    fn from(n: ScssArguments) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsAtRule {
+impl AstNode for ScssContentAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_CONTENT_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_AT_RULE
+        kind == SCSS_CONTENT_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14345,34 +16885,36 @@ impl AstNode for CssSupportsAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsAtRule {
+impl std::fmt::Debug for ScssContentAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsAtRule")
+        f.debug_struct("ScssContentAtRule")
             .field(
-                "supports_token",
-                &support::DebugSyntaxResult(self.supports_token()),
+                "content_token",
+                &support::DebugSyntaxResult(self.content_token()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssSupportsAtRule> for SyntaxNode {
-    fn from(n: CssSupportsAtRule) -> SyntaxNode {
+impl From<ScssContentAtRule> for SyntaxNode {
+    fn from(n: ScssContentAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsAtRule> for SyntaxElement {
-    fn from(n: CssSupportsAtRule) -> SyntaxElement {
+impl From<ScssContentAtRule> for SyntaxElement {
+    fn from(n: ScssContentAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsConditionInParens {
+impl AstNode for ScssDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_CONDITION_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_CONDITION_IN_PARENS
+        kind == SCSS_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14388,37 +16930,39 @@ impl AstNode for CssSupportsConditionInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsConditionInParens {
+impl std::fmt::Debug for ScssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsConditionInParens")
+        f.debug_struct("ScssDeclaration")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field("value", &self.value())
+            .field("modifiers", &self.modifiers())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssSupportsConditionInParens> for SyntaxNode {
-    fn from(n: CssSupportsConditionInParens) -> SyntaxNode {
+impl From<ScssDeclaration> for SyntaxNode {
+    fn from(n: ScssDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsConditionInParens> for SyntaxElement {
-    fn from(n: CssSupportsConditionInParens) -> SyntaxElement {
+impl From<ScssDeclaration> for SyntaxElement {
+    fn from(n: ScssDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsFeatureDeclaration {
+impl AstNode for ScssExtendAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_EXTEND_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_FEATURE_DECLARATION
+        kind == SCSS_EXTEND_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14434,40 +16978,37 @@ impl AstNode for CssSupportsFeatureDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsFeatureDeclaration {
+impl std::fmt::Debug for ScssExtendAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsFeatureDeclaration")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
+        f.debug_struct("ScssExtendAtRule")
             .field(
-                "declaration",
-                &support::DebugSyntaxResult(self.declaration()),
+                "extend_token",
+                &support::DebugSyntaxResult(self.extend_token()),
             )
+            .field("selectors", &self.selectors())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssSupportsFeatureDeclaration> for SyntaxNode {
-    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxNode {
+impl From<ScssExtendAtRule> for SyntaxNode {
+    fn from(n: ScssExtendAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsFeatureDeclaration> for SyntaxElement {
-    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxElement {
+impl From<ScssExtendAtRule> for SyntaxElement {
+    fn from(n: ScssExtendAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsFeatureSelector {
+impl AstNode for ScssForwardAsClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_FORWARD_AS_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_FEATURE_SELECTOR
+        kind == SCSS_FORWARD_AS_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14483,41 +17024,31 @@ impl AstNode for CssSupportsFeatureSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsFeatureSelector {
+impl std::fmt::Debug for ScssForwardAsClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsFeatureSelector")
-            .field(
-                "selector_token",
-                &support::DebugSyntaxResult(self.selector_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("ScssForwardAsClause")
+            .field("as_token", &support::DebugSyntaxResult(self.as_token()))
+            .field("prefix", &support::DebugSyntaxResult(self.prefix()))
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssSupportsFeatureSelector> for SyntaxNode {
-    fn from(n: CssSupportsFeatureSelector) -> SyntaxNode {
+impl From<ScssForwardAsClause> for SyntaxNode {
+    fn from(n: ScssForwardAsClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsFeatureSelector> for SyntaxElement {
-    fn from(n: CssSupportsFeatureSelector) -> SyntaxElement {
+impl From<ScssForwardAsClause> for SyntaxElement {
+    fn from(n: ScssForwardAsClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsNotCondition {
+impl AstNode for ScssForwardAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_NOT_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_FORWARD_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_NOT_CONDITION
+        kind == SCSS_FORWARD_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14533,30 +17064,49 @@ impl AstNode for CssSupportsNotCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsNotCondition {
+impl std::fmt::Debug for ScssForwardAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsNotCondition")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("ScssForwardAtRule")
+            .field(
+                "forward_token",
+                &support::DebugSyntaxResult(self.forward_token()),
+            )
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field(
+                "as_clause",
+                &support::DebugOptionalElement(self.as_clause()),
+            )
+            .field(
+                "visibility",
+                &support::DebugOptionalElement(self.visibility()),
+            )
+            .field(
+                "configuration",
+                &support::DebugOptionalElement(self.configuration()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssSupportsNotCondition> for SyntaxNode {
-    fn from(n: CssSupportsNotCondition) -> SyntaxNode {
+impl From<ScssForwardAtRule> for SyntaxNode {
+    fn from(n: ScssForwardAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsNotCondition> for SyntaxElement {
-    fn from(n: CssSupportsNotCondition) -> SyntaxElement {
+impl From<ScssForwardAtRule> for SyntaxElement {
+    fn from(n: ScssForwardAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsOrCondition {
+impl AstNode for ScssForwardVisibilityClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_OR_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_FORWARD_VISIBILITY_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_OR_CONDITION
+        kind == SCSS_FORWARD_VISIBILITY_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14572,31 +17122,30 @@ impl AstNode for CssSupportsOrCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsOrCondition {
+impl std::fmt::Debug for ScssForwardVisibilityClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsOrCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("ScssForwardVisibilityClause")
+            .field("modifier", &support::DebugSyntaxResult(self.modifier()))
+            .field("members", &self.members())
             .finish()
     }
 }
-impl From<CssSupportsOrCondition> for SyntaxNode {
-    fn from(n: CssSupportsOrCondition) -> SyntaxNode {
+impl From<ScssForwardVisibilityClause> for SyntaxNode {
+    fn from(n: ScssForwardVisibilityClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsOrCondition> for SyntaxElement {
-    fn from(n: CssSupportsOrCondition) -> SyntaxElement {
+impl From<ScssForwardVisibilityClause> for SyntaxElement {
+    fn from(n: ScssForwardVisibilityClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssTypeSelector {
+impl AstNode for ScssIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_TYPE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_TYPE_SELECTOR
+        kind == SCSS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14612,33 +17161,33 @@ impl AstNode for CssTypeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssTypeSelector {
+impl std::fmt::Debug for ScssIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssTypeSelector")
+        f.debug_struct("ScssIdentifier")
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "dollar_token",
+                &support::DebugSyntaxResult(self.dollar_token()),
             )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssTypeSelector> for SyntaxNode {
-    fn from(n: CssTypeSelector) -> SyntaxNode {
+impl From<ScssIdentifier> for SyntaxNode {
+    fn from(n: ScssIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssTypeSelector> for SyntaxElement {
-    fn from(n: CssTypeSelector) -> SyntaxElement {
+impl From<ScssIdentifier> for SyntaxElement {
+    fn from(n: ScssIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnicodeCodepoint {
+impl AstNode for ScssIncludeAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_CODEPOINT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_INCLUDE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNICODE_CODEPOINT
+        kind == SCSS_INCLUDE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14654,32 +17203,42 @@ impl AstNode for CssUnicodeCodepoint {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnicodeCodepoint {
+impl std::fmt::Debug for ScssIncludeAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnicodeCodepoint")
+        f.debug_struct("ScssIncludeAtRule")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "include_token",
+                &support::DebugSyntaxResult(self.include_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "arguments",
+                &support::DebugOptionalElement(self.arguments()),
+            )
+            .field("block", &support::DebugOptionalElement(self.block()))
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssUnicodeCodepoint> for SyntaxNode {
-    fn from(n: CssUnicodeCodepoint) -> SyntaxNode {
+impl From<ScssIncludeAtRule> for SyntaxNode {
+    fn from(n: ScssIncludeAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnicodeCodepoint> for SyntaxElement {
-    fn from(n: CssUnicodeCodepoint) -> SyntaxElement {
+impl From<ScssIncludeAtRule> for SyntaxElement {
+    fn from(n: ScssIncludeAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnicodeRange {
+impl AstNode for ScssInterpolatedIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_INTERPOLATED_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNICODE_RANGE
+        kind == SCSS_INTERPOLATED_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14695,33 +17254,29 @@ impl AstNode for CssUnicodeRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnicodeRange {
+impl std::fmt::Debug for ScssInterpolatedIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnicodeRange")
-            .field(
-                "prefix_token",
-                &support::DebugSyntaxResult(self.prefix_token()),
-            )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("ScssInterpolatedIdentifier")
+            .field("items", &self.items())
             .finish()
     }
 }
-impl From<CssUnicodeRange> for SyntaxNode {
-    fn from(n: CssUnicodeRange) -> SyntaxNode {
+impl From<ScssInterpolatedIdentifier> for SyntaxNode {
+    fn from(n: ScssInterpolatedIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnicodeRange> for SyntaxElement {
-    fn from(n: CssUnicodeRange) -> SyntaxElement {
+impl From<ScssInterpolatedIdentifier> for SyntaxElement {
+    fn from(n: ScssInterpolatedIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnicodeRangeInterval {
+impl AstNode for ScssInterpolation {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE_INTERVAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_INTERPOLATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNICODE_RANGE_INTERVAL
+        kind == SCSS_INTERPOLATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14737,34 +17292,37 @@ impl AstNode for CssUnicodeRangeInterval {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnicodeRangeInterval {
+impl std::fmt::Debug for ScssInterpolation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnicodeRangeInterval")
-            .field("start", &support::DebugSyntaxResult(self.start()))
+        f.debug_struct("ScssInterpolation")
             .field(
-                "minus_token",
-                &support::DebugSyntaxResult(self.minus_token()),
+                "hash_l_curly_token",
+                &support::DebugSyntaxResult(self.hash_l_curly_token()),
+            )
+            .field("value", &self.value())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
-            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssUnicodeRangeInterval> for SyntaxNode {
-    fn from(n: CssUnicodeRangeInterval) -> SyntaxNode {
+impl From<ScssInterpolation> for SyntaxNode {
+    fn from(n: ScssInterpolation) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnicodeRangeInterval> for SyntaxElement {
-    fn from(n: CssUnicodeRangeInterval) -> SyntaxElement {
+impl From<ScssInterpolation> for SyntaxElement {
+    fn from(n: ScssInterpolation) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnicodeRangeWildcard {
+impl AstNode for ScssMapExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNICODE_RANGE_WILDCARD as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_MAP_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNICODE_RANGE_WILDCARD
+        kind == SCSS_MAP_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14780,32 +17338,37 @@ impl AstNode for CssUnicodeRangeWildcard {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnicodeRangeWildcard {
+impl std::fmt::Debug for ScssMapExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnicodeRangeWildcard")
+        f.debug_struct("ScssMapExpression")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("pairs", &self.pairs())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssUnicodeRangeWildcard> for SyntaxNode {
-    fn from(n: CssUnicodeRangeWildcard) -> SyntaxNode {
+impl From<ScssMapExpression> for SyntaxNode {
+    fn from(n: ScssMapExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnicodeRangeWildcard> for SyntaxElement {
-    fn from(n: CssUnicodeRangeWildcard) -> SyntaxElement {
+impl From<ScssMapExpression> for SyntaxElement {
+    fn from(n: ScssMapExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUniversalNamespacePrefix {
+impl AstNode for ScssMapExpressionPair {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_NAMESPACE_PREFIX as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_MAP_EXPRESSION_PAIR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNIVERSAL_NAMESPACE_PREFIX
+        kind == SCSS_MAP_EXPRESSION_PAIR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14821,29 +17384,34 @@ impl AstNode for CssUniversalNamespacePrefix {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUniversalNamespacePrefix {
+impl std::fmt::Debug for ScssMapExpressionPair {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUniversalNamespacePrefix")
-            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
+        f.debug_struct("ScssMapExpressionPair")
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &self.value())
             .finish()
     }
 }
-impl From<CssUniversalNamespacePrefix> for SyntaxNode {
-    fn from(n: CssUniversalNamespacePrefix) -> SyntaxNode {
+impl From<ScssMapExpressionPair> for SyntaxNode {
+    fn from(n: ScssMapExpressionPair) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUniversalNamespacePrefix> for SyntaxElement {
-    fn from(n: CssUniversalNamespacePrefix) -> SyntaxElement {
+impl From<ScssMapExpressionPair> for SyntaxElement {
+    fn from(n: ScssMapExpressionPair) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUniversalSelector {
+impl AstNode for ScssMixinAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_MIXIN_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNIVERSAL_SELECTOR
+        kind == SCSS_MIXIN_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14859,33 +17427,38 @@ impl AstNode for CssUniversalSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUniversalSelector {
+impl std::fmt::Debug for ScssMixinAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUniversalSelector")
+        f.debug_struct("ScssMixinAtRule")
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "mixin_token",
+                &support::DebugSyntaxResult(self.mixin_token()),
             )
-            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "parameters",
+                &support::DebugOptionalElement(self.parameters()),
+            )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssUniversalSelector> for SyntaxNode {
-    fn from(n: CssUniversalSelector) -> SyntaxNode {
+impl From<ScssMixinAtRule> for SyntaxNode {
+    fn from(n: ScssMixinAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUniversalSelector> for SyntaxElement {
-    fn from(n: CssUniversalSelector) -> SyntaxElement {
+impl From<ScssMixinAtRule> for SyntaxElement {
+    fn from(n: ScssMixinAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnknownBlockAtRule {
+impl AstNode for ScssModuleConfiguration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_BLOCK_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_MODULE_CONFIGURATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNKNOWN_BLOCK_AT_RULE
+        kind == SCSS_MODULE_CONFIGURATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14901,31 +17474,30 @@ impl AstNode for CssUnknownBlockAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnknownBlockAtRule {
+impl std::fmt::Debug for ScssModuleConfiguration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnknownBlockAtRule")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("components", &support::DebugSyntaxResult(self.components()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("ScssModuleConfiguration")
+            .field("with_token", &support::DebugSyntaxResult(self.with_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssUnknownBlockAtRule> for SyntaxNode {
-    fn from(n: CssUnknownBlockAtRule) -> SyntaxNode {
+impl From<ScssModuleConfiguration> for SyntaxNode {
+    fn from(n: ScssModuleConfiguration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnknownBlockAtRule> for SyntaxElement {
-    fn from(n: CssUnknownBlockAtRule) -> SyntaxElement {
+impl From<ScssModuleConfiguration> for SyntaxElement {
+    fn from(n: ScssModuleConfiguration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnknownDimension {
+impl AstNode for ScssNestingDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_DIMENSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_NESTING_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNKNOWN_DIMENSION
+        kind == SCSS_NESTING_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14941,33 +17513,34 @@ impl AstNode for CssUnknownDimension {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnknownDimension {
+impl std::fmt::Debug for ScssNestingDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnknownDimension")
+        f.debug_struct("ScssNestingDeclaration")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssUnknownDimension> for SyntaxNode {
-    fn from(n: CssUnknownDimension) -> SyntaxNode {
+impl From<ScssNestingDeclaration> for SyntaxNode {
+    fn from(n: ScssNestingDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnknownDimension> for SyntaxElement {
-    fn from(n: CssUnknownDimension) -> SyntaxElement {
+impl From<ScssNestingDeclaration> for SyntaxElement {
+    fn from(n: ScssNestingDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnknownValueAtRule {
+impl AstNode for ScssParameter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_VALUE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_PARAMETER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNKNOWN_VALUE_AT_RULE
+        kind == SCSS_PARAMETER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -14983,34 +17556,33 @@ impl AstNode for CssUnknownValueAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnknownValueAtRule {
+impl std::fmt::Debug for ScssParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnknownValueAtRule")
+        f.debug_struct("ScssParameter")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("components", &support::DebugSyntaxResult(self.components()))
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "default_value",
+                &support::DebugOptionalElement(self.default_value()),
             )
             .finish()
     }
 }
-impl From<CssUnknownValueAtRule> for SyntaxNode {
-    fn from(n: CssUnknownValueAtRule) -> SyntaxNode {
+impl From<ScssParameter> for SyntaxNode {
+    fn from(n: ScssParameter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnknownValueAtRule> for SyntaxElement {
-    fn from(n: CssUnknownValueAtRule) -> SyntaxElement {
+impl From<ScssParameter> for SyntaxElement {
+    fn from(n: ScssParameter) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUrlFunction {
+impl AstNode for ScssParameterDefaultValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_FUNCTION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_PARAMETER_DEFAULT_VALUE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_URL_FUNCTION
+        kind == SCSS_PARAMETER_DEFAULT_VALUE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15026,39 +17598,33 @@ impl AstNode for CssUrlFunction {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUrlFunction {
+impl std::fmt::Debug for ScssParameterDefaultValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUrlFunction")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("value", &support::DebugOptionalElement(self.value()))
-            .field("modifiers", &self.modifiers())
+        f.debug_struct("ScssParameterDefaultValue")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("value", &self.value())
             .finish()
     }
 }
-impl From<CssUrlFunction> for SyntaxNode {
-    fn from(n: CssUrlFunction) -> SyntaxNode {
+impl From<ScssParameterDefaultValue> for SyntaxNode {
+    fn from(n: ScssParameterDefaultValue) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUrlFunction> for SyntaxElement {
-    fn from(n: CssUrlFunction) -> SyntaxElement {
+impl From<ScssParameterDefaultValue> for SyntaxElement {
+    fn from(n: ScssParameterDefaultValue) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUrlValueRaw {
+impl AstNode for ScssParameters {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_PARAMETERS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_URL_VALUE_RAW
+        kind == SCSS_PARAMETERS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15074,32 +17640,37 @@ impl AstNode for CssUrlValueRaw {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUrlValueRaw {
+impl std::fmt::Debug for ScssParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUrlValueRaw")
+        f.debug_struct("ScssParameters")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssUrlValueRaw> for SyntaxNode {
-    fn from(n: CssUrlValueRaw) -> SyntaxNode {
+impl From<ScssParameters> for SyntaxNode {
+    fn from(n: ScssParameters) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUrlValueRaw> for SyntaxElement {
-    fn from(n: CssUrlValueRaw) -> SyntaxElement {
+impl From<ScssParameters> for SyntaxElement {
+    fn from(n: ScssParameters) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRule {
+impl AstNode for ScssPlaceholderSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_PLACEHOLDER_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE
+        kind == SCSS_PLACEHOLDER_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15115,37 +17686,33 @@ impl AstNode for CssValueAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRule {
+impl std::fmt::Debug for ScssPlaceholderSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRule")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .field("clause", &support::DebugSyntaxResult(self.clause()))
+        f.debug_struct("ScssPlaceholderSelector")
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "percent_token",
+                &support::DebugSyntaxResult(self.percent_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssValueAtRule> for SyntaxNode {
-    fn from(n: CssValueAtRule) -> SyntaxNode {
+impl From<ScssPlaceholderSelector> for SyntaxNode {
+    fn from(n: ScssPlaceholderSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRule> for SyntaxElement {
-    fn from(n: CssValueAtRule) -> SyntaxElement {
+impl From<ScssPlaceholderSelector> for SyntaxElement {
+    fn from(n: ScssPlaceholderSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRuleDeclarationClause {
+impl AstNode for ScssQualifiedName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_DECLARATION_CLAUSE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_QUALIFIED_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_DECLARATION_CLAUSE
+        kind == SCSS_QUALIFIED_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15161,29 +17728,31 @@ impl AstNode for CssValueAtRuleDeclarationClause {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRuleDeclarationClause {
+impl std::fmt::Debug for ScssQualifiedName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleDeclarationClause")
-            .field("properties", &self.properties())
+        f.debug_struct("ScssQualifiedName")
+            .field("module", &support::DebugSyntaxResult(self.module()))
+            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
+            .field("member", &support::DebugSyntaxResult(self.member()))
             .finish()
     }
 }
-impl From<CssValueAtRuleDeclarationClause> for SyntaxNode {
-    fn from(n: CssValueAtRuleDeclarationClause) -> SyntaxNode {
+impl From<ScssQualifiedName> for SyntaxNode {
+    fn from(n: ScssQualifiedName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRuleDeclarationClause> for SyntaxElement {
-    fn from(n: CssValueAtRuleDeclarationClause) -> SyntaxElement {
+impl From<ScssQualifiedName> for SyntaxElement {
+    fn from(n: ScssQualifiedName) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRuleGenericProperty {
+impl AstNode for ScssUseAllNamespace {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_GENERIC_PROPERTY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_USE_ALL_NAMESPACE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_GENERIC_PROPERTY
+        kind == SCSS_USE_ALL_NAMESPACE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15199,34 +17768,29 @@ impl AstNode for CssValueAtRuleGenericProperty {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRuleGenericProperty {
+impl std::fmt::Debug for ScssUseAllNamespace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleGenericProperty")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
-            )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("ScssUseAllNamespace")
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssValueAtRuleGenericProperty> for SyntaxNode {
-    fn from(n: CssValueAtRuleGenericProperty) -> SyntaxNode {
+impl From<ScssUseAllNamespace> for SyntaxNode {
+    fn from(n: ScssUseAllNamespace) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRuleGenericProperty> for SyntaxElement {
-    fn from(n: CssValueAtRuleGenericProperty) -> SyntaxElement {
+impl From<ScssUseAllNamespace> for SyntaxElement {
+    fn from(n: ScssUseAllNamespace) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRuleImportClause {
+impl AstNode for ScssUseAsClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_IMPORT_CLAUSE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_USE_AS_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_IMPORT_CLAUSE
+        kind == SCSS_USE_AS_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15242,31 +17806,30 @@ impl AstNode for CssValueAtRuleImportClause {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRuleImportClause {
+impl std::fmt::Debug for ScssUseAsClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleImportClause")
-            .field("specifiers", &self.specifiers())
-            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
-            .field("source", &support::DebugSyntaxResult(self.source()))
+        f.debug_struct("ScssUseAsClause")
+            .field("as_token", &support::DebugSyntaxResult(self.as_token()))
+            .field("namespace", &support::DebugSyntaxResult(self.namespace()))
             .finish()
     }
 }
-impl From<CssValueAtRuleImportClause> for SyntaxNode {
-    fn from(n: CssValueAtRuleImportClause) -> SyntaxNode {
+impl From<ScssUseAsClause> for SyntaxNode {
+    fn from(n: ScssUseAsClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRuleImportClause> for SyntaxElement {
-    fn from(n: CssValueAtRuleImportClause) -> SyntaxElement {
+impl From<ScssUseAsClause> for SyntaxElement {
+    fn from(n: ScssUseAsClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRuleImportSpecifier {
+impl AstNode for ScssUseAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_IMPORT_SPECIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_USE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_IMPORT_SPECIFIER
+        kind == SCSS_USE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15282,30 +17845,42 @@ impl AstNode for CssValueAtRuleImportSpecifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRuleImportSpecifier {
+impl std::fmt::Debug for ScssUseAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleImportSpecifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("ScssUseAtRule")
+            .field("use_token", &support::DebugSyntaxResult(self.use_token()))
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field(
+                "as_clause",
+                &support::DebugOptionalElement(self.as_clause()),
+            )
+            .field(
+                "configuration",
+                &support::DebugOptionalElement(self.configuration()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssValueAtRuleImportSpecifier> for SyntaxNode {
-    fn from(n: CssValueAtRuleImportSpecifier) -> SyntaxNode {
+impl From<ScssUseAtRule> for SyntaxNode {
+    fn from(n: ScssUseAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRuleImportSpecifier> for SyntaxElement {
-    fn from(n: CssValueAtRuleImportSpecifier) -> SyntaxElement {
+impl From<ScssUseAtRule> for SyntaxElement {
+    fn from(n: ScssUseAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssValueAtRuleNamedImportSpecifier {
+impl AstNode for ScssVariableModifier {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_VARIABLE_MODIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_NAMED_IMPORT_SPECIFIER
+        kind == SCSS_VARIABLE_MODIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -15321,22 +17896,21 @@ impl AstNode for CssValueAtRuleNamedImportSpecifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssValueAtRuleNamedImportSpecifier {
+impl std::fmt::Debug for ScssVariableModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleNamedImportSpecifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("as_token", &support::DebugSyntaxResult(self.as_token()))
-            .field("local_name", &support::DebugSyntaxResult(self.local_name()))
+        f.debug_struct("ScssVariableModifier")
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssValueAtRuleNamedImportSpecifier> for SyntaxNode {
-    fn from(n: CssValueAtRuleNamedImportSpecifier) -> SyntaxNode {
+impl From<ScssVariableModifier> for SyntaxNode {
+    fn from(n: ScssVariableModifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssValueAtRuleNamedImportSpecifier> for SyntaxElement {
-    fn from(n: CssValueAtRuleNamedImportSpecifier) -> SyntaxElement {
+impl From<ScssVariableModifier> for SyntaxElement {
+    fn from(n: ScssVariableModifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -15450,6 +18024,36 @@ impl From<CssValueAtRule> for AnyCssAtRule {
         AnyCssAtRule::CssValueAtRule(node)
     }
 }
+impl From<ScssContentAtRule> for AnyCssAtRule {
+    fn from(node: ScssContentAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssContentAtRule(node)
+    }
+}
+impl From<ScssExtendAtRule> for AnyCssAtRule {
+    fn from(node: ScssExtendAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssExtendAtRule(node)
+    }
+}
+impl From<ScssForwardAtRule> for AnyCssAtRule {
+    fn from(node: ScssForwardAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssForwardAtRule(node)
+    }
+}
+impl From<ScssIncludeAtRule> for AnyCssAtRule {
+    fn from(node: ScssIncludeAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssIncludeAtRule(node)
+    }
+}
+impl From<ScssMixinAtRule> for AnyCssAtRule {
+    fn from(node: ScssMixinAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssMixinAtRule(node)
+    }
+}
+impl From<ScssUseAtRule> for AnyCssAtRule {
+    fn from(node: ScssUseAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssUseAtRule(node)
+    }
+}
 impl AstNode for AnyCssAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssBogusAtRule::KIND_SET
@@ -15473,7 +18077,13 @@ impl AstNode for AnyCssAtRule {
         .union(CssSupportsAtRule::KIND_SET)
         .union(CssUnknownBlockAtRule::KIND_SET)
         .union(CssUnknownValueAtRule::KIND_SET)
-        .union(CssValueAtRule::KIND_SET);
+        .union(CssValueAtRule::KIND_SET)
+        .union(ScssContentAtRule::KIND_SET)
+        .union(ScssExtendAtRule::KIND_SET)
+        .union(ScssForwardAtRule::KIND_SET)
+        .union(ScssIncludeAtRule::KIND_SET)
+        .union(ScssMixinAtRule::KIND_SET)
+        .union(ScssUseAtRule::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -15499,6 +18109,12 @@ impl AstNode for AnyCssAtRule {
                 | CSS_UNKNOWN_BLOCK_AT_RULE
                 | CSS_UNKNOWN_VALUE_AT_RULE
                 | CSS_VALUE_AT_RULE
+                | SCSS_CONTENT_AT_RULE
+                | SCSS_EXTEND_AT_RULE
+                | SCSS_FORWARD_AT_RULE
+                | SCSS_INCLUDE_AT_RULE
+                | SCSS_MIXIN_AT_RULE
+                | SCSS_USE_AT_RULE
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -15545,6 +18161,12 @@ impl AstNode for AnyCssAtRule {
                 AnyCssAtRule::CssUnknownValueAtRule(CssUnknownValueAtRule { syntax })
             }
             CSS_VALUE_AT_RULE => AnyCssAtRule::CssValueAtRule(CssValueAtRule { syntax }),
+            SCSS_CONTENT_AT_RULE => AnyCssAtRule::ScssContentAtRule(ScssContentAtRule { syntax }),
+            SCSS_EXTEND_AT_RULE => AnyCssAtRule::ScssExtendAtRule(ScssExtendAtRule { syntax }),
+            SCSS_FORWARD_AT_RULE => AnyCssAtRule::ScssForwardAtRule(ScssForwardAtRule { syntax }),
+            SCSS_INCLUDE_AT_RULE => AnyCssAtRule::ScssIncludeAtRule(ScssIncludeAtRule { syntax }),
+            SCSS_MIXIN_AT_RULE => AnyCssAtRule::ScssMixinAtRule(ScssMixinAtRule { syntax }),
+            SCSS_USE_AT_RULE => AnyCssAtRule::ScssUseAtRule(ScssUseAtRule { syntax }),
             _ => return None,
         };
         Some(res)
@@ -15573,6 +18195,12 @@ impl AstNode for AnyCssAtRule {
             AnyCssAtRule::CssUnknownBlockAtRule(it) => &it.syntax,
             AnyCssAtRule::CssUnknownValueAtRule(it) => &it.syntax,
             AnyCssAtRule::CssValueAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssContentAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssExtendAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssForwardAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssIncludeAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssMixinAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssUseAtRule(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -15599,6 +18227,12 @@ impl AstNode for AnyCssAtRule {
             AnyCssAtRule::CssUnknownBlockAtRule(it) => it.syntax,
             AnyCssAtRule::CssUnknownValueAtRule(it) => it.syntax,
             AnyCssAtRule::CssValueAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssContentAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssExtendAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssForwardAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssIncludeAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssMixinAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssUseAtRule(it) => it.syntax,
         }
     }
 }
@@ -15627,6 +18261,12 @@ impl std::fmt::Debug for AnyCssAtRule {
             AnyCssAtRule::CssUnknownBlockAtRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssAtRule::CssUnknownValueAtRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssAtRule::CssValueAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssContentAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssExtendAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssForwardAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssIncludeAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssMixinAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssUseAtRule(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -15655,6 +18295,12 @@ impl From<AnyCssAtRule> for SyntaxNode {
             AnyCssAtRule::CssUnknownBlockAtRule(it) => it.into(),
             AnyCssAtRule::CssUnknownValueAtRule(it) => it.into(),
             AnyCssAtRule::CssValueAtRule(it) => it.into(),
+            AnyCssAtRule::ScssContentAtRule(it) => it.into(),
+            AnyCssAtRule::ScssExtendAtRule(it) => it.into(),
+            AnyCssAtRule::ScssForwardAtRule(it) => it.into(),
+            AnyCssAtRule::ScssIncludeAtRule(it) => it.into(),
+            AnyCssAtRule::ScssMixinAtRule(it) => it.into(),
+            AnyCssAtRule::ScssUseAtRule(it) => it.into(),
         }
     }
 }
@@ -16741,12 +19387,21 @@ impl From<CssIdentifier> for AnyCssDeclarationName {
         AnyCssDeclarationName::CssIdentifier(node)
     }
 }
+impl From<ScssInterpolatedIdentifier> for AnyCssDeclarationName {
+    fn from(node: ScssInterpolatedIdentifier) -> AnyCssDeclarationName {
+        AnyCssDeclarationName::ScssInterpolatedIdentifier(node)
+    }
+}
 impl AstNode for AnyCssDeclarationName {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        CssDashedIdentifier::KIND_SET.union(CssIdentifier::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = CssDashedIdentifier::KIND_SET
+        .union(CssIdentifier::KIND_SET)
+        .union(ScssInterpolatedIdentifier::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, CSS_DASHED_IDENTIFIER | CSS_IDENTIFIER)
+        matches!(
+            kind,
+            CSS_DASHED_IDENTIFIER | CSS_IDENTIFIER | SCSS_INTERPOLATED_IDENTIFIER
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
@@ -16754,6 +19409,11 @@ impl AstNode for AnyCssDeclarationName {
                 AnyCssDeclarationName::CssDashedIdentifier(CssDashedIdentifier { syntax })
             }
             CSS_IDENTIFIER => AnyCssDeclarationName::CssIdentifier(CssIdentifier { syntax }),
+            SCSS_INTERPOLATED_IDENTIFIER => {
+                AnyCssDeclarationName::ScssInterpolatedIdentifier(ScssInterpolatedIdentifier {
+                    syntax,
+                })
+            }
             _ => return None,
         };
         Some(res)
@@ -16762,12 +19422,14 @@ impl AstNode for AnyCssDeclarationName {
         match self {
             AnyCssDeclarationName::CssDashedIdentifier(it) => &it.syntax,
             AnyCssDeclarationName::CssIdentifier(it) => &it.syntax,
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyCssDeclarationName::CssDashedIdentifier(it) => it.syntax,
             AnyCssDeclarationName::CssIdentifier(it) => it.syntax,
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(it) => it.syntax,
         }
     }
 }
@@ -16776,6 +19438,7 @@ impl std::fmt::Debug for AnyCssDeclarationName {
         match self {
             AnyCssDeclarationName::CssDashedIdentifier(it) => std::fmt::Debug::fmt(it, f),
             AnyCssDeclarationName::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -16784,6 +19447,7 @@ impl From<AnyCssDeclarationName> for SyntaxNode {
         match n {
             AnyCssDeclarationName::CssDashedIdentifier(it) => it.into(),
             AnyCssDeclarationName::CssIdentifier(it) => it.into(),
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(it) => it.into(),
         }
     }
 }
@@ -16942,15 +19606,24 @@ impl From<CssMetavariable> for AnyCssDeclarationOrRule {
         AnyCssDeclarationOrRule::CssMetavariable(node)
     }
 }
+impl From<ScssNestingDeclaration> for AnyCssDeclarationOrRule {
+    fn from(node: ScssNestingDeclaration) -> AnyCssDeclarationOrRule {
+        AnyCssDeclarationOrRule::ScssNestingDeclaration(node)
+    }
+}
 impl AstNode for AnyCssDeclarationOrRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyCssRule::KIND_SET
         .union(CssBogus::KIND_SET)
         .union(CssDeclarationWithSemicolon::KIND_SET)
-        .union(CssMetavariable::KIND_SET);
+        .union(CssMetavariable::KIND_SET)
+        .union(ScssNestingDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            CSS_BOGUS | CSS_DECLARATION_WITH_SEMICOLON | CSS_METAVARIABLE => true,
+            CSS_BOGUS
+            | CSS_DECLARATION_WITH_SEMICOLON
+            | CSS_METAVARIABLE
+            | SCSS_NESTING_DECLARATION => true,
             k if AnyCssRule::can_cast(k) => true,
             _ => false,
         }
@@ -16966,6 +19639,9 @@ impl AstNode for AnyCssDeclarationOrRule {
             CSS_METAVARIABLE => {
                 AnyCssDeclarationOrRule::CssMetavariable(CssMetavariable { syntax })
             }
+            SCSS_NESTING_DECLARATION => {
+                AnyCssDeclarationOrRule::ScssNestingDeclaration(ScssNestingDeclaration { syntax })
+            }
             _ => {
                 if let Some(any_css_rule) = AnyCssRule::cast(syntax) {
                     return Some(AnyCssDeclarationOrRule::AnyCssRule(any_css_rule));
@@ -16980,6 +19656,7 @@ impl AstNode for AnyCssDeclarationOrRule {
             AnyCssDeclarationOrRule::CssBogus(it) => &it.syntax,
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(it) => &it.syntax,
             AnyCssDeclarationOrRule::CssMetavariable(it) => &it.syntax,
+            AnyCssDeclarationOrRule::ScssNestingDeclaration(it) => &it.syntax,
             AnyCssDeclarationOrRule::AnyCssRule(it) => it.syntax(),
         }
     }
@@ -16988,6 +19665,7 @@ impl AstNode for AnyCssDeclarationOrRule {
             AnyCssDeclarationOrRule::CssBogus(it) => it.syntax,
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(it) => it.syntax,
             AnyCssDeclarationOrRule::CssMetavariable(it) => it.syntax,
+            AnyCssDeclarationOrRule::ScssNestingDeclaration(it) => it.syntax,
             AnyCssDeclarationOrRule::AnyCssRule(it) => it.into_syntax(),
         }
     }
@@ -16999,6 +19677,7 @@ impl std::fmt::Debug for AnyCssDeclarationOrRule {
             AnyCssDeclarationOrRule::CssBogus(it) => std::fmt::Debug::fmt(it, f),
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(it) => std::fmt::Debug::fmt(it, f),
             AnyCssDeclarationOrRule::CssMetavariable(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssDeclarationOrRule::ScssNestingDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -17009,6 +19688,7 @@ impl From<AnyCssDeclarationOrRule> for SyntaxNode {
             AnyCssDeclarationOrRule::CssBogus(it) => it.into(),
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(it) => it.into(),
             AnyCssDeclarationOrRule::CssMetavariable(it) => it.into(),
+            AnyCssDeclarationOrRule::ScssNestingDeclaration(it) => it.into(),
         }
     }
 }
@@ -20191,16 +22871,26 @@ impl From<CssQualifiedRule> for AnyCssRule {
         AnyCssRule::CssQualifiedRule(node)
     }
 }
+impl From<ScssDeclaration> for AnyCssRule {
+    fn from(node: ScssDeclaration) -> AnyCssRule {
+        AnyCssRule::ScssDeclaration(node)
+    }
+}
 impl AstNode for AnyCssRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssAtRule::KIND_SET
         .union(CssBogusRule::KIND_SET)
         .union(CssNestedQualifiedRule::KIND_SET)
-        .union(CssQualifiedRule::KIND_SET);
+        .union(CssQualifiedRule::KIND_SET)
+        .union(ScssDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            CSS_AT_RULE | CSS_BOGUS_RULE | CSS_NESTED_QUALIFIED_RULE | CSS_QUALIFIED_RULE
+            CSS_AT_RULE
+                | CSS_BOGUS_RULE
+                | CSS_NESTED_QUALIFIED_RULE
+                | CSS_QUALIFIED_RULE
+                | SCSS_DECLARATION
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -20211,6 +22901,7 @@ impl AstNode for AnyCssRule {
                 AnyCssRule::CssNestedQualifiedRule(CssNestedQualifiedRule { syntax })
             }
             CSS_QUALIFIED_RULE => AnyCssRule::CssQualifiedRule(CssQualifiedRule { syntax }),
+            SCSS_DECLARATION => AnyCssRule::ScssDeclaration(ScssDeclaration { syntax }),
             _ => return None,
         };
         Some(res)
@@ -20221,6 +22912,7 @@ impl AstNode for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => &it.syntax,
             AnyCssRule::CssNestedQualifiedRule(it) => &it.syntax,
             AnyCssRule::CssQualifiedRule(it) => &it.syntax,
+            AnyCssRule::ScssDeclaration(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -20229,6 +22921,7 @@ impl AstNode for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => it.syntax,
             AnyCssRule::CssNestedQualifiedRule(it) => it.syntax,
             AnyCssRule::CssQualifiedRule(it) => it.syntax,
+            AnyCssRule::ScssDeclaration(it) => it.syntax,
         }
     }
 }
@@ -20239,6 +22932,7 @@ impl std::fmt::Debug for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssRule::CssNestedQualifiedRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssRule::CssQualifiedRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::ScssDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -20249,6 +22943,7 @@ impl From<AnyCssRule> for SyntaxNode {
             AnyCssRule::CssBogusRule(it) => it.into(),
             AnyCssRule::CssNestedQualifiedRule(it) => it.into(),
             AnyCssRule::CssQualifiedRule(it) => it.into(),
+            AnyCssRule::ScssDeclaration(it) => it.into(),
         }
     }
 }
@@ -20500,6 +23195,72 @@ impl From<AnyCssSelector> for SyntaxElement {
         node.into()
     }
 }
+impl From<CssCustomIdentifier> for AnyCssSelectorName {
+    fn from(node: CssCustomIdentifier) -> AnyCssSelectorName {
+        AnyCssSelectorName::CssCustomIdentifier(node)
+    }
+}
+impl From<ScssInterpolatedIdentifier> for AnyCssSelectorName {
+    fn from(node: ScssInterpolatedIdentifier) -> AnyCssSelectorName {
+        AnyCssSelectorName::ScssInterpolatedIdentifier(node)
+    }
+}
+impl AstNode for AnyCssSelectorName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssCustomIdentifier::KIND_SET.union(ScssInterpolatedIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_CUSTOM_IDENTIFIER | SCSS_INTERPOLATED_IDENTIFIER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_CUSTOM_IDENTIFIER => {
+                AnyCssSelectorName::CssCustomIdentifier(CssCustomIdentifier { syntax })
+            }
+            SCSS_INTERPOLATED_IDENTIFIER => {
+                AnyCssSelectorName::ScssInterpolatedIdentifier(ScssInterpolatedIdentifier {
+                    syntax,
+                })
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssSelectorName::CssCustomIdentifier(it) => &it.syntax,
+            AnyCssSelectorName::ScssInterpolatedIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssSelectorName::CssCustomIdentifier(it) => it.syntax,
+            AnyCssSelectorName::ScssInterpolatedIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssSelectorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssSelectorName::CssCustomIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelectorName::ScssInterpolatedIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssSelectorName> for SyntaxNode {
+    fn from(n: AnyCssSelectorName) -> SyntaxNode {
+        match n {
+            AnyCssSelectorName::CssCustomIdentifier(it) => it.into(),
+            AnyCssSelectorName::ScssInterpolatedIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssSelectorName> for SyntaxElement {
+    fn from(n: AnyCssSelectorName) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl From<CssTypeSelector> for AnyCssSimpleSelector {
     fn from(node: CssTypeSelector) -> AnyCssSimpleSelector {
         AnyCssSimpleSelector::CssTypeSelector(node)
@@ -20510,12 +23271,21 @@ impl From<CssUniversalSelector> for AnyCssSimpleSelector {
         AnyCssSimpleSelector::CssUniversalSelector(node)
     }
 }
+impl From<ScssInterpolatedIdentifier> for AnyCssSimpleSelector {
+    fn from(node: ScssInterpolatedIdentifier) -> AnyCssSimpleSelector {
+        AnyCssSimpleSelector::ScssInterpolatedIdentifier(node)
+    }
+}
 impl AstNode for AnyCssSimpleSelector {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        CssTypeSelector::KIND_SET.union(CssUniversalSelector::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = CssTypeSelector::KIND_SET
+        .union(CssUniversalSelector::KIND_SET)
+        .union(ScssInterpolatedIdentifier::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, CSS_TYPE_SELECTOR | CSS_UNIVERSAL_SELECTOR)
+        matches!(
+            kind,
+            CSS_TYPE_SELECTOR | CSS_UNIVERSAL_SELECTOR | SCSS_INTERPOLATED_IDENTIFIER
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
@@ -20523,6 +23293,11 @@ impl AstNode for AnyCssSimpleSelector {
             CSS_UNIVERSAL_SELECTOR => {
                 AnyCssSimpleSelector::CssUniversalSelector(CssUniversalSelector { syntax })
             }
+            SCSS_INTERPOLATED_IDENTIFIER => {
+                AnyCssSimpleSelector::ScssInterpolatedIdentifier(ScssInterpolatedIdentifier {
+                    syntax,
+                })
+            }
             _ => return None,
         };
         Some(res)
@@ -20531,12 +23306,14 @@ impl AstNode for AnyCssSimpleSelector {
         match self {
             AnyCssSimpleSelector::CssTypeSelector(it) => &it.syntax,
             AnyCssSimpleSelector::CssUniversalSelector(it) => &it.syntax,
+            AnyCssSimpleSelector::ScssInterpolatedIdentifier(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyCssSimpleSelector::CssTypeSelector(it) => it.syntax,
             AnyCssSimpleSelector::CssUniversalSelector(it) => it.syntax,
+            AnyCssSimpleSelector::ScssInterpolatedIdentifier(it) => it.syntax,
         }
     }
 }
@@ -20545,6 +23322,7 @@ impl std::fmt::Debug for AnyCssSimpleSelector {
         match self {
             AnyCssSimpleSelector::CssTypeSelector(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSimpleSelector::CssUniversalSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSimpleSelector::ScssInterpolatedIdentifier(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -20553,6 +23331,7 @@ impl From<AnyCssSimpleSelector> for SyntaxNode {
         match n {
             AnyCssSimpleSelector::CssTypeSelector(it) => it.into(),
             AnyCssSimpleSelector::CssUniversalSelector(it) => it.into(),
+            AnyCssSimpleSelector::ScssInterpolatedIdentifier(it) => it.into(),
         }
     }
 }
@@ -20668,6 +23447,11 @@ impl From<CssPseudoElementSelector> for AnyCssSubSelector {
         AnyCssSubSelector::CssPseudoElementSelector(node)
     }
 }
+impl From<ScssPlaceholderSelector> for AnyCssSubSelector {
+    fn from(node: ScssPlaceholderSelector) -> AnyCssSubSelector {
+        AnyCssSubSelector::ScssPlaceholderSelector(node)
+    }
+}
 impl AstNode for AnyCssSubSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssAttributeSelector::KIND_SET
@@ -20675,7 +23459,8 @@ impl AstNode for AnyCssSubSelector {
         .union(CssClassSelector::KIND_SET)
         .union(CssIdSelector::KIND_SET)
         .union(CssPseudoClassSelector::KIND_SET)
-        .union(CssPseudoElementSelector::KIND_SET);
+        .union(CssPseudoElementSelector::KIND_SET)
+        .union(ScssPlaceholderSelector::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -20685,6 +23470,7 @@ impl AstNode for AnyCssSubSelector {
                 | CSS_ID_SELECTOR
                 | CSS_PSEUDO_CLASS_SELECTOR
                 | CSS_PSEUDO_ELEMENT_SELECTOR
+                | SCSS_PLACEHOLDER_SELECTOR
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -20703,6 +23489,9 @@ impl AstNode for AnyCssSubSelector {
             CSS_PSEUDO_ELEMENT_SELECTOR => {
                 AnyCssSubSelector::CssPseudoElementSelector(CssPseudoElementSelector { syntax })
             }
+            SCSS_PLACEHOLDER_SELECTOR => {
+                AnyCssSubSelector::ScssPlaceholderSelector(ScssPlaceholderSelector { syntax })
+            }
             _ => return None,
         };
         Some(res)
@@ -20715,6 +23504,7 @@ impl AstNode for AnyCssSubSelector {
             AnyCssSubSelector::CssIdSelector(it) => &it.syntax,
             AnyCssSubSelector::CssPseudoClassSelector(it) => &it.syntax,
             AnyCssSubSelector::CssPseudoElementSelector(it) => &it.syntax,
+            AnyCssSubSelector::ScssPlaceholderSelector(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -20725,6 +23515,7 @@ impl AstNode for AnyCssSubSelector {
             AnyCssSubSelector::CssIdSelector(it) => it.syntax,
             AnyCssSubSelector::CssPseudoClassSelector(it) => it.syntax,
             AnyCssSubSelector::CssPseudoElementSelector(it) => it.syntax,
+            AnyCssSubSelector::ScssPlaceholderSelector(it) => it.syntax,
         }
     }
 }
@@ -20737,6 +23528,7 @@ impl std::fmt::Debug for AnyCssSubSelector {
             AnyCssSubSelector::CssIdSelector(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSubSelector::CssPseudoClassSelector(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSubSelector::CssPseudoElementSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSubSelector::ScssPlaceholderSelector(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -20749,6 +23541,7 @@ impl From<AnyCssSubSelector> for SyntaxNode {
             AnyCssSubSelector::CssIdSelector(it) => it.into(),
             AnyCssSubSelector::CssPseudoClassSelector(it) => it.into(),
             AnyCssSubSelector::CssPseudoElementSelector(it) => it.into(),
+            AnyCssSubSelector::ScssPlaceholderSelector(it) => it.into(),
         }
     }
 }
@@ -21392,6 +24185,26 @@ impl From<CssUnicodeRange> for AnyCssValue {
         AnyCssValue::CssUnicodeRange(node)
     }
 }
+impl From<ScssIdentifier> for AnyCssValue {
+    fn from(node: ScssIdentifier) -> AnyCssValue {
+        AnyCssValue::ScssIdentifier(node)
+    }
+}
+impl From<ScssInterpolatedIdentifier> for AnyCssValue {
+    fn from(node: ScssInterpolatedIdentifier) -> AnyCssValue {
+        AnyCssValue::ScssInterpolatedIdentifier(node)
+    }
+}
+impl From<ScssMapExpression> for AnyCssValue {
+    fn from(node: ScssMapExpression) -> AnyCssValue {
+        AnyCssValue::ScssMapExpression(node)
+    }
+}
+impl From<ScssQualifiedName> for AnyCssValue {
+    fn from(node: ScssQualifiedName) -> AnyCssValue {
+        AnyCssValue::ScssQualifiedName(node)
+    }
+}
 impl AstNode for AnyCssValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyCssDimension::KIND_SET
@@ -21405,7 +24218,11 @@ impl AstNode for AnyCssValue {
         .union(CssNumber::KIND_SET)
         .union(CssRatio::KIND_SET)
         .union(CssString::KIND_SET)
-        .union(CssUnicodeRange::KIND_SET);
+        .union(CssUnicodeRange::KIND_SET)
+        .union(ScssIdentifier::KIND_SET)
+        .union(ScssInterpolatedIdentifier::KIND_SET)
+        .union(ScssMapExpression::KIND_SET)
+        .union(ScssQualifiedName::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
             CSS_BRACKETED_VALUE
@@ -21417,7 +24234,11 @@ impl AstNode for AnyCssValue {
             | CSS_NUMBER
             | CSS_RATIO
             | CSS_STRING
-            | CSS_UNICODE_RANGE => true,
+            | CSS_UNICODE_RANGE
+            | SCSS_IDENTIFIER
+            | SCSS_INTERPOLATED_IDENTIFIER
+            | SCSS_MAP_EXPRESSION
+            | SCSS_QUALIFIED_NAME => true,
             k if AnyCssDimension::can_cast(k) => true,
             k if AnyCssFunction::can_cast(k) => true,
             _ => false,
@@ -21439,6 +24260,12 @@ impl AstNode for AnyCssValue {
             CSS_RATIO => AnyCssValue::CssRatio(CssRatio { syntax }),
             CSS_STRING => AnyCssValue::CssString(CssString { syntax }),
             CSS_UNICODE_RANGE => AnyCssValue::CssUnicodeRange(CssUnicodeRange { syntax }),
+            SCSS_IDENTIFIER => AnyCssValue::ScssIdentifier(ScssIdentifier { syntax }),
+            SCSS_INTERPOLATED_IDENTIFIER => {
+                AnyCssValue::ScssInterpolatedIdentifier(ScssInterpolatedIdentifier { syntax })
+            }
+            SCSS_MAP_EXPRESSION => AnyCssValue::ScssMapExpression(ScssMapExpression { syntax }),
+            SCSS_QUALIFIED_NAME => AnyCssValue::ScssQualifiedName(ScssQualifiedName { syntax }),
             _ => {
                 let syntax = match AnyCssDimension::try_cast(syntax) {
                     Ok(any_css_dimension) => {
@@ -21466,6 +24293,10 @@ impl AstNode for AnyCssValue {
             AnyCssValue::CssRatio(it) => &it.syntax,
             AnyCssValue::CssString(it) => &it.syntax,
             AnyCssValue::CssUnicodeRange(it) => &it.syntax,
+            AnyCssValue::ScssIdentifier(it) => &it.syntax,
+            AnyCssValue::ScssInterpolatedIdentifier(it) => &it.syntax,
+            AnyCssValue::ScssMapExpression(it) => &it.syntax,
+            AnyCssValue::ScssQualifiedName(it) => &it.syntax,
             AnyCssValue::AnyCssDimension(it) => it.syntax(),
             AnyCssValue::AnyCssFunction(it) => it.syntax(),
         }
@@ -21482,6 +24313,10 @@ impl AstNode for AnyCssValue {
             AnyCssValue::CssRatio(it) => it.syntax,
             AnyCssValue::CssString(it) => it.syntax,
             AnyCssValue::CssUnicodeRange(it) => it.syntax,
+            AnyCssValue::ScssIdentifier(it) => it.syntax,
+            AnyCssValue::ScssInterpolatedIdentifier(it) => it.syntax,
+            AnyCssValue::ScssMapExpression(it) => it.syntax,
+            AnyCssValue::ScssQualifiedName(it) => it.syntax,
             AnyCssValue::AnyCssDimension(it) => it.into_syntax(),
             AnyCssValue::AnyCssFunction(it) => it.into_syntax(),
         }
@@ -21502,6 +24337,10 @@ impl std::fmt::Debug for AnyCssValue {
             AnyCssValue::CssRatio(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssString(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssUnicodeRange(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::ScssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::ScssInterpolatedIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::ScssMapExpression(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::ScssQualifiedName(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -21520,6 +24359,10 @@ impl From<AnyCssValue> for SyntaxNode {
             AnyCssValue::CssRatio(it) => it.into(),
             AnyCssValue::CssString(it) => it.into(),
             AnyCssValue::CssUnicodeRange(it) => it.into(),
+            AnyCssValue::ScssIdentifier(it) => it.into(),
+            AnyCssValue::ScssInterpolatedIdentifier(it) => it.into(),
+            AnyCssValue::ScssMapExpression(it) => it.into(),
+            AnyCssValue::ScssQualifiedName(it) => it.into(),
         }
     }
 }
@@ -21809,6 +24652,271 @@ impl From<AnyCssValueAtRuleProperty> for SyntaxElement {
         node.into()
     }
 }
+impl From<CssIdentifier> for AnyScssForwardMember {
+    fn from(node: CssIdentifier) -> AnyScssForwardMember {
+        AnyScssForwardMember::CssIdentifier(node)
+    }
+}
+impl From<ScssIdentifier> for AnyScssForwardMember {
+    fn from(node: ScssIdentifier) -> AnyScssForwardMember {
+        AnyScssForwardMember::ScssIdentifier(node)
+    }
+}
+impl AstNode for AnyScssForwardMember {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssIdentifier::KIND_SET.union(ScssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_IDENTIFIER | SCSS_IDENTIFIER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_IDENTIFIER => AnyScssForwardMember::CssIdentifier(CssIdentifier { syntax }),
+            SCSS_IDENTIFIER => AnyScssForwardMember::ScssIdentifier(ScssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyScssForwardMember::CssIdentifier(it) => &it.syntax,
+            AnyScssForwardMember::ScssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyScssForwardMember::CssIdentifier(it) => it.syntax,
+            AnyScssForwardMember::ScssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyScssForwardMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyScssForwardMember::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyScssForwardMember::ScssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyScssForwardMember> for SyntaxNode {
+    fn from(n: AnyScssForwardMember) -> SyntaxNode {
+        match n {
+            AnyScssForwardMember::CssIdentifier(it) => it.into(),
+            AnyScssForwardMember::ScssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<AnyScssForwardMember> for SyntaxElement {
+    fn from(n: AnyScssForwardMember) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssCustomIdentifier> for AnyScssInterpolatedIdentifierItem {
+    fn from(node: CssCustomIdentifier) -> AnyScssInterpolatedIdentifierItem {
+        AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(node)
+    }
+}
+impl From<CssIdentifier> for AnyScssInterpolatedIdentifierItem {
+    fn from(node: CssIdentifier) -> AnyScssInterpolatedIdentifierItem {
+        AnyScssInterpolatedIdentifierItem::CssIdentifier(node)
+    }
+}
+impl From<ScssInterpolation> for AnyScssInterpolatedIdentifierItem {
+    fn from(node: ScssInterpolation) -> AnyScssInterpolatedIdentifierItem {
+        AnyScssInterpolatedIdentifierItem::ScssInterpolation(node)
+    }
+}
+impl AstNode for AnyScssInterpolatedIdentifierItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssCustomIdentifier::KIND_SET
+        .union(CssIdentifier::KIND_SET)
+        .union(ScssInterpolation::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_CUSTOM_IDENTIFIER | CSS_IDENTIFIER | SCSS_INTERPOLATION
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_CUSTOM_IDENTIFIER => {
+                AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(CssCustomIdentifier {
+                    syntax,
+                })
+            }
+            CSS_IDENTIFIER => {
+                AnyScssInterpolatedIdentifierItem::CssIdentifier(CssIdentifier { syntax })
+            }
+            SCSS_INTERPOLATION => {
+                AnyScssInterpolatedIdentifierItem::ScssInterpolation(ScssInterpolation { syntax })
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(it) => &it.syntax,
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(it) => &it.syntax,
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(it) => it.syntax,
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(it) => it.syntax,
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyScssInterpolatedIdentifierItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyScssInterpolatedIdentifierItem> for SyntaxNode {
+    fn from(n: AnyScssInterpolatedIdentifierItem) -> SyntaxNode {
+        match n {
+            AnyScssInterpolatedIdentifierItem::CssCustomIdentifier(it) => it.into(),
+            AnyScssInterpolatedIdentifierItem::CssIdentifier(it) => it.into(),
+            AnyScssInterpolatedIdentifierItem::ScssInterpolation(it) => it.into(),
+        }
+    }
+}
+impl From<AnyScssInterpolatedIdentifierItem> for SyntaxElement {
+    fn from(n: AnyScssInterpolatedIdentifierItem) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssFunction> for AnyScssModuleMember {
+    fn from(node: CssFunction) -> AnyScssModuleMember {
+        AnyScssModuleMember::CssFunction(node)
+    }
+}
+impl From<ScssIdentifier> for AnyScssModuleMember {
+    fn from(node: ScssIdentifier) -> AnyScssModuleMember {
+        AnyScssModuleMember::ScssIdentifier(node)
+    }
+}
+impl AstNode for AnyScssModuleMember {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssFunction::KIND_SET.union(ScssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_FUNCTION | SCSS_IDENTIFIER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_FUNCTION => AnyScssModuleMember::CssFunction(CssFunction { syntax }),
+            SCSS_IDENTIFIER => AnyScssModuleMember::ScssIdentifier(ScssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyScssModuleMember::CssFunction(it) => &it.syntax,
+            AnyScssModuleMember::ScssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyScssModuleMember::CssFunction(it) => it.syntax,
+            AnyScssModuleMember::ScssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyScssModuleMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyScssModuleMember::CssFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyScssModuleMember::ScssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyScssModuleMember> for SyntaxNode {
+    fn from(n: AnyScssModuleMember) -> SyntaxNode {
+        match n {
+            AnyScssModuleMember::CssFunction(it) => it.into(),
+            AnyScssModuleMember::ScssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<AnyScssModuleMember> for SyntaxElement {
+    fn from(n: AnyScssModuleMember) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssIdentifier> for AnyScssUseNamespace {
+    fn from(node: CssIdentifier) -> AnyScssUseNamespace {
+        AnyScssUseNamespace::CssIdentifier(node)
+    }
+}
+impl From<ScssUseAllNamespace> for AnyScssUseNamespace {
+    fn from(node: ScssUseAllNamespace) -> AnyScssUseNamespace {
+        AnyScssUseNamespace::ScssUseAllNamespace(node)
+    }
+}
+impl AstNode for AnyScssUseNamespace {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssIdentifier::KIND_SET.union(ScssUseAllNamespace::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_IDENTIFIER | SCSS_USE_ALL_NAMESPACE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_IDENTIFIER => AnyScssUseNamespace::CssIdentifier(CssIdentifier { syntax }),
+            SCSS_USE_ALL_NAMESPACE => {
+                AnyScssUseNamespace::ScssUseAllNamespace(ScssUseAllNamespace { syntax })
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyScssUseNamespace::CssIdentifier(it) => &it.syntax,
+            AnyScssUseNamespace::ScssUseAllNamespace(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyScssUseNamespace::CssIdentifier(it) => it.syntax,
+            AnyScssUseNamespace::ScssUseAllNamespace(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyScssUseNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyScssUseNamespace::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyScssUseNamespace::ScssUseAllNamespace(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyScssUseNamespace> for SyntaxNode {
+    fn from(n: AnyScssUseNamespace) -> SyntaxNode {
+        match n {
+            AnyScssUseNamespace::CssIdentifier(it) => it.into(),
+            AnyScssUseNamespace::ScssUseAllNamespace(it) => it.into(),
+        }
+    }
+}
+impl From<AnyScssUseNamespace> for SyntaxElement {
+    fn from(n: AnyScssUseNamespace) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl std::fmt::Display for AnyCssAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -22129,6 +25237,11 @@ impl std::fmt::Display for AnyCssSelector {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyCssSelectorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyCssSimpleSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -22204,6 +25317,26 @@ impl std::fmt::Display for AnyCssValueAtRuleProperty {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyScssForwardMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyScssInterpolatedIdentifierItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyScssModuleMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyScssUseNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -22759,231 +25892,702 @@ impl std::fmt::Display for CssPseudoElementSelector {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQualifiedRule {
+impl std::fmt::Display for CssQualifiedRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeatureBoolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeaturePlain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeatureRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeatureRangeComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeatureRangeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssQueryFeatureReverseRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssRegularDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssRelativeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssRuleBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssScopeAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssScopeEdge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssScopeRangeEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssScopeRangeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssScopeRangeStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssStartingStyleAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsAndCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsConditionInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsFeatureDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsFeatureSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsNotCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssSupportsOrCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssTypeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnicodeCodepoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnicodeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnicodeRangeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnicodeRangeWildcard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUniversalNamespacePrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUniversalSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnknownBlockAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnknownDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUnknownValueAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUrlFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUrlValueRaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRuleDeclarationClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRuleGenericProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRuleImportClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRuleImportSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssValueAtRuleNamedImportSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssArgumentName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssArguments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssContentAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeatureBoolean {
+impl std::fmt::Display for ScssExtendAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeaturePlain {
+impl std::fmt::Display for ScssForwardAsClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeatureRange {
+impl std::fmt::Display for ScssForwardAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeatureRangeComparison {
+impl std::fmt::Display for ScssForwardVisibilityClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeatureRangeInterval {
+impl std::fmt::Display for ScssIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssQueryFeatureReverseRange {
+impl std::fmt::Display for ScssIncludeAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssRatio {
+impl std::fmt::Display for ScssInterpolatedIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssRegularDimension {
+impl std::fmt::Display for ScssInterpolation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssRelativeSelector {
+impl std::fmt::Display for ScssMapExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssRoot {
+impl std::fmt::Display for ScssMapExpressionPair {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssRuleBlock {
+impl std::fmt::Display for ScssMixinAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssScopeAtRule {
+impl std::fmt::Display for ScssModuleConfiguration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssScopeEdge {
+impl std::fmt::Display for ScssNestingDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssScopeRangeEnd {
+impl std::fmt::Display for ScssParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssScopeRangeInterval {
+impl std::fmt::Display for ScssParameterDefaultValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssScopeRangeStart {
+impl std::fmt::Display for ScssParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssStartingStyleAtRule {
+impl std::fmt::Display for ScssPlaceholderSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssString {
+impl std::fmt::Display for ScssQualifiedName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssSupportsAndCondition {
+impl std::fmt::Display for ScssUseAllNamespace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssSupportsAtRule {
+impl std::fmt::Display for ScssUseAsClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssSupportsConditionInParens {
+impl std::fmt::Display for ScssUseAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssSupportsFeatureDeclaration {
+impl std::fmt::Display for ScssVariableModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssSupportsFeatureSelector {
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogus {
+    syntax: SyntaxNode,
+}
+impl CssBogus {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for CssBogus {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBogus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogus")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
     }
 }
-impl std::fmt::Display for CssSupportsNotCondition {
+impl From<CssBogus> for SyntaxNode {
+    fn from(n: CssBogus) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBogus> for SyntaxElement {
+    fn from(n: CssBogus) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogusAtRule {
+    syntax: SyntaxNode,
+}
+impl CssBogusAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for CssBogusAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBogusAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogusAtRule")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
     }
 }
-impl std::fmt::Display for CssSupportsOrCondition {
+impl From<CssBogusAtRule> for SyntaxNode {
+    fn from(n: CssBogusAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBogusAtRule> for SyntaxElement {
+    fn from(n: CssBogusAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogusBlock {
+    syntax: SyntaxNode,
+}
+impl CssBogusBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for CssBogusBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBogusBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogusBlock")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
     }
 }
-impl std::fmt::Display for CssTypeSelector {
+impl From<CssBogusBlock> for SyntaxNode {
+    fn from(n: CssBogusBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBogusBlock> for SyntaxElement {
+    fn from(n: CssBogusBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogusCustomIdentifier {
+    syntax: SyntaxNode,
+}
+impl CssBogusCustomIdentifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for CssBogusCustomIdentifier {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_CUSTOM_IDENTIFIER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS_CUSTOM_IDENTIFIER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssBogusCustomIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogusCustomIdentifier")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<CssBogusCustomIdentifier> for SyntaxNode {
+    fn from(n: CssBogusCustomIdentifier) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssBogusCustomIdentifier> for SyntaxElement {
+    fn from(n: CssBogusCustomIdentifier) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogusDeclarationItem {
+    syntax: SyntaxNode,
+}
+impl CssBogusDeclarationItem {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-}
-impl std::fmt::Display for CssUnicodeCodepoint {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
     }
 }
-impl std::fmt::Display for CssUnicodeRange {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl AstNode for CssBogusDeclarationItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_DECLARATION_ITEM as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS_DECLARATION_ITEM
     }
-}
-impl std::fmt::Display for CssUnicodeRangeInterval {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
     }
-}
-impl std::fmt::Display for CssUnicodeRangeWildcard {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-}
-impl std::fmt::Display for CssUniversalNamespacePrefix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-impl std::fmt::Display for CssUniversalSelector {
+impl std::fmt::Debug for CssBogusDeclarationItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogusDeclarationItem")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
     }
 }
-impl std::fmt::Display for CssUnknownBlockAtRule {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl From<CssBogusDeclarationItem> for SyntaxNode {
+    fn from(n: CssBogusDeclarationItem) -> SyntaxNode {
+        n.syntax
     }
 }
-impl std::fmt::Display for CssUnknownDimension {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl From<CssBogusDeclarationItem> for SyntaxElement {
+    fn from(n: CssBogusDeclarationItem) -> SyntaxElement {
+        n.syntax.into()
     }
 }
-impl std::fmt::Display for CssUnknownValueAtRule {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CssBogusDocumentMatcher {
+    syntax: SyntaxNode,
 }
-impl std::fmt::Display for CssUrlFunction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl CssBogusDocumentMatcher {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-}
-impl std::fmt::Display for CssUrlValueRaw {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
     }
 }
-impl std::fmt::Display for CssValueAtRule {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl AstNode for CssBogusDocumentMatcher {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_DOCUMENT_MATCHER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BOGUS_DOCUMENT_MATCHER
     }
-}
-impl std::fmt::Display for CssValueAtRuleDeclarationClause {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
     }
-}
-impl std::fmt::Display for CssValueAtRuleGenericProperty {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-impl std::fmt::Display for CssValueAtRuleImportClause {
+impl std::fmt::Debug for CssBogusDocumentMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        f.debug_struct("CssBogusDocumentMatcher")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
     }
 }
-impl std::fmt::Display for CssValueAtRuleImportSpecifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl From<CssBogusDocumentMatcher> for SyntaxNode {
+    fn from(n: CssBogusDocumentMatcher) -> SyntaxNode {
+        n.syntax
     }
 }
-impl std::fmt::Display for CssValueAtRuleNamedImportSpecifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl From<CssBogusDocumentMatcher> for SyntaxElement {
+    fn from(n: CssBogusDocumentMatcher) -> SyntaxElement {
+        n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogus {
+pub struct CssBogusFontFamilyName {
     syntax: SyntaxNode,
 }
-impl CssBogus {
+impl CssBogusFontFamilyName {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -22997,12 +26601,12 @@ impl CssBogus {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogus {
+impl AstNode for CssBogusFontFamilyName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_FONT_FAMILY_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS
+        kind == CSS_BOGUS_FONT_FAMILY_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23018,28 +26622,28 @@ impl AstNode for CssBogus {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogus {
+impl std::fmt::Debug for CssBogusFontFamilyName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogus")
+        f.debug_struct("CssBogusFontFamilyName")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogus> for SyntaxNode {
-    fn from(n: CssBogus) -> SyntaxNode {
+impl From<CssBogusFontFamilyName> for SyntaxNode {
+    fn from(n: CssBogusFontFamilyName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogus> for SyntaxElement {
-    fn from(n: CssBogus) -> SyntaxElement {
+impl From<CssBogusFontFamilyName> for SyntaxElement {
+    fn from(n: CssBogusFontFamilyName) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusAtRule {
+pub struct CssBogusFontFeatureValuesItem {
     syntax: SyntaxNode,
 }
-impl CssBogusAtRule {
+impl CssBogusFontFeatureValuesItem {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23053,12 +26657,12 @@ impl CssBogusAtRule {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusAtRule {
+impl AstNode for CssBogusFontFeatureValuesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_FONT_FEATURE_VALUES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_AT_RULE
+        kind == CSS_BOGUS_FONT_FEATURE_VALUES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23074,28 +26678,28 @@ impl AstNode for CssBogusAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusAtRule {
+impl std::fmt::Debug for CssBogusFontFeatureValuesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusAtRule")
+        f.debug_struct("CssBogusFontFeatureValuesItem")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusAtRule> for SyntaxNode {
-    fn from(n: CssBogusAtRule) -> SyntaxNode {
+impl From<CssBogusFontFeatureValuesItem> for SyntaxNode {
+    fn from(n: CssBogusFontFeatureValuesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusAtRule> for SyntaxElement {
-    fn from(n: CssBogusAtRule) -> SyntaxElement {
+impl From<CssBogusFontFeatureValuesItem> for SyntaxElement {
+    fn from(n: CssBogusFontFeatureValuesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusBlock {
+pub struct CssBogusKeyframesItem {
     syntax: SyntaxNode,
 }
-impl CssBogusBlock {
+impl CssBogusKeyframesItem {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23109,12 +26713,12 @@ impl CssBogusBlock {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusBlock {
+impl AstNode for CssBogusKeyframesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_KEYFRAMES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_BLOCK
+        kind == CSS_BOGUS_KEYFRAMES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23130,28 +26734,28 @@ impl AstNode for CssBogusBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusBlock {
+impl std::fmt::Debug for CssBogusKeyframesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusBlock")
+        f.debug_struct("CssBogusKeyframesItem")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusBlock> for SyntaxNode {
-    fn from(n: CssBogusBlock) -> SyntaxNode {
+impl From<CssBogusKeyframesItem> for SyntaxNode {
+    fn from(n: CssBogusKeyframesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusBlock> for SyntaxElement {
-    fn from(n: CssBogusBlock) -> SyntaxElement {
+impl From<CssBogusKeyframesItem> for SyntaxElement {
+    fn from(n: CssBogusKeyframesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusCustomIdentifier {
+pub struct CssBogusKeyframesName {
     syntax: SyntaxNode,
 }
-impl CssBogusCustomIdentifier {
+impl CssBogusKeyframesName {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23165,12 +26769,12 @@ impl CssBogusCustomIdentifier {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusCustomIdentifier {
+impl AstNode for CssBogusKeyframesName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_CUSTOM_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_KEYFRAMES_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_CUSTOM_IDENTIFIER
+        kind == CSS_BOGUS_KEYFRAMES_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23186,28 +26790,28 @@ impl AstNode for CssBogusCustomIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusCustomIdentifier {
+impl std::fmt::Debug for CssBogusKeyframesName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusCustomIdentifier")
+        f.debug_struct("CssBogusKeyframesName")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusCustomIdentifier> for SyntaxNode {
-    fn from(n: CssBogusCustomIdentifier) -> SyntaxNode {
+impl From<CssBogusKeyframesName> for SyntaxNode {
+    fn from(n: CssBogusKeyframesName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusCustomIdentifier> for SyntaxElement {
-    fn from(n: CssBogusCustomIdentifier) -> SyntaxElement {
+impl From<CssBogusKeyframesName> for SyntaxElement {
+    fn from(n: CssBogusKeyframesName) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusDeclarationItem {
+pub struct CssBogusLayer {
     syntax: SyntaxNode,
 }
-impl CssBogusDeclarationItem {
+impl CssBogusLayer {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23221,12 +26825,12 @@ impl CssBogusDeclarationItem {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusDeclarationItem {
+impl AstNode for CssBogusLayer {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_DECLARATION_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_LAYER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_DECLARATION_ITEM
+        kind == CSS_BOGUS_LAYER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23242,28 +26846,28 @@ impl AstNode for CssBogusDeclarationItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusDeclarationItem {
+impl std::fmt::Debug for CssBogusLayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusDeclarationItem")
+        f.debug_struct("CssBogusLayer")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusDeclarationItem> for SyntaxNode {
-    fn from(n: CssBogusDeclarationItem) -> SyntaxNode {
+impl From<CssBogusLayer> for SyntaxNode {
+    fn from(n: CssBogusLayer) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusDeclarationItem> for SyntaxElement {
-    fn from(n: CssBogusDeclarationItem) -> SyntaxElement {
+impl From<CssBogusLayer> for SyntaxElement {
+    fn from(n: CssBogusLayer) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusDocumentMatcher {
+pub struct CssBogusMediaQuery {
     syntax: SyntaxNode,
 }
-impl CssBogusDocumentMatcher {
+impl CssBogusMediaQuery {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23277,12 +26881,12 @@ impl CssBogusDocumentMatcher {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusDocumentMatcher {
+impl AstNode for CssBogusMediaQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_DOCUMENT_MATCHER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_MEDIA_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_DOCUMENT_MATCHER
+        kind == CSS_BOGUS_MEDIA_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23298,28 +26902,28 @@ impl AstNode for CssBogusDocumentMatcher {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusDocumentMatcher {
+impl std::fmt::Debug for CssBogusMediaQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusDocumentMatcher")
+        f.debug_struct("CssBogusMediaQuery")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusDocumentMatcher> for SyntaxNode {
-    fn from(n: CssBogusDocumentMatcher) -> SyntaxNode {
+impl From<CssBogusMediaQuery> for SyntaxNode {
+    fn from(n: CssBogusMediaQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusDocumentMatcher> for SyntaxElement {
-    fn from(n: CssBogusDocumentMatcher) -> SyntaxElement {
+impl From<CssBogusMediaQuery> for SyntaxElement {
+    fn from(n: CssBogusMediaQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusFontFamilyName {
+pub struct CssBogusPageSelectorPseudo {
     syntax: SyntaxNode,
 }
-impl CssBogusFontFamilyName {
+impl CssBogusPageSelectorPseudo {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23333,12 +26937,12 @@ impl CssBogusFontFamilyName {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusFontFamilyName {
+impl AstNode for CssBogusPageSelectorPseudo {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_FONT_FAMILY_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PAGE_SELECTOR_PSEUDO as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_FONT_FAMILY_NAME
+        kind == CSS_BOGUS_PAGE_SELECTOR_PSEUDO
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23354,28 +26958,28 @@ impl AstNode for CssBogusFontFamilyName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusFontFamilyName {
+impl std::fmt::Debug for CssBogusPageSelectorPseudo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusFontFamilyName")
+        f.debug_struct("CssBogusPageSelectorPseudo")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusFontFamilyName> for SyntaxNode {
-    fn from(n: CssBogusFontFamilyName) -> SyntaxNode {
+impl From<CssBogusPageSelectorPseudo> for SyntaxNode {
+    fn from(n: CssBogusPageSelectorPseudo) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusFontFamilyName> for SyntaxElement {
-    fn from(n: CssBogusFontFamilyName) -> SyntaxElement {
+impl From<CssBogusPageSelectorPseudo> for SyntaxElement {
+    fn from(n: CssBogusPageSelectorPseudo) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusFontFeatureValuesItem {
+pub struct CssBogusParameter {
     syntax: SyntaxNode,
 }
-impl CssBogusFontFeatureValuesItem {
+impl CssBogusParameter {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23389,12 +26993,12 @@ impl CssBogusFontFeatureValuesItem {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusFontFeatureValuesItem {
+impl AstNode for CssBogusParameter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_FONT_FEATURE_VALUES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PARAMETER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_FONT_FEATURE_VALUES_ITEM
+        kind == CSS_BOGUS_PARAMETER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23410,28 +27014,28 @@ impl AstNode for CssBogusFontFeatureValuesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusFontFeatureValuesItem {
+impl std::fmt::Debug for CssBogusParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusFontFeatureValuesItem")
+        f.debug_struct("CssBogusParameter")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusFontFeatureValuesItem> for SyntaxNode {
-    fn from(n: CssBogusFontFeatureValuesItem) -> SyntaxNode {
+impl From<CssBogusParameter> for SyntaxNode {
+    fn from(n: CssBogusParameter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusFontFeatureValuesItem> for SyntaxElement {
-    fn from(n: CssBogusFontFeatureValuesItem) -> SyntaxElement {
+impl From<CssBogusParameter> for SyntaxElement {
+    fn from(n: CssBogusParameter) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusKeyframesItem {
+pub struct CssBogusProperty {
     syntax: SyntaxNode,
 }
-impl CssBogusKeyframesItem {
+impl CssBogusProperty {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23445,12 +27049,12 @@ impl CssBogusKeyframesItem {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusKeyframesItem {
+impl AstNode for CssBogusProperty {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_KEYFRAMES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PROPERTY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_KEYFRAMES_ITEM
+        kind == CSS_BOGUS_PROPERTY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23466,28 +27070,28 @@ impl AstNode for CssBogusKeyframesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusKeyframesItem {
+impl std::fmt::Debug for CssBogusProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusKeyframesItem")
+        f.debug_struct("CssBogusProperty")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusKeyframesItem> for SyntaxNode {
-    fn from(n: CssBogusKeyframesItem) -> SyntaxNode {
+impl From<CssBogusProperty> for SyntaxNode {
+    fn from(n: CssBogusProperty) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusKeyframesItem> for SyntaxElement {
-    fn from(n: CssBogusKeyframesItem) -> SyntaxElement {
+impl From<CssBogusProperty> for SyntaxElement {
+    fn from(n: CssBogusProperty) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusKeyframesName {
+pub struct CssBogusPropertyValue {
     syntax: SyntaxNode,
 }
-impl CssBogusKeyframesName {
+impl CssBogusPropertyValue {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23501,12 +27105,12 @@ impl CssBogusKeyframesName {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusKeyframesName {
+impl AstNode for CssBogusPropertyValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_KEYFRAMES_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PROPERTY_VALUE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_KEYFRAMES_NAME
+        kind == CSS_BOGUS_PROPERTY_VALUE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23522,28 +27126,28 @@ impl AstNode for CssBogusKeyframesName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusKeyframesName {
+impl std::fmt::Debug for CssBogusPropertyValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusKeyframesName")
+        f.debug_struct("CssBogusPropertyValue")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusKeyframesName> for SyntaxNode {
-    fn from(n: CssBogusKeyframesName) -> SyntaxNode {
+impl From<CssBogusPropertyValue> for SyntaxNode {
+    fn from(n: CssBogusPropertyValue) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusKeyframesName> for SyntaxElement {
-    fn from(n: CssBogusKeyframesName) -> SyntaxElement {
+impl From<CssBogusPropertyValue> for SyntaxElement {
+    fn from(n: CssBogusPropertyValue) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusLayer {
+pub struct CssBogusPseudoClass {
     syntax: SyntaxNode,
 }
-impl CssBogusLayer {
+impl CssBogusPseudoClass {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23557,12 +27161,12 @@ impl CssBogusLayer {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusLayer {
+impl AstNode for CssBogusPseudoClass {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_LAYER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PSEUDO_CLASS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_LAYER
+        kind == CSS_BOGUS_PSEUDO_CLASS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23578,28 +27182,28 @@ impl AstNode for CssBogusLayer {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusLayer {
+impl std::fmt::Debug for CssBogusPseudoClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusLayer")
+        f.debug_struct("CssBogusPseudoClass")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusLayer> for SyntaxNode {
-    fn from(n: CssBogusLayer) -> SyntaxNode {
+impl From<CssBogusPseudoClass> for SyntaxNode {
+    fn from(n: CssBogusPseudoClass) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusLayer> for SyntaxElement {
-    fn from(n: CssBogusLayer) -> SyntaxElement {
+impl From<CssBogusPseudoClass> for SyntaxElement {
+    fn from(n: CssBogusPseudoClass) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusMediaQuery {
+pub struct CssBogusPseudoElement {
     syntax: SyntaxNode,
 }
-impl CssBogusMediaQuery {
+impl CssBogusPseudoElement {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23613,12 +27217,12 @@ impl CssBogusMediaQuery {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusMediaQuery {
+impl AstNode for CssBogusPseudoElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_MEDIA_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PSEUDO_ELEMENT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_MEDIA_QUERY
+        kind == CSS_BOGUS_PSEUDO_ELEMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23634,28 +27238,28 @@ impl AstNode for CssBogusMediaQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusMediaQuery {
+impl std::fmt::Debug for CssBogusPseudoElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusMediaQuery")
+        f.debug_struct("CssBogusPseudoElement")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusMediaQuery> for SyntaxNode {
-    fn from(n: CssBogusMediaQuery) -> SyntaxNode {
+impl From<CssBogusPseudoElement> for SyntaxNode {
+    fn from(n: CssBogusPseudoElement) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusMediaQuery> for SyntaxElement {
-    fn from(n: CssBogusMediaQuery) -> SyntaxElement {
+impl From<CssBogusPseudoElement> for SyntaxElement {
+    fn from(n: CssBogusPseudoElement) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusPageSelectorPseudo {
+pub struct CssBogusRule {
     syntax: SyntaxNode,
 }
-impl CssBogusPageSelectorPseudo {
+impl CssBogusRule {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23669,12 +27273,12 @@ impl CssBogusPageSelectorPseudo {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusPageSelectorPseudo {
+impl AstNode for CssBogusRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PAGE_SELECTOR_PSEUDO as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PAGE_SELECTOR_PSEUDO
+        kind == CSS_BOGUS_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23690,28 +27294,28 @@ impl AstNode for CssBogusPageSelectorPseudo {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusPageSelectorPseudo {
+impl std::fmt::Debug for CssBogusRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusPageSelectorPseudo")
+        f.debug_struct("CssBogusRule")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusPageSelectorPseudo> for SyntaxNode {
-    fn from(n: CssBogusPageSelectorPseudo) -> SyntaxNode {
+impl From<CssBogusRule> for SyntaxNode {
+    fn from(n: CssBogusRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusPageSelectorPseudo> for SyntaxElement {
-    fn from(n: CssBogusPageSelectorPseudo) -> SyntaxElement {
+impl From<CssBogusRule> for SyntaxElement {
+    fn from(n: CssBogusRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusParameter {
+pub struct CssBogusScopeRange {
     syntax: SyntaxNode,
 }
-impl CssBogusParameter {
+impl CssBogusScopeRange {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23725,12 +27329,12 @@ impl CssBogusParameter {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusParameter {
+impl AstNode for CssBogusScopeRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PARAMETER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SCOPE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PARAMETER
+        kind == CSS_BOGUS_SCOPE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23746,28 +27350,28 @@ impl AstNode for CssBogusParameter {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusParameter {
+impl std::fmt::Debug for CssBogusScopeRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusParameter")
+        f.debug_struct("CssBogusScopeRange")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusParameter> for SyntaxNode {
-    fn from(n: CssBogusParameter) -> SyntaxNode {
+impl From<CssBogusScopeRange> for SyntaxNode {
+    fn from(n: CssBogusScopeRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusParameter> for SyntaxElement {
-    fn from(n: CssBogusParameter) -> SyntaxElement {
+impl From<CssBogusScopeRange> for SyntaxElement {
+    fn from(n: CssBogusScopeRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusProperty {
+pub struct CssBogusSelector {
     syntax: SyntaxNode,
 }
-impl CssBogusProperty {
+impl CssBogusSelector {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23781,12 +27385,12 @@ impl CssBogusProperty {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusProperty {
+impl AstNode for CssBogusSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PROPERTY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PROPERTY
+        kind == CSS_BOGUS_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23802,28 +27406,28 @@ impl AstNode for CssBogusProperty {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusProperty {
+impl std::fmt::Debug for CssBogusSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusProperty")
+        f.debug_struct("CssBogusSelector")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusProperty> for SyntaxNode {
-    fn from(n: CssBogusProperty) -> SyntaxNode {
+impl From<CssBogusSelector> for SyntaxNode {
+    fn from(n: CssBogusSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusProperty> for SyntaxElement {
-    fn from(n: CssBogusProperty) -> SyntaxElement {
+impl From<CssBogusSelector> for SyntaxElement {
+    fn from(n: CssBogusSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusPropertyValue {
+pub struct CssBogusSubSelector {
     syntax: SyntaxNode,
 }
-impl CssBogusPropertyValue {
+impl CssBogusSubSelector {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23837,12 +27441,12 @@ impl CssBogusPropertyValue {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusPropertyValue {
+impl AstNode for CssBogusSubSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PROPERTY_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SUB_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PROPERTY_VALUE
+        kind == CSS_BOGUS_SUB_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23858,28 +27462,28 @@ impl AstNode for CssBogusPropertyValue {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusPropertyValue {
+impl std::fmt::Debug for CssBogusSubSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusPropertyValue")
+        f.debug_struct("CssBogusSubSelector")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusPropertyValue> for SyntaxNode {
-    fn from(n: CssBogusPropertyValue) -> SyntaxNode {
+impl From<CssBogusSubSelector> for SyntaxNode {
+    fn from(n: CssBogusSubSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusPropertyValue> for SyntaxElement {
-    fn from(n: CssBogusPropertyValue) -> SyntaxElement {
+impl From<CssBogusSubSelector> for SyntaxElement {
+    fn from(n: CssBogusSubSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusPseudoClass {
+pub struct CssBogusUnicodeRangeValue {
     syntax: SyntaxNode,
 }
-impl CssBogusPseudoClass {
+impl CssBogusUnicodeRangeValue {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23893,12 +27497,12 @@ impl CssBogusPseudoClass {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusPseudoClass {
+impl AstNode for CssBogusUnicodeRangeValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PSEUDO_CLASS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_UNICODE_RANGE_VALUE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PSEUDO_CLASS
+        kind == CSS_BOGUS_UNICODE_RANGE_VALUE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23914,28 +27518,28 @@ impl AstNode for CssBogusPseudoClass {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusPseudoClass {
+impl std::fmt::Debug for CssBogusUnicodeRangeValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusPseudoClass")
+        f.debug_struct("CssBogusUnicodeRangeValue")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusPseudoClass> for SyntaxNode {
-    fn from(n: CssBogusPseudoClass) -> SyntaxNode {
+impl From<CssBogusUnicodeRangeValue> for SyntaxNode {
+    fn from(n: CssBogusUnicodeRangeValue) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusPseudoClass> for SyntaxElement {
-    fn from(n: CssBogusPseudoClass) -> SyntaxElement {
+impl From<CssBogusUnicodeRangeValue> for SyntaxElement {
+    fn from(n: CssBogusUnicodeRangeValue) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusPseudoElement {
+pub struct CssBogusUrlModifier {
     syntax: SyntaxNode,
 }
-impl CssBogusPseudoElement {
+impl CssBogusUrlModifier {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -23949,12 +27553,12 @@ impl CssBogusPseudoElement {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusPseudoElement {
+impl AstNode for CssBogusUrlModifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_PSEUDO_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_URL_MODIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_PSEUDO_ELEMENT
+        kind == CSS_BOGUS_URL_MODIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -23970,28 +27574,28 @@ impl AstNode for CssBogusPseudoElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusPseudoElement {
+impl std::fmt::Debug for CssBogusUrlModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusPseudoElement")
+        f.debug_struct("CssBogusUrlModifier")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusPseudoElement> for SyntaxNode {
-    fn from(n: CssBogusPseudoElement) -> SyntaxNode {
+impl From<CssBogusUrlModifier> for SyntaxNode {
+    fn from(n: CssBogusUrlModifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusPseudoElement> for SyntaxElement {
-    fn from(n: CssBogusPseudoElement) -> SyntaxElement {
+impl From<CssBogusUrlModifier> for SyntaxElement {
+    fn from(n: CssBogusUrlModifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusRule {
+pub struct CssUnknownAtRuleComponentList {
     syntax: SyntaxNode,
 }
-impl CssBogusRule {
+impl CssUnknownAtRuleComponentList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24005,12 +27609,12 @@ impl CssBogusRule {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusRule {
+impl AstNode for CssUnknownAtRuleComponentList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_AT_RULE_COMPONENT_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_RULE
+        kind == CSS_UNKNOWN_AT_RULE_COMPONENT_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -24026,28 +27630,28 @@ impl AstNode for CssBogusRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusRule {
+impl std::fmt::Debug for CssUnknownAtRuleComponentList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusRule")
+        f.debug_struct("CssUnknownAtRuleComponentList")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusRule> for SyntaxNode {
-    fn from(n: CssBogusRule) -> SyntaxNode {
+impl From<CssUnknownAtRuleComponentList> for SyntaxNode {
+    fn from(n: CssUnknownAtRuleComponentList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusRule> for SyntaxElement {
-    fn from(n: CssBogusRule) -> SyntaxElement {
+impl From<CssUnknownAtRuleComponentList> for SyntaxElement {
+    fn from(n: CssUnknownAtRuleComponentList) -> SyntaxElement {
         n.syntax.into()
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusScopeRange {
+pub struct CssValueAtRuleGenericValue {
     syntax: SyntaxNode,
 }
-impl CssBogusScopeRange {
+impl CssValueAtRuleGenericValue {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24061,12 +27665,12 @@ impl CssBogusScopeRange {
         support::elements(&self.syntax)
     }
 }
-impl AstNode for CssBogusScopeRange {
+impl AstNode for CssValueAtRuleGenericValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SCOPE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_GENERIC_VALUE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_SCOPE_RANGE
+        kind == CSS_VALUE_AT_RULE_GENERIC_VALUE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -24082,364 +27686,520 @@ impl AstNode for CssBogusScopeRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBogusScopeRange {
+impl std::fmt::Debug for CssValueAtRuleGenericValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusScopeRange")
+        f.debug_struct("CssValueAtRuleGenericValue")
             .field("items", &DebugSyntaxElementChildren(self.items()))
             .finish()
     }
 }
-impl From<CssBogusScopeRange> for SyntaxNode {
-    fn from(n: CssBogusScopeRange) -> SyntaxNode {
+impl From<CssValueAtRuleGenericValue> for SyntaxNode {
+    fn from(n: CssValueAtRuleGenericValue) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBogusScopeRange> for SyntaxElement {
-    fn from(n: CssBogusScopeRange) -> SyntaxElement {
+impl From<CssValueAtRuleGenericValue> for SyntaxElement {
+    fn from(n: CssValueAtRuleGenericValue) -> SyntaxElement {
         n.syntax.into()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusSelector {
-    syntax: SyntaxNode,
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssBracketedValueList {
+    syntax_list: SyntaxList,
 }
-impl CssBogusSelector {
+impl CssBracketedValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssBogusSelector {
+impl AstNode for CssBracketedValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BRACKETED_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_SELECTOR
+        kind == CSS_BRACKETED_VALUE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssBracketedValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssBracketedValueList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for CssBogusSelector {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusSelector")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Serialize for CssBracketedValueList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<CssBogusSelector> for SyntaxNode {
-    fn from(n: CssBogusSelector) -> SyntaxNode {
-        n.syntax
+impl AstNodeList for CssBracketedValueList {
+    type Language = Language;
+    type Node = AnyCssCustomIdentifier;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<CssBogusSelector> for SyntaxElement {
-    fn from(n: CssBogusSelector) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for CssBracketedValueList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssBracketedValueList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusSubSelector {
-    syntax: SyntaxNode,
+impl IntoIterator for &CssBracketedValueList {
+    type Item = AnyCssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
-impl CssBogusSubSelector {
+impl IntoIterator for CssBracketedValueList {
+    type Item = AnyCssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssComponentValueList {
+    syntax_list: SyntaxList,
+}
+impl CssComponentValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssBogusSubSelector {
+impl AstNode for CssComponentValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SUB_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPONENT_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_SUB_SELECTOR
+        kind == CSS_COMPONENT_VALUE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssComponentValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssComponentValueList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for CssBogusSubSelector {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusSubSelector")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Serialize for CssComponentValueList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<CssBogusSubSelector> for SyntaxNode {
-    fn from(n: CssBogusSubSelector) -> SyntaxNode {
-        n.syntax
+impl AstNodeList for CssComponentValueList {
+    type Language = Language;
+    type Node = AnyCssValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<CssBogusSubSelector> for SyntaxElement {
-    fn from(n: CssBogusSubSelector) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for CssComponentValueList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssComponentValueList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusUnicodeRangeValue {
-    syntax: SyntaxNode,
+impl IntoIterator for &CssComponentValueList {
+    type Item = AnyCssValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
-impl CssBogusUnicodeRangeValue {
+impl IntoIterator for CssComponentValueList {
+    type Item = AnyCssValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssComposesClassList {
+    syntax_list: SyntaxList,
+}
+impl CssComposesClassList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssBogusUnicodeRangeValue {
+impl AstNode for CssComposesClassList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_UNICODE_RANGE_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_CLASS_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_UNICODE_RANGE_VALUE
+        kind == CSS_COMPOSES_CLASS_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssComposesClassList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssComposesClassList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for CssComposesClassList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for CssComposesClassList {
+    type Language = Language;
+    type Node = CssCustomIdentifier;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl std::fmt::Debug for CssBogusUnicodeRangeValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusUnicodeRangeValue")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Debug for CssComposesClassList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssComposesClassList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl From<CssBogusUnicodeRangeValue> for SyntaxNode {
-    fn from(n: CssBogusUnicodeRangeValue) -> SyntaxNode {
-        n.syntax
+impl IntoIterator for &CssComposesClassList {
+    type Item = CssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-impl From<CssBogusUnicodeRangeValue> for SyntaxElement {
-    fn from(n: CssBogusUnicodeRangeValue) -> SyntaxElement {
-        n.syntax.into()
+impl IntoIterator for CssComposesClassList {
+    type Item = CssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssBogusUrlModifier {
-    syntax: SyntaxNode,
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssCompoundSelectorList {
+    syntax_list: SyntaxList,
 }
-impl CssBogusUrlModifier {
+impl CssCompoundSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssBogusUrlModifier {
+impl AstNode for CssCompoundSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_URL_MODIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BOGUS_URL_MODIFIER
+        kind == CSS_COMPOUND_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssCompoundSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssCompoundSelectorList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for CssBogusUrlModifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBogusUrlModifier")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Serialize for CssCompoundSelectorList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<CssBogusUrlModifier> for SyntaxNode {
-    fn from(n: CssBogusUrlModifier) -> SyntaxNode {
-        n.syntax
+impl AstSeparatedList for CssCompoundSelectorList {
+    type Language = Language;
+    type Node = AnyCssCompoundSelector;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<CssBogusUrlModifier> for SyntaxElement {
-    fn from(n: CssBogusUrlModifier) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for CssCompoundSelectorList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssCompoundSelectorList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssUnknownAtRuleComponentList {
-    syntax: SyntaxNode,
+impl IntoIterator for CssCompoundSelectorList {
+    type Item = SyntaxResult<AnyCssCompoundSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssCompoundSelector>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
-impl CssUnknownAtRuleComponentList {
+impl IntoIterator for &CssCompoundSelectorList {
+    type Item = SyntaxResult<AnyCssCompoundSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssCompoundSelector>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssCustomIdentifierList {
+    syntax_list: SyntaxList,
+}
+impl CssCustomIdentifierList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssUnknownAtRuleComponentList {
+impl AstNode for CssCustomIdentifierList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_AT_RULE_COMPONENT_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNKNOWN_AT_RULE_COMPONENT_LIST
+        kind == CSS_CUSTOM_IDENTIFIER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssCustomIdentifierList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssCustomIdentifierList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for CssUnknownAtRuleComponentList {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnknownAtRuleComponentList")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Serialize for CssCustomIdentifierList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<CssUnknownAtRuleComponentList> for SyntaxNode {
-    fn from(n: CssUnknownAtRuleComponentList) -> SyntaxNode {
-        n.syntax
+impl AstNodeList for CssCustomIdentifierList {
+    type Language = Language;
+    type Node = AnyCssCustomIdentifier;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<CssUnknownAtRuleComponentList> for SyntaxElement {
-    fn from(n: CssUnknownAtRuleComponentList) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for CssCustomIdentifierList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssCustomIdentifierList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub struct CssValueAtRuleGenericValue {
-    syntax: SyntaxNode,
+impl IntoIterator for &CssCustomIdentifierList {
+    type Item = AnyCssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
-impl CssValueAtRuleGenericValue {
+impl IntoIterator for CssCustomIdentifierList {
+    type Item = AnyCssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssDeclarationList {
+    syntax_list: SyntaxList,
+}
+impl CssDeclarationList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for CssValueAtRuleGenericValue {
+impl AstNode for CssDeclarationList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_GENERIC_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_GENERIC_VALUE
+        kind == CSS_DECLARATION_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(CssDeclarationList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for CssDeclarationList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for CssDeclarationList {
+    type Language = Language;
+    type Node = CssDeclarationWithSemicolon;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl std::fmt::Debug for CssValueAtRuleGenericValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssValueAtRuleGenericValue")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+impl Debug for CssDeclarationList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssDeclarationList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl From<CssValueAtRuleGenericValue> for SyntaxNode {
-    fn from(n: CssValueAtRuleGenericValue) -> SyntaxNode {
-        n.syntax
+impl IntoIterator for &CssDeclarationList {
+    type Item = CssDeclarationWithSemicolon;
+    type IntoIter = AstNodeListIterator<Language, CssDeclarationWithSemicolon>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-impl From<CssValueAtRuleGenericValue> for SyntaxElement {
-    fn from(n: CssValueAtRuleGenericValue) -> SyntaxElement {
-        n.syntax.into()
+impl IntoIterator for CssDeclarationList {
+    type Item = CssDeclarationWithSemicolon;
+    type IntoIter = AstNodeListIterator<Language, CssDeclarationWithSemicolon>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssBracketedValueList {
+pub struct CssDeclarationOrAtRuleList {
     syntax_list: SyntaxList,
 }
-impl CssBracketedValueList {
+impl CssDeclarationOrAtRuleList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24452,16 +28212,16 @@ impl CssBracketedValueList {
         }
     }
 }
-impl AstNode for CssBracketedValueList {
+impl AstNode for CssDeclarationOrAtRuleList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BRACKETED_VALUE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BRACKETED_VALUE_LIST
+        kind == CSS_DECLARATION_OR_AT_RULE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssBracketedValueList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationOrAtRuleList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssBracketedValueList {
+            Some(CssDeclarationOrAtRuleList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24475,7 +28235,7 @@ impl AstNode for CssBracketedValueList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssBracketedValueList {
+impl Serialize for CssDeclarationOrAtRuleList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24487,9 +28247,9 @@ impl Serialize for CssBracketedValueList {
         seq.end()
     }
 }
-impl AstNodeList for CssBracketedValueList {
+impl AstNodeList for CssDeclarationOrAtRuleList {
     type Language = Language;
-    type Node = AnyCssCustomIdentifier;
+    type Node = AnyCssDeclarationOrAtRule;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24497,31 +28257,31 @@ impl AstNodeList for CssBracketedValueList {
         self.syntax_list
     }
 }
-impl Debug for CssBracketedValueList {
+impl Debug for CssDeclarationOrAtRuleList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssBracketedValueList ")?;
+        f.write_str("CssDeclarationOrAtRuleList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssBracketedValueList {
-    type Item = AnyCssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+impl IntoIterator for &CssDeclarationOrAtRuleList {
+    type Item = AnyCssDeclarationOrAtRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrAtRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssBracketedValueList {
-    type Item = AnyCssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+impl IntoIterator for CssDeclarationOrAtRuleList {
+    type Item = AnyCssDeclarationOrAtRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrAtRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssComponentValueList {
+pub struct CssDeclarationOrRuleList {
     syntax_list: SyntaxList,
 }
-impl CssComponentValueList {
+impl CssDeclarationOrRuleList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24534,16 +28294,16 @@ impl CssComponentValueList {
         }
     }
 }
-impl AstNode for CssComponentValueList {
+impl AstNode for CssDeclarationOrRuleList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPONENT_VALUE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPONENT_VALUE_LIST
+        kind == CSS_DECLARATION_OR_RULE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssComponentValueList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationOrRuleList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssComponentValueList {
+            Some(CssDeclarationOrRuleList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24557,7 +28317,7 @@ impl AstNode for CssComponentValueList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssComponentValueList {
+impl Serialize for CssDeclarationOrRuleList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24569,9 +28329,9 @@ impl Serialize for CssComponentValueList {
         seq.end()
     }
 }
-impl AstNodeList for CssComponentValueList {
+impl AstNodeList for CssDeclarationOrRuleList {
     type Language = Language;
-    type Node = AnyCssValue;
+    type Node = AnyCssDeclarationOrRule;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24579,31 +28339,31 @@ impl AstNodeList for CssComponentValueList {
         self.syntax_list
     }
 }
-impl Debug for CssComponentValueList {
+impl Debug for CssDeclarationOrRuleList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssComponentValueList ")?;
+        f.write_str("CssDeclarationOrRuleList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssComponentValueList {
-    type Item = AnyCssValue;
-    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
+impl IntoIterator for &CssDeclarationOrRuleList {
+    type Item = AnyCssDeclarationOrRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssComponentValueList {
-    type Item = AnyCssValue;
-    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
+impl IntoIterator for CssDeclarationOrRuleList {
+    type Item = AnyCssDeclarationOrRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssComposesClassList {
+pub struct CssDocumentMatcherList {
     syntax_list: SyntaxList,
 }
-impl CssComposesClassList {
+impl CssDocumentMatcherList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24616,16 +28376,16 @@ impl CssComposesClassList {
         }
     }
 }
-impl AstNode for CssComposesClassList {
+impl AstNode for CssDocumentMatcherList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOSES_CLASS_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_MATCHER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOSES_CLASS_LIST
+        kind == CSS_DOCUMENT_MATCHER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssComposesClassList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssDocumentMatcherList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssComposesClassList {
+            Some(CssDocumentMatcherList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24639,7 +28399,7 @@ impl AstNode for CssComposesClassList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssComposesClassList {
+impl Serialize for CssDocumentMatcherList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24651,9 +28411,9 @@ impl Serialize for CssComposesClassList {
         seq.end()
     }
 }
-impl AstNodeList for CssComposesClassList {
+impl AstSeparatedList for CssDocumentMatcherList {
     type Language = Language;
-    type Node = CssCustomIdentifier;
+    type Node = AnyCssDocumentMatcher;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24661,31 +28421,31 @@ impl AstNodeList for CssComposesClassList {
         self.syntax_list
     }
 }
-impl Debug for CssComposesClassList {
+impl Debug for CssDocumentMatcherList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssComposesClassList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssDocumentMatcherList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssComposesClassList {
-    type Item = CssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+impl IntoIterator for CssDocumentMatcherList {
+    type Item = SyntaxResult<AnyCssDocumentMatcher>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssDocumentMatcher>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssComposesClassList {
-    type Item = CssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+impl IntoIterator for &CssDocumentMatcherList {
+    type Item = SyntaxResult<AnyCssDocumentMatcher>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssDocumentMatcher>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssCompoundSelectorList {
+pub struct CssFontFamilyNameList {
     syntax_list: SyntaxList,
 }
-impl CssCompoundSelectorList {
+impl CssFontFamilyNameList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24698,16 +28458,16 @@ impl CssCompoundSelectorList {
         }
     }
 }
-impl AstNode for CssCompoundSelectorList {
+impl AstNode for CssFontFamilyNameList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOUND_SELECTOR_LIST
+        kind == CSS_FONT_FAMILY_NAME_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssCompoundSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssFontFamilyNameList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssCompoundSelectorList {
+            Some(CssFontFamilyNameList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24721,7 +28481,7 @@ impl AstNode for CssCompoundSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssCompoundSelectorList {
+impl Serialize for CssFontFamilyNameList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24733,9 +28493,9 @@ impl Serialize for CssCompoundSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssCompoundSelectorList {
+impl AstSeparatedList for CssFontFamilyNameList {
     type Language = Language;
-    type Node = AnyCssCompoundSelector;
+    type Node = AnyCssFontFamilyName;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24743,31 +28503,31 @@ impl AstSeparatedList for CssCompoundSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssCompoundSelectorList {
+impl Debug for CssFontFamilyNameList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssCompoundSelectorList ")?;
+        f.write_str("CssFontFamilyNameList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssCompoundSelectorList {
-    type Item = SyntaxResult<AnyCssCompoundSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssCompoundSelector>;
+impl IntoIterator for CssFontFamilyNameList {
+    type Item = SyntaxResult<AnyCssFontFamilyName>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssFontFamilyName>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssCompoundSelectorList {
-    type Item = SyntaxResult<AnyCssCompoundSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssCompoundSelector>;
+impl IntoIterator for &CssFontFamilyNameList {
+    type Item = SyntaxResult<AnyCssFontFamilyName>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssFontFamilyName>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssCustomIdentifierList {
+pub struct CssFontFeatureValuesItemList {
     syntax_list: SyntaxList,
 }
-impl CssCustomIdentifierList {
+impl CssFontFeatureValuesItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24780,16 +28540,16 @@ impl CssCustomIdentifierList {
         }
     }
 }
-impl AstNode for CssCustomIdentifierList {
+impl AstNode for CssFontFeatureValuesItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CUSTOM_IDENTIFIER_LIST
+        kind == CSS_FONT_FEATURE_VALUES_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssCustomIdentifierList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssFontFeatureValuesItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssCustomIdentifierList {
+            Some(CssFontFeatureValuesItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24803,7 +28563,7 @@ impl AstNode for CssCustomIdentifierList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssCustomIdentifierList {
+impl Serialize for CssFontFeatureValuesItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24815,9 +28575,9 @@ impl Serialize for CssCustomIdentifierList {
         seq.end()
     }
 }
-impl AstNodeList for CssCustomIdentifierList {
+impl AstNodeList for CssFontFeatureValuesItemList {
     type Language = Language;
-    type Node = AnyCssCustomIdentifier;
+    type Node = AnyCssFontFeatureValuesItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24825,31 +28585,31 @@ impl AstNodeList for CssCustomIdentifierList {
         self.syntax_list
     }
 }
-impl Debug for CssCustomIdentifierList {
+impl Debug for CssFontFeatureValuesItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssCustomIdentifierList ")?;
+        f.write_str("CssFontFeatureValuesItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssCustomIdentifierList {
-    type Item = AnyCssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+impl IntoIterator for &CssFontFeatureValuesItemList {
+    type Item = AnyCssFontFeatureValuesItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssFontFeatureValuesItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssCustomIdentifierList {
-    type Item = AnyCssCustomIdentifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssCustomIdentifier>;
+impl IntoIterator for CssFontFeatureValuesItemList {
+    type Item = AnyCssFontFeatureValuesItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssFontFeatureValuesItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDeclarationList {
+pub struct CssGenericComponentValueList {
     syntax_list: SyntaxList,
 }
-impl CssDeclarationList {
+impl CssGenericComponentValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24862,16 +28622,16 @@ impl CssDeclarationList {
         }
     }
 }
-impl AstNode for CssDeclarationList {
+impl AstNode for CssGenericComponentValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_COMPONENT_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_LIST
+        kind == CSS_GENERIC_COMPONENT_VALUE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssGenericComponentValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDeclarationList {
+            Some(CssGenericComponentValueList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24885,7 +28645,7 @@ impl AstNode for CssDeclarationList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssDeclarationList {
+impl Serialize for CssGenericComponentValueList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24897,9 +28657,9 @@ impl Serialize for CssDeclarationList {
         seq.end()
     }
 }
-impl AstNodeList for CssDeclarationList {
+impl AstNodeList for CssGenericComponentValueList {
     type Language = Language;
-    type Node = CssDeclarationWithSemicolon;
+    type Node = AnyCssGenericComponentValue;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24907,31 +28667,31 @@ impl AstNodeList for CssDeclarationList {
         self.syntax_list
     }
 }
-impl Debug for CssDeclarationList {
+impl Debug for CssGenericComponentValueList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDeclarationList ")?;
+        f.write_str("CssGenericComponentValueList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssDeclarationList {
-    type Item = CssDeclarationWithSemicolon;
-    type IntoIter = AstNodeListIterator<Language, CssDeclarationWithSemicolon>;
+impl IntoIterator for &CssGenericComponentValueList {
+    type Item = AnyCssGenericComponentValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssGenericComponentValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssDeclarationList {
-    type Item = CssDeclarationWithSemicolon;
-    type IntoIter = AstNodeListIterator<Language, CssDeclarationWithSemicolon>;
+impl IntoIterator for CssGenericComponentValueList {
+    type Item = AnyCssGenericComponentValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssGenericComponentValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDeclarationOrAtRuleList {
+pub struct CssKeyframesItemList {
     syntax_list: SyntaxList,
 }
-impl CssDeclarationOrAtRuleList {
+impl CssKeyframesItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -24944,16 +28704,16 @@ impl CssDeclarationOrAtRuleList {
         }
     }
 }
-impl AstNode for CssDeclarationOrAtRuleList {
+impl AstNode for CssKeyframesItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_AT_RULE_LIST
+        kind == CSS_KEYFRAMES_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationOrAtRuleList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDeclarationOrAtRuleList {
+            Some(CssKeyframesItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -24967,7 +28727,7 @@ impl AstNode for CssDeclarationOrAtRuleList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssDeclarationOrAtRuleList {
+impl Serialize for CssKeyframesItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -24979,9 +28739,9 @@ impl Serialize for CssDeclarationOrAtRuleList {
         seq.end()
     }
 }
-impl AstNodeList for CssDeclarationOrAtRuleList {
+impl AstNodeList for CssKeyframesItemList {
     type Language = Language;
-    type Node = AnyCssDeclarationOrAtRule;
+    type Node = AnyCssKeyframesItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -24989,31 +28749,31 @@ impl AstNodeList for CssDeclarationOrAtRuleList {
         self.syntax_list
     }
 }
-impl Debug for CssDeclarationOrAtRuleList {
+impl Debug for CssKeyframesItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDeclarationOrAtRuleList ")?;
+        f.write_str("CssKeyframesItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssDeclarationOrAtRuleList {
-    type Item = AnyCssDeclarationOrAtRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrAtRule>;
+impl IntoIterator for &CssKeyframesItemList {
+    type Item = AnyCssKeyframesItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssKeyframesItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssDeclarationOrAtRuleList {
-    type Item = AnyCssDeclarationOrAtRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrAtRule>;
+impl IntoIterator for CssKeyframesItemList {
+    type Item = AnyCssKeyframesItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssKeyframesItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDeclarationOrRuleList {
+pub struct CssKeyframesSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssDeclarationOrRuleList {
+impl CssKeyframesSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25026,16 +28786,16 @@ impl CssDeclarationOrRuleList {
         }
     }
 }
-impl AstNode for CssDeclarationOrRuleList {
+impl AstNode for CssKeyframesSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_RULE_LIST
+        kind == CSS_KEYFRAMES_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationOrRuleList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDeclarationOrRuleList {
+            Some(CssKeyframesSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25049,7 +28809,7 @@ impl AstNode for CssDeclarationOrRuleList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssDeclarationOrRuleList {
+impl Serialize for CssKeyframesSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25061,9 +28821,9 @@ impl Serialize for CssDeclarationOrRuleList {
         seq.end()
     }
 }
-impl AstNodeList for CssDeclarationOrRuleList {
+impl AstSeparatedList for CssKeyframesSelectorList {
     type Language = Language;
-    type Node = AnyCssDeclarationOrRule;
+    type Node = AnyCssKeyframesSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25071,31 +28831,31 @@ impl AstNodeList for CssDeclarationOrRuleList {
         self.syntax_list
     }
 }
-impl Debug for CssDeclarationOrRuleList {
+impl Debug for CssKeyframesSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDeclarationOrRuleList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssKeyframesSelectorList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssDeclarationOrRuleList {
-    type Item = AnyCssDeclarationOrRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrRule>;
+impl IntoIterator for CssKeyframesSelectorList {
+    type Item = SyntaxResult<AnyCssKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssDeclarationOrRuleList {
-    type Item = AnyCssDeclarationOrRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssDeclarationOrRule>;
+impl IntoIterator for &CssKeyframesSelectorList {
+    type Item = SyntaxResult<AnyCssKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDocumentMatcherList {
+pub struct CssLayerNameList {
     syntax_list: SyntaxList,
 }
-impl CssDocumentMatcherList {
+impl CssLayerNameList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25108,16 +28868,16 @@ impl CssDocumentMatcherList {
         }
     }
 }
-impl AstNode for CssDocumentMatcherList {
+impl AstNode for CssLayerNameList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_MATCHER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_NAME_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DOCUMENT_MATCHER_LIST
+        kind == CSS_LAYER_NAME_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssDocumentMatcherList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssLayerNameList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDocumentMatcherList {
+            Some(CssLayerNameList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25131,7 +28891,7 @@ impl AstNode for CssDocumentMatcherList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssDocumentMatcherList {
+impl Serialize for CssLayerNameList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25143,9 +28903,9 @@ impl Serialize for CssDocumentMatcherList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssDocumentMatcherList {
+impl AstSeparatedList for CssLayerNameList {
     type Language = Language;
-    type Node = AnyCssDocumentMatcher;
+    type Node = CssIdentifier;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25153,31 +28913,31 @@ impl AstSeparatedList for CssDocumentMatcherList {
         self.syntax_list
     }
 }
-impl Debug for CssDocumentMatcherList {
+impl Debug for CssLayerNameList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDocumentMatcherList ")?;
+        f.write_str("CssLayerNameList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssDocumentMatcherList {
-    type Item = SyntaxResult<AnyCssDocumentMatcher>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssDocumentMatcher>;
+impl IntoIterator for CssLayerNameList {
+    type Item = SyntaxResult<CssIdentifier>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssIdentifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssDocumentMatcherList {
-    type Item = SyntaxResult<AnyCssDocumentMatcher>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssDocumentMatcher>;
+impl IntoIterator for &CssLayerNameList {
+    type Item = SyntaxResult<CssIdentifier>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssIdentifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssFontFamilyNameList {
+pub struct CssLayerReferenceList {
     syntax_list: SyntaxList,
 }
-impl CssFontFamilyNameList {
+impl CssLayerReferenceList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25190,16 +28950,16 @@ impl CssFontFamilyNameList {
         }
     }
 }
-impl AstNode for CssFontFamilyNameList {
+impl AstNode for CssLayerReferenceList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FAMILY_NAME_LIST
+        kind == CSS_LAYER_REFERENCE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssFontFamilyNameList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssLayerReferenceList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssFontFamilyNameList {
+            Some(CssLayerReferenceList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25213,7 +28973,7 @@ impl AstNode for CssFontFamilyNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssFontFamilyNameList {
+impl Serialize for CssLayerReferenceList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25225,9 +28985,9 @@ impl Serialize for CssFontFamilyNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssFontFamilyNameList {
+impl AstSeparatedList for CssLayerReferenceList {
     type Language = Language;
-    type Node = AnyCssFontFamilyName;
+    type Node = CssLayerNameList;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25235,31 +28995,31 @@ impl AstSeparatedList for CssFontFamilyNameList {
         self.syntax_list
     }
 }
-impl Debug for CssFontFamilyNameList {
+impl Debug for CssLayerReferenceList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssFontFamilyNameList ")?;
+        f.write_str("CssLayerReferenceList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssFontFamilyNameList {
-    type Item = SyntaxResult<AnyCssFontFamilyName>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssFontFamilyName>;
+impl IntoIterator for CssLayerReferenceList {
+    type Item = SyntaxResult<CssLayerNameList>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssLayerNameList>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssFontFamilyNameList {
-    type Item = SyntaxResult<AnyCssFontFamilyName>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssFontFamilyName>;
+impl IntoIterator for &CssLayerReferenceList {
+    type Item = SyntaxResult<CssLayerNameList>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssLayerNameList>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssFontFeatureValuesItemList {
+pub struct CssMediaQueryList {
     syntax_list: SyntaxList,
 }
-impl CssFontFeatureValuesItemList {
+impl CssMediaQueryList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25272,16 +29032,16 @@ impl CssFontFeatureValuesItemList {
         }
     }
 }
-impl AstNode for CssFontFeatureValuesItemList {
+impl AstNode for CssMediaQueryList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_QUERY_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_ITEM_LIST
+        kind == CSS_MEDIA_QUERY_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssFontFeatureValuesItemList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssMediaQueryList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssFontFeatureValuesItemList {
+            Some(CssMediaQueryList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25295,7 +29055,7 @@ impl AstNode for CssFontFeatureValuesItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssFontFeatureValuesItemList {
+impl Serialize for CssMediaQueryList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25307,9 +29067,9 @@ impl Serialize for CssFontFeatureValuesItemList {
         seq.end()
     }
 }
-impl AstNodeList for CssFontFeatureValuesItemList {
+impl AstSeparatedList for CssMediaQueryList {
     type Language = Language;
-    type Node = AnyCssFontFeatureValuesItem;
+    type Node = AnyCssMediaQuery;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25317,31 +29077,31 @@ impl AstNodeList for CssFontFeatureValuesItemList {
         self.syntax_list
     }
 }
-impl Debug for CssFontFeatureValuesItemList {
+impl Debug for CssMediaQueryList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssFontFeatureValuesItemList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssMediaQueryList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssFontFeatureValuesItemList {
-    type Item = AnyCssFontFeatureValuesItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssFontFeatureValuesItem>;
+impl IntoIterator for CssMediaQueryList {
+    type Item = SyntaxResult<AnyCssMediaQuery>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssMediaQuery>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssFontFeatureValuesItemList {
-    type Item = AnyCssFontFeatureValuesItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssFontFeatureValuesItem>;
+impl IntoIterator for &CssMediaQueryList {
+    type Item = SyntaxResult<AnyCssMediaQuery>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssMediaQuery>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssGenericComponentValueList {
+pub struct CssNestedSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssGenericComponentValueList {
+impl CssNestedSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25354,16 +29114,16 @@ impl CssGenericComponentValueList {
         }
     }
 }
-impl AstNode for CssGenericComponentValueList {
+impl AstNode for CssNestedSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_COMPONENT_VALUE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_GENERIC_COMPONENT_VALUE_LIST
+        kind == CSS_NESTED_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssGenericComponentValueList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssNestedSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssGenericComponentValueList {
+            Some(CssNestedSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25377,7 +29137,7 @@ impl AstNode for CssGenericComponentValueList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssGenericComponentValueList {
+impl Serialize for CssNestedSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25389,9 +29149,9 @@ impl Serialize for CssGenericComponentValueList {
         seq.end()
     }
 }
-impl AstNodeList for CssGenericComponentValueList {
+impl AstNodeList for CssNestedSelectorList {
     type Language = Language;
-    type Node = AnyCssGenericComponentValue;
+    type Node = CssNestedSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25399,31 +29159,31 @@ impl AstNodeList for CssGenericComponentValueList {
         self.syntax_list
     }
 }
-impl Debug for CssGenericComponentValueList {
+impl Debug for CssNestedSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssGenericComponentValueList ")?;
+        f.write_str("CssNestedSelectorList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssGenericComponentValueList {
-    type Item = AnyCssGenericComponentValue;
-    type IntoIter = AstNodeListIterator<Language, AnyCssGenericComponentValue>;
+impl IntoIterator for &CssNestedSelectorList {
+    type Item = CssNestedSelector;
+    type IntoIter = AstNodeListIterator<Language, CssNestedSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssGenericComponentValueList {
-    type Item = AnyCssGenericComponentValue;
-    type IntoIter = AstNodeListIterator<Language, AnyCssGenericComponentValue>;
+impl IntoIterator for CssNestedSelectorList {
+    type Item = CssNestedSelector;
+    type IntoIter = AstNodeListIterator<Language, CssNestedSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssKeyframesItemList {
+pub struct CssPageAtRuleItemList {
     syntax_list: SyntaxList,
 }
-impl CssKeyframesItemList {
+impl CssPageAtRuleItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25436,16 +29196,16 @@ impl CssKeyframesItemList {
         }
     }
 }
-impl AstNode for CssKeyframesItemList {
+impl AstNode for CssPageAtRuleItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_ITEM_LIST
+        kind == CSS_PAGE_AT_RULE_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesItemList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssPageAtRuleItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssKeyframesItemList {
+            Some(CssPageAtRuleItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25459,7 +29219,7 @@ impl AstNode for CssKeyframesItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssKeyframesItemList {
+impl Serialize for CssPageAtRuleItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25471,9 +29231,9 @@ impl Serialize for CssKeyframesItemList {
         seq.end()
     }
 }
-impl AstNodeList for CssKeyframesItemList {
+impl AstNodeList for CssPageAtRuleItemList {
     type Language = Language;
-    type Node = AnyCssKeyframesItem;
+    type Node = AnyCssPageAtRuleItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25481,31 +29241,31 @@ impl AstNodeList for CssKeyframesItemList {
         self.syntax_list
     }
 }
-impl Debug for CssKeyframesItemList {
+impl Debug for CssPageAtRuleItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssKeyframesItemList ")?;
+        f.write_str("CssPageAtRuleItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssKeyframesItemList {
-    type Item = AnyCssKeyframesItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssKeyframesItem>;
+impl IntoIterator for &CssPageAtRuleItemList {
+    type Item = AnyCssPageAtRuleItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssPageAtRuleItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssKeyframesItemList {
-    type Item = AnyCssKeyframesItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssKeyframesItem>;
+impl IntoIterator for CssPageAtRuleItemList {
+    type Item = AnyCssPageAtRuleItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssPageAtRuleItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssKeyframesSelectorList {
+pub struct CssPageSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssKeyframesSelectorList {
+impl CssPageSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25518,16 +29278,16 @@ impl CssKeyframesSelectorList {
         }
     }
 }
-impl AstNode for CssKeyframesSelectorList {
+impl AstNode for CssPageSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_SELECTOR_LIST
+        kind == CSS_PAGE_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssKeyframesSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssPageSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssKeyframesSelectorList {
+            Some(CssPageSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25541,7 +29301,7 @@ impl AstNode for CssKeyframesSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssKeyframesSelectorList {
+impl Serialize for CssPageSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25553,9 +29313,9 @@ impl Serialize for CssKeyframesSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssKeyframesSelectorList {
+impl AstSeparatedList for CssPageSelectorList {
     type Language = Language;
-    type Node = AnyCssKeyframesSelector;
+    type Node = AnyCssPageSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25563,31 +29323,31 @@ impl AstSeparatedList for CssKeyframesSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssKeyframesSelectorList {
+impl Debug for CssPageSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssKeyframesSelectorList ")?;
+        f.write_str("CssPageSelectorList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssKeyframesSelectorList {
-    type Item = SyntaxResult<AnyCssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
+impl IntoIterator for CssPageSelectorList {
+    type Item = SyntaxResult<AnyCssPageSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPageSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssKeyframesSelectorList {
-    type Item = SyntaxResult<AnyCssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
+impl IntoIterator for &CssPageSelectorList {
+    type Item = SyntaxResult<AnyCssPageSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPageSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssLayerNameList {
+pub struct CssPageSelectorPseudoList {
     syntax_list: SyntaxList,
 }
-impl CssLayerNameList {
+impl CssPageSelectorPseudoList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25600,16 +29360,16 @@ impl CssLayerNameList {
         }
     }
 }
-impl AstNode for CssLayerNameList {
+impl AstNode for CssPageSelectorPseudoList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_NAME_LIST
+        kind == CSS_PAGE_SELECTOR_PSEUDO_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssLayerNameList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssPageSelectorPseudoList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssLayerNameList {
+            Some(CssPageSelectorPseudoList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25623,7 +29383,7 @@ impl AstNode for CssLayerNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssLayerNameList {
+impl Serialize for CssPageSelectorPseudoList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25635,9 +29395,9 @@ impl Serialize for CssLayerNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssLayerNameList {
+impl AstNodeList for CssPageSelectorPseudoList {
     type Language = Language;
-    type Node = CssIdentifier;
+    type Node = AnyCssPageSelectorPseudo;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25645,31 +29405,31 @@ impl AstSeparatedList for CssLayerNameList {
         self.syntax_list
     }
 }
-impl Debug for CssLayerNameList {
+impl Debug for CssPageSelectorPseudoList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssLayerNameList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("CssPageSelectorPseudoList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for CssLayerNameList {
-    type Item = SyntaxResult<CssIdentifier>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssIdentifier>;
+impl IntoIterator for &CssPageSelectorPseudoList {
+    type Item = AnyCssPageSelectorPseudo;
+    type IntoIter = AstNodeListIterator<Language, AnyCssPageSelectorPseudo>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssLayerNameList {
-    type Item = SyntaxResult<CssIdentifier>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssIdentifier>;
+impl IntoIterator for CssPageSelectorPseudoList {
+    type Item = AnyCssPageSelectorPseudo;
+    type IntoIter = AstNodeListIterator<Language, AnyCssPageSelectorPseudo>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssLayerReferenceList {
+pub struct CssParameterList {
     syntax_list: SyntaxList,
 }
-impl CssLayerReferenceList {
+impl CssParameterList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25682,16 +29442,16 @@ impl CssLayerReferenceList {
         }
     }
 }
-impl AstNode for CssLayerReferenceList {
+impl AstNode for CssParameterList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_REFERENCE_LIST
+        kind == CSS_PARAMETER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssLayerReferenceList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssParameterList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssLayerReferenceList {
+            Some(CssParameterList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25705,7 +29465,7 @@ impl AstNode for CssLayerReferenceList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssLayerReferenceList {
+impl Serialize for CssParameterList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25717,9 +29477,9 @@ impl Serialize for CssLayerReferenceList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssLayerReferenceList {
+impl AstSeparatedList for CssParameterList {
     type Language = Language;
-    type Node = CssLayerNameList;
+    type Node = CssParameter;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25727,31 +29487,31 @@ impl AstSeparatedList for CssLayerReferenceList {
         self.syntax_list
     }
 }
-impl Debug for CssLayerReferenceList {
+impl Debug for CssParameterList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssLayerReferenceList ")?;
+        f.write_str("CssParameterList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssLayerReferenceList {
-    type Item = SyntaxResult<CssLayerNameList>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssLayerNameList>;
+impl IntoIterator for CssParameterList {
+    type Item = SyntaxResult<CssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssLayerReferenceList {
-    type Item = SyntaxResult<CssLayerNameList>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssLayerNameList>;
+impl IntoIterator for &CssParameterList {
+    type Item = SyntaxResult<CssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssMediaQueryList {
+pub struct CssPseudoValueList {
     syntax_list: SyntaxList,
 }
-impl CssMediaQueryList {
+impl CssPseudoValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25764,16 +29524,16 @@ impl CssMediaQueryList {
         }
     }
 }
-impl AstNode for CssMediaQueryList {
+impl AstNode for CssPseudoValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_QUERY_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_QUERY_LIST
+        kind == CSS_PSEUDO_VALUE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssMediaQueryList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssPseudoValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssMediaQueryList {
+            Some(CssPseudoValueList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25787,7 +29547,7 @@ impl AstNode for CssMediaQueryList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssMediaQueryList {
+impl Serialize for CssPseudoValueList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25799,9 +29559,9 @@ impl Serialize for CssMediaQueryList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssMediaQueryList {
+impl AstSeparatedList for CssPseudoValueList {
     type Language = Language;
-    type Node = AnyCssMediaQuery;
+    type Node = AnyCssPseudoValue;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25809,31 +29569,31 @@ impl AstSeparatedList for CssMediaQueryList {
         self.syntax_list
     }
 }
-impl Debug for CssMediaQueryList {
+impl Debug for CssPseudoValueList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssMediaQueryList ")?;
+        f.write_str("CssPseudoValueList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssMediaQueryList {
-    type Item = SyntaxResult<AnyCssMediaQuery>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssMediaQuery>;
+impl IntoIterator for CssPseudoValueList {
+    type Item = SyntaxResult<AnyCssPseudoValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPseudoValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssMediaQueryList {
-    type Item = SyntaxResult<AnyCssMediaQuery>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssMediaQuery>;
+impl IntoIterator for &CssPseudoValueList {
+    type Item = SyntaxResult<AnyCssPseudoValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPseudoValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssNestedSelectorList {
+pub struct CssRelativeSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssNestedSelectorList {
+impl CssRelativeSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25846,16 +29606,16 @@ impl CssNestedSelectorList {
         }
     }
 }
-impl AstNode for CssNestedSelectorList {
+impl AstNode for CssRelativeSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NESTED_SELECTOR_LIST
+        kind == CSS_RELATIVE_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssNestedSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssRelativeSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssNestedSelectorList {
+            Some(CssRelativeSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25869,7 +29629,7 @@ impl AstNode for CssNestedSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssNestedSelectorList {
+impl Serialize for CssRelativeSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25881,9 +29641,9 @@ impl Serialize for CssNestedSelectorList {
         seq.end()
     }
 }
-impl AstNodeList for CssNestedSelectorList {
+impl AstSeparatedList for CssRelativeSelectorList {
     type Language = Language;
-    type Node = CssNestedSelector;
+    type Node = AnyCssRelativeSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25891,31 +29651,31 @@ impl AstNodeList for CssNestedSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssNestedSelectorList {
+impl Debug for CssRelativeSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssNestedSelectorList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("CssRelativeSelectorList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssNestedSelectorList {
-    type Item = CssNestedSelector;
-    type IntoIter = AstNodeListIterator<Language, CssNestedSelector>;
+impl IntoIterator for CssRelativeSelectorList {
+    type Item = SyntaxResult<AnyCssRelativeSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssRelativeSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssNestedSelectorList {
-    type Item = CssNestedSelector;
-    type IntoIter = AstNodeListIterator<Language, CssNestedSelector>;
+impl IntoIterator for &CssRelativeSelectorList {
+    type Item = SyntaxResult<AnyCssRelativeSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssRelativeSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssPageAtRuleItemList {
+pub struct CssRuleList {
     syntax_list: SyntaxList,
 }
-impl CssPageAtRuleItemList {
+impl CssRuleList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -25928,16 +29688,16 @@ impl CssPageAtRuleItemList {
         }
     }
 }
-impl AstNode for CssPageAtRuleItemList {
+impl AstNode for CssRuleList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_AT_RULE_ITEM_LIST
+        kind == CSS_RULE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssPageAtRuleItemList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssRuleList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssPageAtRuleItemList {
+            Some(CssRuleList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -25951,7 +29711,7 @@ impl AstNode for CssPageAtRuleItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssPageAtRuleItemList {
+impl Serialize for CssRuleList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -25963,9 +29723,9 @@ impl Serialize for CssPageAtRuleItemList {
         seq.end()
     }
 }
-impl AstNodeList for CssPageAtRuleItemList {
+impl AstNodeList for CssRuleList {
     type Language = Language;
-    type Node = AnyCssPageAtRuleItem;
+    type Node = AnyCssRule;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -25973,31 +29733,31 @@ impl AstNodeList for CssPageAtRuleItemList {
         self.syntax_list
     }
 }
-impl Debug for CssPageAtRuleItemList {
+impl Debug for CssRuleList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssPageAtRuleItemList ")?;
+        f.write_str("CssRuleList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssPageAtRuleItemList {
-    type Item = AnyCssPageAtRuleItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssPageAtRuleItem>;
+impl IntoIterator for &CssRuleList {
+    type Item = AnyCssRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssPageAtRuleItemList {
-    type Item = AnyCssPageAtRuleItem;
-    type IntoIter = AstNodeListIterator<Language, AnyCssPageAtRuleItem>;
+impl IntoIterator for CssRuleList {
+    type Item = AnyCssRule;
+    type IntoIter = AstNodeListIterator<Language, AnyCssRule>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssPageSelectorList {
+pub struct CssSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssPageSelectorList {
+impl CssSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26010,16 +29770,16 @@ impl CssPageSelectorList {
         }
     }
 }
-impl AstNode for CssPageSelectorList {
+impl AstNode for CssSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR_LIST
+        kind == CSS_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssPageSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssPageSelectorList {
+            Some(CssSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26033,7 +29793,7 @@ impl AstNode for CssPageSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssPageSelectorList {
+impl Serialize for CssSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26045,9 +29805,9 @@ impl Serialize for CssPageSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssPageSelectorList {
+impl AstSeparatedList for CssSelectorList {
     type Language = Language;
-    type Node = AnyCssPageSelector;
+    type Node = AnyCssSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26055,31 +29815,31 @@ impl AstSeparatedList for CssPageSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssPageSelectorList {
+impl Debug for CssSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssPageSelectorList ")?;
+        f.write_str("CssSelectorList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssPageSelectorList {
-    type Item = SyntaxResult<AnyCssPageSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPageSelector>;
+impl IntoIterator for CssSelectorList {
+    type Item = SyntaxResult<AnyCssSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssPageSelectorList {
-    type Item = SyntaxResult<AnyCssPageSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPageSelector>;
+impl IntoIterator for &CssSelectorList {
+    type Item = SyntaxResult<AnyCssSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssPageSelectorPseudoList {
+pub struct CssSubSelectorList {
     syntax_list: SyntaxList,
 }
-impl CssPageSelectorPseudoList {
+impl CssSubSelectorList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26092,16 +29852,16 @@ impl CssPageSelectorPseudoList {
         }
     }
 }
-impl AstNode for CssPageSelectorPseudoList {
+impl AstNode for CssSubSelectorList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUB_SELECTOR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR_PSEUDO_LIST
+        kind == CSS_SUB_SELECTOR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssPageSelectorPseudoList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssSubSelectorList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssPageSelectorPseudoList {
+            Some(CssSubSelectorList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26115,7 +29875,7 @@ impl AstNode for CssPageSelectorPseudoList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssPageSelectorPseudoList {
+impl Serialize for CssSubSelectorList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26127,9 +29887,9 @@ impl Serialize for CssPageSelectorPseudoList {
         seq.end()
     }
 }
-impl AstNodeList for CssPageSelectorPseudoList {
+impl AstNodeList for CssSubSelectorList {
     type Language = Language;
-    type Node = AnyCssPageSelectorPseudo;
+    type Node = AnyCssSubSelector;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26137,31 +29897,31 @@ impl AstNodeList for CssPageSelectorPseudoList {
         self.syntax_list
     }
 }
-impl Debug for CssPageSelectorPseudoList {
+impl Debug for CssSubSelectorList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssPageSelectorPseudoList ")?;
+        f.write_str("CssSubSelectorList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssPageSelectorPseudoList {
-    type Item = AnyCssPageSelectorPseudo;
-    type IntoIter = AstNodeListIterator<Language, AnyCssPageSelectorPseudo>;
+impl IntoIterator for &CssSubSelectorList {
+    type Item = AnyCssSubSelector;
+    type IntoIter = AstNodeListIterator<Language, AnyCssSubSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssPageSelectorPseudoList {
-    type Item = AnyCssPageSelectorPseudo;
-    type IntoIter = AstNodeListIterator<Language, AnyCssPageSelectorPseudo>;
+impl IntoIterator for CssSubSelectorList {
+    type Item = AnyCssSubSelector;
+    type IntoIter = AstNodeListIterator<Language, AnyCssSubSelector>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssParameterList {
+pub struct CssUrlModifierList {
     syntax_list: SyntaxList,
 }
-impl CssParameterList {
+impl CssUrlModifierList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26174,16 +29934,16 @@ impl CssParameterList {
         }
     }
 }
-impl AstNode for CssParameterList {
+impl AstNode for CssUrlModifierList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_MODIFIER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PARAMETER_LIST
+        kind == CSS_URL_MODIFIER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssParameterList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssUrlModifierList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssParameterList {
+            Some(CssUrlModifierList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26197,7 +29957,7 @@ impl AstNode for CssParameterList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssParameterList {
+impl Serialize for CssUrlModifierList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26209,9 +29969,9 @@ impl Serialize for CssParameterList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssParameterList {
+impl AstNodeList for CssUrlModifierList {
     type Language = Language;
-    type Node = CssParameter;
+    type Node = AnyCssUrlModifier;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26219,31 +29979,31 @@ impl AstSeparatedList for CssParameterList {
         self.syntax_list
     }
 }
-impl Debug for CssParameterList {
+impl Debug for CssUrlModifierList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssParameterList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("CssUrlModifierList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for CssParameterList {
-    type Item = SyntaxResult<CssParameter>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
+impl IntoIterator for &CssUrlModifierList {
+    type Item = AnyCssUrlModifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssUrlModifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssParameterList {
-    type Item = SyntaxResult<CssParameter>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
+impl IntoIterator for CssUrlModifierList {
+    type Item = AnyCssUrlModifier;
+    type IntoIter = AstNodeListIterator<Language, AnyCssUrlModifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssPseudoValueList {
+pub struct CssValueAtRuleImportSpecifierList {
     syntax_list: SyntaxList,
 }
-impl CssPseudoValueList {
+impl CssValueAtRuleImportSpecifierList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26256,16 +30016,17 @@ impl CssPseudoValueList {
         }
     }
 }
-impl AstNode for CssPseudoValueList {
+impl AstNode for CssValueAtRuleImportSpecifierList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_VALUE_LIST as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_VALUE_LIST
+        kind == CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssPseudoValueList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssValueAtRuleImportSpecifierList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssPseudoValueList {
+            Some(CssValueAtRuleImportSpecifierList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26279,7 +30040,7 @@ impl AstNode for CssPseudoValueList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssPseudoValueList {
+impl Serialize for CssValueAtRuleImportSpecifierList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26291,9 +30052,9 @@ impl Serialize for CssPseudoValueList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssPseudoValueList {
+impl AstSeparatedList for CssValueAtRuleImportSpecifierList {
     type Language = Language;
-    type Node = AnyCssPseudoValue;
+    type Node = AnyCssValueAtRuleImportSpecifier;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26301,31 +30062,31 @@ impl AstSeparatedList for CssPseudoValueList {
         self.syntax_list
     }
 }
-impl Debug for CssPseudoValueList {
+impl Debug for CssValueAtRuleImportSpecifierList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssPseudoValueList ")?;
+        f.write_str("CssValueAtRuleImportSpecifierList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssPseudoValueList {
-    type Item = SyntaxResult<AnyCssPseudoValue>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPseudoValue>;
+impl IntoIterator for CssValueAtRuleImportSpecifierList {
+    type Item = SyntaxResult<AnyCssValueAtRuleImportSpecifier>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleImportSpecifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssPseudoValueList {
-    type Item = SyntaxResult<AnyCssPseudoValue>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssPseudoValue>;
+impl IntoIterator for &CssValueAtRuleImportSpecifierList {
+    type Item = SyntaxResult<AnyCssValueAtRuleImportSpecifier>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleImportSpecifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssRelativeSelectorList {
+pub struct CssValueAtRulePropertyList {
     syntax_list: SyntaxList,
 }
-impl CssRelativeSelectorList {
+impl CssValueAtRulePropertyList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26338,16 +30099,16 @@ impl CssRelativeSelectorList {
         }
     }
 }
-impl AstNode for CssRelativeSelectorList {
+impl AstNode for CssValueAtRulePropertyList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_PROPERTY_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RELATIVE_SELECTOR_LIST
+        kind == CSS_VALUE_AT_RULE_PROPERTY_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssRelativeSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<CssValueAtRulePropertyList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssRelativeSelectorList {
+            Some(CssValueAtRulePropertyList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26361,7 +30122,7 @@ impl AstNode for CssRelativeSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssRelativeSelectorList {
+impl Serialize for CssValueAtRulePropertyList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26373,9 +30134,9 @@ impl Serialize for CssRelativeSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssRelativeSelectorList {
+impl AstSeparatedList for CssValueAtRulePropertyList {
     type Language = Language;
-    type Node = AnyCssRelativeSelector;
+    type Node = AnyCssValueAtRuleProperty;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26383,31 +30144,31 @@ impl AstSeparatedList for CssRelativeSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssRelativeSelectorList {
+impl Debug for CssValueAtRulePropertyList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssRelativeSelectorList ")?;
+        f.write_str("CssValueAtRulePropertyList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssRelativeSelectorList {
-    type Item = SyntaxResult<AnyCssRelativeSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssRelativeSelector>;
+impl IntoIterator for CssValueAtRulePropertyList {
+    type Item = SyntaxResult<AnyCssValueAtRuleProperty>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleProperty>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssRelativeSelectorList {
-    type Item = SyntaxResult<AnyCssRelativeSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssRelativeSelector>;
+impl IntoIterator for &CssValueAtRulePropertyList {
+    type Item = SyntaxResult<AnyCssValueAtRuleProperty>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleProperty>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssRuleList {
+pub struct ScssArgumentList {
     syntax_list: SyntaxList,
 }
-impl CssRuleList {
+impl ScssArgumentList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26420,16 +30181,16 @@ impl CssRuleList {
         }
     }
 }
-impl AstNode for CssRuleList {
+impl AstNode for ScssArgumentList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_ARGUMENT_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RULE_LIST
+        kind == SCSS_ARGUMENT_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssRuleList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssArgumentList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssRuleList {
+            Some(ScssArgumentList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26443,7 +30204,7 @@ impl AstNode for CssRuleList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssRuleList {
+impl Serialize for ScssArgumentList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26455,9 +30216,9 @@ impl Serialize for CssRuleList {
         seq.end()
     }
 }
-impl AstNodeList for CssRuleList {
+impl AstSeparatedList for ScssArgumentList {
     type Language = Language;
-    type Node = AnyCssRule;
+    type Node = ScssArgument;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26465,31 +30226,31 @@ impl AstNodeList for CssRuleList {
         self.syntax_list
     }
 }
-impl Debug for CssRuleList {
+impl Debug for ScssArgumentList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssRuleList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("ScssArgumentList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssRuleList {
-    type Item = AnyCssRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssRule>;
+impl IntoIterator for ScssArgumentList {
+    type Item = SyntaxResult<ScssArgument>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssArgument>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssRuleList {
-    type Item = AnyCssRule;
-    type IntoIter = AstNodeListIterator<Language, AnyCssRule>;
+impl IntoIterator for &ScssArgumentList {
+    type Item = SyntaxResult<ScssArgument>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssArgument>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssSelectorList {
+pub struct ScssForwardMemberList {
     syntax_list: SyntaxList,
 }
-impl CssSelectorList {
+impl ScssForwardMemberList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26502,16 +30263,16 @@ impl CssSelectorList {
         }
     }
 }
-impl AstNode for CssSelectorList {
+impl AstNode for ScssForwardMemberList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_FORWARD_MEMBER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SELECTOR_LIST
+        kind == SCSS_FORWARD_MEMBER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssForwardMemberList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssSelectorList {
+            Some(ScssForwardMemberList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26525,7 +30286,7 @@ impl AstNode for CssSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssSelectorList {
+impl Serialize for ScssForwardMemberList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26537,9 +30298,9 @@ impl Serialize for CssSelectorList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssSelectorList {
+impl AstSeparatedList for ScssForwardMemberList {
     type Language = Language;
-    type Node = AnyCssSelector;
+    type Node = AnyScssForwardMember;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26547,31 +30308,31 @@ impl AstSeparatedList for CssSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssSelectorList {
+impl Debug for ScssForwardMemberList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssSelectorList ")?;
+        f.write_str("ScssForwardMemberList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssSelectorList {
-    type Item = SyntaxResult<AnyCssSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
+impl IntoIterator for ScssForwardMemberList {
+    type Item = SyntaxResult<AnyScssForwardMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyScssForwardMember>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssSelectorList {
-    type Item = SyntaxResult<AnyCssSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
+impl IntoIterator for &ScssForwardMemberList {
+    type Item = SyntaxResult<AnyScssForwardMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyScssForwardMember>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssSubSelectorList {
+pub struct ScssInterpolatedIdentifierItemList {
     syntax_list: SyntaxList,
 }
-impl CssSubSelectorList {
+impl ScssInterpolatedIdentifierItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26584,16 +30345,16 @@ impl CssSubSelectorList {
         }
     }
 }
-impl AstNode for CssSubSelectorList {
+impl AstNode for ScssInterpolatedIdentifierItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUB_SELECTOR_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUB_SELECTOR_LIST
+        kind == SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssSubSelectorList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssInterpolatedIdentifierItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssSubSelectorList {
+            Some(ScssInterpolatedIdentifierItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26607,7 +30368,7 @@ impl AstNode for CssSubSelectorList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssSubSelectorList {
+impl Serialize for ScssInterpolatedIdentifierItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26619,9 +30380,9 @@ impl Serialize for CssSubSelectorList {
         seq.end()
     }
 }
-impl AstNodeList for CssSubSelectorList {
+impl AstNodeList for ScssInterpolatedIdentifierItemList {
     type Language = Language;
-    type Node = AnyCssSubSelector;
+    type Node = AnyScssInterpolatedIdentifierItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26629,31 +30390,31 @@ impl AstNodeList for CssSubSelectorList {
         self.syntax_list
     }
 }
-impl Debug for CssSubSelectorList {
+impl Debug for ScssInterpolatedIdentifierItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssSubSelectorList ")?;
+        f.write_str("ScssInterpolatedIdentifierItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssSubSelectorList {
-    type Item = AnyCssSubSelector;
-    type IntoIter = AstNodeListIterator<Language, AnyCssSubSelector>;
+impl IntoIterator for &ScssInterpolatedIdentifierItemList {
+    type Item = AnyScssInterpolatedIdentifierItem;
+    type IntoIter = AstNodeListIterator<Language, AnyScssInterpolatedIdentifierItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssSubSelectorList {
-    type Item = AnyCssSubSelector;
-    type IntoIter = AstNodeListIterator<Language, AnyCssSubSelector>;
+impl IntoIterator for ScssInterpolatedIdentifierItemList {
+    type Item = AnyScssInterpolatedIdentifierItem;
+    type IntoIter = AstNodeListIterator<Language, AnyScssInterpolatedIdentifierItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssUrlModifierList {
+pub struct ScssMapExpressionPairList {
     syntax_list: SyntaxList,
 }
-impl CssUrlModifierList {
+impl ScssMapExpressionPairList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26666,16 +30427,16 @@ impl CssUrlModifierList {
         }
     }
 }
-impl AstNode for CssUrlModifierList {
+impl AstNode for ScssMapExpressionPairList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_MODIFIER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_MAP_EXPRESSION_PAIR_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_URL_MODIFIER_LIST
+        kind == SCSS_MAP_EXPRESSION_PAIR_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssUrlModifierList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssMapExpressionPairList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssUrlModifierList {
+            Some(ScssMapExpressionPairList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26689,7 +30450,7 @@ impl AstNode for CssUrlModifierList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssUrlModifierList {
+impl Serialize for ScssMapExpressionPairList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26701,9 +30462,9 @@ impl Serialize for CssUrlModifierList {
         seq.end()
     }
 }
-impl AstNodeList for CssUrlModifierList {
+impl AstSeparatedList for ScssMapExpressionPairList {
     type Language = Language;
-    type Node = AnyCssUrlModifier;
+    type Node = ScssMapExpressionPair;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26711,31 +30472,31 @@ impl AstNodeList for CssUrlModifierList {
         self.syntax_list
     }
 }
-impl Debug for CssUrlModifierList {
+impl Debug for ScssMapExpressionPairList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssUrlModifierList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("ScssMapExpressionPairList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssUrlModifierList {
-    type Item = AnyCssUrlModifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssUrlModifier>;
+impl IntoIterator for ScssMapExpressionPairList {
+    type Item = SyntaxResult<ScssMapExpressionPair>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssMapExpressionPair>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for CssUrlModifierList {
-    type Item = AnyCssUrlModifier;
-    type IntoIter = AstNodeListIterator<Language, AnyCssUrlModifier>;
+impl IntoIterator for &ScssMapExpressionPairList {
+    type Item = SyntaxResult<ScssMapExpressionPair>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssMapExpressionPair>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssValueAtRuleImportSpecifierList {
+pub struct ScssParameterList {
     syntax_list: SyntaxList,
 }
-impl CssValueAtRuleImportSpecifierList {
+impl ScssParameterList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26748,17 +30509,16 @@ impl CssValueAtRuleImportSpecifierList {
         }
     }
 }
-impl AstNode for CssValueAtRuleImportSpecifierList {
+impl AstNode for ScssParameterList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_PARAMETER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_IMPORT_SPECIFIER_LIST
+        kind == SCSS_PARAMETER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssValueAtRuleImportSpecifierList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssParameterList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssValueAtRuleImportSpecifierList {
+            Some(ScssParameterList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26772,7 +30532,7 @@ impl AstNode for CssValueAtRuleImportSpecifierList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssValueAtRuleImportSpecifierList {
+impl Serialize for ScssParameterList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26784,9 +30544,9 @@ impl Serialize for CssValueAtRuleImportSpecifierList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssValueAtRuleImportSpecifierList {
+impl AstSeparatedList for ScssParameterList {
     type Language = Language;
-    type Node = AnyCssValueAtRuleImportSpecifier;
+    type Node = ScssParameter;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26794,31 +30554,31 @@ impl AstSeparatedList for CssValueAtRuleImportSpecifierList {
         self.syntax_list
     }
 }
-impl Debug for CssValueAtRuleImportSpecifierList {
+impl Debug for ScssParameterList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssValueAtRuleImportSpecifierList ")?;
+        f.write_str("ScssParameterList ")?;
         f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for CssValueAtRuleImportSpecifierList {
-    type Item = SyntaxResult<AnyCssValueAtRuleImportSpecifier>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleImportSpecifier>;
+impl IntoIterator for ScssParameterList {
+    type Item = SyntaxResult<ScssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssParameter>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssValueAtRuleImportSpecifierList {
-    type Item = SyntaxResult<AnyCssValueAtRuleImportSpecifier>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleImportSpecifier>;
+impl IntoIterator for &ScssParameterList {
+    type Item = SyntaxResult<ScssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, ScssParameter>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssValueAtRulePropertyList {
+pub struct ScssVariableModifierList {
     syntax_list: SyntaxList,
 }
-impl CssValueAtRulePropertyList {
+impl ScssVariableModifierList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -26831,16 +30591,16 @@ impl CssValueAtRulePropertyList {
         }
     }
 }
-impl AstNode for CssValueAtRulePropertyList {
+impl AstNode for ScssVariableModifierList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_VALUE_AT_RULE_PROPERTY_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_VARIABLE_MODIFIER_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_VALUE_AT_RULE_PROPERTY_LIST
+        kind == SCSS_VARIABLE_MODIFIER_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<CssValueAtRulePropertyList> {
+    fn cast(syntax: SyntaxNode) -> Option<ScssVariableModifierList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssValueAtRulePropertyList {
+            Some(ScssVariableModifierList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -26854,7 +30614,7 @@ impl AstNode for CssValueAtRulePropertyList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for CssValueAtRulePropertyList {
+impl Serialize for ScssVariableModifierList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -26866,9 +30626,9 @@ impl Serialize for CssValueAtRulePropertyList {
         seq.end()
     }
 }
-impl AstSeparatedList for CssValueAtRulePropertyList {
+impl AstNodeList for ScssVariableModifierList {
     type Language = Language;
-    type Node = AnyCssValueAtRuleProperty;
+    type Node = ScssVariableModifier;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -26876,22 +30636,22 @@ impl AstSeparatedList for CssValueAtRulePropertyList {
         self.syntax_list
     }
 }
-impl Debug for CssValueAtRulePropertyList {
+impl Debug for ScssVariableModifierList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssValueAtRulePropertyList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("ScssVariableModifierList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for CssValueAtRulePropertyList {
-    type Item = SyntaxResult<AnyCssValueAtRuleProperty>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleProperty>;
+impl IntoIterator for &ScssVariableModifierList {
+    type Item = ScssVariableModifier;
+    type IntoIter = AstNodeListIterator<Language, ScssVariableModifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &CssValueAtRulePropertyList {
-    type Item = SyntaxResult<AnyCssValueAtRuleProperty>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssValueAtRuleProperty>;
+impl IntoIterator for ScssVariableModifierList {
+    type Item = ScssVariableModifier;
+    type IntoIter = AstNodeListIterator<Language, ScssVariableModifier>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
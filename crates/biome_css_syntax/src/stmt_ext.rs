@@ -1,9 +1,11 @@
 use crate::generated::{
-    CssDeclarationBlock, CssDeclarationOrAtRuleBlock, CssDeclarationOrRuleBlock,
+    CssDeclaration, CssDeclarationBlock, CssDeclarationOrAtRuleBlock, CssDeclarationOrRuleBlock,
     CssFontFeatureValuesBlock, CssKeyframesBlock, CssPageAtRuleBlock, CssRuleBlock,
+    ScssNestingDeclaration,
 };
+use crate::CssSyntaxKind::*;
 use crate::CssSyntaxToken;
-use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, SyntaxResult};
 
 declare_node_union! {
     pub CssBlockLike = CssKeyframesBlock | CssDeclarationOrAtRuleBlock | CssDeclarationBlock | CssRuleBlock | CssFontFeatureValuesBlock | CssPageAtRuleBlock | CssDeclarationOrRuleBlock
@@ -60,3 +62,38 @@ impl CssBlockLike {
                 .is_ok_and(|token| token.has_leading_comments())
     }
 }
+
+impl CssDeclaration {
+    /// Returns the prefix that SCSS nested properties add to the name of the declaration,
+    /// or `None` if the declaration isn't nested in such a property.
+    ///
+    /// ```scss
+    /// font: {
+    ///   family: serif; // The prefix is `font-`
+    /// }
+    /// ```
+    pub fn scss_nesting_prefix(&self) -> Option<String> {
+        let mut prefix: Option<String> = None;
+        for nesting in self
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .take_while(|node| {
+                matches!(
+                    node.kind(),
+                    CSS_DECLARATION_WITH_SEMICOLON
+                        | CSS_DECLARATION_OR_RULE_LIST
+                        | CSS_DECLARATION_OR_RULE_BLOCK
+                        | SCSS_NESTING_DECLARATION
+                )
+            })
+            .filter_map(ScssNestingDeclaration::cast)
+        {
+            let name = nesting.name().ok()?.value_token().ok()?;
+            let prefix = prefix.get_or_insert_with(String::new);
+            prefix.insert(0, '-');
+            prefix.insert_str(0, name.text_trimmed());
+        }
+        prefix
+    }
+}
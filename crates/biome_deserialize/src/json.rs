@@ -99,7 +99,7 @@ impl DeserializableValue for AnyJsonValue {
                 let items = array.elements().iter().map(|x| x.ok());
                 visitor.visit_array(items, range, name, diagnostics)
             }
-            AnyJsonValue::JsonBogusValue(_) => {
+            AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => {
                 // The parser should emit an error about this node
                 // No need to emit another diagnostic.
                 None
@@ -131,7 +131,7 @@ impl DeserializableValue for AnyJsonValue {
     fn visitable_type(&self) -> Option<DeserializableType> {
         match self {
             AnyJsonValue::JsonArrayValue(_) => Some(DeserializableType::Array),
-            AnyJsonValue::JsonBogusValue(_) => None,
+            AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => None,
             AnyJsonValue::JsonBooleanValue(_) => Some(DeserializableType::Bool),
             AnyJsonValue::JsonNullValue(_) => Some(DeserializableType::Null),
             AnyJsonValue::JsonNumberValue(_) => Some(DeserializableType::Number),
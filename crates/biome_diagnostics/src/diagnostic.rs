@@ -175,6 +175,7 @@ pub(super) enum DiagnosticTag {
     UnnecessaryCode = 1 << 2,
     DeprecatedCode = 1 << 3,
     Verbose = 1 << 4,
+    Suppressed = 1 << 5,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -192,6 +193,9 @@ impl DiagnosticTags {
     pub const DEPRECATED_CODE: Self = Self(make_bitflags!(DiagnosticTag::{DeprecatedCode}));
     /// This diagnostic is verbose and should be printed only if the `--verbose` option is provided
     pub const VERBOSE: Self = Self(make_bitflags!(DiagnosticTag::{Verbose}));
+    /// This diagnostic was silenced by a suppression comment, and is only
+    /// emitted for the reporters that list the suppressed results
+    pub const SUPPRESSED: Self = Self(make_bitflags!(DiagnosticTag::{Suppressed}));
     pub const fn all() -> Self {
         Self(BitFlags::ALL)
     }
@@ -213,6 +217,10 @@ impl DiagnosticTags {
     pub fn is_verbose(&self) -> bool {
         self.contains(DiagnosticTag::Verbose)
     }
+
+    pub fn is_suppressed(&self) -> bool {
+        self.contains(DiagnosticTag::Suppressed)
+    }
 }
 
 impl BitOr for DiagnosticTags {
@@ -295,6 +295,7 @@ impl From<DiagnosticTag> for DiagnosticTags {
             DiagnosticTag::UnnecessaryCode => DiagnosticTags::UNNECESSARY_CODE,
             DiagnosticTag::DeprecatedCode => DiagnosticTags::DEPRECATED_CODE,
             DiagnosticTag::Verbose => DiagnosticTags::VERBOSE,
+            DiagnosticTag::Suppressed => DiagnosticTags::SUPPRESSED,
         }
     }
 }
@@ -323,6 +324,10 @@ impl Serialize for DiagnosticTags {
             flags.push(DiagnosticTag::DeprecatedCode);
         }
 
+        if self.contains(Self::SUPPRESSED) {
+            flags.push(DiagnosticTag::Suppressed);
+        }
+
         serializer.collect_seq(flags)
     }
 }
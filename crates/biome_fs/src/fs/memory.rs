@@ -209,6 +209,24 @@ impl FileSystem for MemoryFileSystem {
         false
     }
 
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // Directories only exist implicitly in the memory file system
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        if !self.allow_write {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot remove a directory in read-only filesystem",
+            ));
+        }
+
+        let mut files = self.files.0.write();
+        files.retain(|file_path, _| !file_path.starts_with(path));
+        Ok(())
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
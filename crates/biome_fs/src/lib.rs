@@ -7,7 +7,7 @@ pub use dir::ensure_cache_dir;
 pub use fs::{
     AutoSearchResult, ConfigName, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
     FileSystemExt, MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope,
-    ROME_JSON,
+    DEFAULT_IGNORE, ROME_JSON,
 };
 pub use interner::PathInterner;
 pub use path::BiomePath;
@@ -43,6 +43,7 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         vcs_base_path: None,
         gitignore_matches: vec![],
         workspace_directory: None,
+        configuration_directory: None,
     };
 
     let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
@@ -5,11 +5,14 @@ use crate::{
 use biome_diagnostics::{display::SourceFile, SourceCode};
 use biome_rowan::TextRange;
 use grit_pattern_matcher::{
-    binding::Binding, constant::Constant, effects::Effect, pattern::FileRegistry,
+    binding::Binding,
+    constant::Constant,
+    effects::Effect,
+    pattern::{get_top_level_effects, FileRegistry, ResolvedPattern},
 };
 use grit_util::{
     error::{GritPatternError, GritResult},
-    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, Range,
+    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, EffectKind, Range,
 };
 use std::{borrow::Cow, collections::HashMap, path::Path};
 
@@ -157,14 +160,36 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
         _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Node(node) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                node.source(),
+                node.code_range(),
+                logs,
+            ),
+            Self::Range(range, source) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                source,
+                range.to_code_range(source),
+                logs,
+            ),
+            Self::File(path) => Ok(path.to_string_lossy()),
+            Self::Empty(..) => Ok("".into()),
+            Self::Constant(constant) => Ok(constant.to_string().into()),
+        }
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> GritResult<Cow<'a, str>> {
@@ -272,6 +297,68 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
     }
 }
 
+/// Returns the text of `source` within `range`, with the effects that apply
+/// to it.
+///
+/// Only the outermost effects are applied to the text, the nested ones are
+/// applied when the replacements of the outermost effects are linearized.
+pub(crate) fn linearize_binding<'a>(
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    source: &'a str,
+    range: CodeRange,
+    logs: &mut AnalysisLogs,
+) -> GritResult<Cow<'a, str>> {
+    let text = &source[range.start as usize..range.end as usize];
+    let top_level_effects = get_top_level_effects(effects, memo, &range, language, logs)?;
+    if top_level_effects.is_empty() {
+        return Ok(text.into());
+    }
+
+    let mut replacements = Vec::with_capacity(top_level_effects.len());
+    for effect in top_level_effects {
+        let Some(binding_range) = effect.binding.code_range(language) else {
+            continue;
+        };
+        let replacement = match memo.get(&binding_range) {
+            Some(Some(replacement)) => replacement.clone(),
+            _ => {
+                // The binding is marked as being linearized, so that the
+                // effect doesn't apply again to the bindings of its pattern
+                memo.insert(binding_range.clone(), None);
+                let replacement = effect
+                    .pattern
+                    .linearized_text(language, effects, files, memo, false, logs)?
+                    .into_owned();
+                memo.insert(binding_range.clone(), Some(replacement.clone()));
+                replacement
+            }
+        };
+        let start = match effect.kind {
+            EffectKind::Rewrite => binding_range.start,
+            EffectKind::Insert => binding_range.end,
+        };
+        replacements.push((start as usize, binding_range.end as usize, replacement));
+    }
+    replacements.sort_by_key(|(start, end, _)| (*start, *end));
+
+    let mut linearized = String::with_capacity(text.len());
+    let mut offset = range.start as usize;
+    for (start, end, replacement) in replacements {
+        if start < offset {
+            continue;
+        }
+        linearized.push_str(&source[offset..start]);
+        linearized.push_str(&replacement);
+        offset = end;
+    }
+    linearized.push_str(&source[offset..range.end as usize]);
+
+    Ok(linearized.into())
+}
+
 /// Checks whether two nodes are equivalent.
 ///
 /// We define two nodes to be equivalent if they have the same sort (kind) and
@@ -1,4 +1,4 @@
-use crate::grit_binding::GritBinding;
+use crate::grit_binding::{linearize_binding, GritBinding};
 use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
@@ -16,8 +16,9 @@ use grit_pattern_matcher::pattern::{
     PredicateDefinition, ResolvedPattern, State,
 };
 use grit_util::error::GritPatternError;
-use grit_util::{error::GritResult, AnalysisLogs, FileOrigin, InputRanges, MatchRanges};
+use grit_util::{error::GritResult, AnalysisLogs, CodeRange, FileOrigin, InputRanges, MatchRanges};
 use path_absolutize::Absolutize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -149,8 +150,44 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
             if match_log.input_matches.is_none() {
                 match_log.input_matches = Some(input_ranges.clone());
             }
+            drop(match_log);
 
-            // TODO: Implement effect application
+            if state.effects.is_empty() {
+                continue;
+            }
+
+            let source = file.tree.text();
+            let rewritten = linearize_binding(
+                &self.lang,
+                &state.effects,
+                &state.files,
+                &mut HashMap::new(),
+                source,
+                CodeRange::new(0, source.len() as u32, source),
+                logs,
+            )?;
+            if rewritten == source {
+                continue;
+            }
+
+            let owned_file = new_file_owner(
+                file.name.clone(),
+                &rewritten,
+                FileOrigin::Mutated,
+                &self.lang,
+                logs,
+            )?
+            .ok_or_else(|| {
+                GritPatternError::Builder(format!(
+                    "failed to rewrite file {}",
+                    file.name.to_string_lossy()
+                ))
+            })?;
+            self.files().push(owned_file);
+            // SAFETY: We just pushed to the list of files, so there must be one.
+            state
+                .files
+                .push_revision(&file_ptr, self.files().last().unwrap());
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
@@ -180,12 +217,13 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
                 .into();
             let body = file.body(&state.files).text(&state.files, &self.lang)?;
             let owned_file =
-                new_file_owner(name.clone(), &body, &self.lang, logs)?.ok_or_else(|| {
-                    GritPatternError::Builder(format!(
-                        "failed to construct new file for file {}",
-                        name.to_string_lossy()
-                    ))
-                })?;
+                new_file_owner(name.clone(), &body, FileOrigin::New, &self.lang, logs)?
+                    .ok_or_else(|| {
+                        GritPatternError::Builder(format!(
+                            "failed to construct new file for file {}",
+                            name.to_string_lossy()
+                        ))
+                    })?;
             self.files().push(owned_file);
             // SAFETY: We just pushed to the list of files, so there must be one.
             let _ = state.files.push_new_file(self.files().last().unwrap());
@@ -269,14 +307,16 @@ fn file_owner_from_matches(
 fn new_file_owner(
     name: impl Into<PathBuf>,
     source: &str,
+    origin: FileOrigin<'_, GritTargetTree>,
     language: &GritTargetLanguage,
     logs: &mut AnalysisLogs,
 ) -> GritResult<Option<FileOwner<GritTargetTree>>> {
     let name = name.into();
+    let new = matches!(origin, FileOrigin::New);
 
     let Some(tree) = language
         .get_parser()
-        .parse_file(source, Some(&name), logs, FileOrigin::New)
+        .parse_file(source, Some(&name), logs, origin)
     else {
         return Ok(None);
     };
@@ -287,7 +327,7 @@ fn new_file_owner(
         absolute_path,
         tree,
         matches: Default::default(),
-        new: true,
+        new,
     }))
 }
 
@@ -3,7 +3,7 @@ use crate::{
     grit_tree::GritTargetTree,
 };
 use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::JsFileSource;
+use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_parser::AnyParse;
 use grit_util::{AnalysisLogs, FileOrigin, Parser, SnippetTree};
 use std::path::Path;
@@ -21,7 +21,7 @@ impl GritTargetParser for GritJsParser {
             logs.push(diagnostic.to_log(path));
         }
 
-        Some(GritTargetTree::new(parse.syntax().into()))
+        Some(GritTargetTree::new(parse.syntax::<JsLanguage>().into()))
     }
 }
 
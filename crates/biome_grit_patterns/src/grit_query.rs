@@ -62,6 +62,11 @@ pub struct GritQuery {
 }
 
 impl GritQuery {
+    /// Returns the target language of the query.
+    pub fn language(&self) -> &GritTargetLanguage {
+        &self.language
+    }
+
     pub fn execute(
         &self,
         file: GritTargetFile,
@@ -115,6 +120,8 @@ impl GritQuery {
             function_definition_info,
         } = scan_definitions(root.definitions())?;
 
+        let lang = GritTargetLanguage::from_root(&root)?.unwrap_or(lang);
+
         let context = CompilationContext {
             source_path,
             lang,
@@ -265,9 +272,19 @@ pub struct Match {
 
 impl Match {
     fn from_file_ranges(match_ranges: &InputRanges, path: &Path) -> Self {
+        // Snippets match a list with a single element like they match the
+        // element itself, and `contains` visits both the list and its element.
+        // They share the same range, which must be reported only once.
+        let mut ranges: Vec<Range> = Vec::with_capacity(match_ranges.ranges.len());
+        for range in &match_ranges.ranges {
+            if !ranges.contains(range) {
+                ranges.push(*range);
+            }
+        }
+
         Self {
             source_file: path.to_owned(),
-            ranges: match_ranges.ranges.clone(),
+            ranges,
             variables: match_ranges.variables.clone(),
             messages: vec![],
         }
@@ -520,14 +520,52 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<CodeRange, Option<String>>,
-        _should_pad_snippet: bool,
-        _logs: &mut AnalysisLogs,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
+        should_pad_snippet: bool,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Snippets(snippets) => Ok(snippets
+                .iter()
+                .map(|snippet| {
+                    snippet
+                        .linearized_text(language, effects, files, memo, None, logs)
+                        .map(Cow::into_owned)
+                })
+                .collect::<GritResult<Vec<_>>>()?
+                .concat()
+                .into()),
+            Self::List(list) => Ok(list
+                .iter()
+                .map(|pattern| {
+                    pattern
+                        .linearized_text(language, effects, files, memo, should_pad_snippet, logs)
+                        .map(Cow::into_owned)
+                })
+                .collect::<GritResult<Vec<_>>>()?
+                .join(",")
+                .into()),
+            Self::Binding(bindings) => bindings
+                .last()
+                .ok_or_else(|| {
+                    GritPatternError::new(
+                        "cannot linearize the text of a resolved pattern without binding",
+                    )
+                })?
+                .linearized_text(
+                    language,
+                    effects,
+                    files,
+                    memo,
+                    should_pad_snippet.then_some(0),
+                    logs,
+                ),
+            Self::Constant(constant) => Ok(constant.to_string().into()),
+            Self::Map(_) | Self::File(_) | Self::Files(_) => self.text(files, language),
+        }
     }
 
     fn matches_undefined(&self) -> bool {
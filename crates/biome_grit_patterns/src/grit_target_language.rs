@@ -1,13 +1,23 @@
+mod css_target_language;
 mod js_target_language;
+mod json_target_language;
 
+pub use css_target_language::CssTargetLanguage;
 pub use js_target_language::JsTargetLanguage;
+pub use json_target_language::JsonTargetLanguage;
 
+use crate::grit_css_parser::GritCssParser;
 use crate::grit_js_parser::GritJsParser;
+use crate::grit_json_parser::GritJsonParser;
 use crate::grit_target_node::{GritTargetNode, GritTargetSyntaxKind};
 use crate::grit_tree::GritTargetTree;
 use crate::CompileError;
+use biome_css_syntax::CssLanguage;
+use biome_grit_syntax::{AnyGritLanguageDeclaration, GritRoot};
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
 use biome_parser::AnyParse;
-use biome_rowan::SyntaxKind;
+use biome_rowan::{SendNode, SyntaxKind};
 use grit_util::{AnalysisLogs, Ast, CodeRange, EffectRange, Language, Parser, SnippetTree};
 use std::borrow::Cow;
 use std::path::Path;
@@ -147,7 +157,9 @@ macro_rules! generate_target_language {
 }
 
 generate_target_language! {
-    [JsTargetLanguage, GritJsParser]
+    [CssTargetLanguage, GritCssParser],
+    [JsTargetLanguage, GritJsParser],
+    [JsonTargetLanguage, GritJsonParser]
 }
 
 impl GritTargetLanguage {
@@ -157,10 +169,51 @@ impl GritTargetLanguage {
             "cjs" | "js" | "jsx" | "mjs" | "ts" | "tsx" => {
                 Some(Self::JsTargetLanguage(JsTargetLanguage))
             }
+            "css" => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            "json" | "jsonc" => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
             _ => None,
         }
     }
 
+    /// Returns the target language for the name used in a Grit `language`
+    /// declaration, such as `language css;`.
+    pub fn from_declaration_name(name: &str) -> Option<Self> {
+        match name {
+            "css" => Some(Self::CssTargetLanguage(CssTargetLanguage)),
+            "js" => Some(Self::JsTargetLanguage(JsTargetLanguage)),
+            "json" => Some(Self::JsonTargetLanguage(JsonTargetLanguage)),
+            _ => None,
+        }
+    }
+
+    /// Returns the target language declared by the given query, if any.
+    ///
+    /// Queries without a `language` declaration return `Ok(None)`, in which
+    /// case the caller should fall back to its default language.
+    pub fn from_root(root: &GritRoot) -> Result<Option<Self>, CompileError> {
+        let Some(AnyGritLanguageDeclaration::GritLanguageDeclaration(declaration)) =
+            root.language()
+        else {
+            return Ok(None);
+        };
+
+        let name = declaration.name()?.language_kind()?;
+        let name = name.text_trimmed();
+        Self::from_declaration_name(name)
+            .map(Some)
+            .ok_or_else(|| CompileError::UnknownTargetLanguage(name.to_string()))
+    }
+
+    /// Returns `true` when the given type-erased root node belongs to this
+    /// target language, and queries for this language can be executed on it.
+    pub fn is_compatible_with(&self, root: &SendNode) -> bool {
+        match self {
+            Self::CssTargetLanguage(_) => root.clone().into_node::<CssLanguage>().is_some(),
+            Self::JsTargetLanguage(_) => root.clone().into_node::<JsLanguage>().is_some(),
+            Self::JsonTargetLanguage(_) => root.clone().into_node::<JsonLanguage>().is_some(),
+        }
+    }
+
     /// Returns `true` when the text `content` contains an identifier for a
     /// metavariable using bracket syntax.
     ///
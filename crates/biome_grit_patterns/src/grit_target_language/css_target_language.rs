@@ -0,0 +1,160 @@
+use super::GritTargetLanguageImpl;
+use crate::grit_target_node::GritTargetSyntaxKind;
+use biome_css_syntax::{CssLanguage, CssSyntaxKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKindSet};
+
+const COMMENT_KINDS: SyntaxKindSet<CssLanguage> =
+    SyntaxKindSet::from_raw(RawSyntaxKind(CssSyntaxKind::COMMENT as u16)).union(
+        SyntaxKindSet::from_raw(RawSyntaxKind(CssSyntaxKind::MULTILINE_COMMENT as u16)),
+    );
+
+#[derive(Clone, Debug)]
+pub struct CssTargetLanguage;
+
+impl GritTargetLanguageImpl for CssTargetLanguage {
+    type Kind = CssSyntaxKind;
+
+    fn language_name(&self) -> &'static str {
+        "CSS"
+    }
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_for_name()`.
+    fn kind_by_name(&self, node_name: &str) -> Option<CssSyntaxKind> {
+        use CssSyntaxKind::*;
+        let kind = match node_name {
+            "arguments" => CSS_PARAMETER_LIST,
+            "at_rule" => CSS_UNKNOWN_BLOCK_AT_RULE,
+            "attribute_name" => CSS_ATTRIBUTE_NAME,
+            "attribute_selector" => CSS_ATTRIBUTE_SELECTOR,
+            "binary_expression" => CSS_BINARY_EXPRESSION,
+            "block" => CSS_DECLARATION_OR_RULE_BLOCK,
+            "call_expression" => CSS_FUNCTION,
+            "charset_statement" => CSS_CHARSET_AT_RULE,
+            "class_selector" => CSS_CLASS_SELECTOR,
+            "color_value" => CSS_COLOR,
+            "declaration" => CSS_DECLARATION,
+            "id_selector" => CSS_ID_SELECTOR,
+            "import_statement" => CSS_IMPORT_AT_RULE,
+            "important" => CSS_DECLARATION_IMPORTANT,
+            "keyframe_block" => CSS_KEYFRAMES_ITEM,
+            "keyframe_block_list" => CSS_KEYFRAMES_BLOCK,
+            "keyframes_statement" => CSS_KEYFRAMES_AT_RULE,
+            "media_statement" => CSS_MEDIA_AT_RULE,
+            "namespace_statement" => CSS_NAMESPACE_AT_RULE,
+            "nesting_selector" => CSS_NESTED_SELECTOR,
+            "parenthesized_value" => CSS_PARENTHESIZED_EXPRESSION,
+            "pseudo_class_selector" => CSS_PSEUDO_CLASS_SELECTOR,
+            "pseudo_element_selector" => CSS_PSEUDO_ELEMENT_SELECTOR,
+            "rule_set" => CSS_QUALIFIED_RULE,
+            "selectors" => CSS_SELECTOR_LIST,
+            "string_value" => CSS_STRING,
+            "stylesheet" => CSS_ROOT,
+            "supports_statement" => CSS_SUPPORTS_AT_RULE,
+            "tag_name" => CSS_TYPE_SELECTOR,
+            "universal_selector" => CSS_UNIVERSAL_SELECTOR,
+            _ => return None,
+        };
+
+        Some(kind)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, kind: GritTargetSyntaxKind) -> &'static str {
+        let Some(kind) = kind.as_css_kind() else {
+            return "(unexpected language)";
+        };
+
+        use CssSyntaxKind::*;
+        match kind {
+            CSS_PARAMETER_LIST => "arguments",
+            CSS_UNKNOWN_BLOCK_AT_RULE => "at_rule",
+            CSS_ATTRIBUTE_NAME => "attribute_name",
+            CSS_ATTRIBUTE_SELECTOR => "attribute_selector",
+            CSS_BINARY_EXPRESSION => "binary_expression",
+            CSS_DECLARATION_OR_RULE_BLOCK => "block",
+            CSS_FUNCTION => "call_expression",
+            CSS_CHARSET_AT_RULE => "charset_statement",
+            CSS_CLASS_SELECTOR => "class_selector",
+            CSS_COLOR => "color_value",
+            CSS_DECLARATION => "declaration",
+            CSS_ID_SELECTOR => "id_selector",
+            CSS_IMPORT_AT_RULE => "import_statement",
+            CSS_DECLARATION_IMPORTANT => "important",
+            CSS_KEYFRAMES_ITEM => "keyframe_block",
+            CSS_KEYFRAMES_BLOCK => "keyframe_block_list",
+            CSS_KEYFRAMES_AT_RULE => "keyframes_statement",
+            CSS_MEDIA_AT_RULE => "media_statement",
+            CSS_NAMESPACE_AT_RULE => "namespace_statement",
+            CSS_NESTED_SELECTOR => "nesting_selector",
+            CSS_PARENTHESIZED_EXPRESSION => "parenthesized_value",
+            CSS_PSEUDO_CLASS_SELECTOR => "pseudo_class_selector",
+            CSS_PSEUDO_ELEMENT_SELECTOR => "pseudo_element_selector",
+            CSS_QUALIFIED_RULE => "rule_set",
+            CSS_SELECTOR_LIST => "selectors",
+            CSS_STRING => "string_value",
+            CSS_ROOT => "stylesheet",
+            CSS_SUPPORTS_AT_RULE => "supports_statement",
+            CSS_TYPE_SELECTOR => "tag_name",
+            CSS_UNIVERSAL_SELECTOR => "universal_selector",
+            _ => "(unknown node)",
+        }
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_kind(&self, kind: GritTargetSyntaxKind) -> &'static [(&'static str, u32)] {
+        let Some(kind) = kind.as_css_kind() else {
+            return &[];
+        };
+
+        use CssSyntaxKind::*;
+        match kind {
+            CSS_ATTRIBUTE_SELECTOR => &[("name", 1), ("matcher", 2)],
+            CSS_BINARY_EXPRESSION => &[("left", 0), ("operator", 1), ("right", 2)],
+            CSS_CHARSET_AT_RULE => &[("encoding", 1)],
+            CSS_CLASS_SELECTOR => &[("name", 1)],
+            CSS_DECLARATION => &[("property", 0), ("important", 1)],
+            CSS_FUNCTION => &[("name", 0), ("arguments", 2)],
+            CSS_ID_SELECTOR => &[("name", 1)],
+            CSS_IMPORT_AT_RULE => &[("url", 1), ("layer", 2), ("supports", 3), ("media", 4)],
+            CSS_KEYFRAMES_AT_RULE => &[("name", 1), ("block", 2)],
+            CSS_KEYFRAMES_ITEM => &[("selectors", 0), ("block", 1)],
+            CSS_MEDIA_AT_RULE => &[("queries", 1), ("block", 2)],
+            CSS_NAMESPACE_AT_RULE => &[("prefix", 1), ("url", 2)],
+            CSS_PSEUDO_CLASS_SELECTOR => &[("class", 1)],
+            CSS_PSEUDO_ELEMENT_SELECTOR => &[("element", 1)],
+            CSS_QUALIFIED_RULE => &[("selectors", 0), ("block", 1)],
+            CSS_SUPPORTS_AT_RULE => &[("condition", 1), ("block", 2)],
+            CSS_UNKNOWN_BLOCK_AT_RULE => &[("name", 0), ("components", 1), ("block", 2)],
+            _ => &[],
+        }
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("GRIT_BLOCK { ", " }"),
+            ("GRIT_BLOCK { GRIT_PROPERTY: ", " }"),
+        ]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_css_kind()
+            .map_or(false, |kind| COMMENT_KINDS.matches(kind))
+    }
+
+    fn metavariable_kind() -> Self::Kind {
+        CssSyntaxKind::CSS_METAVARIABLE
+    }
+}
@@ -0,0 +1,96 @@
+use super::GritTargetLanguageImpl;
+use crate::grit_target_node::GritTargetSyntaxKind;
+use biome_json_syntax::{JsonLanguage, JsonSyntaxKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKindSet};
+
+const COMMENT_KINDS: SyntaxKindSet<JsonLanguage> =
+    SyntaxKindSet::from_raw(RawSyntaxKind(JsonSyntaxKind::COMMENT as u16)).union(
+        SyntaxKindSet::from_raw(RawSyntaxKind(JsonSyntaxKind::MULTILINE_COMMENT as u16)),
+    );
+
+#[derive(Clone, Debug)]
+pub struct JsonTargetLanguage;
+
+impl GritTargetLanguageImpl for JsonTargetLanguage {
+    type Kind = JsonSyntaxKind;
+
+    fn language_name(&self) -> &'static str {
+        "JSON"
+    }
+
+    /// Returns the syntax kind for a node by name.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_for_name()`.
+    fn kind_by_name(&self, node_name: &str) -> Option<JsonSyntaxKind> {
+        use JsonSyntaxKind::*;
+        let kind = match node_name {
+            "array" => JSON_ARRAY_VALUE,
+            "document" => JSON_ROOT,
+            "member_name" => JSON_MEMBER_NAME,
+            "null" => JSON_NULL_VALUE,
+            "number" => JSON_NUMBER_VALUE,
+            "object" => JSON_OBJECT_VALUE,
+            "pair" => JSON_MEMBER,
+            "string" => JSON_STRING_VALUE,
+            _ => return None,
+        };
+
+        Some(kind)
+    }
+
+    /// Returns the node name for a given syntax kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_symbol_name()`.
+    fn name_for_kind(&self, kind: GritTargetSyntaxKind) -> &'static str {
+        let Some(kind) = kind.as_json_kind() else {
+            return "(unexpected language)";
+        };
+
+        use JsonSyntaxKind::*;
+        match kind {
+            JSON_ARRAY_VALUE => "array",
+            JSON_ROOT => "document",
+            JSON_MEMBER_NAME => "member_name",
+            JSON_NULL_VALUE => "null",
+            JSON_NUMBER_VALUE => "number",
+            JSON_OBJECT_VALUE => "object",
+            JSON_MEMBER => "pair",
+            JSON_STRING_VALUE => "string",
+            _ => "(unknown node)",
+        }
+    }
+
+    /// Returns the slots with their names for the given node kind.
+    ///
+    /// For compatibility with existing Grit snippets (as well as the online
+    /// Grit playground), node names should be aligned with TreeSitter's
+    /// `ts_language_field_name_for_id()`.
+    fn named_slots_for_kind(&self, kind: GritTargetSyntaxKind) -> &'static [(&'static str, u32)] {
+        let Some(kind) = kind.as_json_kind() else {
+            return &[];
+        };
+
+        use JsonSyntaxKind::*;
+        match kind {
+            JSON_MEMBER => &[("key", 0), ("value", 2)],
+            _ => &[],
+        }
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[("", ""), ("{ ", " }"), ("[", "]")]
+    }
+
+    fn is_comment_kind(kind: GritTargetSyntaxKind) -> bool {
+        kind.as_json_kind()
+            .map_or(false, |kind| COMMENT_KINDS.matches(kind))
+    }
+
+    fn metavariable_kind() -> Self::Kind {
+        JsonSyntaxKind::JSON_METAVARIABLE
+    }
+}
@@ -1,6 +1,8 @@
 use crate::grit_tree::GritTargetTree;
 use crate::util::TextRangeGritExt;
+use biome_css_syntax::{CssSyntaxKind, CssSyntaxNode, CssSyntaxToken};
 use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_json_syntax::{JsonSyntaxKind, JsonSyntaxNode, JsonSyntaxToken};
 use biome_rowan::{NodeOrToken, SyntaxKind, SyntaxSlot, TextRange};
 use grit_util::{error::GritResult, AstCursor, AstNode as GritAstNode, ByteRange, CodeRange};
 use std::{borrow::Cow, fmt::Debug, ops::Deref};
@@ -33,6 +35,7 @@ use NodeOrToken::*;
 ///   literal, for instance.
 macro_rules! generate_target_node {
     ($([$lang:ident, $lang_node:ident, $lang_token:ident, $lang_kind:ident]),+) => {
+        #[allow(clippy::enum_variant_names)]
         #[derive(Clone, Debug, PartialEq)]
         pub enum GritTargetLanguageNode {
             $($lang(NodeOrToken<$lang_node, $lang_token>)),+
@@ -57,9 +60,9 @@ macro_rules! generate_target_node {
         })+
 
         impl GritTargetLanguageNode {
-            pub fn descendants(&self) -> Option<impl Iterator<Item = Self>> {
+            pub fn descendants(&self) -> Option<Box<dyn Iterator<Item = Self>>> {
                 match self {
-                    $(Self::$lang(Node(node)) => Some(node.descendants().map(Into::into))),+,
+                    $(Self::$lang(Node(node)) => Some(Box::new(node.descendants().map(Into::into)))),+,
                     _ => None
                 }
             }
@@ -129,13 +132,13 @@ macro_rules! generate_target_node {
                 }
             }
 
-            pub fn slots<'a>(&self, tree: &'a GritTargetTree) -> Option<impl Iterator<Item = GritSyntaxSlot<'a>>> {
+            pub fn slots<'a>(&self, tree: &'a GritTargetTree) -> Option<Box<dyn Iterator<Item = GritSyntaxSlot<'a>> + 'a>> {
                 match self {
-                    $(Self::$lang(Node(node)) => Some(node.slots().map(|slot| match slot {
+                    $(Self::$lang(Node(node)) => Some(Box::new(node.slots().map(|slot| match slot {
                         SyntaxSlot::Node(node) => GritSyntaxSlot::Node(GritTargetNode::new(node.into(), tree)),
                         SyntaxSlot::Token(token) => GritSyntaxSlot::Node(GritTargetNode::new(token.into(), tree)),
                         SyntaxSlot::Empty { index } => GritSyntaxSlot::Empty { index }
-                    }))),+,
+                    })))),+,
                     $(Self::$lang(Token(_token)) => None),+
                 }
             }
@@ -156,6 +159,7 @@ macro_rules! generate_target_node {
             }
         }
 
+        #[allow(clippy::enum_variant_names)]
         #[derive(Clone, Copy, Debug, PartialEq)]
         pub enum GritTargetSyntaxKind {
             $($lang_kind($lang_kind)),+
@@ -184,7 +188,9 @@ macro_rules! generate_target_node {
 }
 
 generate_target_node! {
-    [JsLanguage, JsSyntaxNode, JsSyntaxToken, JsSyntaxKind]
+    [CssLanguage, CssSyntaxNode, CssSyntaxToken, CssSyntaxKind],
+    [JsLanguage, JsSyntaxNode, JsSyntaxToken, JsSyntaxKind],
+    [JsonLanguage, JsonSyntaxNode, JsonSyntaxToken, JsonSyntaxKind]
 }
 
 #[derive(Clone, PartialEq)]
@@ -335,7 +341,7 @@ impl<'a> GritAstNode for GritTargetNode<'a> {
     }
 
     fn code_range(&self) -> CodeRange {
-        self.text_trimmed_range().to_code_range(self.text())
+        self.text_trimmed_range().to_code_range(self.source())
     }
 
     #[allow(refining_impl_trait)]
@@ -394,9 +400,24 @@ impl<'a> GritAstNode for GritTargetNode<'a> {
 }
 
 impl GritTargetSyntaxKind {
+    pub fn as_css_kind(&self) -> Option<CssSyntaxKind> {
+        match self {
+            Self::CssSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
     pub fn as_js_kind(&self) -> Option<JsSyntaxKind> {
         match self {
             Self::JsSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub fn as_json_kind(&self) -> Option<JsonSyntaxKind> {
+        match self {
+            Self::JsonSyntaxKind(kind) => Some(*kind),
+            _ => None,
         }
     }
 }
@@ -5,9 +5,11 @@ mod grit_binding;
 mod grit_built_in_functions;
 mod grit_code_snippet;
 mod grit_context;
+mod grit_css_parser;
 mod grit_definitions;
 mod grit_file;
 mod grit_js_parser;
+mod grit_json_parser;
 mod grit_node;
 mod grit_node_patterns;
 mod grit_query;
@@ -23,12 +25,17 @@ mod variables;
 pub use errors::*;
 pub use grit_context::GritTargetFile;
 pub use grit_query::{CreateFile, GritQuery, GritQueryResult, Message, OutputFile};
-pub use grit_target_language::{GritTargetLanguage, JsTargetLanguage};
+pub use grit_target_language::{
+    CssTargetLanguage, GritTargetLanguage, JsTargetLanguage, JsonTargetLanguage,
+};
 
 use biome_grit_parser::parse_grit;
 use std::path::Path;
 
 /// Compiles a Grit pattern from the given source string.
+///
+/// The `language` is used as the target language, unless the pattern declares
+/// its own through a `language` declaration.
 pub fn compile_pattern(
     source: &str,
     path: Option<&Path>,
@@ -1,3 +1,4 @@
+use biome_css_parser::{parse_css, CssParserOptions};
 use biome_diagnostics::Diagnostic;
 use biome_grit_parser::parse_grit;
 use biome_grit_patterns::{
@@ -6,6 +7,8 @@ use biome_grit_patterns::{
 };
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::JsFileSource;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_parser::AnyParse;
 use biome_test_utils::register_leak_checker;
 use grit_util::Range;
 use std::{fs::read_to_string, path::Path};
@@ -45,7 +48,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             );
         }
 
-        GritQuery::from_node(parse_grit_result.tree(), None, target_lang)
+        GritQuery::from_node(parse_grit_result.tree(), None, target_lang.clone())
             .unwrap_or_else(|err| panic!("cannot compile query from {query_path:?}: {err:?}"))
     };
 
@@ -54,16 +57,24 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         let target_path = Path::new(&target_path);
         let target_code = read_to_string(target_path)
             .unwrap_or_else(|err| panic!("failed to read code from {target_path:?}: {err:?}"));
-        // TODO: We should generalize this when we have more target languages.
-        let target_parse = parse(
-            &target_code,
-            JsFileSource::tsx(),
-            JsParserOptions::default(),
-        );
+        let target_parse: AnyParse = match target_lang {
+            GritTargetLanguage::CssTargetLanguage(_) => {
+                parse_css(&target_code, CssParserOptions::default()).into()
+            }
+            GritTargetLanguage::JsTargetLanguage(_) => parse(
+                &target_code,
+                JsFileSource::tsx(),
+                JsParserOptions::default(),
+            )
+            .into(),
+            GritTargetLanguage::JsonTargetLanguage(_) => {
+                parse_json(&target_code, JsonParserOptions::default()).into()
+            }
+        };
 
         GritTargetFile {
             path: target_path.into(),
-            parse: target_parse.into(),
+            parse: target_parse,
         }
     };
 
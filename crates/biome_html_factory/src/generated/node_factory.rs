@@ -63,17 +63,34 @@ pub fn html_closing_element(
 }
 pub fn html_comment(
     comment_start_token: SyntaxToken,
-    content_token: SyntaxToken,
     comment_end_token: SyntaxToken,
-) -> HtmlComment {
-    HtmlComment::unwrap_cast(SyntaxNode::new_detached(
-        HtmlSyntaxKind::HTML_COMMENT,
-        [
-            Some(SyntaxElement::Token(comment_start_token)),
-            Some(SyntaxElement::Token(content_token)),
-            Some(SyntaxElement::Token(comment_end_token)),
-        ],
-    ))
+) -> HtmlCommentBuilder {
+    HtmlCommentBuilder {
+        comment_start_token,
+        comment_end_token,
+        content_token: None,
+    }
+}
+pub struct HtmlCommentBuilder {
+    comment_start_token: SyntaxToken,
+    comment_end_token: SyntaxToken,
+    content_token: Option<SyntaxToken>,
+}
+impl HtmlCommentBuilder {
+    pub fn with_content_token(mut self, content_token: SyntaxToken) -> Self {
+        self.content_token = Some(content_token);
+        self
+    }
+    pub fn build(self) -> HtmlComment {
+        HtmlComment::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::HTML_COMMENT,
+            [
+                Some(SyntaxElement::Token(self.comment_start_token)),
+                self.content_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.comment_end_token)),
+            ],
+        ))
+    }
 }
 pub fn html_content(value_token: SyntaxToken) -> HtmlContent {
     HtmlContent::unwrap_cast(SyntaxNode::new_detached(
@@ -146,16 +163,34 @@ impl HtmlDirectiveBuilder {
 pub fn html_element(
     opening_element: HtmlOpeningElement,
     children: HtmlElementList,
-    closing_element: HtmlClosingElement,
-) -> HtmlElement {
-    HtmlElement::unwrap_cast(SyntaxNode::new_detached(
-        HtmlSyntaxKind::HTML_ELEMENT,
-        [
-            Some(SyntaxElement::Node(opening_element.into_syntax())),
-            Some(SyntaxElement::Node(children.into_syntax())),
-            Some(SyntaxElement::Node(closing_element.into_syntax())),
-        ],
-    ))
+) -> HtmlElementBuilder {
+    HtmlElementBuilder {
+        opening_element,
+        children,
+        closing_element: None,
+    }
+}
+pub struct HtmlElementBuilder {
+    opening_element: HtmlOpeningElement,
+    children: HtmlElementList,
+    closing_element: Option<HtmlClosingElement>,
+}
+impl HtmlElementBuilder {
+    pub fn with_closing_element(mut self, closing_element: HtmlClosingElement) -> Self {
+        self.closing_element = Some(closing_element);
+        self
+    }
+    pub fn build(self) -> HtmlElement {
+        HtmlElement::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::HTML_ELEMENT,
+            [
+                Some(SyntaxElement::Node(self.opening_element.into_syntax())),
+                Some(SyntaxElement::Node(self.children.into_syntax())),
+                self.closing_element
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
 }
 pub fn html_name(value_token: SyntaxToken) -> HtmlName {
     HtmlName::unwrap_cast(SyntaxNode::new_detached(
@@ -5,6 +5,8 @@ use biome_formatter::{
     FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, TransformSourceMap,
 };
 use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
+use biome_rowan::TextRange;
+use rustc_hash::FxHashMap;
 
 use crate::comments::{FormatHtmlLeadingComment, HtmlCommentStyle, HtmlComments};
 
@@ -147,6 +149,9 @@ pub struct HtmlFormatContext {
     comments: Rc<HtmlComments>,
 
     source_map: Option<TransformSourceMap>,
+
+    /// The formatted code of the `<script>` and `<style>` elements, by range of the elements
+    embedded_code: Rc<FxHashMap<TextRange, String>>,
 }
 
 impl HtmlFormatContext {
@@ -155,6 +160,7 @@ impl HtmlFormatContext {
             options,
             comments: Rc::new(comments),
             source_map: None,
+            embedded_code: Rc::default(),
         }
     }
 
@@ -162,6 +168,19 @@ impl HtmlFormatContext {
         self.source_map = source_map;
         self
     }
+
+    pub fn with_embedded_code(
+        mut self,
+        embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+    ) -> Self {
+        self.embedded_code = Rc::new(embedded_code.into_iter().collect());
+        self
+    }
+
+    /// Returns the formatted code of the `<script>` or `<style>` element at the given range, if any.
+    pub(crate) fn embedded_code(&self, range: TextRange) -> Option<&str> {
+        self.embedded_code.get(&range).map(String::as_str)
+    }
 }
 
 impl FormatContext for HtmlFormatContext {
@@ -1,6 +1,8 @@
 use crate::prelude::*;
+use crate::utils::metadata::has_preserved_content;
 use biome_formatter::write;
 use biome_html_syntax::{HtmlElement, HtmlElementFields};
+use biome_rowan::{Direction, SyntaxElement, TextSize};
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElement;
 impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
@@ -11,6 +13,38 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             closing_element,
         } = node.as_fields();
 
+        // The content of `<script>` and `<style>` is formatted with the formatter of its language
+        let embedded_code = f
+            .context()
+            .embedded_code(node.range())
+            .map(ToString::to_string);
+        if let Some(code) = embedded_code {
+            write!(f, [opening_element.format()])?;
+            for element in children.syntax().descendants_with_tokens(Direction::Next) {
+                match element {
+                    SyntaxElement::Token(token) => write!(f, [format_removed(&token)])?,
+                    SyntaxElement::Node(node) => {
+                        f.comments().mark_suppression_checked(&node);
+                    }
+                }
+            }
+            if !code.trim().is_empty() {
+                write!(
+                    f,
+                    [block_indent(&FormatEmbeddedCode {
+                        code: &code,
+                        source_position: children.range().start(),
+                    })]
+                )?;
+            }
+            return write!(f, [closing_element.format()]);
+        }
+
+        // The whitespace of the content of `<pre>`, `<textarea>`, etc. is significant
+        if has_preserved_content(node) {
+            return format_suppressed_node(node.syntax()).fmt(f);
+        }
+
         write!(
             f,
             [
@@ -23,3 +57,36 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
         Ok(())
     }
 }
+
+/// Prints the lines of formatted code, keeping at most one empty line between them.
+struct FormatEmbeddedCode<'a> {
+    code: &'a str,
+    source_position: TextSize,
+}
+
+impl Format<HtmlFormatContext> for FormatEmbeddedCode<'_> {
+    fn fmt(&self, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let mut is_first_line = true;
+        let mut has_empty_lines = false;
+        for line in self.code.lines() {
+            if line.trim().is_empty() {
+                has_empty_lines = true;
+                continue;
+            }
+
+            if !is_first_line {
+                if has_empty_lines {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            write!(f, [dynamic_text(line, self.source_position)])?;
+
+            is_first_line = false;
+            has_empty_lines = false;
+        }
+
+        Ok(())
+    }
+}
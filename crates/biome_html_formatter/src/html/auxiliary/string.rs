@@ -18,9 +18,12 @@ impl FormatNodeRule<HtmlString> for FormatHtmlString {
                     && value_text.ends_with('\'')
                     && !contains_double_quote
                 {
-                    value.text_range().add_start(1.into()).sub_end(1.into())
+                    value
+                        .text_trimmed_range()
+                        .add_start(1.into())
+                        .sub_end(1.into())
                 } else {
-                    value.text_range()
+                    value.text_trimmed_range()
                 };
 
                 if !contains_double_quote {
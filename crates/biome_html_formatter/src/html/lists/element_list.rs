@@ -5,13 +5,17 @@ use std::cell::RefCell;
 use crate::{
     comments::HtmlComments,
     prelude::*,
-    utils::children::{
-        html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator, HtmlSpace,
+    utils::{
+        children::{
+            html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator,
+            HtmlSpace,
+        },
+        metadata::{is_inline_element, is_whitespace_sensitive},
     },
 };
 use biome_formatter::{best_fitting, prelude::*, CstFormatContext};
 use biome_formatter::{format_args, write, VecBuffer};
-use biome_html_syntax::{AnyHtmlElement, HtmlElementList, HtmlRoot};
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlElementList, HtmlRoot};
 use tag::GroupMode;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElementList {
@@ -83,13 +87,32 @@ impl FormatHtmlElementList {
             .parent()
             .is_some_and(|parent| HtmlRoot::can_cast(parent.kind()));
 
+        let mut children = html_split_children(list.iter(), f.context().comments())?;
+
+        // The children of an inline element can't be moved to their own lines
+        // when there's no whitespace around them, because it would add whitespace.
+        // ```html
+        // <span><b>a</b></span>
+        // ```
+        let is_inline_parent = list
+            .syntax()
+            .parent()
+            .and_then(HtmlElement::cast)
+            .is_some_and(|parent| is_inline_element(&parent));
+        let is_surrounded_by_whitespace = matches!(
+            children.first(),
+            Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine)
+        ) && matches!(
+            children.last(),
+            Some(HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine)
+        );
+        let block_indent = !is_root_parent && (!is_inline_parent || is_surrounded_by_whitespace);
+
         let mut flat = FlatBuilder::new();
-        let mut multiline = MultilineBuilder::new(multiline_layout, is_root_parent);
+        let mut multiline = MultilineBuilder::new(multiline_layout, block_indent);
 
         let mut force_multiline = layout.is_multiline();
 
-        let mut children = html_split_children(list.iter(), f.context().comments())?;
-
         // Trim trailing new lines
         if let Some(HtmlChild::EmptyLine | HtmlChild::Newline) = children.last() {
             children.pop();
@@ -114,6 +137,13 @@ impl FormatHtmlElementList {
                             Some(WordSeparator::BetweenWords)
                         }
 
+                        // Last word before an inline element without any whitespace in between
+                        Some(HtmlChild::NonText(next_child))
+                            if is_whitespace_sensitive(next_child) =>
+                        {
+                            Some(WordSeparator::Empty)
+                        }
+
                         // Last word or last word before an element without any whitespace in between
                         Some(HtmlChild::NonText(next_child)) => Some(WordSeparator::EndOfText {
                             is_soft_line_break: !matches!(
@@ -221,7 +251,22 @@ impl FormatHtmlElementList {
 
                 // Any child that isn't text
                 HtmlChild::NonText(non_text) => {
-                    let line_mode = match children_iter.peek() {
+                    let separator = match children_iter.peek() {
+                        // An inline element followed by a word or another inline element without
+                        // any whitespace in between must stay on the same line
+                        // ```html
+                        // <b>a</b>, <b>b</b><i>c</i>
+                        // ```
+                        Some(HtmlChild::Word(_)) if is_whitespace_sensitive(non_text) => {
+                            Some(NonTextSeparator::Empty)
+                        }
+                        Some(HtmlChild::NonText(next_child))
+                            if is_whitespace_sensitive(non_text)
+                                && is_whitespace_sensitive(next_child) =>
+                        {
+                            Some(NonTextSeparator::Empty)
+                        }
+
                         Some(HtmlChild::Word(word)) => {
                             // Break if the current or next element is a self closing element
                             // ```javascript
@@ -235,14 +280,14 @@ impl FormatHtmlElementList {
                             if matches!(non_text, AnyHtmlElement::HtmlSelfClosingElement(_))
                                 && !word.is_single_character()
                             {
-                                Some(LineMode::Hard)
+                                Some(NonTextSeparator::Line(LineMode::Hard))
                             } else {
-                                Some(LineMode::Soft)
+                                Some(NonTextSeparator::Line(LineMode::Soft))
                             }
                         }
 
                         // Add a hard line break if what comes after the element is not a text or is all whitespace
-                        Some(HtmlChild::NonText(_)) => Some(LineMode::Hard),
+                        Some(HtmlChild::NonText(_)) => Some(NonTextSeparator::Line(LineMode::Hard)),
 
                         Some(HtmlChild::Newline | HtmlChild::Whitespace | HtmlChild::EmptyLine) => {
                             None
@@ -251,19 +296,11 @@ impl FormatHtmlElementList {
                         None => None,
                     };
 
-                    child_breaks = line_mode.map_or(false, |mode| mode.is_hard());
-
-                    let format_separator = line_mode.map(|mode| {
-                        format_with(move |f| f.write_element(FormatElement::Line(mode)))
-                    });
+                    child_breaks = separator.map_or(false, |separator| separator.will_break());
 
                     if force_multiline {
-                        if let Some(format_separator) = format_separator {
-                            multiline.write_with_separator(
-                                &non_text.format(),
-                                &format_separator,
-                                f,
-                            );
+                        if let Some(separator) = separator {
+                            multiline.write_with_separator(&non_text.format(), &separator, f);
                         } else {
                             // it's safe to write without a separator because None means that next element is a separator or end of the iterator
                             multiline.write_content(&non_text.format(), f);
@@ -272,10 +309,10 @@ impl FormatHtmlElementList {
                         let mut memoized = non_text.format().memoized();
 
                         force_multiline = memoized.inspect(f)?.will_break();
-                        flat.write(&format_args![memoized, format_separator], f);
+                        flat.write(&format_args![memoized, separator], f);
 
-                        if let Some(format_separator) = format_separator {
-                            multiline.write_with_separator(&memoized, &format_separator, f);
+                        if let Some(separator) = separator {
+                            multiline.write_with_separator(&memoized, &separator, f);
                         } else {
                             // it's safe to write without a separator because None means that next element is a separator or end of the iterator
                             multiline.write_content(&memoized, f);
@@ -428,6 +465,14 @@ enum WordSeparator {
     /// );
     /// ```
     EndOfText { is_soft_line_break: bool },
+
+    /// A separator of a word directly followed by an inline element. It prints nothing, because
+    /// a line break between them would be rendered as a space.
+    ///
+    /// ```html
+    /// <div>a<b>b</b></div>
+    /// ```
+    Empty,
 }
 
 impl WordSeparator {
@@ -465,6 +510,34 @@ impl Format<HtmlFormatContext> for WordSeparator {
                     hard_line_break().fmt(f)
                 }
             }
+            WordSeparator::Empty => Ok(()),
+        }
+    }
+}
+
+/// The separator after a child that isn't a text.
+#[derive(Copy, Clone, Debug)]
+enum NonTextSeparator {
+    /// A line break of the given mode
+    Line(LineMode),
+
+    /// Nothing, because the child is an inline element directly followed by a word or
+    /// another inline element
+    Empty,
+}
+
+impl NonTextSeparator {
+    /// Returns if formatting this separator will result in a child that expands
+    fn will_break(&self) -> bool {
+        matches!(self, NonTextSeparator::Line(LineMode::Hard))
+    }
+}
+
+impl Format<HtmlFormatContext> for NonTextSeparator {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        match self {
+            NonTextSeparator::Line(mode) => f.write_element(FormatElement::Line(*mode)),
+            NonTextSeparator::Empty => Ok(()),
         }
     }
 }
@@ -491,15 +564,15 @@ enum MultilineLayout {
 #[derive(Debug, Clone)]
 struct MultilineBuilder {
     layout: MultilineLayout,
-    is_root: bool,
+    block_indent: bool,
     result: FormatResult<Vec<FormatElement>>,
 }
 
 impl MultilineBuilder {
-    fn new(layout: MultilineLayout, is_root: bool) -> Self {
+    fn new(layout: MultilineLayout, block_indent: bool) -> Self {
         Self {
             layout,
-            is_root,
+            block_indent,
             result: Ok(Vec::new()),
         }
     }
@@ -570,7 +643,7 @@ impl MultilineBuilder {
     fn finish(self) -> FormatResult<FormatMultilineChildren> {
         Ok(FormatMultilineChildren {
             layout: self.layout,
-            is_root: self.is_root,
+            block_indent: self.block_indent,
             elements: RefCell::new(self.result?),
         })
     }
@@ -579,7 +652,9 @@ impl MultilineBuilder {
 #[derive(Debug)]
 pub(crate) struct FormatMultilineChildren {
     layout: MultilineLayout,
-    is_root: bool,
+    /// `false` when the list node is at the html root node, or when it's the content of an inline element
+    /// that isn't surrounded by whitespace
+    block_indent: bool,
     elements: RefCell<Vec<FormatElement>>,
 }
 
@@ -605,8 +680,9 @@ impl Format<HtmlFormatContext> for FormatMultilineChildren {
 
             Ok(())
         });
-        // We do not need the block ident when the list node is at the html root node
-        if self.is_root {
+        // We do not need the block ident when the list node is at the html root node,
+        // and we can't add one around the content of an inline element without changing its rendering
+        if !self.block_indent {
             return write!(f, [format_inner]);
         }
 
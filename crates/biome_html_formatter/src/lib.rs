@@ -2,11 +2,12 @@ use biome_formatter::comments::Comments;
 use biome_formatter::{prelude::*, CstFormatContext, FormatOwnedWithRule, FormatRefWithRule};
 use biome_formatter::{write, FormatLanguage, FormatResult, FormatToken, Formatted};
 use biome_html_syntax::{HtmlLanguage, HtmlSyntaxNode, HtmlSyntaxToken};
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, TextRange};
 use comments::HtmlCommentStyle;
 use context::HtmlFormatContext;
 pub use context::HtmlFormatOptions;
 use cst::FormatHtmlSyntaxNode;
+use rustc_hash::FxHashMap;
 
 mod comments;
 pub mod context;
@@ -26,6 +27,21 @@ pub fn format_node(
     biome_formatter::format_node(root, HtmlFormatLanguage::new(options))
 }
 
+/// Formats a Html file, and prints the given formatted code for the `<script>` and `<style>`
+/// elements instead of their content.
+///
+/// The formatted code is keyed by the range of the elements, see [HtmlFormatLanguage::with_embedded_code].
+pub fn format_node_with_embedded_code(
+    options: HtmlFormatOptions,
+    root: &HtmlSyntaxNode,
+    embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+) -> FormatResult<Formatted<HtmlFormatContext>> {
+    biome_formatter::format_node(
+        root,
+        HtmlFormatLanguage::new(options).with_embedded_code(embedded_code),
+    )
+}
+
 /// Used to get an object that knows how to format this object.
 pub(crate) trait AsFormat<Context> {
     type Format<'a>: biome_formatter::Format<Context>
@@ -116,11 +132,27 @@ where
 #[derive(Debug, Clone)]
 pub struct HtmlFormatLanguage {
     options: HtmlFormatOptions,
+    /// The formatted code of the `<script>` and `<style>` elements, by range of the elements
+    embedded_code: FxHashMap<TextRange, String>,
 }
 
 impl HtmlFormatLanguage {
     pub fn new(options: HtmlFormatOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            embedded_code: FxHashMap::default(),
+        }
+    }
+
+    /// Sets the formatted code of the `<script>` and `<style>` elements, by range of the elements.
+    ///
+    /// The content of the other `<script>` and `<style>` elements is printed as is.
+    pub fn with_embedded_code(
+        mut self,
+        embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+    ) -> Self {
+        self.embedded_code = embedded_code.into_iter().collect();
+        self
     }
 }
 
@@ -139,7 +171,9 @@ impl FormatLanguage for HtmlFormatLanguage {
         source_map: Option<biome_formatter::TransformSourceMap>,
     ) -> Self::Context {
         let comments = Comments::from_node(root, &HtmlCommentStyle, source_map.as_ref());
-        HtmlFormatContext::new(self.options, comments).with_source_map(source_map)
+        HtmlFormatContext::new(self.options, comments)
+            .with_source_map(source_map)
+            .with_embedded_code(self.embedded_code)
     }
 }
 
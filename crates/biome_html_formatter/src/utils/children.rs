@@ -275,7 +275,19 @@ where
                     }
                 }
 
+                // The whitespace after the element is the trailing trivia of its last token
+                let trailing_whitespace =
+                    &text[text.trim_end_matches([' ', '\n', '\t', '\r']).len()..];
+
                 builder.entry(HtmlChild::NonText(child));
+
+                if !trailing_whitespace.is_empty() {
+                    if trailing_whitespace.contains('\n') {
+                        builder.entry(HtmlChild::Newline);
+                    } else {
+                        builder.entry(HtmlChild::Whitespace);
+                    }
+                }
                 prev_was_content = false;
             }
         }
@@ -285,7 +297,8 @@ where
 }
 
 /// The builder is used to:
-/// 1. Remove [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace] if a next element is [HtmlChild::Whitespace]
+/// 1. Remove [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace] if a next element is [HtmlChild::Whitespace],
+///    and replace [HtmlChild::Whitespace] with the next [HtmlChild::EmptyLine] or [HtmlChild::Newline]
 /// 2. Don't push a new element [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace] if previous one is [HtmlChild::EmptyLine], [HtmlChild::Newline], [HtmlChild::Whitespace]
 ///
 /// [Prettier applies]: https://github.com/prettier/prettier/blob/b0d9387b95cdd4e9d50f5999d3be53b0b5d03a97/src/language-js/print/jsx.js#L144-L180
@@ -302,7 +315,10 @@ impl HtmlSplitChildrenBuilder {
     fn entry(&mut self, child: HtmlChild) {
         match self.buffer.last_mut() {
             Some(last @ (HtmlChild::EmptyLine | HtmlChild::Newline | HtmlChild::Whitespace)) => {
-                if matches!(child, HtmlChild::Whitespace) {
+                if matches!(child, HtmlChild::Whitespace)
+                    || matches!(last, HtmlChild::Whitespace)
+                        && matches!(child, HtmlChild::Newline | HtmlChild::EmptyLine)
+                {
                     *last = child;
                 } else if matches!(child, HtmlChild::NonText(_) | HtmlChild::Word(_)) {
                     self.buffer.push(child);
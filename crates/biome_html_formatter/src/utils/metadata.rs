@@ -0,0 +1,44 @@
+use biome_html_syntax::{AnyHtmlElement, HtmlElement};
+
+/// The elements that are displayed inline by default. The whitespace around and inside them is
+/// significant, because it's rendered.
+///
+/// See: https://developer.mozilla.org/en-US/docs/Web/HTML/Inline_elements
+static INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "acronym", "audio", "b", "bdi", "bdo", "big", "br", "button", "canvas", "cite",
+    "code", "data", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd",
+    "label", "map", "mark", "meter", "object", "output", "picture", "progress", "q", "ruby", "s",
+    "samp", "select", "slot", "small", "span", "strong", "sub", "sup", "svg", "textarea", "time",
+    "tt", "u", "var", "video", "wbr",
+];
+
+/// The elements whose content is printed as it is, because its whitespace is significant or
+/// because it isn't HTML.
+static PRESERVED_CONTENT_ELEMENTS: &[&str] = &["pre", "textarea", "script", "style", "title"];
+
+/// Returns `true` if adding or removing whitespace around the element changes how it's rendered.
+///
+/// This is the case of the inline elements, and of the text and comments.
+pub(crate) fn is_whitespace_sensitive(element: &AnyHtmlElement) -> bool {
+    match element {
+        AnyHtmlElement::HtmlElement(element) => is_inline_element(element),
+        AnyHtmlElement::HtmlSelfClosingElement(element) => {
+            INLINE_ELEMENTS.iter().any(|name| element.has_name(name))
+        }
+        AnyHtmlElement::HtmlContent(_)
+        | AnyHtmlElement::HtmlComment(_)
+        | AnyHtmlElement::HtmlBogusElement(_) => true,
+    }
+}
+
+/// Returns `true` if the element is displayed inline by default.
+pub(crate) fn is_inline_element(element: &HtmlElement) -> bool {
+    INLINE_ELEMENTS.iter().any(|name| element.has_name(name))
+}
+
+/// Returns `true` if the content of the element must be printed as it is.
+pub(crate) fn has_preserved_content(element: &HtmlElement) -> bool {
+    PRESERVED_CONTENT_ELEMENTS
+        .iter()
+        .any(|name| element.has_name(name))
+}
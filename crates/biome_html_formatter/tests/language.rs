@@ -1,10 +1,17 @@
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_formatter::{FormatResult, Formatted};
 use biome_formatter_test::TestFormatLanguage;
 use biome_fs::BiomePath;
 use biome_html_formatter::context::HtmlFormatContext;
 use biome_html_formatter::HtmlFormatLanguage;
 use biome_html_parser::parse_html;
-use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
+use biome_html_syntax::{HtmlElement, HtmlFileSource, HtmlLanguage, HtmlSyntaxNode};
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
 use biome_parser::AnyParse;
+use biome_rowan::{AstNode, TextRange};
 use biome_service::{
     settings::{ServiceLanguage, Settings},
     workspace::DocumentFileSource,
@@ -30,6 +37,15 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
         parse_html(text).into()
     }
 
+    fn format_node(
+        &self,
+        language: Self::FormatLanguage,
+        node: &HtmlSyntaxNode,
+    ) -> FormatResult<Formatted<Self::Context>> {
+        let language = language.with_embedded_code(format_embedded_code(node));
+        biome_formatter::format_node(node, language)
+    }
+
     fn to_format_language(
         &self,
         settings: &Settings,
@@ -45,3 +61,40 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
         HtmlFormatLanguage::new(options)
     }
 }
+
+/// Formats the content of the `<script>` and `<style>` elements without a `type`, like the
+/// workspace does with the default settings.
+fn format_embedded_code(root: &HtmlSyntaxNode) -> Vec<(TextRange, String)> {
+    root.descendants()
+        .filter_map(HtmlElement::cast)
+        .filter(|element| element.find_attribute_by_name("type").is_none())
+        .filter_map(|element| {
+            let content = element.children().syntax().to_string();
+            let code = if element.is_style_tag() {
+                let parse = parse_css(&content, CssParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let formatted =
+                    biome_css_formatter::format_node(CssFormatOptions::default(), &parse.syntax())
+                        .ok()?;
+                formatted.print().ok()?.into_code()
+            } else if element.is_script_tag() {
+                let source_type = JsFileSource::js_script();
+                let parse = parse(&content, source_type, JsParserOptions::default());
+                if parse.has_errors() {
+                    return None;
+                }
+                let formatted = biome_js_formatter::format_node(
+                    JsFormatOptions::new(source_type),
+                    &parse.syntax(),
+                )
+                .ok()?;
+                formatted.print().ok()?.into_code()
+            } else {
+                return None;
+            };
+            Some((element.range(), code))
+        })
+        .collect()
+}
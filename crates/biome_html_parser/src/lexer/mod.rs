@@ -1,6 +1,6 @@
 mod tests;
 
-use crate::token_source::{HtmlEmbededLanguage, HtmlLexContext};
+use crate::token_source::HtmlLexContext;
 use biome_html_syntax::HtmlSyntaxKind::{
     DOCTYPE_KW, EOF, ERROR_TOKEN, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL, NEWLINE, TOMBSTONE,
     UNICODE_BOM, WHITESPACE,
@@ -10,8 +10,7 @@ use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
 use biome_rowan::SyntaxKind;
 use biome_unicode_table::lookup_byte;
-use biome_unicode_table::Dispatch::{BSL, QOT, UNI};
-use std::ops::Add;
+use biome_unicode_table::Dispatch::{QOT, UNI};
 
 pub(crate) struct HtmlLexer<'src> {
     /// Source text
@@ -80,7 +79,7 @@ impl<'src> HtmlLexer<'src> {
     fn consume_token_outside_tag(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
             b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
-            b'<' => self.consume_l_angle(),
+            b'<' if self.at_tag_start() => self.consume_l_angle(),
             _ => self.consume_html_text(),
         }
     }
@@ -92,10 +91,52 @@ impl<'src> HtmlLexer<'src> {
             b'<' => self.consume_byte(T![<]),
             b'>' => self.consume_byte(T![>]),
             b'\'' | b'"' => self.consume_string_literal(current),
+            b'{' => self
+                .consume_expression_attribute_value()
+                .unwrap_or_else(|| self.consume_unquoted_string_literal()),
             _ => self.consume_unquoted_string_literal(),
         }
     }
 
+    /// Consume an attribute value between braces, such as `{() => select(item)}`.
+    ///
+    /// The attribute values of the templates of Svelte and Astro components are expressions
+    /// between braces, which can contain whitespace and quotes. The value ends at the brace that
+    /// closes the opening brace, ignoring the braces of the strings of the expression.
+    ///
+    /// Returns `None` without consuming anything if the opening brace is never closed.
+    fn consume_expression_attribute_value(&mut self) -> Option<HtmlSyntaxKind> {
+        self.assert_byte(b'{');
+
+        let bytes = &self.source.as_bytes()[self.position..];
+        let mut depth = 0usize;
+        let mut quote = None;
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            match quote {
+                Some(_) if byte == b'\\' => index += 1,
+                Some(quote_byte) if byte == quote_byte => quote = None,
+                Some(_) => {}
+                None => match byte {
+                    b'\'' | b'"' | b'`' => quote = Some(byte),
+                    b'{' => depth += 1,
+                    b'}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            self.advance(index + 1);
+                            return Some(HTML_STRING_LITERAL);
+                        }
+                    }
+                    _ => {}
+                },
+            }
+            index += 1;
+        }
+
+        None
+    }
+
     /// Consume a token in the [HtmlLexContext::Doctype] context.
     fn consume_token_doctype(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -111,18 +152,12 @@ impl<'src> HtmlLexer<'src> {
         }
     }
 
-    /// Consume an embedded language in its entirety. Stops immediately before the closing tag.
-    fn consume_token_embedded_language(
-        &mut self,
-        _current: u8,
-        lang: HtmlEmbededLanguage,
-    ) -> HtmlSyntaxKind {
+    /// Consume the text of a raw text element in its entirety, for instance the code of an
+    /// embedded language. Stops immediately before the end tag.
+    fn consume_raw_text(&mut self, end_tag: &str) -> HtmlSyntaxKind {
         let start = self.text_position();
-        let end_tag = lang.end_tag();
         while self.current_byte().is_some() {
-            if self.source[self.position..(self.position + end_tag.len())]
-                .eq_ignore_ascii_case(end_tag)
-            {
+            if self.at_end_tag(end_tag) {
                 break;
             }
             self.advance(1);
@@ -137,6 +172,34 @@ impl<'src> HtmlLexer<'src> {
         }
     }
 
+    /// Returns `true` if the lexer is at the end tag that starts with `end_tag`, like `</script>`.
+    ///
+    /// The name of the tag is case-insensitive, and can be followed by whitespace.
+    fn at_end_tag(&self, end_tag: &str) -> bool {
+        let Some(text) = self.source.as_bytes().get(self.position..) else {
+            return false;
+        };
+
+        text.len() > end_tag.len()
+            && text[..end_tag.len()].eq_ignore_ascii_case(end_tag.as_bytes())
+            && matches!(
+                text[end_tag.len()],
+                b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r'
+            )
+    }
+
+    /// Returns `true` if the `<` at the current position starts a tag, a comment or a doctype.
+    ///
+    /// Otherwise the `<` is text, like in `a < b`.
+    ///
+    /// See: https://html.spec.whatwg.org/#data-state
+    fn at_tag_start(&self) -> bool {
+        self.current_byte() == Some(b'<')
+            && self
+                .byte_at(1)
+                .is_some_and(|byte| byte.is_ascii_alphabetic() || matches!(byte, b'/' | b'!'))
+    }
+
     /// Consume a token in the [HtmlLexContext::Comment] context.
     fn consume_inside_comment(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -206,63 +269,27 @@ impl<'src> HtmlLexer<'src> {
         }
 
         match &buffer[..len] {
-            b"doctype" | b"DOCTYPE" => DOCTYPE_KW,
-            b"html" | b"HTML" if doctype_context => HTML_KW,
+            keyword if keyword.eq_ignore_ascii_case(b"doctype") => DOCTYPE_KW,
+            keyword if doctype_context && keyword.eq_ignore_ascii_case(b"html") => HTML_KW,
             _ => HTML_LITERAL,
         }
     }
 
+    /// Consume a quoted attribute value or doctype identifier.
+    ///
+    /// There are no escape sequences in HTML strings: a backslash is a regular character, and
+    /// quotes are written with character references like `&quot;`.
     fn consume_string_literal(&mut self, quote: u8) -> HtmlSyntaxKind {
         self.assert_current_char_boundary();
         let start = self.text_position();
 
         self.advance(1); // Skip over the quote
-        let mut state = LexStringState::InString;
 
         while let Some(chr) = self.current_byte() {
-            let dispatch = lookup_byte(chr);
-
-            match dispatch {
+            match lookup_byte(chr) {
                 QOT if quote == chr => {
                     self.advance(1);
-                    state = match state {
-                        LexStringState::InString => LexStringState::Terminated,
-                        state => state,
-                    };
-                    break;
-                }
-                // '\t' etc
-                BSL => {
-                    self.advance(1);
-
-                    match self.current_byte() {
-                        Some(b'\n' | b'\r') => self.advance(1),
-
-                        // Handle escaped `'` but only if this is a end quote string.
-                        Some(b'\'') if quote == b'\'' => {
-                            self.advance(1);
-                        }
-
-                        // Handle escaped `'` but only if this is a end quote string.
-                        Some(b'"') if quote == b'"' => {
-                            self.advance(1);
-                        }
-
-                        Some(b'u') => match (self.consume_unicode_escape(), state) {
-                            (Ok(_), _) => {}
-                            (Err(err), LexStringState::InString) => {
-                                self.diagnostics.push(err);
-                                state = LexStringState::InvalidEscapeSequence;
-                            }
-                            (Err(_), _) => {}
-                        },
-
-                        Some(chr) => {
-                            self.advance_byte_or_char(chr);
-                        }
-
-                        None => {}
-                    }
+                    return HTML_STRING_LITERAL;
                 }
                 // we don't need to handle IDT because it's always len 1.
                 UNI => self.advance_char_unchecked(),
@@ -271,21 +298,14 @@ impl<'src> HtmlLexer<'src> {
             }
         }
 
-        match state {
-            LexStringState::Terminated => HTML_STRING_LITERAL,
-            LexStringState::InString => {
-                let unterminated =
-                    ParseDiagnostic::new("Missing closing quote", start..self.text_position())
-                        .with_detail(
-                            self.source.text_len()..self.source.text_len(),
-                            "file ends here",
-                        );
-                self.diagnostics.push(unterminated);
-
-                ERROR_TOKEN
-            }
-            LexStringState::InvalidEscapeSequence => ERROR_TOKEN,
-        }
+        let unterminated =
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.source.text_len()..self.source.text_len(),
+                "file ends here",
+            );
+        self.diagnostics.push(unterminated);
+
+        ERROR_TOKEN
     }
 
     /// Consume an attribute value that is not quoted.
@@ -299,15 +319,14 @@ impl<'src> HtmlLexer<'src> {
                 // these characters safely terminate an unquoted attribute value
                 b'\n' | b'\r' | b'\t' | b' ' | b'>' => break,
                 // these characters are absolutely invalid in an unquoted attribute value
-                b'?' | b'\'' | b'"' | b'=' | b'<' | b'`' => {
+                b'\'' | b'"' | b'=' | b'<' | b'`' => {
                     encountered_invalid = true;
                     break;
                 }
-                _ if current.is_ascii() => {
-                    self.advance(1);
+                _ => {
+                    self.advance_byte_or_char(current);
                     content_started = true;
                 }
-                _ => break,
             }
         }
 
@@ -360,54 +379,6 @@ impl<'src> HtmlLexer<'src> {
         T![-->]
     }
 
-    /// Lexes a `\u0000` escape sequence. Assumes that the lexer is positioned at the `u` token.
-    ///
-    /// A unicode escape sequence must consist of 4 hex characters.
-    fn consume_unicode_escape(&mut self) -> Result<(), ParseDiagnostic> {
-        self.assert_byte(b'u');
-        self.assert_current_char_boundary();
-
-        let start = self.text_position();
-
-        let start = start
-            // Subtract 1 to get position of `\`
-            .checked_sub(TextSize::from(1))
-            .unwrap_or(start);
-
-        self.advance(1); // Advance over `u`
-
-        for _ in 0..4 {
-            match self.current_byte() {
-                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
-                Some(_) => {
-                    let char = self.current_char_unchecked();
-                    // Reached a non-hex digit which is invalid
-                    return Err(ParseDiagnostic::new(
-
-                        "Invalid unicode sequence",
-                        start..self.text_position(),
-                    )
-                        .with_detail(self.text_position()..self.text_position().add(char.text_len()), "Non hexadecimal number")
-                        .with_hint("A unicode escape sequence must consist of 4 hexadecimal numbers: `\\uXXXX`, e.g. `\\u002F' for '/'."));
-                }
-                None => {
-                    // Reached the end of the file before processing 4 hex digits
-                    return Err(ParseDiagnostic::new(
-                        "Unicode escape sequence with two few hexadecimal numbers.",
-                        start..self.text_position(),
-                    )
-                        .with_detail(
-                            self.text_position()..self.text_position(),
-                            "reached the end of the file",
-                        )
-                        .with_hint("A unicode escape sequence must consist of 4 hexadecimal numbers: `\\uXXXX`, e.g. `\\u002F' for '/'."));
-                }
-            }
-        }
-
-        Ok(())
-    }
-
     /// Consume HTML text literals outside of tags.
     ///
     /// This includes text and single spaces between words. If newline or a second
@@ -420,7 +391,7 @@ impl<'src> HtmlLexer<'src> {
         let mut saw_space = false;
         while let Some(current) = self.current_byte() {
             match current {
-                b'<' => break,
+                b'<' if self.at_tag_start() => break,
                 b'\n' | b'\r' => {
                     self.after_newline = true;
                     break;
@@ -475,8 +446,9 @@ impl<'src> Lexer<'src> for HtmlLexer<'src> {
                     HtmlLexContext::OutsideTag => self.consume_token_outside_tag(current),
                     HtmlLexContext::AttributeValue => self.consume_token_attribute_value(current),
                     HtmlLexContext::Doctype => self.consume_token_doctype(current),
-                    HtmlLexContext::EmbeddedLanguage(lang) => {
-                        self.consume_token_embedded_language(current, lang)
+                    HtmlLexContext::EmbeddedLanguage(lang) => self.consume_raw_text(lang.end_tag()),
+                    HtmlLexContext::EscapableRawText(element) => {
+                        self.consume_raw_text(element.end_tag())
                     }
                     HtmlLexContext::Comment => self.consume_inside_comment(current),
                 },
@@ -561,18 +533,6 @@ fn is_attribute_name_byte(byte: u8) -> bool {
         )
 }
 
-#[derive(Copy, Clone, Debug)]
-enum LexStringState {
-    /// String that contains an invalid escape sequence
-    InvalidEscapeSequence,
-
-    /// Between the opening `"` and closing `"` quotes.
-    InString,
-
-    /// Properly terminated string
-    Terminated,
-}
-
 impl<'src> LexerWithCheckpoint<'src> for HtmlLexer<'src> {
     fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
         LexerCheckpoint {
@@ -2,7 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{HtmlLexer, TextSize};
-use crate::token_source::HtmlLexContext;
+use crate::token_source::{HtmlEmbededLanguage, HtmlEscapableRawTextElement, HtmlLexContext};
 use biome_html_syntax::HtmlSyntaxKind::{self, *};
 use biome_parser::lexer::Lexer;
 use quickcheck_macros::quickcheck;
@@ -128,6 +128,16 @@ fn string_literal() {
     }
 }
 
+#[test]
+fn string_literal_with_backslash() {
+    assert_lex! {
+        "\"C:\\path\\\" a",
+        HTML_STRING_LITERAL: 10,
+        WHITESPACE: 1,
+        HTML_LITERAL: 1,
+    }
+}
+
 #[test]
 fn self_closing() {
     assert_lex! {
@@ -163,6 +173,39 @@ fn html_text() {
     }
 }
 
+#[test]
+fn html_text_less_than() {
+    assert_lex! {
+        HtmlLexContext::OutsideTag,
+        "a < b<div>",
+        HTML_LITERAL: 5,
+        L_ANGLE: 1,
+        HTML_LITERAL: 4,
+    }
+}
+
+#[test]
+fn embedded_script_end_tag() {
+    assert_lex! {
+        HtmlLexContext::EmbeddedLanguage(HtmlEmbededLanguage::Script),
+        "a = '</scripts>';</SCRIPT >",
+        HTML_LITERAL: 17,
+        L_ANGLE: 1,
+        HTML_LITERAL: 9,
+    }
+}
+
+#[test]
+fn escapable_raw_text() {
+    assert_lex! {
+        HtmlLexContext::EscapableRawText(HtmlEscapableRawTextElement::Textarea),
+        "<p>&amp;</p></textarea>",
+        HTML_LITERAL: 12,
+        L_ANGLE: 1,
+        HTML_LITERAL: 10,
+    }
+}
+
 #[test]
 fn doctype_with_quirk() {
     assert_lex! {
@@ -287,11 +330,33 @@ fn unquoted_attribute_value_2() {
     }
 }
 
+#[test]
+fn expression_attribute_value() {
+    assert_lex! {
+        HtmlLexContext::AttributeValue,
+        "{() => select({ id: '}' })} value",
+        HTML_STRING_LITERAL: 27,
+        WHITESPACE: 1,
+        HTML_STRING_LITERAL: 5,
+    }
+}
+
+#[test]
+fn unclosed_expression_attribute_value() {
+    assert_lex! {
+        HtmlLexContext::AttributeValue,
+        "{value value",
+        HTML_STRING_LITERAL: 6,
+        WHITESPACE: 1,
+        HTML_STRING_LITERAL: 5,
+    }
+}
+
 #[test]
 fn unquoted_attribute_value_invalid_chars() {
     assert_lex! {
         HtmlLexContext::AttributeValue,
-        "?<='\"`",
+        "`<='\"`",
         ERROR_TOKEN: 1,
         L_ANGLE: 1,
         ERROR_TOKEN: 1,
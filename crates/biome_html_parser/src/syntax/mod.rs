@@ -2,7 +2,7 @@ mod parse_error;
 
 use crate::parser::HtmlParser;
 use crate::syntax::parse_error::*;
-use crate::token_source::{HtmlEmbededLanguage, HtmlLexContext};
+use crate::token_source::{HtmlEmbededLanguage, HtmlEscapableRawTextElement, HtmlLexContext};
 use biome_html_syntax::HtmlSyntaxKind::*;
 use biome_html_syntax::{HtmlSyntaxKind, T};
 use biome_parser::parse_lists::ParseNodeList;
@@ -20,8 +20,66 @@ static VOID_ELEMENTS: &[&str] = &[
     "track", "wbr",
 ];
 
-/// For these elements, the content is treated as raw text and no parsing is done inside them. This is so that the contents of these tags can be parsed by a different parser.
-pub(crate) static EMBEDDED_LANGUAGE_ELEMENTS: &[&str] = &["script", "style"];
+/// The elements whose end tag can be omitted, with the start tags that implicitly close them.
+///
+/// The end tag of these elements can also be omitted at the end of the content of their parent.
+///
+/// See: https://html.spec.whatwg.org/#optional-tags
+static OPTIONAL_END_TAG_ELEMENTS: &[(&str, &[&str])] = &[
+    ("html", &[]),
+    ("head", &["body"]),
+    ("body", &[]),
+    ("li", &["li"]),
+    ("dt", &["dt", "dd"]),
+    ("dd", &["dt", "dd"]),
+    (
+        "p",
+        &[
+            "address",
+            "article",
+            "aside",
+            "blockquote",
+            "details",
+            "dialog",
+            "div",
+            "dl",
+            "fieldset",
+            "figcaption",
+            "figure",
+            "footer",
+            "form",
+            "h1",
+            "h2",
+            "h3",
+            "h4",
+            "h5",
+            "h6",
+            "header",
+            "hgroup",
+            "hr",
+            "main",
+            "menu",
+            "nav",
+            "ol",
+            "p",
+            "pre",
+            "search",
+            "section",
+            "table",
+            "ul",
+        ],
+    ),
+    ("rt", &["rt", "rp"]),
+    ("rp", &["rt", "rp"]),
+    ("optgroup", &["optgroup"]),
+    ("option", &["option", "optgroup", "hr"]),
+    ("thead", &["tbody", "tfoot"]),
+    ("tbody", &["tbody", "tfoot"]),
+    ("tfoot", &[]),
+    ("tr", &["tr", "tbody", "thead", "tfoot"]),
+    ("td", &["td", "th", "tr", "tbody", "thead", "tfoot"]),
+    ("th", &["td", "th", "tr", "tbody", "thead", "tfoot"]),
+];
 
 pub(crate) fn parse_root(p: &mut HtmlParser) {
     let m = p.start();
@@ -29,7 +87,20 @@ pub(crate) fn parse_root(p: &mut HtmlParser) {
     p.eat(UNICODE_BOM);
 
     parse_doc_type(p).ok();
-    ElementList.parse_list(p);
+
+    loop {
+        ElementList::default().parse_list(p);
+        if p.at(EOF) {
+            break;
+        }
+
+        // A closing tag without a matching opening tag
+        let closing = parse_closing_element(p);
+        if let Present(mut closing) = closing {
+            p.error(expected_matching_closing_tag(p, closing.range(p)).into_diagnostic(p));
+            closing.change_to_bogus(p);
+        }
+    }
 
     m.complete(p, HTML_ROOT);
 }
@@ -63,7 +134,7 @@ fn parse_doc_type(p: &mut HtmlParser) -> ParsedSyntax {
         p.eat_with_context(HTML_STRING_LITERAL, HtmlLexContext::Doctype);
     }
 
-    p.eat(T![>]);
+    p.eat_with_context(T![>], HtmlLexContext::OutsideTag);
 
     Present(m.complete(p, HTML_DIRECTIVE))
 }
@@ -75,13 +146,14 @@ fn parse_element(p: &mut HtmlParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![<]);
-    let opening_tag_name = p.cur_text().to_string();
-    let should_be_self_closing = VOID_ELEMENTS
-        .iter()
-        .any(|tag| tag.eq_ignore_ascii_case(opening_tag_name.as_str()));
-    let is_embedded_language_tag = EMBEDDED_LANGUAGE_ELEMENTS
-        .iter()
-        .any(|tag| tag.eq_ignore_ascii_case(opening_tag_name.as_str()));
+    // Tag names are case-insensitive
+    let mut opening_tag_name = if p.at(HTML_LITERAL) {
+        p.cur_text().to_string()
+    } else {
+        String::new()
+    };
+    opening_tag_name.make_ascii_lowercase();
+    let should_be_self_closing = VOID_ELEMENTS.contains(&opening_tag_name.as_str());
     parse_literal(p).or_add_diagnostic(p, expected_element_name);
 
     AttributeList.parse_list(p);
@@ -98,25 +170,27 @@ fn parse_element(p: &mut HtmlParser) -> ParsedSyntax {
             p.expect_with_context(T![>], HtmlLexContext::OutsideTag);
             return Present(m.complete(p, HTML_SELF_CLOSING_ELEMENT));
         }
-        p.expect_with_context(
-            T![>],
-            if is_embedded_language_tag {
-                HtmlLexContext::EmbeddedLanguage(match opening_tag_name.as_str() {
-                    tag if tag.eq_ignore_ascii_case("script") => HtmlEmbededLanguage::Script,
-                    tag if tag.eq_ignore_ascii_case("style") => HtmlEmbededLanguage::Style,
-                    _ => unreachable!(),
-                })
-            } else {
-                HtmlLexContext::OutsideTag
-            },
-        );
+        p.expect_with_context(T![>], content_lex_context(&opening_tag_name));
         let opening = m.complete(p, HTML_OPENING_ELEMENT);
+        let closed_by = OPTIONAL_END_TAG_ELEMENTS
+            .iter()
+            .find_map(|(name, closed_by)| (*name == opening_tag_name).then_some(*closed_by));
         loop {
-            ElementList.parse_list(p);
+            ElementList {
+                closed_by: closed_by.unwrap_or_default(),
+            }
+            .parse_list(p);
+
+            // The end tag of the element is omitted
+            if closed_by.is_some() && !at_closing_tag(p, &opening_tag_name) {
+                break;
+            }
+
+            let at_matching_closing_tag = at_closing_tag(p, &opening_tag_name);
             if let Some(mut closing) =
                 parse_closing_element(p).or_add_diagnostic(p, expected_closing_tag)
             {
-                if !closing.text(p).contains(opening_tag_name.as_str()) {
+                if !at_matching_closing_tag {
                     p.error(expected_matching_closing_tag(p, closing.range(p)).into_diagnostic(p));
                     closing.change_to_bogus(p);
                     continue;
@@ -130,6 +204,49 @@ fn parse_element(p: &mut HtmlParser) -> ParsedSyntax {
     }
 }
 
+/// Returns the context used to lex the content of the element with the given name.
+///
+/// The content of `script` and `style` is treated as raw text and no parsing is done inside them. This is so that the contents of these tags can be parsed by a different parser.
+/// The content of `textarea` and `title` is text too, but it can contain character references.
+fn content_lex_context(tag_name: &str) -> HtmlLexContext {
+    match tag_name {
+        "script" => HtmlLexContext::EmbeddedLanguage(HtmlEmbededLanguage::Script),
+        "style" => HtmlLexContext::EmbeddedLanguage(HtmlEmbededLanguage::Style),
+        "textarea" => HtmlLexContext::EscapableRawText(HtmlEscapableRawTextElement::Textarea),
+        "title" => HtmlLexContext::EscapableRawText(HtmlEscapableRawTextElement::Title),
+        _ => HtmlLexContext::OutsideTag,
+    }
+}
+
+/// Returns the name of the tag that starts with the current `<` token.
+fn tag_name<'a>(p: &'a HtmlParser) -> Option<&'a str> {
+    if !p.at(T![<]) {
+        return None;
+    }
+
+    let source = p.source().text();
+    let start = usize::from(p.cur_range().end());
+    let text = &source[start..];
+    let text = text.strip_prefix('/').unwrap_or(text);
+    let end = text
+        .find(|c: char| c.is_ascii_whitespace() || matches!(c, '/' | '>'))
+        .unwrap_or(text.len());
+
+    Some(&text[..end])
+}
+
+/// Returns `true` if the parser is at a start tag with one of the given names.
+fn at_start_tag(p: &mut HtmlParser, names: &[&str]) -> bool {
+    !p.nth_at(1, T![/])
+        && tag_name(p)
+            .is_some_and(|tag_name| names.iter().any(|name| name.eq_ignore_ascii_case(tag_name)))
+}
+
+/// Returns `true` if the parser is at the closing tag of the element with the given name.
+fn at_closing_tag(p: &mut HtmlParser, name: &str) -> bool {
+    p.nth_at(1, T![/]) && tag_name(p).is_some_and(|tag_name| tag_name.eq_ignore_ascii_case(name))
+}
+
 fn parse_closing_element(p: &mut HtmlParser) -> ParsedSyntax {
     if !p.at(T![<]) || !p.nth_at(1, T![/]) {
         return Absent;
@@ -144,12 +261,15 @@ fn parse_closing_element(p: &mut HtmlParser) -> ParsedSyntax {
         p.error(void_element_should_not_have_closing_tag(p, p.cur_range()).into_diagnostic(p));
     }
     let _name = parse_literal(p);
-    p.bump_with_context(T![>], HtmlLexContext::OutsideTag);
+    p.expect_with_context(T![>], HtmlLexContext::OutsideTag);
     Present(m.complete(p, HTML_CLOSING_ELEMENT))
 }
 
 #[derive(Default)]
-struct ElementList;
+struct ElementList {
+    /// The start tags that implicitly close the parent element, when its end tag can be omitted
+    closed_by: &'static [&'static str],
+}
 
 impl ParseNodeList for ElementList {
     type Kind = HtmlSyntaxKind;
@@ -173,7 +293,7 @@ impl ParseNodeList for ElementList {
         let at_l_angle0 = p.at(T![<]);
         let at_slash1 = p.nth_at(1, T![/]);
         let at_eof = p.at(EOF);
-        at_l_angle0 && at_slash1 || at_eof
+        at_l_angle0 && at_slash1 || at_eof || at_start_tag(p, self.closed_by)
     }
 
     fn recover(
@@ -271,7 +391,8 @@ fn parse_comment(p: &mut HtmlParser) -> ParsedSyntax {
     }
     let m = p.start();
     p.bump_with_context(T![<!--], HtmlLexContext::Comment);
-    p.bump_with_context(HTML_LITERAL, HtmlLexContext::Comment);
-    p.expect(T![-->]);
+    // The comment can be empty
+    p.eat_with_context(HTML_LITERAL, HtmlLexContext::Comment);
+    p.expect_with_context(T![-->], HtmlLexContext::OutsideTag);
     Present(m.complete(p, HTML_COMMENT))
 }
@@ -33,6 +33,9 @@ pub(crate) enum HtmlLexContext {
     Doctype,
     /// Treat everything as text until the closing tag is encountered.
     EmbeddedLanguage(HtmlEmbededLanguage),
+    /// Treat everything as text until the closing tag is encountered, like for an embedded
+    /// language. The text can contain character references.
+    EscapableRawText(HtmlEscapableRawTextElement),
     /// Comments are treated as text until the closing comment tag is encountered.
     Comment,
 }
@@ -46,8 +49,26 @@ pub(crate) enum HtmlEmbededLanguage {
 impl HtmlEmbededLanguage {
     pub fn end_tag(&self) -> &'static str {
         match self {
-            Self::Script => "</script>",
-            Self::Style => "</style>",
+            Self::Script => "</script",
+            Self::Style => "</style",
+        }
+    }
+}
+
+/// The elements whose content is text that can't contain elements.
+///
+/// See: https://html.spec.whatwg.org/#escapable-raw-text-elements
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum HtmlEscapableRawTextElement {
+    Textarea,
+    Title,
+}
+
+impl HtmlEscapableRawTextElement {
+    pub fn end_tag(&self) -> &'static str {
+        match self {
+            Self::Textarea => "</textarea",
+            Self::Title => "</title",
         }
     }
 }
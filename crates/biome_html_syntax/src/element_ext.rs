@@ -0,0 +1,87 @@
+use crate::{
+    AnyHtmlAttribute, HtmlAttribute, HtmlAttributeList, HtmlElement, HtmlName,
+    HtmlSelfClosingElement, HtmlString,
+};
+use biome_rowan::{AstNodeList, SyntaxResult, TextRange, TextSize, TokenText};
+
+impl HtmlElement {
+    /// Returns the name of the element, as written in the opening tag.
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        self.opening_element()?.name()
+    }
+
+    /// Returns `true` if the element has the given name. The comparison is case-insensitive.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name()
+            .is_ok_and(|element_name| element_name.is_named(name))
+    }
+
+    /// Returns the attribute with the given name, if any.
+    pub fn find_attribute_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.opening_element().ok()?.attributes().find_by_name(name)
+    }
+
+    /// Returns `true` if the element is a `<script>` element.
+    pub fn is_script_tag(&self) -> bool {
+        self.has_name("script")
+    }
+
+    /// Returns `true` if the element is a `<style>` element.
+    pub fn is_style_tag(&self) -> bool {
+        self.has_name("style")
+    }
+}
+
+impl HtmlSelfClosingElement {
+    /// Returns `true` if the element has the given name. The comparison is case-insensitive.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name()
+            .is_ok_and(|element_name| element_name.is_named(name))
+    }
+}
+
+impl HtmlName {
+    /// Returns `true` if the name is equal to the given name, ignoring the ASCII case.
+    pub fn is_named(&self, name: &str) -> bool {
+        self.value_token()
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the first attribute with the given name. The comparison is case-insensitive.
+    pub fn find_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.iter().find_map(|attribute| match attribute {
+            AnyHtmlAttribute::HtmlAttribute(attribute)
+                if attribute
+                    .name()
+                    .is_ok_and(|attribute_name| attribute_name.is_named(name)) =>
+            {
+                Some(attribute)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl HtmlAttribute {
+    /// Returns the value of the attribute without the quotes, if it has one.
+    pub fn value(&self) -> Option<TokenText> {
+        self.initializer()?.value().ok()?.inner_string_text().ok()
+    }
+}
+
+impl HtmlString {
+    /// Returns the text of the string without the quotes.
+    ///
+    /// The text of unquoted attribute values is returned as is.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        let token = self.value_token()?;
+        let mut text = token.token_text_trimmed();
+        if text.len() >= TextSize::from(2) && (text.starts_with('"') || text.starts_with('\'')) {
+            let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
+            text = text.slice(range);
+        }
+        Ok(text)
+    }
+}
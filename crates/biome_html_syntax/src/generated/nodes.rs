@@ -176,8 +176,8 @@ impl HtmlComment {
     pub fn comment_start_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn content_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
     }
     pub fn comment_end_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
@@ -194,7 +194,7 @@ impl Serialize for HtmlComment {
 #[derive(Serialize)]
 pub struct HtmlCommentFields {
     pub comment_start_token: SyntaxResult<SyntaxToken>,
-    pub content_token: SyntaxResult<SyntaxToken>,
+    pub content_token: Option<SyntaxToken>,
     pub comment_end_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -329,8 +329,8 @@ impl HtmlElement {
     pub fn children(&self) -> HtmlElementList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn closing_element(&self) -> SyntaxResult<HtmlClosingElement> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn closing_element(&self) -> Option<HtmlClosingElement> {
+        support::node(&self.syntax, 2usize)
     }
 }
 impl Serialize for HtmlElement {
@@ -345,7 +345,7 @@ impl Serialize for HtmlElement {
 pub struct HtmlElementFields {
     pub opening_element: SyntaxResult<HtmlOpeningElement>,
     pub children: HtmlElementList,
-    pub closing_element: SyntaxResult<HtmlClosingElement>,
+    pub closing_element: Option<HtmlClosingElement>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlName {
@@ -792,7 +792,7 @@ impl std::fmt::Debug for HtmlComment {
             )
             .field(
                 "content_token",
-                &support::DebugSyntaxResult(self.content_token()),
+                &support::DebugOptionalElement(self.content_token()),
             )
             .field(
                 "comment_end_token",
@@ -949,7 +949,7 @@ impl std::fmt::Debug for HtmlElement {
             .field("children", &self.children())
             .field(
                 "closing_element",
-                &support::DebugSyntaxResult(self.closing_element()),
+                &support::DebugOptionalElement(self.closing_element()),
             )
             .finish()
     }
@@ -1,4 +1,5 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+pub mod refactor;
 pub mod source;
-::biome_analyze::declare_category! { pub Assists { kind : Action , groups : [self :: source :: Source ,] } }
+::biome_analyze::declare_category! { pub Assists { kind : Action , groups : [self :: refactor :: Refactor , self :: source :: Source ,] } }
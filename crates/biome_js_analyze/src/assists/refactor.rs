@@ -0,0 +1,24 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_assists_group;
+
+pub mod convert_to_arrow_function;
+pub mod convert_to_template_literal;
+pub mod extract_to_variable;
+pub mod inline_variable;
+pub mod organize_selected_imports;
+pub mod sort_selected_jsx_props;
+
+declare_assists_group! {
+    pub Refactor {
+        name : "refactor" ,
+        rules : [
+            self :: convert_to_arrow_function :: ConvertToArrowFunction ,
+            self :: convert_to_template_literal :: ConvertToTemplateLiteral ,
+            self :: extract_to_variable :: ExtractToVariable ,
+            self :: inline_variable :: InlineVariable ,
+            self :: organize_selected_imports :: OrganizeSelectedImports ,
+            self :: sort_selected_jsx_props :: SortSelectedJsxProps ,
+        ]
+     }
+}
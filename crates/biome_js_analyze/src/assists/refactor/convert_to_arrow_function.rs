@@ -0,0 +1,78 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsFileSource, JsFunctionExpression, TextRange};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+use crate::lint::complexity::use_arrow_function::{
+    convertible_function_expression, to_arrow_function, ActualThisScope,
+};
+use crate::JsRuleAction;
+
+declare_refactor_rule! {
+    /// Turns the selected function expression into an arrow function.
+    ///
+    /// The refactor is offered when the selection is on the head of the
+    /// function, from the `function` keyword to the parameters.
+    /// As for [useArrowFunction](https://biomejs.dev/linter/rules/use-arrow-function),
+    /// the functions that use `this`, the generators and the named functions
+    /// are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const add = function (a, b) {
+    ///     return a + b;
+    /// };
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const add = (a, b) => a + b;
+    /// ```
+    pub ConvertToArrowFunction {
+        version: "next",
+        name: "convertToArrowFunction",
+        language: "js",
+    }
+}
+
+impl Rule for ConvertToArrowFunction {
+    type Query = ActualThisScope;
+    type State = JsFunctionExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // The refactor is only offered on demand
+        ctx.range()?;
+        convertible_function_expression(ctx.query()).cloned()
+    }
+
+    fn text_range(_: &RuleContext<Self>, function_expression: &Self::State) -> Option<TextRange> {
+        let start = function_expression.range().start();
+        let end = function_expression.parameters().ok()?.range().end();
+        Some(TextRange::new(start, end))
+    }
+
+    fn action(ctx: &RuleContext<Self>, function_expression: &Self::State) -> Option<JsRuleAction> {
+        let arrow_function = to_arrow_function(
+            function_expression,
+            ctx.source_type::<JsFileSource>().is_jsx(),
+        )?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::from(function_expression.clone()),
+            arrow_function,
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::Always,
+            markup! { "Convert to an arrow function" },
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,66 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, Ast, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsBinaryExpression};
+use biome_rowan::BatchMutationExt;
+
+use crate::lint::style::use_template::{
+    is_convertible_concatenation, template_expression_from_binary_expression,
+};
+use crate::JsRuleAction;
+
+declare_refactor_rule! {
+    /// Turns the selected string concatenation into a template literal.
+    ///
+    /// The refactor applies to the same concatenations as
+    /// [useTemplate](https://biomejs.dev/linter/rules/use-template), and is
+    /// offered even when the lint rule is disabled.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const greeting = "Hello, " + name + "!";
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const greeting = `Hello, ${name}!`;
+    /// ```
+    pub ConvertToTemplateLiteral {
+        version: "next",
+        name: "convertToTemplateLiteral",
+        language: "js",
+    }
+}
+
+impl Rule for ConvertToTemplateLiteral {
+    type Query = Ast<JsBinaryExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        // The refactor is only offered on demand
+        ctx.range()?;
+        is_convertible_concatenation(ctx.query()).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let template = template_expression_from_binary_expression(node)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsBinaryExpression(node.clone()),
+            AnyJsExpression::JsTemplateExpression(template),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::Always,
+            markup! { "Convert to a template literal" },
+            mutation,
+        ))
+    }
+}
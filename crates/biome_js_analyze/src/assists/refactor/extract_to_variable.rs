@@ -0,0 +1,265 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::{Scope, SemanticModel};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsClassMember, AnyJsExpression, AnyJsFunction,
+    AnyJsModuleItem, AnyJsStatement, JsCallExpression, JsConditionalExpression, JsIfStatement,
+    JsLogicalExpression, JsModuleItemList, JsNewExpression, JsStatementList, JsSyntaxKind,
+    JsTemplateExpression, JsUnaryExpression, JsUnaryOperator, TriviaPieceKind, T,
+};
+use biome_rowan::{AstNode, AstNodeExt, AstNodeList, BatchMutationExt};
+
+use crate::{services::semantic::Semantic, JsRuleAction};
+
+declare_refactor_rule! {
+    /// Moves the selected expression to a new variable declared right before
+    /// the statement that contains it.
+    ///
+    /// The refactor is offered when the selection covers exactly an
+    /// expression that is evaluated once and unconditionally by its
+    /// statement. The expression is evaluated before the rest of the
+    /// statement after the refactor, so the refactor may change the behavior
+    /// of the code when the statement has side effects.
+    ///
+    /// ## Examples
+    ///
+    /// When the selection covers `price * quantity`:
+    ///
+    /// ```js
+    /// console.log(price * quantity);
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const extracted = price * quantity;
+    /// console.log(extracted);
+    /// ```
+    pub ExtractToVariable {
+        version: "next",
+        name: "extractToVariable",
+        language: "js",
+    }
+}
+
+impl Rule for ExtractToVariable {
+    type Query = Semantic<AnyJsExpression>;
+    type State = ExtractedExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let range = ctx.range()?;
+        let expression = ctx.query();
+        if expression.range() != range || !is_extractable(expression) {
+            return None;
+        }
+        let statement = enclosing_statement(expression)?;
+        let model = ctx.model();
+        let scope = model.scope(&statement.syntax().parent()?);
+        let name = (0..)
+            .map(|index| match index {
+                0 => String::from("extracted"),
+                _ => format!("extracted{index}"),
+            })
+            .find(|name| is_available(model, &scope, name))?;
+
+        Some(ExtractedExpression { statement, name })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let ExtractedExpression { statement, name } = state;
+        let expression = ctx.query();
+
+        let declaration = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![const]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_variable_declarator_list(
+                    [
+                        make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                            AnyJsBinding::JsIdentifierBinding(make::js_identifier_binding(
+                                make::ident(name),
+                            )),
+                        ))
+                        .with_initializer(make::js_initializer_clause(
+                            make::token_decorated_with_space(T![=]),
+                            expression.clone().trim_trivia()?,
+                        ))
+                        .build(),
+                    ],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+
+        let reference =
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(name)));
+        let new_statement = statement
+            .clone()
+            .replace_node(expression.clone(), AnyJsExpression::from(reference))?;
+
+        // The declaration takes the place of the statement with its comments,
+        // and the statement moves to the next line with the same indentation
+        let first_token = new_statement.syntax().first_token()?;
+        let leading_trivia = first_token.leading_trivia();
+        let declaration = declaration.prepend_trivia_pieces(leading_trivia.pieces())?;
+        let indentation = leading_trivia
+            .pieces()
+            .rev()
+            .take_while(|piece| piece.is_whitespace())
+            .map(|piece| piece.text().to_string())
+            .collect::<Vec<_>>();
+        let indentation: String = indentation.into_iter().rev().collect();
+        let mut trivia = vec![(TriviaPieceKind::Newline, "\n")];
+        if !indentation.is_empty() {
+            trivia.push((TriviaPieceKind::Whitespace, indentation.as_str()));
+        }
+        let new_first_token = first_token.with_leading_trivia(trivia);
+        let new_statement =
+            new_statement.replace_token_discard_trivia(first_token, new_first_token)?;
+
+        let mut mutation = ctx.root().begin();
+        let parent = statement.syntax().parent()?;
+        if let Some(list) = JsStatementList::cast_ref(&parent) {
+            let mut items = Vec::with_capacity(list.len() + 1);
+            for item in list.iter() {
+                if &item == statement {
+                    items.push(AnyJsStatement::from(declaration.clone()));
+                    items.push(new_statement.clone());
+                } else {
+                    items.push(item);
+                }
+            }
+            mutation.replace_node_discard_trivia(list, make::js_statement_list(items));
+        } else {
+            let list = JsModuleItemList::cast(parent)?;
+            let mut items = Vec::with_capacity(list.len() + 1);
+            for item in list.iter() {
+                match &item {
+                    AnyJsModuleItem::AnyJsStatement(item) if item == statement => {
+                        items.push(AnyJsModuleItem::AnyJsStatement(AnyJsStatement::from(
+                            declaration.clone(),
+                        )));
+                        items.push(AnyJsModuleItem::AnyJsStatement(new_statement.clone()));
+                    }
+                    _ => items.push(item),
+                }
+            }
+            mutation.replace_node_discard_trivia(list, make::js_module_item_list(items));
+        }
+
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Extract),
+            Applicability::MaybeIncorrect,
+            markup! { "Extract to a variable" },
+            mutation,
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct ExtractedExpression {
+    /// The statement before which the variable is declared
+    statement: AnyJsStatement,
+    /// The name of the variable
+    name: String,
+}
+
+/// Returns `true` if `expression` can be replaced by a reference to a variable
+fn is_extractable(expression: &AnyJsExpression) -> bool {
+    if matches!(expression, AnyJsExpression::JsSuperExpression(_)) {
+        return false;
+    }
+    let Some(parent) = expression.syntax().parent() else {
+        return false;
+    };
+    match parent.kind() {
+        // The statement would be reduced to a reference
+        JsSyntaxKind::JS_EXPRESSION_STATEMENT => false,
+        // A method called through a variable loses its `this`
+        JsSyntaxKind::JS_CALL_EXPRESSION => JsCallExpression::cast(parent)
+            .and_then(|call| call.callee().ok())
+            .map_or(true, |callee| &callee != expression),
+        JsSyntaxKind::JS_NEW_EXPRESSION => JsNewExpression::cast(parent)
+            .and_then(|new| new.callee().ok())
+            .map_or(true, |callee| &callee != expression),
+        JsSyntaxKind::JS_TEMPLATE_EXPRESSION => JsTemplateExpression::cast(parent)
+            .and_then(|template| template.tag())
+            .map_or(true, |tag| &tag != expression),
+        // `delete` applies to a property, not to a value
+        JsSyntaxKind::JS_UNARY_EXPRESSION => JsUnaryExpression::cast(parent)
+            .and_then(|unary| unary.operator().ok())
+            .map_or(true, |operator| operator != JsUnaryOperator::Delete),
+        _ => true,
+    }
+}
+
+/// Returns the statement that evaluates `expression` once and unconditionally
+fn enclosing_statement(expression: &AnyJsExpression) -> Option<AnyJsStatement> {
+    let mut child = expression.syntax().clone();
+    for ancestor in expression.syntax().ancestors().skip(1) {
+        let kind = ancestor.kind();
+        match kind {
+            JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST => {
+                return AnyJsStatement::cast(child);
+            }
+            // The expression would be evaluated at another place, or another
+            // number of times
+            JsSyntaxKind::JS_FOR_STATEMENT
+            | JsSyntaxKind::JS_FOR_IN_STATEMENT
+            | JsSyntaxKind::JS_FOR_OF_STATEMENT
+            | JsSyntaxKind::JS_WHILE_STATEMENT
+            | JsSyntaxKind::JS_DO_WHILE_STATEMENT
+            | JsSyntaxKind::JS_CASE_CLAUSE
+            | JsSyntaxKind::JS_WITH_STATEMENT => return None,
+            _ if AnyJsFunction::can_cast(kind) || AnyJsClassMember::can_cast(kind) => return None,
+            // The expression would be evaluated unconditionally
+            JsSyntaxKind::JS_IF_STATEMENT => {
+                let test = JsIfStatement::unwrap_cast(ancestor.clone()).test().ok()?;
+                if test.syntax() != &child {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_CONDITIONAL_EXPRESSION => {
+                let test = JsConditionalExpression::unwrap_cast(ancestor.clone())
+                    .test()
+                    .ok()?;
+                if test.syntax() != &child {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_LOGICAL_EXPRESSION => {
+                let left = JsLogicalExpression::unwrap_cast(ancestor.clone())
+                    .left()
+                    .ok()?;
+                if left.syntax() != &child {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+        child = ancestor;
+    }
+    None
+}
+
+/// Returns `true` if a variable named `name` declared in `scope` doesn't
+/// shadow or conflict with another variable
+fn is_available(model: &SemanticModel, scope: &Scope, name: &str) -> bool {
+    scope
+        .ancestors()
+        .all(|scope| scope.get_binding(name).is_none())
+        && model
+            .all_unresolved_references()
+            .all(|reference| reference.syntax().text_trimmed() != name)
+        && model
+            .all_global_references()
+            .all(|reference| reference.syntax().text_trimmed() != name)
+}
@@ -0,0 +1,285 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsOptionalChainExpression,
+    JsIdentifierBinding, JsIdentifierExpression, JsNewExpression, JsReferenceIdentifier,
+    JsSyntaxKind, JsVariableDeclaration, JsVariableDeclarator, JsVariableStatement, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+use crate::{services::semantic::Semantic, JsRuleAction};
+
+declare_refactor_rule! {
+    /// Replaces the references of the selected variable with its initializer,
+    /// and removes the declaration of the variable.
+    ///
+    /// The refactor is offered when the selection is on the name of a
+    /// variable that is never reassigned, and whose references are all
+    /// expressions that follow the declaration.
+    /// The initializer is evaluated once per reference after the refactor, so
+    /// the refactor may change the behavior of the code when the initializer
+    /// has side effects.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const total = price * quantity;
+    /// console.log(total);
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// console.log(price * quantity);
+    /// ```
+    pub InlineVariable {
+        version: "next",
+        name: "inlineVariable",
+        language: "js",
+    }
+}
+
+impl Rule for InlineVariable {
+    type Query = Semantic<JsVariableDeclarator>;
+    type State = InlinedVariable;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // The refactor is only offered on demand
+        ctx.range()?;
+        let declarator = ctx.query();
+        let model = ctx.model();
+        let Ok(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(id))) =
+            declarator.id()
+        else {
+            // Ignore destructuring
+            return None;
+        };
+        // The references of the template of a Vue, Svelte or Astro component
+        // can't be replaced
+        if model.is_referenced_by_template(&id) {
+            return None;
+        }
+        if declarator.variable_annotation().is_some() {
+            // The type annotation would be lost
+            return None;
+        }
+        let initializer = declarator.initializer()?.expression().ok()?;
+        let declaration = declarator
+            .syntax()
+            .ancestors()
+            .find_map(JsVariableDeclaration::cast)?;
+        if declaration.declarators().len() != 1 {
+            return None;
+        }
+        // Ignore the exported declarations and the declarations of the loops
+        let statement = JsVariableStatement::cast(declaration.syntax().parent()?)?;
+
+        let mut references = Vec::new();
+        for reference in id.all_references(model) {
+            if reference.is_write()
+                || reference.range_start() < declarator.syntax().text_range().end()
+            {
+                return None;
+            }
+            // The other references, such as the exports, can't be replaced by an expression
+            let expression = JsIdentifierExpression::cast(reference.syntax().parent()?)?;
+            if !has_same_bindings(model, &initializer, &expression) {
+                return None;
+            }
+            references.push(expression);
+        }
+        if references.is_empty() {
+            return None;
+        }
+
+        Some(InlinedVariable {
+            id,
+            initializer,
+            statement,
+            references,
+        })
+    }
+
+    fn text_range(_: &RuleContext<Self>, state: &Self::State) -> Option<TextRange> {
+        Some(state.id.range())
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let InlinedVariable {
+            id,
+            initializer,
+            statement,
+            references,
+        } = state;
+        let initializer = initializer.clone().trim_trivia()?;
+
+        // The next statement takes the leading trivia of the removed statement,
+        // so that the removal doesn't leave an empty line
+        let next_token = statement.syntax().last_token()?.next_token()?;
+        let leading_trivia: Vec<_> = statement
+            .syntax()
+            .first_leading_trivia()?
+            .pieces()
+            .chain(
+                next_token
+                    .leading_trivia()
+                    .pieces()
+                    .skip_while(|piece| !piece.is_newline())
+                    .skip(1)
+                    .skip_while(|piece| piece.is_whitespace()),
+            )
+            .collect();
+
+        let mut mutation = ctx.root().begin();
+        let mut is_next_token_replaced = false;
+        for reference in references {
+            let replacement = if needs_parentheses(&initializer, reference) {
+                AnyJsExpression::from(make::parenthesized(initializer.clone()))
+            } else {
+                initializer.clone()
+            };
+            let reference = AnyJsExpression::from(reference.clone());
+            if reference.syntax().first_token().as_ref() == Some(&next_token) {
+                is_next_token_replaced = true;
+                let replacement = replacement
+                    .with_leading_trivia_pieces(leading_trivia.clone())?
+                    .with_trailing_trivia_pieces(
+                        reference.syntax().last_trailing_trivia()?.pieces(),
+                    )?;
+                mutation.replace_node_discard_trivia(reference, replacement);
+            } else {
+                mutation.replace_node(reference, replacement);
+            }
+        }
+        if !is_next_token_replaced {
+            let new_next_token = next_token.with_leading_trivia_pieces(leading_trivia);
+            mutation.replace_token_discard_trivia(next_token, new_next_token);
+        }
+        mutation.remove_node(statement.clone());
+
+        let name = id.name_token().ok()?;
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Inline),
+            Applicability::MaybeIncorrect,
+            markup! { "Inline the variable "<Emphasis>{name.text_trimmed()}</Emphasis> },
+            mutation,
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct InlinedVariable {
+    /// The name of the variable
+    id: JsIdentifierBinding,
+    /// The expression that replaces the references
+    initializer: AnyJsExpression,
+    /// The statement that declares the variable
+    statement: JsVariableStatement,
+    /// The references of the variable
+    references: Vec<JsIdentifierExpression>,
+}
+
+/// Returns `true` if the names of `initializer` refer to the same bindings at
+/// the place of `reference`, e.g. they aren't shadowed by a parameter
+fn has_same_bindings(
+    model: &SemanticModel,
+    initializer: &AnyJsExpression,
+    reference: &JsIdentifierExpression,
+) -> bool {
+    let scope = model.scope(reference.syntax());
+    initializer
+        .syntax()
+        .descendants()
+        .filter_map(JsReferenceIdentifier::cast)
+        .all(|identifier| {
+            let Ok(name) = identifier.name() else {
+                return false;
+            };
+            let binding_at_reference = scope
+                .ancestors()
+                .find_map(|scope| scope.get_binding(name.text()));
+            model
+                .binding(&identifier)
+                .map(|binding| binding.syntax().clone())
+                == binding_at_reference.map(|binding| binding.syntax().clone())
+        })
+}
+
+/// Returns `true` if `initializer` needs parentheses to take the place of
+/// `reference`
+fn needs_parentheses(initializer: &AnyJsExpression, reference: &JsIdentifierExpression) -> bool {
+    let is_primary = match initializer {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsParenthesizedExpression(_)
+        | AnyJsExpression::JsTemplateExpression(_)
+        | AnyJsExpression::JsThisExpression(_) => true,
+        // `a?.b` can't take the place of `x` in `x.c`, the chain would short-circuit `.c`
+        AnyJsExpression::JsCallExpression(_)
+        | AnyJsExpression::JsComputedMemberExpression(_)
+        | AnyJsExpression::JsStaticMemberExpression(_) => {
+            AnyJsOptionalChainExpression::cast_ref(initializer.syntax())
+                .is_some_and(|chain| !chain.is_optional_chain())
+                // `new` would take the arguments of the first call, e.g. in `new a()()`
+                && !(is_new_callee(reference) && has_call_in_member_chain(initializer))
+        }
+        _ => false,
+    };
+    if is_primary {
+        return false;
+    }
+    // The commas of a sequence would separate the arguments, elements or
+    // properties of any parent
+    if matches!(initializer, AnyJsExpression::JsSequenceExpression(_)) {
+        return true;
+    }
+    // The reference is a whole operand that can't be mixed with the operators
+    // of its parent
+    !reference.syntax().parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+                | JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+                | JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                | JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+                | JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+                | JsSyntaxKind::JS_RETURN_STATEMENT
+                | JsSyntaxKind::JS_TEMPLATE_ELEMENT
+                | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+                | JsSyntaxKind::JSX_EXPRESSION_CHILD
+        )
+    })
+}
+
+/// Returns `true` if `reference` is the callee of a `new` expression
+fn is_new_callee(reference: &JsIdentifierExpression) -> bool {
+    reference
+        .parent::<JsNewExpression>()
+        .and_then(|expression| expression.callee().ok())
+        .is_some_and(|callee| callee.syntax() == reference.syntax())
+}
+
+/// Returns `true` if `expression` is a call, or a member of the result of a call
+fn has_call_in_member_chain(expression: &AnyJsExpression) -> bool {
+    let mut expression = expression.clone();
+    loop {
+        let object = match expression {
+            AnyJsExpression::JsCallExpression(_) => return true,
+            AnyJsExpression::JsComputedMemberExpression(member) => member.object(),
+            AnyJsExpression::JsStaticMemberExpression(member) => member.object(),
+            _ => return false,
+        };
+        let Ok(object) = object else {
+            return false;
+        };
+        expression = object;
+    }
+}
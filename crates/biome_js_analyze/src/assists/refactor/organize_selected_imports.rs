@@ -0,0 +1,64 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, Ast, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::JsModule;
+use biome_rowan::BatchMutationExt;
+
+use crate::assists::source::organize_imports::ImportGroups;
+use crate::JsRuleAction;
+
+declare_refactor_rule! {
+    /// Sorts the imports of the groups that intersect the selection.
+    ///
+    /// The imports are sorted the same way as
+    /// [organizeImports](https://biomejs.dev/analyzer/import-sorting), but the
+    /// groups outside the selection are left untouched.
+    ///
+    /// ## Examples
+    ///
+    /// When the selection covers the first group, only the first group is sorted:
+    ///
+    /// ```js
+    /// import b from "b";
+    /// import a from "a";
+    ///
+    /// import d from "d";
+    /// import c from "c";
+    /// ```
+    pub OrganizeSelectedImports {
+        version: "next",
+        name: "organizeSelectedImports",
+        language: "js",
+    }
+}
+
+impl Rule for OrganizeSelectedImports {
+    type Query = Ast<JsModule>;
+    type State = ImportGroups;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let range = ctx.range()?;
+        let mut groups = ImportGroups::from_module(ctx.query())?;
+        groups.retain_in_range(range);
+        (!groups.is_sorted()).then_some(groups)
+    }
+
+    fn action(ctx: &RuleContext<Self>, groups: &Self::State) -> Option<JsRuleAction> {
+        let old_list = ctx.query().items();
+        let new_list = groups.sorted_items(ctx.query())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(old_list, new_list);
+
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::MaybeIncorrect,
+            markup! { "Organize the selected imports" },
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,64 @@
+use biome_analyze::{
+    context::RuleContext, declare_refactor_rule, ActionCategory, Ast, RefactorKind, Rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::JsxAttributeList;
+use biome_rowan::BatchMutationExt;
+
+use crate::assists::source::sort_jsx_props::{prop_groups, PropGroup};
+use crate::JsRuleAction;
+
+declare_refactor_rule! {
+    /// Sorts the selected props of a JSX element.
+    ///
+    /// The props are sorted the same way as
+    /// [sortJsxProps](https://biomejs.dev/analyzer/assists/source/sortJsxProps),
+    /// but the props outside the selection keep their position.
+    /// The props are never moved across a spread prop.
+    ///
+    /// ## Examples
+    ///
+    /// When the selection covers `lastName` and `firstName`, only these two
+    /// props are sorted:
+    ///
+    /// ```jsx
+    /// <Hello tel={5555555} lastName="Smith" firstName="John" />;
+    /// ```
+    pub SortSelectedJsxProps {
+        version: "next",
+        name: "sortSelectedJsxProps",
+        language: "js",
+    }
+}
+
+impl Rule for SortSelectedJsxProps {
+    type Query = Ast<JsxAttributeList>;
+    type State = PropGroup;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(range) = ctx.range() else {
+            return Box::default();
+        };
+        let mut prop_groups = prop_groups(ctx.query()).into_vec();
+        for prop_group in &mut prop_groups {
+            prop_group.retain_in_range(range);
+        }
+        prop_groups.retain(|prop_group| !prop_group.is_sorted());
+        prop_groups.into_boxed_slice()
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        state.sort(&mut mutation);
+
+        Some(JsRuleAction::new(
+            ActionCategory::Refactor(RefactorKind::Rewrite),
+            Applicability::Always,
+            markup! { "Sort the selected JSX props" },
+            mutation,
+        ))
+    }
+}
@@ -7,7 +7,7 @@ use biome_console::markup;
 use biome_js_factory::make;
 use biome_js_syntax::{
     AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImportSpecifier, JsImport, JsLanguage, JsModule,
-    JsSyntaxToken, JsSyntaxTrivia, TextRange, TriviaPieceKind, T,
+    JsModuleItemList, JsSyntaxToken, JsSyntaxTrivia, TextRange, TriviaPieceKind, T,
 };
 use biome_rowan::{
     chain_trivia_pieces, AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt,
@@ -56,7 +56,35 @@ impl Rule for OrganizeImports {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
-        let root = ctx.query();
+        let groups = ImportGroups::from_module(ctx.query())?;
+        (!groups.is_sorted()).then_some(groups)
+    }
+
+    fn action(ctx: &RuleContext<Self>, groups: &Self::State) -> Option<JsRuleAction> {
+        let old_list = ctx.query().items();
+        let new_list = groups.sorted_items(ctx.query())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(old_list, new_list);
+
+        Some(JsRuleAction::new(
+            ActionCategory::Source(SourceActionKind::OrganizeImports),
+            ctx.metadata().applicability(),
+            markup! { "Organize Imports (Biome)" },
+            mutation,
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportGroups {
+    /// The list of all the import groups in the file
+    groups: Vec<ImportGroup>,
+}
+
+impl ImportGroups {
+    /// Collects the import groups of the module
+    pub(crate) fn from_module(root: &JsModule) -> Option<Self> {
         let mut groups = Vec::new();
         let mut first_node = None;
         let mut nodes = BTreeMap::new();
@@ -124,17 +152,29 @@ impl Rule for OrganizeImports {
             groups.push(ImportGroup { first_node, nodes });
         }
 
-        groups
-            .iter()
-            .any(|group| !group.is_sorted())
-            .then_some(ImportGroups { groups })
+        Some(Self { groups })
     }
 
-    fn action(ctx: &RuleContext<Self>, groups: &Self::State) -> Option<JsRuleAction> {
-        let mut groups_iter = groups.groups.iter();
-        let mut next_group = groups_iter.next().expect("state is empty");
+    /// Returns true if the nodes of all the groups are already sorted
+    pub(crate) fn is_sorted(&self) -> bool {
+        self.groups.iter().all(ImportGroup::is_sorted)
+    }
 
-        let old_list = ctx.query().items();
+    /// Only keeps the imports that intersect `range`, and the groups that
+    /// contain one of them
+    pub(crate) fn retain_in_range(&mut self, range: TextRange) {
+        for group in &mut self.groups {
+            group.retain_in_range(range);
+        }
+        self.groups.retain(|group| !group.nodes.is_empty());
+    }
+
+    /// Returns the items of `root` with the imports of the groups sorted
+    pub(crate) fn sorted_items(&self, root: &JsModule) -> Option<JsModuleItemList> {
+        let mut groups_iter = self.groups.iter();
+        let mut next_group = groups_iter.next()?;
+
+        let old_list = root.items();
         let mut new_list = Vec::new();
 
         let mut items_iter = old_list.iter();
@@ -254,26 +294,10 @@ impl Rule for OrganizeImports {
         // early exit after reaching the last group
         new_list.extend(items_iter);
 
-        let new_list = make::js_module_item_list(new_list);
-
-        let mut mutation = ctx.root().begin();
-        mutation.replace_node_discard_trivia(old_list, new_list);
-
-        Some(JsRuleAction::new(
-            ActionCategory::Source(SourceActionKind::OrganizeImports),
-            ctx.metadata().applicability(),
-            markup! { "Organize Imports (Biome)" },
-            mutation,
-        ))
+        Some(make::js_module_item_list(new_list))
     }
 }
 
-#[derive(Debug)]
-pub struct ImportGroups {
-    /// The list of all the import groups in the file
-    groups: Vec<ImportGroup>,
-}
-
 #[derive(Debug)]
 struct ImportGroup {
     /// The import that was at the start of the group before sorting
@@ -284,6 +308,33 @@ struct ImportGroup {
 }
 
 impl ImportGroup {
+    /// Only keeps the imports that intersect `range`. The kept imports follow
+    /// each other, so they form a group that starts at the first of them.
+    fn retain_in_range(&mut self, range: TextRange) {
+        for import_nodes in self.nodes.values_mut() {
+            import_nodes.retain(|import_node| {
+                import_node
+                    .node
+                    .syntax()
+                    .text_trimmed_range()
+                    .intersect(range)
+                    .is_some()
+            });
+        }
+        self.nodes
+            .retain(|_, import_nodes| !import_nodes.is_empty());
+
+        let first_node = self
+            .nodes
+            .values()
+            .flatten()
+            .map(|import_node| &import_node.node)
+            .min_by_key(|node| node.syntax().text_range().start());
+        if let Some(first_node) = first_node {
+            self.first_node = first_node.clone();
+        }
+    }
+
     /// Returns true if the nodes in the group are already sorted in the file
     fn is_sorted(&self) -> bool {
         // The imports are sorted if the text position of each node in the `BTreeMap`
@@ -6,8 +6,8 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_diagnostics::Applicability;
-use biome_js_syntax::{AnyJsxAttribute, JsxAttribute, JsxAttributeList};
-use biome_rowan::{AstNode, BatchMutationExt};
+use biome_js_syntax::{AnyJsxAttribute, JsLanguage, JsxAttribute, JsxAttributeList, TextRange};
+use biome_rowan::{AstNode, BatchMutation, BatchMutationExt};
 
 use crate::JsRuleAction;
 
@@ -56,23 +56,7 @@ impl Rule for SortJsxProps {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let props = ctx.query().clone();
-        let mut current_prop_group = PropGroup::default();
-        let mut prop_groups = Vec::new();
-        for prop in props.clone() {
-            match prop {
-                AnyJsxAttribute::JsxAttribute(attr) => {
-                    current_prop_group.props.push(PropElement { prop: attr });
-                }
-                // spread prop reset sort order
-                AnyJsxAttribute::JsxSpreadAttribute(_) => {
-                    prop_groups.push(current_prop_group);
-                    current_prop_group = PropGroup::default();
-                }
-            }
-        }
-        prop_groups.push(current_prop_group);
-        prop_groups.into_boxed_slice()
+        prop_groups(ctx.query())
     }
 
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
@@ -80,12 +64,7 @@ impl Rule for SortJsxProps {
             return None;
         }
         let mut mutation = ctx.root().begin();
-
-        for (PropElement { prop }, PropElement { prop: sorted_prop }) in
-            zip(state.props.clone(), state.get_sorted_props())
-        {
-            mutation.replace_node(prop, sorted_prop);
-        }
+        state.sort(&mut mutation);
 
         Some(RuleAction::new(
             rule_action_category!(),
@@ -96,6 +75,26 @@ impl Rule for SortJsxProps {
     }
 }
 
+/// Splits the props at the spread props, which can override the previous props
+pub(crate) fn prop_groups(props: &JsxAttributeList) -> Box<[PropGroup]> {
+    let mut current_prop_group = PropGroup::default();
+    let mut prop_groups = Vec::new();
+    for prop in props.clone() {
+        match prop {
+            AnyJsxAttribute::JsxAttribute(attr) => {
+                current_prop_group.props.push(PropElement { prop: attr });
+            }
+            // spread prop reset sort order
+            AnyJsxAttribute::JsxSpreadAttribute(_) => {
+                prop_groups.push(current_prop_group);
+                current_prop_group = PropGroup::default();
+            }
+        }
+    }
+    prop_groups.push(current_prop_group);
+    prop_groups.into_boxed_slice()
+}
+
 #[derive(PartialEq, Eq, Clone)]
 pub struct PropElement {
     prop: JsxAttribute,
@@ -124,7 +123,7 @@ pub struct PropGroup {
 }
 
 impl PropGroup {
-    fn is_sorted(&self) -> bool {
+    pub(crate) fn is_sorted(&self) -> bool {
         let mut new_props = self.props.clone();
         new_props.sort();
         new_props == self.props
@@ -135,4 +134,19 @@ impl PropGroup {
         new_props.sort();
         new_props
     }
+
+    /// Only keeps the props that intersect `range`
+    pub(crate) fn retain_in_range(&mut self, range: TextRange) {
+        self.props
+            .retain(|PropElement { prop }| prop.range().intersect(range).is_some());
+    }
+
+    /// Replaces the props of the group with the sorted props
+    pub(crate) fn sort(&self, mutation: &mut BatchMutation<JsLanguage>) {
+        for (PropElement { prop }, PropElement { prop: sorted_prop }) in
+            zip(self.props.clone(), self.get_sorted_props())
+        {
+            mutation.replace_node(prop, sorted_prop);
+        }
+    }
 }
@@ -3,13 +3,14 @@
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    GroupCategory, InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, Queryable,
+    RegistryVisitor, Rule, RuleAction, RuleCategory, RuleGroup, RuleRegistry, SuppressionKind,
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Error as DiagnosticError};
+use biome_js_semantic::TemplateReferences;
 use biome_js_syntax::{JsFileSource, JsLanguage};
-use biome_project::PackageJson;
+use biome_project::{ModuleGraph, PackageJson};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
 use std::sync::{Arc, LazyLock};
@@ -28,6 +29,7 @@ pub mod utils;
 
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+use crate::services::module_graph::ModuleGraphServices;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -37,6 +39,28 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     metadata
 });
 
+/// The information about the project of the analyzed file, that the rules can
+/// query through their services
+#[derive(Debug, Clone, Default)]
+pub struct JsAnalyzerServices {
+    /// The `package.json` manifest of the project
+    pub manifest: Option<PackageJson>,
+    /// The imports and exports of the modules of the project
+    pub module_graph: Arc<ModuleGraph>,
+    /// The names referenced by the template of the Vue, Svelte or Astro
+    /// component that embeds the analyzed script
+    pub template_references: TemplateReferences,
+}
+
+impl From<Option<PackageJson>> for JsAnalyzerServices {
+    fn from(manifest: Option<PackageJson>) -> Self {
+        Self {
+            manifest,
+            ..Self::default()
+        }
+    }
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
@@ -49,7 +73,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    project: JsAnalyzerServices,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -121,9 +145,15 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if filter.categories.contains(RuleCategory::Lint) {
+        analyzer.add_plugins(options.plugins().clone());
+    }
+
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
-    services.insert_service(Arc::new(manifest));
+    services.insert_service(Arc::new(project.manifest));
+    services.insert_service(project.module_graph);
+    services.insert_service(project.template_references);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -144,7 +174,7 @@ pub fn analyze<'a, F, B>(
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    project: JsAnalyzerServices,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -157,11 +187,55 @@ where
         |_| {},
         options,
         source_type,
-        manifest,
+        project,
         emit_signal,
     )
 }
 
+/// Returns whether one of the rules enabled by `filter` queries the module
+/// graph of the project, so the graph only needs to be built when it's `true`
+pub fn uses_module_graph(filter: &AnalysisFilter) -> bool {
+    struct ModuleGraphVisitor<'a> {
+        filter: &'a AnalysisFilter<'a>,
+        uses_module_graph: bool,
+    }
+
+    impl RegistryVisitor<JsLanguage> for ModuleGraphVisitor<'_> {
+        fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+            if self.filter.match_category::<C>() {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_group<G: RuleGroup<Language = JsLanguage>>(&mut self) {
+            if self.filter.match_group::<G>() {
+                G::record_rules(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+        {
+            // The services of a query aren't required to be `'static`, so
+            // they can't be compared with their `TypeId`
+            if self.filter.match_rule::<R>()
+                && std::any::type_name::<<R::Query as Queryable>::Services>()
+                    == std::any::type_name::<ModuleGraphServices>()
+            {
+                self.uses_module_graph = true;
+            }
+        }
+    }
+
+    let mut visitor = ModuleGraphVisitor {
+        filter,
+        uses_module_graph: false,
+    };
+    visit_registry(&mut visitor);
+    visitor.uses_module_graph
+}
+
 #[cfg(test)]
 mod tests {
     use biome_analyze::{AnalyzerOptions, Never, RuleCategoriesBuilder, RuleFilter};
@@ -175,7 +249,7 @@ mod tests {
     use biome_project::{Dependencies, PackageJson};
     use std::slice;
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    use crate::{analyze, AnalysisFilter, ControlFlow, JsAnalyzerServices};
 
     #[ignore]
     #[test]
@@ -210,7 +284,8 @@ mod tests {
             Some(PackageJson {
                 dependencies,
                 ..Default::default()
-            }),
+            })
+            .into(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -302,7 +377,7 @@ mod tests {
             AnalysisFilter::default(),
             &options,
             JsFileSource::js_module(),
-            None,
+            JsAnalyzerServices::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -388,7 +463,7 @@ mod tests {
             filter,
             &options,
             JsFileSource::js_module(),
-            None,
+            JsAnalyzerServices::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
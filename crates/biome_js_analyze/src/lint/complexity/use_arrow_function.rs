@@ -84,45 +84,7 @@ impl Rule for UseArrowFunction {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let AnyThisScopeMetadata { scope, has_this } = ctx.query();
-        if *has_this {
-            return None;
-        }
-        let AnyThisScope::JsFunctionExpression(function_expression) = scope else {
-            return None;
-        };
-        if function_expression.star_token().is_some() || function_expression.id().is_some() {
-            // Ignore generators and function with a name.
-            return None;
-        }
-        let has_this_parameter = function_expression
-            .parameters()
-            .ok()?
-            .items()
-            .iter()
-            .nth(0)
-            .and_then(|param| param.ok())
-            .is_some_and(|param| param.as_ts_this_parameter().is_some());
-        if has_this_parameter {
-            // Ignore functions that explicitly declare a `this` type.
-            return None;
-        }
-        let requires_prototype = function_expression
-            .syntax()
-            .ancestors()
-            .skip(1)
-            .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
-            .is_some_and(|ancestor| {
-                matches!(
-                    ancestor.kind(),
-                    JsSyntaxKind::JS_NEW_EXPRESSION | JsSyntaxKind::JS_EXTENDS_CLAUSE
-                )
-            });
-        if requires_prototype {
-            // Ignore cases where a prototype is required
-            return None;
-        }
-        Some(())
+        convertible_function_expression(ctx.query()).map(|_| ())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -145,44 +107,10 @@ impl Rule for UseArrowFunction {
         let AnyThisScope::JsFunctionExpression(function_expression) = scope else {
             return None;
         };
-        let mut arrow_function_builder = make::js_arrow_function_expression(
-            function_expression.parameters().ok()?.into(),
-            make::token(T![=>]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
-            to_arrow_body(function_expression.body().ok()?),
-        );
-        if let Some(async_token) = function_expression.async_token() {
-            arrow_function_builder = arrow_function_builder.with_async_token(async_token);
-        }
-        if let Some(type_parameters) = function_expression.type_parameters() {
-            let mut type_parameters_iter =
-                type_parameters.items().iter().filter_map(|item| item.ok());
-            let type_parameter = type_parameters_iter.next();
-            // Keep a trailing comma when there is a single type parameter in arrow functions and JSX is enabled
-            // Or the parser will treat it as a JSX tag and fail to parse it.
-            let type_parameters = if type_parameter.is_some()
-                && type_parameters_iter.next().is_none()
-                && ctx.source_type::<JsFileSource>().is_jsx()
-            {
-                make::ts_type_parameters(
-                    make::token(T![<]),
-                    make::ts_type_parameter_list(type_parameter, Some(make::token(T![,]))),
-                    make::token(T![>]),
-                )
-            } else {
-                type_parameters
-            };
-            arrow_function_builder = arrow_function_builder.with_type_parameters(type_parameters);
-        }
-        if let Some(return_type_annotation) = function_expression.return_type_annotation() {
-            arrow_function_builder =
-                arrow_function_builder.with_return_type_annotation(return_type_annotation);
-        }
-        let arrow_function = arrow_function_builder.build();
-        let arrow_function = if needs_parentheses(function_expression) {
-            AnyJsExpression::from(make::parenthesized(arrow_function.trim_trailing_trivia()?))
-        } else {
-            AnyJsExpression::from(arrow_function)
-        };
+        let arrow_function = to_arrow_function(
+            function_expression,
+            ctx.source_type::<JsFileSource>().is_jsx(),
+        )?;
         let mut mutation = ctx.root().begin();
         mutation.replace_node(
             AnyJsExpression::from(function_expression.clone()),
@@ -197,6 +125,96 @@ impl Rule for UseArrowFunction {
     }
 }
 
+/// Returns the function expression of `scope_metadata` when it can be turned
+/// into an arrow function
+pub(crate) fn convertible_function_expression(
+    scope_metadata: &AnyThisScopeMetadata,
+) -> Option<&JsFunctionExpression> {
+    let AnyThisScopeMetadata { scope, has_this } = scope_metadata;
+    if *has_this {
+        return None;
+    }
+    let AnyThisScope::JsFunctionExpression(function_expression) = scope else {
+        return None;
+    };
+    if function_expression.star_token().is_some() || function_expression.id().is_some() {
+        // Ignore generators and function with a name.
+        return None;
+    }
+    let has_this_parameter = function_expression
+        .parameters()
+        .ok()?
+        .items()
+        .iter()
+        .next()
+        .and_then(|param| param.ok())
+        .is_some_and(|param| param.as_ts_this_parameter().is_some());
+    if has_this_parameter {
+        // Ignore functions that explicitly declare a `this` type.
+        return None;
+    }
+    let requires_prototype = function_expression
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .is_some_and(|ancestor| {
+            matches!(
+                ancestor.kind(),
+                JsSyntaxKind::JS_NEW_EXPRESSION | JsSyntaxKind::JS_EXTENDS_CLAUSE
+            )
+        });
+    if requires_prototype {
+        // Ignore cases where a prototype is required
+        return None;
+    }
+    Some(function_expression)
+}
+
+/// Turns `function_expression` into the equivalent arrow function
+pub(crate) fn to_arrow_function(
+    function_expression: &JsFunctionExpression,
+    is_jsx: bool,
+) -> Option<AnyJsExpression> {
+    let mut arrow_function_builder = make::js_arrow_function_expression(
+        function_expression.parameters().ok()?.into(),
+        make::token(T![=>]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        to_arrow_body(function_expression.body().ok()?),
+    );
+    if let Some(async_token) = function_expression.async_token() {
+        arrow_function_builder = arrow_function_builder.with_async_token(async_token);
+    }
+    if let Some(type_parameters) = function_expression.type_parameters() {
+        let mut type_parameters_iter = type_parameters.items().iter().filter_map(|item| item.ok());
+        let type_parameter = type_parameters_iter.next();
+        // Keep a trailing comma when there is a single type parameter in arrow functions and JSX is enabled
+        // Or the parser will treat it as a JSX tag and fail to parse it.
+        let type_parameters =
+            if type_parameter.is_some() && type_parameters_iter.next().is_none() && is_jsx {
+                make::ts_type_parameters(
+                    make::token(T![<]),
+                    make::ts_type_parameter_list(type_parameter, Some(make::token(T![,]))),
+                    make::token(T![>]),
+                )
+            } else {
+                type_parameters
+            };
+        arrow_function_builder = arrow_function_builder.with_type_parameters(type_parameters);
+    }
+    if let Some(return_type_annotation) = function_expression.return_type_annotation() {
+        arrow_function_builder =
+            arrow_function_builder.with_return_type_annotation(return_type_annotation);
+    }
+    let arrow_function = arrow_function_builder.build();
+    if needs_parentheses(function_expression) {
+        Some(AnyJsExpression::from(make::parenthesized(
+            arrow_function.trim_trailing_trivia()?,
+        )))
+    } else {
+        Some(AnyJsExpression::from(arrow_function))
+    }
+}
+
 /// Returns `true` if `function_expr` needs parenthesis when turned into an arrow function.
 fn needs_parentheses(function_expression: &JsFunctionExpression) -> bool {
     function_expression.syntax().parent().is_some_and(|parent| {
@@ -27,6 +27,9 @@ declare_lint_rule! {
     /// the unused imports will also be removed. So that comment directives
     /// like `@ts-expect-error` won't be transferred to a wrong place.
     ///
+    /// In Vue, Svelte and Astro files, the imports used by the template of the
+    /// component, or by its other scripts, aren't reported.
+    ///
     /// ## Options
     ///
     /// This rule respects the [`jsxRuntime`](https://biomejs.dev/reference/configuration/#javascriptjsxruntime)
@@ -103,6 +106,9 @@ impl Rule for NoUnusedImports {
             return None;
         }
         let model = ctx.model();
+        if model.is_referenced_by_template(binding) {
+            return None;
+        }
         binding.all_references(model).next().is_none().then_some(())
     }
 
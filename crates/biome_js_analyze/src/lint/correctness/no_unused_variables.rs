@@ -34,6 +34,9 @@ declare_lint_rule! {
     /// If you want to report unused function parameters,
     /// enable [noUnusedFunctionParameters](https://biomejs.dev/linter/rules/no-unused-function-parameters/).
     ///
+    /// In Vue, Svelte and Astro files, the top-level variables used by the template
+    /// of the component, or by its other scripts, aren't reported.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -313,7 +316,7 @@ impl Rule for NoUnusedVariables {
 
         let suggestion = suggested_fix_if_unused(binding)?;
 
-        if model.is_exported(binding) {
+        if model.is_exported(binding) || model.is_referenced_by_template(binding) {
             return None;
         }
 
@@ -12,6 +12,7 @@ pub mod no_exported_imports;
 pub mod no_head_element;
 pub mod no_head_import_in_document;
 pub mod no_img_element;
+pub mod no_import_cycles;
 pub mod no_irregular_whitespace;
 pub mod no_nested_ternary;
 pub mod no_octal_escape;
@@ -22,6 +23,7 @@ pub mod no_secrets;
 pub mod no_static_element_interactions;
 pub mod no_substr;
 pub mod no_template_curly_in_string;
+pub mod no_unresolved_imports;
 pub mod no_useless_escape_in_regex;
 pub mod no_useless_string_raw;
 pub mod use_adjacent_overload_signatures;
@@ -52,6 +54,7 @@ declare_lint_group! {
             self :: no_head_element :: NoHeadElement ,
             self :: no_head_import_in_document :: NoHeadImportInDocument ,
             self :: no_img_element :: NoImgElement ,
+            self :: no_import_cycles :: NoImportCycles ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
             self :: no_nested_ternary :: NoNestedTernary ,
             self :: no_octal_escape :: NoOctalEscape ,
@@ -62,6 +65,7 @@ declare_lint_group! {
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
             self :: no_template_curly_in_string :: NoTemplateCurlyInString ,
+            self :: no_unresolved_imports :: NoUnresolvedImports ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_useless_string_raw :: NoUselessStringRaw ,
             self :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures ,
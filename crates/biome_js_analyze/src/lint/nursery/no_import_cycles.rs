@@ -0,0 +1,145 @@
+use crate::services::module_graph::Modules;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::JsModule;
+use biome_rowan::TextRange;
+use std::path::{Component, Path, PathBuf};
+
+declare_lint_rule! {
+    /// Disallow import cycles.
+    ///
+    /// An import cycle happens when a module imports, directly or through other modules, a module
+    /// that imports it back. The modules of a cycle depend on the order in which they're evaluated:
+    /// a module of the cycle may read the exports of another one before they're initialized.
+    ///
+    /// The rule resolves the import specifiers of the modules of the project: the relative
+    /// specifiers, e.g. `./a.js`, the `paths` and the `baseUrl` of the closest `tsconfig.json`,
+    /// and the `exports` of the `package.json` of the packages of the project. The rule reports
+    /// the static imports and re-exports that are part of a cycle, with the modules of the cycle.
+    ///
+    /// The type-only imports and the dynamic imports are ignored, because they don't load the
+    /// imported module when the importing module is loaded.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// A cycle spans several files, so the invalid examples can't be checked on their own.
+    ///
+    /// Given a file `a.js`:
+    ///
+    /// ```js,ignore
+    /// import { b } from "./b.js";
+    ///
+    /// export const a = () => b();
+    /// ```
+    ///
+    /// And a file `b.js`:
+    ///
+    /// ```js,ignore
+    /// import { a } from "./a.js";
+    ///
+    /// export const b = () => a();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// import type { B } from "./b.js";
+    ///
+    /// export type A = B[];
+    /// ```
+    ///
+    /// ```js
+    /// export const a = () => import("./b.js");
+    /// ```
+    pub NoImportCycles {
+        version: "next",
+        name: "noImportCycles",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for NoImportCycles {
+    type Query = Modules<JsModule>;
+    type State = ImportCycle;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let module_graph = ctx.module_graph();
+        let path = ctx.file_path();
+        let Some(info) = module_graph.get(path) else {
+            return Box::default();
+        };
+
+        info.imports
+            .iter()
+            .filter(|import| import.kind.is_static() && !import.is_type_only)
+            .filter_map(|import| {
+                let imported = module_graph.resolve(path, &import.specifier)?;
+                let modules = module_graph.import_chain(&imported, path)?;
+                Some(ImportCycle {
+                    range: import.range,
+                    modules,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let directory = ctx.file_path().parent().unwrap_or(Path::new(""));
+        let modules: Vec<_> = state
+            .modules
+            .iter()
+            .map(|path| relative_path(path, directory))
+            .collect();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This import is part of a cycle."
+                },
+            )
+            .note(markup! {
+                "Move the code that the modules share to another module, or use a dynamic import."
+            })
+            .footer_list(
+                markup! {
+                    "This module imports itself through these modules:"
+                },
+                &modules,
+            ),
+        )
+    }
+}
+
+pub struct ImportCycle {
+    /// The range of the specifier of the import
+    range: TextRange,
+    /// The modules of the cycle, from the imported module to the importing
+    /// module
+    modules: Vec<PathBuf>,
+}
+
+/// Returns `path` relative to `directory`, e.g. `../utils/a.ts`, with `/` as
+/// separator
+fn relative_path(path: &Path, directory: &Path) -> String {
+    let mut path_components = path.components().peekable();
+    let mut directory_components = directory
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .peekable();
+    while path_components.peek().is_some() && path_components.peek() == directory_components.peek()
+    {
+        path_components.next();
+        directory_components.next();
+    }
+
+    let parents = directory_components.map(|_| "..".to_string());
+    let names =
+        path_components.map(|component| component.as_os_str().to_string_lossy().to_string());
+    parents.chain(names).collect::<Vec<_>>().join("/")
+}
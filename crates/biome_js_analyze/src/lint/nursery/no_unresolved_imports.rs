@@ -0,0 +1,172 @@
+use crate::services::module_graph::Modules;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsNamedImportSpecifier, JsImport,
+    JsNamedImportSpecifiers,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+declare_lint_rule! {
+    /// Disallow importing names that aren't exported by the imported module.
+    ///
+    /// The rule checks the default and named imports of the relative modules of the project,
+    /// e.g. `import { a } from "./a.js"`. It uses the imports and the exports of the other files
+    /// of the project, so it only reports an import when the imported module is known
+    /// and doesn't export the imported name, even through an `export * from "..."` re-export.
+    ///
+    /// The imports of packages, and of modules that don't have any export, such as CommonJS modules,
+    /// are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// Given a file `a.js` that contains `export const a = 0;`:
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// import { b } from "./a.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// import a from "./a.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// import { a } from "./a.js";
+    /// ```
+    ///
+    /// ```js,ignore
+    /// import * as ns from "./a.js";
+    /// ```
+    pub NoUnresolvedImports {
+        version: "next",
+        name: "noUnresolvedImports",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnresolvedImports {
+    type Query = Modules<JsImport>;
+    type State = UnresolvedImport;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Ok(import_clause) = node.import_clause() else {
+            return Box::default();
+        };
+        let Some(specifier) = import_clause
+            .source()
+            .ok()
+            .and_then(|source| source.inner_string_text().ok())
+        else {
+            return Box::default();
+        };
+        let module_graph = ctx.module_graph();
+        let Some(path) = module_graph.resolve(ctx.file_path(), specifier.text()) else {
+            return Box::default();
+        };
+
+        imported_names(&import_clause)
+            .into_iter()
+            .filter(|(name, _)| module_graph.has_export(&path, name) == Some(false))
+            .map(|(name, range)| UnresolvedImport {
+                name,
+                specifier: specifier.text().to_string(),
+                range,
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnresolvedImport {
+            name,
+            specifier,
+            range,
+        } = state;
+        let message = if name == "default" {
+            markup! {
+                "The module "<Emphasis>{specifier}</Emphasis>" doesn't have a default export."
+            }
+            .to_owned()
+        } else {
+            markup! {
+                "The module "<Emphasis>{specifier}</Emphasis>" doesn't export "<Emphasis>{name}</Emphasis>"."
+            }
+            .to_owned()
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Export the name from the imported module, or fix the import."
+            }),
+        )
+    }
+}
+
+pub struct UnresolvedImport {
+    /// The imported name, `default` for the default import
+    name: String,
+    specifier: String,
+    range: TextRange,
+}
+
+/// Returns the names imported by `import_clause`, with the range of their
+/// specifier
+fn imported_names(import_clause: &AnyJsImportClause) -> Vec<(String, TextRange)> {
+    let mut names = Vec::new();
+    match import_clause {
+        AnyJsImportClause::JsImportCombinedClause(clause) => {
+            if let Ok(default_specifier) = clause.default_specifier() {
+                names.push(("default".to_string(), default_specifier.range()));
+            }
+            if let Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) =
+                clause.specifier()
+            {
+                collect_named_imports(&specifiers, &mut names);
+            }
+        }
+        AnyJsImportClause::JsImportDefaultClause(clause) => {
+            if let Ok(default_specifier) = clause.default_specifier() {
+                names.push(("default".to_string(), default_specifier.range()));
+            }
+        }
+        AnyJsImportClause::JsImportNamedClause(clause) => {
+            if let Ok(specifiers) = clause.named_specifiers() {
+                collect_named_imports(&specifiers, &mut names);
+            }
+        }
+        AnyJsImportClause::JsImportBareClause(_)
+        | AnyJsImportClause::JsImportNamespaceClause(_) => {}
+    }
+    names
+}
+
+fn collect_named_imports(
+    specifiers: &JsNamedImportSpecifiers,
+    names: &mut Vec<(String, TextRange)>,
+) {
+    for specifier in specifiers.specifiers().iter().flatten() {
+        match &specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(named_specifier) => {
+                if let Some(name) = named_specifier
+                    .name()
+                    .ok()
+                    .and_then(|name| name.inner_string_text().ok())
+                {
+                    names.push((name.text().to_string(), specifier.range()));
+                }
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+                if let Some(name) = specifier.imported_name() {
+                    names.push((name.text_trimmed().to_string(), specifier.range()));
+                }
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+        }
+    }
+}
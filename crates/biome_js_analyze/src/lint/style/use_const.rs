@@ -208,6 +208,12 @@ fn check_binding_can_be_const(
     has_initializer: bool,
     model: &SemanticModel,
 ) -> Option<ConstCheckResult> {
+    // The assignments of the template of a Vue, Svelte or Astro component
+    // aren't part of the script
+    if model.is_written_by_template(binding) {
+        return None;
+    }
+
     let mut writes = binding.all_writes(model);
 
     // In a for-in or for-of loop or if it has an initializer
@@ -43,6 +43,9 @@ declare_lint_rule! {
     /// This setting is available in Visual Studio Code.
     /// It ensures the `type` is used when the editor automatically imports a type.
     ///
+    /// In Vue, Svelte and Astro files, the imports used by the template of the component,
+    /// or by its other scripts, are considered used as values.
+    ///
     /// ## Caveat with TypeScript experimental decorators
     ///
     /// Some frameworks like Angular and NestJS rely on
@@ -598,8 +601,12 @@ pub enum ImportTypeFix {
 }
 
 /// Returns `true` if all references of `binding` are only used as a type.
-/// If there is no reference, then returns `false`.
+/// If there is no reference, or if the template of the component references it,
+/// then returns `false`.
 fn is_only_used_as_type(model: &SemanticModel, binding: &JsIdentifierBinding) -> bool {
+    if model.is_referenced_by_template(binding) {
+        return false;
+    }
     let mut result = false;
     for reference in binding.all_references(model) {
         if let Some(reference) = AnyJsIdentifierUsage::cast_ref(reference.syntax()) {
@@ -63,19 +63,7 @@ impl Rule for UseTemplate {
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
-        let node = ctx.query();
-        // Do not handle binary operations contained in a binary operation with operator `+`
-        if node
-            .syntax()
-            .ancestors()
-            .skip(1) // skip node
-            .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
-            .and_then(JsBinaryExpression::cast)
-            .is_some_and(|parent| parent.operator() == Ok(JsBinaryOperator::Plus))
-        {
-            return None;
-        }
-        can_be_template_literal(node)?.then_some(())
+        is_convertible_concatenation(ctx.query()).then_some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -106,6 +94,23 @@ impl Rule for UseTemplate {
     }
 }
 
+/// Returns true if `node` is a concatenation that can be converted to a
+/// template literal, and isn't part of a larger concatenation
+pub(crate) fn is_convertible_concatenation(node: &JsBinaryExpression) -> bool {
+    // Do not handle binary operations contained in a binary operation with operator `+`
+    if node
+        .syntax()
+        .ancestors()
+        .skip(1) // skip node
+        .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
+        .and_then(JsBinaryExpression::cast)
+        .is_some_and(|parent| parent.operator() == Ok(JsBinaryOperator::Plus))
+    {
+        return false;
+    }
+    can_be_template_literal(node).unwrap_or(false)
+}
+
 /// Returns true if `node` can be converted to a template literal.
 ///
 /// This is the case, if:
@@ -157,7 +162,7 @@ fn can_be_template_literal(node: &JsBinaryExpression) -> Option<bool> {
     Some(false)
 }
 
-fn template_expression_from_binary_expression(
+pub(crate) fn template_expression_from_binary_expression(
     node: &JsBinaryExpression,
 ) -> Option<JsTemplateExpression> {
     // For each nested binary expression (with operator `+`), we keep track of the last left expressions
@@ -3,6 +3,12 @@
 use crate::assists;
 use crate::lint;
 
+pub type ConvertToArrowFunction = < assists :: refactor :: convert_to_arrow_function :: ConvertToArrowFunction as biome_analyze :: Rule > :: Options ;
+pub type ConvertToTemplateLiteral = < assists :: refactor :: convert_to_template_literal :: ConvertToTemplateLiteral as biome_analyze :: Rule > :: Options ;
+pub type ExtractToVariable =
+    <assists::refactor::extract_to_variable::ExtractToVariable as biome_analyze::Rule>::Options;
+pub type InlineVariable =
+    <assists::refactor::inline_variable::InlineVariable as biome_analyze::Rule>::Options;
 pub type NoAccessKey = <lint::a11y::no_access_key::NoAccessKey as biome_analyze::Rule>::Options;
 pub type NoAccumulatingSpread = < lint :: performance :: no_accumulating_spread :: NoAccumulatingSpread as biome_analyze :: Rule > :: Options ;
 pub type NoApproximativeNumericConstant = < lint :: suspicious :: no_approximative_numeric_constant :: NoApproximativeNumericConstant as biome_analyze :: Rule > :: Options ;
@@ -127,6 +133,8 @@ pub type NoImplicitBoolean =
     <lint::style::no_implicit_boolean::NoImplicitBoolean as biome_analyze::Rule>::Options;
 pub type NoImportAssign =
     <lint::suspicious::no_import_assign::NoImportAssign as biome_analyze::Rule>::Options;
+pub type NoImportCycles =
+    <lint::nursery::no_import_cycles::NoImportCycles as biome_analyze::Rule>::Options;
 pub type NoInferrableTypes =
     <lint::style::no_inferrable_types::NoInferrableTypes as biome_analyze::Rule>::Options;
 pub type NoInnerDeclarations =
@@ -228,6 +236,8 @@ pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
 pub type NoUnreachableSuper =
     <lint::correctness::no_unreachable_super::NoUnreachableSuper as biome_analyze::Rule>::Options;
+pub type NoUnresolvedImports =
+    <lint::nursery::no_unresolved_imports::NoUnresolvedImports as biome_analyze::Rule>::Options;
 pub type NoUnsafeDeclarationMerging = < lint :: suspicious :: no_unsafe_declaration_merging :: NoUnsafeDeclarationMerging as biome_analyze :: Rule > :: Options ;
 pub type NoUnsafeFinally =
     <lint::correctness::no_unsafe_finally::NoUnsafeFinally as biome_analyze::Rule>::Options;
@@ -278,8 +288,10 @@ pub type NoYodaExpression =
     <lint::style::no_yoda_expression::NoYodaExpression as biome_analyze::Rule>::Options;
 pub type OrganizeImports =
     <assists::source::organize_imports::OrganizeImports as biome_analyze::Rule>::Options;
+pub type OrganizeSelectedImports = < assists :: refactor :: organize_selected_imports :: OrganizeSelectedImports as biome_analyze :: Rule > :: Options ;
 pub type SortJsxProps =
     <assists::source::sort_jsx_props::SortJsxProps as biome_analyze::Rule>::Options;
+pub type SortSelectedJsxProps = < assists :: refactor :: sort_selected_jsx_props :: SortSelectedJsxProps as biome_analyze :: Rule > :: Options ;
 pub type UseAdjacentOverloadSignatures = < lint :: nursery :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures as biome_analyze :: Rule > :: Options ;
 pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
 pub type UseAnchorContent =
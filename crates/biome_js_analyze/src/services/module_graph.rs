@@ -0,0 +1,70 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
+use biome_project::ModuleGraph;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct ModuleGraphServices {
+    pub(crate) module_graph: Arc<ModuleGraph>,
+}
+
+impl ModuleGraphServices {
+    /// The imports and exports of the modules of the project. The analyzed
+    /// file can be found in the graph with [biome_analyze::context::RuleContext::file_path].
+    pub(crate) fn module_graph(&self) -> &ModuleGraph {
+        self.module_graph.as_ref()
+    }
+}
+
+impl FromServices for ModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        let module_graph: &Arc<ModuleGraph> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["ModuleGraph"])
+        })?;
+
+        Ok(Self {
+            module_graph: module_graph.clone(),
+        })
+    }
+}
+
+impl Phase for ModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the module graph of the project**
+/// to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct Modules<N>(pub N);
+
+impl<N> Queryable for Modules<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ModuleGraphServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
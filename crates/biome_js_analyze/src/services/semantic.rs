@@ -2,7 +2,9 @@ use biome_analyze::{
     AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, QueryMatch,
     Queryable, RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
 };
-use biome_js_semantic::{SemanticEventExtractor, SemanticModel, SemanticModelBuilder};
+use biome_js_semantic::{
+    SemanticEventExtractor, SemanticModel, SemanticModelBuilder, TemplateReferences,
+};
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode, TextRange, WalkEvent};
 use biome_rowan::AstNode;
 
@@ -119,7 +121,16 @@ impl Visitor for SemanticModelBuilderVisitor {
         }
     }
 
-    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+    fn finish(mut self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        if let Some(references) = ctx.services.get_service::<TemplateReferences>() {
+            for name in &references.reads {
+                self.builder.push_template_reference(name.as_str());
+            }
+            for name in &references.writes {
+                self.builder.push_template_write(name.as_str());
+            }
+        }
+
         let model = self.builder.build();
         ctx.services.insert_service(model);
     }
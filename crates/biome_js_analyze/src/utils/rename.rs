@@ -80,6 +80,13 @@ pub enum RenameError {
         original_range: TextRange,
         new_name: String,
     },
+    /// The renamed export is imported by a module that accesses it through a
+    /// namespace, e.g. `import * as ns` or `require()`, whose uses can't be
+    /// renamed
+    ImportedAsNamespace {
+        export_name: String,
+        importer: String,
+    },
 }
 
 impl std::fmt::Display for RenameError {
@@ -101,6 +108,15 @@ impl std::fmt::Display for RenameError {
                     "encountered an error finding a declaration at the specified position"
                 )
             }
+            RenameError::ImportedAsNamespace {
+                export_name,
+                importer,
+            } => {
+                write!(
+                    f,
+                    "the export \"{export_name}\" can't be renamed because \"{importer}\" imports it through a namespace"
+                )
+            }
         }
     }
 }
@@ -110,6 +126,10 @@ impl Diagnostic for RenameError {
         Severity::Error
     }
 
+    fn description(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+
     fn message(&self, fmt: &mut Formatter<'_>) -> std::io::Result<()> {
         match self {
             RenameError::CannotFindDeclaration(node) => {
@@ -122,6 +142,11 @@ impl Diagnostic for RenameError {
                     markup! { "Can't rename from "<Emphasis>{{original_name}}</Emphasis>" to "<Emphasis>{{new_name}}</Emphasis>"" }
                 )
             }
+            RenameError::ImportedAsNamespace { export_name, importer } => {
+                fmt.write_markup(
+                    markup! { "Can't rename the export "<Emphasis>{{export_name}}</Emphasis>" because "<Emphasis>{{importer}}</Emphasis>" imports it through a namespace" }
+                )
+            }
         }
     }
 
@@ -1,21 +1,28 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_js_analyze::JsAnalyzerServices;
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{JsFileSource, JsLanguage, ModuleKind};
-use biome_project::PackageType;
-use biome_rowan::AstNode;
+use biome_project::{ModuleGraph, ModuleInfo, PackageType};
+use biome_rowan::{AstNode, TextRange, TextSize};
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
     scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_suppression_test, "module"}
 
+/// The markers of the range the analysis is restricted to, such as the
+/// selection of the editor when the refactors are computed
+const RANGE_START_MARKER: &str = "<<<ROME_RANGE_START>>>";
+const RANGE_END_MARKER: &str = "<<<ROME_RANGE_END>>>";
+
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
 
@@ -37,17 +44,19 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         panic!("could not find rule {group}/{rule}");
     }
 
+    let mut snapshot = String::new();
+    let extension = input_file.extension().unwrap_or_default();
+
+    let mut input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+    let range = strip_range_markers(&mut input_code);
+
     let rule_filter = RuleFilter::Rule(group, rule);
     let filter = AnalysisFilter {
         enabled_rules: Some(slice::from_ref(&rule_filter)),
+        range,
         ..AnalysisFilter::default()
     };
-
-    let mut snapshot = String::new();
-    let extension = input_file.extension().unwrap_or_default();
-
-    let input_code = read_to_string(input_file)
-        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
     let quantity_diagnostics = if let Some(scripts) = scripts_from_json(extension, &input_code) {
         for script in scripts {
             analyze_and_snap(
@@ -91,6 +100,62 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     }
 }
 
+/// Removes the range markers from `input_code`, and returns the range they
+/// delimit
+fn strip_range_markers(input_code: &mut String) -> Option<TextRange> {
+    let start = input_code.find(RANGE_START_MARKER)?;
+    input_code.replace_range(start..start + RANGE_START_MARKER.len(), "");
+    let end = input_code
+        .find(RANGE_END_MARKER)
+        .expect("the range start marker must be followed by a range end marker");
+    input_code.replace_range(end..end + RANGE_END_MARKER.len(), "");
+
+    Some(TextRange::new(
+        TextSize::try_from(start).unwrap(),
+        TextSize::try_from(end).unwrap(),
+    ))
+}
+
+/// Creates the module graph of the files of the directory of the rule of
+/// `input_file`, and of its subdirectories, so that the test files can import
+/// each other
+fn load_module_graph(input_file: &Path) -> Arc<ModuleGraph> {
+    let module_graph = ModuleGraph::default();
+    let rule_directory = input_file
+        .ancestors()
+        .find(|directory| {
+            directory
+                .parent()
+                .and_then(Path::parent)
+                .and_then(Path::file_name)
+                == Some(OsStr::new("specs"))
+        })
+        .or_else(|| input_file.parent());
+    if let Some(directory) = rule_directory {
+        insert_modules(&module_graph, directory);
+    }
+    Arc::new(module_graph)
+}
+
+fn insert_modules(module_graph: &ModuleGraph, directory: &Path) {
+    let entries = directory.read_dir().into_iter().flatten().flatten();
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            insert_modules(module_graph, &path);
+            continue;
+        }
+        let Ok(source_type) = JsFileSource::try_from(path.as_path()) else {
+            continue;
+        };
+        let Ok(code) = read_to_string(&path) else {
+            continue;
+        };
+        let parsed = parse(&code, source_type, JsParserOptions::default());
+        module_graph.insert(&path, ModuleInfo::from_js_root(&parsed.tree()));
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn analyze_and_snap(
     snapshot: &mut String,
@@ -120,8 +185,13 @@ pub(crate) fn analyze_and_snap(
     //
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
+    let project = JsAnalyzerServices {
+        manifest,
+        module_graph: load_module_graph(input_file),
+        ..Default::default()
+    };
     let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+        biome_js_analyze::analyze(&root, filter, &options, source_type, project, |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -0,0 +1 @@
+export { a as c } from "./a";
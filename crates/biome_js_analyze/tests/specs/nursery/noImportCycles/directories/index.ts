@@ -0,0 +1,5 @@
+import { format } from "./utils";
+
+export const prefix = "> ";
+
+export const print = (value: string) => format(value);
@@ -0,0 +1,3 @@
+import { prefix } from "../index";
+
+export const format = (value: string) => `${prefix}${value}`;
@@ -0,0 +1,3 @@
+import "./selfImport";
+
+export const value = 1;
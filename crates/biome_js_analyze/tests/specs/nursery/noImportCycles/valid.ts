@@ -0,0 +1,5 @@
+import type { Types } from "./validTypes";
+
+export type Valid = Types[];
+
+export const load = () => import("./validDynamic");
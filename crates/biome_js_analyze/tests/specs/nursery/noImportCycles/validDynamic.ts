@@ -0,0 +1,3 @@
+import { load } from "./valid";
+
+export const reload = load;
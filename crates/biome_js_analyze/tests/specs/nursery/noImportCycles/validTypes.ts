@@ -0,0 +1,3 @@
+import type { Valid } from "./valid";
+
+export type Types = Valid | string;
@@ -0,0 +1,7 @@
+export function format(value: string) {
+	return value;
+}
+
+export function trim(value: string) {
+	return value.trim();
+}
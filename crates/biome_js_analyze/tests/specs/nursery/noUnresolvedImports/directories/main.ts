@@ -0,0 +1,4 @@
+import { format, missing } from "./lib";
+import { trim } from "./lib/strings.js";
+
+format(trim(missing));
@@ -0,0 +1,4 @@
+export const a = 1;
+export function b() {}
+export type C = string;
+export default a;
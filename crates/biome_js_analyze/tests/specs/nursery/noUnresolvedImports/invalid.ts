@@ -0,0 +1,5 @@
+import { missing } from "./exports";
+import { a, missingToo } from "./exports";
+import { default as x, f as y } from "./noDefault";
+import noDefault from "./noDefault";
+import reExported, { c } from "./reExports";
@@ -0,0 +1,2 @@
+export * from "./exports";
+export { b as d } from "./exports";
@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+import { a, b, type C } from "./exports";
+import exported from "./exports.js";
+import * as ns from "./noDefault";
+import { a as a2, d } from "./reExports";
+import { e } from "./commonjs";
+import { g } from "./unknown";
+import { useState } from "react";
+import "./noDefault";
@@ -0,0 +1,3 @@
+items.map(<<<ROME_RANGE_START>>>function (item)<<<ROME_RANGE_END>>> {
+	return item.price;
+});
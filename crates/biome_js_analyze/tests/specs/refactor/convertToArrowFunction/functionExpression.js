@@ -0,0 +1,3 @@
+const add = <<<ROME_RANGE_START>>>function (a, b)<<<ROME_RANGE_END>>> {
+	return a + b;
+};
@@ -0,0 +1,3 @@
+const generate = <<<ROME_RANGE_START>>>function* ()<<<ROME_RANGE_END>>> {
+	yield 1;
+};
@@ -0,0 +1,3 @@
+const getName = <<<ROME_RANGE_START>>>function ()<<<ROME_RANGE_END>>> {
+	return this.name;
+};
@@ -0,0 +1,3 @@
+const add = function (a, b) {
+	return a + b;
+};
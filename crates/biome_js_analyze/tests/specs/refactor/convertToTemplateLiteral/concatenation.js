@@ -0,0 +1 @@
+const greeting = <<<ROME_RANGE_START>>>"Hello, " + name + "!"<<<ROME_RANGE_END>>>;
@@ -0,0 +1 @@
+const sum = <<<ROME_RANGE_START>>>a + b<<<ROME_RANGE_END>>>;
@@ -0,0 +1 @@
+const greeting = "Hello, " + name + "!";
@@ -0,0 +1,3 @@
+function total(price, quantity) {
+	console.log(<<<ROME_RANGE_START>>>price * quantity<<<ROME_RANGE_END>>>);
+}
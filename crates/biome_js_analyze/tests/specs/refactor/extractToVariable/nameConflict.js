@@ -0,0 +1,2 @@
+const extracted = 1;
+console.log(<<<ROME_RANGE_START>>>a + b<<<ROME_RANGE_END>>>);
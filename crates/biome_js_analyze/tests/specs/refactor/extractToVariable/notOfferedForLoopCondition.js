@@ -0,0 +1 @@
+for (let i = 0; i < <<<ROME_RANGE_START>>>items.length<<<ROME_RANGE_END>>>; i++) {}
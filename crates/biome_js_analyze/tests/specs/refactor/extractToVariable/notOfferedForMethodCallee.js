@@ -0,0 +1 @@
+<<<ROME_RANGE_START>>>object.method<<<ROME_RANGE_END>>>();
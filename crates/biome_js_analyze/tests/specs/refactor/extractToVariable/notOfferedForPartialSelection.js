@@ -0,0 +1 @@
+console.log(<<<ROME_RANGE_START>>>price *<<<ROME_RANGE_END>>> quantity);
@@ -0,0 +1,6 @@
+function print() {
+	// The total of the order
+	const <<<ROME_RANGE_START>>>total<<<ROME_RANGE_END>>> = price * quantity;
+	// Logs the total
+	console.log(total);
+}
@@ -0,0 +1,2 @@
+const <<<ROME_RANGE_START>>>Class<<<ROME_RANGE_END>>> = factory().getClass();
+new Class();
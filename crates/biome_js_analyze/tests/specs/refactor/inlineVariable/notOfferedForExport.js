@@ -0,0 +1,2 @@
+export const <<<ROME_RANGE_START>>>value<<<ROME_RANGE_END>>> = 1;
+console.log(value);
@@ -0,0 +1,3 @@
+let <<<ROME_RANGE_START>>>count<<<ROME_RANGE_END>>> = 0;
+count += 1;
+console.log(count);
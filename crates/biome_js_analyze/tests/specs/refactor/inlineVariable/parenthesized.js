@@ -0,0 +1,2 @@
+const <<<ROME_RANGE_START>>>sum<<<ROME_RANGE_END>>> = a + b;
+console.log(sum * 2);
@@ -0,0 +1,2 @@
+const <<<ROME_RANGE_START>>>items<<<ROME_RANGE_END>>> = getItems();
+items.forEach(print);
@@ -0,0 +1,2 @@
+const <<<ROME_RANGE_START>>>value<<<ROME_RANGE_END>>> = (first(), second());
+console.log(value, [value]);
@@ -0,0 +1,2 @@
+const <<<ROME_RANGE_START>>>total<<<ROME_RANGE_END>>> = price * quantity;
+console.log(total);
@@ -0,0 +1,4 @@
+import c from "c";
+<<<ROME_RANGE_START>>>import a from "a";
+import b from "b";<<<ROME_RANGE_END>>>
+import d from "d";
@@ -0,0 +1,4 @@
+import c from "c";
+<<<ROME_RANGE_START>>>import b from "b";
+import a from "a";<<<ROME_RANGE_END>>>
+import d from "d";
@@ -0,0 +1,6 @@
+// Header
+import d from "d";
+<<<ROME_RANGE_START>>>// Comment of c
+import c from "c";
+import a from "a";
+import b from "b";<<<ROME_RANGE_END>>>
@@ -0,0 +1 @@
+<Component d="d" <<<ROME_RANGE_START>>>a="a" b="b"<<<ROME_RANGE_END>>> c="c" />;
@@ -0,0 +1 @@
+<Component <<<ROME_RANGE_START>>>d="d" c="c"<<<ROME_RANGE_END>>> b="b" a="a" />;
@@ -42,6 +42,20 @@ pub use scope::*;
 pub struct SemanticModelOptions {
     /// All the allowed globals names
     pub globals: FxHashSet<String>,
+    /// The names referenced by the template of the component that embeds the script
+    pub template_references: TemplateReferences,
+}
+
+/// The names that the template of a component refers to, such as the template
+/// of a Vue, Svelte or Astro file. They are resolved against the top-level
+/// bindings of the script that the component embeds.
+#[derive(Clone, Debug, Default)]
+pub struct TemplateReferences {
+    /// The names read by the template
+    pub reads: FxHashSet<String>,
+    /// The names assigned by the template, such as `count` in `count++` or
+    /// `bind:value={count}`
+    pub writes: FxHashSet<String>,
 }
 
 /// Build the complete [SemanticModel] of a parsed file.
@@ -50,12 +64,23 @@ pub fn semantic_model(root: &AnyJsRoot, options: SemanticModelOptions) -> Semant
     let mut extractor = SemanticEventExtractor::default();
     let mut builder = SemanticModelBuilder::new(root.clone());
 
-    let SemanticModelOptions { globals } = options;
+    let SemanticModelOptions {
+        globals,
+        template_references,
+    } = options;
 
     for global in globals {
         builder.push_global(global);
     }
 
+    for name in template_references.reads {
+        builder.push_template_reference(name);
+    }
+
+    for name in template_references.writes {
+        builder.push_template_write(name);
+    }
+
     let root = root.syntax();
     for node in root.preorder() {
         match node {
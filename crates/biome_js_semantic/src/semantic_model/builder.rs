@@ -26,6 +26,10 @@ pub struct SemanticModelBuilder {
     declared_at_by_start: FxHashMap<TextSize, BindingId>,
     exported: FxHashSet<TextSize>,
     unresolved_references: Vec<SemanticModelUnresolvedReference>,
+    /// The names read by the template of the component that embeds the script
+    template_references: FxHashSet<String>,
+    /// The names assigned by the template of the component that embeds the script
+    template_writes: FxHashSet<String>,
 }
 
 impl SemanticModelBuilder {
@@ -44,6 +48,8 @@ impl SemanticModelBuilder {
             declared_at_by_start: FxHashMap::default(),
             exported: FxHashSet::default(),
             unresolved_references: Vec::new(),
+            template_references: FxHashSet::default(),
+            template_writes: FxHashSet::default(),
         }
     }
 
@@ -123,6 +129,25 @@ impl SemanticModelBuilder {
         self.globals_by_name.insert(name.into(), None);
     }
 
+    /// Records a name referenced by the template of the component that embeds
+    /// the script, such as the template of a Vue, Svelte or Astro file.
+    ///
+    /// The name refers to the top-level binding with the same name, if any.
+    #[inline]
+    pub fn push_template_reference(&mut self, name: impl Into<String>) {
+        self.template_references.insert(name.into());
+    }
+
+    /// Records a name assigned by the template of the component that embeds
+    /// the script, such as `count` in `<button on:click={() => count++}>`.
+    ///
+    /// The top-level binding with the same name, if any, is referenced and
+    /// written by the template.
+    #[inline]
+    pub fn push_template_write(&mut self, name: impl Into<String>) {
+        self.template_writes.insert(name.into());
+    }
+
     #[inline]
     pub fn push_event(&mut self, e: SemanticEvent) {
         use SemanticEvent::*;
@@ -324,6 +349,22 @@ impl SemanticModelBuilder {
 
     #[inline]
     pub fn build(self) -> SemanticModel {
+        // The template only sees the top-level bindings of the script
+        let template_bindings = |names: &FxHashSet<String>| -> FxHashSet<TextSize> {
+            self.scopes
+                .first()
+                .map(|global_scope| {
+                    names
+                        .iter()
+                        .filter_map(|name| global_scope.bindings_by_name.get(name.as_str()))
+                        .map(|binding_id| self.bindings[binding_id.index()].range.start())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let written_by_template = template_bindings(&self.template_writes);
+        let mut referenced_by_template = template_bindings(&self.template_references);
+        referenced_by_template.extend(written_by_template.iter().copied());
         let data = SemanticModelData {
             root: self.root,
             scopes: self.scopes,
@@ -341,6 +382,8 @@ impl SemanticModelBuilder {
             bindings_by_start: self.bindings_by_start,
             declared_at_by_start: self.declared_at_by_start,
             exported: self.exported,
+            referenced_by_template,
+            written_by_template,
             unresolved_references: self.unresolved_references,
             globals: self.globals,
         };
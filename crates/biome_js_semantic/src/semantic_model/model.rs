@@ -85,6 +85,10 @@ pub(crate) struct SemanticModelData {
     pub(crate) bindings_by_start: FxHashMap<TextSize, BindingId>,
     // All bindings that were exported
     pub(crate) exported: FxHashSet<TextSize>,
+    // All bindings that are referenced by the template of the component
+    pub(crate) referenced_by_template: FxHashSet<TextSize>,
+    // All bindings that are assigned by the template of the component
+    pub(crate) written_by_template: FxHashSet<TextSize>,
     /// All references that could not be resolved
     pub(crate) unresolved_references: Vec<SemanticModelUnresolvedReference>,
     /// All globals references
@@ -387,6 +391,27 @@ impl SemanticModel {
         self.data.has_exports()
     }
 
+    /// Returns `true` if the binding is referenced by the template of the
+    /// component that embeds the script, such as the template of a Vue,
+    /// Svelte or Astro file.
+    ///
+    /// See [SemanticModelBuilder::push_template_reference].
+    pub fn is_referenced_by_template(&self, binding: &impl IsBindingAstNode) -> bool {
+        let range = binding.syntax().text_trimmed_range();
+        self.data.referenced_by_template.contains(&range.start())
+    }
+
+    /// Returns `true` if the binding is assigned by the template of the
+    /// component that embeds the script. The template assignments aren't
+    /// returned by [Binding::all_writes], because they don't have a node in
+    /// the tree of the script.
+    ///
+    /// See [SemanticModelBuilder::push_template_write].
+    pub fn is_written_by_template(&self, binding: &impl IsBindingAstNode) -> bool {
+        let range = binding.syntax().text_trimmed_range();
+        self.data.written_by_template.contains(&range.start())
+    }
+
     /// Returns if the node is imported or is a reference to a binding
     /// that is imported.
     ///
@@ -421,6 +446,17 @@ impl SemanticModel {
         }
     }
 
+    /// Returns the [Binding] of the node, or [None] if the node doesn't
+    /// declare one, unlike [Self::as_binding] which panics.
+    pub fn try_as_binding(&self, binding: &impl IsBindingAstNode) -> Option<Binding> {
+        let range = binding.syntax().text_trimmed_range();
+        let id = *self.data.bindings_by_start.get(&range.start())?;
+        Some(Binding {
+            data: self.data.clone(),
+            id,
+        })
+    }
+
     /// Returns all [FunctionCall] of a [AnyJsFunction].
     ///
     /// ```rust
@@ -272,4 +272,43 @@ mod test {
         assert!(globals[0].is_read());
         assert_eq!(globals[0].syntax().text_trimmed(), "console");
     }
+
+    #[test]
+    pub fn ok_semantic_model_template_references() {
+        let r = biome_js_parser::parse(
+            "import A from 'a'; import B from 'b'; let count = 0; function f() { let count = 1; }",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut options = SemanticModelOptions::default();
+        options.template_references.reads.insert("A".into());
+        options.template_references.writes.insert("count".into());
+
+        let model = semantic_model(&r.tree(), options);
+
+        let referenced_by_template: Vec<_> = r
+            .syntax()
+            .descendants()
+            .filter_map(JsIdentifierBinding::cast)
+            .map(|binding| {
+                (
+                    binding.syntax().text_trimmed().to_string(),
+                    model.is_referenced_by_template(&binding),
+                    model.is_written_by_template(&binding),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            referenced_by_template,
+            [
+                ("A".to_string(), true, false),
+                ("B".to_string(), false, false),
+                ("count".to_string(), true, true),
+                ("f".to_string(), false, false),
+                ("count".to_string(), false, false),
+            ]
+        );
+    }
 }
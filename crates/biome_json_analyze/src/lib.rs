@@ -10,7 +10,8 @@ pub use crate::registry::visit_registry;
 use crate::suppression_action::JsonSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleCategory, RuleRegistry, SuppressionDiagnostic,
+    SuppressionKind,
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
@@ -88,6 +89,10 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if filter.categories.contains(RuleCategory::Lint) {
+        analyzer.add_plugins(options.plugins().clone());
+    }
+
     services.insert_service(file_source);
 
     (
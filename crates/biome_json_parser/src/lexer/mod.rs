@@ -318,6 +318,10 @@ impl<'src> Lexer<'src> {
 
             SLH => self.lex_slash(),
 
+            UNI if self.options.grit_metavariables && self.is_metavariable_start() => {
+                self.consume_metavariable()
+            }
+
             UNI => {
                 let chr = self.current_char_unchecked();
 
@@ -790,6 +794,42 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Check if the lexer is at a valid metavariable start (`µ[a-zA-Z_]` or `µ...`)
+    fn is_metavariable_start(&self) -> bool {
+        let current_char = self.current_char_unchecked();
+        if current_char != 'µ' {
+            return false;
+        }
+
+        let current_char_length = current_char.len_utf8();
+        match self.byte_at(current_char_length) {
+            Some(b'a'..=b'z' | b'A'..=b'Z' | b'_') => true,
+            Some(b'.') => {
+                self.byte_at(current_char_length + 1) == Some(b'.')
+                    && self.byte_at(current_char_length + 2) == Some(b'.')
+            }
+            _ => false,
+        }
+    }
+
+    /// Consumes a Grit metavariable (`µ[a-zA-Z_][a-zA-Z0-9_]*` or `µ...`)
+    fn consume_metavariable(&mut self) -> JsonSyntaxKind {
+        debug_assert!(self.is_metavariable_start());
+
+        self.advance_char_unchecked();
+
+        if self.current_byte() == Some(b'.') {
+            self.advance(3);
+        } else {
+            self.advance(1);
+            while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_') = self.current_byte() {
+                self.advance(1);
+            }
+        }
+
+        GRIT_METAVARIABLE
+    }
+
     pub(crate) fn with_options(mut self, options: JsonParserOptions) -> Self {
         self.options = options;
         self
@@ -38,6 +38,7 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let parse_config = JsonParserOptions {
         allow_comments: test_directory.contains("allow_comments"),
         allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+        grit_metavariables: false,
     };
     let parsed = parse_json(&content, parse_config);
     let formatted_ast = format!("{:#?}", parsed.tree());
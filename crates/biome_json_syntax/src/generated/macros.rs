@@ -32,6 +32,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::JsonMemberName::new_unchecked(node) };
                     $body
                 }
+                $crate::JsonSyntaxKind::JSON_METAVARIABLE => {
+                    let $pattern = unsafe { $crate::JsonMetavariable::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsonSyntaxKind::JSON_NULL_VALUE => {
                     let $pattern = unsafe { $crate::JsonNullValue::new_unchecked(node) };
                     $body
@@ -183,6 +183,41 @@ pub struct JsonMemberNameFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JsonMetavariable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl JsonMetavariable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> JsonMetavariableFields {
+        JsonMetavariableFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for JsonMetavariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct JsonMetavariableFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsonNullValue {
     pub(crate) syntax: SyntaxNode,
 }
@@ -382,6 +417,7 @@ pub enum AnyJsonValue {
     JsonArrayValue(JsonArrayValue),
     JsonBogusValue(JsonBogusValue),
     JsonBooleanValue(JsonBooleanValue),
+    JsonMetavariable(JsonMetavariable),
     JsonNullValue(JsonNullValue),
     JsonNumberValue(JsonNumberValue),
     JsonObjectValue(JsonObjectValue),
@@ -406,6 +442,12 @@ impl AnyJsonValue {
             _ => None,
         }
     }
+    pub fn as_json_metavariable(&self) -> Option<&JsonMetavariable> {
+        match &self {
+            AnyJsonValue::JsonMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_json_null_value(&self) -> Option<&JsonNullValue> {
         match &self {
             AnyJsonValue::JsonNullValue(item) => Some(item),
@@ -602,6 +644,47 @@ impl From<JsonMemberName> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for JsonMetavariable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(JSON_METAVARIABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == JSON_METAVARIABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for JsonMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonMetavariable")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<JsonMetavariable> for SyntaxNode {
+    fn from(n: JsonMetavariable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<JsonMetavariable> for SyntaxElement {
+    fn from(n: JsonMetavariable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for JsonNullValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -829,6 +912,11 @@ impl From<JsonBooleanValue> for AnyJsonValue {
         AnyJsonValue::JsonBooleanValue(node)
     }
 }
+impl From<JsonMetavariable> for AnyJsonValue {
+    fn from(node: JsonMetavariable) -> AnyJsonValue {
+        AnyJsonValue::JsonMetavariable(node)
+    }
+}
 impl From<JsonNullValue> for AnyJsonValue {
     fn from(node: JsonNullValue) -> AnyJsonValue {
         AnyJsonValue::JsonNullValue(node)
@@ -854,6 +942,7 @@ impl AstNode for AnyJsonValue {
     const KIND_SET: SyntaxKindSet<Language> = JsonArrayValue::KIND_SET
         .union(JsonBogusValue::KIND_SET)
         .union(JsonBooleanValue::KIND_SET)
+        .union(JsonMetavariable::KIND_SET)
         .union(JsonNullValue::KIND_SET)
         .union(JsonNumberValue::KIND_SET)
         .union(JsonObjectValue::KIND_SET)
@@ -864,6 +953,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE
                 | JSON_BOGUS_VALUE
                 | JSON_BOOLEAN_VALUE
+                | JSON_METAVARIABLE
                 | JSON_NULL_VALUE
                 | JSON_NUMBER_VALUE
                 | JSON_OBJECT_VALUE
@@ -875,6 +965,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE => AnyJsonValue::JsonArrayValue(JsonArrayValue { syntax }),
             JSON_BOGUS_VALUE => AnyJsonValue::JsonBogusValue(JsonBogusValue { syntax }),
             JSON_BOOLEAN_VALUE => AnyJsonValue::JsonBooleanValue(JsonBooleanValue { syntax }),
+            JSON_METAVARIABLE => AnyJsonValue::JsonMetavariable(JsonMetavariable { syntax }),
             JSON_NULL_VALUE => AnyJsonValue::JsonNullValue(JsonNullValue { syntax }),
             JSON_NUMBER_VALUE => AnyJsonValue::JsonNumberValue(JsonNumberValue { syntax }),
             JSON_OBJECT_VALUE => AnyJsonValue::JsonObjectValue(JsonObjectValue { syntax }),
@@ -888,6 +979,7 @@ impl AstNode for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => &it.syntax,
             AnyJsonValue::JsonBogusValue(it) => &it.syntax,
             AnyJsonValue::JsonBooleanValue(it) => &it.syntax,
+            AnyJsonValue::JsonMetavariable(it) => &it.syntax,
             AnyJsonValue::JsonNullValue(it) => &it.syntax,
             AnyJsonValue::JsonNumberValue(it) => &it.syntax,
             AnyJsonValue::JsonObjectValue(it) => &it.syntax,
@@ -899,6 +991,7 @@ impl AstNode for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => it.syntax,
             AnyJsonValue::JsonBogusValue(it) => it.syntax,
             AnyJsonValue::JsonBooleanValue(it) => it.syntax,
+            AnyJsonValue::JsonMetavariable(it) => it.syntax,
             AnyJsonValue::JsonNullValue(it) => it.syntax,
             AnyJsonValue::JsonNumberValue(it) => it.syntax,
             AnyJsonValue::JsonObjectValue(it) => it.syntax,
@@ -912,6 +1005,7 @@ impl std::fmt::Debug for AnyJsonValue {
             AnyJsonValue::JsonArrayValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonBogusValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonBooleanValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyJsonValue::JsonMetavariable(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonNullValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonNumberValue(it) => std::fmt::Debug::fmt(it, f),
             AnyJsonValue::JsonObjectValue(it) => std::fmt::Debug::fmt(it, f),
@@ -925,6 +1019,7 @@ impl From<AnyJsonValue> for SyntaxNode {
             AnyJsonValue::JsonArrayValue(it) => it.into(),
             AnyJsonValue::JsonBogusValue(it) => it.into(),
             AnyJsonValue::JsonBooleanValue(it) => it.into(),
+            AnyJsonValue::JsonMetavariable(it) => it.into(),
             AnyJsonValue::JsonNullValue(it) => it.into(),
             AnyJsonValue::JsonNumberValue(it) => it.into(),
             AnyJsonValue::JsonObjectValue(it) => it.into(),
@@ -963,6 +1058,11 @@ impl std::fmt::Display for JsonMemberName {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for JsonMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for JsonNullValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -58,6 +58,7 @@ impl biome_rowan::SyntaxKind for JsonSyntaxKind {
             | JsonSyntaxKind::JSON_NULL_VALUE
             | JsonSyntaxKind::JSON_ARRAY_VALUE
             | JsonSyntaxKind::JSON_OBJECT_VALUE
+            | JsonSyntaxKind::JSON_METAVARIABLE
             | JsonSyntaxKind::JSON_BOGUS_VALUE => JsonSyntaxKind::JSON_BOGUS_VALUE,
             _ => JsonSyntaxKind::JSON_BOGUS,
         }
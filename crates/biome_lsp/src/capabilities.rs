@@ -1,8 +1,12 @@
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
+use crate::handlers::{commands, semantic_tokens};
 use tower_lsp::lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    DocumentOnTypeFormattingOptions, OneOf, PositionEncodingKind, ServerCapabilities,
-    TextDocumentSyncCapability, TextDocumentSyncKind,
+    DiagnosticOptions, DiagnosticServerCapabilities, DocumentOnTypeFormattingOptions,
+    ExecuteCommandOptions, FoldingRangeProviderCapability, HoverProviderCapability, OneOf,
+    PositionEncodingKind, SelectionRangeProviderCapability, SemanticTokensFullOptions,
+    SemanticTokensOptions, SemanticTokensServerCapabilities, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
 };
 
 /// The capabilities to send from server as part of [`InitializeResult`]
@@ -55,19 +59,42 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         .text_document
         .as_ref()
         .and_then(|text_document| text_document.code_action.as_ref())
-        .and_then(|code_action| code_action.code_action_literal_support.as_ref())
-        .map(|_| {
+        .filter(|code_action| code_action.code_action_literal_support.is_some())
+        .map(|code_action| {
             CodeActionOptions {
                 code_action_kinds: Some(vec![
                     CodeActionKind::from("quickfix.biome"),
                     CodeActionKind::from("source.fixAll.biome"),
                     CodeActionKind::from("source.organizeImports.biome"),
+                    CodeActionKind::from("source.removeUnusedSuppressions.biome"),
+                    CodeActionKind::from("refactor.extract.biome"),
+                    CodeActionKind::from("refactor.inline.biome"),
+                    CodeActionKind::from("refactor.rewrite.biome"),
                 ]),
+                // The edit of the actions that act on the whole workspace is
+                // computed on resolve
+                resolve_provider: code_action.resolve_support.as_ref().map(|_| true),
                 ..Default::default()
             }
             .into()
         })
         .or(Some(CodeActionProviderCapability::Simple(true)));
+
+    // The diagnostics of a file depend on the other files of the project
+    // through the module graph
+    let diagnostic_provider = capabilities
+        .text_document
+        .as_ref()
+        .and_then(|text_document| text_document.diagnostic.as_ref())
+        .map(|_| {
+            DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some(String::from("biome")),
+                inter_file_dependencies: true,
+                workspace_diagnostics: true,
+                ..Default::default()
+            })
+        });
+
     ServerCapabilities {
         position_encoding: Some(match negotiated_encoding(capabilities) {
             PositionEncoding::Utf8 => PositionEncodingKind::UTF8,
@@ -83,7 +110,28 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         document_range_formatting_provider: supports_range_formatter_dynamic_registration,
         document_on_type_formatting_provider: supports_on_type_formatter_dynamic_registration,
         code_action_provider,
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        document_highlight_provider: Some(OneOf::Left(true)),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: semantic_tokens::legend(),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                range: None,
+                ..Default::default()
+            },
+        )),
         rename_provider: None,
+        diagnostic_provider,
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: commands::commands(),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: Some(true),
+            },
+        }),
         ..Default::default()
     }
 }
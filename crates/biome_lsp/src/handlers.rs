@@ -1,4 +1,10 @@
 pub(crate) mod analysis;
+pub(crate) mod commands;
+pub(crate) mod diagnostics;
 pub(crate) mod formatting;
+pub(crate) mod hover;
+pub(crate) mod navigation;
+pub(crate) mod ranges;
 pub(crate) mod rename;
+pub(crate) mod semantic_tokens;
 pub(crate) mod text_document;
@@ -2,13 +2,16 @@ use crate::converters::from_proto;
 use crate::converters::line_index::LineIndex;
 use crate::diagnostics::LspError;
 use crate::session::Session;
-use crate::utils;
+use crate::traversal::WorkspaceFile;
+use crate::{traversal, utils};
 use anyhow::{Context, Result};
 use biome_analyze::{ActionCategory, RuleCategoriesBuilder, SourceActionKind};
 use biome_diagnostics::Applicability;
 use biome_fs::BiomePath;
 use biome_rowan::{TextRange, TextSize};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
 use biome_service::workspace::{
     FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams, PullActionsParams,
     SupportsFeatureParams,
@@ -25,6 +28,14 @@ use tracing::{debug, info, trace};
 
 const FIX_ALL_CATEGORY: ActionCategory = ActionCategory::Source(SourceActionKind::FixAll);
 
+const REMOVE_UNUSED_SUPPRESSIONS_CATEGORY: ActionCategory = ActionCategory::Source(
+    SourceActionKind::Other(Cow::Borrowed("removeUnusedSuppressions")),
+);
+
+/// The value of [lsp::CodeAction::data] for the action that removes the unused
+/// suppression comments of the workspace, whose edit is computed on resolve
+const REMOVE_UNUSED_SUPPRESSIONS_IN_WORKSPACE: &str = "biome.removeUnusedSuppressionsInWorkspace";
+
 fn fix_all_kind() -> CodeActionKind {
     match FIX_ALL_CATEGORY.to_str() {
         Cow::Borrowed(kind) => CodeActionKind::from(kind),
@@ -32,6 +43,10 @@ fn fix_all_kind() -> CodeActionKind {
     }
 }
 
+fn remove_unused_suppressions_kind() -> CodeActionKind {
+    CodeActionKind::from(REMOVE_UNUSED_SUPPRESSIONS_CATEGORY.to_str().into_owned())
+}
+
 /// Queries the [`AnalysisServer`] for code actions of the file matching its path
 ///
 /// If the AnalysisServer has no matching file, results in error.
@@ -62,12 +77,15 @@ pub(crate) fn code_actions(
 
     let mut has_fix_all = false;
     let mut has_quick_fix = false;
+    let mut has_remove_unused_suppressions = false;
     let mut filters = Vec::new();
     if let Some(filter) = &params.context.only {
         for kind in filter {
             let kind = kind.as_str();
             if FIX_ALL_CATEGORY.matches(kind) {
                 has_fix_all = true;
+            } else if REMOVE_UNUSED_SUPPRESSIONS_CATEGORY.matches(kind) {
+                has_remove_unused_suppressions = true;
             } else if ActionCategory::QuickFix.to_str() == kind {
                 // The action is a on-save quick-fixes
                 has_quick_fix = true;
@@ -82,6 +100,15 @@ pub(crate) fn code_actions(
     let position_encoding = session.position_encoding();
 
     let diagnostics = params.context.diagnostics;
+    let unused_suppressions: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| is_unused_suppression(diagnostic))
+        .cloned()
+        .collect();
+    // Offer to remove all the unused suppression comments when the client asks
+    // for them, or when the cursor is on one of them
+    let has_remove_unused_suppressions = has_remove_unused_suppressions
+        || (params.context.only.is_none() && !unused_suppressions.is_empty());
     let content = session.workspace.get_file_content(GetFileContentParams {
         path: biome_path.clone(),
     })?;
@@ -148,6 +175,23 @@ pub(crate) fn code_actions(
         None
     };
 
+    let remove_unused_suppressions =
+        if has_remove_unused_suppressions && file_features.supports_lint() {
+            let in_file = remove_unused_suppressions(
+                session,
+                &url,
+                biome_path.clone(),
+                &doc.line_index,
+                &unused_suppressions,
+            )?;
+            let in_workspace = session
+                .supports_code_action_resolve()
+                .then(|| remove_unused_suppressions_in_workspace(&unused_suppressions));
+            in_file.into_iter().chain(in_workspace).collect()
+        } else {
+            Vec::new()
+        };
+
     let mut has_fixes = false;
 
     let mut actions: Vec<_> = result
@@ -170,8 +214,10 @@ pub(crate) fn code_actions(
                 return None;
             }
 
-            // Filter out the refactor.* actions when assists are disabled
-            if action.category.matches("source") && !file_features.supports_assists() {
+            // Filter out the source.* and refactor.* actions when assists are disabled
+            if (action.category.matches("source") || action.category.matches("refactor"))
+                && !file_features.supports_assists()
+            {
                 return None;
             }
             // Remove actions that do not match the categories requested by the
@@ -203,6 +249,7 @@ pub(crate) fn code_actions(
         })
         .rev()
         .chain(fix_all)
+        .chain(remove_unused_suppressions)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -210,7 +257,9 @@ pub(crate) fn code_actions(
     if has_fixes {
         actions.retain(|action| {
             if let CodeActionOrCommand::CodeAction(action) = action {
-                action.kind.as_ref() == Some(&fix_all_kind()) || action.diagnostics.is_some()
+                action.kind.as_ref() == Some(&fix_all_kind())
+                    || action.kind.as_ref() == Some(&remove_unused_suppressions_kind())
+                    || action.diagnostics.is_some()
             } else {
                 true
             }
@@ -303,16 +352,7 @@ fn fix_all(
         .collect();
 
     let mut changes = HashMap::new();
-    changes.insert(
-        url.clone(),
-        vec![lsp::TextEdit {
-            range: lsp::Range {
-                start: lsp::Position::new(0, 0),
-                end: lsp::Position::new(line_index.len(), 0),
-            },
-            new_text: fixed.code,
-        }],
-    );
+    changes.insert(url.clone(), vec![replace_document(line_index, fixed.code)]);
 
     let edit = lsp::WorkspaceEdit {
         changes: Some(changes),
@@ -331,3 +371,157 @@ fn fix_all(
         data: None,
     })))
 }
+
+/// Generate a code action that removes the unused suppression comments of the
+/// given document
+#[tracing::instrument(level = "debug", skip(session), err)]
+fn remove_unused_suppressions(
+    session: &Session,
+    url: &lsp::Url,
+    biome_path: BiomePath,
+    line_index: &LineIndex,
+    diagnostics: &[lsp::Diagnostic],
+) -> Result<Option<CodeActionOrCommand>, WorkspaceError> {
+    let fixed = session.workspace.fix_file(FixFileParams {
+        path: biome_path,
+        fix_file_mode: FixFileMode::RemoveUnusedSuppressions,
+        should_format: false,
+        only: vec![],
+        skip: vec![],
+        rule_categories: RuleCategoriesBuilder::default()
+            .with_syntax()
+            .with_lint()
+            .build(),
+    })?;
+
+    if fixed.actions.is_empty() {
+        return Ok(None);
+    }
+
+    let mut changes = HashMap::new();
+    changes.insert(url.clone(), vec![replace_document(line_index, fixed.code)]);
+
+    Ok(Some(CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Remove the unused suppression comments of the file"),
+        kind: Some(remove_unused_suppressions_kind()),
+        diagnostics: (!diagnostics.is_empty()).then(|| diagnostics.to_vec()),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    })))
+}
+
+/// Generate a code action that removes the unused suppression comments of all
+/// the files of the workspace. Traversing the workspace is expensive, so the
+/// edit is only computed when the client resolves the action.
+fn remove_unused_suppressions_in_workspace(diagnostics: &[lsp::Diagnostic]) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Remove the unused suppression comments of the workspace"),
+        kind: Some(remove_unused_suppressions_kind()),
+        diagnostics: (!diagnostics.is_empty()).then(|| diagnostics.to_vec()),
+        edit: None,
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: Some(serde_json::Value::from(
+            REMOVE_UNUSED_SUPPRESSIONS_IN_WORKSPACE,
+        )),
+    })
+}
+
+/// Computes the edit of the code actions that are resolved lazily
+#[tracing::instrument(level = "debug", skip_all, fields(title = display(&action.title)), err)]
+pub(crate) fn resolve_code_action(
+    session: &Session,
+    mut action: lsp::CodeAction,
+) -> Result<lsp::CodeAction, LspError> {
+    let is_remove_unused_suppressions_in_workspace =
+        action.data.as_ref().and_then(serde_json::Value::as_str)
+            == Some(REMOVE_UNUSED_SUPPRESSIONS_IN_WORKSPACE);
+    if !is_remove_unused_suppressions_in_workspace {
+        return Ok(action);
+    }
+
+    let paths = traversal::workspace_files(
+        session,
+        FeaturesBuilder::new().with_linter().build(),
+        &|path: &BiomePath| DocumentFileSource::from_path(path) != DocumentFileSource::Unknown,
+    );
+
+    let mut changes = Vec::new();
+    for path in paths {
+        let Ok(url) = lsp::Url::from_file_path(path.as_path()) else {
+            continue;
+        };
+        let Some((file, fixed)) = remove_unused_suppressions_in_path(session, &url, path)? else {
+            continue;
+        };
+        changes.push(file.replace(url, fixed));
+    }
+    debug!(
+        "Removed unused suppression comments in {} files",
+        changes.len()
+    );
+
+    action.edit = Some(traversal::workspace_edit(changes));
+    Ok(action)
+}
+
+/// Removes the unused suppression comments of the file at `path`, and returns
+/// its new content along with the state of the file it was computed from. The
+/// files that aren't open in the client are read from the disk.
+fn remove_unused_suppressions_in_path(
+    session: &Session,
+    url: &lsp::Url,
+    path: BiomePath,
+) -> Result<Option<(WorkspaceFile, String)>, LspError> {
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: path.clone(),
+        features: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_lint() {
+        return Ok(None);
+    }
+
+    let result = traversal::with_workspace_file(session, url, &path, |_| {
+        Ok(session.workspace.fix_file(FixFileParams {
+            path: path.clone(),
+            fix_file_mode: FixFileMode::RemoveUnusedSuppressions,
+            should_format: false,
+            only: vec![],
+            skip: vec![],
+            rule_categories: RuleCategoriesBuilder::default()
+                .with_syntax()
+                .with_lint()
+                .build(),
+        })?)
+    })?;
+
+    Ok(result
+        .filter(|(_, fixed)| !fixed.actions.is_empty())
+        .map(|(file, fixed)| (file, fixed.code)))
+}
+
+fn is_unused_suppression(diagnostic: &lsp::Diagnostic) -> bool {
+    matches!(
+        &diagnostic.code,
+        Some(lsp::NumberOrString::String(code)) if code == "suppressions/unused"
+    )
+}
+
+/// The edit that replaces the whole document with `new_text`
+pub(crate) fn replace_document(line_index: &LineIndex, new_text: String) -> lsp::TextEdit {
+    lsp::TextEdit {
+        range: lsp::Range {
+            start: lsp::Position::new(0, 0),
+            end: lsp::Position::new(line_index.len(), 0),
+        },
+        new_text,
+    }
+}
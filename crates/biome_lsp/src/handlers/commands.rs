@@ -0,0 +1,208 @@
+//! The commands of `workspace/executeCommand` requests, that act on all the
+//! files of the workspace folders. The edits are computed from the content of
+//! the documents open in the client, so the unsaved changes aren't lost.
+
+use crate::diagnostics::LspError;
+use crate::progress::Progress;
+use crate::session::Session;
+use crate::traversal;
+use anyhow::anyhow;
+use biome_analyze::RuleCategoriesBuilder;
+use biome_fs::BiomePath;
+use biome_service::file_handlers::{
+    AstroFileHandler, DocumentFileSource, SvelteFileHandler, VueFileHandler,
+};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, FormatFileParams,
+    GetFileContentParams, SupportsFeatureParams,
+};
+use biome_service::WorkspaceError;
+use serde_json::Value;
+use std::ffi::OsStr;
+use std::panic::AssertUnwindSafe;
+use tower_lsp::lsp_types as lsp;
+use tracing::{debug, error};
+
+/// Applies the safe fixes to all the files of the workspace, like
+/// `biome check --write` without the formatting
+pub(crate) const APPLY_ALL_SAFE_FIXES: &str = "biome.applyAllSafeFixes";
+
+/// Formats all the files of the workspace, like `biome format --write`
+pub(crate) const FORMAT_WORKSPACE: &str = "biome.formatWorkspace";
+
+/// Returns the commands that the server can execute
+pub(crate) fn commands() -> Vec<String> {
+    vec![
+        String::from(APPLY_ALL_SAFE_FIXES),
+        String::from(FORMAT_WORKSPACE),
+    ]
+}
+
+#[derive(Clone, Copy, Debug)]
+enum WorkspaceCommand {
+    ApplyAllSafeFixes,
+    FormatWorkspace,
+}
+
+impl WorkspaceCommand {
+    fn title(self) -> &'static str {
+        match self {
+            WorkspaceCommand::ApplyAllSafeFixes => "Applying the safe fixes",
+            WorkspaceCommand::FormatWorkspace => "Formatting the workspace",
+        }
+    }
+
+    fn features(self) -> FeatureName {
+        match self {
+            WorkspaceCommand::ApplyAllSafeFixes => FeaturesBuilder::new()
+                .with_linter()
+                .with_organize_imports()
+                .with_assists()
+                .build(),
+            WorkspaceCommand::FormatWorkspace => FeaturesBuilder::new().with_formatter().build(),
+        }
+    }
+
+    /// Returns the new content of the file at `path`, if the command changes it
+    fn run(self, session: &Session, path: &BiomePath) -> Result<Option<String>, WorkspaceError> {
+        let file_features = session.workspace.file_features(SupportsFeatureParams {
+            path: path.clone(),
+            features: self.features(),
+        })?;
+
+        let output = match self {
+            WorkspaceCommand::ApplyAllSafeFixes => {
+                if !file_features.supports_lint()
+                    && !file_features.supports_organize_imports()
+                    && !file_features.supports_assists()
+                {
+                    return Ok(None);
+                }
+                let fixed = session.workspace.fix_file(FixFileParams {
+                    path: path.clone(),
+                    fix_file_mode: FixFileMode::SafeFixes,
+                    should_format: false,
+                    only: vec![],
+                    skip: vec![],
+                    rule_categories: RuleCategoriesBuilder::default()
+                        .with_syntax()
+                        .with_lint()
+                        .with_action()
+                        .build(),
+                })?;
+                if fixed.actions.is_empty() {
+                    return Ok(None);
+                }
+                fixed.code
+            }
+            WorkspaceCommand::FormatWorkspace => {
+                if !file_features.supports_format() {
+                    return Ok(None);
+                }
+                let printed = session
+                    .workspace
+                    .format_file(FormatFileParams { path: path.clone() })?;
+                printed.into_code()
+            }
+        };
+
+        let input = session
+            .workspace
+            .get_file_content(GetFileContentParams { path: path.clone() })?;
+        // The output of the files with embedded languages only covers the script
+        let output = match path.extension().map(OsStr::as_encoded_bytes) {
+            Some(b"astro") => AstroFileHandler::output(input.as_str(), output.as_str()),
+            Some(b"vue") => VueFileHandler::output(input.as_str(), output.as_str()),
+            Some(b"svelte") => SvelteFileHandler::output(input.as_str(), output.as_str()),
+            _ => output,
+        };
+
+        Ok((output != input).then_some(output))
+    }
+}
+
+impl TryFrom<&str> for WorkspaceCommand {
+    type Error = LspError;
+
+    fn try_from(command: &str) -> Result<Self, Self::Error> {
+        match command {
+            APPLY_ALL_SAFE_FIXES => Ok(WorkspaceCommand::ApplyAllSafeFixes),
+            FORMAT_WORKSPACE => Ok(WorkspaceCommand::FormatWorkspace),
+            _ => Err(anyhow!("Unknown command {command}").into()),
+        }
+    }
+}
+
+/// Handler for `workspace/executeCommand` requests. The files of the
+/// workspace are processed with the progress reported to the client, and
+/// their changes are sent in a single `workspace/applyEdit` request.
+#[tracing::instrument(level = "debug", skip_all, fields(command = display(&params.command)), err)]
+pub(crate) async fn execute_command(
+    session: &Session,
+    params: lsp::ExecuteCommandParams,
+) -> Result<Option<Value>, LspError> {
+    let command = WorkspaceCommand::try_from(params.command.as_str())?;
+    let mut progress = Progress::begin(
+        session,
+        params.work_done_progress_params.work_done_token,
+        command.title(),
+    )
+    .await;
+
+    let paths = traversal::workspace_files(session, command.features(), &|path: &BiomePath| {
+        DocumentFileSource::from_path(path) != DocumentFileSource::Unknown
+    });
+
+    let mut changes = Vec::new();
+    for (index, path) in paths.iter().enumerate() {
+        if let Some(progress) = &mut progress {
+            progress.report(index, paths.len()).await;
+        }
+        let Ok(url) = lsp::Url::from_file_path(path.as_path()) else {
+            continue;
+        };
+        let result = biome_diagnostics::panic::catch_unwind(AssertUnwindSafe(|| {
+            traversal::with_workspace_file(session, &url, path, |_| Ok(command.run(session, path)?))
+        }));
+        match result {
+            Ok(Ok(Some((file, Some(output))))) => {
+                changes.push(file.replace(url, output));
+            }
+            Ok(Ok(_)) => {}
+            // The other files are still processed
+            Ok(Err(error)) => error!("Failed to process the file {}: {error}", path.display()),
+            Err(error) => error!("Failed to process the file {}: {error}", path.display()),
+        }
+    }
+
+    let changed_files = changes.len();
+    debug!("Changed {changed_files} files out of {}", paths.len());
+    if let Some(progress) = progress {
+        progress
+            .end(format!(
+                "Changed {changed_files} files out of {}",
+                paths.len()
+            ))
+            .await;
+    }
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    let response = session
+        .client
+        .apply_edit(traversal::workspace_edit(changes))
+        .await
+        .map_err(|error| anyhow!("The client couldn't apply the edit: {error}"))?;
+    if !response.applied {
+        error!(
+            "The client didn't apply the edit: {}",
+            response
+                .failure_reason
+                .as_deref()
+                .unwrap_or("unknown reason")
+        );
+    }
+
+    Ok(None)
+}
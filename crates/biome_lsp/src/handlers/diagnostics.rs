@@ -0,0 +1,412 @@
+use crate::diagnostics::LspError;
+use crate::module_graph;
+use crate::progress::Progress;
+use crate::session::{Session, SessionHandle};
+use crate::traversal;
+use anyhow::anyhow;
+use biome_fs::BiomePath;
+use biome_service::workspace::{FeaturesBuilder, SupportsFeatureParams};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::hash::Hasher;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+use tokio::task::spawn_blocking;
+use tower_lsp::lsp_types::notification::Notification;
+use tower_lsp::lsp_types::{
+    Diagnostic, DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    FullDocumentDiagnosticReport, ProgressToken, RelatedFullDocumentDiagnosticReport,
+    RelatedUnchangedDocumentDiagnosticReport, UnchangedDocumentDiagnosticReport, Url,
+    WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportPartialResult,
+    WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use tracing::{error, trace};
+use xxhash_rust::xxh3::xxh3_128;
+
+/// Computes the diagnostics of an open document. The client is told when they
+/// didn't change since its last request.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReportResult, LspError> {
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+    let diagnostics = session.compute_diagnostics(&url, &doc.line_index)?;
+    let result_id = result_id(&diagnostics);
+
+    let report = if params.previous_result_id.as_ref() == Some(&result_id) {
+        DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+            related_documents: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    };
+
+    Ok(DocumentDiagnosticReportResult::Report(report))
+}
+
+/// The number of files whose diagnostics are computed at once by a
+/// `workspace/diagnostic` request. The request reports its progress and can
+/// be cancelled between two chunks of files.
+const FILES_PER_CHUNK: usize = 32;
+
+/// Computes the diagnostics of the files of the workspace folders that aren't
+/// open in the client. The folders are traversed the way the CLI does.
+///
+/// The clients send this request again as soon as it returns, so the files
+/// are processed on the blocking threads to keep answering the other
+/// requests, and the diagnostics of the files whose content didn't change are
+/// reused. When the client cancels the request, the files stop being processed
+/// after the current chunk.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn workspace_diagnostic(
+    session: SessionHandle,
+    params: WorkspaceDiagnosticParams,
+) -> Result<WorkspaceDiagnosticReportResult, LspError> {
+    // The server doesn't create a progress token of its own, otherwise the
+    // progress would be shown each time the request is sent again
+    let mut progress = match params.work_done_progress_params.work_done_token {
+        Some(token) => Progress::begin(&session, Some(token), "Computing the diagnostics").await,
+        None => None,
+    };
+    let partial_result_token = params.partial_result_params.partial_result_token;
+
+    let paths = run_blocking(&session, workspace_files).await?;
+    session.workspace_diagnostics.retain(&paths);
+
+    // The identifiers of the diagnostics the client already has, by file
+    let previous_result_ids: Arc<FxHashMap<Url, String>> = Arc::new(
+        params
+            .previous_result_ids
+            .into_iter()
+            .map(|previous| (previous.uri, previous.value))
+            .collect(),
+    );
+
+    let mut items = Vec::new();
+    let reports = removed_files_reports(&session, &paths, &previous_result_ids);
+    send_reports(&session, partial_result_token.as_ref(), &mut items, reports).await;
+
+    let paths: Vec<_> = paths.into_iter().collect();
+    for (index, chunk) in paths.chunks(FILES_PER_CHUNK).enumerate() {
+        if let Some(progress) = &mut progress {
+            progress.report(index * FILES_PER_CHUNK, paths.len()).await;
+        }
+
+        let chunk = chunk.to_vec();
+        let previous_result_ids = previous_result_ids.clone();
+        let reports = run_blocking(&session, move |session| {
+            files_reports(session, &chunk, &previous_result_ids)
+        })
+        .await?;
+        send_reports(&session, partial_result_token.as_ref(), &mut items, reports).await;
+    }
+
+    trace!("Computed the diagnostics of {} files", paths.len());
+    if let Some(progress) = progress {
+        progress
+            .end(format!("Computed the diagnostics of {} files", paths.len()))
+            .await;
+    }
+
+    // The response is empty when the reports were sent as partial results
+    items.sort_by(|left, right| report_url(left).cmp(report_url(right)));
+    Ok(WorkspaceDiagnosticReportResult::Report(
+        WorkspaceDiagnosticReport { items },
+    ))
+}
+
+/// Runs `f` on a blocking thread of the runtime
+async fn run_blocking<T: Send + 'static>(
+    session: &SessionHandle,
+    f: impl FnOnce(&Session) -> T + Send + 'static,
+) -> Result<T, LspError> {
+    let session = session.clone();
+    spawn_blocking(move || f(&session))
+        .await
+        .map_err(|error| anyhow!("Failed to compute the diagnostics: {error}").into())
+}
+
+/// Returns the files of the workspace folders that have diagnostics, and
+/// indexes their modules
+fn workspace_files(session: &Session) -> BTreeSet<BiomePath> {
+    let features = FeaturesBuilder::new()
+        .with_linter()
+        .with_assists()
+        .with_organize_imports()
+        .build();
+    let paths = traversal::workspace_files(session, features, &|path: &BiomePath| {
+        session
+            .workspace
+            .file_features(SupportsFeatureParams {
+                path: path.clone(),
+                features,
+            })
+            .is_ok_and(|file_features| {
+                file_features.supports_lint()
+                    || file_features.supports_organize_imports()
+                    || file_features.supports_assists()
+            })
+    });
+    module_graph::update_module_graph(session, &paths);
+    paths
+}
+
+/// Returns the reports of the diagnostics of the files at `paths` that aren't
+/// open in the client
+fn files_reports(
+    session: &Session,
+    paths: &[BiomePath],
+    previous_result_ids: &FxHashMap<Url, String>,
+) -> Vec<WorkspaceDocumentDiagnosticReport> {
+    let mut items = Vec::new();
+    for path in paths {
+        let Ok(url) = Url::from_file_path(path.as_path()) else {
+            continue;
+        };
+        // The diagnostics of the open documents are pulled with their content
+        if session.document(&url).is_ok() {
+            continue;
+        }
+
+        let result = biome_diagnostics::panic::catch_unwind(AssertUnwindSafe(|| {
+            file_diagnostics(session, &url, path)
+        }));
+        match result {
+            Ok(Ok(Some(diagnostics))) => {
+                items.extend(report(&url, diagnostics, previous_result_ids.get(&url)));
+            }
+            Ok(Ok(None)) => {}
+            // The other files are still processed
+            Ok(Err(error)) => error!(
+                "Failed to compute the diagnostics of {}: {error}",
+                path.display()
+            ),
+            Err(error) => error!(
+                "Failed to compute the diagnostics of {}: {error}",
+                path.display()
+            ),
+        }
+    }
+    items
+}
+
+/// Returns the diagnostics of the file at `path`, which are computed again
+/// only when its content changed. Returns `None` when the file can't be read,
+/// isn't supported, or was opened by the client in the meantime.
+fn file_diagnostics(
+    session: &Session,
+    url: &Url,
+    path: &BiomePath,
+) -> Result<Option<Vec<Diagnostic>>, LspError> {
+    let Ok(content) = session.fs.read_file_from_path(path) else {
+        return Ok(None);
+    };
+    let content_hash = xxh3_128(content.as_bytes());
+    let cache = &session.workspace_diagnostics;
+    if let Some(diagnostics) = cache.get(path, content_hash) {
+        return Ok(Some(diagnostics));
+    }
+
+    let generation = cache.generation();
+    let result = traversal::with_workspace_file_content(session, url, path, content, |file| {
+        // The client opened the document in the meantime
+        if file.version.is_some() {
+            return Ok(None);
+        }
+        session.compute_diagnostics(url, &file.line_index).map(Some)
+    })?;
+    let Some((_, Some(diagnostics))) = result else {
+        return Ok(None);
+    };
+    cache.insert(generation, path, content_hash, diagnostics.clone());
+    Ok(Some(diagnostics))
+}
+
+/// Returns empty reports for the files that the client has diagnostics of,
+/// but that aren't among the files of the workspace folders anymore, e.g.
+/// because they were deleted, so that the client removes their diagnostics
+fn removed_files_reports(
+    session: &Session,
+    paths: &BTreeSet<BiomePath>,
+    previous_result_ids: &FxHashMap<Url, String>,
+) -> Vec<WorkspaceDocumentDiagnosticReport> {
+    previous_result_ids
+        .keys()
+        .filter(|url| session.document(url).is_err())
+        .filter(|url| {
+            url.to_file_path()
+                .map_or(true, |path| !paths.contains(&BiomePath::new(path)))
+        })
+        .map(|url| {
+            WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                uri: url.clone(),
+                version: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: None,
+                    items: Vec::new(),
+                },
+            })
+        })
+        .collect()
+}
+
+/// The `$/progress` notification that sends a part of the result of a
+/// `workspace/diagnostic` request
+enum PartialResult {}
+
+impl Notification for PartialResult {
+    type Params = PartialResultParams;
+    const METHOD: &'static str = "$/progress";
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PartialResultParams {
+    token: ProgressToken,
+    value: WorkspaceDiagnosticReportPartialResult,
+}
+
+/// Sends `reports` to the client as a partial result of the request when it
+/// provided a token for them, otherwise adds them to the result
+async fn send_reports(
+    session: &Session,
+    partial_result_token: Option<&ProgressToken>,
+    items: &mut Vec<WorkspaceDocumentDiagnosticReport>,
+    reports: Vec<WorkspaceDocumentDiagnosticReport>,
+) {
+    match partial_result_token {
+        Some(_) if reports.is_empty() => {}
+        Some(token) => {
+            session
+                .client
+                .send_notification::<PartialResult>(PartialResultParams {
+                    token: token.clone(),
+                    value: WorkspaceDiagnosticReportPartialResult { items: reports },
+                })
+                .await;
+        }
+        None => items.extend(reports),
+    }
+}
+
+/// The diagnostics computed for the files of the workspace folders that
+/// aren't open in the client, with the hash of the content they were computed
+/// for. They're forgotten when the configuration or the modules change, since
+/// the diagnostics of a file depend on them.
+#[derive(Debug, Default)]
+pub(crate) struct WorkspaceDiagnosticsCache {
+    state: Mutex<WorkspaceDiagnosticsCacheState>,
+}
+
+#[derive(Debug, Default)]
+struct WorkspaceDiagnosticsCacheState {
+    /// Incremented each time the diagnostics are forgotten, so that the
+    /// diagnostics computed in the meantime aren't stored
+    generation: u64,
+    diagnostics: FxHashMap<BiomePath, (u128, Vec<Diagnostic>)>,
+}
+
+impl WorkspaceDiagnosticsCache {
+    /// Returns the diagnostics of the file at `path` if they were computed for
+    /// the content with the hash `content_hash`
+    fn get(&self, path: &BiomePath, content_hash: u128) -> Option<Vec<Diagnostic>> {
+        let state = self.state.lock().unwrap();
+        let (hash, diagnostics) = state.diagnostics.get(path)?;
+        (*hash == content_hash).then(|| diagnostics.clone())
+    }
+
+    fn generation(&self) -> u64 {
+        self.state.lock().unwrap().generation
+    }
+
+    /// Stores the diagnostics of the file at `path`, unless they were
+    /// forgotten since the diagnostics started being computed at `generation`
+    fn insert(
+        &self,
+        generation: u64,
+        path: &BiomePath,
+        content_hash: u128,
+        diagnostics: Vec<Diagnostic>,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        if state.generation == generation {
+            state
+                .diagnostics
+                .insert(path.clone(), (content_hash, diagnostics));
+        }
+    }
+
+    /// Forgets the diagnostics of the files that aren't among `paths`
+    fn retain(&self, paths: &BTreeSet<BiomePath>) {
+        let mut state = self.state.lock().unwrap();
+        state.diagnostics.retain(|path, _| paths.contains(path));
+    }
+
+    /// Forgets all the diagnostics, e.g. when the configuration changed
+    pub(crate) fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.diagnostics.clear();
+    }
+}
+
+/// Returns the report of the diagnostics of the file at `url`, or `None` when
+/// the file doesn't have diagnostics and the client doesn't have diagnostics
+/// for it
+fn report(
+    url: &Url,
+    diagnostics: Vec<Diagnostic>,
+    previous_result_id: Option<&String>,
+) -> Option<WorkspaceDocumentDiagnosticReport> {
+    if diagnostics.is_empty() && previous_result_id.is_none() {
+        return None;
+    }
+
+    let result_id = result_id(&diagnostics);
+    let report = if previous_result_id == Some(&result_id) {
+        WorkspaceDocumentDiagnosticReport::Unchanged(WorkspaceUnchangedDocumentDiagnosticReport {
+            uri: url.clone(),
+            version: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+            uri: url.clone(),
+            version: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    };
+
+    Some(report)
+}
+
+/// Identifies a list of diagnostics, so that the client can tell the server
+/// which diagnostics it already has
+fn result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = FxHasher::default();
+    for diagnostic in diagnostics {
+        // Serializing a diagnostic never fails
+        hasher.write(&serde_json::to_vec(diagnostic).unwrap_or_default());
+    }
+    format!("{:x}", hasher.finish())
+}
+
+fn report_url(report: &WorkspaceDocumentDiagnosticReport) -> &Url {
+    match report {
+        WorkspaceDocumentDiagnosticReport::Full(report) => &report.uri,
+        WorkspaceDocumentDiagnosticReport::Unchanged(report) => &report.uri,
+    }
+}
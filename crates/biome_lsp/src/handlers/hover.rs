@@ -0,0 +1,296 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_analyze::{ActionCategory, FixKind, RuleMetadata};
+use biome_configuration::analyzer::RuleSelector;
+use biome_diagnostics::{serde::Diagnostic, Applicability, Diagnostic as _};
+use biome_fs::BiomePath;
+use biome_rowan::TextRange;
+use biome_service::documentation::Doc;
+use biome_service::workspace::{
+    FeaturesBuilder, GetFileContentParams, GetRuleConfigurationParams, PullActionsParams,
+    SupportsFeatureParams,
+};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use std::fmt::Write;
+use std::str::FromStr;
+use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+use tracing::trace;
+
+/// Shows the documentation of the lint rules that emitted the diagnostics at
+/// the position of the cursor, with their configuration and a preview of
+/// their fix
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: biome_path.clone(),
+        features: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_lint() || !session.configuration_status_of(&biome_path).is_loaded() {
+        return Ok(None);
+    }
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let position = params.text_document_position_params.position;
+    let cursor = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    // The documentation is shown for the diagnostics that the client already
+    // has, which are computed for the current version of the document
+    let Some((script, diagnostics)) = session
+        .published_diagnostics(&url, |published| {
+            (published.version == doc.version)
+                .then(|| (published.script, published.diagnostics.clone()))
+        })
+        .flatten()
+    else {
+        return Ok(None);
+    };
+
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: biome_path.clone(),
+    })?;
+    let script_code = &content[script];
+
+    let mut range: Option<TextRange> = None;
+    let mut sections = Vec::new();
+    for diagnostic in &diagnostics {
+        let Some(span) = diagnostic.location().span else {
+            continue;
+        };
+        if !span.contains_inclusive(cursor) {
+            continue;
+        }
+        let Some((group, rule)) = diagnostic
+            .category()
+            .and_then(|category| category.name().strip_prefix("lint/"))
+            .and_then(|name| name.split_once('/'))
+        else {
+            continue;
+        };
+        let Ok(Doc::Rule(metadata)) = Doc::from_str(rule) else {
+            continue;
+        };
+
+        trace!("Hover on the diagnostic of {group}/{rule}");
+        let configuration =
+            session
+                .workspace
+                .get_rule_configuration(GetRuleConfigurationParams {
+                    path: biome_path.clone(),
+                    group: group.to_string(),
+                    rule: rule.to_string(),
+                })?;
+        // The fixes of the other parts of Vue, Svelte and Astro files, such
+        // as their styles, don't apply to the script
+        let fix = if script.contains_range(span) {
+            diagnostic_fix(
+                session,
+                &biome_path,
+                group,
+                rule,
+                span - script.start(),
+                script_code,
+            )?
+        } else {
+            None
+        };
+        sections.push(rule_documentation(
+            &metadata,
+            diagnostic,
+            configuration,
+            fix,
+        ));
+        range = Some(range.map_or(span, |range| range.cover(span)));
+    }
+
+    let Some(range) = range else {
+        return Ok(None);
+    };
+    let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range: Some(range),
+    }))
+}
+
+/// The fix of a diagnostic, as it's shown in the documentation of its rule
+struct DiagnosticFix {
+    applicability: Applicability,
+    message: String,
+    preview: String,
+}
+
+/// Returns the fix that the rule `group/rule` offers for its diagnostic at
+/// `range`, relative to the start of the script, if it offers one
+fn diagnostic_fix(
+    session: &Session,
+    biome_path: &BiomePath,
+    group: &str,
+    rule: &str,
+    range: TextRange,
+    script_code: &str,
+) -> Result<Option<DiagnosticFix>, LspError> {
+    let Ok(selector) = RuleSelector::from_str(&format!("{group}/{rule}")) else {
+        return Ok(None);
+    };
+    let result = session.workspace.pull_actions(PullActionsParams {
+        path: biome_path.clone(),
+        range: Some(range),
+        only: vec![selector],
+        skip: vec![],
+    })?;
+    // The rule also offers to suppress its diagnostic, which isn't its fix
+    let fix = result.actions.into_iter().find(|action| {
+        action.category == ActionCategory::QuickFix
+            && action
+                .rule_name
+                .as_ref()
+                .is_some_and(|(action_group, action_rule)| {
+                    action_group == group && action_rule == rule
+                })
+    });
+    Ok(fix.map(|action| DiagnosticFix {
+        applicability: action.suggestion.applicability,
+        message: utils::print_markup(&action.suggestion.msg),
+        preview: fix_preview(script_code, &action.suggestion.suggestion),
+    }))
+}
+
+/// Prints the documentation of the rule the way `biome explain` does, with
+/// the severity of the diagnostic, the configuration of the rule, and the
+/// preview of its fix
+fn rule_documentation(
+    metadata: &RuleMetadata,
+    diagnostic: &Diagnostic,
+    configuration: Option<serde_json::Value>,
+    fix: Option<DiagnosticFix>,
+) -> String {
+    let mut markdown = String::new();
+    // Writing to a `String` never fails
+    let _ = writeln!(markdown, "# {}\n", metadata.name);
+    let _ = writeln!(markdown, "Severity: {}.", diagnostic.severity());
+    let _ = writeln!(
+        markdown,
+        "{}",
+        // The configuration of the rule can change the kind of its fix
+        match fix.as_ref().map(|fix| fix.applicability) {
+            Some(Applicability::Always) => "Fix is safe.",
+            Some(Applicability::MaybeIncorrect) => "Fix is unsafe.",
+            None => match metadata.fix_kind {
+                FixKind::None => "No fix available.",
+                FixKind::Safe => "Fix is safe.",
+                FixKind::Unsafe => "Fix is unsafe.",
+            },
+        }
+    );
+    let _ = writeln!(
+        markdown,
+        "This rule is {}.",
+        if metadata.recommended {
+            "recommended"
+        } else {
+            "not recommended"
+        }
+    );
+
+    if let Some(configuration) = configuration
+        .as_ref()
+        .and_then(|configuration| serde_json::to_string_pretty(configuration).ok())
+    {
+        let _ = writeln!(markdown, "\n# Configuration\n```json\n{configuration}\n```");
+    }
+
+    if let Some(DiagnosticFix {
+        message, preview, ..
+    }) = fix
+    {
+        let _ = writeln!(markdown, "\n# Fix\n{message}\n```diff\n{preview}```");
+    }
+
+    let docs = metadata
+        .docs
+        .lines()
+        .map(|line| line.trim_start())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _ = write!(markdown, "\n# Description\n{docs}");
+
+    markdown
+}
+
+/// Prints the lines of `old_code` changed by `edit` with a `-` prefix,
+/// followed by the lines that replace them with a `+` prefix
+fn fix_preview(old_code: &str, edit: &TextEdit) -> String {
+    let new_code = edit.new_string(old_code);
+    // The edit doesn't cover the code that follows the embedded script of
+    // Vue, Svelte and Astro files
+    let old_code = &old_code[..edited_len(old_code, edit)];
+
+    let prefix_len: usize = old_code
+        .chars()
+        .zip(new_code.chars())
+        .take_while(|(old, new)| old == new)
+        .map(|(old, _)| old.len_utf8())
+        .sum();
+    let start = old_code[..prefix_len]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let suffix_len: usize = old_code[start..]
+        .chars()
+        .rev()
+        .zip(new_code[start..].chars().rev())
+        .take_while(|(old, new)| old == new)
+        .map(|(old, _)| old.len_utf8())
+        .sum();
+    let suffix_len = suffix_len.min(old_code.len().min(new_code.len()) - prefix_len);
+
+    let line_end = |code: &str| {
+        let end = code.len() - suffix_len;
+        code[end..]
+            .find('\n')
+            .map_or(code.len(), |index| end + index)
+    };
+    let old_lines = &old_code[start..line_end(old_code)];
+    let new_lines = &new_code[start..line_end(&new_code)];
+
+    let mut preview = String::new();
+    for line in old_lines.lines() {
+        let _ = writeln!(preview, "- {line}");
+    }
+    for line in new_lines.lines() {
+        let _ = writeln!(preview, "+ {line}");
+    }
+    preview
+}
+
+/// Returns the length of the part of `old_code` that `edit` covers
+fn edited_len(old_code: &str, edit: &TextEdit) -> usize {
+    let mut len = 0;
+    for op in edit {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range } | DiffOp::Delete { range }) => {
+                len += usize::from(range.len());
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { .. }) => {}
+            CompressedOp::EqualLines { line_count } => {
+                len += old_code[len..]
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                    .map(str::len)
+                    .sum::<usize>();
+            }
+        }
+    }
+    len.min(old_code.len())
+}
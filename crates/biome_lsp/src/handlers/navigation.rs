@@ -0,0 +1,141 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_rowan::TextSize;
+use biome_service::workspace::{
+    FindReferencesParams, GotoDefinitionParams, SymbolReference, SymbolReferenceKind,
+};
+use tower_lsp::lsp_types::{
+    self, DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams,
+    GotoDefinitionResponse, Location, Position, ReferenceParams, Url,
+};
+use tracing::trace;
+
+/// Jumps to the declaration of the binding at the position of the cursor
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: lsp_types::GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let symbol_at = cursor(session, &url, position)?;
+
+    trace!("Looking for the definition of the symbol at {symbol_at:?}");
+    let result = match session.workspace.goto_definition(GotoDefinitionParams {
+        path: biome_path,
+        symbol_at,
+    }) {
+        Ok(result) => result,
+        Err(error) if utils::is_unsupported_file(&error) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let Some(range) = result.range else {
+        return Ok(None);
+    };
+    let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+    Ok(Some(GotoDefinitionResponse::Scalar(Location {
+        uri: url,
+        range,
+    })))
+}
+
+/// Lists the references of the binding at the position of the cursor
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let Some(references) =
+        find_references(session, &url, position, params.context.include_declaration)?
+    else {
+        return Ok(None);
+    };
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let locations = references
+        .into_iter()
+        .map(|reference| {
+            let range = to_proto::range(&doc.line_index, reference.range, position_encoding)?;
+            Ok(Location {
+                uri: url.clone(),
+                range,
+            })
+        })
+        .collect::<Result<Vec<_>, LspError>>()?;
+
+    Ok(Some(locations))
+}
+
+/// Highlights the declaration and the references of the binding at the
+/// position of the cursor, telling apart the reads and the writes
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_highlight(
+    session: &Session,
+    params: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let Some(references) = find_references(session, &url, position, true)? else {
+        return Ok(None);
+    };
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let highlights = references
+        .into_iter()
+        .map(|reference| {
+            let range = to_proto::range(&doc.line_index, reference.range, position_encoding)?;
+            let kind = match reference.kind {
+                SymbolReferenceKind::Declaration => DocumentHighlightKind::TEXT,
+                SymbolReferenceKind::Read => DocumentHighlightKind::READ,
+                SymbolReferenceKind::Write => DocumentHighlightKind::WRITE,
+            };
+            Ok(DocumentHighlight {
+                range,
+                kind: Some(kind),
+            })
+        })
+        .collect::<Result<Vec<_>, LspError>>()?;
+
+    Ok(Some(highlights))
+}
+
+/// Returns the references of the binding at `position`, or `None` when the
+/// file doesn't support the search of references
+fn find_references(
+    session: &Session,
+    url: &Url,
+    position: Position,
+    include_declaration: bool,
+) -> Result<Option<Vec<SymbolReference>>, LspError> {
+    let biome_path = session.file_path(url)?;
+    let symbol_at = cursor(session, url, position)?;
+
+    trace!("Looking for the references of the symbol at {symbol_at:?}");
+    match session.workspace.find_references(FindReferencesParams {
+        path: biome_path,
+        symbol_at,
+        include_declaration,
+    }) {
+        Ok(result) => Ok(Some(result.references)),
+        Err(error) if utils::is_unsupported_file(&error) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn cursor(session: &Session, url: &Url, position: Position) -> Result<TextSize, LspError> {
+    let doc = session.document(url)?;
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+    Ok(offset)
+}
@@ -0,0 +1,165 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_rowan::TextRange;
+use biome_service::workspace::{
+    FoldingRangeKind, GetFileContentParams, GetFoldingRangesParams, GetSelectionRangesParams,
+};
+use std::cmp::Reverse;
+use tower_lsp::lsp_types::{
+    self, FoldingRangeParams, Position, SelectionRange, SelectionRangeParams,
+};
+use tracing::trace;
+
+/// Lists the regions of the document that the client can fold: the blocks,
+/// the runs of comments, the imports and the `#region` markers
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<lsp_types::FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let result = match session
+        .workspace
+        .get_folding_ranges(GetFoldingRangesParams {
+            path: biome_path.clone(),
+        }) {
+        Ok(result) => result,
+        Err(error) if utils::is_unsupported_file(&error) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    trace!("Found {} folding ranges", result.ranges.len());
+
+    let content = session
+        .workspace
+        .get_file_content(GetFileContentParams { path: biome_path })?;
+
+    let mut folding_ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(&doc.line_index, folding_range.range, position_encoding)?;
+        let mut end_line = range.end.line;
+        // The line of the closing delimiter stays visible, unless the block
+        // ends with code on the same line
+        if folding_range.kind == FoldingRangeKind::Block
+            && ends_with_line_break(&content, folding_range.range)
+        {
+            end_line = end_line.saturating_sub(1);
+        }
+        if end_line <= range.start.line {
+            continue;
+        }
+
+        let kind = match folding_range.kind {
+            FoldingRangeKind::Block => None,
+            FoldingRangeKind::Comment => Some(lsp_types::FoldingRangeKind::Comment),
+            FoldingRangeKind::Imports => Some(lsp_types::FoldingRangeKind::Imports),
+            FoldingRangeKind::Region => Some(lsp_types::FoldingRangeKind::Region),
+        };
+        folding_ranges.push(lsp_types::FoldingRange {
+            start_line: range.start.line,
+            end_line,
+            kind,
+            ..Default::default()
+        });
+    }
+
+    // Clients only keep one folding range per line, so the largest one wins
+    folding_ranges.sort_by_key(|range| (range.start_line, Reverse(range.end_line)));
+    folding_ranges.dedup_by_key(|range| range.start_line);
+
+    Ok(Some(folding_ranges))
+}
+
+/// Returns, for each position of the request, the ranges of the nodes that
+/// contain it, from the innermost to the outermost
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let offsets = params
+        .positions
+        .iter()
+        .map(|position| {
+            from_proto::offset(&doc.line_index, *position, position_encoding).with_context(|| {
+                format!("failed to access position {position:?} in document {url}")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let result = match session
+        .workspace
+        .get_selection_ranges(GetSelectionRangesParams {
+            path: biome_path,
+            positions: offsets,
+        }) {
+        Ok(result) => result,
+        Err(error) if utils::is_unsupported_file(&error) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let selection_ranges = params
+        .positions
+        .into_iter()
+        .zip(result.ranges)
+        .map(|(position, ranges)| {
+            let mut selection_range: Option<SelectionRange> = None;
+            for range in ranges.into_iter().rev() {
+                let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+                selection_range = Some(SelectionRange {
+                    range,
+                    parent: selection_range.map(Box::new),
+                });
+            }
+            Ok(selection_range.unwrap_or_else(|| empty_selection(position)))
+        })
+        .collect::<Result<Vec<_>, LspError>>()?;
+
+    Ok(Some(selection_ranges))
+}
+
+/// Whether the code of the block is followed by a line break before its
+/// closing delimiter
+fn ends_with_line_break(content: &str, range: TextRange) -> bool {
+    content
+        .get(std::ops::Range::<usize>::from(range))
+        .and_then(|block| block.trim_end_matches([' ', '\t']).chars().last())
+        .is_some_and(|last| last == '\n' || last == '\r')
+}
+
+/// The selection of a position that isn't inside a node, e.g. at the end of
+/// an empty file
+fn empty_selection(position: Position) -> SelectionRange {
+    SelectionRange {
+        range: lsp_types::Range::new(position, position),
+        parent: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ends_with_line_break;
+    use biome_rowan::{TextRange, TextSize};
+
+    #[test]
+    fn closing_delimiter_on_its_own_line() {
+        let content = "{\n  a\n}";
+        let block = TextRange::new(TextSize::from(1), TextSize::from(6));
+        assert!(ends_with_line_break(content, block));
+
+        let content = "{\n  a }";
+        let block = TextRange::new(TextSize::from(1), TextSize::from(6));
+        assert!(!ends_with_line_break(content, block));
+    }
+}
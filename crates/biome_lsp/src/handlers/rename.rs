@@ -1,10 +1,12 @@
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
-use crate::converters::from_proto;
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
 use crate::diagnostics::LspError;
-use crate::{session::Session, utils};
+use crate::{module_graph, session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use tower_lsp::lsp_types::{self, RenameParams, Url, WorkspaceEdit};
 use tracing::trace;
 
 #[tracing::instrument(level = "debug", skip(session), err)]
@@ -31,6 +33,11 @@ pub(crate) fn rename(
         )
     })?;
 
+    // The imports of the renamed symbol in the other modules are only known
+    // once the workspace folders are indexed. After the first rename, only
+    // the files that changed are indexed again.
+    module_graph::index_workspace(session);
+
     let result = session
         .workspace
         .rename(biome_service::workspace::RenameParams {
@@ -45,6 +52,35 @@ pub(crate) fn rename(
         utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?,
     );
 
+    let mut line_indexes: HashMap<Url, LineIndex> = HashMap::new();
+    for edit in result.module_edits {
+        let Ok(url) = Url::from_file_path(edit.path.as_path()) else {
+            continue;
+        };
+        let line_index = match line_indexes.entry(url.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let line_index = match session.document(&url) {
+                    Ok(doc) => doc.line_index,
+                    // The file may have been deleted since it was indexed
+                    Err(_) => match session.fs.read_file_from_path(&edit.path) {
+                        Ok(content) => LineIndex::new(&content),
+                        Err(_) => continue,
+                    },
+                };
+                entry.insert(line_index)
+            }
+        };
+        let range = to_proto::range(line_index, edit.range, position_encoding)?;
+        changes
+            .entry(url)
+            .or_insert_with(Vec::new)
+            .push(lsp_types::TextEdit {
+                range,
+                new_text: edit.new_text,
+            });
+    }
+
     let workspace_edit = WorkspaceEdit {
         changes: Some(changes),
         document_changes: None,
@@ -0,0 +1,272 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{to_proto, PositionEncoding};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use biome_service::workspace::{GetSemanticTokensParams, SemanticTokenKind, SemanticTokenModifier};
+use rustc_hash::FxHasher;
+use std::hash::Hasher;
+use tower_lsp::lsp_types::{
+    self, SemanticToken, SemanticTokenType, SemanticTokens, SemanticTokensDelta,
+    SemanticTokensDeltaParams, SemanticTokensEdit, SemanticTokensFullDeltaResult,
+    SemanticTokensLegend, SemanticTokensParams, SemanticTokensResult, Url,
+};
+use tracing::trace;
+
+/// The token types sent to the client. The index of a type is the value of
+/// [SemanticToken::token_type].
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+];
+
+/// The token modifiers sent to the client. The index of a modifier is its bit
+/// in [SemanticToken::token_modifiers_bitset].
+const TOKEN_MODIFIERS: &[lsp_types::SemanticTokenModifier] = &[
+    lsp_types::SemanticTokenModifier::DECLARATION,
+    lsp_types::SemanticTokenModifier::READONLY,
+    lsp_types::SemanticTokenModifier::new("local"),
+    lsp_types::SemanticTokenModifier::new("imported"),
+    lsp_types::SemanticTokenModifier::DEFAULT_LIBRARY,
+    lsp_types::SemanticTokenModifier::new("unresolved"),
+];
+
+/// The token types and the token modifiers that the server uses
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Classifies the identifiers of the whole document
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>, LspError> {
+    let url = params.text_document.uri;
+    let Some(semantic_tokens) = compute_semantic_tokens(session, &url)? else {
+        return Ok(None);
+    };
+
+    session.set_semantic_tokens(url, semantic_tokens.clone());
+    Ok(Some(SemanticTokensResult::Tokens(semantic_tokens)))
+}
+
+/// Classifies the identifiers of the whole document, and sends the changes
+/// since the tokens of `previous_result_id` when the client has them
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full_delta(
+    session: &Session,
+    params: SemanticTokensDeltaParams,
+) -> Result<Option<SemanticTokensFullDeltaResult>, LspError> {
+    let url = params.text_document.uri;
+    let Some(semantic_tokens) = compute_semantic_tokens(session, &url)? else {
+        return Ok(None);
+    };
+
+    let previous = session
+        .semantic_tokens(&url)
+        .filter(|previous| previous.result_id.as_ref() == Some(&params.previous_result_id));
+    session.set_semantic_tokens(url, semantic_tokens.clone());
+
+    let result = match previous {
+        Some(previous) => SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+            result_id: semantic_tokens.result_id,
+            edits: edits(&previous.data, &semantic_tokens.data),
+        }),
+        None => SemanticTokensFullDeltaResult::Tokens(semantic_tokens),
+    };
+    Ok(Some(result))
+}
+
+/// Returns the tokens of the document, or `None` when its language doesn't
+/// support semantic tokens
+fn compute_semantic_tokens(
+    session: &Session,
+    url: &Url,
+) -> Result<Option<SemanticTokens>, LspError> {
+    let biome_path = session.file_path(url)?;
+    let doc = session.document(url)?;
+
+    let result = match session
+        .workspace
+        .get_semantic_tokens(GetSemanticTokensParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(error) if utils::is_unsupported_file(&error) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    trace!("Classified {} identifiers", result.tokens.len());
+
+    let data = encode(&doc.line_index, result.tokens, session.position_encoding())?;
+    Ok(Some(SemanticTokens {
+        result_id: Some(result_id(&data)),
+        data,
+    }))
+}
+
+/// Encodes the tokens relative to the position of the previous token, the
+/// way the protocol expects them
+fn encode(
+    line_index: &LineIndex,
+    tokens: Vec<biome_service::workspace::SemanticToken>,
+    position_encoding: PositionEncoding,
+) -> Result<Vec<SemanticToken>, LspError> {
+    let mut data = Vec::with_capacity(tokens.len());
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    for token in tokens {
+        let range = to_proto::range(line_index, token.range, position_encoding)?;
+        // Multi-line tokens need a capability of the client, and the
+        // identifiers never span multiple lines anyway
+        if range.start.line != range.end.line {
+            continue;
+        }
+
+        let delta_line = range.start.line - previous_line;
+        let delta_start = if delta_line == 0 {
+            range.start.character - previous_start
+        } else {
+            range.start.character
+        };
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: range.end.character - range.start.character,
+            token_type: token_type(token.kind),
+            token_modifiers_bitset: token_modifiers(&token.modifiers),
+        });
+        previous_line = range.start.line;
+        previous_start = range.start.character;
+    }
+    Ok(data)
+}
+
+fn token_type(kind: SemanticTokenKind) -> u32 {
+    let token_type = match kind {
+        SemanticTokenKind::Namespace => SemanticTokenType::NAMESPACE,
+        SemanticTokenKind::Type => SemanticTokenType::TYPE,
+        SemanticTokenKind::Class => SemanticTokenType::CLASS,
+        SemanticTokenKind::Enum => SemanticTokenType::ENUM,
+        SemanticTokenKind::Interface => SemanticTokenType::INTERFACE,
+        SemanticTokenKind::TypeParameter => SemanticTokenType::TYPE_PARAMETER,
+        SemanticTokenKind::Parameter => SemanticTokenType::PARAMETER,
+        SemanticTokenKind::Variable => SemanticTokenType::VARIABLE,
+        SemanticTokenKind::EnumMember => SemanticTokenType::ENUM_MEMBER,
+        SemanticTokenKind::Function => SemanticTokenType::FUNCTION,
+    };
+    legend_index(TOKEN_TYPES, &token_type)
+}
+
+fn token_modifiers(modifiers: &[SemanticTokenModifier]) -> u32 {
+    modifiers
+        .iter()
+        .map(|modifier| {
+            let modifier = match modifier {
+                SemanticTokenModifier::Declaration => lsp_types::SemanticTokenModifier::DECLARATION,
+                SemanticTokenModifier::Readonly => lsp_types::SemanticTokenModifier::READONLY,
+                SemanticTokenModifier::Local => lsp_types::SemanticTokenModifier::new("local"),
+                SemanticTokenModifier::Imported => {
+                    lsp_types::SemanticTokenModifier::new("imported")
+                }
+                SemanticTokenModifier::Global => lsp_types::SemanticTokenModifier::DEFAULT_LIBRARY,
+                SemanticTokenModifier::Unresolved => {
+                    lsp_types::SemanticTokenModifier::new("unresolved")
+                }
+            };
+            1 << legend_index(TOKEN_MODIFIERS, &modifier)
+        })
+        .fold(0, |bitset, bit| bitset | bit)
+}
+
+fn legend_index<T: PartialEq>(legend: &[T], item: &T) -> u32 {
+    // The legend lists every type and every modifier of the workspace
+    legend
+        .iter()
+        .position(|legend_item| legend_item == item)
+        .expect("the legend to contain the item") as u32
+}
+
+/// Identifies a list of tokens, so that the client can ask for the changes
+/// since its last request
+fn result_id(data: &[SemanticToken]) -> String {
+    let mut hasher = FxHasher::default();
+    for token in data {
+        hasher.write_u32(token.delta_line);
+        hasher.write_u32(token.delta_start);
+        hasher.write_u32(token.length);
+        hasher.write_u32(token.token_type);
+        hasher.write_u32(token.token_modifiers_bitset);
+    }
+    format!("{:x}", hasher.finish())
+}
+
+/// Returns the edit that turns `previous` into `current`: the tokens between
+/// their common prefix and their common suffix are replaced. The offsets of
+/// the edit count the integers of the tokens, five per token.
+fn edits(previous: &[SemanticToken], current: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+    let prefix = previous
+        .iter()
+        .zip(current)
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &current[prefix..current.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return Vec::new();
+    }
+
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: (deleted * 5) as u32,
+        data: Some(inserted.to_vec()),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::edits;
+    use tower_lsp::lsp_types::{SemanticToken, SemanticTokensEdit};
+
+    fn token(delta_start: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line: 0,
+            delta_start,
+            length: 1,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn replaces_the_tokens_between_the_common_prefix_and_suffix() {
+        let previous = [token(1), token(2), token(3)];
+        let current = [token(1), token(4), token(5), token(3)];
+        assert_eq!(
+            edits(&previous, &current),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 5,
+                data: Some(vec![token(4), token(5)]),
+            }]
+        );
+        assert_eq!(edits(&previous, &previous), vec![]);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::apply_document_changes;
-use crate::{documents::Document, session::Session};
+use crate::{documents::Document, module_graph, session::Session};
 use anyhow::Result;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, DocumentFileSource, GetFileContentParams, OpenFileParams,
@@ -28,14 +28,20 @@ pub(crate) async fn did_open(
     let biome_path = session.file_path(&url)?;
     let doc = Document::new(version, &content);
 
-    session.workspace.open_file(OpenFileParams {
-        path: biome_path,
-        version,
-        content,
-        document_file_source: Some(language_hint),
-    })?;
-
-    session.insert_document(url.clone(), doc);
+    session.load_nested_configuration(&biome_path).await;
+    {
+        let _lock = session.lock_workspace_files();
+        session.workspace.open_file(OpenFileParams {
+            path: biome_path.clone(),
+            version,
+            content,
+            document_file_source: Some(language_hint),
+        })?;
+
+        session.insert_document(url.clone(), doc);
+    }
+    // The content of the document may differ from the content on disk
+    module_graph::invalidate_diagnostics(session, &biome_path);
 
     if let Err(err) = session.update_diagnostics(url).await {
         error!("Failed to update diagnostics: {}", err);
@@ -72,10 +78,11 @@ pub(crate) async fn did_change(
     session.insert_document(url.clone(), Document::new(version, &text));
 
     session.workspace.change_file(ChangeFileParams {
-        path: biome_path,
+        path: biome_path.clone(),
         version,
         content: text,
     })?;
+    module_graph::invalidate_diagnostics(session, &biome_path);
 
     if let Err(err) = session.update_diagnostics(url).await {
         error!("Failed to update diagnostics: {}", err);
@@ -93,11 +100,17 @@ pub(crate) async fn did_close(
     let url = params.text_document.uri;
     let biome_path = session.file_path(&url)?;
 
-    session
-        .workspace
-        .close_file(CloseFileParams { path: biome_path })?;
+    {
+        let _lock = session.lock_workspace_files();
+        session.workspace.close_file(CloseFileParams {
+            path: biome_path.clone(),
+        })?;
 
-    session.remove_document(&url);
+        session.remove_document(&url);
+    }
+    // The module graph indexed the content of the document, which may differ
+    // from the content on disk
+    module_graph::mark_changed(session, biome_path);
 
     let diagnostics = vec![];
     let version = None;
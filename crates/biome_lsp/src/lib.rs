@@ -4,9 +4,12 @@ mod diagnostics;
 mod documents;
 mod extension_settings;
 mod handlers;
+mod module_graph;
+mod progress;
 mod requests;
 mod server;
 mod session;
+mod traversal;
 mod utils;
 
 pub use crate::extension_settings::WorkspaceSettings;
@@ -0,0 +1,165 @@
+//! Indexing of the modules of the workspace folders, so that the lint rules
+//! and the rename of the exported symbols can query the other modules of the
+//! project.
+//!
+//! The workspace folders are traversed once, then only the files that changed
+//! on disk are indexed again, see [mark_changed], and the deleted files are
+//! removed, see [remove]. The diagnostics computed for the files of the
+//! workspace are forgotten when the modules change, since they depend on the
+//! modules they import.
+
+use crate::session::Session;
+use crate::traversal;
+use biome_fs::BiomePath;
+use biome_service::file_handlers::DocumentFileSource;
+use biome_service::workspace::{
+    FeaturesBuilder, RemoveFromModuleGraphParams, UpdateModuleGraphParams,
+};
+use rustc_hash::FxHashSet;
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use tower_lsp::lsp_types::Url;
+use tracing::error;
+
+/// The files that configure the resolution of the imports of the modules
+const MODULE_GRAPH_CONFIG_FILES: &[&str] = &["package.json", "tsconfig.json", "jsconfig.json"];
+
+/// The files of the workspace folders that are indexed in the module graph
+#[derive(Debug, Default)]
+pub(crate) struct ModuleGraphIndex {
+    /// Whether the workspace folders were traversed and indexed
+    is_workspace_indexed: bool,
+    /// The files whose content on disk is indexed
+    indexed: FxHashSet<BiomePath>,
+    /// The files that changed on disk since they were indexed, or that were
+    /// created
+    changed: FxHashSet<BiomePath>,
+}
+
+/// Traverses the workspace folders and indexes their modules the first time,
+/// then only indexes the files that changed since
+pub(crate) fn index_workspace(session: &Session) {
+    let changed = {
+        let index = session.module_graph_index.read().unwrap();
+        index
+            .is_workspace_indexed
+            .then(|| index.changed.iter().cloned().collect::<BTreeSet<_>>())
+    };
+    let paths = changed.unwrap_or_else(|| {
+        traversal::workspace_files(
+            session,
+            FeaturesBuilder::new().with_linter().build(),
+            &is_module_graph_file,
+        )
+    });
+    update_module_graph(session, &paths);
+    session
+        .module_graph_index
+        .write()
+        .unwrap()
+        .is_workspace_indexed = true;
+}
+
+/// Traverses the workspace folders again the next time they're indexed, e.g.
+/// when a folder is added to the workspace
+pub(crate) fn invalidate_workspace(session: &Session) {
+    session
+        .module_graph_index
+        .write()
+        .unwrap()
+        .is_workspace_indexed = false;
+}
+
+/// Indexes the imports and the exports of the JavaScript files among `paths`
+/// that aren't indexed yet, or that changed since they were indexed. The open
+/// documents are skipped, because the workspace already indexed their
+/// content.
+pub(crate) fn update_module_graph(session: &Session, paths: &BTreeSet<BiomePath>) {
+    for path in paths {
+        if !is_module_graph_file(path) {
+            continue;
+        }
+        if session
+            .module_graph_index
+            .read()
+            .unwrap()
+            .indexed
+            .contains(path)
+        {
+            continue;
+        }
+        let is_open =
+            Url::from_file_path(path.as_path()).is_ok_and(|url| session.document(&url).is_ok());
+        if is_open {
+            continue;
+        }
+
+        let Ok(content) = session.fs.read_file_from_path(path) else {
+            // The file was deleted, or can't be read anymore
+            remove(session, path);
+            continue;
+        };
+        match session
+            .workspace
+            .update_module_graph(UpdateModuleGraphParams {
+                path: path.clone(),
+                content,
+            }) {
+            Ok(()) => {
+                session.workspace_diagnostics.clear();
+                let mut index = session.module_graph_index.write().unwrap();
+                index.changed.remove(path);
+                index.indexed.insert(path.clone());
+            }
+            Err(err) => error!("Failed to index the module {}: {err}", path.display()),
+        }
+    }
+}
+
+/// Marks the file at `path` as changed on disk, e.g. when the client notifies
+/// a change of a watched file or closes a document, so the next update of the
+/// module graph indexes it again
+pub(crate) fn mark_changed(session: &Session, path: BiomePath) {
+    if !is_module_graph_file(&path) {
+        return;
+    }
+    let mut index = session.module_graph_index.write().unwrap();
+    index.indexed.remove(&path);
+    index.changed.insert(path);
+}
+
+/// Removes the file at `path` from the module graph, e.g. when the client
+/// notifies that it was deleted, so the other modules don't import it anymore
+pub(crate) fn remove(session: &Session, path: &BiomePath) {
+    if !is_module_graph_file(path) {
+        return;
+    }
+    let result = session
+        .workspace
+        .remove_from_module_graph(RemoveFromModuleGraphParams { path: path.clone() });
+    if let Err(err) = result {
+        error!("Failed to remove the module {}: {err}", path.display());
+    }
+    session.workspace_diagnostics.clear();
+    let mut index = session.module_graph_index.write().unwrap();
+    index.indexed.remove(path);
+    index.changed.remove(path);
+}
+
+/// Forgets the diagnostics computed for the files of the workspace, which may
+/// import the module at `path`, e.g. when the client changes its content
+pub(crate) fn invalidate_diagnostics(session: &Session, path: &BiomePath) {
+    if is_module_graph_file(path) {
+        session.workspace_diagnostics.clear();
+    }
+}
+
+fn is_module_graph_file(path: &BiomePath) -> bool {
+    DocumentFileSource::from_path(path)
+        .to_js_file_source()
+        .is_some()
+        || path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|file_name| MODULE_GRAPH_CONFIG_FILES.contains(&file_name))
+}
@@ -0,0 +1,97 @@
+//! The progress of the requests that process all the files of the workspace
+//! folders, reported to the client with `$/progress` notifications.
+
+use crate::session::Session;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tower_lsp::lsp_types::{self as lsp, notification, request};
+
+/// The progress of a request, reported to the client with `$/progress`
+/// notifications
+pub(crate) struct Progress<'a> {
+    session: &'a Session,
+    token: lsp::ProgressToken,
+    /// The last percentage sent to the client, to send a notification only
+    /// when it changes
+    percentage: u32,
+}
+
+impl<'a> Progress<'a> {
+    /// Starts reporting the progress with the token provided by the client, or
+    /// with a token created by the server. Returns `None` when the client
+    /// can't show the progress.
+    pub(crate) async fn begin(
+        session: &'a Session,
+        token: Option<lsp::ProgressToken>,
+        title: &str,
+    ) -> Option<Progress<'a>> {
+        static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+        let token = match token {
+            Some(token) => token,
+            None if session.supports_work_done_progress() => {
+                let token = lsp::ProgressToken::String(format!(
+                    "biome/progress/{}",
+                    NEXT_TOKEN.fetch_add(1, Ordering::Relaxed)
+                ));
+                session
+                    .client
+                    .send_request::<request::WorkDoneProgressCreate>(
+                        lsp::WorkDoneProgressCreateParams {
+                            token: token.clone(),
+                        },
+                    )
+                    .await
+                    .ok()?;
+                token
+            }
+            None => return None,
+        };
+
+        let progress = Self {
+            session,
+            token,
+            percentage: 0,
+        };
+        progress
+            .notify(lsp::WorkDoneProgress::Begin(lsp::WorkDoneProgressBegin {
+                title: String::from(title),
+                cancellable: Some(false),
+                message: None,
+                percentage: Some(0),
+            }))
+            .await;
+        Some(progress)
+    }
+
+    /// Reports that `done` files out of `total` are processed
+    pub(crate) async fn report(&mut self, done: usize, total: usize) {
+        let percentage = u32::try_from(done * 100 / total.max(1)).unwrap_or(100);
+        if percentage == self.percentage {
+            return;
+        }
+        self.percentage = percentage;
+        self.notify(lsp::WorkDoneProgress::Report(lsp::WorkDoneProgressReport {
+            cancellable: Some(false),
+            message: Some(format!("{done}/{total}")),
+            percentage: Some(percentage),
+        }))
+        .await;
+    }
+
+    pub(crate) async fn end(self, message: String) {
+        self.notify(lsp::WorkDoneProgress::End(lsp::WorkDoneProgressEnd {
+            message: Some(message),
+        }))
+        .await;
+    }
+
+    async fn notify(&self, value: lsp::WorkDoneProgress) {
+        self.session
+            .client
+            .send_notification::<notification::Progress>(lsp::ProgressParams {
+                token: self.token.clone(),
+                value: lsp::ProgressParamsValue::WorkDone(value),
+            })
+            .await;
+    }
+}
@@ -5,10 +5,10 @@ use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
 };
 use crate::utils::{into_lsp_error, panic_to_lsp_error};
-use crate::{handlers, requests};
+use crate::{handlers, module_graph, requests};
 use biome_console::markup;
 use biome_diagnostics::panic::PanicError;
-use biome_fs::{ConfigName, FileSystem, OsFileSystem, ROME_JSON};
+use biome_fs::{BiomePath, ConfigName, FileSystem, OsFileSystem, ROME_JSON};
 use biome_service::workspace::{
     RageEntry, RageParams, RageResult, RegisterProjectFolderParams, UnregisterProjectFolderParams,
 };
@@ -16,7 +16,8 @@ use biome_service::{workspace, DynRef, Workspace};
 use futures::future::ready;
 use futures::FutureExt;
 use rustc_hash::FxHashMap;
-use serde_json::json;
+use serde_json::{json, Value};
+use std::ffi::OsStr;
 use std::panic::RefUnwindSafe;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -138,14 +139,14 @@ impl LSPServer {
                     watchers: vec![
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
+                                "{}/**/biome.json",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
                         },
                         FileSystemWatcher {
                             glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.jsonc",
+                                "{}/**/biome.jsonc",
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
@@ -164,6 +165,21 @@ impl LSPServer {
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
+                        },
+                        // The modules indexed in the module graph
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/*.{{js,jsx,mjs,cjs,ts,tsx,mts,cts}}",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
+                        },
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/{{package,tsconfig,jsconfig}}.json",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
                         }
                     ],
                 })))
@@ -322,6 +338,17 @@ impl LanguageServer for LSPServer {
 
     #[tracing::instrument(level = "trace", skip(self))]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in &params.changes {
+            if let Ok(file_path) = change.uri.to_file_path() {
+                let path = BiomePath::new(file_path);
+                if change.typ == FileChangeType::DELETED {
+                    module_graph::remove(&self.session, &path);
+                } else {
+                    module_graph::mark_changed(&self.session, path);
+                }
+            }
+        }
+
         let file_paths = params
             .changes
             .iter()
@@ -339,6 +366,8 @@ impl LanguageServer for LSPServer {
                                 || watched_file.ends_with(".editorconfig")
                             {
                                 self.session.load_workspace_settings().await;
+                                // The nested configurations may extend the root one
+                                self.session.reload_nested_configurations(&base_path).await;
                                 self.session.load_manifest().await;
                                 self.setup_capabilities().await;
                                 self.session.update_all_diagnostics().await;
@@ -346,6 +375,23 @@ impl LanguageServer for LSPServer {
                                 // so it's OK to exist the loop
                                 break;
                             }
+
+                            // The configuration file of a nested folder, e.g. a
+                            // package of a monorepo, only affects this folder
+                            let is_nested_configuration = watched_file
+                                .file_name()
+                                .and_then(OsStr::to_str)
+                                .is_some_and(|file_name| {
+                                    ConfigName::file_names().contains(&file_name)
+                                });
+                            if is_nested_configuration {
+                                if let Some(directory) = file_path.parent() {
+                                    self.session.reload_nested_configurations(directory).await;
+                                    self.session
+                                        .update_diagnostics_in_directory(directory)
+                                        .await;
+                                }
+                            }
                         }
                     }
                 }
@@ -414,6 +460,11 @@ impl LanguageServer for LSPServer {
                 }
             }
         }
+
+        // The modules of the added folders aren't indexed yet
+        if !params.event.added.is_empty() {
+            module_graph::invalidate_workspace(&self.session);
+        }
     }
 
     async fn code_action(&self, params: CodeActionParams) -> LspResult<Option<CodeActionResponse>> {
@@ -423,6 +474,18 @@ impl LanguageServer for LSPServer {
         .map_err(into_lsp_error)?
     }
 
+    async fn code_action_resolve(&self, params: CodeAction) -> LspResult<CodeAction> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::analysis::resolve_code_action(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> LspResult<Option<Value>> {
+        let result = handlers::commands::execute_command(&self.session, params).await;
+        self.map_op_error(Ok(result)).await
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -455,6 +518,102 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::hover::hover(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::document_diagnostic(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReportResult> {
+        handlers::diagnostics::workspace_diagnostic(self.session.clone(), params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LspResult<Option<Vec<DocumentHighlight>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::document_highlight(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::folding_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::selection_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> LspResult<Option<SemanticTokensFullDeltaResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full_delta(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -626,6 +785,10 @@ impl ServerFactory {
         workspace_method!(builder, unregister_project_folder);
         workspace_method!(builder, open_file);
         workspace_method!(builder, set_manifest_for_project);
+        workspace_method!(builder, update_module_graph);
+        workspace_method!(builder, remove_from_module_graph);
+        workspace_method!(builder, uses_module_graph);
+        workspace_method!(builder, get_rule_configuration);
         workspace_method!(builder, get_syntax_tree);
         workspace_method!(builder, get_control_flow_graph);
         workspace_method!(builder, get_formatter_ir);
@@ -639,7 +802,15 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, get_folding_ranges);
+        workspace_method!(builder, get_selection_ranges);
+        workspace_method!(builder, get_semantic_tokens);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, parse_pattern);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, drop_pattern);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
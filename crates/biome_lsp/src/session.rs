@@ -1,8 +1,11 @@
+use crate::converters::line_index::LineIndex;
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use crate::diagnostics::LspError;
 use crate::documents::Document;
 use crate::extension_settings::ExtensionSettings;
 use crate::extension_settings::CONFIGURATION_SECTION;
+use crate::handlers::diagnostics::WorkspaceDiagnosticsCache;
+use crate::module_graph::ModuleGraphIndex;
 use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategoriesBuilder;
@@ -10,28 +13,29 @@ use biome_configuration::ConfigurationPathHint;
 use biome_console::markup;
 use biome_deserialize::Merge;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDescription};
-use biome_fs::{BiomePath, FileSystem};
+use biome_fs::{BiomePath, ConfigName, FileSystem};
+use biome_rowan::{TextRange, TextSize};
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
     FeaturesBuilder, GetFileContentParams, PullDiagnosticsParams, RegisterProjectFolderParams,
-    SetManifestForProjectParams, SupportsFeatureParams,
+    SetManifestForProjectParams, SupportsFeatureParams, UnregisterProjectFolderParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::Workspace;
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
 use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::{Mutex, MutexGuard, RwLock};
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
 use tower_lsp::lsp_types;
@@ -70,6 +74,14 @@ pub(crate) struct Session {
 
     configuration_status: AtomicU8,
 
+    /// The folders inside the workspace folders that have their own
+    /// configuration file, e.g. the packages of a monorepo
+    nested_configurations: RwLock<FxHashSet<PathBuf>>,
+
+    /// The nested folders whose configuration file couldn't be loaded. Only
+    /// the files inside these folders are affected by the broken configuration
+    broken_nested_configurations: RwLock<FxHashSet<PathBuf>>,
+
     /// A flag to notify a message to the user when the configuration is broken, and the LSP attempts
     /// to update the diagnostics
     notified_broken_configuration: AtomicBool,
@@ -79,12 +91,42 @@ pub(crate) struct Session {
 
     documents: RwLock<FxHashMap<lsp_types::Url, Document>>,
 
+    /// Held while the client opens or closes a document, and while the server
+    /// opens or closes a file of the workspace that isn't open in the client.
+    /// The server doesn't close a document opened by the client in between.
+    workspace_files: Mutex<()>,
+
+    /// The last semantic tokens sent to the client for each document, used to
+    /// compute the edits of the next request
+    semantic_tokens: RwLock<FxHashMap<lsp_types::Url, lsp_types::SemanticTokens>>,
+
+    /// The last diagnostics computed for each document, reused by the hover
+    published_diagnostics: RwLock<FxHashMap<lsp_types::Url, PublishedDiagnostics>>,
+
+    /// The files of the workspace folders that are indexed in the module graph
+    pub(crate) module_graph_index: RwLock<ModuleGraphIndex>,
+
+    /// The diagnostics computed for the files of the workspace folders that
+    /// aren't open in the client, reused while their content doesn't change
+    pub(crate) workspace_diagnostics: WorkspaceDiagnosticsCache,
+
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
     pub(crate) manifest_path: Option<PathBuf>,
 }
 
+/// The diagnostics of a document, as they were computed for the client
+pub(crate) struct PublishedDiagnostics {
+    /// The version of the document the diagnostics were computed for
+    pub(crate) version: i32,
+    /// The range of the script embedded in the Vue, Svelte and Astro files,
+    /// or the range of the whole document. The fixes of the diagnostics of
+    /// the script are relative to its start.
+    pub(crate) script: TextRange,
+    pub(crate) diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+}
+
 /// The parameters provided by the client in the "initialize" request
 struct InitializeParams {
     /// The capabilities provided by the client as part of [`lsp_types::InitializeParams`]
@@ -175,7 +217,14 @@ impl Session {
             initialize_params: OnceCell::default(),
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
+            nested_configurations: RwLock::default(),
+            broken_nested_configurations: RwLock::default(),
             documents,
+            workspace_files: Mutex::default(),
+            semantic_tokens: RwLock::default(),
+            published_diagnostics: RwLock::default(),
+            module_graph_index: RwLock::default(),
+            workspace_diagnostics: WorkspaceDiagnosticsCache::default(),
             extension_settings: config,
             fs,
             cancellation,
@@ -279,9 +328,46 @@ impl Session {
         self.documents.write().unwrap().insert(url, document);
     }
 
+    /// Locks the opening and closing of the files in the workspace, until the
+    /// returned guard is dropped
+    pub(crate) fn lock_workspace_files(&self) -> MutexGuard<'_, ()> {
+        self.workspace_files.lock().unwrap()
+    }
+
     /// Remove the [`Document`] matching the provided [`lsp_types::Url`]
     pub(crate) fn remove_document(&self, url: &lsp_types::Url) {
         self.documents.write().unwrap().remove(url);
+        self.semantic_tokens.write().unwrap().remove(url);
+        self.published_diagnostics.write().unwrap().remove(url);
+    }
+
+    /// Get the last diagnostics computed for the document
+    pub(crate) fn published_diagnostics<T>(
+        &self,
+        url: &lsp_types::Url,
+        f: impl FnOnce(&PublishedDiagnostics) -> T,
+    ) -> Option<T> {
+        self.published_diagnostics.read().unwrap().get(url).map(f)
+    }
+
+    /// Get the last semantic tokens sent to the client for the document
+    pub(crate) fn semantic_tokens(
+        &self,
+        url: &lsp_types::Url,
+    ) -> Option<lsp_types::SemanticTokens> {
+        self.semantic_tokens.read().unwrap().get(url).cloned()
+    }
+
+    /// Remember the semantic tokens sent to the client for the document
+    pub(crate) fn set_semantic_tokens(
+        &self,
+        url: lsp_types::Url,
+        semantic_tokens: lsp_types::SemanticTokens,
+    ) {
+        self.semantic_tokens
+            .write()
+            .unwrap()
+            .insert(url, semantic_tokens);
     }
 
     pub(crate) fn file_path(&self, url: &lsp_types::Url) -> Result<BiomePath> {
@@ -300,16 +386,44 @@ impl Session {
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// The diagnostics aren't published when the client pulls them.
     #[tracing::instrument(level = "trace", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<(), LspError> {
-        let biome_path = self.file_path(&url)?;
         let doc = self.document(&url)?;
-        if self.configuration_status().is_error() && !self.notified_broken_configuration() {
+        let biome_path = self.file_path(&url)?;
+        if self.configuration_status_of(&biome_path).is_error()
+            && !self.notified_broken_configuration()
+        {
             self.set_notified_broken_configuration();
             self.client
                     .show_message(MessageType::WARNING, "The configuration file has errors. Biome will report only parsing errors until the configuration is fixed.")
                     .await;
         }
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
+        let diagnostics = self.compute_diagnostics(&url, &doc.line_index)?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of the file matching the provided url, which
+    /// must be open in the workspace. The ranges of the diagnostics are
+    /// computed with `line_index`.
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        line_index: &LineIndex,
+    ) -> Result<Vec<Diagnostic>, LspError> {
+        let biome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             features: FeaturesBuilder::new()
                 .with_linter()
@@ -323,78 +437,113 @@ impl Session {
             && !file_features.supports_organize_imports()
             && !file_features.supports_assists()
         {
-            self.client
-                .publish_diagnostics(url, vec![], Some(doc.version))
-                .await;
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        let diagnostics: Vec<Diagnostic> = {
-            let mut categories = RuleCategoriesBuilder::default().with_syntax();
-            if self.configuration_status().is_loaded() {
-                if file_features.supports_lint() {
-                    categories = categories.with_lint();
-                }
-                if file_features.supports_organize_imports() {
-                    categories = categories.with_action();
-                }
+        let mut categories = RuleCategoriesBuilder::default().with_syntax();
+        if self.configuration_status_of(&biome_path).is_loaded() {
+            if file_features.supports_lint() {
+                categories = categories.with_lint();
             }
-            let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
-                path: biome_path.clone(),
-                categories: categories.build(),
-                max_diagnostics: u64::MAX,
-                only: Vec::new(),
-                skip: Vec::new(),
-            })?;
-
-            tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                path: biome_path.clone(),
-            })?;
-            let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
-                Some(b"vue") => VueFileHandler::start(content.as_str()),
-                Some(b"astro") => AstroFileHandler::start(content.as_str()),
-                Some(b"svelte") => SvelteFileHandler::start(content.as_str()),
-                _ => None,
-            };
+            if file_features.supports_organize_imports() {
+                categories = categories.with_action();
+            }
+        }
+        let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
+            path: biome_path.clone(),
+            categories: categories.build(),
+            max_diagnostics: u64::MAX,
+            only: Vec::new(),
+            skip: Vec::new(),
+            include_suppressed: false,
+        })?;
 
-            result
-                .diagnostics
-                .into_iter()
-                .filter_map(|d| {
-                    match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
-                        &doc.line_index,
-                        self.position_encoding(),
-                        offset,
-                    ) {
-                        Ok(diag) => Some(diag),
-                        Err(err) => {
-                            error!("failed to convert diagnostic to LSP: {err:?}");
-                            None
-                        }
-                    }
-                })
-                .collect()
+        tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
+        let content = self.workspace.get_file_content(GetFileContentParams {
+            path: biome_path.clone(),
+        })?;
+        let script = match biome_path.extension().map(OsStr::as_encoded_bytes) {
+            Some(b"vue") => VueFileHandler::start(content.as_str())
+                .map(|start| (start, VueFileHandler::input(content.as_str()))),
+            Some(b"astro") => AstroFileHandler::start(content.as_str())
+                .map(|start| (start, AstroFileHandler::input(content.as_str()))),
+            Some(b"svelte") => SvelteFileHandler::start(content.as_str())
+                .map(|start| (start, SvelteFileHandler::input(content.as_str()))),
+            _ => None,
         };
+        let script = script.map_or(
+            TextRange::up_to(TextSize::of(content.as_str())),
+            |(start, input)| TextRange::at(TextSize::from(start), TextSize::of(input)),
+        );
+
+        // The diagnostics of the open documents are reused by the hover
+        if let Ok(doc) = self.document(url) {
+            self.published_diagnostics.write().unwrap().insert(
+                url.clone(),
+                PublishedDiagnostics {
+                    version: doc.version,
+                    script,
+                    diagnostics: result.diagnostics.clone(),
+                },
+            );
+        }
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
+        let diagnostics = result
+            .diagnostics
+            .into_iter()
+            .filter_map(|d| {
+                match utils::diagnostic_to_lsp(d, url, line_index, self.position_encoding()) {
+                    Ok(diag) => Some(diag),
+                    Err(err) => {
+                        error!("failed to convert diagnostic to LSP: {err:?}");
+                        None
+                    }
+                }
+            })
+            .collect();
 
-        Ok(())
+        Ok(diagnostics)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
+    ///
+    /// When the client pulls the diagnostics, it's asked to pull them again
     pub(crate) async fn update_all_diagnostics(&self) {
+        self.update_diagnostics_of_documents(|_| true).await;
+    }
+
+    /// Updates diagnostics for the [`Document`]s inside `directory`, e.g.
+    /// after its configuration file changed
+    ///
+    /// The client can only be asked to pull the diagnostics of the whole
+    /// workspace again, so it's asked to when it pulls the diagnostics
+    pub(crate) async fn update_diagnostics_in_directory(&self, directory: &Path) {
+        self.update_diagnostics_of_documents(|url| {
+            url.to_file_path()
+                .is_ok_and(|path| path.starts_with(directory))
+        })
+        .await;
+    }
+
+    async fn update_diagnostics_of_documents(&self, filter: impl Fn(&lsp_types::Url) -> bool) {
+        // The diagnostics are updated when the configuration changed
+        self.workspace_diagnostics.clear();
+
+        if self.supports_pull_diagnostics() {
+            if self.supports_diagnostic_refresh() {
+                if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+                    error!("Error while refreshing the diagnostics: {err}");
+                }
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .read()
             .unwrap()
             .keys()
+            .filter(|url| filter(url))
             .map(|url| self.update_diagnostics(url.clone()))
             .collect();
 
@@ -415,6 +564,46 @@ impl Session {
             == Some(true)
     }
 
+    /// True if the client pulls the diagnostics with "textDocument/diagnostic"
+    /// requests, instead of waiting for the server to publish them
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .is_some_and(|c| c.diagnostic.is_some())
+    }
+
+    /// True if the client can resolve the edit of a code action with
+    /// "codeAction/resolve" requests
+    pub(crate) fn supports_code_action_resolve(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.code_action.as_ref())
+            .and_then(|c| c.resolve_support.as_ref())
+            .is_some_and(|c| c.properties.iter().any(|property| property == "edit"))
+    }
+
+    /// True if the client can show the progress of the long running commands,
+    /// created with "window/workDoneProgress/create" requests
+    pub(crate) fn supports_work_done_progress(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.window.as_ref())
+            .and_then(|c| c.work_done_progress)
+            == Some(true)
+    }
+
+    /// True if the client supports "workspace/diagnostic/refresh" requests
+    fn supports_diagnostic_refresh(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.diagnostic.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true)
+    }
+
     /// Get the current workspace folders
     pub(crate) fn get_workspace_folders(&self) -> Option<&Vec<WorkspaceFolder>> {
         self.initialize_params
@@ -422,6 +611,18 @@ impl Session {
             .and_then(|c| c.workspace_folders.as_ref())
     }
 
+    /// Returns the folders of the workspace, or its base path when the client
+    /// doesn't support workspace folders
+    pub(crate) fn workspace_roots(&self) -> Vec<PathBuf> {
+        match self.get_workspace_folders() {
+            Some(folders) => folders
+                .iter()
+                .filter_map(|folder| folder.uri.to_file_path().ok())
+                .collect(),
+            None => self.base_path().into_iter().collect(),
+        }
+    }
+
     /// Returns the base path of the workspace on the filesystem if it has one
     pub(crate) fn base_path(&self) -> Option<PathBuf> {
         let initialize_params = self.initialize_params.get()?;
@@ -541,6 +742,15 @@ impl Session {
                     let result =
                         configuration.retrieve_gitignore_matches(fs, configuration_path.as_deref());
 
+                    let nested_directory = match &base_path {
+                        ConfigurationPathHint::FromWorkspace(path)
+                            if self.nested_configurations.read().unwrap().contains(path) =>
+                        {
+                            Some(path.clone())
+                        }
+                        _ => None,
+                    };
+
                     match result {
                         Ok((vcs_base_path, gitignore_matches)) => {
                             let register_result =
@@ -550,8 +760,9 @@ impl Session {
                                         .register_project_folder(RegisterProjectFolderParams {
                                             path: Some(path.clone()),
                                             // This is naive, but we don't know if the user has a file already open or not, so we register every project as the current one.
-                                            // The correct one is actually set when the LSP calls `textDocument/didOpen`
-                                            set_as_current_workspace: true,
+                                            // The correct one is actually set when the LSP calls `textDocument/didOpen`.
+                                            // A nested project is only used for the files of its folder.
+                                            set_as_current_workspace: nested_directory.is_none(),
                                         })
                                         .err()
                                 } else {
@@ -567,11 +778,17 @@ impl Session {
                                 self.client.log_message(MessageType::ERROR, &error).await;
                                 return ConfigurationStatus::Error;
                             }
+                            // The settings are applied to the project registered with this folder
+                            let workspace_directory = match &base_path {
+                                ConfigurationPathHint::FromWorkspace(path) => Some(path.clone()),
+                                _ => fs.working_directory(),
+                            };
                             let result = self.workspace.update_settings(UpdateSettingsParams {
-                                workspace_directory: fs.working_directory(),
+                                workspace_directory,
                                 configuration,
                                 vcs_base_path,
                                 gitignore_matches,
+                                configuration_directory: configuration_path,
                             });
 
                             if let Err(error) = result {
@@ -599,6 +816,119 @@ impl Session {
         }
     }
 
+    /// Loads the configuration file of the nearest folder of `path` that has
+    /// one, when this folder is inside a workspace folder and its
+    /// configuration isn't loaded yet
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn load_nested_configuration(&self, path: &Path) {
+        // Providing a custom configuration path will not allow to support workspaces
+        if self.config_path.is_some() {
+            return;
+        }
+        let workspace_roots = self.workspace_roots();
+        let Some(directory) = path
+            .ancestors()
+            .skip(1)
+            .take_while(|directory| {
+                workspace_roots
+                    .iter()
+                    .any(|root| directory.starts_with(root))
+            })
+            .find(|directory| self.has_configuration_file(directory))
+        else {
+            return;
+        };
+        // The configurations of the workspace folders are loaded on initialization
+        if workspace_roots.iter().any(|root| root == directory) {
+            return;
+        }
+        let is_new = self
+            .nested_configurations
+            .write()
+            .unwrap()
+            .insert(directory.to_path_buf());
+        if is_new {
+            self.load_nested_configuration_file(directory.to_path_buf())
+                .await;
+        }
+    }
+
+    /// Reloads the nested configurations of `directory` and of its
+    /// subfolders, e.g. after the configuration file of `directory` changed.
+    /// The nested configurations usually extend the configuration of a parent
+    /// folder, so they are reloaded from the outermost to the innermost.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn reload_nested_configurations(&self, directory: &Path) {
+        let workspace_roots = self.workspace_roots();
+        let is_workspace_root = workspace_roots.iter().any(|root| root == directory);
+        if !is_workspace_root && self.has_configuration_file(directory) {
+            // The configuration file was just created
+            self.nested_configurations
+                .write()
+                .unwrap()
+                .insert(directory.to_path_buf());
+        }
+
+        let mut directories: Vec<_> = self
+            .nested_configurations
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|nested_directory| nested_directory.starts_with(directory))
+            .cloned()
+            .collect();
+        directories.sort_by_key(|directory| directory.components().count());
+
+        for directory in directories {
+            if self.has_configuration_file(&directory) {
+                self.load_nested_configuration_file(directory).await;
+            } else {
+                // The files of the folder now use the configuration of a parent folder
+                self.nested_configurations
+                    .write()
+                    .unwrap()
+                    .remove(&directory);
+                self.broken_nested_configurations
+                    .write()
+                    .unwrap()
+                    .remove(&directory);
+                let result =
+                    self.workspace
+                        .unregister_project_folder(UnregisterProjectFolderParams {
+                            path: BiomePath::new(directory),
+                        });
+                if let Err(error) = result {
+                    error!("Failed to unregister the project folder: {}", error);
+                }
+            }
+        }
+    }
+
+    async fn load_nested_configuration_file(&self, directory: PathBuf) {
+        info!("Load the nested configuration file in {:?}", directory);
+        let status = self
+            .load_biome_configuration_file(ConfigurationPathHint::FromWorkspace(directory.clone()))
+            .await;
+        // A broken nested configuration only affects the files of its folder,
+        // until it's loaded again successfully
+        let mut broken_nested_configurations = self.broken_nested_configurations.write().unwrap();
+        if status.is_error() {
+            if broken_nested_configurations.insert(directory) {
+                self.notified_broken_configuration
+                    .store(false, Ordering::Relaxed);
+            }
+        } else {
+            broken_nested_configurations.remove(&directory);
+        }
+    }
+
+    /// Returns `true` if `directory` has a `biome.json` or `biome.jsonc` file
+    fn has_configuration_file(&self, directory: &Path) -> bool {
+        ConfigName::file_names()
+            .iter()
+            .any(|file_name| self.fs.path_is_file(&directory.join(file_name)))
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn load_manifest(&self) {
         let base_path = self
@@ -687,6 +1017,23 @@ impl Session {
             .unwrap()
     }
 
+    /// Retrieves the status of the configuration that applies to `path`,
+    /// which is broken when the configuration of a nested folder containing
+    /// `path` couldn't be loaded
+    pub(crate) fn configuration_status_of(&self, path: &Path) -> ConfigurationStatus {
+        let is_in_broken_nested_folder = self
+            .broken_nested_configurations
+            .read()
+            .unwrap()
+            .iter()
+            .any(|directory| path.starts_with(directory));
+        if is_in_broken_nested_folder {
+            ConfigurationStatus::Error
+        } else {
+            self.configuration_status()
+        }
+    }
+
     /// Updates the status of the configuration
     fn set_configuration_status(&self, status: ConfigurationStatus) {
         self.notified_broken_configuration
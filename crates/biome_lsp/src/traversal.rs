@@ -0,0 +1,236 @@
+//! Traversal of the workspace folders, for the features that act on the files
+//! of the project and not only on the open documents.
+
+use crate::converters::line_index::LineIndex;
+use crate::diagnostics::LspError;
+use crate::handlers::analysis::replace_document;
+use crate::session::Session;
+use crate::utils;
+use biome_diagnostics::{print_diagnostic_to_string, Error};
+use biome_fs::{BiomePath, PathInterner, TraversalContext, TraversalScope};
+use biome_service::workspace::{CloseFileParams, FeatureName, IsPathIgnoredParams, OpenFileParams};
+use biome_service::WorkspaceError;
+use std::collections::BTreeSet;
+use std::sync::RwLock;
+use tower_lsp::lsp_types as lsp;
+use tracing::error;
+
+/// Returns the files of the workspace folders accepted by `can_handle_file`.
+/// The folders ignored for `features` aren't traversed.
+pub(crate) fn workspace_files(
+    session: &Session,
+    features: FeatureName,
+    can_handle_file: &(dyn Fn(&BiomePath) -> bool + Sync),
+) -> BTreeSet<BiomePath> {
+    let (interner, _) = PathInterner::new();
+    let ctx = WorkspaceTraversalContext {
+        session,
+        features,
+        can_handle_file,
+        interner,
+        evaluated_paths: RwLock::default(),
+    };
+
+    session.fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for root in session.workspace_roots() {
+            scope.evaluate(&ctx, root);
+        }
+    }));
+
+    ctx.evaluated_paths()
+}
+
+/// A file of the workspace processed by [with_workspace_file]
+pub(crate) struct WorkspaceFile {
+    /// The version of the document open in the client, or `None` when the file
+    /// was read from the disk
+    pub(crate) version: Option<i32>,
+    /// The line index of the content of the file when it was processed
+    pub(crate) line_index: LineIndex,
+}
+
+impl WorkspaceFile {
+    /// The edit that replaces the whole content of the file at `url` with
+    /// `new_text`. The edit carries the version of the document open in the
+    /// client, so that the client rejects it if the document changed since.
+    pub(crate) fn replace(&self, url: lsp::Url, new_text: String) -> lsp::TextDocumentEdit {
+        lsp::TextDocumentEdit {
+            text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                uri: url,
+                version: self.version,
+            },
+            edits: vec![lsp::OneOf::Left(replace_document(
+                &self.line_index,
+                new_text,
+            ))],
+        }
+    }
+}
+
+/// Runs `f` on the file at `path` of the workspace, and returns its result
+/// along with the state of the file when it was processed. The files that
+/// aren't open in the client are read from the disk, and closed once `f`
+/// returns. Returns `None` when the file can't be read, or isn't supported.
+pub(crate) fn with_workspace_file<T>(
+    session: &Session,
+    url: &lsp::Url,
+    path: &BiomePath,
+    f: impl FnOnce(&WorkspaceFile) -> Result<T, LspError>,
+) -> Result<Option<(WorkspaceFile, T)>, LspError> {
+    let Some(file) = open_workspace_file(session, url, path, None)? else {
+        return Ok(None);
+    };
+
+    with_open_workspace_file(session, url, path, file, f)
+}
+
+/// Like [with_workspace_file], when the content of the file on the disk was
+/// already read
+pub(crate) fn with_workspace_file_content<T>(
+    session: &Session,
+    url: &lsp::Url,
+    path: &BiomePath,
+    content: String,
+    f: impl FnOnce(&WorkspaceFile) -> Result<T, LspError>,
+) -> Result<Option<(WorkspaceFile, T)>, LspError> {
+    let Some(file) = open_workspace_file(session, url, path, Some(content))? else {
+        return Ok(None);
+    };
+
+    with_open_workspace_file(session, url, path, file, f)
+}
+
+fn with_open_workspace_file<T>(
+    session: &Session,
+    url: &lsp::Url,
+    path: &BiomePath,
+    file: WorkspaceFile,
+    f: impl FnOnce(&WorkspaceFile) -> Result<T, LspError>,
+) -> Result<Option<(WorkspaceFile, T)>, LspError> {
+    let result = f(&file);
+
+    close_workspace_file(session, url, path, &file)?;
+
+    match result {
+        Ok(result) => Ok(Some((file, result))),
+        Err(LspError::WorkspaceError(error)) if utils::is_unsupported_file(&error) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Opens the file at `path` in the workspace with its content on the disk,
+/// which is read unless `content` is provided, unless the client has it open.
+/// Returns `None` when the file can't be read.
+fn open_workspace_file(
+    session: &Session,
+    url: &lsp::Url,
+    path: &BiomePath,
+    content: Option<String>,
+) -> Result<Option<WorkspaceFile>, WorkspaceError> {
+    let _lock = session.lock_workspace_files();
+    if let Ok(document) = session.document(url) {
+        return Ok(Some(WorkspaceFile {
+            version: Some(document.version),
+            line_index: document.line_index,
+        }));
+    }
+
+    let content = match content {
+        Some(content) => content,
+        None => match session.fs.read_file_from_path(path) {
+            Ok(content) => content,
+            Err(_) => return Ok(None),
+        },
+    };
+    let line_index = LineIndex::new(&content);
+    session.workspace.open_file(OpenFileParams {
+        path: path.clone(),
+        content,
+        version: 0,
+        document_file_source: None,
+    })?;
+
+    Ok(Some(WorkspaceFile {
+        version: None,
+        line_index,
+    }))
+}
+
+/// Closes the file opened from the disk by [open_workspace_file]. The file
+/// stays open when the client opened it in the meantime.
+fn close_workspace_file(
+    session: &Session,
+    url: &lsp::Url,
+    path: &BiomePath,
+    file: &WorkspaceFile,
+) -> Result<(), WorkspaceError> {
+    // The workspace only keeps the documents that the client opened
+    if file.version.is_some() {
+        return Ok(());
+    }
+
+    let _lock = session.lock_workspace_files();
+    if session.document(url).is_err() {
+        session
+            .workspace
+            .close_file(CloseFileParams { path: path.clone() })?;
+    }
+
+    Ok(())
+}
+
+/// Returns a workspace edit that applies the given document changes
+pub(crate) fn workspace_edit(changes: Vec<lsp::TextDocumentEdit>) -> lsp::WorkspaceEdit {
+    lsp::WorkspaceEdit {
+        changes: None,
+        document_changes: Some(lsp::DocumentChanges::Edits(changes)),
+        change_annotations: None,
+    }
+}
+
+struct WorkspaceTraversalContext<'a> {
+    session: &'a Session,
+    features: FeatureName,
+    can_handle_file: &'a (dyn Fn(&BiomePath) -> bool + Sync),
+    interner: PathInterner,
+    evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+}
+
+impl TraversalContext for WorkspaceTraversalContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        error!(
+            "Error while traversing the workspace: {}",
+            print_diagnostic_to_string(&error)
+        );
+    }
+
+    fn can_handle(&self, biome_path: &BiomePath) -> bool {
+        let path = biome_path.as_path();
+        if self.session.fs.path_is_dir(path) || self.session.fs.path_is_symlink(path) {
+            return !self
+                .session
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    biome_path: biome_path.clone(),
+                    features: self.features,
+                })
+                .unwrap_or(true);
+        }
+
+        self.session.fs.path_is_file(path) && (self.can_handle_file)(biome_path)
+    }
+
+    fn handle_path(&self, _path: BiomePath) {}
+
+    fn store_path(&self, path: BiomePath) {
+        self.evaluated_paths.write().unwrap().insert(path);
+    }
+
+    fn evaluated_paths(&self) -> BTreeSet<BiomePath> {
+        self.evaluated_paths.read().unwrap().clone()
+    }
+}
@@ -9,14 +9,15 @@ use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{
     Applicability, {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
 };
-use biome_rowan::{TextRange, TextSize};
+use biome_rowan::TextSize;
 use biome_service::workspace::CodeAction;
+use biome_service::WorkspaceError;
 use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
 use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::ops::{Add, Range};
+use std::ops::Range;
 use std::{io, mem};
 use tower_lsp::jsonrpc::Error as LspError;
 use tower_lsp::lsp_types;
@@ -182,19 +183,10 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
     url: &lsp::Url,
     line_index: &LineIndex,
     position_encoding: PositionEncoding,
-    offset: Option<u32>,
 ) -> Result<lsp::Diagnostic> {
     let location = diagnostic.location();
 
     let span = location.span.context("diagnostic location has no span")?;
-    let span = if let Some(offset) = offset {
-        TextRange::new(
-            span.start().add(TextSize::from(offset)),
-            span.end().add(TextSize::from(offset)),
-        )
-    } else {
-        span
-    };
     let span = to_proto::range(line_index, span, position_encoding)
         .context("failed to convert diagnostic span to LSP range")?;
 
@@ -296,7 +288,7 @@ impl Visit for RelatedInformationVisitor<'_> {
 }
 
 /// Convert a piece of markup into a String
-fn print_markup(markup: &MarkupBuf) -> String {
+pub(crate) fn print_markup(markup: &MarkupBuf) -> String {
     let mut message = Termcolor(NoColor::new(Vec::new()));
     fmt::Display::fmt(markup, &mut Formatter::new(&mut message))
         // SAFETY: Writing to a memory buffer should never fail
@@ -306,6 +298,15 @@ fn print_markup(markup: &MarkupBuf) -> String {
     String::from_utf8(message.0.into_inner()).unwrap()
 }
 
+/// Whether the workspace can't compute the features of the file, either
+/// because it's ignored or because its language doesn't support them
+pub(crate) fn is_unsupported_file(error: &WorkspaceError) -> bool {
+    matches!(
+        error,
+        WorkspaceError::FileIgnored(_) | WorkspaceError::SourceFileNotSupported(_)
+    )
+}
+
 /// Helper to create a [tower_lsp::jsonrpc::Error] from a message
 pub(crate) fn into_lsp_error(msg: impl Display + Debug) -> LspError {
     let mut error = LspError::internal_error();
@@ -2,7 +2,7 @@ use anyhow::bail;
 use anyhow::Context;
 use anyhow::Error;
 use anyhow::Result;
-use biome_fs::{BiomePath, MemoryFileSystem};
+use biome_fs::{BiomePath, FileSystem, MemoryFileSystem, OpenOptions};
 use biome_lsp::LSPServer;
 use biome_lsp::ServerFactory;
 use biome_lsp::WorkspaceSettings;
@@ -16,11 +16,12 @@ use futures::Stream;
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, to_value, Value};
 use std::any::type_name;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::slice;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 use tower::timeout::Timeout;
@@ -356,6 +357,10 @@ const CHANNEL_BUFFER_SIZE: usize = 8;
 #[derive(Debug, PartialEq, Eq)]
 enum ServerNotification {
     PublishDiagnostics(PublishDiagnosticsParams),
+    ApplyEdit(lsp::ApplyWorkspaceEditParams),
+    /// The parameters of a `$/progress` notification, which can carry a
+    /// partial result of a request
+    Progress(Value),
 }
 
 /// Basic handler for requests and notifications coming from the server for tests
@@ -380,6 +385,14 @@ where
                 Err(_) => break,
             }
         }
+        if req.method() == "$/progress" {
+            let params = req.params().expect("invalid request");
+            notify
+                .send(ServerNotification::Progress(params.clone()))
+                .await
+                .ok();
+            continue;
+        }
 
         let id = match req.id() {
             Some(id) => id,
@@ -389,6 +402,7 @@ where
         let res = match req.method() {
             "workspace/configuration" => {
                 let settings = WorkspaceSettings {
+                    rename: Some(true),
                     ..WorkspaceSettings::default()
                 };
                 let result =
@@ -396,6 +410,19 @@ where
 
                 Response::from_ok(id.clone(), result)
             }
+            "workspace/applyEdit" => {
+                let params = req.params().expect("invalid request");
+                let edit = from_value(params.clone()).expect("invalid params");
+                notify.send(ServerNotification::ApplyEdit(edit)).await.ok();
+                let result = to_value(lsp::ApplyWorkspaceEditResponse {
+                    applied: true,
+                    failure_reason: None,
+                    failed_change: None,
+                })
+                .context("failed to serialize the response")?;
+
+                Response::from_ok(id.clone(), result)
+            }
             _ => Response::from_error(id.clone(), jsonrpc::Error::method_not_found()),
         };
 
@@ -1899,31 +1926,31 @@ async fn pull_refactors() -> Result<()> {
                     },
                     end: Position {
                         line: 0,
-                        character: 15,
+                        character: 24,
                     },
                 },
-                new_text: String::from("func("),
+                new_text: String::new(),
             },
             TextEdit {
                 range: Range {
                     start: Position {
                         line: 0,
-                        character: 22,
+                        character: 29,
                     },
                     end: Position {
                         line: 0,
                         character: 37,
                     },
                 },
-                new_text: String::new(),
+                new_text: String::from("\"value\""),
             },
         ],
     );
 
-    let _expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Inline variable"),
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Inline the variable variable"),
         kind: Some(lsp::CodeActionKind::new(
-            "refactor.inline.biome.correctness.inlineVariable",
+            "refactor.inline.biome.refactor.inlineVariable",
         )),
         diagnostics: None,
         edit: Some(lsp::WorkspaceEdit {
@@ -1932,12 +1959,12 @@ async fn pull_refactors() -> Result<()> {
             change_annotations: None,
         }),
         command: None,
-        is_preferred: Some(true),
+        is_preferred: None,
         disabled: None,
         data: None,
     });
 
-    assert_eq!(res, vec![]);
+    assert_eq!(res, vec![expected_action]);
 
     server.close_document().await?;
 
@@ -2051,9 +2078,25 @@ async fn pull_fix_all() -> Result<()> {
 }
 
 #[tokio::test]
-async fn change_document_remove_line() -> Result<()> {
+async fn hover_shows_rule_documentation() -> Result<()> {
     let factory = ServerFactory::default();
-    let (service, client) = factory.create(None).into_inner();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "linter": {
+            "rules": { "suspicious": { "noCompareNegZero": "warn" } }
+        },
+        "overrides": [{
+            "include": ["document.js"],
+            "linter": {
+                "rules": { "suspicious": { "noCompareNegZero": "error" } }
+            }
+        }]
+    }"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
     let (stream, sink) = client.split();
     let mut server = Server::new(service);
 
@@ -2063,51 +2106,63 @@ async fn change_document_remove_line() -> Result<()> {
     server.initialize().await?;
     server.initialized().await?;
 
-    server
-        .open_document(
-            r#"("Jan 1, 2018 – Jan 1, 2019");
-("Jan 1, 2018 – Jan 1, 2019");
-isSpreadAssignment;
-"#,
-        )
-        .await?;
+    server.load_configuration().await?;
 
-    server
-        .change_document(
-            1,
-            vec![TextDocumentContentChangeEvent {
-                range: Some(Range {
-                    start: Position {
-                        line: 0,
-                        character: 30,
-                    },
-                    end: Position {
-                        line: 1,
-                        character: 0,
-                    },
-                }),
-                range_length: None,
-                text: String::new(),
-            }],
-        )
-        .await?;
+    server.open_document("if(a === -0) {}").await?;
 
-    let actual: String = server
-        .request(
-            "biome/get_file_content",
-            "get_file_content",
-            GetFileContentParams {
-                path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
+    let hover_at = |character| lsp::HoverParams {
+        text_document_position_params: lsp::TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: url!("document.js"),
             },
-        )
-        .await?
-        .context("get file content error")?;
+            position: Position { line: 0, character },
+        },
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+    };
 
-    let expected = r#"("Jan 1, 2018 – Jan 1, 2019");("Jan 1, 2018 – Jan 1, 2019");
-isSpreadAssignment;
-"#;
+    let res: lsp::Hover = server
+        .request("textDocument/hover", "hover", hover_at(10))
+        .await?
+        .context("hover returned None")?;
 
-    assert_eq!(&actual, expected);
+    assert_eq!(
+        res.range,
+        Some(Range {
+            start: Position {
+                line: 0,
+                character: 3,
+            },
+            end: Position {
+                line: 0,
+                character: 11,
+            },
+        })
+    );
+    let lsp::HoverContents::Markup(contents) = res.contents else {
+        bail!("hover didn't return markup contents");
+    };
+    assert_eq!(contents.kind, lsp::MarkupKind::Markdown);
+    assert!(contents.value.starts_with(
+        "# noCompareNegZero\n\nSeverity: error.\nFix is safe.\nThis rule is recommended.\n"
+    ));
+    // The configuration of the rule applies the overrides of the file
+    assert!(contents
+        .value
+        .contains("\n# Configuration\n```json\n\"error\"\n```\n"));
+    assert!(contents.value.contains(
+        "\n# Fix\nReplace -0 with 0\n```diff\n- if(a === -0) {}\n+ if(a === 0) {}\n```\n"
+    ));
+    assert!(contents
+        .value
+        .contains("\n# Description\nDisallow comparing against `-0`\n"));
+
+    let res: Option<lsp::Hover> = server
+        .request("textDocument/hover", "hover", hover_at(0))
+        .await?
+        .context("hover returned None")?;
+    assert!(res.is_none());
 
     server.close_document().await?;
 
@@ -2118,7 +2173,7 @@ isSpreadAssignment;
 }
 
 #[tokio::test]
-async fn format_with_syntax_errors() -> Result<()> {
+async fn navigates_to_the_definition_and_the_references() -> Result<()> {
     let factory = ServerFactory::default();
     let (service, client) = factory.create(None).into_inner();
     let (stream, sink) = client.split();
@@ -2130,33 +2185,103 @@ async fn format_with_syntax_errors() -> Result<()> {
     server.initialize().await?;
     server.initialized().await?;
 
-    server.open_document("expression(").await?;
+    server
+        .open_document("let a = 1;\na = 2;\nconsole.log(a);\n")
+        .await?;
 
-    let res: Option<Vec<TextEdit>> = server
+    let position_at = |line, character| lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        position: Position { line, character },
+    };
+    let range = |line, character| Range {
+        start: Position { line, character },
+        end: Position {
+            line,
+            character: character + 1,
+        },
+    };
+
+    let res: lsp::GotoDefinitionResponse = server
         .request(
-            "textDocument/formatting",
-            "formatting",
-            DocumentFormattingParams {
-                text_document: TextDocumentIdentifier {
-                    uri: url!("document.js"),
+            "textDocument/definition",
+            "goto_definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: position_at(2, 12),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
                 },
-                options: FormattingOptions {
-                    tab_size: 4,
-                    insert_spaces: false,
-                    properties: HashMap::default(),
-                    trim_trailing_whitespace: None,
-                    insert_final_newline: None,
-                    trim_final_newlines: None,
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
                 },
+            },
+        )
+        .await?
+        .context("goto_definition returned None")?;
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+            uri: url!("document.js"),
+            range: range(0, 4),
+        })
+    );
+
+    let res: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: position_at(1, 0),
                 work_done_progress_params: WorkDoneProgressParams {
                     work_done_token: None,
                 },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+                context: lsp::ReferenceContext {
+                    include_declaration: false,
+                },
             },
         )
         .await?
-        .context("formatting returned None")?;
+        .context("references returned None")?;
+    let ranges: Vec<_> = res.into_iter().map(|location| location.range).collect();
+    assert_eq!(ranges, vec![range(1, 0), range(2, 12)]);
 
-    assert!(res.is_none());
+    let res: Vec<lsp::DocumentHighlight> = server
+        .request(
+            "textDocument/documentHighlight",
+            "document_highlight",
+            lsp::DocumentHighlightParams {
+                text_document_position_params: position_at(0, 4),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("document_highlight returned None")?;
+    assert_eq!(
+        res,
+        vec![
+            lsp::DocumentHighlight {
+                range: range(0, 4),
+                kind: Some(lsp::DocumentHighlightKind::TEXT),
+            },
+            lsp::DocumentHighlight {
+                range: range(1, 0),
+                kind: Some(lsp::DocumentHighlightKind::WRITE),
+            },
+            lsp::DocumentHighlight {
+                range: range(2, 12),
+                kind: Some(lsp::DocumentHighlightKind::READ),
+            },
+        ]
+    );
 
     server.close_document().await?;
 
@@ -2167,62 +2292,138 @@ async fn format_with_syntax_errors() -> Result<()> {
 }
 
 #[tokio::test]
-async fn format_jsx_in_javascript_file() -> Result<()> {
+// The `root_path` field is deprecated, but we still need to specify it
+#[allow(deprecated)]
+async fn pull_document_and_workspace_diagnostics() -> Result<()> {
     let factory = ServerFactory::default();
-    let (service, client) = factory.create(None).into_inner();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}");
+    fs.insert(url!("other.js").to_file_path().unwrap(), "debugger;\n");
+    fs.insert(url!("clean.js").to_file_path().unwrap(), "export {};\n");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
     let (stream, sink) = client.split();
     let mut server = Server::new(service);
 
     let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
     let reader = tokio::spawn(client_handler(stream, sink, sender));
 
-    server.initialize().await?;
-    server.initialized().await?;
-
-    server
-        .notify(
-            "textDocument/didOpen",
-            DidOpenTextDocumentParams {
-                text_document: TextDocumentItem {
-                    uri: url!("document"),
-                    language_id: String::from("javascript"),
-                    version: 0,
-                    text: String::from("const f  =  () => <div/>;"),
+    let res: InitializeResult = server
+        .request(
+            "initialize",
+            "_init",
+            InitializeParams {
+                process_id: None,
+                root_path: None,
+                root_uri: Some(url!("")),
+                initialization_options: None,
+                capabilities: ClientCapabilities {
+                    text_document: Some(lsp::TextDocumentClientCapabilities {
+                        diagnostic: Some(lsp::DiagnosticClientCapabilities::default()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
                 },
+                trace: None,
+                workspace_folders: None,
+                client_info: None,
+                locale: None,
             },
         )
-        .await?;
+        .await?
+        .context("initialize returned None")?;
+    assert!(res.capabilities.diagnostic_provider.is_some());
+    server.initialized().await?;
 
-    let res: Option<Vec<TextEdit>> = server
+    server.load_configuration().await?;
+
+    server.open_document("debugger;\n").await?;
+
+    let document_diagnostic = |previous_result_id| lsp::DocumentDiagnosticParams {
+        text_document: TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        identifier: None,
+        previous_result_id,
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+
+    let res: lsp::DocumentDiagnosticReportResult = server
         .request(
-            "textDocument/formatting",
-            "formatting",
-            DocumentFormattingParams {
-                text_document: TextDocumentIdentifier {
-                    uri: url!("document"),
-                },
-                options: FormattingOptions::default(),
-                work_done_progress_params: WorkDoneProgressParams {
-                    work_done_token: None,
-                },
-            },
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            document_diagnostic(None),
         )
         .await?
-        .context("formatting returned None")?;
+        .context("document_diagnostic returned None")?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        res
+    else {
+        bail!("document_diagnostic didn't return a full report");
+    };
+    let report = report.full_document_diagnostic_report;
+    assert_eq!(report.items.len(), 1);
+    assert_eq!(
+        report.items[0].code,
+        Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDebugger"
+        )))
+    );
 
-    let edits = res.context("formatting did not return an edit list")?;
-    assert!(!edits.is_empty(), "formatting returned an empty edit list");
+    let res: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            document_diagnostic(report.result_id.clone()),
+        )
+        .await?
+        .context("document_diagnostic returned None")?;
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Unchanged(
+        unchanged,
+    )) = res
+    else {
+        bail!("document_diagnostic didn't return an unchanged report");
+    };
+    assert_eq!(
+        Some(unchanged.unchanged_document_diagnostic_report.result_id),
+        report.result_id
+    );
 
-    server
-        .notify(
-            "textDocument/didClose",
-            DidCloseTextDocumentParams {
-                text_document: TextDocumentIdentifier {
-                    uri: url!("document"),
+    let res: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: Vec::new(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
                 },
             },
         )
-        .await?;
+        .await?
+        .context("workspace_diagnostic returned None")?;
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = res else {
+        bail!("workspace_diagnostic didn't return a report");
+    };
+    // The open document and the files without diagnostics aren't reported
+    assert_eq!(report.items.len(), 1);
+    let lsp::WorkspaceDocumentDiagnosticReport::Full(report) = &report.items[0] else {
+        bail!("workspace_diagnostic didn't return a full report");
+    };
+    assert_eq!(report.uri, url!("other.js"));
+    assert_eq!(report.full_document_diagnostic_report.items.len(), 1);
+
+    server.close_document().await?;
 
     server.shutdown().await?;
     reader.abort();
@@ -2230,34 +2431,687 @@ async fn format_jsx_in_javascript_file() -> Result<()> {
     Ok(())
 }
 
+/// Pulls the diagnostics of the workspace, telling the server which
+/// diagnostics the client already has
+async fn pull_workspace_diagnostics(
+    server: &mut Server,
+    previous_result_ids: Vec<lsp::PreviousResultId>,
+    partial_result_token: Option<lsp::ProgressToken>,
+) -> Result<lsp::WorkspaceDiagnosticReport> {
+    let res: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids,
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token,
+                },
+            },
+        )
+        .await?
+        .context("workspace_diagnostic returned None")?;
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = res else {
+        bail!("workspace_diagnostic didn't return a report");
+    };
+    Ok(report)
+}
+
 #[tokio::test]
-async fn does_not_format_ignored_files() -> Result<()> {
+// The `root_path` field is deprecated, but we still need to specify it
+#[allow(deprecated)]
+async fn pull_workspace_diagnostics_of_changed_and_removed_files() -> Result<()> {
     let factory = ServerFactory::default();
     let mut fs = MemoryFileSystem::default();
-    let config = r#"{
-        "files": {
-            "ignore": ["document.js"]
-        }
-    }"#;
-
-    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}");
+    fs.insert(url!("other.js").to_file_path().unwrap(), "debugger;\n");
+    // The server shares the file system, so that the test can change the files
+    let fs = Arc::new(fs);
     let (service, client) = factory
-        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .create_with_fs(None, DynRef::Owned(Box::new(fs.clone())))
         .into_inner();
     let (stream, sink) = client.split();
     let mut server = Server::new(service);
 
-    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
     let reader = tokio::spawn(client_handler(stream, sink, sender));
 
-    server.initialize().await?;
-    server.initialized().await?;
-
     server
-        .open_named_document(config, url!("biome.json"), "json")
-        .await?;
+        .request::<_, InitializeResult>(
+            "initialize",
+            "_init",
+            InitializeParams {
+                process_id: None,
+                root_path: None,
+                root_uri: Some(url!("")),
+                initialization_options: None,
+                capabilities: ClientCapabilities {
+                    text_document: Some(lsp::TextDocumentClientCapabilities {
+                        diagnostic: Some(lsp::DiagnosticClientCapabilities::default()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                trace: None,
+                workspace_folders: None,
+                client_info: None,
+                locale: None,
+            },
+        )
+        .await?
+        .context("initialize returned None")?;
+    server.initialized().await?;
+    server.load_configuration().await?;
 
-    server
+    let write_file = |url: Url, content: &str| {
+        let mut file = fs.open_with_options(
+            &url.to_file_path().unwrap(),
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(content.as_bytes())?;
+        Ok::<_, Error>(())
+    };
+    let previous_result_id = |report: &lsp::WorkspaceDocumentDiagnosticReport| match report {
+        lsp::WorkspaceDocumentDiagnosticReport::Full(report) => lsp::PreviousResultId {
+            uri: report.uri.clone(),
+            value: report
+                .full_document_diagnostic_report
+                .result_id
+                .clone()
+                .unwrap(),
+        },
+        lsp::WorkspaceDocumentDiagnosticReport::Unchanged(report) => lsp::PreviousResultId {
+            uri: report.uri.clone(),
+            value: report
+                .unchanged_document_diagnostic_report
+                .result_id
+                .clone(),
+        },
+    };
+
+    let report = pull_workspace_diagnostics(&mut server, Vec::new(), None).await?;
+    let [lsp::WorkspaceDocumentDiagnosticReport::Full(full)] = report.items.as_slice() else {
+        bail!("expected a full report, received {:?}", report.items);
+    };
+    assert_eq!(full.uri, url!("other.js"));
+    assert_eq!(full.full_document_diagnostic_report.items.len(), 1);
+    let previous_result_ids = vec![previous_result_id(&report.items[0])];
+
+    let report = pull_workspace_diagnostics(&mut server, previous_result_ids.clone(), None).await?;
+    let [lsp::WorkspaceDocumentDiagnosticReport::Unchanged(unchanged)] = report.items.as_slice()
+    else {
+        bail!("expected an unchanged report, received {:?}", report.items);
+    };
+    assert_eq!(unchanged.uri, url!("other.js"));
+
+    // The diagnostics are computed again when the content of the file changes
+    write_file(url!("other.js"), "debugger;\ndebugger;\n")?;
+    let report = pull_workspace_diagnostics(&mut server, previous_result_ids.clone(), None).await?;
+    let [lsp::WorkspaceDocumentDiagnosticReport::Full(full)] = report.items.as_slice() else {
+        bail!("expected a full report, received {:?}", report.items);
+    };
+    assert_eq!(full.full_document_diagnostic_report.items.len(), 2);
+    let previous_result_ids = vec![previous_result_id(&report.items[0])];
+
+    // The client is told to remove the diagnostics of the removed file
+    fs.remove_dir_all(&url!("other.js").to_file_path().unwrap())?;
+    let report = pull_workspace_diagnostics(&mut server, previous_result_ids, None).await?;
+    let [lsp::WorkspaceDocumentDiagnosticReport::Full(full)] = report.items.as_slice() else {
+        bail!("expected a full report, received {:?}", report.items);
+    };
+    assert_eq!(full.uri, url!("other.js"));
+    assert!(full.full_document_diagnostic_report.items.is_empty());
+
+    // The reports are sent as partial results when the client asks for them
+    write_file(url!("new.js"), "debugger;\n")?;
+    let token = lsp::ProgressToken::String(String::from("partial"));
+    let report = pull_workspace_diagnostics(&mut server, Vec::new(), Some(token)).await?;
+    assert!(report.items.is_empty());
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            bail!("timed out waiting for the server to send a partial result")
+        }
+    };
+    let Some(ServerNotification::Progress(params)) = notification else {
+        bail!("expected a partial result, received {notification:?}");
+    };
+    assert_eq!(params["token"], "partial");
+    let partial_result: lsp::WorkspaceDiagnosticReportPartialResult =
+        from_value(params["value"].clone())?;
+    let [lsp::WorkspaceDocumentDiagnosticReport::Full(full)] = partial_result.items.as_slice()
+    else {
+        bail!(
+            "expected a full report, received {:?}",
+            partial_result.items
+        );
+    };
+    assert_eq!(full.uri, url!("new.js"));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+/// Pulls the diagnostics of the workspace, and returns the files that have some
+async fn workspace_diagnostic_urls(server: &mut Server) -> Result<Vec<Url>> {
+    let report = pull_workspace_diagnostics(server, Vec::new(), None).await?;
+    Ok(report
+        .items
+        .into_iter()
+        .map(|item| match item {
+            lsp::WorkspaceDocumentDiagnosticReport::Full(report) => report.uri,
+            lsp::WorkspaceDocumentDiagnosticReport::Unchanged(report) => report.uri,
+        })
+        .collect())
+}
+
+#[tokio::test]
+// The `root_path` field is deprecated, but we still need to specify it
+#[allow(deprecated)]
+async fn pull_workspace_diagnostics_indexes_the_changed_modules() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("biome.json").to_file_path().unwrap(),
+        r#"{ "linter": { "rules": { "nursery": { "noUnresolvedImports": "error" } } } }"#,
+    );
+    fs.insert(
+        url!("a.js").to_file_path().unwrap(),
+        "import { foo } from \"./b.js\";\nfoo();\n",
+    );
+    fs.insert(
+        url!("b.js").to_file_path().unwrap(),
+        "export const foo = 1;\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server
+        .request::<_, InitializeResult>(
+            "initialize",
+            "_init",
+            InitializeParams {
+                process_id: None,
+                root_path: None,
+                root_uri: Some(url!("")),
+                initialization_options: None,
+                capabilities: ClientCapabilities {
+                    text_document: Some(lsp::TextDocumentClientCapabilities {
+                        diagnostic: Some(lsp::DiagnosticClientCapabilities::default()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                trace: None,
+                workspace_folders: None,
+                client_info: None,
+                locale: None,
+            },
+        )
+        .await?
+        .context("initialize returned None")?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    assert_eq!(
+        workspace_diagnostic_urls(&mut server).await?,
+        Vec::<Url>::new()
+    );
+
+    // The open document is indexed instead of the file on the disk
+    server
+        .open_named_document("export const bar = 1;\n", url!("b.js"), "javascript")
+        .await?;
+    server
+        .request::<_, lsp::DocumentDiagnosticReportResult>(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier { uri: url!("b.js") },
+                identifier: None,
+                previous_result_id: None,
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?;
+    assert_eq!(
+        workspace_diagnostic_urls(&mut server).await?,
+        vec![url!("a.js")]
+    );
+
+    // The file on the disk is indexed again once the document is closed
+    server
+        .notify(
+            "textDocument/didClose",
+            DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri: url!("b.js") },
+            },
+        )
+        .await?;
+    assert_eq!(
+        workspace_diagnostic_urls(&mut server).await?,
+        Vec::<Url>::new()
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn format_workspace_command() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}\n");
+    fs.insert(url!("document.js").to_file_path().unwrap(), "let a = 1;\n");
+    fs.insert(url!("other.js").to_file_path().unwrap(), "statement (   );");
+    fs.insert(url!("clean.js").to_file_path().unwrap(), "statement();\n");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    // The content of the open documents is formatted instead of the one on the disk
+    server.open_document("let   a = 1").await?;
+
+    let res: Option<Value> = server
+        .request(
+            "workspace/executeCommand",
+            "execute_command",
+            lsp::ExecuteCommandParams {
+                command: String::from("biome.formatWorkspace"),
+                arguments: Vec::new(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?;
+    assert_eq!(res, Some(Value::Null));
+
+    // The diagnostics of the open document can be published before the edit
+    let notification = loop {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(1)) => {
+                panic!("timed out waiting for the server to send the edit")
+            }
+        };
+        if !matches!(
+            notification,
+            Some(ServerNotification::PublishDiagnostics(_))
+        ) {
+            break notification;
+        }
+    };
+    let Some(ServerNotification::ApplyEdit(params)) = notification else {
+        bail!("the server didn't send the edit");
+    };
+    let Some(lsp::DocumentChanges::Edits(changes)) = params.edit.document_changes else {
+        bail!("the edit has no document changes");
+    };
+    // The files that are already formatted aren't changed. The edits of the
+    // open documents carry their version.
+    assert_eq!(
+        changes,
+        vec![
+            lsp::TextDocumentEdit {
+                text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                    uri: url!("document.js"),
+                    version: Some(0),
+                },
+                edits: vec![lsp::OneOf::Left(TextEdit {
+                    range: Range {
+                        start: Position {
+                            line: 0,
+                            character: 0,
+                        },
+                        end: Position {
+                            line: 1,
+                            character: 0,
+                        },
+                    },
+                    new_text: String::from("let a = 1;\n"),
+                })],
+            },
+            lsp::TextDocumentEdit {
+                text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                    uri: url!("other.js"),
+                    version: None,
+                },
+                edits: vec![lsp::OneOf::Left(TextEdit {
+                    range: Range {
+                        start: Position {
+                            line: 0,
+                            character: 0,
+                        },
+                        end: Position {
+                            line: 1,
+                            character: 0,
+                        },
+                    },
+                    new_text: String::from("statement();\n"),
+                })],
+            },
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolve_remove_unused_suppressions_in_workspace() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}\n");
+    fs.insert(url!("document.js").to_file_path().unwrap(), "let a = 1;\n");
+    fs.insert(
+        url!("other.js").to_file_path().unwrap(),
+        "// biome-ignore lint/suspicious/noDebugger: reason\nlet b = 2;\n",
+    );
+    fs.insert(url!("clean.js").to_file_path().unwrap(), "let c = 3;\n");
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    // The content of the open documents is fixed instead of the one on the disk
+    server
+        .open_document("// biome-ignore lint/suspicious/noDebugger: reason\nlet a = 1;\n")
+        .await?;
+
+    let action: lsp::CodeAction = server
+        .request(
+            "codeAction/resolve",
+            "resolve_code_action",
+            lsp::CodeAction {
+                title: String::from("Remove the unused suppression comments of the workspace"),
+                data: Some(Value::from("biome.removeUnusedSuppressionsInWorkspace")),
+                ..lsp::CodeAction::default()
+            },
+        )
+        .await?
+        .context("resolve_code_action returned None")?;
+
+    let edit = action.edit.context("the action has no edit")?;
+    assert_eq!(edit.changes, None);
+    let Some(lsp::DocumentChanges::Edits(changes)) = edit.document_changes else {
+        bail!("the edit has no document changes");
+    };
+    // The files without unused suppression comments aren't changed. The edits
+    // of the open documents carry their version.
+    assert_eq!(
+        changes
+            .iter()
+            .map(|change| (
+                change.text_document.uri.clone(),
+                change.text_document.version
+            ))
+            .collect::<Vec<_>>(),
+        vec![(url!("document.js"), Some(0)), (url!("other.js"), None)]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_document_remove_line() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            r#"("Jan 1, 2018 – Jan 1, 2019");
+("Jan 1, 2018 – Jan 1, 2019");
+isSpreadAssignment;
+"#,
+        )
+        .await?;
+
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: Some(Range {
+                    start: Position {
+                        line: 0,
+                        character: 30,
+                    },
+                    end: Position {
+                        line: 1,
+                        character: 0,
+                    },
+                }),
+                range_length: None,
+                text: String::new(),
+            }],
+        )
+        .await?;
+
+    let actual: String = server
+        .request(
+            "biome/get_file_content",
+            "get_file_content",
+            GetFileContentParams {
+                path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
+            },
+        )
+        .await?
+        .context("get file content error")?;
+
+    let expected = r#"("Jan 1, 2018 – Jan 1, 2019");("Jan 1, 2018 – Jan 1, 2019");
+isSpreadAssignment;
+"#;
+
+    assert_eq!(&actual, expected);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn format_with_syntax_errors() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("expression(").await?;
+
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/formatting",
+            "formatting",
+            DocumentFormattingParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                options: FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: false,
+                    properties: HashMap::default(),
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("formatting returned None")?;
+
+    assert!(res.is_none());
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn format_jsx_in_javascript_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .notify(
+            "textDocument/didOpen",
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: url!("document"),
+                    language_id: String::from("javascript"),
+                    version: 0,
+                    text: String::from("const f  =  () => <div/>;"),
+                },
+            },
+        )
+        .await?;
+
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/formatting",
+            "formatting",
+            DocumentFormattingParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document"),
+                },
+                options: FormattingOptions::default(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("formatting returned None")?;
+
+    let edits = res.context("formatting did not return an edit list")?;
+    assert!(!edits.is_empty(), "formatting returned an empty edit list");
+
+    server
+        .notify(
+            "textDocument/didClose",
+            DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document"),
+                },
+            },
+        )
+        .await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_format_ignored_files() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "files": {
+            "ignore": ["document.js"]
+        }
+    }"#;
+
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(config, url!("biome.json"), "json")
+        .await?;
+
+    server
         .open_named_document("statement (   );", url!("document.js"), "js")
         .await?;
 
@@ -2546,3 +3400,222 @@ async fn multiple_projects() -> Result<()> {
 
     Ok(())
 }
+
+/// Waits for the next diagnostics published by the server, and returns the
+/// document they belong to with their codes
+async fn next_published_diagnostics(
+    receiver: &mut futures::channel::mpsc::Receiver<ServerNotification>,
+) -> Result<(Url, Vec<String>)> {
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            bail!("timed out waiting for the server to send diagnostics")
+        }
+    };
+    let Some(ServerNotification::PublishDiagnostics(params)) = notification else {
+        bail!("expected published diagnostics, received {notification:?}");
+    };
+    let codes = params
+        .diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| match diagnostic.code {
+            Some(lsp::NumberOrString::String(code)) => Some(code),
+            _ => None,
+        })
+        .collect();
+    Ok((params.uri, codes))
+}
+
+#[tokio::test]
+async fn reload_broken_nested_configuration() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}");
+    fs.insert(url!("packages/a/biome.json").to_file_path().unwrap(), "{}");
+    // The server shares the file system, so that the test can change the nested configuration
+    let fs = Arc::new(fs);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs.clone())))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server
+        .open_named_document("debugger;\n", url!("file.js"), "javascript")
+        .await?;
+    assert_eq!(
+        next_published_diagnostics(&mut receiver).await?,
+        (
+            url!("file.js"),
+            vec![String::from("lint/suspicious/noDebugger")]
+        )
+    );
+    server
+        .open_named_document("debugger;\n", url!("packages/a/file.js"), "javascript")
+        .await?;
+    assert_eq!(
+        next_published_diagnostics(&mut receiver).await?,
+        (
+            url!("packages/a/file.js"),
+            vec![String::from("lint/suspicious/noDebugger")]
+        )
+    );
+
+    let change_nested_configuration = |content: &'static str| {
+        let mut file = fs.open_with_options(
+            &url!("packages/a/biome.json").to_file_path().unwrap(),
+            OpenOptions::default().write(true).truncate(true),
+        )?;
+        file.set_content(content.as_bytes())?;
+        Ok::<_, Error>(lsp::DidChangeWatchedFilesParams {
+            changes: vec![lsp::FileEvent {
+                uri: url!("packages/a/biome.json"),
+                typ: lsp::FileChangeType::CHANGED,
+            }],
+        })
+    };
+
+    // Only the syntax errors are reported in the folder of the broken configuration,
+    // and only the documents of this folder are updated
+    let params = change_nested_configuration("{ \"linter\": ")?;
+    server
+        .notify("workspace/didChangeWatchedFiles", params)
+        .await?;
+    assert_eq!(
+        next_published_diagnostics(&mut receiver).await?,
+        (url!("packages/a/file.js"), Vec::new())
+    );
+
+    // The lint rules run again once the configuration is fixed
+    let params = change_nested_configuration("{}")?;
+    server
+        .notify("workspace/didChangeWatchedFiles", params)
+        .await?;
+    assert_eq!(
+        next_published_diagnostics(&mut receiver).await?,
+        (
+            url!("packages/a/file.js"),
+            vec![String::from("lint/suspicious/noDebugger")]
+        )
+    );
+
+    tokio::select! {
+        msg = receiver.next() => panic!("unexpected notification {msg:?}"),
+        _ = sleep(Duration::from_millis(100)) => {}
+    };
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+/// Renames the symbol at `position` in the document `a.js`, and returns the
+/// files that are changed
+async fn renamed_files(
+    server: &mut Server,
+    position: Position,
+    new_name: &str,
+) -> Result<Vec<Url>> {
+    let edit: lsp::WorkspaceEdit = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: url!("a.js") },
+                    position,
+                },
+                new_name: new_name.to_string(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+    let mut urls: Vec<_> = edit.changes.unwrap_or_default().into_keys().collect();
+    urls.sort();
+    Ok(urls)
+}
+
+#[tokio::test]
+async fn rename_after_deleting_an_importer() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(url!("biome.json").to_file_path().unwrap(), "{}");
+    fs.insert(
+        url!("a.js").to_file_path().unwrap(),
+        "export const foo = 1;\n",
+    );
+    for path in [url!("b.js"), url!("c.js")] {
+        fs.insert(
+            path.to_file_path().unwrap(),
+            "import { foo } from \"./a.js\";\nfoo();\n",
+        );
+    }
+    // The server shares the file system, so that the test can delete the importers
+    let fs = Arc::new(fs);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs.clone())))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+    server.load_configuration().await?;
+
+    server
+        .open_named_document("export const foo = 1;\n", url!("a.js"), "javascript")
+        .await?;
+
+    let position = Position {
+        line: 0,
+        character: 13,
+    };
+    assert_eq!(
+        renamed_files(&mut server, position, "bar").await?,
+        vec![url!("a.js"), url!("b.js"), url!("c.js")]
+    );
+
+    // The deleted file is removed from the module graph
+    fs.remove_dir_all(&url!("c.js").to_file_path().unwrap())?;
+    server
+        .notify(
+            "workspace/didChangeWatchedFiles",
+            lsp::DidChangeWatchedFilesParams {
+                changes: vec![lsp::FileEvent {
+                    uri: url!("c.js"),
+                    typ: lsp::FileChangeType::DELETED,
+                }],
+            },
+        )
+        .await?;
+    assert_eq!(
+        renamed_files(&mut server, position, "bar").await?,
+        vec![url!("a.js"), url!("b.js")]
+    );
+
+    // The edits of a file deleted without a notification are skipped
+    fs.remove_dir_all(&url!("b.js").to_file_path().unwrap())?;
+    assert_eq!(
+        renamed_files(&mut server, position, "bar").await?,
+        vec![url!("a.js")]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
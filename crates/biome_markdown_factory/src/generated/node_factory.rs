@@ -7,10 +7,19 @@ use biome_markdown_syntax::{
     MarkdownSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
-pub fn md_bullet_list_item(md_bullet_list: MdBulletList) -> MdBulletListItem {
+pub fn md_bullet(bullet_token: SyntaxToken, content: MdBlockList) -> MdBullet {
+    MdBullet::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET,
+        [
+            Some(SyntaxElement::Token(bullet_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
+    ))
+}
+pub fn md_bullet_list_item(list: MdBulletList) -> MdBulletListItem {
     MdBulletListItem::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_BULLET_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+        [Some(SyntaxElement::Node(list.into_syntax()))],
     ))
 }
 pub fn md_document(value: MdBlockList, eof_token: SyntaxToken) -> MdDocumentBuilder {
@@ -41,11 +50,44 @@ impl MdDocumentBuilder {
         ))
     }
 }
-pub fn md_fenced_code_block(md_textual: MdTextual) -> MdFencedCodeBlock {
-    MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
-    ))
+pub fn md_fenced_code_block(
+    l_fence_token: SyntaxToken,
+    content: MdCodeLineList,
+) -> MdFencedCodeBlockBuilder {
+    MdFencedCodeBlockBuilder {
+        l_fence_token,
+        content,
+        info: None,
+        r_fence_token: None,
+    }
+}
+pub struct MdFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    content: MdCodeLineList,
+    info: Option<MdTextual>,
+    r_fence_token: Option<SyntaxToken>,
+}
+impl MdFencedCodeBlockBuilder {
+    pub fn with_info(mut self, info: MdTextual) -> Self {
+        self.info = Some(info);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
+        self
+    }
+    pub fn build(self) -> MdFencedCodeBlock {
+        MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
 }
 pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
     MdHardLine::unwrap_cast(SyntaxNode::new_detached(
@@ -63,17 +105,17 @@ pub fn md_header(before: MdHashList, after: MdHashList) -> MdHeaderBuilder {
     MdHeaderBuilder {
         before,
         after,
-        md_paragraph: None,
+        content: None,
     }
 }
 pub struct MdHeaderBuilder {
     before: MdHashList,
     after: MdHashList,
-    md_paragraph: Option<MdParagraph>,
+    content: Option<MdParagraph>,
 }
 impl MdHeaderBuilder {
-    pub fn with_md_paragraph(mut self, md_paragraph: MdParagraph) -> Self {
-        self.md_paragraph = Some(md_paragraph);
+    pub fn with_content(mut self, content: MdParagraph) -> Self {
+        self.content = Some(content);
         self
     }
     pub fn build(self) -> MdHeader {
@@ -81,162 +123,201 @@ impl MdHeaderBuilder {
             MarkdownSyntaxKind::MD_HEADER,
             [
                 Some(SyntaxElement::Node(self.before.into_syntax())),
-                self.md_paragraph
+                self.content
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
                 Some(SyntaxElement::Node(self.after.into_syntax())),
             ],
         ))
     }
 }
-pub fn md_html_block(md_textual: MdTextual) -> MdHtmlBlock {
+pub fn md_html_block(content: MdCodeLineList) -> MdHtmlBlock {
     MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_HTML_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_indent(value_token: SyntaxToken) -> MdIndent {
-    MdIndent::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT,
-        [Some(SyntaxElement::Token(value_token))],
-    ))
-}
-pub fn md_indent_code_block(md_textual: MdTextual) -> MdIndentCodeBlock {
+pub fn md_indent_code_block(content: MdCodeLineList) -> MdIndentCodeBlock {
     MdIndentCodeBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_inline_code(md_textual: MdTextual) -> MdInlineCode {
+pub fn md_inline_code(value_token: SyntaxToken) -> MdInlineCode {
     MdInlineCode::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INLINE_CODE,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_inline_emphasis(md_textual: MdTextual) -> MdInlineEmphasis {
+pub fn md_inline_emphasis(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineEmphasis {
     MdInlineEmphasis::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INLINE_EMPHASIS,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
     ))
 }
-pub fn md_inline_image(alt: MdTextual, src: MdTextual) -> MdInlineImageBuilder {
-    MdInlineImageBuilder {
-        alt,
-        src,
-        title: None,
-    }
-}
-pub struct MdInlineImageBuilder {
-    alt: MdTextual,
-    src: MdTextual,
-    title: Option<MdTextual>,
-}
-impl MdInlineImageBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
-        self
-    }
-    pub fn build(self) -> MdInlineImage {
-        MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_IMAGE,
-            [
-                Some(SyntaxElement::Node(self.alt.into_syntax())),
-                Some(SyntaxElement::Node(self.src.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
-}
-pub fn md_inline_link(label: MdTextual, url: MdTextual) -> MdInlineLinkBuilder {
-    MdInlineLinkBuilder {
-        label,
-        url,
-        title: None,
-    }
-}
-pub struct MdInlineLinkBuilder {
-    label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+pub fn md_inline_image(
+    excl_token: SyntaxToken,
+    l_brack_token: SyntaxToken,
+    alt: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    source: MdInlineItemList,
+    r_paren_token: SyntaxToken,
+) -> MdInlineImage {
+    MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_IMAGE,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(alt.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(source.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
 }
-impl MdInlineLinkBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
-        self
-    }
-    pub fn build(self) -> MdInlineLink {
-        MdInlineLink::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_LINK,
-            [
-                Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
+pub fn md_inline_italic(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineItalic {
+    MdInlineItalic::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_ITALIC,
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
+    ))
 }
-pub fn md_link_block(label: MdTextual, url: MdTextual) -> MdLinkBlockBuilder {
-    MdLinkBlockBuilder {
-        label,
-        url,
-        title: None,
-    }
+pub fn md_inline_link(
+    l_brack_token: SyntaxToken,
+    label: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    source: MdInlineItemList,
+    r_paren_token: SyntaxToken,
+) -> MdInlineLink {
+    MdInlineLink::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_LINK,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(label.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(source.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
 }
-pub struct MdLinkBlockBuilder {
-    label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+pub fn md_link_block(
+    l_brack_token: SyntaxToken,
+    label: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    url: MdInlineItemList,
+    title: MdInlineItemList,
+) -> MdLinkBlock {
+    MdLinkBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINK_BLOCK,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(label.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(url.into_syntax())),
+            Some(SyntaxElement::Node(title.into_syntax())),
+        ],
+    ))
 }
-impl MdLinkBlockBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
-        self
-    }
-    pub fn build(self) -> MdLinkBlock {
-        MdLinkBlock::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_LINK_BLOCK,
-            [
-                Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
+pub fn md_order_bullet(marker_token: SyntaxToken, content: MdBlockList) -> MdOrderBullet {
+    MdOrderBullet::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDER_BULLET,
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
+    ))
 }
-pub fn md_order_list_item(md_bullet_list: MdBulletList) -> MdOrderListItem {
+pub fn md_order_list_item(list: MdOrderList) -> MdOrderListItem {
     MdOrderListItem::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_ORDER_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+        [Some(SyntaxElement::Node(list.into_syntax()))],
     ))
 }
-pub fn md_paragraph(md_paragraph_item_list: MdParagraphItemList) -> MdParagraph {
+pub fn md_paragraph(list: MdInlineItemList) -> MdParagraph {
     MdParagraph::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_PARAGRAPH,
-        [Some(SyntaxElement::Node(
-            md_paragraph_item_list.into_syntax(),
-        ))],
+        [Some(SyntaxElement::Node(list.into_syntax()))],
     ))
 }
-pub fn md_quote(any_md_block: AnyMdBlock) -> MdQuote {
+pub fn md_quote(r_angle_token: SyntaxToken, content: MdBlockList) -> MdQuote {
     MdQuote::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_QUOTE,
-        [Some(SyntaxElement::Node(any_md_block.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(r_angle_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
     ))
 }
-pub fn md_setext_header(md_paragraph: MdParagraph) -> MdSetextHeader {
+pub fn md_setext_header(content: MdParagraph, underline_token: SyntaxToken) -> MdSetextHeader {
     MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_SETEXT_HEADER,
-        [Some(SyntaxElement::Node(md_paragraph.into_syntax()))],
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
     ))
 }
-pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
-    MdSoftBreak::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_SOFT_BREAK,
-        [Some(SyntaxElement::Token(value_token))],
+pub fn md_table(header: MdTableRow, delimiter: MdTableRow, rows: MdTableRowList) -> MdTable {
+    MdTable::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE,
+        [
+            Some(SyntaxElement::Node(header.into_syntax())),
+            Some(SyntaxElement::Node(delimiter.into_syntax())),
+            Some(SyntaxElement::Node(rows.into_syntax())),
+        ],
+    ))
+}
+pub fn md_table_cell(content: MdInlineItemList) -> MdTableCell {
+    MdTableCell::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
+pub fn md_table_row(cells: MdTableCellList) -> MdTableRowBuilder {
+    MdTableRowBuilder {
+        cells,
+        l_pipe_token: None,
+    }
+}
+pub struct MdTableRowBuilder {
+    cells: MdTableCellList,
+    l_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableRowBuilder {
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableRow {
+        MdTableRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_ROW,
+            [
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn md_textual(value_token: SyntaxToken) -> MdTextual {
     MdTextual::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_TEXTUAL,
@@ -263,7 +344,7 @@ where
 }
 pub fn md_bullet_list<I>(items: I) -> MdBulletList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = MdBullet>,
     I::IntoIter: ExactSizeIterator,
 {
     MdBulletList::unwrap_cast(SyntaxNode::new_detached(
@@ -273,6 +354,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn md_code_line_list<I>(items: I) -> MdCodeLineList
+where
+    I: IntoIterator<Item = MdTextual>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdCodeLineList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_CODE_LINE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn md_hash_list<I>(items: I) -> MdHashList
 where
     I: IntoIterator<Item = MdHash>,
@@ -285,9 +378,21 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
+where
+    I: IntoIterator<Item = AnyMdInline>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn md_order_list<I>(items: I) -> MdOrderList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = MdOrderBullet>,
     I::IntoIter: ExactSizeIterator,
 {
     MdOrderList::unwrap_cast(SyntaxNode::new_detached(
@@ -297,13 +402,34 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_paragraph_item_list<I>(items: I) -> MdParagraphItemList
+pub fn md_table_cell_list<I, S>(items: I, separators: S) -> MdTableCellList
 where
-    I: IntoIterator<Item = AnyMdInline>,
+    I: IntoIterator<Item = MdTableCell>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = MarkdownSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    MdTableCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn md_table_row_list<I>(items: I) -> MdTableRowList
+where
+    I: IntoIterator<Item = MdTableRow>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdParagraphItemList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_PARAGRAPH_ITEM_LIST,
+    MdTableRowList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_ROW_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
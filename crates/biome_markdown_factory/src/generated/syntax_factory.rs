@@ -15,6 +15,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
+            MD_BULLET => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [-] | T ! [*] | T ! [+]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_BULLET.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_BULLET, children)
+            }
             MD_BULLET_LIST_ITEM => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -69,8 +95,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_FENCE_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
@@ -78,6 +111,20 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdCodeLineList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_FENCE_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         MD_FENCED_CODE_BLOCK.to_bogus(),
@@ -159,7 +206,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdCodeLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -173,12 +220,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
+            MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_INDENT_CHUNK_LITERAL {
+                    if MdCodeLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -186,18 +233,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
+                        MD_INDENT_CODE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT, children)
+                slots.into_node(MD_INDENT_CODE_BLOCK, children)
             }
-            MD_INDENT_CODE_BLOCK => {
+            MD_INLINE_CODE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_INLINE_CODE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -205,18 +252,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT_CODE_BLOCK.to_bogus(),
+                        MD_INLINE_CODE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT_CODE_BLOCK, children)
+                slots.into_node(MD_INLINE_CODE, children)
             }
-            MD_INLINE_CODE => {
+            MD_INLINE_EMPHASIS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [**] | T![__]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [**] | T![__]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -224,18 +285,60 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_CODE.to_bogus(),
+                        MD_INLINE_EMPHASIS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_CODE, children)
+                slots.into_node(MD_INLINE_EMPHASIS, children)
             }
-            MD_INLINE_EMPHASIS => {
+            MD_INLINE_IMAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['['] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -243,32 +346,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_EMPHASIS.to_bogus(),
+                        MD_INLINE_IMAGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_EMPHASIS, children)
+                slots.into_node(MD_INLINE_IMAGE, children)
             }
-            MD_INLINE_IMAGE => {
+            MD_INLINE_ITALIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [*] | T![_]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [*] | T![_]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -276,32 +379,53 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE.to_bogus(),
+                        MD_INLINE_ITALIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE, children)
+                slots.into_node(MD_INLINE_ITALIC, children)
             }
             MD_INLINE_LINK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -317,24 +441,45 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_LINK_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [:] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -348,12 +493,38 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_LINK_BLOCK, children)
             }
+            MD_ORDER_BULLET => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_ORDERED_LIST_MARKER_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_ORDER_BULLET.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_ORDER_BULLET, children)
+            }
             MD_ORDER_LIST_ITEM => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdOrderList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -372,7 +543,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraphItemList::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -388,10 +559,17 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_QUOTE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyMdBlock::can_cast(element.kind()) {
+                    if element.kind() == T ! [>] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -404,7 +582,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_SETEXT_HEADER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if MdParagraph::can_cast(element.kind()) {
@@ -413,6 +591,13 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_SETEXT_UNDERLINE_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         MD_SETEXT_HEADER.to_bogus(),
@@ -421,12 +606,68 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TABLE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRowList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_TABLE, children)
+            }
+            MD_TABLE_CELL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_SOFT_BREAK_LITERAL {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_TABLE_CELL.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_TABLE_CELL, children)
+            }
+            MD_TABLE_ROW => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableCellList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -434,11 +675,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TABLE_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TABLE_ROW, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -479,12 +720,21 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.into_node(MD_THEMATIC_BREAK_BLOCK, children)
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
-            MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
+            MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, MdBullet::can_cast),
+            MD_CODE_LINE_LIST => Self::make_node_list_syntax(kind, children, MdTextual::can_cast),
             MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
-            MD_PARAGRAPH_ITEM_LIST => {
+            MD_INLINE_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
             }
+            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, MdOrderBullet::can_cast),
+            MD_TABLE_CELL_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                MdTableCell::can_cast,
+                T ! [|],
+                true,
+            ),
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
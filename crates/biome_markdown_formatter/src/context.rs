@@ -0,0 +1,174 @@
+use crate::MarkdownCommentStyle;
+use biome_formatter::{prelude::*, AttributePosition, BracketSpacing, IndentWidth};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownComments};
+use biome_markdown_syntax::{MarkdownFileSource, MarkdownLanguage};
+use biome_rowan::TextRange;
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+    /// The formatted code of the fenced code blocks, by range of the code blocks
+    embedded_code: FxHashMap<TextRange, String>,
+    /// The markers of the enclosing block quotes and list items, printed at the start of every line
+    line_prefix: String,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+            embedded_code: FxHashMap::default(),
+            line_prefix: String::new(),
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_embedded_code(
+        mut self,
+        embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+    ) -> Self {
+        self.embedded_code = embedded_code.into_iter().collect();
+        self
+    }
+
+    /// Returns the formatted code of the fenced code block at the given range, if any.
+    pub(crate) fn embedded_code(&self, range: TextRange) -> Option<&str> {
+        self.embedded_code.get(&range).map(String::as_str)
+    }
+
+    pub(crate) fn line_prefix(&self) -> &str {
+        &self.line_prefix
+    }
+
+    pub(crate) fn line_prefix_mut(&mut self) -> &mut String {
+        &mut self.line_prefix
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+/// The options of the Markdown formatter.
+///
+/// There's no indent style option, because the content of the block quotes and of the list
+/// items is always aligned with spaces.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    _file_source: MarkdownFileSource,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new(file_source: MarkdownFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            indent_width: IndentWidth::default(),
+            line_ending: LineEnding::default(),
+            line_width: LineWidth::default(),
+        }
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn attribute_position(&self) -> AttributePosition {
+        AttributePosition::default()
+    }
+
+    fn bracket_spacing(&self) -> BracketSpacing {
+        BracketSpacing::default()
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style())?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())
+    }
+}
@@ -0,0 +1,374 @@
+mod comments;
+pub mod context;
+mod cst;
+mod generated;
+mod markdown;
+mod prelude;
+mod utils;
+
+use crate::comments::MarkdownCommentStyle;
+pub(crate) use crate::context::MarkdownFormatContext;
+use crate::context::MarkdownFormatOptions;
+use crate::cst::FormatMarkdownSyntaxNode;
+use biome_formatter::comments::Comments;
+use biome_formatter::prelude::*;
+use biome_formatter::{
+    write, CstFormatContext, FormatContext, FormatLanguage, FormatOwnedWithRule, FormatRefWithRule,
+    FormatToken, TransformSourceMap,
+};
+use biome_formatter::{Formatted, Printed};
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MarkdownSyntaxToken};
+use biome_rowan::{AstNode, SyntaxNode, TextRange};
+use rustc_hash::FxHashMap;
+
+/// Used to get an object that knows how to format this object.
+pub(crate) trait AsFormat<Context> {
+    type Format<'a>: biome_formatter::Format<Context>
+    where
+        Self: 'a;
+
+    /// Returns an object that is able to format this object.
+    fn format(&self) -> Self::Format<'_>;
+}
+
+/// Implement [AsFormat] for references to types that implement [AsFormat].
+impl<T, C> AsFormat<C> for &T
+where
+    T: AsFormat<C>,
+{
+    type Format<'a> = T::Format<'a> where Self: 'a;
+
+    fn format(&self) -> Self::Format<'_> {
+        AsFormat::format(&**self)
+    }
+}
+
+/// Implement [AsFormat] for [SyntaxResult] where `T` implements [AsFormat].
+///
+/// Useful to format mandatory AST fields without having to unwrap the value first.
+impl<T, C> AsFormat<C> for biome_rowan::SyntaxResult<T>
+where
+    T: AsFormat<C>,
+{
+    type Format<'a> = biome_rowan::SyntaxResult<T::Format<'a>> where Self: 'a;
+
+    fn format(&self) -> Self::Format<'_> {
+        match self {
+            Ok(value) => Ok(value.format()),
+            Err(err) => Err(*err),
+        }
+    }
+}
+
+/// Implement [AsFormat] for [Option] when `T` implements [AsFormat]
+///
+/// Allows to call format on optional AST fields without having to unwrap the field first.
+impl<T, C> AsFormat<C> for Option<T>
+where
+    T: AsFormat<C>,
+{
+    type Format<'a> = Option<T::Format<'a>> where Self: 'a;
+
+    fn format(&self) -> Self::Format<'_> {
+        self.as_ref().map(|value| value.format())
+    }
+}
+
+/// Used to convert this object into an object that can be formatted.
+///
+/// The difference to [AsFormat] is that this trait takes ownership of `self`.
+pub(crate) trait IntoFormat<Context> {
+    type Format: biome_formatter::Format<Context>;
+
+    fn into_format(self) -> Self::Format;
+}
+
+impl<T, Context> IntoFormat<Context> for biome_rowan::SyntaxResult<T>
+where
+    T: IntoFormat<Context>,
+{
+    type Format = biome_rowan::SyntaxResult<T::Format>;
+
+    fn into_format(self) -> Self::Format {
+        self.map(IntoFormat::into_format)
+    }
+}
+
+/// Implement [IntoFormat] for [Option] when `T` implements [IntoFormat]
+///
+/// Allows to call format on optional AST fields without having to unwrap the field first.
+impl<T, Context> IntoFormat<Context> for Option<T>
+where
+    T: IntoFormat<Context>,
+{
+    type Format = Option<T::Format>;
+
+    fn into_format(self) -> Self::Format {
+        self.map(IntoFormat::into_format)
+    }
+}
+
+/// Formatting specific [Iterator] extensions
+pub(crate) trait FormattedIterExt {
+    /// Converts every item to an object that knows how to format it.
+    fn formatted<Context>(self) -> FormattedIter<Self, Self::Item, Context>
+    where
+        Self: Iterator + Sized,
+        Self::Item: IntoFormat<Context>,
+    {
+        FormattedIter {
+            inner: self,
+            options: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<I> FormattedIterExt for I where I: std::iter::Iterator {}
+
+pub(crate) struct FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item>,
+{
+    inner: Iter,
+    options: std::marker::PhantomData<Context>,
+}
+
+impl<Iter, Item, Context> std::iter::Iterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item>,
+    Item: IntoFormat<Context>,
+{
+    type Item = Item::Format;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.inner.next()?.into_format())
+    }
+}
+
+impl<Iter, Item, Context> std::iter::FusedIterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: std::iter::FusedIterator<Item = Item>,
+    Item: IntoFormat<Context>,
+{
+}
+
+impl<Iter, Item, Context> std::iter::ExactSizeIterator for FormattedIter<Iter, Item, Context>
+where
+    Iter: Iterator<Item = Item> + std::iter::ExactSizeIterator,
+    Item: IntoFormat<Context>,
+{
+}
+
+pub(crate) type MarkdownFormatter<'buf> = Formatter<'buf, MarkdownFormatContext>;
+
+/// Format a [MarkdownSyntaxNode]
+pub(crate) trait FormatNodeRule<N>
+where
+    N: AstNode<Language = MarkdownLanguage>,
+{
+    fn fmt(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if self.is_suppressed(node, f) {
+            return write!(f, [format_suppressed_node(node.syntax())]);
+        }
+
+        self.fmt_leading_comments(node, f)?;
+        self.fmt_fields(node, f)?;
+        self.fmt_dangling_comments(node, f)?;
+        self.fmt_trailing_comments(node, f)
+    }
+
+    fn fmt_fields(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()>;
+
+    /// Returns `true` if the node has a suppression comment and should use the same formatting as in the source document.
+    fn is_suppressed(&self, node: &N, f: &MarkdownFormatter) -> bool {
+        f.context().comments().is_suppressed(node.syntax())
+    }
+
+    /// Formats the [leading comments](biome_formatter::comments#leading-comments) of the node.
+    ///
+    /// You may want to override this method if you want to manually handle the formatting of comments
+    /// inside of the `fmt_fields` method or customize the formatting of the leading comments.
+    fn fmt_leading_comments(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_leading_comments(node.syntax()).fmt(f)
+    }
+
+    /// Formats the [dangling comments](biome_formatter::comments#dangling-comments) of the node.
+    ///
+    /// You should override this method if the node handled by this rule can have dangling comments because the
+    /// default implementation formats the dangling comments at the end of the node, which isn't ideal but ensures that
+    /// no comments are dropped.
+    ///
+    /// A node can have dangling comments if all its children are tokens or if all node childrens are optional.
+    fn fmt_dangling_comments(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_dangling_comments(node.syntax())
+            .with_soft_block_indent()
+            .fmt(f)
+    }
+
+    /// Formats the [trailing comments](biome_formatter::comments#trailing-comments) of the node.
+    ///
+    /// You may want to override this method if you want to manually handle the formatting of comments
+    /// inside of the `fmt_fields` method or customize the formatting of the trailing comments.
+    fn fmt_trailing_comments(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_trailing_comments(node.syntax()).fmt(f)
+    }
+}
+
+/// Rule for formatting an bogus nodes.
+pub(crate) trait FormatBogusNodeRule<N>
+where
+    N: AstNode<Language = MarkdownLanguage>,
+{
+    fn fmt(&self, node: &N, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_bogus_node(node.syntax()).fmt(f)
+    }
+}
+
+/// Format implementation specific to Markdown tokens.
+pub(crate) type FormatMarkdownSyntaxToken = FormatToken<MarkdownFormatContext>;
+
+impl AsFormat<MarkdownFormatContext> for MarkdownSyntaxToken {
+    type Format<'a> = FormatRefWithRule<'a, MarkdownSyntaxToken, FormatMarkdownSyntaxToken>;
+
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, FormatMarkdownSyntaxToken::default())
+    }
+}
+
+impl IntoFormat<MarkdownFormatContext> for MarkdownSyntaxToken {
+    type Format = FormatOwnedWithRule<MarkdownSyntaxToken, FormatMarkdownSyntaxToken>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatMarkdownSyntaxToken::default())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MarkdownFormatLanguage {
+    options: MarkdownFormatOptions,
+    /// The formatted code of the fenced code blocks, by range of the code blocks
+    embedded_code: FxHashMap<TextRange, String>,
+}
+
+impl MarkdownFormatLanguage {
+    pub fn new(options: MarkdownFormatOptions) -> Self {
+        Self {
+            options,
+            embedded_code: FxHashMap::default(),
+        }
+    }
+
+    /// Sets the formatted code of the fenced code blocks, by range of the code blocks.
+    ///
+    /// The content of the other code blocks is printed as is.
+    pub fn with_embedded_code(
+        mut self,
+        embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+    ) -> Self {
+        self.embedded_code = embedded_code.into_iter().collect();
+        self
+    }
+}
+
+impl FormatLanguage for MarkdownFormatLanguage {
+    type SyntaxLanguage = MarkdownLanguage;
+    type Context = MarkdownFormatContext;
+    type FormatRule = FormatMarkdownSyntaxNode;
+
+    fn is_range_formatting_node(&self, _node: &SyntaxNode<Self::SyntaxLanguage>) -> bool {
+        // TODO implement range formatting
+        true
+    }
+
+    fn options(&self) -> &<Self::Context as FormatContext>::Options {
+        &self.options
+    }
+
+    fn create_context(
+        self,
+        root: &MarkdownSyntaxNode,
+        source_map: Option<TransformSourceMap>,
+    ) -> Self::Context {
+        let comments = Comments::from_node(root, &MarkdownCommentStyle, source_map.as_ref());
+        MarkdownFormatContext::new(self.options, comments)
+            .with_source_map(source_map)
+            .with_embedded_code(self.embedded_code)
+    }
+}
+
+/// Formats a range within a file, supported by Biome
+///
+/// This runs a simple heuristic to determine the initial indentation
+/// level of the node based on the provided [MarkdownFormatOptions], which
+/// must match currently the current initial of the file. Additionally,
+/// because the reformatting happens only locally the resulting code
+/// will be indented with the same level as the original selection,
+/// even if it's a mismatch from the rest of the block the selection is in
+///
+/// It returns a [Printed] result with a range corresponding to the
+/// range of the input that was effectively overwritten by the formatter
+pub fn format_range(
+    options: MarkdownFormatOptions,
+    root: &MarkdownSyntaxNode,
+    range: TextRange,
+) -> FormatResult<Printed> {
+    biome_formatter::format_range(root, range, MarkdownFormatLanguage::new(options))
+}
+
+/// Formats a Markdown syntax tree.
+///
+/// It returns the [Formatted] document that can be printed to a string.
+pub fn format_node(
+    options: MarkdownFormatOptions,
+    root: &MarkdownSyntaxNode,
+) -> FormatResult<Formatted<MarkdownFormatContext>> {
+    biome_formatter::format_node(root, MarkdownFormatLanguage::new(options))
+}
+
+/// Formats a Markdown syntax tree, and prints the given formatted code for the fenced code blocks
+/// instead of their content.
+///
+/// The formatted code is keyed by the range of the fenced code blocks, see [MarkdownFormatLanguage::with_embedded_code].
+pub fn format_node_with_embedded_code(
+    options: MarkdownFormatOptions,
+    root: &MarkdownSyntaxNode,
+    embedded_code: impl IntoIterator<Item = (TextRange, String)>,
+) -> FormatResult<Formatted<MarkdownFormatContext>> {
+    biome_formatter::format_node(
+        root,
+        MarkdownFormatLanguage::new(options).with_embedded_code(embedded_code),
+    )
+}
+
+/// Formats a single node within a file, supported by Biome.
+///
+/// This runs a simple heuristic to determine the initial indentation
+/// level of the node based on the provided [MarkdownFormatOptions], which
+/// must match currently the current initial of the file. Additionally,
+/// because the reformatting happens only locally the resulting code
+/// will be indented with the same level as the original selection,
+/// even if it's a mismatch from the rest of the block the selection is in
+///
+/// Returns the [Printed] code.
+pub fn format_sub_tree(
+    options: MarkdownFormatOptions,
+    root: &MarkdownSyntaxNode,
+) -> FormatResult<Printed> {
+    biome_formatter::format_sub_tree(root, MarkdownFormatLanguage::new(options))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::MarkdownFormatOptions;
+    use crate::format_node;
+    use biome_markdown_parser::parse_markdown;
+
+    #[test]
+    fn smoke_test() {
+        let src = r#"*   foo"#;
+        let parse = parse_markdown(src);
+        let options = MarkdownFormatOptions::default();
+        let formatted = format_node(options, &parse.syntax()).unwrap();
+        assert_eq!(formatted.print().unwrap().as_code(), "- foo\n");
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use crate::utils::with_line_prefix;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdBullet, MdBulletFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBullet;
+impl FormatNodeRule<MdBullet> for FormatMdBullet {
+    fn fmt_fields(&self, node: &MdBullet, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletFields { bullet, content } = node.as_fields();
+
+        write!(f, [format_replaced(&bullet?, &text(bullet_marker(node)))])?;
+
+        if !content.is_empty() {
+            write!(f, [space(), with_line_prefix("  ", &content.format())])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the marker of the items of the list: `-`, or `*` when the list directly follows another
+/// list that uses `-`. Lists with the same marker would merge into a single list.
+pub(crate) fn bullet_marker(bullet: &MdBullet) -> &'static str {
+    let Some(list_item) = bullet.syntax().grand_parent() else {
+        return "-";
+    };
+
+    let preceding_lists = list_item
+        .siblings(biome_rowan::Direction::Prev)
+        .skip(1)
+        .take_while(|sibling| sibling.kind() == MarkdownSyntaxKind::MD_BULLET_LIST_ITEM)
+        .count();
+
+    if preceding_lists % 2 == 0 {
+        "-"
+    } else {
+        "*"
+    }
+}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+use crate::utils::{format_line_break, format_removed_node, write_code_lines};
+use biome_formatter::write;
+use biome_markdown_syntax::code_block_ext::token_column;
+use biome_markdown_syntax::{MdFencedCodeBlock, MdFencedCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence_token,
+            info,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+        let l_fence = l_fence_token?;
+
+        write!(f, [l_fence.format(), info.format()])?;
+
+        let embedded_code = f
+            .context()
+            .embedded_code(node.range())
+            .map(ToString::to_string);
+
+        if let Some(code) = embedded_code {
+            write!(f, [format_removed_node(content.syntax())])?;
+
+            // The empty lines are printed with the following line
+            let mut empty_lines = 0;
+            for line in code.lines() {
+                if line.trim().is_empty() {
+                    empty_lines += 1;
+                } else {
+                    write!(
+                        f,
+                        [
+                            format_line_break(empty_lines),
+                            dynamic_text(line, content.range().start())
+                        ]
+                    )?;
+                    empty_lines = 0;
+                }
+            }
+        } else {
+            // The lines keep their indentation beyond the indentation of the opening fence
+            let fence_column = token_column(&l_fence);
+            write_code_lines(f, content.code_lines(), false, |line| {
+                line.column.saturating_sub(fence_column)
+            })?;
+        }
+
+        // The closing fence is the same as the opening fence, and it's added when it's missing
+        let fence = l_fence.text_trimmed();
+        write!(f, [format_line_break(0)])?;
+        match r_fence_token {
+            Some(r_fence) => write!(
+                f,
+                [format_replaced(
+                    &r_fence,
+                    &dynamic_text(fence, r_fence.text_trimmed_range().start())
+                )]
+            ),
+            None => write!(
+                f,
+                [dynamic_text(fence, l_fence.text_trimmed_range().start())]
+            ),
+        }
+    }
+}
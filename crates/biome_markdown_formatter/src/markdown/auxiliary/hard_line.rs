@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHardLine, MdHardLineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHardLineFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The trailing spaces at the end of a block aren't a line break, while a trailing
+        // backslash is printed as is
+        let ends_block =
+            node.syntax().next_sibling().is_none() && value_token.text_trimmed().trim().is_empty();
+
+        if ends_block {
+            write!(f, [format_removed(&value_token)])
+        } else {
+            write!(f, [value_token.format()])
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use crate::utils::{container_content_column, write_code_lines};
+use biome_markdown_syntax::{MdHtmlBlock, MdHtmlBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHtmlBlock;
+impl FormatNodeRule<MdHtmlBlock> for FormatMdHtmlBlock {
+    fn fmt_fields(&self, node: &MdHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHtmlBlockFields { content } = node.as_fields();
+
+        // The lines are printed as is, the first one without its optional indentation
+        let content_column = container_content_column(node.syntax());
+        let mut lines = content.code_lines().peekable();
+        let first_line_start = lines.peek().map(|line| line.token.text_range().start());
+
+        write_code_lines(f, lines, true, |line| {
+            if Some(line.token.text_range().start()) == first_line_start {
+                0
+            } else {
+                line.column.saturating_sub(content_column)
+            }
+        })
+    }
+}
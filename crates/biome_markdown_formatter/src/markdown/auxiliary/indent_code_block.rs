@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use crate::utils::{container_content_column, write_code_lines};
+use biome_markdown_syntax::{MdIndentCodeBlock, MdIndentCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { content } = node.as_fields();
+
+        // The lines keep their indentation beyond the four spaces of the code block
+        let code_column = container_content_column(node.syntax()) + 4;
+        write_code_lines(f, content.code_lines(), true, |line| {
+            4 + line.column.saturating_sub(code_column)
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineCode, MdInlineCodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineCodeFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxToken, MdInlineItalic, MdInlineItalicFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItalic;
+impl FormatNodeRule<MdInlineItalic> for FormatMdInlineItalic {
+    fn fmt_fields(&self, node: &MdInlineItalic, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineItalicFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+        let l_fence = l_fence?;
+        let r_fence = r_fence?;
+
+        // Underscores can't emphasize a part of a word
+        let fence = if is_intraword(&l_fence, &r_fence) {
+            "*"
+        } else {
+            "_"
+        };
+
+        write!(
+            f,
+            [
+                format_replaced(&l_fence, &text(fence)),
+                content.format(),
+                format_replaced(&r_fence, &text(fence))
+            ]
+        )
+    }
+}
+
+/// Returns `true` if an alphanumeric character directly precedes or follows the emphasis.
+fn is_intraword(l_fence: &MarkdownSyntaxToken, r_fence: &MarkdownSyntaxToken) -> bool {
+    let before = l_fence
+        .prev_token()
+        .filter(|previous| {
+            l_fence.leading_trivia().is_empty() && previous.trailing_trivia().is_empty()
+        })
+        .and_then(|previous| previous.text_trimmed().chars().next_back());
+    let after = r_fence
+        .next_token()
+        .filter(|next| r_fence.trailing_trivia().is_empty() && next.leading_trivia().is_empty())
+        .and_then(|next| next.text_trimmed().chars().next());
+
+    before.is_some_and(char::is_alphanumeric) || after.is_some_and(char::is_alphanumeric)
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use crate::utils::format_inline_separator;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineLink, MdInlineLinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineLink;
+impl FormatNodeRule<MdInlineLink> for FormatMdInlineLink {
+    fn fmt_fields(&self, node: &MdInlineLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineLinkFields {
+            l_brack_token,
+            label,
+            r_brack_token,
+            l_paren_token,
+            source,
+            r_paren_token,
+        } = node.as_fields();
+        let r_brack_token = r_brack_token?;
+        let r_paren_token = r_paren_token?;
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                label.format(),
+                format_inline_separator(&r_brack_token),
+                r_brack_token.format(),
+                l_paren_token.format(),
+                source.format(),
+                format_inline_separator(&r_paren_token),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::format_inline_separator;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdLinkBlock, MdLinkBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkBlock;
+impl FormatNodeRule<MdLinkBlock> for FormatMdLinkBlock {
+    fn fmt_fields(&self, node: &MdLinkBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdLinkBlockFields {
+            l_brack_token,
+            label,
+            r_brack_token,
+            colon_token,
+            url,
+            title,
+        } = node.as_fields();
+        let r_brack_token = r_brack_token?;
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                label.format(),
+                format_inline_separator(&r_brack_token),
+                r_brack_token.format(),
+                colon_token.format(),
+                url.format(),
+                title.format()
+            ]
+        )
+    }
+}
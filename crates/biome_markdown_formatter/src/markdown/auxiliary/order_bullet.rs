@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use crate::utils::with_line_prefix;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderBullet, MdOrderBulletFields, MdOrderList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderBullet;
+impl FormatNodeRule<MdOrderBullet> for FormatMdOrderBullet {
+    fn fmt_fields(&self, node: &MdOrderBullet, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderBulletFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+
+        let marker_token = marker_token?;
+        let marker = ordered_marker(node, marker_token.text_trimmed());
+
+        write!(
+            f,
+            [format_replaced(
+                &marker_token,
+                &dynamic_text(&marker, marker_token.text_trimmed_range().start())
+            )]
+        )?;
+
+        if !content.is_empty() {
+            let prefix = " ".repeat(marker.len() + 1);
+            write!(f, [space(), with_line_prefix(&prefix, &content.format())])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the marker of the item, renumbered from the number of the first item of the list.
+///
+/// All the items keep the number of the first item when the second item has the same number,
+/// like `1.` for all the items.
+fn ordered_marker(bullet: &MdOrderBullet, marker: &str) -> String {
+    let (_, delimiter) = marker.split_at(marker.len() - 1);
+    let number_of = |bullet: MdOrderBullet| {
+        bullet
+            .marker_token()
+            .ok()
+            .and_then(|marker| {
+                let text = marker.text_trimmed();
+                text[..text.len() - 1].parse::<u64>().ok()
+            })
+            .unwrap_or(1)
+    };
+
+    let Some(list) = bullet.parent::<MdOrderList>() else {
+        return marker.to_string();
+    };
+
+    let mut bullets = list.iter();
+    let start = bullets.next().map_or(1, number_of);
+    let increment = u64::from(
+        bullets
+            .next()
+            .map_or(true, |second| number_of(second) != start),
+    );
+    let index = list
+        .iter()
+        .position(|item| item == *bullet)
+        .unwrap_or_default() as u64;
+
+    std::format!("{}{delimiter}", start + index * increment)
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderListItem, MdOrderListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderListItem;
+impl FormatNodeRule<MdOrderListItem> for FormatMdOrderListItem {
+    fn fmt_fields(&self, node: &MdOrderListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderListItemFields { list } = node.as_fields();
+        write!(f, [list.format()])
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use crate::utils::with_line_prefix;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdQuote, MdQuoteFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuote;
+impl FormatNodeRule<MdQuote> for FormatMdQuote {
+    fn fmt_fields(&self, node: &MdQuote, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteFields {
+            r_angle_token,
+            content,
+        } = node.as_fields();
+
+        write!(f, [r_angle_token.format()])?;
+
+        if !content.is_empty() {
+            write!(f, [space(), with_line_prefix("> ", &content.format())])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use crate::utils::format_line_break;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdSetextHeader, MdSetextHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+        let content = content?;
+        let underline_token = underline_token?;
+
+        let marker = if underline_token.text_trimmed().starts_with('=') {
+            "="
+        } else {
+            "-"
+        };
+
+        // The headings on a single line are printed as ATX headings, the other ones keep their
+        // line breaks
+        let is_single_line = content
+            .syntax()
+            .descendants_tokens(biome_rowan::Direction::Next)
+            .skip(1)
+            .all(|token| !token.has_leading_newline());
+
+        if is_single_line {
+            let hashes = if marker == "=" { "#" } else { "##" };
+            write!(
+                f,
+                [
+                    text(hashes),
+                    space(),
+                    content.format(),
+                    format_removed(&underline_token)
+                ]
+            )
+        } else {
+            let underline = marker.repeat(3);
+            write!(
+                f,
+                [
+                    content.format(),
+                    format_line_break(0),
+                    format_replaced(
+                        &underline_token,
+                        &dynamic_text(&underline, underline_token.text_trimmed_range().start())
+                    )
+                ]
+            )
+        }
+    }
+}
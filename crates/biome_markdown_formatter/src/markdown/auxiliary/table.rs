@@ -0,0 +1,156 @@
+use crate::markdown::auxiliary::table_row::FormatMdTableRowOptions;
+use crate::prelude::*;
+use crate::utils::{format_line_break, has_preceding_whitespace};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineItemList, MdTable, MdTableFields, MdTableRow};
+use biome_rowan::Direction;
+use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+        let header = header?;
+        let delimiter = delimiter?;
+
+        let layout = Rc::new(TableLayout::from_rows(
+            &delimiter,
+            std::iter::once(header.clone()).chain(rows.iter()),
+        ));
+        let options = |is_delimiter| FormatMdTableRowOptions {
+            layout: layout.clone(),
+            is_delimiter,
+        };
+
+        write!(
+            f,
+            [
+                header.format().with_options(options(false)),
+                format_line_break(0),
+                delimiter.format().with_options(options(true))
+            ]
+        )?;
+
+        for row in rows {
+            write!(
+                f,
+                [
+                    format_line_break(0),
+                    row.format().with_options(options(false))
+                ]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The alignment of a column, set by the colons of its delimiter cell.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum ColumnAlignment {
+    /// `---`
+    #[default]
+    None,
+    /// `:--`
+    Left,
+    /// `:-:`
+    Center,
+    /// `--:`
+    Right,
+}
+
+impl ColumnAlignment {
+    fn from_delimiter(text: &str) -> Self {
+        match (text.starts_with(':'), text.ends_with(':') && text.len() > 1) {
+            (true, true) => Self::Center,
+            (true, false) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+
+    /// Returns the text of a delimiter cell of the given width, which is at least 3.
+    pub(crate) fn delimiter(self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => std::format!(":{}", "-".repeat(width - 1)),
+            Self::Center => std::format!(":{}:", "-".repeat(width - 2)),
+            Self::Right => std::format!("{}:", "-".repeat(width - 1)),
+        }
+    }
+
+    /// Returns the number of spaces before and after a cell content to fill the given padding.
+    pub(crate) fn split_padding(self, padding: usize) -> (usize, usize) {
+        match self {
+            Self::None | Self::Left => (0, padding),
+            Self::Center => (padding / 2, padding - padding / 2),
+            Self::Right => (padding, 0),
+        }
+    }
+}
+
+/// The width and the alignment of the columns of a table.
+#[derive(Debug, Default)]
+pub(crate) struct TableLayout {
+    widths: Vec<usize>,
+    alignments: Vec<ColumnAlignment>,
+}
+
+impl TableLayout {
+    fn from_rows(delimiter: &MdTableRow, rows: impl Iterator<Item = MdTableRow>) -> Self {
+        let alignments: Vec<_> = delimiter
+            .cells()
+            .iter()
+            .map(|cell| {
+                cell.map_or(ColumnAlignment::None, |cell| {
+                    ColumnAlignment::from_delimiter(cell.syntax().text_trimmed().to_string().trim())
+                })
+            })
+            .collect();
+
+        // The delimiter cells are made of at least three characters
+        let mut widths = vec![3; alignments.len()];
+        for row in rows {
+            for (index, cell) in row.cells().iter().enumerate() {
+                let width = cell.map_or(0, |cell| inline_width(&cell.content()));
+                match widths.get_mut(index) {
+                    Some(column_width) => *column_width = (*column_width).max(width),
+                    None => widths.push(width.max(3)),
+                }
+            }
+        }
+
+        Self { widths, alignments }
+    }
+
+    pub(crate) fn columns(&self) -> usize {
+        self.widths.len()
+    }
+
+    pub(crate) fn width(&self, column: usize) -> usize {
+        self.widths.get(column).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn alignment(&self, column: usize) -> ColumnAlignment {
+        self.alignments.get(column).copied().unwrap_or_default()
+    }
+}
+
+/// Returns the width of the inline content once printed on a single line.
+pub(crate) fn inline_width(content: &MdInlineItemList) -> usize {
+    content
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .enumerate()
+        .map(|(index, token)| {
+            let separator = usize::from(index > 0 && has_preceding_whitespace(&token));
+            separator + token.text_trimmed().width()
+        })
+        .sum()
+}
@@ -0,0 +1,114 @@
+use crate::markdown::auxiliary::table::{inline_width, TableLayout};
+use crate::prelude::*;
+use crate::utils::format_removed_node;
+use biome_formatter::{write, FormatRuleWithOptions};
+use biome_markdown_syntax::{MdTableRow, MdTableRowFields};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow {
+    layout: Rc<TableLayout>,
+    is_delimiter: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct FormatMdTableRowOptions {
+    /// The layout of the columns of the table, the cells aren't padded without it
+    pub(crate) layout: Rc<TableLayout>,
+    /// Whether the row is the delimiter row, which is printed from the layout
+    pub(crate) is_delimiter: bool,
+}
+
+impl FormatRuleWithOptions<MdTableRow> for FormatMdTableRow {
+    type Options = FormatMdTableRowOptions;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.layout = options.layout;
+        self.is_delimiter = options.is_delimiter;
+        self
+    }
+}
+
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableRowFields {
+            l_pipe_token,
+            cells,
+        } = node.as_fields();
+
+        // The rows always start and end with a pipe
+        if let Some(l_pipe_token) = l_pipe_token {
+            write!(f, [format_removed(&l_pipe_token)])?;
+        }
+        write!(f, [text("|")])?;
+
+        let mut columns = 0;
+        for element in cells.elements() {
+            let cell = element.node()?;
+            let width = self.layout.width(columns);
+            let alignment = self.layout.alignment(columns);
+
+            if self.is_delimiter {
+                write!(f, [format_removed_node(cell.syntax())])?;
+
+                let delimiter = alignment.delimiter(width.max(3));
+                write!(
+                    f,
+                    [
+                        space(),
+                        dynamic_text(&delimiter, cell.syntax().text_trimmed_range().start())
+                    ]
+                )?;
+            } else {
+                let padding = width.saturating_sub(inline_width(&cell.content()));
+                let (before, after) = alignment.split_padding(padding);
+                write!(
+                    f,
+                    [
+                        space(),
+                        format_padding(before),
+                        cell.format(),
+                        format_padding(after)
+                    ]
+                )?;
+            }
+
+            if let Some(separator) = element.trailing_separator()? {
+                write!(f, [format_removed(separator)])?;
+            }
+            write!(f, [space(), text("|")])?;
+            columns += 1;
+        }
+
+        // The missing cells are empty
+        for column in columns..self.layout.columns() {
+            let width = self.layout.width(column);
+            let cell = if self.is_delimiter {
+                self.layout.alignment(column).delimiter(width)
+            } else {
+                " ".repeat(width)
+            };
+            write!(
+                f,
+                [
+                    space(),
+                    dynamic_text(&cell, node.syntax().text_trimmed_range().end()),
+                    space(),
+                    text("|")
+                ]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_padding(width: usize) -> impl Format<MarkdownFormatContext> {
+    format_with(move |f| {
+        if width > 0 {
+            write!(f, [dynamic_text(&" ".repeat(width), Default::default())])
+        } else {
+            Ok(())
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use crate::markdown::auxiliary::bullet::bullet_marker;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MdBullet, MdThematicBreakBlock, MdThematicBreakBlockFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+
+        // In a list item, `---` is a setext heading underline when it follows a paragraph, and
+        // `- ---` is a thematic break instead of a list item.
+        let item = node.syntax().grand_parent();
+        let in_list_item = item.as_ref().is_some_and(|item| {
+            matches!(
+                item.kind(),
+                MarkdownSyntaxKind::MD_BULLET | MarkdownSyntaxKind::MD_ORDER_BULLET
+            )
+        });
+        let follows_star_bullet = node.syntax().prev_sibling().is_none()
+            && item
+                .and_then(MdBullet::cast)
+                .is_some_and(|bullet| bullet_marker(&bullet) == "*");
+
+        let marker = if in_list_item && !follows_star_bullet {
+            "***"
+        } else {
+            "---"
+        };
+        write!(f, [format_replaced(&value_token?, &text(marker))])
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::{format_line_break, is_tight_list_item_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdBlockList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The blocks are separated by an empty line, except in the items of tight lists
+        let empty_lines = if is_tight_list_item_content(node) {
+            0
+        } else {
+            1
+        };
+
+        for (index, block) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            write!(f, [block.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdCodeLineList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdCodeLineList;
+impl FormatRule<MdCodeLineList> for FormatMdCodeLineList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdCodeLineList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::format_inline_separator;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdInlineItemList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItemList;
+impl FormatRule<MdInlineItemList> for FormatMdInlineItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdInlineItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The first item of a paragraph or of a table cell is separated from the preceding
+        // token by its block
+        let starts_block = node.syntax().parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                MarkdownSyntaxKind::MD_PARAGRAPH | MarkdownSyntaxKind::MD_TABLE_CELL
+            )
+        });
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 || !starts_block {
+                if let Some(token) = item.syntax().first_token() {
+                    write!(f, [format_inline_separator(&token)])?;
+                }
+            }
+            write!(f, [item.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_list;
+pub(crate) mod bullet_list;
+pub(crate) mod code_line_list;
+pub(crate) mod hash_list;
+pub(crate) mod inline_item_list;
+pub(crate) mod order_list;
+pub(crate) mod table_cell_list;
+pub(crate) mod table_row_list;
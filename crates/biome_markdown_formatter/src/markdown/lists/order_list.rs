@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::{format_line_break, is_tight_list};
+use biome_formatter::write;
+use biome_markdown_syntax::MdOrderList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderList;
+impl FormatRule<MdOrderList> for FormatMdOrderList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdOrderList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_tight = is_tight_list(
+            node.iter()
+                .map(|bullet| (bullet.syntax().clone(), bullet.content())),
+        );
+
+        for (index, bullet) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [format_line_break(usize::from(!is_tight))])?;
+            }
+            write!(f, [bullet.format()])?;
+        }
+
+        Ok(())
+    }
+}
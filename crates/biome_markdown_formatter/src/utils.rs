@@ -0,0 +1,258 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::code_block_ext::{token_column, CodeLine};
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdBlockList, MdBullet,
+    MdOrderBullet, MdQuote,
+};
+use biome_rowan::{Direction, TextSize, TriviaPieceKind};
+
+/// Formats a line break followed by the markers of the enclosing block quotes and list items.
+///
+/// The empty lines only keep the markers of the block quotes, without their trailing whitespace.
+pub(crate) struct FormatLineBreak {
+    empty_lines: usize,
+}
+
+pub(crate) const fn format_line_break(empty_lines: usize) -> FormatLineBreak {
+    FormatLineBreak { empty_lines }
+}
+
+impl Format<MarkdownFormatContext> for FormatLineBreak {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let prefix = f.context().line_prefix();
+
+        // Consecutive hard line breaks collapse, the line feeds of the empty lines are printed
+        // as text instead.
+        let mut text = String::new();
+        for _ in 0..self.empty_lines {
+            text.push_str(prefix.trim_end());
+            text.push('\n');
+        }
+        text.push_str(prefix);
+
+        write!(f, [hard_line_break()])?;
+        if !text.is_empty() {
+            write!(f, [dynamic_text(&text, TextSize::default())])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats `content` with `prefix` added to the markers printed at the start of its lines.
+///
+/// The first line of the content isn't prefixed, because it follows the marker of its container.
+pub(crate) struct FormatWithLinePrefix<'a> {
+    prefix: &'a str,
+    content: &'a dyn Format<MarkdownFormatContext>,
+}
+
+pub(crate) fn with_line_prefix<'a>(
+    prefix: &'a str,
+    content: &'a impl Format<MarkdownFormatContext>,
+) -> FormatWithLinePrefix<'a> {
+    FormatWithLinePrefix { prefix, content }
+}
+
+impl Format<MarkdownFormatContext> for FormatWithLinePrefix<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let length = f.context().line_prefix().len();
+        f.context_mut().line_prefix_mut().push_str(self.prefix);
+
+        let result = self.content.fmt(f);
+
+        f.context_mut().line_prefix_mut().truncate(length);
+        result
+    }
+}
+
+/// Removes all the tokens of the node, which is replaced by text or not printed at all.
+pub(crate) struct FormatRemovedNode<'a> {
+    node: &'a MarkdownSyntaxNode,
+}
+
+pub(crate) const fn format_removed_node(node: &MarkdownSyntaxNode) -> FormatRemovedNode<'_> {
+    FormatRemovedNode { node }
+}
+
+impl Format<MarkdownFormatContext> for FormatRemovedNode<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        for node in self.node.descendants() {
+            f.comments().mark_suppression_checked(&node);
+        }
+        for token in self.node.descendants_tokens(Direction::Next) {
+            write!(f, [format_removed(&token)])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the separator of an inline token from the preceding one: a line break if the token
+/// starts a new line, a space if whitespace separates them, and nothing otherwise.
+pub(crate) struct FormatInlineSeparator<'a> {
+    token: &'a MarkdownSyntaxToken,
+}
+
+pub(crate) const fn format_inline_separator(
+    token: &MarkdownSyntaxToken,
+) -> FormatInlineSeparator<'_> {
+    FormatInlineSeparator { token }
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineSeparator<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if self.token.has_leading_newline() {
+            write!(f, [format_line_break(0)])
+        } else if has_preceding_whitespace(self.token) {
+            write!(f, [space()])
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns `true` if whitespace separates the token from the preceding token on the same line.
+pub(crate) fn has_preceding_whitespace(token: &MarkdownSyntaxToken) -> bool {
+    !token.leading_trivia().is_empty()
+        || token
+            .prev_token()
+            .is_some_and(|previous| !previous.trailing_trivia().is_empty())
+}
+
+/// Returns the number of line feeds in the leading trivia of the token, including the ones of
+/// the lines made of block quote markers only.
+pub(crate) fn leading_newlines(token: &MarkdownSyntaxToken) -> usize {
+    token
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| piece.kind() == TriviaPieceKind::Newline)
+        .count()
+}
+
+/// Returns `true` if the node is separated from the preceding block by an empty line.
+pub(crate) fn has_preceding_empty_line(node: &MarkdownSyntaxNode) -> bool {
+    node.first_token()
+        .is_some_and(|token| leading_newlines(&token) > 1)
+}
+
+/// Returns `true` if the items of the list aren't separated by empty lines, nor are the blocks
+/// of the items. The blocks of the items of tight lists are printed without empty lines between them.
+///
+/// See https://spec.commonmark.org/0.31.2/#loose
+pub(crate) fn is_tight_list(
+    items: impl Iterator<Item = (MarkdownSyntaxNode, MdBlockList)>,
+) -> bool {
+    items.enumerate().all(|(index, (item, content))| {
+        (index == 0 || !has_preceding_empty_line(&item))
+            && content
+                .iter()
+                .skip(1)
+                .all(|block| !has_preceding_empty_line(block.syntax()))
+    })
+}
+
+/// Returns `true` if the blocks are the content of an item of a tight list.
+pub(crate) fn is_tight_list_item_content(blocks: &MdBlockList) -> bool {
+    let Some(item) = blocks.syntax().parent() else {
+        return false;
+    };
+    let Some(list) = item.parent() else {
+        return false;
+    };
+
+    match item.kind() {
+        MarkdownSyntaxKind::MD_BULLET => is_tight_list(
+            list.children()
+                .filter_map(MdBullet::cast)
+                .map(|bullet| (bullet.syntax().clone(), bullet.content())),
+        ),
+        MarkdownSyntaxKind::MD_ORDER_BULLET => is_tight_list(
+            list.children()
+                .filter_map(MdOrderBullet::cast)
+                .map(|bullet| (bullet.syntax().clone(), bullet.content())),
+        ),
+        _ => false,
+    }
+}
+
+/// Returns the column where the content of the innermost block quote or list item that contains
+/// the node starts in the source text.
+pub(crate) fn container_content_column(node: &MarkdownSyntaxNode) -> usize {
+    for ancestor in node.ancestors().skip(1) {
+        if let Some(quote) = MdQuote::cast_ref(&ancestor) {
+            let Ok(marker) = quote.r_angle_token() else {
+                return 0;
+            };
+
+            // Only one space of the whitespace that follows the marker belongs to it
+            let followed_by_space = !marker.trailing_trivia().is_empty();
+            return token_column(&marker) + 1 + usize::from(followed_by_space);
+        }
+
+        let item = if let Some(bullet) = MdBullet::cast_ref(&ancestor) {
+            bullet
+                .bullet()
+                .ok()
+                .map(|marker| (marker, bullet.content()))
+        } else if let Some(bullet) = MdOrderBullet::cast_ref(&ancestor) {
+            bullet
+                .marker_token()
+                .ok()
+                .map(|marker| (marker, bullet.content()))
+        } else {
+            continue;
+        };
+
+        let Some((marker, content)) = item else {
+            return 0;
+        };
+
+        let marker_end = token_column(&marker) + marker.text_trimmed().chars().count();
+        let spaces = marker.trailing_trivia().text().chars().count();
+        let starts_on_next_line = content
+            .syntax()
+            .first_token()
+            .map_or(true, |token| token.has_leading_newline());
+
+        // The content of an item that starts with an indented code block, or on the next line,
+        // is aligned one column after the marker
+        return if starts_on_next_line || spaces == 0 || spaces > 4 {
+            marker_end + 1
+        } else {
+            marker_end + spaces
+        };
+    }
+
+    0
+}
+
+/// Writes the lines of a code block or of a HTML block, indented by the number of spaces that
+/// `indentation` returns for each line.
+///
+/// The first line is printed on the current line if the block starts with it, otherwise the
+/// lines follow the opening fence of the block.
+pub(crate) fn write_code_lines(
+    f: &mut MarkdownFormatter,
+    lines: impl Iterator<Item = CodeLine>,
+    starts_block: bool,
+    indentation: impl Fn(&CodeLine) -> usize,
+) -> FormatResult<()> {
+    for (index, line) in lines.enumerate() {
+        if index > 0 || !starts_block {
+            write!(f, [format_line_break(line.blank_lines_before)])?;
+        }
+
+        let indentation = " ".repeat(indentation(&line));
+        if !indentation.is_empty() {
+            write!(
+                f,
+                [dynamic_text(&indentation, line.token.text_range().start())]
+            )?;
+        }
+        write!(f, [line.node.format()])?;
+    }
+
+    Ok(())
+}
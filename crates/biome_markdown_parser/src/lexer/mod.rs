@@ -1,21 +1,26 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lookup table based, Markdown lexer which yields SyntaxKind tokens used by the biome-markdown parser.
 
 #[rustfmt::skip]
 mod tests;
 
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::T;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
 };
 use biome_rowan::{SyntaxKind, TextSize};
-use biome_unicode_table::{lookup_byte, Dispatch::*};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
+    /// Lexes the start of a line, where the markers of the blocks are recognized.
     #[default]
     Regular,
+    /// Lexes the content of paragraphs, headings and table cells.
+    Inline,
+    /// Lexes the rest of the line as a single token, used for the lines of code blocks and HTML blocks.
+    CodeLine,
 }
 
 impl LexContext for MarkdownLexContext {
@@ -28,9 +33,9 @@ impl LexContext for MarkdownLexContext {
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[allow(dead_code)]
     Regular,
-    // UnicodeRange,
+    Inline,
+    CodeLine,
 }
 
 /// An extremely fast, lookup table based, lossless Markdown lexer
@@ -90,12 +95,16 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = self.text_position();
         self.current_flags = TokenFlags::empty();
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current),
+            Some(current) => match context {
+                MarkdownLexContext::Regular => self.consume_token(current),
+                MarkdownLexContext::Inline => self.consume_inline_token(current),
+                MarkdownLexContext::CodeLine => self.consume_code_line(current),
+            },
             None => EOF,
         };
 
@@ -176,21 +185,62 @@ impl<'src> MarkdownLexer<'src> {
         }
     }
 
+    /// Lexes a token at the start of a line, where the markers of the blocks are recognized.
+    ///
+    /// Falls back to [Self::consume_inline_token] when the line doesn't start with a marker.
     pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
-        let dispatched = lookup_byte(current);
-        match dispatched {
-            WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespace(),
+            b'#' => self.eat_byte(T![#]),
+            b'>' => self.eat_byte(T![>]),
+            b'-' | b'*' | b'_' | b'+' | b'=' => self.consume_line_marker(current),
+            b'`' | b'~' => self.consume_fence(current),
+            b'0'..=b'9' => self.consume_ordered_list_marker(),
+            _ => self.consume_inline_token(current),
+        }
+    }
+
+    /// Lexes a token of the inline content of a block.
+    ///
+    /// The whitespace between the words is trivia, except the trailing spaces of a hard line break.
+    pub(crate) fn consume_inline_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b' ' | b'\t' => self.consume_inline_whitespace(),
+            b'*' | b'_' => self.consume_delimiter_run(current),
+            b'`' => self.consume_code_span(),
+            b'<' => self.consume_inline_html(),
+            b'[' => self.eat_byte(T!['[']),
+            b']' => self.eat_byte(T![']']),
+            b'(' => self.eat_byte(T!['(']),
+            b')' => self.eat_byte(T![')']),
+            b'|' => self.eat_byte(T![|]),
+            b'#' => self.eat_byte(T![#]),
+            b'!' if self.peek_byte() == Some(b'[') => self.eat_byte(T![!]),
+            b'\\' if matches!(self.peek_byte(), Some(b'\n' | b'\r')) => {
+                self.eat_byte(MD_HARD_LINE_LITERAL)
+            }
             _ => self.consume_textual(),
         }
     }
 
+    /// Lexes the rest of the line as a single token, the leading and trailing whitespace are trivia.
+    pub(crate) fn consume_code_line(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespace(),
+            _ => {
+                let length = self.rest_of_line().trim_end().len();
+                self.advance(length);
+                MD_TEXTUAL_LITERAL
+            }
+        }
+    }
+
     fn text_position(&self) -> TextSize {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[allow(dead_code)]
     fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
@@ -269,31 +319,191 @@ impl<'src> MarkdownLexer<'src> {
         TAB
     }
 
-    fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
+    /// Consumes the whitespace inside a line.
+    ///
+    /// Two or more spaces at the end of a line are a hard line break.
+    fn consume_inline_whitespace(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
-        let start_char = match self.current_byte() {
-            Some(b'-') => b'-',
-            Some(b'*') => b'*',
-            Some(b'_') => b'_',
-            _ => return self.consume_textual(),
-        };
+        let at_line_start = self.position == 0
+            || matches!(self.source.as_bytes()[self.position - 1], b'\n' | b'\r');
+        let rest = self.rest_of_line();
+        if !at_line_start && rest.len() >= 2 && rest.bytes().all(|byte| byte == b' ') {
+            let next = self.position + rest.len();
+            if next < self.source.len() {
+                self.advance(rest.len());
+                return MD_HARD_LINE_LITERAL;
+            }
+        }
 
+        match self.current_byte() {
+            Some(b'\t') => self.consume_tab(),
+            _ => self.consume_whitespace(),
+        }
+    }
+
+    /// Consumes a thematic break, a setext heading underline or a bullet list marker.
+    fn consume_line_marker(&mut self, marker: u8) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        if let Some(length) = self.thematic_break_length(marker) {
+            self.advance(length);
+            return MD_THEMATIC_BREAK_LITERAL;
+        }
+
+        if matches!(marker, b'-' | b'=') {
+            let rest = self.rest_of_line();
+            let length = rest.bytes().take_while(|byte| *byte == marker).count();
+            if rest[length..].trim().is_empty() {
+                self.advance(length);
+                return MD_SETEXT_UNDERLINE_LITERAL;
+            }
+        }
+
+        let at_bullet = matches!(marker, b'-' | b'*' | b'+')
+            && matches!(self.peek_byte(), Some(b' ' | b'\t' | b'\n' | b'\r') | None);
+        match marker {
+            b'-' if at_bullet => self.eat_byte(T![-]),
+            b'*' if at_bullet => self.eat_byte(T![*]),
+            b'+' if at_bullet => self.eat_byte(T![+]),
+            _ => self.consume_inline_token(marker),
+        }
+    }
+
+    /// Returns the length of the thematic break that starts at the current position, if any.
+    ///
+    /// A thematic break is made of three or more matching `-`, `_` or `*` characters,
+    /// optionally separated by whitespace.
+    fn thematic_break_length(&self, marker: u8) -> Option<usize> {
+        if !matches!(marker, b'-' | b'*' | b'_') {
+            return None;
+        }
+
+        let rest = self.rest_of_line();
         let mut count = 0;
-        loop {
-            self.consume_whitespace();
-            if matches!(self.current_byte(), Some(ch) if ch == start_char) {
-                self.advance(1);
-                count += 1;
-            } else {
-                break;
+        let mut length = 0;
+        for (index, byte) in rest.bytes().enumerate() {
+            match byte {
+                b' ' | b'\t' => {}
+                _ if byte == marker => {
+                    count += 1;
+                    length = index + 1;
+                }
+                _ => return None,
             }
         }
-        // until next newline or eof
-        if matches!(self.current_byte(), Some(b'\n' | b'\r') | None) && count >= 3 {
-            return MD_THEMATIC_BREAK_LITERAL;
+
+        (count >= 3).then_some(length)
+    }
+
+    /// Consumes the opening or the closing fence of a fenced code block.
+    fn consume_fence(&mut self, marker: u8) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let rest = self.rest_of_line();
+        let length = rest.bytes().take_while(|byte| *byte == marker).count();
+        // The info string of a backtick fence can't contain backticks
+        if length < 3 || (marker == b'`' && rest[length..].contains('`')) {
+            return self.consume_inline_token(marker);
+        }
+
+        self.advance(length);
+        MD_FENCE_LITERAL
+    }
+
+    /// Consumes the marker of an ordered list item, made of one to nine digits followed by `.` or `)`.
+    fn consume_ordered_list_marker(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let rest = self.rest_of_line().as_bytes();
+        let digits = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if (1..=9).contains(&digits)
+            && matches!(rest.get(digits), Some(b'.' | b')'))
+            && matches!(rest.get(digits + 1), Some(b' ' | b'\t') | None)
+        {
+            self.advance(digits + 1);
+            return MD_ORDERED_LIST_MARKER_LITERAL;
+        }
+
+        self.consume_textual()
+    }
+
+    /// Consumes a run of `*` or `_` delimiters, one or two characters at a time.
+    ///
+    /// An odd run of three or more characters starts with the single delimiter when it opens,
+    /// and with the double delimiter when it closes, so that `***foo***` is both emphasized and strong.
+    fn consume_delimiter_run(&mut self, marker: u8) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let length = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| **byte == marker)
+            .count();
+        let opening =
+            self.position == 0 || self.source.as_bytes()[self.position - 1].is_ascii_whitespace();
+        let single = length == 1 || (length % 2 == 1 && opening);
+
+        match (marker, single) {
+            (b'*', true) => self.eat_byte(T![*]),
+            (b'_', true) => self.eat_byte(T![_]),
+            (b'*', false) => {
+                self.advance(2);
+                T![**]
+            }
+            _ => {
+                self.advance(2);
+                T![__]
+            }
         }
-        ERROR_TOKEN
+    }
+
+    /// Consumes a code span, or the run of backticks when it isn't closed on the same line.
+    fn consume_code_span(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let rest = self.rest_of_line();
+        let length = rest.bytes().take_while(|byte| *byte == b'`').count();
+        let mut index = length;
+        while index < rest.len() {
+            let run = rest.as_bytes()[index..]
+                .iter()
+                .take_while(|byte| **byte == b'`')
+                .count();
+            if run == length {
+                self.advance(index + run);
+                return MD_INLINE_CODE_LITERAL;
+            }
+            index += run.max(1);
+        }
+
+        self.advance(length);
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Consumes an autolink or a HTML tag as a single textual token, so that its content isn't
+    /// interpreted as Markdown.
+    fn consume_inline_html(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        let rest = self.rest_of_line();
+        let is_tag = rest[1..]
+            .chars()
+            .next()
+            .is_some_and(|char| char.is_ascii_alphabetic() || matches!(char, '/' | '!' | '?'));
+        match rest.find('>') {
+            Some(end) if is_tag => {
+                self.advance(end + 1);
+                MD_TEXTUAL_LITERAL
+            }
+            _ => self.consume_textual(),
+        }
+    }
+
+    /// Returns the text from the current position to the end of the line.
+    fn rest_of_line(&self) -> &'src str {
+        let rest = &self.source[self.position..];
+        let end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+        &rest[..end]
     }
 
     /// Get the UTF8 char which starts at the current byte
@@ -352,43 +562,49 @@ impl<'src> MarkdownLexer<'src> {
         self.position >= self.source.len()
     }
 
-    #[inline]
+    /// Consumes a word, until the next whitespace or the next character that can start an inline element.
+    ///
+    /// The first character is always part of the word, and so are the backslash escapes.
     fn consume_textual(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
-        let char = self.current_char_unchecked();
-        self.advance(char.len_utf8());
+        let mut first = true;
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\\' => match self.peek_byte() {
+                    Some(next) if next.is_ascii_punctuation() => self.advance(2),
+                    Some(b'\n' | b'\r') if !first => break,
+                    _ => self.advance(1),
+                },
+                _ if first => self.advance_char_unchecked(),
+                b' ' | b'\t' | b'\n' | b'\r' | b'*' | b'_' | b'`' | b'<' | b'[' | b']' | b'('
+                | b')' | b'|' => break,
+                b'!' if self.peek_byte() == Some(b'[') => break,
+                _ => self.advance_char_unchecked(),
+            }
+            first = false;
+        }
 
         MD_TEXTUAL_LITERAL
     }
-
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
-    fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
 }
 
 impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
-        let old_position = self.position;
         self.position = u32::from(self.current_start) as usize;
 
         let re_lexed_kind = match self.current_byte() {
             Some(current) => match context {
                 MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                MarkdownReLexContext::Inline => self.consume_inline_token(current),
+                MarkdownReLexContext::CodeLine => self.consume_code_line(current),
             },
             None => EOF,
         };
 
-        if self.current() == re_lexed_kind {
-            // Didn't re-lex anything. Return existing token again
-            self.position = old_position;
-        } else {
-            self.current_kind = re_lexed_kind;
-        }
+        // Unlike the other lexers, the position is kept even when the kind didn't change
+        // because a textual token can end at a different offset in another context.
+        self.current_kind = re_lexed_kind;
 
         re_lexed_kind
     }
@@ -109,8 +109,16 @@ fn empty() {
 #[test]
 fn textual() {
     assert_lex! {
-        "+",
-       MD_TEXTUAL_LITERAL:1,
+        "+foo",
+       MD_TEXTUAL_LITERAL:4,
+    }
+}
+
+#[test]
+fn escaped_textual() {
+    assert_lex! {
+        r#"\*foo\|bar"#,
+        MD_TEXTUAL_LITERAL:10,
     }
 }
 
@@ -159,6 +167,82 @@ _ _ _ _  _ "#,
         NEWLINE:1,
         MD_THEMATIC_BREAK_LITERAL:7,
         NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:11,
+        MD_THEMATIC_BREAK_LITERAL:10,
+        WHITESPACE:1,
+    }
+}
+
+#[test]
+fn list_markers() {
+    assert_lex! {
+        "- a\n* b\n+ c\n1. d\n10) e",
+        MINUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        STAR:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        PLUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_ORDERED_LIST_MARKER_LITERAL:2,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_ORDERED_LIST_MARKER_LITERAL:3,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn setext_underline() {
+    assert_lex! {
+        "===\n-- ",
+        MD_SETEXT_UNDERLINE_LITERAL:3,
+        NEWLINE:1,
+        MD_SETEXT_UNDERLINE_LITERAL:2,
+        WHITESPACE:1,
+    }
+}
+
+#[test]
+fn fence() {
+    assert_lex! {
+        "```js\n~~~~",
+        MD_FENCE_LITERAL:3,
+        MD_TEXTUAL_LITERAL:2,
+        NEWLINE:1,
+        MD_FENCE_LITERAL:4,
+    }
+}
+
+#[test]
+fn inline() {
+    assert_lex! {
+        "# **a** _b_ `c` [d](e)\\\n",
+        HASH:1,
+        WHITESPACE:1,
+        DOUBLE_STAR:2,
+        MD_TEXTUAL_LITERAL:1,
+        DOUBLE_STAR:2,
+        WHITESPACE:1,
+        UNDERSCORE:1,
+        MD_TEXTUAL_LITERAL:1,
+        UNDERSCORE:1,
+        WHITESPACE:1,
+        MD_INLINE_CODE_LITERAL:3,
+        WHITESPACE:1,
+        L_BRACK:1,
+        MD_TEXTUAL_LITERAL:1,
+        R_BRACK:1,
+        L_PAREN:1,
+        MD_TEXTUAL_LITERAL:1,
+        R_PAREN:1,
+        MD_HARD_LINE_LITERAL:1,
+        NEWLINE:1,
     }
 }
@@ -1,6 +1,6 @@
 use biome_markdown_factory::MarkdownSyntaxFactory;
 use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument};
-use biome_parser::{prelude::ParseDiagnostic, tree_sink::LosslessTreeSink};
+use biome_parser::{prelude::ParseDiagnostic, tree_sink::LosslessTreeSink, AnyParse};
 use biome_rowan::{AstNode, NodeCache};
 use parser::MarkdownParser;
 use syntax::parse_document;
@@ -75,3 +75,15 @@ impl MarkdownParse {
         MdDocument::unwrap_cast(self.syntax())
     }
 }
+
+impl From<MarkdownParse> for AnyParse {
+    fn from(parse: MarkdownParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+        Self::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        )
+    }
+}
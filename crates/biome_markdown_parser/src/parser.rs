@@ -1,15 +1,48 @@
-use biome_markdown_syntax::MarkdownSyntaxKind;
+use biome_markdown_syntax::{MarkdownSyntaxKind, T};
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
 use biome_parser::ParserContext;
 use biome_parser::{diagnostic::merge_diagnostics, ParserContextCheckpoint};
+use biome_rowan::TextSize;
 
+use crate::lexer::MarkdownReLexContext;
 use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
 
 pub(crate) struct MarkdownParser<'source> {
     context: ParserContext<MarkdownSyntaxKind>,
     source: MarkdownTokenSource<'source>,
+    state: MarkdownParserState,
+}
+
+/// A container block whose content spans multiple lines.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum MarkdownContainer {
+    /// A block quote, each line of its content is prefixed with `>`
+    Quote,
+    /// A list item, each line of its content is indented up to the column of its first block
+    ListItem { indent: usize },
+}
+
+/// The containers that continue on the current line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct LinePrefix {
+    /// The start of the first token after the markers of the containers
+    position: TextSize,
+    /// The number of containers, from the outermost one, that continue on this line
+    pub matched: usize,
+    /// Whether a blank line precedes the line
+    pub after_blank_line: bool,
+    /// The column where the content of the innermost matched container starts
+    content_column: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MarkdownParserState {
+    /// The stack of the containers that enclose the block being parsed
+    containers: Vec<MarkdownContainer>,
+    /// The prefix of the last line that has been matched against the containers
+    line_prefix: Option<LinePrefix>,
 }
 
 impl<'source> MarkdownParser<'source> {
@@ -17,9 +50,10 @@ impl<'source> MarkdownParser<'source> {
         Self {
             context: ParserContext::default(),
             source: MarkdownTokenSource::from_str(source),
+            state: MarkdownParserState::default(),
         }
     }
-    #[allow(dead_code)]
+
     pub fn checkpoint(&self) -> MarkdownParserCheckpoint {
         MarkdownParserCheckpoint {
             context: self.context.checkpoint(),
@@ -27,11 +61,132 @@ impl<'source> MarkdownParser<'source> {
         }
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
+    /// Returns the number of containers that enclose the block being parsed.
+    pub fn depth(&self) -> usize {
+        self.state.containers.len()
+    }
+
+    pub fn push_container(&mut self, container: MarkdownContainer) {
+        self.state.containers.push(container);
+    }
+
+    pub fn pop_container(&mut self) {
+        self.state.containers.pop();
+    }
+
+    /// Re-lexes the current token in the given context.
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.source.re_lex(context)
+    }
+
+    /// Returns the column of the current token from the start of its line.
+    pub fn column(&self) -> usize {
+        self.source.current_column()
+    }
+
+    /// Returns `true` if the current token is the first token of its line, not counting the
+    /// markers of the containers.
+    pub fn at_line_start(&self) -> bool {
+        self.has_preceding_line_break()
+            || self
+                .state
+                .line_prefix
+                .is_some_and(|prefix| prefix.position == self.cur_range().start())
+    }
+
+    /// Returns the indentation of the current token relative to the content of the innermost
+    /// container that continues on its line.
+    pub fn line_indent(&mut self) -> usize {
+        if self.at_line_start() {
+            let content_column = self.line_prefix().content_column;
+            self.column().saturating_sub(content_column)
+        } else {
+            0
+        }
+    }
+
+    /// Matches the start of the current line against the containers, skipping the `>` markers
+    /// of the block quotes that continue on this line.
+    ///
+    /// Must only be called when the current token is the first token of its line. The result is
+    /// cached, so the enclosing blocks can check the same line without skipping the markers twice.
+    pub fn line_prefix(&mut self) -> LinePrefix {
+        if let Some(prefix) = self.state.line_prefix {
+            if prefix.position == self.cur_range().start() {
+                return prefix;
+            }
+        }
+
+        let containers = self.state.containers.clone();
+        let mut after_blank_line = false;
+        // A blank line closes the block quotes, but not the list items
+        let mut blank_limit = if self.source.preceding_newlines() > 1 {
+            after_blank_line = true;
+            first_quote(&containers)
+        } else {
+            containers.len()
+        };
+
+        let mut matched = 0;
+        let mut content_column = 0;
+        let mut index = 0;
+        self.re_lex(MarkdownReLexContext::Regular);
+
+        while index < containers.len() && matched < blank_limit {
+            match containers[index] {
+                MarkdownContainer::Quote => {
+                    let column = self.column();
+                    if !self.at(T![>]) || column.saturating_sub(content_column) > 3 {
+                        break;
+                    }
+
+                    let end = usize::from(self.cur_range().end());
+                    let followed_by_space =
+                        matches!(self.source.text().as_bytes().get(end), Some(b' ' | b'\t'));
+                    content_column = column + 1 + usize::from(followed_by_space);
+                    self.source.skip_container_marker();
+                    matched += 1;
+
+                    if self.has_preceding_line_break() || self.at(T![EOF]) {
+                        // A blank line inside of the quote, only the list items that directly
+                        // follow the quote can continue on the next line
+                        after_blank_line = true;
+                        blank_limit = matched
+                            + containers[index + 1..]
+                                .iter()
+                                .take_while(|container| {
+                                    matches!(container, MarkdownContainer::ListItem { .. })
+                                })
+                                .count();
+                        matched = 0;
+                        content_column = 0;
+                        index = 0;
+                        self.re_lex(MarkdownReLexContext::Regular);
+                        continue;
+                    }
+                }
+                MarkdownContainer::ListItem { indent } => {
+                    if self.column() < indent {
+                        break;
+                    }
+                    content_column = indent;
+                    matched += 1;
+                }
+            }
+
+            index += 1;
+        }
+
+        let prefix = LinePrefix {
+            position: self.cur_range().start(),
+            matched: matched.min(blank_limit),
+            after_blank_line,
+            content_column,
+        };
+        self.state.line_prefix = Some(prefix);
+        prefix
     }
 
-    #[allow(dead_code)]
     pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
         let MarkdownParserCheckpoint { context, source } = checkpoint;
 
@@ -76,8 +231,15 @@ impl<'source> Parser for MarkdownParser<'source> {
     }
 }
 
-#[allow(dead_code)]
 pub struct MarkdownParserCheckpoint {
     pub(super) context: ParserContextCheckpoint,
     pub(super) source: MarkdownTokenSourceCheckpoint,
 }
+
+/// Returns the index of the outermost block quote, or the number of containers if there's none.
+fn first_quote(containers: &[MarkdownContainer]) -> usize {
+    containers
+        .iter()
+        .position(|container| matches!(container, MarkdownContainer::Quote))
+        .unwrap_or(containers.len())
+}
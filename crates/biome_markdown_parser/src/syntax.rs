@@ -1,56 +1,111 @@
+pub mod code_block;
+pub mod header;
+pub mod html_block;
+pub mod inline;
+pub mod link_block;
+pub mod list;
+pub mod paragraph;
+pub mod quote;
+pub mod table;
 pub mod thematic_break_block;
 
 use biome_markdown_syntax::{kind::MarkdownSyntaxKind::*, T};
 use biome_parser::{
-    prelude::ParsedSyntax::{self, *},
+    prelude::{
+        ParsedSyntax::{self, *},
+        TokenSource,
+    },
     Parser,
 };
+use code_block::{
+    at_fenced_code_block, at_indent_code_block, parse_fenced_code_block, parse_indent_code_block,
+};
+use header::{at_header, parse_header};
+use html_block::{at_html_block, parse_html_block};
+use link_block::{at_link_block, parse_link_block};
+use list::{
+    at_bullet_list_item, at_order_list_item, parse_bullet_list_item, parse_order_list_item,
+};
+use paragraph::parse_paragraph;
+use quote::{at_quote, parse_quote};
+use table::{at_table, parse_table};
 use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
 
 use crate::MarkdownParser;
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
     let m = p.start();
+    // Matches the first line like the following ones, so that its first token is at the line start
+    p.line_prefix();
     let _ = parse_block_list(p);
     m.complete(p, MD_DOCUMENT);
 }
 
+/// Parses the blocks until the end of the file, or until a line that doesn't continue the
+/// innermost container.
 pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
     let m = p.start();
 
     while !p.at(T![EOF]) {
-        parse_any_block(p);
+        if p.at_line_start() && p.line_prefix().matched < p.depth() {
+            break;
+        }
+
+        let _ = parse_any_block(p);
     }
     Present(m.complete(p, MD_BLOCK_LIST))
 }
 
-pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
+pub(crate) fn parse_any_block(p: &mut MarkdownParser) -> ParsedSyntax {
     if at_indent_code_block(p) {
-        parse_indent_code_block(p);
+        parse_indent_code_block(p)
     } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
-            }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
-        }
+        parse_thematic_break_block(p)
+    } else if at_header(p) {
+        parse_header(p)
+    } else if at_fenced_code_block(p) {
+        parse_fenced_code_block(p)
+    } else if at_html_block(p) {
+        parse_html_block(p)
+    } else if at_quote(p) {
+        parse_quote(p)
+    } else if at_bullet_list_item(p) {
+        parse_bullet_list_item(p)
+    } else if at_order_list_item(p) {
+        parse_order_list_item(p)
+    } else if at_link_block(p) {
+        parse_link_block(p)
+    } else if at_table(p) {
+        parse_table(p)
+    } else {
+        parse_paragraph(p)
     }
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
+/// Returns `true` if the current token is the first token of the next line, or the end of the file.
+pub(crate) fn at_line_end(p: &MarkdownParser) -> bool {
+    p.at(T![EOF]) || p.has_preceding_line_break()
+}
+
+/// Returns the text from the start of the current token to the end of its line.
+pub(crate) fn current_line<'a>(p: &'a MarkdownParser) -> &'a str {
+    let text = &p.source().text()[usize::from(p.cur_range().start())..];
+    let end = text.find(['\n', '\r']).unwrap_or(text.len());
+    &text[..end]
 }
 
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns the text of the line that follows the line of the current token.
+pub(crate) fn next_line<'a>(p: &'a MarkdownParser) -> Option<&'a str> {
+    let text = &p.source().text()[usize::from(p.cur_range().start())..];
+    let rest = &text[text.find(['\n', '\r'])?..];
+    let text = rest.strip_prefix("\r\n").unwrap_or(&rest[1..]);
+    let end = text.find(['\n', '\r']).unwrap_or(text.len());
+    Some(&text[..end])
 }
 
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns the text between the end of the current token and the end of its line.
+pub(crate) fn rest_of_line<'a>(p: &'a MarkdownParser) -> &'a str {
+    &current_line(p)[usize::from(p.cur_range().len())..]
 }
 
 /// Attempt to parse some input with the given parsing function. If parsing
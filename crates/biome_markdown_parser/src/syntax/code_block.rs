@@ -0,0 +1,109 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::{at_line_end, rest_of_line};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Returns `true` if the current line is indented by four or more columns relative to the
+/// content of its container.
+pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
+    !p.at(T![EOF]) && p.at_line_start() && p.line_indent() >= 4
+}
+
+/// Parses an indented code block, made of the following lines that are indented by four
+/// or more columns.
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_indent_code_block(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        parse_code_line(p);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+
+        let prefix = p.line_prefix();
+        if prefix.matched < p.depth() || p.line_indent() < 4 {
+            break;
+        }
+    }
+
+    list.complete(p, MD_CODE_LINE_LIST);
+    Present(m.complete(p, MD_INDENT_CODE_BLOCK))
+}
+
+pub(crate) fn at_fenced_code_block(p: &mut MarkdownParser) -> bool {
+    p.at(MD_FENCE_LITERAL)
+}
+
+/// Parses a fenced code block, from its opening fence to the closing fence of the same kind
+/// and at least the same length.
+///
+/// The block is closed by the end of its container when the closing fence is missing.
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_fenced_code_block(p) {
+        return Absent;
+    }
+
+    let fence = p.cur_text();
+    let marker = fence.as_bytes()[0];
+    let length = fence.len();
+
+    let m = p.start();
+    p.bump_with_context(MD_FENCE_LITERAL, MarkdownLexContext::CodeLine);
+
+    if !at_line_end(p) {
+        let info = p.start();
+        p.bump_with_context(MD_TEXTUAL_LITERAL, MarkdownLexContext::Regular);
+        info.complete(p, MD_TEXTUAL);
+    }
+
+    let list = p.start();
+    let mut closed = false;
+    while !p.at(T![EOF]) {
+        let prefix = p.line_prefix();
+        if prefix.matched < p.depth() {
+            break;
+        }
+
+        if at_closing_fence(p, marker, length) {
+            closed = true;
+            break;
+        }
+
+        parse_code_line(p);
+    }
+    list.complete(p, MD_CODE_LINE_LIST);
+
+    if closed {
+        p.bump(MD_FENCE_LITERAL);
+    }
+
+    Present(m.complete(p, MD_FENCED_CODE_BLOCK))
+}
+
+/// Parses the rest of the current line as a code line.
+pub(crate) fn parse_code_line(p: &mut MarkdownParser) {
+    p.re_lex(MarkdownReLexContext::CodeLine);
+
+    let m = p.start();
+    p.bump_remap(MD_TEXTUAL_LITERAL);
+    m.complete(p, MD_TEXTUAL);
+}
+
+fn at_closing_fence(p: &mut MarkdownParser, marker: u8, length: usize) -> bool {
+    if !p.at(MD_FENCE_LITERAL) || p.line_indent() >= 4 {
+        return false;
+    }
+
+    let fence = p.cur_text();
+    fence.as_bytes()[0] == marker && fence.len() >= length && rest_of_line(p).trim().is_empty()
+}
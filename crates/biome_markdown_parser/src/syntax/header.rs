@@ -0,0 +1,67 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::parse_any_inline;
+use crate::syntax::{at_line_end, current_line};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Returns `true` if the current line starts an ATX heading: one to six `#` followed by
+/// whitespace or the end of the line.
+pub(crate) fn at_header(p: &mut MarkdownParser) -> bool {
+    p.at(T![#]) && opening_sequence_length(p).is_some()
+}
+
+/// Parses an ATX heading, `## Heading ##`.
+pub(crate) fn parse_header(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(level) = opening_sequence_length(p).filter(|_| p.at(T![#])) else {
+        return Absent;
+    };
+
+    let m = p.start();
+
+    let before = p.start();
+    for _ in 0..level {
+        parse_hash(p);
+    }
+    before.complete(p, MD_HASH_LIST);
+
+    if !at_line_end(p) && !at_closing_sequence(p) {
+        let paragraph = p.start();
+        let list = p.start();
+        while !at_line_end(p) && !at_closing_sequence(p) {
+            let _ = parse_any_inline(p);
+        }
+        list.complete(p, MD_INLINE_ITEM_LIST);
+        paragraph.complete(p, MD_PARAGRAPH);
+    }
+
+    let after = p.start();
+    while p.at(T![#]) && !at_line_end(p) {
+        parse_hash(p);
+    }
+    after.complete(p, MD_HASH_LIST);
+
+    Present(m.complete(p, MD_HEADER))
+}
+
+fn parse_hash(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.bump_with_context(T![#], MarkdownLexContext::Inline);
+    m.complete(p, MD_HASH);
+}
+
+fn opening_sequence_length(p: &MarkdownParser) -> Option<usize> {
+    let line = current_line(p);
+    let length = line.bytes().take_while(|byte| *byte == b'#').count();
+    let followed_by_whitespace = matches!(line.as_bytes().get(length), Some(b' ' | b'\t') | None);
+
+    ((1..=6).contains(&length) && followed_by_whitespace).then_some(length)
+}
+
+/// Returns `true` if the rest of the line is the optional closing sequence of `#`.
+fn at_closing_sequence(p: &MarkdownParser) -> bool {
+    p.at(T![#]) && current_line(p).trim_end().bytes().all(|byte| byte == b'#')
+}
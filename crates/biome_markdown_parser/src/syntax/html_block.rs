@@ -0,0 +1,216 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::code_block::parse_code_line;
+use crate::syntax::current_line;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// The tag names that start a HTML block ended by a blank line.
+const BLOCK_TAG_NAMES: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// The tag names whose content is kept verbatim until their closing tag.
+const RAW_TAG_NAMES: &[&str] = &["pre", "script", "style", "textarea"];
+
+/// The kinds of HTML blocks, see https://spec.commonmark.org/0.31.2/#html-blocks
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum HtmlBlockKind {
+    /// `<script>`, `<pre>`, `<style>` or `<textarea>`, ended by their closing tag
+    Raw,
+    /// `<!-- -->`
+    Comment,
+    /// `<? ?>`
+    ProcessingInstruction,
+    /// `<!DOCTYPE >`
+    Declaration,
+    /// `<![CDATA[ ]]>`
+    CData,
+    /// A block level tag, ended by a blank line
+    Block,
+    /// Any other complete tag alone on its line, ended by a blank line
+    Tag,
+}
+
+impl HtmlBlockKind {
+    /// Returns the kind of the HTML block that starts with the given line.
+    fn from_line(line: &str) -> Option<Self> {
+        let rest = line.strip_prefix('<')?;
+
+        if rest.starts_with("!--") {
+            Some(Self::Comment)
+        } else if rest.starts_with('?') {
+            Some(Self::ProcessingInstruction)
+        } else if rest.starts_with("![CDATA[") {
+            Some(Self::CData)
+        } else if rest
+            .strip_prefix('!')
+            .is_some_and(|rest| rest.starts_with(|char: char| char.is_ascii_alphabetic()))
+        {
+            Some(Self::Declaration)
+        } else if starts_with_tag_name(rest, RAW_TAG_NAMES) {
+            Some(Self::Raw)
+        } else if starts_with_tag_name(rest.strip_prefix('/').unwrap_or(rest), BLOCK_TAG_NAMES) {
+            Some(Self::Block)
+        } else if is_complete_tag(line) {
+            Some(Self::Tag)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the HTML block ends with the given line.
+    fn is_end(self, line: &str) -> bool {
+        match self {
+            Self::Raw => line.match_indices("</").any(|(start, _)| {
+                let rest = &line.as_bytes()[start + 2..];
+                RAW_TAG_NAMES.iter().any(|name| {
+                    rest.len() > name.len()
+                        && rest[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                        && rest[name.len()] == b'>'
+                })
+            }),
+            Self::Comment => line.contains("-->"),
+            Self::ProcessingInstruction => line.contains("?>"),
+            Self::Declaration => line.contains('>'),
+            Self::CData => line.contains("]]>"),
+            Self::Block | Self::Tag => false,
+        }
+    }
+
+    /// Returns `true` if the HTML block ends with a blank line.
+    const fn ends_with_blank_line(self) -> bool {
+        matches!(self, Self::Block | Self::Tag)
+    }
+}
+
+/// Returns `true` if the text starts with one of the tag names, case-insensitively, followed by
+/// whitespace, the end of the tag or the end of the line.
+fn starts_with_tag_name(text: &str, names: &[&str]) -> bool {
+    names.iter().any(|name| {
+        text.get(..name.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            && matches!(
+                text.as_bytes().get(name.len()),
+                Some(b' ' | b'\t' | b'>' | b'/') | None
+            )
+    })
+}
+
+/// Returns `true` if the line is made of a single opening or closing tag.
+fn is_complete_tag(line: &str) -> bool {
+    let line = line.trim_end();
+    let Some(tag) = line.strip_prefix("</").or_else(|| line.strip_prefix('<')) else {
+        return false;
+    };
+
+    let name_length = tag
+        .find(|char: char| !char.is_ascii_alphanumeric() && char != '-')
+        .unwrap_or(tag.len());
+
+    tag.starts_with(|char: char| char.is_ascii_alphabetic())
+        && matches!(
+            tag.as_bytes().get(name_length),
+            Some(b' ' | b'\t' | b'/' | b'>')
+        )
+        && tag.find('>') == Some(tag.len() - 1)
+}
+
+pub(crate) fn at_html_block(p: &mut MarkdownParser) -> bool {
+    HtmlBlockKind::from_line(current_line(p)).is_some()
+}
+
+/// Returns `true` if the current line starts a HTML block that can interrupt a paragraph.
+pub(crate) fn at_html_block_interrupting_paragraph(p: &mut MarkdownParser) -> bool {
+    HtmlBlockKind::from_line(current_line(p)).is_some_and(|kind| kind != HtmlBlockKind::Tag)
+}
+
+/// Parses a HTML block. Its lines are kept verbatim.
+pub(crate) fn parse_html_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(kind) = HtmlBlockKind::from_line(current_line(p)) else {
+        return Absent;
+    };
+
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        let is_end = kind.is_end(current_line(p));
+        parse_code_line(p);
+
+        if is_end || p.at(T![EOF]) {
+            break;
+        }
+
+        let prefix = p.line_prefix();
+        if prefix.matched < p.depth() || (prefix.after_blank_line && kind.ends_with_blank_line()) {
+            break;
+        }
+    }
+
+    list.complete(p, MD_CODE_LINE_LIST);
+    Present(m.complete(p, MD_HTML_BLOCK))
+}
@@ -0,0 +1,211 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::{at_line_end, try_parse};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::{
+        ParsedSyntax::{self, *},
+        TokenSource,
+    },
+    Parser,
+};
+
+/// Parses the inline items from the current token to the end of its line.
+pub(crate) fn parse_inline_line(p: &mut MarkdownParser) {
+    p.re_lex(MarkdownReLexContext::Inline);
+
+    loop {
+        let _ = parse_any_inline(p);
+
+        if at_line_end(p) {
+            break;
+        }
+    }
+}
+
+pub(crate) fn parse_any_inline(p: &mut MarkdownParser) -> ParsedSyntax {
+    match p.cur() {
+        MD_INLINE_CODE_LITERAL => parse_inline_code(p),
+        MD_HARD_LINE_LITERAL => parse_hard_line(p),
+        T![*] | T![_] | T![**] | T![__] => parse_inline_emphasis(p).or_else(|| parse_textual(p)),
+        T!['['] => parse_inline_link(p).or_else(|| parse_textual(p)),
+        T![!] => parse_inline_image(p).or_else(|| parse_textual(p)),
+        _ => parse_textual(p),
+    }
+}
+
+/// Parses the current token as text, whatever its kind.
+pub(crate) fn parse_textual(p: &mut MarkdownParser) -> ParsedSyntax {
+    if p.at(T![EOF]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_remap_with_context(MD_TEXTUAL_LITERAL, MarkdownLexContext::Inline);
+    Present(m.complete(p, MD_TEXTUAL))
+}
+
+fn parse_inline_code(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !p.at(MD_INLINE_CODE_LITERAL) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_with_context(MD_INLINE_CODE_LITERAL, MarkdownLexContext::Inline);
+    Present(m.complete(p, MD_INLINE_CODE))
+}
+
+fn parse_hard_line(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !p.at(MD_HARD_LINE_LITERAL) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_with_context(MD_HARD_LINE_LITERAL, MarkdownLexContext::Inline);
+    Present(m.complete(p, MD_HARD_LINE))
+}
+
+/// Parses an emphasis (`*foo*`, `_foo_`) or a strong emphasis (`**foo**`, `__foo__`) that is
+/// closed on the same line.
+fn parse_inline_emphasis(p: &mut MarkdownParser) -> ParsedSyntax {
+    let delimiter = p.cur();
+    if !matches!(delimiter, T![*] | T![_] | T![**] | T![__]) || !is_left_flanking(p) {
+        return Absent;
+    }
+
+    let kind = if matches!(delimiter, T![**] | T![__]) {
+        MD_INLINE_EMPHASIS
+    } else {
+        MD_INLINE_ITALIC
+    };
+
+    try_parse(p, |p| {
+        let m = p.start();
+        p.bump_with_context(delimiter, MarkdownLexContext::Inline);
+
+        let content = p.start();
+        let mut is_empty = true;
+        while !(p.at(delimiter) && !is_empty && is_right_flanking(p)) {
+            if at_line_end(p) {
+                content.abandon(p);
+                m.abandon(p);
+                return Err(());
+            }
+
+            let _ = parse_any_inline(p);
+            is_empty = false;
+        }
+        content.complete(p, MD_INLINE_ITEM_LIST);
+
+        p.bump_with_context(delimiter, MarkdownLexContext::Inline);
+        Ok(Present(m.complete(p, kind)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses a link, `[label](source)`.
+fn parse_inline_link(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+        if parse_link_parts(p).is_err() {
+            m.abandon(p);
+            return Err(());
+        }
+        Ok(Present(m.complete(p, MD_INLINE_LINK)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses an image, `![alt](source)`.
+fn parse_inline_image(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !p.at(T![!]) {
+        return Absent;
+    }
+
+    try_parse(p, |p| {
+        let m = p.start();
+        p.bump_with_context(T![!], MarkdownLexContext::Inline);
+        if !p.at(T!['[']) || parse_link_parts(p).is_err() {
+            m.abandon(p);
+            return Err(());
+        }
+        Ok(Present(m.complete(p, MD_INLINE_IMAGE)))
+    })
+    .unwrap_or(Absent)
+}
+
+/// Parses the `[label](source)` part shared by links and images. The source is kept as text.
+fn parse_link_parts(p: &mut MarkdownParser) -> Result<(), ()> {
+    p.bump_with_context(T!['['], MarkdownLexContext::Inline);
+
+    let label = p.start();
+    while !p.at(T![']']) {
+        if at_line_end(p) {
+            label.abandon(p);
+            return Err(());
+        }
+        let _ = parse_any_inline(p);
+    }
+    label.complete(p, MD_INLINE_ITEM_LIST);
+
+    let label_end = p.cur_range().end();
+    p.bump_with_context(T![']'], MarkdownLexContext::Inline);
+    if !p.at(T!['(']) || p.cur_range().start() != label_end {
+        return Err(());
+    }
+    p.bump_with_context(T!['('], MarkdownLexContext::Inline);
+
+    let source = p.start();
+    let mut depth = 0usize;
+    loop {
+        if at_line_end(p) {
+            source.abandon(p);
+            return Err(());
+        }
+
+        match p.cur() {
+            T!['('] => depth += 1,
+            T![')'] if depth == 0 => break,
+            T![')'] => depth -= 1,
+            _ => {}
+        }
+        let _ = parse_textual(p);
+    }
+    source.complete(p, MD_INLINE_ITEM_LIST);
+
+    p.bump_with_context(T![')'], MarkdownLexContext::Inline);
+    Ok(())
+}
+
+/// Returns `true` if the current delimiter can open an emphasis: it's followed by a non-whitespace
+/// character, and an underscore isn't preceded by an alphanumeric character.
+fn is_left_flanking(p: &MarkdownParser) -> bool {
+    let (before, after) = surrounding_chars(p);
+    let intraword = is_underscore(p.cur()) && before.is_some_and(char::is_alphanumeric);
+    after.is_some_and(|char| !char.is_whitespace()) && !intraword
+}
+
+/// Returns `true` if the current delimiter can close an emphasis: it's preceded by a
+/// non-whitespace character, and an underscore isn't followed by an alphanumeric character.
+fn is_right_flanking(p: &MarkdownParser) -> bool {
+    let (before, after) = surrounding_chars(p);
+    let intraword = is_underscore(p.cur()) && after.is_some_and(char::is_alphanumeric);
+    before.is_some_and(|char| !char.is_whitespace()) && !intraword
+}
+
+fn is_underscore(kind: MarkdownSyntaxKind) -> bool {
+    matches!(kind, T![_] | T![__])
+}
+
+/// Returns the characters before and after the current token.
+fn surrounding_chars(p: &MarkdownParser) -> (Option<char>, Option<char>) {
+    let text = p.source().text();
+    let range = p.cur_range();
+    let before = text[..usize::from(range.start())].chars().next_back();
+    let after = text[usize::from(range.end())..].chars().next();
+    (before, after)
+}
@@ -0,0 +1,91 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::parse_textual;
+use crate::syntax::{at_line_end, current_line};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::{
+        ParsedSyntax::{self, *},
+        TokenSource,
+    },
+    Parser,
+};
+
+/// Returns `true` if the current line is a link reference definition, `[label]: url "title"`,
+/// with its destination on the same line.
+pub(crate) fn at_link_block(p: &mut MarkdownParser) -> bool {
+    if !p.at(T!['[']) {
+        return false;
+    }
+
+    let line = current_line(p);
+    let Some(label_end) = label_end(line) else {
+        return false;
+    };
+
+    let rest = &line[label_end + 1..];
+    rest.strip_prefix(':').is_some_and(|destination| {
+        destination.starts_with([' ', '\t']) && !destination.trim().is_empty()
+    })
+}
+
+/// Returns the offset of the `]` that closes the label which starts the line.
+///
+/// Labels with nested brackets or code spans aren't supported.
+fn label_end(line: &str) -> Option<usize> {
+    let mut bytes = line.bytes().enumerate().skip(1);
+    while let Some((index, byte)) = bytes.next() {
+        match byte {
+            b'\\' => {
+                bytes.next();
+            }
+            b'[' | b'`' => return None,
+            b']' if index > 1 => return Some(index),
+            b']' => return None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Parses a link reference definition. The label, the destination and the title are kept as text.
+pub(crate) fn parse_link_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_link_block(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::Inline);
+    p.bump_with_context(T!['['], MarkdownLexContext::Inline);
+
+    let label = p.start();
+    while !p.at(T![']']) {
+        let _ = parse_textual(p);
+    }
+    label.complete(p, MD_INLINE_ITEM_LIST);
+
+    p.bump_with_context(T![']'], MarkdownLexContext::Inline);
+    p.bump_remap_with_context(T![:], MarkdownLexContext::Inline);
+
+    let url = p.start();
+    loop {
+        let end = usize::from(p.cur_range().end());
+        let _ = parse_textual(p);
+
+        let followed_by_whitespace =
+            matches!(p.source().text().as_bytes().get(end), Some(b' ' | b'\t'));
+        if followed_by_whitespace || at_line_end(p) {
+            break;
+        }
+    }
+    url.complete(p, MD_INLINE_ITEM_LIST);
+
+    let title = p.start();
+    while !at_line_end(p) {
+        let _ = parse_textual(p);
+    }
+    title.complete(p, MD_INLINE_ITEM_LIST);
+
+    Present(m.complete(p, MD_LINK_BLOCK))
+}
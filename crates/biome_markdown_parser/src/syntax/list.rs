@@ -0,0 +1,110 @@
+use crate::parser::{MarkdownContainer, MarkdownParser};
+use crate::syntax::{at_line_end, parse_block_list};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Returns `true` if the current line starts a bullet list item, `- item`.
+///
+/// A single `-` alone on its line is lexed as a setext heading underline, it's an empty list
+/// item when it doesn't follow a paragraph.
+pub(crate) fn at_bullet_list_item(p: &mut MarkdownParser) -> bool {
+    p.at(T![-])
+        || p.at(T![*])
+        || p.at(T![+])
+        || (p.at(MD_SETEXT_UNDERLINE_LITERAL) && p.cur_text() == "-")
+}
+
+/// Parses the consecutive bullet list items that use the same bullet character.
+pub(crate) fn parse_bullet_list_item(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_bullet_list_item(p) {
+        return Absent;
+    }
+
+    let bullet = p.cur_text().as_bytes()[0];
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        parse_bullet(p);
+
+        if !at_next_list_item(p) || !at_bullet_list_item(p) || p.cur_text().as_bytes()[0] != bullet
+        {
+            break;
+        }
+    }
+
+    list.complete(p, MD_BULLET_LIST);
+    Present(m.complete(p, MD_BULLET_LIST_ITEM))
+}
+
+fn parse_bullet(p: &mut MarkdownParser) {
+    let m = p.start();
+    if p.at(MD_SETEXT_UNDERLINE_LITERAL) {
+        parse_list_marker(p, T![-]);
+    } else {
+        let kind = p.cur();
+        parse_list_marker(p, kind);
+    }
+    m.complete(p, MD_BULLET);
+}
+
+pub(crate) fn at_order_list_item(p: &mut MarkdownParser) -> bool {
+    p.at(MD_ORDERED_LIST_MARKER_LITERAL)
+}
+
+/// Parses the consecutive ordered list items that use the same delimiter, `.` or `)`.
+pub(crate) fn parse_order_list_item(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_order_list_item(p) {
+        return Absent;
+    }
+
+    let delimiter = ordered_list_delimiter(p);
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        let bullet = p.start();
+        parse_list_marker(p, MD_ORDERED_LIST_MARKER_LITERAL);
+        bullet.complete(p, MD_ORDER_BULLET);
+
+        if !at_next_list_item(p) || !at_order_list_item(p) || ordered_list_delimiter(p) != delimiter
+        {
+            break;
+        }
+    }
+
+    list.complete(p, MD_ORDER_LIST);
+    Present(m.complete(p, MD_ORDER_LIST_ITEM))
+}
+
+fn ordered_list_delimiter(p: &MarkdownParser) -> u8 {
+    let marker = p.cur_text().as_bytes();
+    marker[marker.len() - 1]
+}
+
+/// Bumps the marker of a list item, as `kind`, and parses the blocks of the item.
+fn parse_list_marker(p: &mut MarkdownParser, kind: MarkdownSyntaxKind) {
+    let marker_end = p.column() + usize::from(p.cur_range().len());
+    p.bump_remap(kind);
+
+    // The content of the item is indented to the column of its first block, unless it's
+    // an indented code block or it starts on the next line.
+    let indent = if at_line_end(p) || p.column().saturating_sub(marker_end) > 4 {
+        marker_end + 1
+    } else {
+        p.column()
+    };
+
+    p.push_container(MarkdownContainer::ListItem { indent });
+    let _ = parse_block_list(p);
+    p.pop_container();
+}
+
+/// Returns `true` if the current line is at the same depth as the list, so that it can
+/// start its next item.
+fn at_next_list_item(p: &mut MarkdownParser) -> bool {
+    !p.at(T![EOF]) && p.line_prefix().matched == p.depth() && p.line_indent() < 4
+}
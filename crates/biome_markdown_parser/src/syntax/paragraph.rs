@@ -0,0 +1,89 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::code_block::at_fenced_code_block;
+use crate::syntax::header::at_header;
+use crate::syntax::html_block::at_html_block_interrupting_paragraph;
+use crate::syntax::inline::parse_inline_line;
+use crate::syntax::list::{at_bullet_list_item, at_order_list_item};
+use crate::syntax::quote::at_quote;
+use crate::syntax::rest_of_line;
+use crate::syntax::thematic_break_block::at_thematic_break_block;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Parses a paragraph, including its lazy continuation lines.
+///
+/// A paragraph followed by a setext heading underline becomes a [MD_SETEXT_HEADER].
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser) -> ParsedSyntax {
+    if p.at(T![EOF]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        parse_inline_line(p);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+
+        let prefix = p.line_prefix();
+        if prefix.after_blank_line {
+            break;
+        }
+
+        if prefix.matched == p.depth() && p.line_indent() < 4 && at_setext_underline(p) {
+            list.complete(p, MD_INLINE_ITEM_LIST);
+            let header = m.complete(p, MD_PARAGRAPH).precede(p);
+            p.bump_remap(MD_SETEXT_UNDERLINE_LITERAL);
+            return Present(header.complete(p, MD_SETEXT_HEADER));
+        }
+
+        if at_paragraph_interruption(p) {
+            break;
+        }
+    }
+
+    list.complete(p, MD_INLINE_ITEM_LIST);
+    Present(m.complete(p, MD_PARAGRAPH))
+}
+
+/// Returns `true` if the current line is the underline of a setext heading: a line of `=` or
+/// a line of `-` without spaces.
+fn at_setext_underline(p: &mut MarkdownParser) -> bool {
+    p.at(MD_SETEXT_UNDERLINE_LITERAL)
+        || (p.at(MD_THEMATIC_BREAK_LITERAL) && p.cur_text().bytes().all(|byte| byte == b'-'))
+}
+
+/// Returns `true` if the current line starts a block that interrupts a paragraph.
+///
+/// Empty list items, and the ordered list items that don't start at 1, can't interrupt a paragraph
+/// of the innermost container. They still close the containers that the line doesn't continue.
+pub(crate) fn at_paragraph_interruption(p: &mut MarkdownParser) -> bool {
+    if p.line_indent() >= 4 {
+        return false;
+    }
+
+    let is_lazy = p.line_prefix().matched < p.depth();
+
+    if at_bullet_list_item(p) {
+        return is_lazy || !rest_of_line(p).trim().is_empty();
+    }
+
+    if at_order_list_item(p) {
+        let marker = p.cur_text();
+        return is_lazy
+            || (marker[..marker.len() - 1].parse::<u32>() == Ok(1)
+                && !rest_of_line(p).trim().is_empty());
+    }
+
+    at_thematic_break_block(p)
+        || at_header(p)
+        || at_fenced_code_block(p)
+        || at_quote(p)
+        || at_html_block_interrupting_paragraph(p)
+}
@@ -0,0 +1,27 @@
+use crate::parser::{MarkdownContainer, MarkdownParser};
+use crate::syntax::parse_block_list;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+pub(crate) fn at_quote(p: &mut MarkdownParser) -> bool {
+    p.at(T![>])
+}
+
+/// Parses a block quote. The `>` markers of its following lines are skipped as trivia.
+pub(crate) fn parse_quote(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_quote(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![>]);
+
+    p.push_container(MarkdownContainer::Quote);
+    let _ = parse_block_list(p);
+    p.pop_container();
+
+    Present(m.complete(p, MD_QUOTE))
+}
@@ -0,0 +1,128 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::parse_any_inline;
+use crate::syntax::paragraph::at_paragraph_interruption;
+use crate::syntax::{current_line, next_line};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+use biome_rowan::TextSize;
+
+/// Returns `true` if the current line is the header row of a table: it's followed by a
+/// delimiter row with the same number of cells.
+pub(crate) fn at_table(p: &mut MarkdownParser) -> bool {
+    let line = current_line(p);
+    if !line.contains('|') {
+        return false;
+    }
+
+    let Some(delimiter) = next_line(p) else {
+        return false;
+    };
+    let delimiter = delimiter.trim_start_matches(|char: char| char == '>' || char.is_whitespace());
+
+    delimiter.contains('|')
+        && split_row(delimiter)
+            .iter()
+            .all(|cell| is_delimiter_cell(cell))
+        && split_row(delimiter).len() == split_row(line).len()
+}
+
+/// Splits a row into its cells, ignoring the leading and trailing pipes.
+fn split_row(row: &str) -> Vec<&str> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = match row.strip_suffix('|') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => row,
+    };
+
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, byte) in row.bytes().enumerate() {
+        match byte {
+            b'\\' if !escaped => {
+                escaped = true;
+                continue;
+            }
+            b'|' if !escaped => {
+                cells.push(&row[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+        escaped = false;
+    }
+    cells.push(&row[start..]);
+    cells
+}
+
+/// Returns `true` if the cell is a delimiter, `---`, optionally with alignment colons, `:---:`.
+fn is_delimiter_cell(cell: &str) -> bool {
+    let cell = cell.trim();
+    let cell = cell.strip_prefix(':').unwrap_or(cell);
+    let cell = cell.strip_suffix(':').unwrap_or(cell);
+    !cell.is_empty() && cell.bytes().all(|byte| byte == b'-')
+}
+
+/// Parses a table: its header row, its delimiter row and the following rows until a blank line
+/// or the start of another block.
+pub(crate) fn parse_table(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_table(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_table_row(p);
+    p.line_prefix();
+    parse_table_row(p);
+
+    let rows = p.start();
+    while !p.at(T![EOF]) {
+        let prefix = p.line_prefix();
+        if prefix.after_blank_line || prefix.matched < p.depth() || at_paragraph_interruption(p) {
+            break;
+        }
+
+        parse_table_row(p);
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    Present(m.complete(p, MD_TABLE))
+}
+
+fn parse_table_row(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::Inline);
+    let start = p.cur_range().start();
+
+    if p.at(T![|]) {
+        p.bump_with_context(T![|], MarkdownLexContext::Inline);
+    }
+
+    let cells = p.start();
+    while !at_row_end(p, start) {
+        let cell = p.start();
+        let content = p.start();
+        while !p.at(T![|]) && !at_row_end(p, start) {
+            let _ = parse_any_inline(p);
+        }
+        content.complete(p, MD_INLINE_ITEM_LIST);
+        cell.complete(p, MD_TABLE_CELL);
+
+        if p.at(T![|]) && !at_row_end(p, start) {
+            p.bump_with_context(T![|], MarkdownLexContext::Inline);
+        }
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+
+    m.complete(p, MD_TABLE_ROW);
+}
+
+/// Returns `true` if the current token is after the end of the row that starts at `start`.
+fn at_row_end(p: &MarkdownParser, start: TextSize) -> bool {
+    p.at(T![EOF]) || (p.has_preceding_line_break() && p.cur_range().start() != start)
+}
@@ -14,7 +14,6 @@ pub(crate) struct MarkdownTokenSource<'source> {
     pub(super) trivia_list: Vec<Trivia>,
 }
 
-#[allow(dead_code)]
 pub(crate) type MarkdownTokenSourceCheckpoint = TokenSourceCheckpoint<MarkdownSyntaxKind>;
 
 impl<'source> MarkdownTokenSource<'source> {
@@ -63,36 +62,70 @@ impl<'source> MarkdownTokenSource<'source> {
         }
     }
 
-    /// Returns the number of whitespace characters before the current token until the first new line.
-    /// tab will be counted as 4 spaces https://spec.commonmark.org/0.31.2/#tabs
-    /// whitespace will be counted as 1 space
-    pub fn before_whitespace_count(&self) -> usize {
-        let last_trivia: Vec<&Trivia> = self
-            .trivia_list
-            .iter()
-            .rev()
-            .take_while(|item| {
-                // get before whitespace and tab collect
-                matches!(
-                    item.kind(),
-                    TriviaPieceKind::Whitespace | TriviaPieceKind::Skipped
-                )
+    /// Skips the current token, the marker of a container block that continues on this line,
+    /// and lexes the first token after it as if it was at the start of the line.
+    ///
+    /// The marker is stored as whitespace trivia because the formatter prints the markers of the
+    /// continuation lines itself.
+    pub fn skip_container_marker(&mut self) {
+        if self.current() != EOF {
+            self.trivia_list.push(Trivia::new(
+                TriviaPieceKind::Whitespace,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(MarkdownLexContext::Regular, true)
+        }
+    }
+
+    /// Returns the number of line breaks between the current token and the previous token or
+    /// container marker on the same line.
+    pub fn preceding_newlines(&self) -> usize {
+        let mut end = self.current_range().start();
+        let mut newlines = 0;
+
+        for trivia in self.trivia_list.iter().rev() {
+            if trivia.text_range().end() != end {
+                break;
+            }
+
+            match trivia.kind() {
+                TriviaPieceKind::Newline => newlines += 1,
+                TriviaPieceKind::Whitespace
+                    if self.text()[trivia.text_range()]
+                        .bytes()
+                        .all(|byte| byte.is_ascii_whitespace()) => {}
+                _ => break,
+            }
+
+            end = trivia.text_range().start();
+        }
+
+        newlines
+    }
+
+    /// Returns the column of the current token from the start of its line.
+    ///
+    /// Tabs advance to the next multiple of 4 columns, see https://spec.commonmark.org/0.31.2/#tabs
+    pub fn current_column(&self) -> usize {
+        let start = usize::from(self.current_range().start());
+        let line = &self.text()[..start];
+        let line_start = line.rfind(['\n', '\r']).map_or(0, |index| index + 1);
+
+        line[line_start..]
+            .chars()
+            .fold(0, |column, char| match char {
+                '\t' => column + 4 - column % 4,
+                _ => column + 1,
             })
-            .collect();
-        last_trivia.iter().fold(0, |count, b| match b.kind() {
-            TriviaPieceKind::Skipped => count + 4,
-            TriviaPieceKind::Whitespace => count + u32::from(b.len()) as usize,
-            _ => count,
-        })
     }
 
-    #[allow(dead_code)]
     pub fn re_lex(&mut self, mode: MarkdownReLexContext) -> MarkdownSyntaxKind {
         self.lexer.re_lex(mode)
     }
 
     /// Creates a checkpoint to which it can later return using [Self::rewind].
-    #[allow(dead_code)]
     pub fn checkpoint(&self) -> MarkdownTokenSourceCheckpoint {
         MarkdownTokenSourceCheckpoint {
             trivia_len: self.trivia_list.len() as u32,
@@ -101,7 +134,6 @@ impl<'source> MarkdownTokenSource<'source> {
     }
 
     /// Restores the token source to a previous state
-    #[allow(dead_code)]
     pub fn rewind(&mut self, checkpoint: MarkdownTokenSourceCheckpoint) {
         assert!(self.trivia_list.len() >= checkpoint.trivia_len as usize);
         self.trivia_list.truncate(checkpoint.trivia_len as usize);
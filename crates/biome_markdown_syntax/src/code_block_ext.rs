@@ -0,0 +1,113 @@
+use crate::{MarkdownSyntaxToken, MdCodeLineList, MdFencedCodeBlock, MdTextual};
+use biome_rowan::{AstNodeList, TriviaPieceKind};
+
+impl MdFencedCodeBlock {
+    /// Returns the language of the code block, which is the first word of its info string.
+    ///
+    /// ## Examples
+    ///
+    /// ````markdown
+    /// ```js title="example.js"
+    /// ```
+    /// ````
+    ///
+    /// The language of this code block is `js`.
+    pub fn language(&self) -> Option<String> {
+        let info = self.info()?.value_token().ok()?;
+        info.text_trimmed()
+            .split_whitespace()
+            .next()
+            .map(ToString::to_string)
+    }
+
+    /// Returns the code of the block, without the indentation of the opening fence and
+    /// without the markers of the enclosing block quotes and list items.
+    pub fn content_text(&self) -> String {
+        let fence_column = self.l_fence_token().map_or(0, |fence| token_column(&fence));
+        let mut text = String::new();
+
+        for line in self.content().code_lines() {
+            if !text.is_empty() {
+                text.push_str(&"\n".repeat(line.blank_lines_before));
+            }
+            text.push_str(&" ".repeat(line.column.saturating_sub(fence_column)));
+            text.push_str(line.token.text_trimmed());
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// A line of a code block or of a HTML block.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CodeLine {
+    /// The node of the line
+    pub node: MdTextual,
+    /// The content of the line, without its indentation
+    pub token: MarkdownSyntaxToken,
+    /// The column where the content of the line starts, see [token_column]
+    pub column: usize,
+    /// The number of blank lines between this line and the previous one
+    pub blank_lines_before: usize,
+}
+
+impl MdCodeLineList {
+    /// Returns the non-blank lines of the list.
+    pub fn code_lines(&self) -> impl Iterator<Item = CodeLine> + '_ {
+        self.iter().filter_map(|line| {
+            let token = line.value_token().ok()?;
+            let newlines = token
+                .leading_trivia()
+                .pieces()
+                .filter(|piece| piece.kind() == TriviaPieceKind::Newline)
+                .count();
+
+            Some(CodeLine {
+                column: token_column(&token),
+                blank_lines_before: newlines.saturating_sub(1),
+                token,
+                node: line,
+            })
+        })
+    }
+}
+
+/// Returns the column where the token starts in its line, including the markers of the
+/// enclosing block quotes and list items. Tabs advance to the next multiple of 4.
+pub fn token_column(token: &MarkdownSyntaxToken) -> usize {
+    // The texts between the start of the line and the token, from the last to the first
+    let mut texts = Vec::new();
+    let mut current = token.clone();
+
+    'line: loop {
+        for piece in current.leading_trivia().pieces().rev() {
+            if piece.is_newline() {
+                break 'line;
+            }
+            texts.push(piece.text().to_string());
+        }
+
+        let Some(previous) = current.prev_token() else {
+            break;
+        };
+
+        for piece in previous.trailing_trivia().pieces().rev() {
+            texts.push(piece.text().to_string());
+        }
+        texts.push(previous.text_trimmed().to_string());
+        current = previous;
+    }
+
+    texts
+        .iter()
+        .rev()
+        .flat_map(|text| text.chars())
+        .fold(0, |column, char| {
+            if char == '\t' {
+                column + 4 - column % 4
+            } else {
+                column + 1
+            }
+        })
+}
@@ -0,0 +1,56 @@
+use biome_rowan::FileSourceError;
+use biome_string_case::StrLikeExtension;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct MarkdownFileSource {
+    // ?? Options
+}
+
+impl MarkdownFileSource {
+    pub fn markdown() -> Self {
+        Self {
+            // ?? Options
+        }
+    }
+
+    /// Try to return the Markdown file source corresponding to this file extension
+    pub fn try_from_extension(extension: &OsStr) -> Result<Self, FileSourceError> {
+        // We assume the file extension is normalized to lowercase
+        match extension.as_encoded_bytes() {
+            // https://github.com/github-linguist/linguist/blob/4ac734c15a96f9e16fd12330d0cb8de82274f700/lib/linguist/languages.yml#L4276-L4290
+            b"md" | b"markdown" | b"mdown" | b"mdwn" | b"mkd" | b"mkdn" | b"mkdown" | b"ronn"
+            | b"scd" | b"workbook" => Ok(Self::markdown()),
+            _ => Err(FileSourceError::UnknownExtension),
+        }
+    }
+
+    /// Try to return the Markdown file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "markdown" => Ok(Self::markdown()),
+            _ => Err(FileSourceError::UnknownLanguageId),
+        }
+    }
+}
+
+impl TryFrom<&Path> for MarkdownFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let Some(extension) = path.extension() else {
+            return Err(FileSourceError::MissingFileExtension);
+        };
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        Self::try_from_extension(&extension.to_ascii_lowercase_cow())
+    }
+}
@@ -13,30 +13,29 @@ pub enum MarkdownSyntaxKind {
     #[doc = r" Any Unicode BOM character that may be present at the start of"]
     #[doc = r" a file."]
     UNICODE_BOM,
-    L_ANGLE,
     R_ANGLE,
     L_PAREN,
     R_PAREN,
     L_BRACK,
     R_BRACK,
-    SLASH,
-    EQ,
     BANG,
+    COLON,
+    PIPE,
     MINUS,
+    PLUS,
     STAR,
-    BACKTICK,
-    TILDE,
-    WHITESPACE3,
+    DOUBLE_STAR,
     UNDERSCORE,
+    DOUBLE_UNDERSCORE,
     HASH,
     NULL_KW,
     MD_HARD_LINE_LITERAL,
-    MD_SOFT_BREAK_LITERAL,
     MD_TEXTUAL_LITERAL,
-    MD_STRING_LITERAL,
-    MD_INDENT_CHUNK_LITERAL,
+    MD_INLINE_CODE_LITERAL,
+    MD_FENCE_LITERAL,
+    MD_ORDERED_LIST_MARKER_LITERAL,
+    MD_SETEXT_UNDERLINE_LITERAL,
     MD_THEMATIC_BREAK_LITERAL,
-    MD_ERROR_LITERAL,
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
@@ -45,31 +44,37 @@ pub enum MarkdownSyntaxKind {
     MD_BOGUS,
     MD_DOCUMENT,
     MD_BLOCK_LIST,
+    MD_THEMATIC_BREAK_BLOCK,
     MD_HASH_LIST,
     MD_HASH,
     MD_HEADER,
+    MD_SETEXT_HEADER,
     MD_INDENT_CODE_BLOCK,
     MD_FENCED_CODE_BLOCK,
+    MD_CODE_LINE_LIST,
     MD_HTML_BLOCK,
     MD_LINK_BLOCK,
+    MD_TABLE,
+    MD_TABLE_ROW_LIST,
+    MD_TABLE_ROW,
+    MD_TABLE_CELL_LIST,
+    MD_TABLE_CELL,
     MD_QUOTE,
-    MD_ORDER_LIST_ITEM,
     MD_BULLET_LIST_ITEM,
     MD_BULLET_LIST,
+    MD_BULLET,
+    MD_ORDER_LIST_ITEM,
     MD_ORDER_LIST,
+    MD_ORDER_BULLET,
     MD_PARAGRAPH,
-    MD_PARAGRAPH_ITEM_LIST,
+    MD_INLINE_ITEM_LIST,
     MD_INLINE_CODE,
     MD_INLINE_EMPHASIS,
+    MD_INLINE_ITALIC,
     MD_INLINE_LINK,
     MD_INLINE_IMAGE,
     MD_HARD_LINE,
-    MD_SOFT_BREAK,
     MD_TEXTUAL,
-    MD_SETEXT_HEADER,
-    MD_STRING,
-    MD_INDENT,
-    MD_THEMATIC_BREAK_BLOCK,
     #[doc(hidden)]
     __LAST,
 }
@@ -77,30 +82,27 @@ use self::MarkdownSyntaxKind::*;
 impl MarkdownSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            L_ANGLE | R_ANGLE | L_PAREN | R_PAREN | L_BRACK | R_BRACK | SLASH | EQ | BANG
-            | MINUS | STAR | BACKTICK | TILDE | WHITESPACE3 | UNDERSCORE | HASH => true,
+            R_ANGLE | L_PAREN | R_PAREN | L_BRACK | R_BRACK | BANG | COLON | PIPE | MINUS
+            | PLUS | STAR | DOUBLE_STAR | UNDERSCORE | DOUBLE_UNDERSCORE | HASH => true,
             _ => false,
         }
     }
     pub const fn is_literal(self) -> bool {
         match self {
             MD_HARD_LINE_LITERAL
-            | MD_SOFT_BREAK_LITERAL
             | MD_TEXTUAL_LITERAL
-            | MD_STRING_LITERAL
-            | MD_INDENT_CHUNK_LITERAL
-            | MD_THEMATIC_BREAK_LITERAL
-            | MD_ERROR_LITERAL => true,
+            | MD_INLINE_CODE_LITERAL
+            | MD_FENCE_LITERAL
+            | MD_ORDERED_LIST_MARKER_LITERAL
+            | MD_SETEXT_UNDERLINE_LITERAL
+            | MD_THEMATIC_BREAK_LITERAL => true,
             _ => false,
         }
     }
     pub const fn is_list(self) -> bool {
         match self {
-            MD_BLOCK_LIST
-            | MD_HASH_LIST
-            | MD_BULLET_LIST
-            | MD_ORDER_LIST
-            | MD_PARAGRAPH_ITEM_LIST => true,
+            MD_BLOCK_LIST | MD_HASH_LIST | MD_CODE_LINE_LIST | MD_TABLE_ROW_LIST
+            | MD_TABLE_CELL_LIST | MD_BULLET_LIST | MD_ORDER_LIST | MD_INLINE_ITEM_LIST => true,
             _ => false,
         }
     }
@@ -113,21 +115,20 @@ impl MarkdownSyntaxKind {
     }
     pub const fn to_string(&self) -> Option<&'static str> {
         let tok = match self {
-            L_ANGLE => "<",
             R_ANGLE => ">",
             L_PAREN => "(",
             R_PAREN => ")",
             L_BRACK => "[",
             R_BRACK => "]",
-            SLASH => "/",
-            EQ => "=",
             BANG => "!",
+            COLON => ":",
+            PIPE => "|",
             MINUS => "-",
+            PLUS => "+",
             STAR => "*",
-            BACKTICK => "`",
-            TILDE => "~",
-            WHITESPACE3 => "   ",
+            DOUBLE_STAR => "**",
             UNDERSCORE => "_",
+            DOUBLE_UNDERSCORE => "__",
             HASH => "#",
             NULL_KW => "null",
             _ => return None,
@@ -137,4 +138,4 @@ impl MarkdownSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; [   ] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; [_] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
+macro_rules ! T { [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [:] => { $ crate :: MarkdownSyntaxKind :: COLON } ; [|] => { $ crate :: MarkdownSyntaxKind :: PIPE } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [+] => { $ crate :: MarkdownSyntaxKind :: PLUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; [_] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; [__] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
@@ -16,6 +16,10 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::MarkdownSyntaxNode::kind(&node) {
+                $crate::MarkdownSyntaxKind::MD_BULLET => {
+                    let $pattern = unsafe { $crate::MdBullet::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_BULLET_LIST_ITEM => {
                     let $pattern = unsafe { $crate::MdBulletListItem::new_unchecked(node) };
                     $body
@@ -44,10 +48,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHtmlBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENT => {
-                    let $pattern = unsafe { $crate::MdIndent::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK => {
                     let $pattern = unsafe { $crate::MdIndentCodeBlock::new_unchecked(node) };
                     $body
@@ -64,6 +64,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_INLINE_ITALIC => {
+                    let $pattern = unsafe { $crate::MdInlineItalic::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_LINK => {
                     let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
                     $body
@@ -72,6 +76,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdLinkBlock::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_ORDER_BULLET => {
+                    let $pattern = unsafe { $crate::MdOrderBullet::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_ORDER_LIST_ITEM => {
                     let $pattern = unsafe { $crate::MdOrderListItem::new_unchecked(node) };
                     $body
@@ -88,8 +96,16 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdSetextHeader::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_SOFT_BREAK => {
-                    let $pattern = unsafe { $crate::MdSoftBreak::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE => {
+                    let $pattern = unsafe { $crate::MdTable::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL => {
+                    let $pattern = unsafe { $crate::MdTableCell::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW => {
+                    let $pattern = unsafe { $crate::MdTableRow::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_TEXTUAL => {
@@ -112,16 +128,28 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_CODE_LINE_LIST => {
+                    let $pattern = unsafe { $crate::MdCodeLineList::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_HASH_LIST => {
                     let $pattern = unsafe { $crate::MdHashList::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_INLINE_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MdInlineItemList::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_ORDER_LIST => {
                     let $pattern = unsafe { $crate::MdOrderList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_PARAGRAPH_ITEM_LIST => {
-                    let $pattern = unsafe { $crate::MdParagraphItemList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL_LIST => {
+                    let $pattern = unsafe { $crate::MdTableCellList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW_LIST => {
+                    let $pattern = unsafe { $crate::MdTableRowList::new_unchecked(node) };
                     $body
                 }
                 _ => unreachable!(),
@@ -24,6 +24,46 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdBullet {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdBullet {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdBulletFields {
+        MdBulletFields {
+            bullet: self.bullet(),
+            content: self.content(),
+        }
+    }
+    pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdBullet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdBulletFields {
+    pub bullet: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBulletListItem {
     pub(crate) syntax: SyntaxNode,
 }
@@ -38,11 +78,9 @@ impl MdBulletListItem {
         Self { syntax }
     }
     pub fn as_fields(&self) -> MdBulletListItemFields {
-        MdBulletListItemFields {
-            md_bullet_list: self.md_bullet_list(),
-        }
+        MdBulletListItemFields { list: self.list() }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
+    pub fn list(&self) -> MdBulletList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -56,7 +94,7 @@ impl Serialize for MdBulletListItem {
 }
 #[derive(Serialize)]
 pub struct MdBulletListItemFields {
-    pub md_bullet_list: MdBulletList,
+    pub list: MdBulletList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdDocument {
@@ -119,11 +157,23 @@ impl MdFencedCodeBlock {
     }
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
-            md_textual: self.md_textual(),
+            l_fence_token: self.l_fence_token(),
+            info: self.info(),
+            content: self.content(),
+            r_fence_token: self.r_fence_token(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn info(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn content(&self) -> MdCodeLineList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -136,7 +186,10 @@ impl Serialize for MdFencedCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub info: Option<MdTextual>,
+    pub content: MdCodeLineList,
+    pub r_fence_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -225,14 +278,14 @@ impl MdHeader {
     pub fn as_fields(&self) -> MdHeaderFields {
         MdHeaderFields {
             before: self.before(),
-            md_paragraph: self.md_paragraph(),
+            content: self.content(),
             after: self.after(),
         }
     }
     pub fn before(&self) -> MdHashList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn md_paragraph(&self) -> Option<MdParagraph> {
+    pub fn content(&self) -> Option<MdParagraph> {
         support::node(&self.syntax, 1usize)
     }
     pub fn after(&self) -> MdHashList {
@@ -250,7 +303,7 @@ impl Serialize for MdHeader {
 #[derive(Serialize)]
 pub struct MdHeaderFields {
     pub before: MdHashList,
-    pub md_paragraph: Option<MdParagraph>,
+    pub content: Option<MdParagraph>,
     pub after: MdHashList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -269,11 +322,11 @@ impl MdHtmlBlock {
     }
     pub fn as_fields(&self) -> MdHtmlBlockFields {
         MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdCodeLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdHtmlBlock {
@@ -286,42 +339,7 @@ impl Serialize for MdHtmlBlock {
 }
 #[derive(Serialize)]
 pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndent {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
-            value_token: self.value_token(),
-        }
-    }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-}
-impl Serialize for MdIndent {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub content: MdCodeLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdIndentCodeBlock {
@@ -339,11 +357,11 @@ impl MdIndentCodeBlock {
     }
     pub fn as_fields(&self) -> MdIndentCodeBlockFields {
         MdIndentCodeBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdCodeLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdIndentCodeBlock {
@@ -356,7 +374,7 @@ impl Serialize for MdIndentCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdIndentCodeBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+    pub content: MdCodeLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineCode {
@@ -374,11 +392,11 @@ impl MdInlineCode {
     }
     pub fn as_fields(&self) -> MdInlineCodeFields {
         MdInlineCodeFields {
-            md_textual: self.md_textual(),
+            value_token: self.value_token(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdInlineCode {
@@ -391,7 +409,7 @@ impl Serialize for MdInlineCode {
 }
 #[derive(Serialize)]
 pub struct MdInlineCodeFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineEmphasis {
@@ -409,11 +427,19 @@ impl MdInlineEmphasis {
     }
     pub fn as_fields(&self) -> MdInlineEmphasisFields {
         MdInlineEmphasisFields {
-            md_textual: self.md_textual(),
+            l_fence: self.l_fence(),
+            content: self.content(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 impl Serialize for MdInlineEmphasis {
@@ -426,7 +452,9 @@ impl Serialize for MdInlineEmphasis {
 }
 #[derive(Serialize)]
 pub struct MdInlineEmphasisFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+    pub l_fence: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineImage {
@@ -444,19 +472,35 @@ impl MdInlineImage {
     }
     pub fn as_fields(&self) -> MdInlineImageFields {
         MdInlineImageFields {
+            excl_token: self.excl_token(),
+            l_brack_token: self.l_brack_token(),
             alt: self.alt(),
-            src: self.src(),
-            title: self.title(),
+            r_brack_token: self.r_brack_token(),
+            l_paren_token: self.l_paren_token(),
+            source: self.source(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn alt(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn src(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 5usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 6usize)
     }
 }
 impl Serialize for MdInlineImage {
@@ -469,9 +513,58 @@ impl Serialize for MdInlineImage {
 }
 #[derive(Serialize)]
 pub struct MdInlineImageFields {
-    pub alt: SyntaxResult<MdTextual>,
-    pub src: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub source: MdInlineItemList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdInlineItalic {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdInlineItalic {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdInlineItalicFields {
+        MdInlineItalicFields {
+            l_fence: self.l_fence(),
+            content: self.content(),
+            r_fence: self.r_fence(),
+        }
+    }
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdInlineItalic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdInlineItalicFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineLink {
@@ -489,19 +582,31 @@ impl MdInlineLink {
     }
     pub fn as_fields(&self) -> MdInlineLinkFields {
         MdInlineLinkFields {
+            l_brack_token: self.l_brack_token(),
             label: self.label(),
-            url: self.url(),
-            title: self.title(),
+            r_brack_token: self.r_brack_token(),
+            l_paren_token: self.l_paren_token(),
+            source: self.source(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn label(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 5usize)
     }
 }
 impl Serialize for MdInlineLink {
@@ -514,9 +619,12 @@ impl Serialize for MdInlineLink {
 }
 #[derive(Serialize)]
 pub struct MdInlineLinkFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub source: MdInlineItemList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdLinkBlock {
@@ -534,19 +642,31 @@ impl MdLinkBlock {
     }
     pub fn as_fields(&self) -> MdLinkBlockFields {
         MdLinkBlockFields {
+            l_brack_token: self.l_brack_token(),
             label: self.label(),
+            r_brack_token: self.r_brack_token(),
+            colon_token: self.colon_token(),
             url: self.url(),
             title: self.title(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn label(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn url(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
+    }
+    pub fn title(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 5usize)
     }
 }
 impl Serialize for MdLinkBlock {
@@ -559,9 +679,52 @@ impl Serialize for MdLinkBlock {
 }
 #[derive(Serialize)]
 pub struct MdLinkBlockFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub url: MdInlineItemList,
+    pub title: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdOrderBullet {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdOrderBullet {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdOrderBulletFields {
+        MdOrderBulletFields {
+            marker_token: self.marker_token(),
+            content: self.content(),
+        }
+    }
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdOrderBullet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdOrderBulletFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdOrderListItem {
@@ -578,11 +741,9 @@ impl MdOrderListItem {
         Self { syntax }
     }
     pub fn as_fields(&self) -> MdOrderListItemFields {
-        MdOrderListItemFields {
-            md_bullet_list: self.md_bullet_list(),
-        }
+        MdOrderListItemFields { list: self.list() }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
+    pub fn list(&self) -> MdOrderList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -596,7 +757,7 @@ impl Serialize for MdOrderListItem {
 }
 #[derive(Serialize)]
 pub struct MdOrderListItemFields {
-    pub md_bullet_list: MdBulletList,
+    pub list: MdOrderList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdParagraph {
@@ -613,11 +774,9 @@ impl MdParagraph {
         Self { syntax }
     }
     pub fn as_fields(&self) -> MdParagraphFields {
-        MdParagraphFields {
-            md_paragraph_item_list: self.md_paragraph_item_list(),
-        }
+        MdParagraphFields { list: self.list() }
     }
-    pub fn md_paragraph_item_list(&self) -> MdParagraphItemList {
+    pub fn list(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -631,7 +790,7 @@ impl Serialize for MdParagraph {
 }
 #[derive(Serialize)]
 pub struct MdParagraphFields {
-    pub md_paragraph_item_list: MdParagraphItemList,
+    pub list: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -649,11 +808,15 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            r_angle_token: self.r_angle_token(),
+            content: self.content(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdQuote {
@@ -666,7 +829,8 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub r_angle_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdSetextHeader {
@@ -684,12 +848,16 @@ impl MdSetextHeader {
     }
     pub fn as_fields(&self) -> MdSetextHeaderFields {
         MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+            content: self.content(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
+    pub fn content(&self) -> SyntaxResult<MdParagraph> {
         support::required_node(&self.syntax, 0usize)
     }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
 }
 impl Serialize for MdSetextHeader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -701,13 +869,14 @@ impl Serialize for MdSetextHeader {
 }
 #[derive(Serialize)]
 pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+    pub content: SyntaxResult<MdParagraph>,
+    pub underline_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSoftBreak {
+pub struct MdTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSoftBreak {
+impl MdTable {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -717,16 +886,24 @@ impl MdSoftBreak {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSoftBreakFields {
-        MdSoftBreakFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdSoftBreak {
+impl Serialize for MdTable {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -735,8 +912,85 @@ impl Serialize for MdSoftBreak {
     }
 }
 #[derive(Serialize)]
-pub struct MdSoftBreakFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            content: self.content(),
+        }
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub content: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            l_pipe_token: self.l_pipe_token(),
+            cells: self.cells(),
+        }
+    }
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub cells: MdTableCellList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdTextual {
@@ -809,183 +1063,222 @@ pub struct MdThematicBreakBlockFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCodeBlock {
+pub enum AnyMdBlock {
+    AnyMdContainerBlock(AnyMdContainerBlock),
+    AnyMdLeafBlock(AnyMdLeafBlock),
+}
+impl AnyMdBlock {
+    pub fn as_any_md_container_block(&self) -> Option<&AnyMdContainerBlock> {
+        match &self {
+            AnyMdBlock::AnyMdContainerBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_md_leaf_block(&self) -> Option<&AnyMdLeafBlock> {
+        match &self {
+            AnyMdBlock::AnyMdLeafBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdCodeBlock {
     MdFencedCodeBlock(MdFencedCodeBlock),
     MdIndentCodeBlock(MdIndentCodeBlock),
 }
-impl AnyCodeBlock {
+impl AnyMdCodeBlock {
     pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
         match &self {
-            AnyCodeBlock::MdFencedCodeBlock(item) => Some(item),
+            AnyMdCodeBlock::MdFencedCodeBlock(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_indent_code_block(&self) -> Option<&MdIndentCodeBlock> {
         match &self {
-            AnyCodeBlock::MdIndentCodeBlock(item) => Some(item),
+            AnyMdCodeBlock::MdIndentCodeBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyContainerBlock {
+pub enum AnyMdContainerBlock {
     MdBulletListItem(MdBulletListItem),
     MdOrderListItem(MdOrderListItem),
     MdQuote(MdQuote),
 }
-impl AnyContainerBlock {
+impl AnyMdContainerBlock {
     pub fn as_md_bullet_list_item(&self) -> Option<&MdBulletListItem> {
         match &self {
-            AnyContainerBlock::MdBulletListItem(item) => Some(item),
+            AnyMdContainerBlock::MdBulletListItem(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_order_list_item(&self) -> Option<&MdOrderListItem> {
         match &self {
-            AnyContainerBlock::MdOrderListItem(item) => Some(item),
+            AnyMdContainerBlock::MdOrderListItem(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_quote(&self) -> Option<&MdQuote> {
         match &self {
-            AnyContainerBlock::MdQuote(item) => Some(item),
+            AnyMdContainerBlock::MdQuote(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyLeafBlock {
-    AnyCodeBlock(AnyCodeBlock),
-    MdHeader(MdHeader),
-    MdHtmlBlock(MdHtmlBlock),
-    MdLinkBlock(MdLinkBlock),
-    MdParagraph(MdParagraph),
-    MdSetextHeader(MdSetextHeader),
-    MdThematicBreakBlock(MdThematicBreakBlock),
-}
-impl AnyLeafBlock {
-    pub fn as_any_code_block(&self) -> Option<&AnyCodeBlock> {
-        match &self {
-            AnyLeafBlock::AnyCodeBlock(item) => Some(item),
+pub enum AnyMdInline {
+    MdHardLine(MdHardLine),
+    MdInlineCode(MdInlineCode),
+    MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineImage(MdInlineImage),
+    MdInlineItalic(MdInlineItalic),
+    MdInlineLink(MdInlineLink),
+    MdTextual(MdTextual),
+}
+impl AnyMdInline {
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+        match &self {
+            AnyMdInline::MdHardLine(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_header(&self) -> Option<&MdHeader> {
+    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
         match &self {
-            AnyLeafBlock::MdHeader(item) => Some(item),
+            AnyMdInline::MdInlineCode(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
         match &self {
-            AnyLeafBlock::MdHtmlBlock(item) => Some(item),
+            AnyMdInline::MdInlineEmphasis(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
+    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
         match &self {
-            AnyLeafBlock::MdLinkBlock(item) => Some(item),
+            AnyMdInline::MdInlineImage(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
+    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
         match &self {
-            AnyLeafBlock::MdParagraph(item) => Some(item),
+            AnyMdInline::MdInlineItalic(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
         match &self {
-            AnyLeafBlock::MdSetextHeader(item) => Some(item),
+            AnyMdInline::MdInlineLink(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+    pub fn as_md_textual(&self) -> Option<&MdTextual> {
         match &self {
-            AnyLeafBlock::MdThematicBreakBlock(item) => Some(item),
+            AnyMdInline::MdTextual(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdBlock {
-    AnyContainerBlock(AnyContainerBlock),
-    AnyLeafBlock(AnyLeafBlock),
+pub enum AnyMdLeafBlock {
+    AnyMdCodeBlock(AnyMdCodeBlock),
+    MdHeader(MdHeader),
+    MdHtmlBlock(MdHtmlBlock),
+    MdLinkBlock(MdLinkBlock),
+    MdParagraph(MdParagraph),
+    MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
+    MdThematicBreakBlock(MdThematicBreakBlock),
 }
-impl AnyMdBlock {
-    pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
-        match &self {
-            AnyMdBlock::AnyContainerBlock(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_any_leaf_block(&self) -> Option<&AnyLeafBlock> {
+impl AnyMdLeafBlock {
+    pub fn as_any_md_code_block(&self) -> Option<&AnyMdCodeBlock> {
         match &self {
-            AnyMdBlock::AnyLeafBlock(item) => Some(item),
+            AnyMdLeafBlock::AnyMdCodeBlock(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdInline {
-    MdHardLine(MdHardLine),
-    MdHtmlBlock(MdHtmlBlock),
-    MdInlineCode(MdInlineCode),
-    MdInlineEmphasis(MdInlineEmphasis),
-    MdInlineImage(MdInlineImage),
-    MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
-    MdTextual(MdTextual),
-}
-impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+    pub fn as_md_header(&self) -> Option<&MdHeader> {
         match &self {
-            AnyMdInline::MdHardLine(item) => Some(item),
+            AnyMdLeafBlock::MdHeader(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
         match &self {
-            AnyMdInline::MdHtmlBlock(item) => Some(item),
+            AnyMdLeafBlock::MdHtmlBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
+    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
         match &self {
-            AnyMdInline::MdInlineCode(item) => Some(item),
+            AnyMdLeafBlock::MdLinkBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
+    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
         match &self {
-            AnyMdInline::MdInlineEmphasis(item) => Some(item),
+            AnyMdLeafBlock::MdParagraph(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
+    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
         match &self {
-            AnyMdInline::MdInlineImage(item) => Some(item),
+            AnyMdLeafBlock::MdSetextHeader(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+    pub fn as_md_table(&self) -> Option<&MdTable> {
         match &self {
-            AnyMdInline::MdInlineLink(item) => Some(item),
+            AnyMdLeafBlock::MdTable(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
+    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
         match &self {
-            AnyMdInline::MdSoftBreak(item) => Some(item),
+            AnyMdLeafBlock::MdThematicBreakBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_textual(&self) -> Option<&MdTextual> {
-        match &self {
-            AnyMdInline::MdTextual(item) => Some(item),
-            _ => None,
+}
+impl AstNode for MdBullet {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_BULLET
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdBullet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdBullet")
+            .field("bullet", &support::DebugSyntaxResult(self.bullet()))
+            .field("content", &self.content())
+            .finish()
+    }
+}
+impl From<MdBullet> for SyntaxNode {
+    fn from(n: MdBullet) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdBullet> for SyntaxElement {
+    fn from(n: MdBullet) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
 impl AstNode for MdBulletListItem {
     type Language = Language;
@@ -1011,7 +1304,7 @@ impl AstNode for MdBulletListItem {
 impl std::fmt::Debug for MdBulletListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdBulletListItem")
-            .field("md_bullet_list", &self.md_bullet_list())
+            .field("list", &self.list())
             .finish()
     }
 }
@@ -1092,7 +1385,16 @@ impl AstNode for MdFencedCodeBlock {
 impl std::fmt::Debug for MdFencedCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdFencedCodeBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            .field(
+                "l_fence_token",
+                &support::DebugSyntaxResult(self.l_fence_token()),
+            )
+            .field("info", &support::DebugOptionalElement(self.info()))
+            .field("content", &self.content())
+            .field(
+                "r_fence_token",
+                &support::DebugOptionalElement(self.r_fence_token()),
+            )
             .finish()
     }
 }
@@ -1210,10 +1512,7 @@ impl std::fmt::Debug for MdHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdHeader")
             .field("before", &self.before())
-            .field(
-                "md_paragraph",
-                &support::DebugOptionalElement(self.md_paragraph()),
-            )
+            .field("content", &support::DebugOptionalElement(self.content()))
             .field("after", &self.after())
             .finish()
     }
@@ -1252,7 +1551,7 @@ impl AstNode for MdHtmlBlock {
 impl std::fmt::Debug for MdHtmlBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdHtmlBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            .field("content", &self.content())
             .finish()
     }
 }
@@ -1266,47 +1565,6 @@ impl From<MdHtmlBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdIndent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdIndent")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .finish()
-    }
-}
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> SyntaxNode {
-        n.syntax
-    }
-}
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> SyntaxElement {
-        n.syntax.into()
-    }
-}
 impl AstNode for MdIndentCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1331,7 +1589,7 @@ impl AstNode for MdIndentCodeBlock {
 impl std::fmt::Debug for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdIndentCodeBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            .field("content", &self.content())
             .finish()
     }
 }
@@ -1369,7 +1627,10 @@ impl AstNode for MdInlineCode {
 impl std::fmt::Debug for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdInlineCode")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
@@ -1407,7 +1668,9 @@ impl AstNode for MdInlineEmphasis {
 impl std::fmt::Debug for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdInlineEmphasis")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+            .field("content", &self.content())
+            .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
             .finish()
     }
 }
@@ -1445,9 +1708,25 @@ impl AstNode for MdInlineImage {
 impl std::fmt::Debug for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdInlineImage")
-            .field("alt", &support::DebugSyntaxResult(self.alt()))
-            .field("src", &support::DebugSyntaxResult(self.src()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("alt", &self.alt())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("source", &self.source())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
@@ -1461,6 +1740,46 @@ impl From<MdInlineImage> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for MdInlineItalic {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INLINE_ITALIC
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdInlineItalic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdInlineItalic")
+            .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+            .field("content", &self.content())
+            .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
+            .finish()
+    }
+}
+impl From<MdInlineItalic> for SyntaxNode {
+    fn from(n: MdInlineItalic) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdInlineItalic> for SyntaxElement {
+    fn from(n: MdInlineItalic) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdInlineLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1485,9 +1804,24 @@ impl AstNode for MdInlineLink {
 impl std::fmt::Debug for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdInlineLink")
-            .field("label", &support::DebugSyntaxResult(self.label()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("label", &self.label())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("source", &self.source())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
@@ -1525,9 +1859,21 @@ impl AstNode for MdLinkBlock {
 impl std::fmt::Debug for MdLinkBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdLinkBlock")
-            .field("label", &support::DebugSyntaxResult(self.label()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("label", &self.label())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("url", &self.url())
+            .field("title", &self.title())
             .finish()
     }
 }
@@ -1541,6 +1887,48 @@ impl From<MdLinkBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for MdOrderBullet {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_BULLET as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_ORDER_BULLET
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdOrderBullet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdOrderBullet")
+            .field(
+                "marker_token",
+                &support::DebugSyntaxResult(self.marker_token()),
+            )
+            .field("content", &self.content())
+            .finish()
+    }
+}
+impl From<MdOrderBullet> for SyntaxNode {
+    fn from(n: MdOrderBullet) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdOrderBullet> for SyntaxElement {
+    fn from(n: MdOrderBullet) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdOrderListItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1565,7 +1953,7 @@ impl AstNode for MdOrderListItem {
 impl std::fmt::Debug for MdOrderListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdOrderListItem")
-            .field("md_bullet_list", &self.md_bullet_list())
+            .field("list", &self.list())
             .finish()
     }
 }
@@ -1603,7 +1991,7 @@ impl AstNode for MdParagraph {
 impl std::fmt::Debug for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdParagraph")
-            .field("md_paragraph_item_list", &self.md_paragraph_item_list())
+            .field("list", &self.list())
             .finish()
     }
 }
@@ -1642,9 +2030,10 @@ impl std::fmt::Debug for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdQuote")
             .field(
-                "any_md_block",
-                &support::DebugSyntaxResult(self.any_md_block()),
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
             )
+            .field("content", &self.content())
             .finish()
     }
 }
@@ -1682,9 +2071,10 @@ impl AstNode for MdSetextHeader {
 impl std::fmt::Debug for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdSetextHeader")
+            .field("content", &support::DebugSyntaxResult(self.content()))
             .field(
-                "md_paragraph",
-                &support::DebugSyntaxResult(self.md_paragraph()),
+                "underline_token",
+                &support::DebugSyntaxResult(self.underline_token()),
             )
             .finish()
     }
@@ -1699,12 +2089,12 @@ impl From<MdSetextHeader> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdSoftBreak {
+impl AstNode for MdTable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SOFT_BREAK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SOFT_BREAK
+        kind == MD_TABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1720,32 +2110,31 @@ impl AstNode for MdSoftBreak {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSoftBreak {
+impl std::fmt::Debug for MdTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdSoftBreak")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("MdTable")
+            .field("header", &support::DebugSyntaxResult(self.header()))
+            .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+            .field("rows", &self.rows())
             .finish()
     }
 }
-impl From<MdSoftBreak> for SyntaxNode {
-    fn from(n: MdSoftBreak) -> SyntaxNode {
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdSoftBreak> for SyntaxElement {
-    fn from(n: MdSoftBreak) -> SyntaxElement {
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdTextual {
+impl AstNode for MdTableCell {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TEXTUAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_TEXTUAL
+        kind == MD_TABLE_CELL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1761,32 +2150,29 @@ impl AstNode for MdTextual {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdTextual {
+impl std::fmt::Debug for MdTableCell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdTextual")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("MdTableCell")
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdTextual> for SyntaxNode {
-    fn from(n: MdTextual) -> SyntaxNode {
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdTextual> for SyntaxElement {
-    fn from(n: MdTextual) -> SyntaxElement {
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdThematicBreakBlock {
+impl AstNode for MdTableRow {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_THEMATIC_BREAK_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_THEMATIC_BREAK_BLOCK
+        kind == MD_TABLE_ROW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1802,336 +2188,296 @@ impl AstNode for MdThematicBreakBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdThematicBreakBlock {
+impl std::fmt::Debug for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdThematicBreakBlock")
+        f.debug_struct("MdTableRow")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_pipe_token",
+                &support::DebugOptionalElement(self.l_pipe_token()),
             )
+            .field("cells", &self.cells())
             .finish()
     }
 }
-impl From<MdThematicBreakBlock> for SyntaxNode {
-    fn from(n: MdThematicBreakBlock) -> SyntaxNode {
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdThematicBreakBlock> for SyntaxElement {
-    fn from(n: MdThematicBreakBlock) -> SyntaxElement {
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl From<MdFencedCodeBlock> for AnyCodeBlock {
-    fn from(node: MdFencedCodeBlock) -> AnyCodeBlock {
-        AnyCodeBlock::MdFencedCodeBlock(node)
-    }
-}
-impl From<MdIndentCodeBlock> for AnyCodeBlock {
-    fn from(node: MdIndentCodeBlock) -> AnyCodeBlock {
-        AnyCodeBlock::MdIndentCodeBlock(node)
-    }
-}
-impl AstNode for AnyCodeBlock {
+impl AstNode for MdTextual {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TEXTUAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_FENCED_CODE_BLOCK | MD_INDENT_CODE_BLOCK)
+        kind == MD_TEXTUAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_FENCED_CODE_BLOCK => AnyCodeBlock::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
-            MD_INDENT_CODE_BLOCK => AnyCodeBlock::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
-            _ => return None,
-        };
-        Some(res)
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
     }
     fn syntax(&self) -> &SyntaxNode {
-        match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => &it.syntax,
-            AnyCodeBlock::MdIndentCodeBlock(it) => &it.syntax,
-        }
+        &self.syntax
     }
     fn into_syntax(self) -> SyntaxNode {
-        match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.syntax,
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.syntax,
-        }
+        self.syntax
     }
 }
-impl std::fmt::Debug for AnyCodeBlock {
+impl std::fmt::Debug for MdTextual {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyCodeBlock::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
+        f.debug_struct("MdTextual")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
     }
 }
-impl From<AnyCodeBlock> for SyntaxNode {
-    fn from(n: AnyCodeBlock) -> SyntaxNode {
-        match n {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.into(),
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.into(),
-        }
+impl From<MdTextual> for SyntaxNode {
+    fn from(n: MdTextual) -> SyntaxNode {
+        n.syntax
     }
 }
-impl From<AnyCodeBlock> for SyntaxElement {
-    fn from(n: AnyCodeBlock) -> SyntaxElement {
-        let node: SyntaxNode = n.into();
-        node.into()
+impl From<MdTextual> for SyntaxElement {
+    fn from(n: MdTextual) -> SyntaxElement {
+        n.syntax.into()
     }
 }
-impl From<MdBulletListItem> for AnyContainerBlock {
-    fn from(node: MdBulletListItem) -> AnyContainerBlock {
-        AnyContainerBlock::MdBulletListItem(node)
+impl AstNode for MdThematicBreakBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_THEMATIC_BREAK_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_THEMATIC_BREAK_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-impl From<MdOrderListItem> for AnyContainerBlock {
-    fn from(node: MdOrderListItem) -> AnyContainerBlock {
-        AnyContainerBlock::MdOrderListItem(node)
+impl std::fmt::Debug for MdThematicBreakBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdThematicBreakBlock")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MdThematicBreakBlock> for SyntaxNode {
+    fn from(n: MdThematicBreakBlock) -> SyntaxNode {
+        n.syntax
     }
 }
-impl From<MdQuote> for AnyContainerBlock {
-    fn from(node: MdQuote) -> AnyContainerBlock {
-        AnyContainerBlock::MdQuote(node)
+impl From<MdThematicBreakBlock> for SyntaxElement {
+    fn from(n: MdThematicBreakBlock) -> SyntaxElement {
+        n.syntax.into()
     }
 }
-impl AstNode for AnyContainerBlock {
+impl AstNode for AnyMdBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
-        .union(MdOrderListItem::KIND_SET)
-        .union(MdQuote::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> =
+        AnyMdContainerBlock::KIND_SET.union(AnyMdLeafBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_BULLET_LIST_ITEM | MD_ORDER_LIST_ITEM | MD_QUOTE)
+        match kind {
+            k if AnyMdContainerBlock::can_cast(k) => true,
+            k if AnyMdLeafBlock::can_cast(k) => true,
+            _ => false,
+        }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_BULLET_LIST_ITEM => AnyContainerBlock::MdBulletListItem(MdBulletListItem { syntax }),
-            MD_ORDER_LIST_ITEM => AnyContainerBlock::MdOrderListItem(MdOrderListItem { syntax }),
-            MD_QUOTE => AnyContainerBlock::MdQuote(MdQuote { syntax }),
-            _ => return None,
+        let syntax = match AnyMdContainerBlock::try_cast(syntax) {
+            Ok(any_md_container_block) => {
+                return Some(AnyMdBlock::AnyMdContainerBlock(any_md_container_block));
+            }
+            Err(syntax) => syntax,
         };
-        Some(res)
+        if let Some(any_md_leaf_block) = AnyMdLeafBlock::cast(syntax) {
+            return Some(AnyMdBlock::AnyMdLeafBlock(any_md_leaf_block));
+        }
+        None
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => &it.syntax,
-            AnyContainerBlock::MdOrderListItem(it) => &it.syntax,
-            AnyContainerBlock::MdQuote(it) => &it.syntax,
+            AnyMdBlock::AnyMdContainerBlock(it) => it.syntax(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => it.syntax,
-            AnyContainerBlock::MdOrderListItem(it) => it.syntax,
-            AnyContainerBlock::MdQuote(it) => it.syntax,
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into_syntax(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into_syntax(),
         }
     }
 }
-impl std::fmt::Debug for AnyContainerBlock {
+impl std::fmt::Debug for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
-            AnyContainerBlock::MdOrderListItem(it) => std::fmt::Debug::fmt(it, f),
-            AnyContainerBlock::MdQuote(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdBlock::AnyMdContainerBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdBlock::AnyMdLeafBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxNode {
-    fn from(n: AnyContainerBlock) -> SyntaxNode {
+impl From<AnyMdBlock> for SyntaxNode {
+    fn from(n: AnyMdBlock) -> SyntaxNode {
         match n {
-            AnyContainerBlock::MdBulletListItem(it) => it.into(),
-            AnyContainerBlock::MdOrderListItem(it) => it.into(),
-            AnyContainerBlock::MdQuote(it) => it.into(),
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxElement {
-    fn from(n: AnyContainerBlock) -> SyntaxElement {
+impl From<AnyMdBlock> for SyntaxElement {
+    fn from(n: AnyMdBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdHeader> for AnyLeafBlock {
-    fn from(node: MdHeader) -> AnyLeafBlock {
-        AnyLeafBlock::MdHeader(node)
-    }
-}
-impl From<MdHtmlBlock> for AnyLeafBlock {
-    fn from(node: MdHtmlBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdHtmlBlock(node)
+impl From<MdFencedCodeBlock> for AnyMdCodeBlock {
+    fn from(node: MdFencedCodeBlock) -> AnyMdCodeBlock {
+        AnyMdCodeBlock::MdFencedCodeBlock(node)
     }
 }
-impl From<MdLinkBlock> for AnyLeafBlock {
-    fn from(node: MdLinkBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdLinkBlock(node)
+impl From<MdIndentCodeBlock> for AnyMdCodeBlock {
+    fn from(node: MdIndentCodeBlock) -> AnyMdCodeBlock {
+        AnyMdCodeBlock::MdIndentCodeBlock(node)
     }
 }
-impl From<MdParagraph> for AnyLeafBlock {
-    fn from(node: MdParagraph) -> AnyLeafBlock {
-        AnyLeafBlock::MdParagraph(node)
-    }
-}
-impl From<MdSetextHeader> for AnyLeafBlock {
-    fn from(node: MdSetextHeader) -> AnyLeafBlock {
-        AnyLeafBlock::MdSetextHeader(node)
-    }
-}
-impl From<MdThematicBreakBlock> for AnyLeafBlock {
-    fn from(node: MdThematicBreakBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdThematicBreakBlock(node)
-    }
-}
-impl AstNode for AnyLeafBlock {
+impl AstNode for AnyMdCodeBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCodeBlock::KIND_SET
-        .union(MdHeader::KIND_SET)
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdLinkBlock::KIND_SET)
-        .union(MdParagraph::KIND_SET)
-        .union(MdSetextHeader::KIND_SET)
-        .union(MdThematicBreakBlock::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> =
+        MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            MD_HEADER
-            | MD_HTML_BLOCK
-            | MD_LINK_BLOCK
-            | MD_PARAGRAPH
-            | MD_SETEXT_HEADER
-            | MD_THEMATIC_BREAK_BLOCK => true,
-            k if AnyCodeBlock::can_cast(k) => true,
-            _ => false,
-        }
+        matches!(kind, MD_FENCED_CODE_BLOCK | MD_INDENT_CODE_BLOCK)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_HEADER => AnyLeafBlock::MdHeader(MdHeader { syntax }),
-            MD_HTML_BLOCK => AnyLeafBlock::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_LINK_BLOCK => AnyLeafBlock::MdLinkBlock(MdLinkBlock { syntax }),
-            MD_PARAGRAPH => AnyLeafBlock::MdParagraph(MdParagraph { syntax }),
-            MD_SETEXT_HEADER => AnyLeafBlock::MdSetextHeader(MdSetextHeader { syntax }),
-            MD_THEMATIC_BREAK_BLOCK => {
-                AnyLeafBlock::MdThematicBreakBlock(MdThematicBreakBlock { syntax })
-            }
-            _ => {
-                if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
-                    return Some(AnyLeafBlock::AnyCodeBlock(any_code_block));
-                }
-                return None;
-            }
+            MD_FENCED_CODE_BLOCK => AnyMdCodeBlock::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
+            MD_INDENT_CODE_BLOCK => AnyMdCodeBlock::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
+            _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyLeafBlock::MdHeader(it) => &it.syntax,
-            AnyLeafBlock::MdHtmlBlock(it) => &it.syntax,
-            AnyLeafBlock::MdLinkBlock(it) => &it.syntax,
-            AnyLeafBlock::MdParagraph(it) => &it.syntax,
-            AnyLeafBlock::MdSetextHeader(it) => &it.syntax,
-            AnyLeafBlock::MdThematicBreakBlock(it) => &it.syntax,
-            AnyLeafBlock::AnyCodeBlock(it) => it.syntax(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => &it.syntax,
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyLeafBlock::MdHeader(it) => it.syntax,
-            AnyLeafBlock::MdHtmlBlock(it) => it.syntax,
-            AnyLeafBlock::MdLinkBlock(it) => it.syntax,
-            AnyLeafBlock::MdParagraph(it) => it.syntax,
-            AnyLeafBlock::MdSetextHeader(it) => it.syntax,
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.syntax,
-            AnyLeafBlock::AnyCodeBlock(it) => it.into_syntax(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.syntax,
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyLeafBlock {
+impl std::fmt::Debug for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyLeafBlock::AnyCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdHeader(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyLeafBlock> for SyntaxNode {
-    fn from(n: AnyLeafBlock) -> SyntaxNode {
+impl From<AnyMdCodeBlock> for SyntaxNode {
+    fn from(n: AnyMdCodeBlock) -> SyntaxNode {
         match n {
-            AnyLeafBlock::AnyCodeBlock(it) => it.into(),
-            AnyLeafBlock::MdHeader(it) => it.into(),
-            AnyLeafBlock::MdHtmlBlock(it) => it.into(),
-            AnyLeafBlock::MdLinkBlock(it) => it.into(),
-            AnyLeafBlock::MdParagraph(it) => it.into(),
-            AnyLeafBlock::MdSetextHeader(it) => it.into(),
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.into(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyLeafBlock> for SyntaxElement {
-    fn from(n: AnyLeafBlock) -> SyntaxElement {
+impl From<AnyMdCodeBlock> for SyntaxElement {
+    fn from(n: AnyMdCodeBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl AstNode for AnyMdBlock {
+impl From<MdBulletListItem> for AnyMdContainerBlock {
+    fn from(node: MdBulletListItem) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdBulletListItem(node)
+    }
+}
+impl From<MdOrderListItem> for AnyMdContainerBlock {
+    fn from(node: MdOrderListItem) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdOrderListItem(node)
+    }
+}
+impl From<MdQuote> for AnyMdContainerBlock {
+    fn from(node: MdQuote) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdQuote(node)
+    }
+}
+impl AstNode for AnyMdContainerBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
+        .union(MdOrderListItem::KIND_SET)
+        .union(MdQuote::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            k if AnyContainerBlock::can_cast(k) => true,
-            k if AnyLeafBlock::can_cast(k) => true,
-            _ => false,
-        }
+        matches!(kind, MD_BULLET_LIST_ITEM | MD_ORDER_LIST_ITEM | MD_QUOTE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(AnyMdBlock::AnyContainerBlock(any_container_block));
+        let res = match syntax.kind() {
+            MD_BULLET_LIST_ITEM => {
+                AnyMdContainerBlock::MdBulletListItem(MdBulletListItem { syntax })
             }
-            Err(syntax) => syntax,
+            MD_ORDER_LIST_ITEM => AnyMdContainerBlock::MdOrderListItem(MdOrderListItem { syntax }),
+            MD_QUOTE => AnyMdContainerBlock::MdQuote(MdQuote { syntax }),
+            _ => return None,
         };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(AnyMdBlock::AnyLeafBlock(any_leaf_block));
-        }
-        None
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyMdBlock::AnyContainerBlock(it) => it.syntax(),
-            AnyMdBlock::AnyLeafBlock(it) => it.syntax(),
+            AnyMdContainerBlock::MdBulletListItem(it) => &it.syntax,
+            AnyMdContainerBlock::MdOrderListItem(it) => &it.syntax,
+            AnyMdContainerBlock::MdQuote(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyMdBlock::AnyContainerBlock(it) => it.into_syntax(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into_syntax(),
+            AnyMdContainerBlock::MdBulletListItem(it) => it.syntax,
+            AnyMdContainerBlock::MdOrderListItem(it) => it.syntax,
+            AnyMdContainerBlock::MdQuote(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyMdBlock {
+impl std::fmt::Debug for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyMdBlock::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdBlock::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdOrderListItem(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdQuote(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyMdBlock> for SyntaxNode {
-    fn from(n: AnyMdBlock) -> SyntaxNode {
+impl From<AnyMdContainerBlock> for SyntaxNode {
+    fn from(n: AnyMdContainerBlock) -> SyntaxNode {
         match n {
-            AnyMdBlock::AnyContainerBlock(it) => it.into(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into(),
+            AnyMdContainerBlock::MdBulletListItem(it) => it.into(),
+            AnyMdContainerBlock::MdOrderListItem(it) => it.into(),
+            AnyMdContainerBlock::MdQuote(it) => it.into(),
         }
     }
 }
-impl From<AnyMdBlock> for SyntaxElement {
-    fn from(n: AnyMdBlock) -> SyntaxElement {
+impl From<AnyMdContainerBlock> for SyntaxElement {
+    fn from(n: AnyMdContainerBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
@@ -2141,11 +2487,6 @@ impl From<MdHardLine> for AnyMdInline {
         AnyMdInline::MdHardLine(node)
     }
 }
-impl From<MdHtmlBlock> for AnyMdInline {
-    fn from(node: MdHtmlBlock) -> AnyMdInline {
-        AnyMdInline::MdHtmlBlock(node)
-    }
-}
 impl From<MdInlineCode> for AnyMdInline {
     fn from(node: MdInlineCode) -> AnyMdInline {
         AnyMdInline::MdInlineCode(node)
@@ -2161,16 +2502,16 @@ impl From<MdInlineImage> for AnyMdInline {
         AnyMdInline::MdInlineImage(node)
     }
 }
+impl From<MdInlineItalic> for AnyMdInline {
+    fn from(node: MdInlineItalic) -> AnyMdInline {
+        AnyMdInline::MdInlineItalic(node)
+    }
+}
 impl From<MdInlineLink> for AnyMdInline {
     fn from(node: MdInlineLink) -> AnyMdInline {
         AnyMdInline::MdInlineLink(node)
     }
 }
-impl From<MdSoftBreak> for AnyMdInline {
-    fn from(node: MdSoftBreak) -> AnyMdInline {
-        AnyMdInline::MdSoftBreak(node)
-    }
-}
 impl From<MdTextual> for AnyMdInline {
     fn from(node: MdTextual) -> AnyMdInline {
         AnyMdInline::MdTextual(node)
@@ -2179,35 +2520,32 @@ impl From<MdTextual> for AnyMdInline {
 impl AstNode for AnyMdInline {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = MdHardLine::KIND_SET
-        .union(MdHtmlBlock::KIND_SET)
         .union(MdInlineCode::KIND_SET)
         .union(MdInlineEmphasis::KIND_SET)
         .union(MdInlineImage::KIND_SET)
+        .union(MdInlineItalic::KIND_SET)
         .union(MdInlineLink::KIND_SET)
-        .union(MdSoftBreak::KIND_SET)
         .union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
             MD_HARD_LINE
-                | MD_HTML_BLOCK
                 | MD_INLINE_CODE
                 | MD_INLINE_EMPHASIS
                 | MD_INLINE_IMAGE
+                | MD_INLINE_ITALIC
                 | MD_INLINE_LINK
-                | MD_SOFT_BREAK
                 | MD_TEXTUAL
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             MD_HARD_LINE => AnyMdInline::MdHardLine(MdHardLine { syntax }),
-            MD_HTML_BLOCK => AnyMdInline::MdHtmlBlock(MdHtmlBlock { syntax }),
             MD_INLINE_CODE => AnyMdInline::MdInlineCode(MdInlineCode { syntax }),
             MD_INLINE_EMPHASIS => AnyMdInline::MdInlineEmphasis(MdInlineEmphasis { syntax }),
             MD_INLINE_IMAGE => AnyMdInline::MdInlineImage(MdInlineImage { syntax }),
+            MD_INLINE_ITALIC => AnyMdInline::MdInlineItalic(MdInlineItalic { syntax }),
             MD_INLINE_LINK => AnyMdInline::MdInlineLink(MdInlineLink { syntax }),
-            MD_SOFT_BREAK => AnyMdInline::MdSoftBreak(MdSoftBreak { syntax }),
             MD_TEXTUAL => AnyMdInline::MdTextual(MdTextual { syntax }),
             _ => return None,
         };
@@ -2216,24 +2554,22 @@ impl AstNode for AnyMdInline {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             AnyMdInline::MdHardLine(it) => &it.syntax,
-            AnyMdInline::MdHtmlBlock(it) => &it.syntax,
             AnyMdInline::MdInlineCode(it) => &it.syntax,
             AnyMdInline::MdInlineEmphasis(it) => &it.syntax,
             AnyMdInline::MdInlineImage(it) => &it.syntax,
+            AnyMdInline::MdInlineItalic(it) => &it.syntax,
             AnyMdInline::MdInlineLink(it) => &it.syntax,
-            AnyMdInline::MdSoftBreak(it) => &it.syntax,
             AnyMdInline::MdTextual(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyMdInline::MdHardLine(it) => it.syntax,
-            AnyMdInline::MdHtmlBlock(it) => it.syntax,
             AnyMdInline::MdInlineCode(it) => it.syntax,
             AnyMdInline::MdInlineEmphasis(it) => it.syntax,
             AnyMdInline::MdInlineImage(it) => it.syntax,
+            AnyMdInline::MdInlineItalic(it) => it.syntax,
             AnyMdInline::MdInlineLink(it) => it.syntax,
-            AnyMdInline::MdSoftBreak(it) => it.syntax,
             AnyMdInline::MdTextual(it) => it.syntax,
         }
     }
@@ -2242,12 +2578,11 @@ impl std::fmt::Debug for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AnyMdInline::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdInline::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdTextual(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -2256,12 +2591,11 @@ impl From<AnyMdInline> for SyntaxNode {
     fn from(n: AnyMdInline) -> SyntaxNode {
         match n {
             AnyMdInline::MdHardLine(it) => it.into(),
-            AnyMdInline::MdHtmlBlock(it) => it.into(),
             AnyMdInline::MdInlineCode(it) => it.into(),
             AnyMdInline::MdInlineEmphasis(it) => it.into(),
             AnyMdInline::MdInlineImage(it) => it.into(),
+            AnyMdInline::MdInlineItalic(it) => it.into(),
             AnyMdInline::MdInlineLink(it) => it.into(),
-            AnyMdInline::MdSoftBreak(it) => it.into(),
             AnyMdInline::MdTextual(it) => it.into(),
         }
     }
@@ -2272,27 +2606,169 @@ impl From<AnyMdInline> for SyntaxElement {
         node.into()
     }
 }
-impl std::fmt::Display for AnyCodeBlock {
+impl From<MdHeader> for AnyMdLeafBlock {
+    fn from(node: MdHeader) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdHeader(node)
+    }
+}
+impl From<MdHtmlBlock> for AnyMdLeafBlock {
+    fn from(node: MdHtmlBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdHtmlBlock(node)
+    }
+}
+impl From<MdLinkBlock> for AnyMdLeafBlock {
+    fn from(node: MdLinkBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdLinkBlock(node)
+    }
+}
+impl From<MdParagraph> for AnyMdLeafBlock {
+    fn from(node: MdParagraph) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdParagraph(node)
+    }
+}
+impl From<MdSetextHeader> for AnyMdLeafBlock {
+    fn from(node: MdSetextHeader) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdSetextHeader(node)
+    }
+}
+impl From<MdTable> for AnyMdLeafBlock {
+    fn from(node: MdTable) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdTable(node)
+    }
+}
+impl From<MdThematicBreakBlock> for AnyMdLeafBlock {
+    fn from(node: MdThematicBreakBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdThematicBreakBlock(node)
+    }
+}
+impl AstNode for AnyMdLeafBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = AnyMdCodeBlock::KIND_SET
+        .union(MdHeader::KIND_SET)
+        .union(MdHtmlBlock::KIND_SET)
+        .union(MdLinkBlock::KIND_SET)
+        .union(MdParagraph::KIND_SET)
+        .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
+        .union(MdThematicBreakBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            MD_HEADER
+            | MD_HTML_BLOCK
+            | MD_LINK_BLOCK
+            | MD_PARAGRAPH
+            | MD_SETEXT_HEADER
+            | MD_TABLE
+            | MD_THEMATIC_BREAK_BLOCK => true,
+            k if AnyMdCodeBlock::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            MD_HEADER => AnyMdLeafBlock::MdHeader(MdHeader { syntax }),
+            MD_HTML_BLOCK => AnyMdLeafBlock::MdHtmlBlock(MdHtmlBlock { syntax }),
+            MD_LINK_BLOCK => AnyMdLeafBlock::MdLinkBlock(MdLinkBlock { syntax }),
+            MD_PARAGRAPH => AnyMdLeafBlock::MdParagraph(MdParagraph { syntax }),
+            MD_SETEXT_HEADER => AnyMdLeafBlock::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => AnyMdLeafBlock::MdTable(MdTable { syntax }),
+            MD_THEMATIC_BREAK_BLOCK => {
+                AnyMdLeafBlock::MdThematicBreakBlock(MdThematicBreakBlock { syntax })
+            }
+            _ => {
+                if let Some(any_md_code_block) = AnyMdCodeBlock::cast(syntax) {
+                    return Some(AnyMdLeafBlock::AnyMdCodeBlock(any_md_code_block));
+                }
+                return None;
+            }
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyMdLeafBlock::MdHeader(it) => &it.syntax,
+            AnyMdLeafBlock::MdHtmlBlock(it) => &it.syntax,
+            AnyMdLeafBlock::MdLinkBlock(it) => &it.syntax,
+            AnyMdLeafBlock::MdParagraph(it) => &it.syntax,
+            AnyMdLeafBlock::MdSetextHeader(it) => &it.syntax,
+            AnyMdLeafBlock::MdTable(it) => &it.syntax,
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => &it.syntax,
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.syntax(),
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyMdLeafBlock::MdHeader(it) => it.syntax,
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.syntax,
+            AnyMdLeafBlock::MdLinkBlock(it) => it.syntax,
+            AnyMdLeafBlock::MdParagraph(it) => it.syntax,
+            AnyMdLeafBlock::MdSetextHeader(it) => it.syntax,
+            AnyMdLeafBlock::MdTable(it) => it.syntax,
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.syntax,
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into_syntax(),
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdLeafBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdHeader(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdTable(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdLeafBlock> for SyntaxNode {
+    fn from(n: AnyMdLeafBlock) -> SyntaxNode {
+        match n {
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdHeader(it) => it.into(),
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.into(),
+            AnyMdLeafBlock::MdLinkBlock(it) => it.into(),
+            AnyMdLeafBlock::MdParagraph(it) => it.into(),
+            AnyMdLeafBlock::MdSetextHeader(it) => it.into(),
+            AnyMdLeafBlock::MdTable(it) => it.into(),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMdLeafBlock> for SyntaxElement {
+    fn from(n: AnyMdLeafBlock) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyContainerBlock {
+impl std::fmt::Display for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyLeafBlock {
+impl std::fmt::Display for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdBlock {
+impl std::fmt::Display for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdInline {
+impl std::fmt::Display for AnyMdLeafBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdBullet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2332,11 +2808,6 @@ impl std::fmt::Display for MdHtmlBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
 impl std::fmt::Display for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2357,6 +2828,11 @@ impl std::fmt::Display for MdInlineImage {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MdInlineItalic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2367,6 +2843,11 @@ impl std::fmt::Display for MdLinkBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MdOrderBullet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MdOrderListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -2387,7 +2868,17 @@ impl std::fmt::Display for MdSetextHeader {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSoftBreak {
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2594,7 +3085,7 @@ impl Serialize for MdBulletList {
 }
 impl AstNodeList for MdBulletList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdBullet;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2609,15 +3100,97 @@ impl Debug for MdBulletList {
     }
 }
 impl IntoIterator for &MdBulletList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+    type Item = MdBullet;
+    type IntoIter = AstNodeListIterator<Language, MdBullet>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for MdBulletList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+    type Item = MdBullet;
+    type IntoIter = AstNodeListIterator<Language, MdBullet>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdCodeLineList {
+    syntax_list: SyntaxList,
+}
+impl MdCodeLineList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdCodeLineList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_CODE_LINE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_CODE_LINE_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MdCodeLineList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MdCodeLineList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdCodeLineList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdCodeLineList {
+    type Language = Language;
+    type Node = MdTextual;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdCodeLineList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdCodeLineList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MdCodeLineList {
+    type Item = MdTextual;
+    type IntoIter = AstNodeListIterator<Language, MdTextual>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MdCodeLineList {
+    type Item = MdTextual;
+    type IntoIter = AstNodeListIterator<Language, MdTextual>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -2705,6 +3278,88 @@ impl IntoIterator for MdHashList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdInlineItemList {
+    syntax_list: SyntaxList,
+}
+impl MdInlineItemList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdInlineItemList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_INLINE_ITEM_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MdInlineItemList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MdInlineItemList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdInlineItemList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdInlineItemList {
+    type Language = Language;
+    type Node = AnyMdInline;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdInlineItemList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdInlineItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct MdOrderList {
     syntax_list: SyntaxList,
 }
@@ -2758,7 +3413,7 @@ impl Serialize for MdOrderList {
 }
 impl AstNodeList for MdOrderList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdOrderBullet;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2773,24 +3428,24 @@ impl Debug for MdOrderList {
     }
 }
 impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+    type Item = MdOrderBullet;
+    type IntoIter = AstNodeListIterator<Language, MdOrderBullet>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+    type Item = MdOrderBullet;
+    type IntoIter = AstNodeListIterator<Language, MdOrderBullet>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdParagraphItemList {
+pub struct MdTableCellList {
     syntax_list: SyntaxList,
 }
-impl MdParagraphItemList {
+impl MdTableCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -2803,16 +3458,16 @@ impl MdParagraphItemList {
         }
     }
 }
-impl AstNode for MdParagraphItemList {
+impl AstNode for MdTableCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_PARAGRAPH_ITEM_LIST
+        kind == MD_TABLE_CELL_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<MdParagraphItemList> {
+    fn cast(syntax: SyntaxNode) -> Option<MdTableCellList> {
         if Self::can_cast(syntax.kind()) {
-            Some(MdParagraphItemList {
+            Some(MdTableCellList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -2826,7 +3481,7 @@ impl AstNode for MdParagraphItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdParagraphItemList {
+impl Serialize for MdTableCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -2838,9 +3493,91 @@ impl Serialize for MdParagraphItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdParagraphItemList {
+impl AstSeparatedList for MdTableCellList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdTableCell;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdTableCellList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdTableCellList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdTableRowList {
+    syntax_list: SyntaxList,
+}
+impl MdTableRowList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdTableRowList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MdTableRowList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MdTableRowList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdTableRowList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdTableRowList {
+    type Language = Language;
+    type Node = MdTableRow;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2848,22 +3585,22 @@ impl AstNodeList for MdParagraphItemList {
         self.syntax_list
     }
 }
-impl Debug for MdParagraphItemList {
+impl Debug for MdTableRowList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdParagraphItemList ")?;
+        f.write_str("MdTableRowList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdParagraphItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdParagraphItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
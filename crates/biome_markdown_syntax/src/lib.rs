@@ -1,9 +1,12 @@
+pub mod code_block_ext;
+mod file_source;
 #[macro_use]
 mod generated;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::{RawSyntaxKind, SyntaxKind, TriviaPieceKind};
+pub use file_source::MarkdownFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for MarkdownSyntaxKind {
@@ -35,7 +38,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_root(&self) -> bool {
-        todo!()
+        matches!(self, MarkdownSyntaxKind::MD_DOCUMENT)
     }
 
     fn is_list(&self) -> bool {
@@ -62,7 +65,7 @@ impl TryFrom<MarkdownSyntaxKind> for TriviaPieceKind {
             match value {
                 MarkdownSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
                 MarkdownSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
-                MarkdownSyntaxKind::TAB => Ok(TriviaPieceKind::Skipped),
+                MarkdownSyntaxKind::TAB => Ok(TriviaPieceKind::Whitespace),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
@@ -662,6 +662,11 @@ impl AnyParse {
         AnyParse { root, diagnostics }
     }
 
+    /// Returns the type-erased root node of the parsed file.
+    pub fn root(&self) -> &SendNode {
+        &self.root
+    }
+
     pub fn syntax<L>(&self) -> SyntaxNode<L>
     where
         L: Language + 'static,
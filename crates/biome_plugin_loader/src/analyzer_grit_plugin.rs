@@ -0,0 +1,200 @@
+use crate::PluginDiagnostic;
+use biome_analyze::{AnalyzerPlugin, RuleDiagnostic};
+use biome_console::markup;
+use biome_diagnostics::{category, Severity};
+use biome_fs::FileSystem;
+use biome_grit_patterns::{
+    compile_pattern, GritQuery, GritQueryResult, GritTargetFile, GritTargetLanguage,
+    JsTargetLanguage,
+};
+use biome_parser::AnyParse;
+use biome_rowan::{SendNode, TextRange};
+use std::path::{Path, PathBuf};
+
+/// Definition of an analyzer plugin powered by a GritQL pattern.
+///
+/// Every match of the pattern is reported as a diagnostic. The categories of the
+/// diagnostics are known at compile time, so the diagnostics of all the plugins
+/// share the `plugin` category, and are told apart by their message.
+#[derive(Debug)]
+pub struct AnalyzerGritPlugin {
+    grit_query: GritQuery,
+    severity: Severity,
+    message: Option<String>,
+}
+
+impl AnalyzerGritPlugin {
+    /// Reads and compiles the GritQL pattern stored at `path`.
+    pub fn load(fs: &dyn FileSystem, path: &Path) -> Result<Self, PluginDiagnostic> {
+        let source = fs.read_file_from_path(&PathBuf::from(path))?;
+        let grit_query = compile_pattern(&source, Some(path), JsTargetLanguage.into())
+            .map_err(|error| PluginDiagnostic::compile_error(path.display().to_string(), error))?;
+
+        Ok(Self {
+            grit_query,
+            severity: Severity::Error,
+            message: None,
+        })
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_message(mut self, message: Option<String>) -> Self {
+        self.message = message;
+        self
+    }
+
+    pub fn language(&self) -> &GritTargetLanguage {
+        self.grit_query.language()
+    }
+
+    fn diagnostic(&self, range: TextRange) -> RuleDiagnostic {
+        match (&self.message, &self.grit_query.name) {
+            (Some(message), _) => RuleDiagnostic::new(category!("plugin"), range, message),
+            (None, Some(name)) => RuleDiagnostic::new(
+                category!("plugin"),
+                range,
+                markup! {
+                    "This code matches the pattern of the plugin "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+            (None, None) => RuleDiagnostic::new(
+                category!("plugin"),
+                range,
+                "This code matches the pattern of a plugin.",
+            ),
+        }
+    }
+
+    /// The diagnostic reported when the pattern fails to run on the file at `path`.
+    ///
+    /// It doesn't have a span, because the failure isn't tied to a range of the file.
+    fn failure_diagnostic(&self, path: &Path, error: &str) -> RuleDiagnostic {
+        let path = path.display().to_string();
+        match &self.grit_query.name {
+            Some(name) => RuleDiagnostic::new(
+                category!("plugin"),
+                None::<TextRange>,
+                markup! {
+                    "The plugin "<Emphasis>{name}</Emphasis>" failed to run on "<Emphasis>{path}</Emphasis>": "{error}
+                },
+            ),
+            None => RuleDiagnostic::new(
+                category!("plugin"),
+                None::<TextRange>,
+                markup! {
+                    "A plugin failed to run on "<Emphasis>{path}</Emphasis>": "{error}
+                },
+            ),
+        }
+    }
+}
+
+impl AnalyzerPlugin for AnalyzerGritPlugin {
+    fn evaluate(&self, root: SendNode, path: &Path) -> Vec<RuleDiagnostic> {
+        if !self.language().is_compatible_with(&root) {
+            return Vec::new();
+        }
+
+        let file = GritTargetFile {
+            path: path.to_path_buf(),
+            parse: AnyParse::new(root, Vec::new()),
+        };
+
+        match self.grit_query.execute(file) {
+            Ok((results, _logs)) => results
+                .into_iter()
+                .flat_map(|result| match result {
+                    GritQueryResult::Match(m) => m.ranges,
+                    GritQueryResult::Rewrite(rewrite) => rewrite.original.ranges,
+                    GritQueryResult::CreateFile(_) => Vec::new(),
+                })
+                .map(|range| {
+                    self.diagnostic(TextRange::new(
+                        range.start_byte.into(),
+                        range.end_byte.into(),
+                    ))
+                })
+                .collect(),
+            Err(error) => vec![self.failure_diagnostic(path, &error.to_string())],
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_diagnostics::{Diagnostic, PrintDescription};
+    use biome_fs::MemoryFileSystem;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    fn load_plugin(source: &str) -> AnalyzerGritPlugin {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/noConsole.grit"), source);
+        AnalyzerGritPlugin::load(&fs, Path::new("/noConsole.grit")).expect("plugin to load")
+    }
+
+    #[test]
+    fn reports_matches_as_diagnostics() {
+        let plugin = load_plugin("`console.log($message)`");
+        let parsed = parse(
+            "console.log('hello');\nfoo();\nconsole.log('world');",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let root = parsed.syntax().as_send().unwrap();
+
+        let diagnostics = plugin.evaluate(root, Path::new("file.js"));
+
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn uses_the_configured_message() {
+        let plugin = load_plugin("`console.log($message)`")
+            .with_message(Some("Use the logger instead.".to_string()));
+        let parsed = parse(
+            "console.log('hello');",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let root = parsed.syntax().as_send().unwrap();
+
+        let diagnostics = plugin.evaluate(root, Path::new("file.js"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            PrintDescription(&diagnostics[0]).to_string(),
+            "Use the logger instead."
+        );
+    }
+
+    #[test]
+    fn reports_the_failures_without_a_span() {
+        // `capitalize()` fails at runtime when it's called without an argument
+        let plugin = load_plugin("`console.log($message)` where { $name = capitalize() }");
+        let parsed = parse(
+            "console.log('hello');",
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let root = parsed.syntax().as_send().unwrap();
+
+        let diagnostics = plugin.evaluate(root, Path::new("file.js"));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location().span, None);
+        assert_eq!(
+            PrintDescription(&diagnostics[0]).to_string(),
+            "The plugin noConsole failed to run on file.js: capitalize() takes 1 argument: string"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use biome_diagnostics::{Diagnostic, MessageAndDescription};
+use biome_fs::FileSystemDiagnostic;
+use biome_grit_patterns::CompileError;
+use serde::{Deserialize, Serialize};
+
+/// Errors that can occur while loading a plugin
+#[derive(Debug, Deserialize, Diagnostic, Serialize)]
+pub enum PluginDiagnostic {
+    /// Thrown when the file of a plugin can't be read
+    FileSystem(FileSystemDiagnostic),
+    /// Thrown when Biome doesn't know how to load a plugin
+    UnsupportedPlugin(UnsupportedPlugin),
+    /// Thrown when the source of a plugin can't be compiled
+    CompileError(CompileDiagnostic),
+}
+
+impl PluginDiagnostic {
+    pub fn unsupported_plugin(path: impl Into<String>) -> Self {
+        Self::UnsupportedPlugin(UnsupportedPlugin { path: path.into() })
+    }
+
+    pub fn compile_error(path: impl Into<String>, error: CompileError) -> Self {
+        let message = match error {
+            CompileError::ParsePatternError(diagnostic) => diagnostic.message,
+            // FIXME: `CompileError` should provide proper messages for all its variants
+            error => MessageAndDescription::from(format!("{error:?}")),
+        };
+
+        Self::CompileError(CompileDiagnostic {
+            path: path.into(),
+            message,
+        })
+    }
+}
+
+impl From<FileSystemDiagnostic> for PluginDiagnostic {
+    fn from(diagnostic: FileSystemDiagnostic) -> Self {
+        Self::FileSystem(diagnostic)
+    }
+}
+
+#[derive(Debug, Deserialize, Diagnostic, Serialize)]
+#[diagnostic(
+    category = "plugin",
+    message(
+        message("Biome doesn't know how to load the plugin "<Emphasis>{self.path}</Emphasis>". Only GritQL files with the "<Emphasis>".grit"</Emphasis>" extension are supported."),
+        description = "Biome doesn't know how to load the plugin {path}. Only GritQL files with the .grit extension are supported."
+    )
+)]
+pub struct UnsupportedPlugin {
+    #[location(resource)]
+    path: String,
+}
+
+#[derive(Debug, Deserialize, Diagnostic, Serialize)]
+#[diagnostic(category = "plugin")]
+pub struct CompileDiagnostic {
+    #[location(resource)]
+    path: String,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
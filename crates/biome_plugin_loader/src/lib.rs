@@ -0,0 +1,60 @@
+mod analyzer_grit_plugin;
+mod diagnostics;
+
+pub use analyzer_grit_plugin::AnalyzerGritPlugin;
+pub use diagnostics::PluginDiagnostic;
+
+use biome_analyze::AnalyzerPluginVec;
+use biome_configuration::{PluginConfiguration, RulePlainConfiguration};
+use biome_diagnostics::Severity;
+use biome_fs::FileSystem;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A plugin loaded from the configuration, along with all the capabilities it
+/// provides to Biome.
+#[derive(Debug)]
+pub struct BiomePlugin {
+    pub analyzer_plugins: AnalyzerPluginVec,
+}
+
+impl BiomePlugin {
+    /// Loads the plugin described by `plugin`.
+    ///
+    /// Relative plugin paths are resolved against `base_path`, which is
+    /// usually the directory that contains the configuration file.
+    pub fn load(
+        fs: &dyn FileSystem,
+        plugin: &PluginConfiguration,
+        base_path: &Path,
+    ) -> Result<Self, PluginDiagnostic> {
+        let plugin_path = base_path.join(plugin.path());
+
+        if plugin_path
+            .extension()
+            .map_or(true, |extension| extension != "grit")
+        {
+            return Err(PluginDiagnostic::unsupported_plugin(
+                plugin_path.display().to_string(),
+            ));
+        }
+
+        let severity = match plugin.level() {
+            Some(RulePlainConfiguration::Off) => {
+                return Ok(Self {
+                    analyzer_plugins: Vec::new(),
+                })
+            }
+            Some(level) => Severity::from(level),
+            None => Severity::Error,
+        };
+
+        let plugin = AnalyzerGritPlugin::load(fs, &plugin_path)?
+            .with_severity(severity)
+            .with_message(plugin.message().map(str::to_string));
+
+        Ok(Self {
+            analyzer_plugins: vec![Arc::new(plugin)],
+        })
+    }
+}
@@ -0,0 +1,235 @@
+mod module_info;
+mod resolver;
+
+use crate::{CompilerOptions, PackageJson, TsConfigJson};
+use dashmap::DashMap;
+pub use module_info::{
+    ImportKind, ImportedSymbol, ModuleExport, ModuleImport, ModuleInfo, NamedSpecifier,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// The imports and exports of the modules of a project.
+///
+/// The graph only knows the modules that were inserted in it, so the queries
+/// return [None] when they depend on a module that it doesn't know, e.g. a
+/// dependency in `node_modules`. The `tsconfig.json` and `package.json` files
+/// inserted in the graph are used to resolve the non-relative specifiers, see
+/// [ModuleGraph::resolve].
+///
+/// Each module is stored in its own entry, so the graph can be updated while
+/// other threads query it. The queries never hold an entry while they look up
+/// another one.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    modules: DashMap<PathBuf, Arc<ModuleInfo>>,
+    /// The `compilerOptions` of the `tsconfig.json` files, by path
+    tsconfigs: DashMap<PathBuf, Arc<CompilerOptions>>,
+    /// The manifests of the packages of the project, by path
+    packages: DashMap<PathBuf, Arc<PackageJson>>,
+}
+
+impl ModuleGraph {
+    /// Inserts the module at `path`, replacing the previous information about
+    /// this module
+    pub fn insert(&self, path: &Path, info: ModuleInfo) {
+        self.modules.insert(normalize(path), Arc::new(info));
+    }
+
+    /// Inserts the `tsconfig.json`, or `jsconfig.json`, at `path`
+    pub fn insert_tsconfig(&self, path: &Path, tsconfig: TsConfigJson) {
+        self.tsconfigs
+            .insert(normalize(path), Arc::new(tsconfig.compiler_options));
+    }
+
+    /// Inserts the `package.json` at `path`
+    pub fn insert_package(&self, path: &Path, manifest: PackageJson) {
+        self.packages.insert(normalize(path), Arc::new(manifest));
+    }
+
+    /// Removes the module, the `tsconfig.json` or the `package.json` at
+    /// `path`, or all the ones inside the directory at `path`, e.g. when the
+    /// file or the directory was deleted
+    pub fn remove(&self, path: &Path) {
+        let path = normalize(path);
+        self.modules.retain(|module, _| !module.starts_with(&path));
+        self.tsconfigs
+            .retain(|tsconfig, _| !tsconfig.starts_with(&path));
+        self.packages
+            .retain(|package, _| !package.starts_with(&path));
+    }
+
+    pub fn get(&self, path: &Path) -> Option<Arc<ModuleInfo>> {
+        self.get_normalized(&normalize(path))
+    }
+
+    /// Returns the module at the normalized `path`, without holding its entry
+    fn get_normalized(&self, path: &Path) -> Option<Arc<ModuleInfo>> {
+        self.modules.get(path).map(|info| info.value().clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Returns whether the module at `path` exports `name`, following the
+    /// `export * from "..."` re-exports.
+    ///
+    /// Returns [None] when the answer can't be known, because the graph
+    /// doesn't know the module or one of its re-exported modules, or because
+    /// the module doesn't have any export, and so it may be a CommonJS module.
+    pub fn has_export(&self, path: &Path, name: &str) -> Option<bool> {
+        self.has_export_inner(&normalize(path), name, &mut FxHashSet::default())
+    }
+
+    fn has_export_inner(
+        &self,
+        path: &Path,
+        name: &str,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<bool> {
+        if !visited.insert(path.to_path_buf()) {
+            return Some(false);
+        }
+        let info = self.get_normalized(path)?;
+        if info.exports.iter().any(|export| export.name == name) {
+            return Some(true);
+        }
+
+        let mut is_module = !info.exports.is_empty();
+        let mut is_unknown = false;
+        for import in info
+            .imports
+            .iter()
+            .filter(|import| import.symbols.contains(&ImportedSymbol::All))
+        {
+            is_module = true;
+            // `export *` doesn't re-export the default export
+            if name == "default" {
+                continue;
+            }
+            let result = self
+                .resolve(path, &import.specifier)
+                .and_then(|source| self.has_export_inner(&source, name, visited));
+            match result {
+                Some(true) => return Some(true),
+                Some(false) => {}
+                None => is_unknown = true,
+            }
+        }
+
+        (is_module && !is_unknown).then_some(false)
+    }
+
+    /// Returns the modules that import the module at `path`, with their
+    /// imports of this module
+    pub fn importers(&self, path: &Path) -> Vec<(PathBuf, ModuleImport)> {
+        let path = normalize(path);
+        let modules: Vec<_> = self
+            .modules
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        modules
+            .into_iter()
+            .flat_map(|(importer, info)| {
+                info.imports
+                    .iter()
+                    .filter(|import| {
+                        self.resolve(&importer, &import.specifier).as_ref() == Some(&path)
+                    })
+                    .map(|import| (importer.clone(), import.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns a cycle of static imports that starts and ends with the module
+    /// at `path`, if there is one. The returned paths start with the module
+    /// imported by `path`, and end with `path`.
+    ///
+    /// The type-only imports are ignored, because they don't load the
+    /// imported module.
+    pub fn import_cycle(&self, path: &Path) -> Option<Vec<PathBuf>> {
+        let path = normalize(path);
+        self.static_imports(&path)
+            .iter()
+            .find_map(|imported| self.import_chain(imported, &path))
+    }
+
+    /// Returns the shortest chain of static imports from the module at `from`
+    /// to the module at `to`, if there is one. The returned paths start with
+    /// `from` and end with `to`.
+    ///
+    /// The type-only imports are ignored, because they don't load the
+    /// imported module.
+    pub fn import_chain(&self, from: &Path, to: &Path) -> Option<Vec<PathBuf>> {
+        let from = normalize(from);
+        let to = normalize(to);
+        // The module that imports each visited module first
+        let mut importers: FxHashMap<PathBuf, Option<PathBuf>> = FxHashMap::default();
+        let mut queue = VecDeque::from([from.clone()]);
+        importers.insert(from, None);
+
+        while let Some(path) = queue.pop_front() {
+            if path == to {
+                let mut chain = vec![path];
+                while let Some(Some(importer)) = chain.last().and_then(|path| importers.get(path)) {
+                    chain.push(importer.clone());
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+            for imported in self.static_imports(&path) {
+                if !importers.contains_key(&imported) {
+                    importers.insert(imported.clone(), Some(path.clone()));
+                    queue.push_back(imported);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the known modules that are statically imported by the module
+    /// at `path`, in source order
+    fn static_imports(&self, path: &Path) -> Vec<PathBuf> {
+        let Some(info) = self.get_normalized(path) else {
+            return Vec::new();
+        };
+        info.imports
+            .iter()
+            .filter(|import| import.kind.is_static() && !import.is_type_only)
+            .filter_map(|import| self.resolve(path, &import.specifier))
+            .collect()
+    }
+}
+
+/// Removes the `.` and `..` components of `path`, without accessing the file
+/// system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    None | Some(Component::ParentDir)
+                ) {
+                    normalized.push("..");
+                } else {
+                    normalized.pop();
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
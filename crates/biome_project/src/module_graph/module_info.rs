@@ -0,0 +1,496 @@
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCombinedSpecifier, AnyJsDeclarationClause, AnyJsExportClause,
+    AnyJsExportNamedSpecifier, AnyJsImportClause, AnyJsImportLike, AnyJsModuleItem,
+    AnyJsModuleSource, AnyJsNamedImportSpecifier, AnyJsRoot, AnyTsIdentifierBinding,
+    AnyTsModuleName, JsIdentifierBinding, JsLiteralExportName, JsModuleSource,
+    JsNamedImportSpecifiers, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+/// The imports and exports of a module
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModuleInfo {
+    /// The static imports, the re-exports, and the dynamic imports and
+    /// `require()` calls that use a string literal, in source order
+    pub imports: Vec<ModuleImport>,
+    /// The names exported by the module, including the re-exported names.
+    /// The names re-exported by `export * from "..."` aren't included, because
+    /// they depend on the other module.
+    pub exports: Vec<ModuleExport>,
+}
+
+/// An import of another module
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleImport {
+    /// The specifier of the imported module, e.g. `./utils` or `react`
+    pub specifier: String,
+    pub kind: ImportKind,
+    /// The symbols imported from the module. It's empty for side effect
+    /// imports, e.g. `import "./polyfill"`.
+    pub symbols: Vec<ImportedSymbol>,
+    /// The specifiers of the symbols imported by name, e.g. `a` and `b as c`
+    /// in `import { a, b as c } from "./a"`
+    pub named_specifiers: Vec<NamedSpecifier>,
+    /// Whether only types are imported. Type-only imports are removed at
+    /// compile time, so they don't load the module.
+    pub is_type_only: bool,
+    /// The range of the specifier in the importing module
+    pub range: TextRange,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImportKind {
+    /// `import { a } from "./a"`
+    Static,
+    /// `export { a } from "./a"`
+    ReExport,
+    /// `import("./a")`
+    Dynamic,
+    /// `require("./a")`
+    Require,
+}
+
+impl ImportKind {
+    /// Whether the imported module is loaded when the importing module is
+    /// loaded
+    pub const fn is_static(&self) -> bool {
+        matches!(self, Self::Static | Self::ReExport)
+    }
+}
+
+/// A symbol imported from another module
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ImportedSymbol {
+    /// `import a from "./a"`
+    Default,
+    /// `import * as a from "./a"`, and the dynamic imports and `require()`
+    /// calls, that get all the exports of the module
+    Namespace,
+    /// `import { a } from "./a"`
+    Named(String),
+    /// `export * from "./a"`
+    All,
+}
+
+/// A symbol imported, or re-exported, by name
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NamedSpecifier {
+    /// The name exported by the imported module
+    pub name: String,
+    /// The range of the name exported by the imported module, e.g. the range
+    /// of `a` in `import { a as b } from "./a"`
+    pub range: TextRange,
+    /// Whether the symbol keeps its name in the importing module, e.g.
+    /// `import { a } from "./a"`
+    pub is_shorthand: bool,
+}
+
+/// A name exported by a module
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleExport {
+    /// The exported name, `default` for the default export
+    pub name: String,
+    /// Whether the name is only a type, e.g. an interface
+    pub is_type_only: bool,
+    /// The range of the exported name, or of the whole export when the
+    /// export doesn't have a name, e.g. `export default 1`
+    pub range: TextRange,
+}
+
+impl ModuleInfo {
+    /// Collects the imports and exports of a JavaScript module
+    pub fn from_js_root(root: &AnyJsRoot) -> Self {
+        let mut info = Self::default();
+
+        if let AnyJsRoot::JsModule(module) = root {
+            for item in module.items() {
+                match item {
+                    AnyJsModuleItem::JsImport(import) => {
+                        if let Ok(import_clause) = import.import_clause() {
+                            info.collect_import(&import_clause);
+                        }
+                    }
+                    AnyJsModuleItem::JsExport(export) => {
+                        if let Ok(export_clause) = export.export_clause() {
+                            info.collect_export(&export_clause);
+                        }
+                    }
+                    AnyJsModuleItem::AnyJsStatement(_) => {}
+                }
+            }
+        }
+
+        // The static imports are module sources, that are already collected
+        for import_like in root
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsImportLike::cast)
+        {
+            let kind = match &import_like {
+                AnyJsImportLike::JsModuleSource(_) => continue,
+                AnyJsImportLike::JsCallExpression(_) => ImportKind::Require,
+                AnyJsImportLike::JsImportCallExpression(_) => ImportKind::Dynamic,
+            };
+            if let Some(specifier) = import_like.inner_string_text() {
+                info.imports.push(ModuleImport {
+                    specifier: specifier.text().to_string(),
+                    kind,
+                    symbols: vec![ImportedSymbol::Namespace],
+                    named_specifiers: Vec::new(),
+                    is_type_only: false,
+                    range: import_like.range(),
+                });
+            }
+        }
+
+        info
+    }
+
+    fn collect_import(&mut self, import_clause: &AnyJsImportClause) {
+        let mut symbols = Vec::new();
+        let mut named_specifiers = Vec::new();
+        let is_type_only = match import_clause {
+            AnyJsImportClause::JsImportBareClause(_) => false,
+            AnyJsImportClause::JsImportCombinedClause(clause) => {
+                symbols.push(ImportedSymbol::Default);
+                match clause.specifier() {
+                    Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) => {
+                        collect_named_imports(&specifiers, &mut symbols, &mut named_specifiers);
+                    }
+                    Ok(AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(_)) => {
+                        symbols.push(ImportedSymbol::Namespace);
+                    }
+                    Err(_) => {}
+                }
+                false
+            }
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                symbols.push(ImportedSymbol::Default);
+                clause.type_token().is_some()
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if let Ok(specifiers) = clause.named_specifiers() {
+                    let mut is_type_only = clause.type_token().is_some();
+                    if !is_type_only && !specifiers.specifiers().is_empty() {
+                        is_type_only = specifiers
+                            .specifiers()
+                            .iter()
+                            .flatten()
+                            .all(|specifier| specifier.imports_only_types());
+                    }
+                    collect_named_imports(&specifiers, &mut symbols, &mut named_specifiers);
+                    is_type_only
+                } else {
+                    clause.type_token().is_some()
+                }
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                symbols.push(ImportedSymbol::Namespace);
+                clause.type_token().is_some()
+            }
+        };
+
+        if let Ok(source) = import_clause.source() {
+            self.push_import(
+                &source,
+                ImportKind::Static,
+                symbols,
+                named_specifiers,
+                is_type_only,
+            );
+        }
+    }
+
+    fn collect_export(&mut self, export_clause: &AnyJsExportClause) {
+        match export_clause {
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+                self.collect_declaration(declaration);
+            }
+            AnyJsExportClause::TsExportDeclareClause(clause) => {
+                if let Ok(declaration) = clause.declaration() {
+                    self.collect_declaration(&declaration);
+                }
+            }
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let is_type_only = clause
+                    .declaration()
+                    .is_ok_and(|declaration| declaration.as_ts_interface_declaration().is_some());
+                self.push_export("default".to_string(), is_type_only, clause.range());
+            }
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => {
+                self.push_export("default".to_string(), false, clause.range());
+            }
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                for specifier in clause.specifiers().iter().flatten() {
+                    let is_type_only = specifier.exports_only_types();
+                    match specifier {
+                        AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            if let Ok(name) = specifier.name() {
+                                self.push_export(
+                                    name.syntax().text_trimmed().to_string(),
+                                    is_type_only,
+                                    name.range(),
+                                );
+                            }
+                        }
+                        AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            if let Ok(name) = specifier.exported_name() {
+                                self.push_export_name(&name, is_type_only);
+                            }
+                        }
+                    }
+                }
+            }
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                let mut symbols = Vec::new();
+                let mut named_specifiers = Vec::new();
+                let mut is_type_only = clause.type_token().is_some();
+                let mut all_specifiers_are_types = true;
+                for specifier in clause.specifiers().iter().flatten() {
+                    let Ok(source_name) = specifier.source_name() else {
+                        continue;
+                    };
+                    let is_specifier_type_only = is_type_only || specifier.type_token().is_some();
+                    all_specifiers_are_types &= is_specifier_type_only;
+                    symbols.push(imported_symbol(&source_name));
+                    let exported_name = specifier
+                        .export_as()
+                        .and_then(|export_as| export_as.exported_name().ok());
+                    named_specifiers.extend(named_specifier(&source_name, exported_name.is_none()));
+                    match exported_name {
+                        Some(exported_name) => {
+                            self.push_export_name(&exported_name, is_specifier_type_only)
+                        }
+                        None => self.push_export_name(&source_name, is_specifier_type_only),
+                    }
+                }
+                is_type_only |= !symbols.is_empty() && all_specifiers_are_types;
+
+                if let Ok(AnyJsModuleSource::JsModuleSource(source)) = clause.source() {
+                    self.push_import(
+                        &source,
+                        ImportKind::ReExport,
+                        symbols,
+                        named_specifiers,
+                        is_type_only,
+                    );
+                }
+            }
+            AnyJsExportClause::JsExportFromClause(clause) => {
+                let is_type_only = clause.type_token().is_some();
+                let symbol = match clause
+                    .export_as()
+                    .and_then(|export_as| export_as.exported_name().ok())
+                {
+                    // export * as ns from "./a"
+                    Some(exported_name) => {
+                        self.push_export_name(&exported_name, is_type_only);
+                        ImportedSymbol::Namespace
+                    }
+                    // export * from "./a"
+                    None => ImportedSymbol::All,
+                };
+
+                if let Ok(AnyJsModuleSource::JsModuleSource(source)) = clause.source() {
+                    self.push_import(
+                        &source,
+                        ImportKind::ReExport,
+                        vec![symbol],
+                        Vec::new(),
+                        is_type_only,
+                    );
+                }
+            }
+            AnyJsExportClause::TsExportAsNamespaceClause(_)
+            | AnyJsExportClause::TsExportAssignmentClause(_) => {}
+        }
+    }
+
+    fn collect_declaration(&mut self, declaration: &AnyJsDeclarationClause) {
+        match declaration {
+            AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_binding(&id, false);
+                }
+            }
+            AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_binding(&id, false);
+                }
+            }
+            AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_binding(&id, false);
+                }
+            }
+            AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_binding(&id, false);
+                }
+            }
+            AnyJsDeclarationClause::TsImportEqualsDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_binding(&id, declaration.type_token().is_some());
+                }
+            }
+            AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => {
+                if let Ok(id) = declaration.id() {
+                    self.push_ts_binding(&id, true);
+                }
+            }
+            AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => {
+                if let Ok(id) = declaration.binding_identifier() {
+                    self.push_ts_binding(&id, true);
+                }
+            }
+            AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+                if let Ok(AnyTsModuleName::AnyTsIdentifierBinding(id)) = declaration.name() {
+                    self.push_ts_binding(&id, false);
+                }
+            }
+            AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+                let Ok(declaration) = clause.declaration() else {
+                    return;
+                };
+                for declarator in declaration.declarators().iter().flatten() {
+                    if let Ok(id) = declarator.id() {
+                        for binding in pattern_bindings(id.syntax()) {
+                            self.push_binding(&AnyJsBinding::JsIdentifierBinding(binding), false);
+                        }
+                    }
+                }
+            }
+            AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+            | AnyJsDeclarationClause::TsGlobalDeclaration(_) => {}
+        }
+    }
+
+    fn push_import(
+        &mut self,
+        source: &JsModuleSource,
+        kind: ImportKind,
+        symbols: Vec<ImportedSymbol>,
+        named_specifiers: Vec<NamedSpecifier>,
+        is_type_only: bool,
+    ) {
+        if let Ok(specifier) = source.inner_string_text() {
+            self.imports.push(ModuleImport {
+                specifier: specifier.text().to_string(),
+                kind,
+                symbols,
+                named_specifiers,
+                is_type_only,
+                range: source.range(),
+            });
+        }
+    }
+
+    fn push_export(&mut self, name: String, is_type_only: bool, range: TextRange) {
+        self.exports.push(ModuleExport {
+            name,
+            is_type_only,
+            range,
+        });
+    }
+
+    fn push_export_name(&mut self, name: &JsLiteralExportName, is_type_only: bool) {
+        if let Ok(text) = name.inner_string_text() {
+            self.push_export(text.text().to_string(), is_type_only, name.range());
+        }
+    }
+
+    fn push_binding(&mut self, binding: &AnyJsBinding, is_type_only: bool) {
+        if let Some(name_token) = binding
+            .as_js_identifier_binding()
+            .and_then(|binding| binding.name_token().ok())
+        {
+            self.push_export(
+                name_token.text_trimmed().to_string(),
+                is_type_only,
+                name_token.text_trimmed_range(),
+            );
+        }
+    }
+
+    fn push_ts_binding(&mut self, binding: &AnyTsIdentifierBinding, is_type_only: bool) {
+        if let Some(name_token) = binding
+            .as_ts_identifier_binding()
+            .and_then(|binding| binding.name_token().ok())
+        {
+            self.push_export(
+                name_token.text_trimmed().to_string(),
+                is_type_only,
+                name_token.text_trimmed_range(),
+            );
+        }
+    }
+}
+
+fn collect_named_imports(
+    specifiers: &JsNamedImportSpecifiers,
+    symbols: &mut Vec<ImportedSymbol>,
+    named_specifiers: &mut Vec<NamedSpecifier>,
+) {
+    for specifier in specifiers.specifiers().iter().flatten() {
+        match specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                if let Ok(name) = specifier.name() {
+                    symbols.push(imported_symbol(&name));
+                    named_specifiers.extend(named_specifier(&name, false));
+                }
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+                if let Some(name) = specifier.imported_name() {
+                    symbols.push(ImportedSymbol::Named(name.text_trimmed().to_string()));
+                    named_specifiers.push(NamedSpecifier {
+                        name: name.text_trimmed().to_string(),
+                        range: name.text_trimmed_range(),
+                        is_shorthand: true,
+                    });
+                }
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+        }
+    }
+}
+
+/// Returns the symbol that an import or a re-export of `name` refers to, e.g.
+/// `import { default as a } from "./a"` imports the default export
+fn imported_symbol(name: &JsLiteralExportName) -> ImportedSymbol {
+    if name.is_default() {
+        ImportedSymbol::Default
+    } else {
+        let text = name
+            .inner_string_text()
+            .map(|text| text.text().to_string())
+            .unwrap_or_default();
+        ImportedSymbol::Named(text)
+    }
+}
+
+/// Returns the specifier of an import or a re-export of `name`, unless it
+/// refers to the default export
+fn named_specifier(name: &JsLiteralExportName, is_shorthand: bool) -> Option<NamedSpecifier> {
+    if name.is_default() {
+        return None;
+    }
+    let text = name.inner_string_text().ok()?;
+    Some(NamedSpecifier {
+        name: text.text().to_string(),
+        range: name.range(),
+        is_shorthand,
+    })
+}
+
+/// Returns the bindings declared by a binding pattern, e.g. `a` and `b` in
+/// `{ a, b: [b] = [] }`, skipping the default values
+fn pattern_bindings(pattern: &JsSyntaxNode) -> impl Iterator<Item = JsIdentifierBinding> + '_ {
+    pattern
+        .descendants()
+        .filter(move |node| {
+            node.ancestors()
+                .take_while(|ancestor| ancestor != pattern)
+                .all(|ancestor| ancestor.kind() != JsSyntaxKind::JS_INITIALIZER_CLAUSE)
+        })
+        .filter_map(JsIdentifierBinding::cast)
+}
@@ -0,0 +1,248 @@
+use super::{normalize, ModuleGraph};
+use crate::{CompilerOptions, PackageExports};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The extensions that are tried, in order, when an import specifier doesn't
+/// have an extension
+const EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// The TypeScript extensions of the files that are imported with a JavaScript
+/// extension, e.g. `import "./a.js"` imports `a.ts`
+const TS_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("js", &["ts", "tsx"]),
+    ("jsx", &["tsx"]),
+    ("mjs", &["mts"]),
+    ("cjs", &["cts"]),
+];
+
+/// The names of the files that configure the resolution of the non-relative
+/// specifiers, from the closest `tsconfig.json` or `jsconfig.json`
+const TSCONFIG_FILE_NAMES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+/// The conditions of the `exports` of the packages that are matched, in the
+/// order of their declaration in the `package.json`. Both the ESM and the
+/// CommonJS conditions are matched, because the analysis doesn't depend on
+/// the environment where the code runs.
+const EXPORTS_CONDITIONS: &[&str] = &["types", "import", "require", "module", "node", "default"];
+
+impl ModuleGraph {
+    /// Returns the path of the module imported by `importer` with
+    /// `specifier`, if the graph knows it.
+    ///
+    /// The specifiers are resolved like TypeScript does:
+    /// - the relative specifiers, e.g. `./a`, are resolved against the
+    ///   directory of the importer;
+    /// - the other specifiers are resolved with the `paths` and the `baseUrl`
+    ///   of the closest `tsconfig.json`, then with the `exports` of the
+    ///   packages of the project.
+    ///
+    /// A specifier can omit the extension of the file, use the JavaScript
+    /// extension of a TypeScript file, or refer to the `index` file of a
+    /// directory.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let importer = normalize(importer);
+        if is_relative_specifier(specifier) {
+            return self.resolve_path(&importer.parent()?.join(specifier));
+        }
+        self.resolve_with_tsconfig(&importer, specifier)
+            .or_else(|| self.resolve_with_packages(specifier))
+    }
+
+    /// Returns the path of the module at `path`, trying the extensions and the
+    /// `index` files when the path doesn't exist
+    fn resolve_path(&self, path: &Path) -> Option<PathBuf> {
+        let path = normalize(path);
+
+        let mut candidates = vec![path.clone()];
+        if let Some(extension) = path.extension().and_then(|extension| extension.to_str()) {
+            if let Some((_, ts_extensions)) = TS_EXTENSIONS
+                .iter()
+                .find(|(js_extension, _)| *js_extension == extension)
+            {
+                candidates.extend(
+                    ts_extensions
+                        .iter()
+                        .map(|ts_extension| path.with_extension(ts_extension)),
+                );
+            }
+        }
+        candidates.extend(EXTENSIONS.iter().map(|extension| {
+            let mut file_name = path.clone().into_os_string();
+            file_name.push(".");
+            file_name.push(extension);
+            PathBuf::from(file_name)
+        }));
+        candidates.extend(
+            EXTENSIONS
+                .iter()
+                .map(|extension| path.join("index").with_extension(extension)),
+        );
+
+        candidates
+            .into_iter()
+            .find(|candidate| self.modules.contains_key(candidate))
+    }
+
+    /// Resolves `specifier` with the `compilerOptions` of the closest
+    /// `tsconfig.json` of `importer`
+    fn resolve_with_tsconfig(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        let (directory, options) = self.closest_tsconfig(importer)?;
+        let base_directory = match &options.base_url {
+            Some(base_url) => directory.join(base_url),
+            None => directory,
+        };
+
+        if let Some((targets, captured)) = match_paths(&options, specifier) {
+            let resolved = targets.iter().find_map(|target| {
+                self.resolve_path(&base_directory.join(target.replacen('*', captured, 1)))
+            });
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        options
+            .base_url
+            .as_ref()
+            .and_then(|_| self.resolve_path(&base_directory.join(specifier)))
+    }
+
+    /// Returns the directory and the options of the closest `tsconfig.json`
+    /// of `importer`
+    fn closest_tsconfig(&self, importer: &Path) -> Option<(PathBuf, Arc<CompilerOptions>)> {
+        importer.ancestors().skip(1).find_map(|directory| {
+            TSCONFIG_FILE_NAMES.iter().find_map(|file_name| {
+                self.tsconfigs
+                    .get(&directory.join(file_name))
+                    .map(|options| (directory.to_path_buf(), options.value().clone()))
+            })
+        })
+    }
+
+    /// Resolves `specifier` with the `exports` of the package it refers to,
+    /// e.g. `@scope/package/utils`
+    fn resolve_with_packages(&self, specifier: &str) -> Option<PathBuf> {
+        let (name, subpath) = split_package_specifier(specifier)?;
+        let (path, manifest) = self.packages.iter().find_map(|entry| {
+            (entry.value().name.as_deref() == Some(name))
+                .then(|| (entry.key().clone(), entry.value().clone()))
+        })?;
+        let directory = path.parent()?;
+
+        match &manifest.exports {
+            Some(exports) => {
+                let subpath = if subpath.is_empty() {
+                    ".".to_string()
+                } else {
+                    format!("./{subpath}")
+                };
+                exports_targets(exports, &subpath)
+                    .iter()
+                    .find_map(|target| self.resolve_path(&directory.join(target)))
+            }
+            None => self.resolve_path(&directory.join(subpath)),
+        }
+    }
+}
+
+pub(super) fn is_relative_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+/// Returns the targets of the pattern of `paths` that matches `specifier`,
+/// with the part of the specifier matched by the `*` of the pattern.
+///
+/// Like TypeScript, an exact pattern is preferred, then the pattern with the
+/// longest prefix.
+fn match_paths<'a>(
+    options: &'a CompilerOptions,
+    specifier: &'a str,
+) -> Option<(&'a [String], &'a str)> {
+    if let Some(targets) = options.paths.get(specifier) {
+        return Some((targets, ""));
+    }
+    options
+        .paths
+        .iter()
+        .filter_map(|(pattern, targets)| {
+            let captured = match_pattern(pattern, specifier)?;
+            Some((pattern.find('*'), targets.as_slice(), captured))
+        })
+        .max_by_key(|(prefix_len, _, _)| *prefix_len)
+        .map(|(_, targets, captured)| (targets, captured))
+}
+
+/// Returns the part of `specifier` matched by the `*` of `pattern`, e.g.
+/// `utils` for `@app/*` and `@app/utils`
+fn match_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    let (prefix, suffix) = pattern.split_once('*')?;
+    if specifier.len() < prefix.len() + suffix.len() {
+        return None;
+    }
+    specifier
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+}
+
+/// Splits a package specifier in the name of the package and the subpath,
+/// e.g. `@scope/package` and `utils` for `@scope/package/utils`
+fn split_package_specifier(specifier: &str) -> Option<(&str, &str)> {
+    let name_len = if specifier.starts_with('@') {
+        let (scope, rest) = specifier.split_once('/')?;
+        scope.len() + 1 + rest.find('/').unwrap_or(rest.len())
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+    let (name, subpath) = specifier.split_at(name_len);
+    (!name.is_empty()).then(|| (name, subpath.trim_start_matches('/')))
+}
+
+/// Returns the targets of `exports` for `subpath`, e.g. `.` or `./utils`, in
+/// the order they should be tried
+fn exports_targets(exports: &PackageExports, subpath: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    match exports {
+        PackageExports::Map(entries) if entries.iter().all(|(key, _)| key.starts_with('.')) => {
+            if let Some((_, target)) = entries.iter().find(|(key, _)| key == subpath) {
+                collect_exports_targets(target, "", &mut targets);
+            } else if let Some((_, target, captured)) = entries
+                .iter()
+                .filter_map(|(key, target)| {
+                    let captured = match_pattern(key, subpath)?;
+                    Some((key.find('*'), target, captured))
+                })
+                .max_by_key(|(prefix_len, _, _)| *prefix_len)
+            {
+                collect_exports_targets(target, captured, &mut targets);
+            }
+        }
+        // The exports without subpaths only export the main entry point
+        _ if subpath == "." => collect_exports_targets(exports, "", &mut targets),
+        _ => {}
+    }
+    targets
+}
+
+fn collect_exports_targets(exports: &PackageExports, captured: &str, targets: &mut Vec<String>) {
+    match exports {
+        PackageExports::Target(Some(target)) => targets.push(target.replace('*', captured)),
+        PackageExports::Target(None) => {}
+        PackageExports::Fallbacks(fallbacks) => {
+            for fallback in fallbacks {
+                collect_exports_targets(fallback, captured, targets);
+            }
+        }
+        PackageExports::Map(conditions) => {
+            for (_, target) in conditions
+                .iter()
+                .filter(|(condition, _)| EXPORTS_CONDITIONS.contains(&condition.as_str()))
+            {
+                collect_exports_targets(target, captured, targets);
+            }
+        }
+    }
+}
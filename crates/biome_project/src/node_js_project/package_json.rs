@@ -19,6 +19,7 @@ pub struct PackageJson {
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
     pub r#type: Option<PackageType>,
+    pub exports: Option<PackageExports>,
 }
 
 impl Manifest for PackageJson {
@@ -136,6 +137,9 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 "type" => {
                     result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -176,3 +180,88 @@ impl PackageType {
         matches!(self, Self::Module)
     }
 }
+
+/// The `exports` field of a package, see <https://nodejs.org/api/packages.html#exports>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageExports {
+    /// `"./index.js"`, or `null` when the subpath isn't exported
+    Target(Option<String>),
+    /// `["./a.js", "./b.js"]`, where the first valid target is used
+    Fallbacks(Vec<PackageExports>),
+    /// The subpaths, e.g. `{ ".": "./index.js", "./utils": "./utils.js" }`,
+    /// or the conditions, e.g. `{ "import": "./index.mjs", "require": "./index.cjs" }`,
+    /// in source order
+    Map(Vec<(String, PackageExports)>),
+}
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::NULL
+        .union(DeserializableTypes::STR)
+        .union(DeserializableTypes::ARRAY)
+        .union(DeserializableTypes::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Target(None))
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Target(Some(value.text().to_string())))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let fallbacks = items
+            .flatten()
+            .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(PackageExports::Fallbacks(fallbacks))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(value) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), value));
+            }
+        }
+        Some(PackageExports::Map(entries))
+    }
+}
@@ -8,8 +8,18 @@ use rustc_hash::FxHashMap;
 #[derive(Debug, Default, Clone, Deserializable)]
 #[deserializable(unknown_fields = "allow")]
 pub struct TsConfigJson {
-    base_url: Option<String>,
-    paths: FxHashMap<String, Vec<String>>,
+    pub compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub struct CompilerOptions {
+    /// The directory of the non-relative module names, relative to the
+    /// `tsconfig.json` file
+    pub base_url: Option<String>,
+    /// The mappings of the module names, e.g. `"@app/*": ["./src/app/*"]`,
+    /// relative to [Self::base_url], or to the `tsconfig.json` file
+    pub paths: FxHashMap<String, Vec<String>>,
 }
 
 impl Manifest for TsConfigJson {
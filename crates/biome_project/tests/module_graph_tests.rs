@@ -0,0 +1,445 @@
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_project::{
+    CompilerOptions, ImportKind, ImportedSymbol, ModuleGraph, ModuleInfo, NamedSpecifier,
+    PackageExports, PackageJson, TsConfigJson,
+};
+use biome_rowan::{TextRange, TextSize};
+use std::path::{Path, PathBuf};
+
+fn module_info(code: &str) -> ModuleInfo {
+    let parsed = parse(code, JsFileSource::ts(), JsParserOptions::default());
+    ModuleInfo::from_js_root(&parsed.tree())
+}
+
+fn module_graph(modules: &[(&str, &str)]) -> ModuleGraph {
+    let graph = ModuleGraph::default();
+    for (path, code) in modules {
+        graph.insert(Path::new(path), module_info(code));
+    }
+    graph
+}
+
+fn export_names(info: &ModuleInfo) -> Vec<&str> {
+    info.exports
+        .iter()
+        .map(|export| export.name.as_str())
+        .collect()
+}
+
+#[test]
+fn collects_imports() {
+    let info = module_info(
+        r#"import a, { b, c as d, default as e } from "./a";
+import * as f from "./f";
+import type { G } from "./g";
+import { type H } from "./h";
+import "./side-effect";
+const i = require("./i");
+const j = await import("./j");
+"#,
+    );
+
+    let imports: Vec<_> = info
+        .imports
+        .iter()
+        .map(|import| {
+            (
+                import.specifier.as_str(),
+                import.kind,
+                import.symbols.clone(),
+                import.is_type_only,
+            )
+        })
+        .collect();
+    assert_eq!(
+        imports,
+        vec![
+            (
+                "./a",
+                ImportKind::Static,
+                vec![
+                    ImportedSymbol::Default,
+                    ImportedSymbol::Named("b".to_string()),
+                    ImportedSymbol::Named("c".to_string()),
+                    ImportedSymbol::Default,
+                ],
+                false
+            ),
+            (
+                "./f",
+                ImportKind::Static,
+                vec![ImportedSymbol::Namespace],
+                false
+            ),
+            (
+                "./g",
+                ImportKind::Static,
+                vec![ImportedSymbol::Named("G".to_string())],
+                true
+            ),
+            (
+                "./h",
+                ImportKind::Static,
+                vec![ImportedSymbol::Named("H".to_string())],
+                true
+            ),
+            ("./side-effect", ImportKind::Static, vec![], false),
+            (
+                "./i",
+                ImportKind::Require,
+                vec![ImportedSymbol::Namespace],
+                false
+            ),
+            (
+                "./j",
+                ImportKind::Dynamic,
+                vec![ImportedSymbol::Namespace],
+                false
+            ),
+        ]
+    );
+}
+
+#[test]
+fn collects_exports() {
+    let info = module_info(
+        r#"export const a = 1, { b, c: [d] = [] } = {};
+export function e() {}
+export class F {}
+export interface G {}
+export type H = string;
+export enum I {}
+const j = 1;
+export { j, j as k };
+export { l, m as n } from "./l";
+export * as o from "./o";
+export * from "./p";
+export default function () {}
+"#,
+    );
+
+    assert_eq!(
+        export_names(&info),
+        vec!["a", "b", "d", "e", "F", "G", "H", "I", "j", "k", "l", "n", "o", "default"]
+    );
+    let type_only_exports: Vec<_> = info
+        .exports
+        .iter()
+        .filter(|export| export.is_type_only)
+        .map(|export| export.name.as_str())
+        .collect();
+    assert_eq!(type_only_exports, vec!["G", "H"]);
+
+    let re_exports: Vec<_> = info
+        .imports
+        .iter()
+        .filter(|import| import.kind == ImportKind::ReExport)
+        .map(|import| (import.specifier.as_str(), import.symbols.clone()))
+        .collect();
+    assert_eq!(
+        re_exports,
+        vec![
+            (
+                "./l",
+                vec![
+                    ImportedSymbol::Named("l".to_string()),
+                    ImportedSymbol::Named("m".to_string())
+                ]
+            ),
+            ("./o", vec![ImportedSymbol::Namespace]),
+            ("./p", vec![ImportedSymbol::All]),
+        ]
+    );
+}
+
+#[test]
+fn collects_named_specifiers() {
+    let info = module_info(
+        r#"import a, { b, c as d, default as e } from "./a";
+export { f, g as h } from "./f";
+"#,
+    );
+
+    let specifier = |name: &str, start: u32, is_shorthand| NamedSpecifier {
+        name: name.to_string(),
+        range: TextRange::at(TextSize::from(start), TextSize::from(1)),
+        is_shorthand,
+    };
+    assert_eq!(
+        info.imports[0].named_specifiers,
+        vec![specifier("b", 12, true), specifier("c", 15, false)]
+    );
+    assert_eq!(
+        info.imports[1].named_specifiers,
+        vec![specifier("f", 59, true), specifier("g", 62, false)]
+    );
+}
+
+#[test]
+fn resolves_relative_specifiers() {
+    let graph = module_graph(&[
+        ("src/a.ts", ""),
+        ("src/b.js", ""),
+        ("src/utils/index.ts", ""),
+        ("src/c.tsx", ""),
+    ]);
+    let importer = Path::new("src/main.ts");
+
+    assert_eq!(
+        graph.resolve(importer, "./a").as_deref(),
+        Some(Path::new("src/a.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "./b.js").as_deref(),
+        Some(Path::new("src/b.js"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "./a.js").as_deref(),
+        Some(Path::new("src/a.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "./c.js").as_deref(),
+        Some(Path::new("src/c.tsx"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "./utils").as_deref(),
+        Some(Path::new("src/utils/index.ts"))
+    );
+    assert_eq!(
+        graph
+            .resolve(Path::new("./src/utils/index.ts"), "../a")
+            .as_deref(),
+        Some(Path::new("src/a.ts"))
+    );
+    assert_eq!(graph.resolve(importer, "./missing").as_deref(), None);
+    assert_eq!(graph.resolve(importer, "react").as_deref(), None);
+}
+
+#[test]
+fn resolves_tsconfig_paths() {
+    let graph = module_graph(&[
+        ("app/src/utils/index.ts", ""),
+        ("app/src/components/button.tsx", ""),
+        ("app/src/config.ts", ""),
+        ("app/vendor/config.ts", ""),
+        ("app/lib/other.ts", ""),
+        ("other/main.ts", ""),
+    ]);
+    graph.insert_tsconfig(
+        Path::new("app/tsconfig.json"),
+        TsConfigJson {
+            compiler_options: CompilerOptions {
+                base_url: Some("src".to_string()),
+                paths: [
+                    ("@/*".to_string(), vec!["./*".to_string()]),
+                    (
+                        "@/components/*".to_string(),
+                        vec!["./components/*.tsx".to_string()],
+                    ),
+                    (
+                        "config".to_string(),
+                        vec!["../vendor/config".to_string(), "./config".to_string()],
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            },
+        },
+    );
+    let importer = Path::new("app/lib/other.ts");
+
+    assert_eq!(
+        graph.resolve(importer, "@/utils").as_deref(),
+        Some(Path::new("app/src/utils/index.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "@/components/button").as_deref(),
+        Some(Path::new("app/src/components/button.tsx"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "config").as_deref(),
+        Some(Path::new("app/vendor/config.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "utils").as_deref(),
+        Some(Path::new("app/src/utils/index.ts"))
+    );
+    assert_eq!(
+        graph
+            .resolve(Path::new("other/main.ts"), "utils")
+            .as_deref(),
+        None
+    );
+}
+
+#[test]
+fn resolves_package_exports() {
+    let graph = module_graph(&[
+        ("packages/ui/src/index.ts", ""),
+        ("packages/ui/src/button.ts", ""),
+        ("packages/ui/src/internal.ts", ""),
+        ("packages/utils/index.js", ""),
+        ("packages/utils/strings.js", ""),
+        ("app/main.ts", ""),
+    ]);
+    graph.insert_package(
+        Path::new("packages/ui/package.json"),
+        PackageJson {
+            name: Some("@acme/ui".to_string()),
+            exports: Some(PackageExports::Map(vec![
+                (
+                    ".".to_string(),
+                    PackageExports::Map(vec![
+                        (
+                            "browser".to_string(),
+                            PackageExports::Target(Some("./dist/browser.js".to_string())),
+                        ),
+                        (
+                            "import".to_string(),
+                            PackageExports::Target(Some("./src/index.js".to_string())),
+                        ),
+                    ]),
+                ),
+                (
+                    "./*".to_string(),
+                    PackageExports::Target(Some("./src/*.ts".to_string())),
+                ),
+                ("./internal".to_string(), PackageExports::Target(None)),
+            ])),
+            ..PackageJson::default()
+        },
+    );
+    graph.insert_package(
+        Path::new("packages/utils/package.json"),
+        PackageJson {
+            name: Some("utils".to_string()),
+            ..PackageJson::default()
+        },
+    );
+    let importer = Path::new("app/main.ts");
+
+    assert_eq!(
+        graph.resolve(importer, "@acme/ui").as_deref(),
+        Some(Path::new("packages/ui/src/index.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "@acme/ui/button").as_deref(),
+        Some(Path::new("packages/ui/src/button.ts"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "@acme/ui/internal").as_deref(),
+        None
+    );
+    assert_eq!(
+        graph.resolve(importer, "utils").as_deref(),
+        Some(Path::new("packages/utils/index.js"))
+    );
+    assert_eq!(
+        graph.resolve(importer, "utils/strings").as_deref(),
+        Some(Path::new("packages/utils/strings.js"))
+    );
+    assert_eq!(graph.resolve(importer, "react").as_deref(), None);
+}
+
+#[test]
+fn finds_exports_through_re_exports() {
+    let graph = module_graph(&[
+        ("index.ts", r#"export * from "./a"; export * from "./b";"#),
+        ("a.ts", "export const a = 1; export default a;"),
+        ("b.ts", r#"export { c as b } from "./c";"#),
+        ("c.ts", "export const c = 1;"),
+        ("commonjs.js", "module.exports = {};"),
+        ("external.ts", r#"export * from "external";"#),
+    ]);
+
+    assert_eq!(graph.has_export(Path::new("index.ts"), "a"), Some(true));
+    assert_eq!(graph.has_export(Path::new("index.ts"), "b"), Some(true));
+    assert_eq!(graph.has_export(Path::new("index.ts"), "c"), Some(false));
+    assert_eq!(
+        graph.has_export(Path::new("index.ts"), "default"),
+        Some(false)
+    );
+    assert_eq!(graph.has_export(Path::new("commonjs.js"), "a"), None);
+    assert_eq!(graph.has_export(Path::new("external.ts"), "a"), None);
+    assert_eq!(graph.has_export(Path::new("missing.ts"), "a"), None);
+}
+
+#[test]
+fn finds_importers() {
+    let graph = module_graph(&[
+        ("a.ts", r#"import { b } from "./b";"#),
+        ("b.ts", "export const b = 1;"),
+        ("c.ts", r#"const b = require("./b.ts");"#),
+    ]);
+
+    let mut importers: Vec<_> = graph
+        .importers(Path::new("b.ts"))
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect();
+    importers.sort();
+    assert_eq!(
+        importers,
+        vec![PathBuf::from("a.ts"), PathBuf::from("c.ts")]
+    );
+}
+
+#[test]
+fn finds_import_cycles() {
+    let graph = module_graph(&[
+        ("a.ts", r#"import { b } from "./b";"#),
+        ("b.ts", r#"export { c as b } from "./c";"#),
+        ("c.ts", r#"import "./a"; export const c = 1;"#),
+        (
+            "d.ts",
+            r#"import type { E } from "./e"; export type D = string;"#,
+        ),
+        (
+            "e.ts",
+            r#"import type { D } from "./d"; export type E = string;"#,
+        ),
+        ("f.ts", r#"const g = () => import("./g");"#),
+        ("g.ts", r#"import "./f";"#),
+    ]);
+
+    assert_eq!(
+        graph.import_cycle(Path::new("a.ts")),
+        Some(vec![
+            PathBuf::from("b.ts"),
+            PathBuf::from("c.ts"),
+            PathBuf::from("a.ts")
+        ])
+    );
+    assert_eq!(graph.import_cycle(Path::new("d.ts")), None);
+    assert_eq!(graph.import_cycle(Path::new("f.ts")), None);
+    assert_eq!(graph.import_cycle(Path::new("g.ts")), None);
+}
+
+#[test]
+fn finds_import_chains() {
+    let graph = module_graph(&[
+        ("a.ts", r#"import "./b"; import "./c";"#),
+        ("b.ts", r#"import "./d";"#),
+        ("c.ts", r#"import "./e";"#),
+        ("d.ts", r#"import "./e";"#),
+        ("e.ts", ""),
+    ]);
+
+    assert_eq!(
+        graph.import_chain(Path::new("a.ts"), Path::new("e.ts")),
+        Some(vec![
+            PathBuf::from("a.ts"),
+            PathBuf::from("c.ts"),
+            PathBuf::from("e.ts")
+        ])
+    );
+    assert_eq!(
+        graph.import_chain(Path::new("a.ts"), Path::new("a.ts")),
+        Some(vec![PathBuf::from("a.ts")])
+    );
+    assert_eq!(
+        graph.import_chain(Path::new("e.ts"), Path::new("a.ts")),
+        None
+    );
+}
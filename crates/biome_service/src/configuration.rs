@@ -393,7 +393,7 @@ pub trait PartialConfigurationExt {
         fs: &DynRef<'_, dyn FileSystem>,
         relative_resolution_base_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError>;
+    ) -> Result<Vec<(Deserialized<PartialConfiguration>, PathBuf)>, WorkspaceError>;
 
     fn migrate_deprecated_fields(&mut self);
 
@@ -425,8 +425,16 @@ impl PartialConfigurationExt for PartialConfiguration {
         )?;
         let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
             .into_iter()
-            .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
+            .map(|(deserialized, directory)| {
+                let (config, diagnostics) = deserialized.consume();
+                let mut config = config.unwrap_or_default();
+                // The plugins are resolved from the directory of the configuration file that
+                // extends this one
+                config.plugins = config
+                    .plugins
+                    .map(|plugins| plugins.with_base_path(&directory));
+                (config, diagnostics)
+            })
             .unzip();
 
         let extended_configuration = configurations.into_iter().reduce(
@@ -458,7 +466,7 @@ impl PartialConfigurationExt for PartialConfiguration {
         fs: &DynRef<'_, dyn FileSystem>,
         relative_resolution_base_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError> {
+    ) -> Result<Vec<(Deserialized<PartialConfiguration>, PathBuf)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
@@ -467,22 +475,31 @@ impl PartialConfigurationExt for PartialConfiguration {
         for extend_entry in extends.iter() {
             let extend_entry_as_path = Path::new(extend_entry);
 
-            let extend_configuration_file_path = if extend_entry_as_path.starts_with(".")
+            // The directory of the extended configuration file, relative to the directory of
+            // this one when it's listed with a relative path
+            let (extend_configuration_file_path, extend_directory) = if extend_entry_as_path
+                .starts_with(".")
                 // TODO: Remove extension in Biome 2.0
                 || matches!(
                     extend_entry_as_path.extension().map(OsStr::as_encoded_bytes),
                     Some(b"json" | b"jsonc")
                 ) {
-                relative_resolution_base_path.join(extend_entry)
+                (
+                    relative_resolution_base_path.join(extend_entry),
+                    extend_entry_as_path.parent().map(Path::to_path_buf),
+                )
             } else {
-                fs.resolve_configuration(extend_entry.as_str(), external_resolution_base_path)
+                let path = fs
+                    .resolve_configuration(extend_entry.as_str(), external_resolution_base_path)
                     .map_err(|error| {
                         BiomeDiagnostic::cant_resolve(
                             external_resolution_base_path.display().to_string(),
                             error,
                         )
                     })?
-                    .into_path_buf()
+                    .into_path_buf();
+                let directory = path.parent().map(Path::to_path_buf);
+                (path, directory)
             };
 
             let mut file = fs
@@ -526,7 +543,7 @@ impl PartialConfigurationExt for PartialConfiguration {
                 },
                 "",
             );
-            deserialized_configurations.push(deserialized)
+            deserialized_configurations.push((deserialized, extend_directory.unwrap_or_default()))
         }
         Ok(deserialized_configurations)
     }
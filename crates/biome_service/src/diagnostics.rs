@@ -13,6 +13,7 @@ use biome_formatter::{FormatError, PrintError};
 use biome_fs::{BiomePath, FileSystemDiagnostic};
 use biome_grit_patterns::CompileError;
 use biome_js_analyze::utils::rename::RenameError;
+use biome_plugin_loader::PluginDiagnostic;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
@@ -62,6 +63,8 @@ pub enum WorkspaceError {
     ProtectedFile(ProtectedFile),
     /// Error when searching for a pattern
     SearchError(SearchError),
+    /// Error when loading a plugin
+    Plugin(PluginDiagnostic),
 }
 
 impl WorkspaceError {
@@ -472,6 +475,12 @@ impl From<VcsDiagnostic> for WorkspaceError {
     }
 }
 
+impl From<PluginDiagnostic> for WorkspaceError {
+    fn from(value: PluginDiagnostic) -> Self {
+        Self::Plugin(value)
+    }
+}
+
 impl From<CompileError> for WorkspaceError {
     fn from(value: CompileError) -> Self {
         match value {
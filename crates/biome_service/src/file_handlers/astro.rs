@@ -5,19 +5,19 @@ use crate::file_handlers::{
 };
 use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
+    DocumentFileSource, FixFileResult, FoldingRange, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use regex::{Matches, Regex, RegexBuilder};
 use std::sync::LazyLock;
 
-use super::SearchCapabilities;
+use super::{sfc, EditorCapabilities, SearchCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
@@ -80,6 +80,7 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                find_references: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -90,6 +91,11 @@ impl ExtensionHandler for AstroFileHandler {
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -122,7 +128,7 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     javascript::format(biome_path, document_file_source, parse, settings)
 }
@@ -147,7 +153,15 @@ pub(crate) fn format_on_type(
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    sfc::lint(params, EmbeddingKind::Astro)
+}
+
+fn folding_ranges(parse: AnyParse, text: &str) -> Vec<FoldingRange> {
+    sfc::folding_ranges(parse, text, EmbeddingKind::Astro)
+}
+
+fn selection_ranges(parse: AnyParse, text: &str, positions: &[TextSize]) -> Vec<Vec<TextRange>> {
+    sfc::selection_ranges(parse, text, positions, EmbeddingKind::Astro)
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
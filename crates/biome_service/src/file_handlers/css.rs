@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, SearchCapabilities,
+    editor, is_diagnostic_error, search, AnalyzerVisitorBuilder, CodeActionsParams,
+    EditorCapabilities, ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult,
+    SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -12,8 +13,8 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
-    OrganizeImportsResult, PullActionsResult,
+    CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, FoldingRange,
+    GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
 use biome_analyze::options::PreferredQuote;
@@ -147,11 +148,13 @@ impl ServiceLanguage for CssLanguage {
             globals: Vec::new(),
             preferred_quote,
             jsx_runtime: None,
+            plugins: global.map(|g| g.plugins.clone()).unwrap_or_default(),
         };
 
         AnalyzerOptions {
             configuration,
             file_path: file_path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -172,6 +175,7 @@ impl ExtensionHandler for CssFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                find_references: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -180,14 +184,21 @@ impl ExtensionHandler for CssFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<CssLanguage>),
+                semantic_tokens: None,
+            },
         }
     }
 }
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     settings: Option<&Settings>,
     cache: &mut NodeCache,
@@ -200,6 +211,9 @@ fn parse(
             .and_then(|s| s.languages.css.parser.css_modules)
             .unwrap_or_default(),
         grit_metavariables: false,
+        scss: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_scss()),
     };
     if let Some(settings) = settings {
         options = settings
@@ -242,7 +256,7 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<CssLanguage>(biome_path, document_file_source);
 
@@ -312,8 +326,10 @@ fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting CSS file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
-            let analyzer_options =
+            let mut analyzer_options =
                 workspace_settings.analyzer_options::<CssLanguage>(params.path, &params.language);
+            analyzer_options.suppressed_signals = params.include_suppressed;
+            let file_source = params.language.to_css_file_source().unwrap_or_default();
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -349,53 +365,64 @@ fn lint(params: LintParams) -> LintResults {
                 .iter()
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
+            let mut suppressed_diagnostics = Vec::new();
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) =
+                analyze(&tree, filter, &analyzer_options, file_source, |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map_or_else(
+                                || diagnostic.severity(),
+                                |category| {
+                                    rules
+                                        .as_ref()
+                                        .and_then(|rules| rules.get_severity_from_code(category))
+                                        .unwrap_or(Severity::Warning)
+                                },
+                            );
+
+                        // The diagnostics silenced by suppression comments are only listed by the
+                        // reporters, they don't count towards the errors nor the maximum
+                        if diagnostic.tags().is_suppressed() {
+                            let error = diagnostic.with_severity(severity);
+                            suppressed_diagnostics
+                                .push(biome_diagnostics::serde::Diagnostic::new(error));
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        diagnostic_count += 1;
+
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                });
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -405,6 +432,8 @@ fn lint(params: LintParams) -> LintResults {
             );
             let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
 
+            diagnostics.extend(suppressed_diagnostics);
+
             LintResults {
                 diagnostics,
                 errors,
@@ -428,6 +457,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
+        content: _,
         language,
         only,
         skip,
@@ -435,7 +466,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     debug_span!("Code actions CSS", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
         trace_span!("Parsed file", tree =? tree).in_scope(move || {
-            let Some(_) = language.to_css_file_source() else {
+            let Some(file_source) = language.to_css_file_source() else {
                 error!("Could not determine the file source of the file");
                 return PullActionsResult {
                     actions: Vec::new(),
@@ -464,7 +495,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
             info!("CSS runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
+            analyze(&tree, filter, &analyzer_options, file_source, |signal| {
                 actions.extend(signal.actions().into_code_action_iter().map(|item| {
                     CodeAction {
                         category: item.category.clone(),
@@ -519,10 +550,25 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<CssLanguage>(params.biome_path, &params.document_file_source);
+    let file_source = params
+        .document_file_source
+        .to_css_file_source()
+        .unwrap_or_default();
+    // The unused suppression comments can only be trusted when all the lint rules run
+    let ignores_unused_suppressions = !filter.categories.contains(RuleCategory::Lint)
+        || !params.only.is_empty()
+        || !params.skip.is_empty();
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+        let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
 
+            let is_unused_suppression = current_diagnostic.as_ref().is_some_and(|diagnostic| {
+                diagnostic.category() == Some(category!("suppressions/unused"))
+            });
+            if is_unused_suppression && ignores_unused_suppressions {
+                return ControlFlow::Continue(());
+            }
+
             if let Some(diagnostic) = current_diagnostic.as_ref() {
                 if is_diagnostic_error(diagnostic, rules.as_deref()) {
                     errors += 1;
@@ -554,6 +600,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             return ControlFlow::Break(action);
                         }
                     }
+                    FixFileMode::RemoveUnusedSuppressions => {
+                        if is_unused_suppression {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                 }
             }
 
@@ -610,6 +661,10 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     }
 }
 
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<CssLanguage>(), |_| None)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,262 @@
+//! The folding ranges and the selection ranges of a file, derived from its
+//! syntax tree. They are computed the same way for every language, and the
+//! languages add the folding ranges of the nodes that aren't delimited by
+//! brackets, e.g. the elements of JSX and HTML.
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_parser::AnyParse;
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, SyntaxTriviaPiece, TextRange, TextSize,
+    TokenAtOffset, TriviaPieceKind,
+};
+
+/// The pairs of tokens that delimit a block that can be folded
+const DELIMITERS: &[(&str, &str)] = &[("{", "}"), ("[", "]"), ("(", ")"), ("`", "`")];
+
+/// Returns the folding ranges of `root`: the multi-line blocks delimited by
+/// brackets, the runs of comments, the `#region` markers, and the ranges
+/// returned by `fold_node` for the other nodes.
+pub(crate) fn folding_ranges<L: Language>(
+    root: &SyntaxNode<L>,
+    fold_node: impl Fn(&SyntaxNode<L>) -> Option<TextRange>,
+) -> Vec<FoldingRange> {
+    let text = root.text();
+    let mut ranges = Vec::new();
+
+    for node in root.descendants() {
+        let mut blocks = delimited_blocks(&node);
+        blocks.extend(fold_node(&node));
+        ranges.extend(
+            blocks
+                .into_iter()
+                .filter(|range| text.slice(*range).contains_char('\n'))
+                .map(|range| FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Block,
+                }),
+        );
+    }
+
+    let mut comments = CommentFolds::default();
+    for token in root.descendants_tokens(Direction::Next) {
+        for piece in token.leading_trivia().pieces() {
+            comments.visit_piece(&piece);
+        }
+        comments.end_run();
+        for piece in token.trailing_trivia().pieces() {
+            if piece.is_comments() {
+                comments.visit_piece(&piece);
+                comments.end_run();
+            }
+        }
+    }
+    ranges.extend(comments.ranges);
+
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
+/// Returns the ranges between the delimiters of the blocks that are direct
+/// children of `node`, e.g. the members of `class A { ... }`
+fn delimited_blocks<L: Language>(node: &SyntaxNode<L>) -> Vec<TextRange> {
+    let mut blocks = Vec::new();
+    let mut openers: Vec<(&str, TextSize)> = Vec::new();
+    for token in node
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+    {
+        let text = token.text_trimmed();
+        let range = token.text_trimmed_range();
+        if let Some((closer, start)) = openers.last().copied() {
+            if closer == text {
+                openers.pop();
+                blocks.push(TextRange::new(start, range.start()));
+                continue;
+            }
+        }
+        if let Some((_, closer)) = DELIMITERS.iter().find(|(opener, _)| *opener == text) {
+            openers.push((*closer, range.end()));
+        }
+    }
+    blocks
+}
+
+/// Collects the runs of single-line comments, the multi-line comments and
+/// the regions delimited by `#region` and `#endregion` comments
+#[derive(Default)]
+struct CommentFolds {
+    ranges: Vec<FoldingRange>,
+    /// The first and the last comments of the current run
+    run: Option<(TextRange, TextRange)>,
+    /// The number of line breaks since the last comment of the run
+    newlines: usize,
+    /// The ranges of the `#region` comments that aren't closed yet
+    regions: Vec<TextRange>,
+}
+
+impl CommentFolds {
+    fn visit_piece<L: Language>(&mut self, piece: &SyntaxTriviaPiece<L>) {
+        match piece.kind() {
+            TriviaPieceKind::Newline => {
+                self.newlines += 1;
+                if self.newlines > 1 {
+                    self.end_run();
+                }
+            }
+            TriviaPieceKind::Whitespace => {}
+            TriviaPieceKind::SingleLineComment | TriviaPieceKind::MultiLineComment => {
+                let range = piece.text_range();
+                match region_marker(piece.text()) {
+                    Some(RegionMarker::Start) => {
+                        self.end_run();
+                        self.regions.push(range);
+                    }
+                    Some(RegionMarker::End) => {
+                        self.end_run();
+                        if let Some(start) = self.regions.pop() {
+                            self.ranges.push(FoldingRange {
+                                range: start.cover(range),
+                                kind: FoldingRangeKind::Region,
+                            });
+                        }
+                    }
+                    None if piece.kind().is_multiline_comment() => {
+                        self.end_run();
+                        if piece.text().contains('\n') {
+                            self.ranges.push(FoldingRange {
+                                range,
+                                kind: FoldingRangeKind::Comment,
+                            });
+                        }
+                    }
+                    None => {
+                        self.run = match self.run {
+                            Some((first, _)) if self.newlines == 1 => Some((first, range)),
+                            _ => {
+                                self.end_run();
+                                Some((range, range))
+                            }
+                        };
+                    }
+                }
+                self.newlines = 0;
+            }
+            TriviaPieceKind::Skipped => self.end_run(),
+        }
+    }
+
+    /// Folds the current run of single-line comments if it has more than one
+    /// comment
+    fn end_run(&mut self) {
+        if let Some((first, last)) = self.run.take() {
+            if first != last {
+                self.ranges.push(FoldingRange {
+                    range: first.cover(last),
+                    kind: FoldingRangeKind::Comment,
+                });
+            }
+        }
+        self.newlines = 0;
+    }
+}
+
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Returns the marker of a `// #region`, `/* #region */`, `<!-- #region -->`
+/// or `# region` comment
+fn region_marker(comment: &str) -> Option<RegionMarker> {
+    let body = ["//", "/*", "<!--"]
+        .iter()
+        .find_map(|prefix| comment.strip_prefix(prefix))
+        .and_then(|body| body.trim_start().strip_prefix('#'))
+        .or_else(|| comment.strip_prefix('#'))?
+        .trim_start();
+    // The keyword must be a whole word, e.g. `// #regional` isn't a marker
+    let is_keyword = |keyword: &str| {
+        body.strip_prefix(keyword).is_some_and(|rest| {
+            !rest
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_')
+        })
+    };
+    if is_keyword("endregion") {
+        Some(RegionMarker::End)
+    } else if is_keyword("region") {
+        Some(RegionMarker::Start)
+    } else {
+        None
+    }
+}
+
+/// Returns, for each position, the ranges of the token or the comment at the
+/// position and of its ancestors, from the innermost to the outermost
+pub(crate) fn selection_ranges<L: Language + 'static>(
+    parse: AnyParse,
+    _text: &str,
+    positions: &[TextSize],
+) -> Vec<Vec<TextRange>> {
+    let root = parse.syntax::<L>();
+    positions
+        .iter()
+        .map(|position| selection_ranges_at(&root, *position))
+        .collect()
+}
+
+/// Returns the ranges of the token or the comment at the position and of its
+/// ancestors in the tree, from the innermost to the outermost
+pub(crate) fn selection_ranges_at<L: Language>(
+    root: &SyntaxNode<L>,
+    position: TextSize,
+) -> Vec<TextRange> {
+    let token = match root.token_at_offset(position) {
+        TokenAtOffset::None => return Vec::new(),
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if is_word(&right) || !is_word(&left) {
+                right
+            } else {
+                left
+            }
+        }
+    };
+
+    let mut ranges: Vec<TextRange> = Vec::new();
+    let mut push = |range: TextRange| {
+        if ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    };
+    if let Some(comment) = token
+        .leading_trivia()
+        .pieces()
+        .chain(token.trailing_trivia().pieces())
+        .find(|piece| piece.is_comments() && piece.text_range().contains(position))
+    {
+        push(comment.text_range());
+        // The trimmed ranges of the ancestors don't contain the comment
+        // when it's at their start or at their end
+        for node in token.ancestors() {
+            push(node.text_range());
+        }
+        return ranges;
+    }
+    if token.text_trimmed_range().contains_inclusive(position) {
+        push(token.text_trimmed_range());
+    }
+    for node in token.ancestors() {
+        push(node.text_trimmed_range());
+    }
+    ranges
+}
+
+/// Whether the token is a name or a literal, rather than a punctuation
+fn is_word<L: Language>(token: &SyntaxToken<L>) -> bool {
+    token
+        .text_trimmed()
+        .chars()
+        .any(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
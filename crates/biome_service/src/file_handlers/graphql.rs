@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
+    editor, is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
+    EditorCapabilities, ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult,
+    SearchCapabilities,
 };
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
@@ -11,7 +12,8 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    CodeAction, FixAction, FixFileMode, FixFileResult, FoldingRange, GetSyntaxTreeResult,
+    PullActionsResult,
 };
 use crate::WorkspaceError;
 use biome_analyze::{
@@ -145,6 +147,7 @@ impl ServiceLanguage for GraphqlLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -165,6 +168,7 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                find_references: None,
                 fix_all: Some(fix_all),
                 organize_imports: None,
             },
@@ -174,6 +178,11 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_on_type: Some(format_on_type),
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<GraphqlLanguage>),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -222,7 +231,7 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<GraphqlLanguage>(biome_path, document_file_source);
 
@@ -292,8 +301,9 @@ fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
-            let analyzer_options = workspace_settings
+            let mut analyzer_options = workspace_settings
                 .analyzer_options::<GraphqlLanguage>(params.path, &params.language);
+            analyzer_options.suppressed_signals = params.include_suppressed;
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -329,6 +339,7 @@ fn lint(params: LintParams) -> LintResults {
                 .iter()
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
+            let mut suppressed_diagnostics = Vec::new();
 
             info!("Analyze file {}", params.path.display());
             let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
@@ -340,8 +351,6 @@ fn lint(params: LintParams) -> LintResults {
                         return ControlFlow::<Never>::Continue(());
                     }
 
-                    diagnostic_count += 1;
-
                     // We do now check if the severity of the diagnostics should be changed.
                     // The configuration allows to change the severity of the diagnostics emitted by rules.
                     let severity = diagnostic
@@ -357,6 +366,17 @@ fn lint(params: LintParams) -> LintResults {
                             },
                         );
 
+                    // The diagnostics silenced by suppression comments are only listed by the
+                    // reporters, they don't count towards the errors nor the maximum
+                    if diagnostic.tags().is_suppressed() {
+                        let error = diagnostic.with_severity(severity);
+                        suppressed_diagnostics
+                            .push(biome_diagnostics::serde::Diagnostic::new(error));
+                        return ControlFlow::<Never>::Continue(());
+                    }
+
+                    diagnostic_count += 1;
+
                     if severity >= Severity::Error {
                         errors += 1;
                     }
@@ -385,6 +405,8 @@ fn lint(params: LintParams) -> LintResults {
             );
             let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
 
+            diagnostics.extend(suppressed_diagnostics);
+
             LintResults {
                 diagnostics,
                 errors,
@@ -402,6 +424,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
+        content: _,
         language,
         only,
         skip,
@@ -494,10 +518,21 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<GraphqlLanguage>(params.biome_path, &params.document_file_source);
+    // The unused suppression comments can only be trusted when all the lint rules run
+    let ignores_unused_suppressions = !filter.categories.contains(RuleCategory::Lint)
+        || !params.only.is_empty()
+        || !params.skip.is_empty();
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
             let current_diagnostic = signal.diagnostic();
 
+            let is_unused_suppression = current_diagnostic.as_ref().is_some_and(|diagnostic| {
+                diagnostic.category() == Some(category!("suppressions/unused"))
+            });
+            if is_unused_suppression && ignores_unused_suppressions {
+                return ControlFlow::Continue(());
+            }
+
             if let Some(diagnostic) = current_diagnostic.as_ref() {
                 if is_diagnostic_error(diagnostic, rules.as_deref()) {
                     errors += 1;
@@ -529,6 +564,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                             return ControlFlow::Break(action);
                         }
                     }
+                    FixFileMode::RemoveUnusedSuppressions => {
+                        if is_unused_suppression {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                 }
             }
 
@@ -582,3 +622,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         }
     }
 }
+
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<GraphqlLanguage>(), |_| None)
+}
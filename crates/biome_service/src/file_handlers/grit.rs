@@ -1,5 +1,6 @@
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::FoldingRange,
     WorkspaceError,
 };
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
@@ -12,8 +13,9 @@ use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    editor, AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource,
+    EditorCapabilities, ExtensionHandler, FormatterCapabilities, ParseResult, ParserCapabilities,
+    SearchCapabilities,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -99,6 +101,7 @@ impl ServiceLanguage for GritLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -119,6 +122,7 @@ impl ExtensionHandler for GritFileHandler {
                 lint: None,
                 code_actions: None,
                 rename: None,
+                find_references: None,
                 fix_all: None,
                 organize_imports: None,
             },
@@ -128,6 +132,11 @@ impl ExtensionHandler for GritFileHandler {
                 format_on_type: None,
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<GritLanguage>),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -152,7 +161,7 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<GritLanguage>(biome_path, document_file_source);
 
@@ -166,3 +175,7 @@ fn format(
         Err(error) => Err(WorkspaceError::FormatError(error.into())),
     }
 }
+
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<GritLanguage>(), |_| None)
+}
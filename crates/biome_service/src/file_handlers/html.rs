@@ -1,21 +1,33 @@
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
+use biome_css_syntax::CssFileSource;
+use biome_diagnostics::{Diagnostic, Severity};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::BiomePath;
-use biome_html_formatter::{format_node, HtmlFormatOptions};
-use biome_html_parser::parse_html_with_cache;
-use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_html_formatter::{format_node, format_node_with_embedded_code, HtmlFormatOptions};
+use biome_html_parser::{parse_html, parse_html_with_cache};
+use biome_html_syntax::{HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_js_parser::{parse_js_with_cache, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize};
+use biome_string_case::StrOnlyExtension;
+use biome_text_edit::TextEdit;
 
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
-    workspace::GetSyntaxTreeResult,
+    workspace::{
+        CodeAction, FileFeaturesResult, FixAction, FixFileResult, FoldingRange,
+        GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    },
     WorkspaceError,
 };
 
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    editor, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    DocumentFileSource, EditorCapabilities, ExtensionHandler, Features, FixAllParams,
+    FormatterCapabilities, LintParams, LintResults, ParseResult, ParserCapabilities,
+    SearchCapabilities,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -102,6 +114,7 @@ impl ServiceLanguage for HtmlLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -119,11 +132,12 @@ impl ExtensionHandler for HtmlFileHandler {
                 debug_formatter_ir: Some(debug_formatter_ir),
             },
             analyzer: AnalyzerCapabilities {
-                lint: None,
-                code_actions: None,
+                lint: Some(lint),
+                code_actions: Some(code_actions),
                 rename: None,
-                fix_all: None,
-                organize_imports: None,
+                find_references: None,
+                fix_all: Some(fix_all),
+                organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -131,6 +145,11 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_on_type: None,
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<HtmlLanguage>),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -179,17 +198,467 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<HtmlLanguage>(biome_path, document_file_source);
 
     tracing::debug!("Format with the following options: \n{}", options);
 
     let tree = parse.syntax();
-    let formatted = format_node(options, &tree)?;
+    let embedded_code = format_embedded_code(biome_path, &tree, settings);
+    let formatted = format_node_with_embedded_code(options, &tree, embedded_code)?;
 
     match formatted.print() {
         Ok(printed) => Ok(printed),
         Err(error) => Err(WorkspaceError::FormatError(error.into())),
     }
 }
+
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    tree_folding_ranges(&parse.syntax::<HtmlLanguage>())
+}
+
+/// Returns the folding ranges of an HTML tree, with the content of the elements
+pub(crate) fn tree_folding_ranges(root: &HtmlSyntaxNode) -> Vec<FoldingRange> {
+    editor::folding_ranges(root, |node| {
+        let element = HtmlElement::cast_ref(node)?;
+        // The elements whose end tag is omitted are folded until the end of their content
+        let end = match element.closing_element() {
+            Some(closing_element) => closing_element.range().start(),
+            None => element.children().range().end(),
+        };
+        Some(TextRange::new(
+            element.opening_element().ok()?.range().end(),
+            end,
+        ))
+    })
+}
+
+/// The content of a `<script>` or `<style>` element, written in a language supported by Biome.
+pub(crate) struct EmbeddedContent {
+    /// The range of the `<script>` or `<style>` element
+    pub(crate) element_range: TextRange,
+    /// The range of the content of the element
+    pub(crate) content_range: TextRange,
+    /// The language of the content
+    pub(crate) file_source: DocumentFileSource,
+}
+
+/// The `type` of the `<script>` elements that contain JavaScript.
+///
+/// See: https://mimesniff.spec.whatwg.org/#javascript-mime-type
+const JAVASCRIPT_MIME_TYPES: &[&str] = &[
+    "application/ecmascript",
+    "application/javascript",
+    "application/x-ecmascript",
+    "application/x-javascript",
+    "text/ecmascript",
+    "text/javascript",
+    "text/javascript1.0",
+    "text/javascript1.1",
+    "text/javascript1.2",
+    "text/javascript1.3",
+    "text/javascript1.4",
+    "text/javascript1.5",
+    "text/jscript",
+    "text/livescript",
+    "text/x-ecmascript",
+    "text/x-javascript",
+];
+
+/// The `type` of the `<script>` elements that contain JSON.
+const JSON_SCRIPT_TYPES: &[&str] = &[
+    "application/json",
+    "application/ld+json",
+    "importmap",
+    "speculationrules",
+];
+
+impl EmbeddedContent {
+    /// Returns the contents of the `<script>` and `<style>` elements of the document whose language is supported.
+    fn collect(root: &HtmlSyntaxNode) -> Vec<Self> {
+        root.descendants()
+            .filter_map(HtmlElement::cast)
+            .filter_map(|element| {
+                let file_source = Self::file_source(&element)?;
+                Some(Self {
+                    element_range: element.range(),
+                    content_range: element.children().range(),
+                    file_source,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the language of the content of the element, if it's a `<script>` or a `<style>`
+    /// element with a supported type.
+    ///
+    /// See: https://html.spec.whatwg.org/#attr-script-type
+    fn file_source(element: &HtmlElement) -> Option<DocumentFileSource> {
+        if element.is_style_tag() {
+            return Some(CssFileSource::css().into());
+        }
+        if !element.is_script_tag() {
+            return None;
+        }
+
+        let script_type = element
+            .find_attribute_by_name("type")
+            .and_then(|attribute| attribute.value());
+        let Some(script_type) = script_type else {
+            return Some(JsFileSource::js_script().into());
+        };
+        let script_type = script_type.trim().to_ascii_lowercase_cow();
+        if script_type.is_empty() || JAVASCRIPT_MIME_TYPES.contains(&script_type.as_ref()) {
+            Some(JsFileSource::js_script().into())
+        } else if script_type == "module" {
+            Some(JsFileSource::js_module().into())
+        } else if JSON_SCRIPT_TYPES.contains(&script_type.as_ref()) {
+            Some(JsonFileSource::json().into())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the features supported for the content, according to the settings of the document.
+    pub(crate) fn features(
+        &self,
+        capabilities: &Capabilities,
+        biome_path: &BiomePath,
+        settings: &WorkspaceSettingsHandle,
+    ) -> FileFeaturesResult {
+        let features = FileFeaturesResult::new().with_capabilities(capabilities);
+        match settings.settings() {
+            Some(settings) => {
+                features.with_settings_and_language(settings, &self.file_source, biome_path)
+            }
+            None => features,
+        }
+    }
+
+    /// Parses the content with the parser of its language.
+    pub(crate) fn parse(
+        &self,
+        text: &str,
+        capabilities: &Capabilities,
+        biome_path: &BiomePath,
+        settings: &WorkspaceSettingsHandle,
+        cache: &mut NodeCache,
+    ) -> Option<AnyParse> {
+        let parse = capabilities.parser.parse?;
+        let parse = parse(
+            biome_path,
+            self.file_source,
+            &text[self.content_range],
+            settings.settings(),
+            cache,
+        );
+        Some(parse.any_parse)
+    }
+
+    /// Converts a code action on the content to a code action on the document.
+    fn to_document_action(&self, mut action: CodeAction, text: &str) -> CodeAction {
+        let offset = self.content_range.start();
+        let suggestion = &mut action.suggestion;
+        suggestion.span += offset;
+        for label in &mut suggestion.labels {
+            *label += offset;
+        }
+
+        let content = &text[self.content_range];
+        let new_content = suggestion.suggestion.new_string(content);
+        let mut builder = TextEdit::builder();
+        builder.equal(&text[..self.content_range.start().into()]);
+        builder.with_unicode_words_diff(content, &new_content);
+        builder.equal(&text[self.content_range.end().into()..]);
+        suggestion.suggestion = builder.finish();
+
+        action
+    }
+}
+
+/// Formats the content of the `<script>` and `<style>` elements with the formatter of its language.
+///
+/// The contents whose language has its formatter disabled, or that have syntax errors
+/// or can't be formatted, are left as they are.
+fn format_embedded_code(
+    biome_path: &BiomePath,
+    root: &HtmlSyntaxNode,
+    settings: &WorkspaceSettingsHandle,
+) -> Vec<(TextRange, String)> {
+    let features = Features::new();
+    let mut cache = NodeCache::default();
+    let text = root.to_string();
+
+    EmbeddedContent::collect(root)
+        .into_iter()
+        .filter_map(|content| {
+            let capabilities = features.get_file_source_capabilities(content.file_source);
+            if !content
+                .features(&capabilities, biome_path, settings)
+                .supports_format()
+            {
+                return None;
+            }
+
+            let format = capabilities.formatter.format?;
+            let parse = content.parse(&text, &capabilities, biome_path, settings, &mut cache)?;
+            if parse.has_errors() {
+                return None;
+            }
+
+            let printed = format(biome_path, &content.file_source, parse, settings).ok()?;
+            Some((content.element_range, printed.into_code()))
+        })
+        .collect()
+}
+
+/// Lints the content of the `<script>` and `<style>` elements with the linter of its language.
+fn lint(params: LintParams) -> LintResults {
+    let root: HtmlSyntaxNode = params.parse.syntax();
+    let text = root.to_string();
+    let features = Features::new();
+    let mut cache = NodeCache::default();
+
+    let mut diagnostics = params.parse.into_diagnostics();
+    let mut errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity() <= Severity::Error)
+        .count();
+    let mut skipped_diagnostics = 0;
+
+    for content in EmbeddedContent::collect(&root) {
+        let capabilities = features.get_file_source_capabilities(content.file_source);
+        if !content
+            .features(&capabilities, params.path, params.workspace)
+            .supports_lint()
+        {
+            continue;
+        }
+        let Some(lint) = capabilities.analyzer.lint else {
+            continue;
+        };
+        let Some(parse) = content.parse(
+            &text,
+            &capabilities,
+            params.path,
+            params.workspace,
+            &mut cache,
+        ) else {
+            continue;
+        };
+
+        let results = lint(LintParams {
+            parse,
+            workspace: params.workspace,
+            language: content.file_source,
+            max_diagnostics: params.max_diagnostics,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            include_suppressed: params.include_suppressed,
+            manifest: params.manifest.clone(),
+            module_graph: params.module_graph.clone(),
+            content: &text[content.content_range],
+        });
+
+        let offset = content.content_range.start();
+        diagnostics.extend(
+            results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(offset)),
+        );
+        errors += results.errors;
+        skipped_diagnostics += results.skipped_diagnostics;
+    }
+
+    LintResults {
+        diagnostics,
+        errors,
+        skipped_diagnostics,
+    }
+}
+
+/// Pulls the code actions of the content of the `<script>` and `<style>` elements within the given range.
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let root: HtmlSyntaxNode = params.parse.syntax();
+    let text = root.to_string();
+    let features = Features::new();
+    let mut cache = NodeCache::default();
+    let mut actions = Vec::new();
+
+    for content in EmbeddedContent::collect(&root) {
+        let range = match params.range {
+            Some(range) => match range.intersect(content.content_range) {
+                Some(range) => Some(range - content.content_range.start()),
+                None => continue,
+            },
+            None => None,
+        };
+
+        let capabilities = features.get_file_source_capabilities(content.file_source);
+        let file_features = content.features(&capabilities, params.path, params.workspace);
+        if !file_features.supports_lint() && !file_features.supports_assists() {
+            continue;
+        }
+        let Some(code_actions) = capabilities.analyzer.code_actions else {
+            continue;
+        };
+        let Some(parse) = content.parse(
+            &text,
+            &capabilities,
+            params.path,
+            params.workspace,
+            &mut cache,
+        ) else {
+            continue;
+        };
+
+        let result = code_actions(CodeActionsParams {
+            parse,
+            range,
+            workspace: params.workspace,
+            path: params.path,
+            manifest: params.manifest.clone(),
+            module_graph: params.module_graph.clone(),
+            language: content.file_source,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            content: &text[content.content_range],
+        });
+        actions.extend(
+            result
+                .actions
+                .into_iter()
+                .map(|action| content.to_document_action(action, &text)),
+        );
+    }
+
+    PullActionsResult { actions }
+}
+
+/// Applies the fixes to the content of the `<script>` and `<style>` elements.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let root: HtmlSyntaxNode = params.parse.syntax();
+    let text = root.to_string();
+    let features = Features::new();
+    let mut cache = NodeCache::default();
+
+    let mut code = String::with_capacity(text.len());
+    let mut last_end = TextSize::from(0);
+    let mut actions = Vec::new();
+    let mut errors = 0;
+    let mut skipped_suggested_fixes = 0;
+
+    for content in EmbeddedContent::collect(&root) {
+        let capabilities = features.get_file_source_capabilities(content.file_source);
+        if !content
+            .features(&capabilities, params.biome_path, params.workspace)
+            .supports_lint()
+        {
+            continue;
+        }
+        let Some(fix_all) = capabilities.analyzer.fix_all else {
+            continue;
+        };
+        let Some(parse) = content.parse(
+            &text,
+            &capabilities,
+            params.biome_path,
+            params.workspace,
+            &mut cache,
+        ) else {
+            continue;
+        };
+        if parse.has_errors() {
+            continue;
+        }
+
+        let result = fix_all(FixAllParams {
+            parse,
+            fix_file_mode: params.fix_file_mode,
+            workspace: params.workspace,
+            // The whole document is formatted at the end
+            should_format: false,
+            biome_path: params.biome_path,
+            manifest: params.manifest.clone(),
+            module_graph: params.module_graph.clone(),
+            document_file_source: content.file_source,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            rule_categories: params.rule_categories,
+            content: &text[content.content_range],
+        })?;
+
+        code.push_str(&text[TextRange::new(last_end, content.content_range.start())]);
+        let offset = TextSize::of(&code);
+        code.push_str(&result.code);
+        last_end = content.content_range.end();
+
+        actions.extend(result.actions.into_iter().map(|action| FixAction {
+            range: action.range + offset,
+            ..action
+        }));
+        errors += result.errors;
+        skipped_suggested_fixes += result.skipped_suggested_fixes;
+    }
+    code.push_str(&text[last_end.into()..]);
+
+    if params.should_format {
+        let parse = parse_html(&code);
+        if !parse.has_errors() {
+            code = format(
+                params.biome_path,
+                &params.document_file_source,
+                parse.into(),
+                params.workspace,
+            )?
+            .into_code();
+        }
+    }
+
+    Ok(FixFileResult {
+        code,
+        actions,
+        errors,
+        skipped_suggested_fixes,
+    })
+}
+
+/// Organizes the imports of the module scripts.
+fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let root: HtmlSyntaxNode = parse.syntax();
+    let text = root.to_string();
+    let mut cache = NodeCache::default();
+
+    let mut code = String::with_capacity(text.len());
+    let mut last_end = TextSize::from(0);
+    for content in EmbeddedContent::collect(&root) {
+        let Some(file_source) = content.file_source.to_js_file_source() else {
+            continue;
+        };
+        if !file_source.is_module() {
+            continue;
+        }
+
+        let parse = parse_js_with_cache(
+            &text[content.content_range],
+            file_source,
+            JsParserOptions::default(),
+            &mut cache,
+        );
+        if parse.has_errors() {
+            continue;
+        }
+
+        let result = javascript::organize_imports(parse.into())?;
+        code.push_str(&text[TextRange::new(last_end, content.content_range.start())]);
+        code.push_str(&result.code);
+        last_end = content.content_range.end();
+    }
+    code.push_str(&text[last_end.into()..]);
+
+    Ok(OrganizeImportsResult { code })
+}
@@ -1,7 +1,7 @@
 use super::{
-    search, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    editor, search, sfc, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams,
+    DebugCapabilities, EditorCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
+    LintResults, ParseResult, ParserCapabilities, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
@@ -14,8 +14,9 @@ use crate::{
         WorkspaceSettingsHandle,
     },
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, FixAction, FixFileMode, FixFileResult, FoldingRange, FoldingRangeKind,
+        GetSyntaxTreeResult, PullActionsResult, RenameResult, SemanticToken, SemanticTokenKind,
+        SemanticTokenModifier, SymbolReference, SymbolReferenceKind,
     },
     WorkspaceError,
 };
@@ -31,17 +32,23 @@ use biome_formatter::{
     LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
+use biome_js_analyze::globals::{is_js_global, is_ts_global};
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
-use biome_js_analyze::{analyze, analyze_with_inspect_matcher, ControlFlowGraph};
+use biome_js_analyze::{
+    analyze, analyze_with_inspect_matcher, ControlFlowGraph, JsAnalyzerServices,
+};
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
     ArrowParentheses, BracketSameLine, JsFormatOptions, QuoteProperties, Semicolons,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, Binding, SemanticModelOptions, TemplateReferences};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    AnyJsModuleItem, AnyJsRoot, JsFileSource, JsIdentifierAssignment, JsLanguage,
+    JsReferenceIdentifier, JsSyntaxNode, JsxElement, JsxReferenceIdentifier, TextRange, TextSize,
+    TokenAtOffset,
 };
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
@@ -274,11 +281,13 @@ impl ServiceLanguage for JsLanguage {
             globals,
             preferred_quote,
             jsx_runtime,
+            plugins: global.map(|g| g.plugins.clone()).unwrap_or_default(),
         };
 
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -300,6 +309,7 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                find_references: Some(find_references),
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
@@ -310,6 +320,11 @@ impl ExtensionHandler for JsFileHandler {
             search: SearchCapabilities {
                 search: Some(search),
             },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<JsLanguage>),
+                semantic_tokens: Some(semantic_tokens),
+            },
         }
     }
 }
@@ -391,7 +406,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         },
         &options,
         JsFileSource::default(),
-        None,
+        JsAnalyzerServices::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -414,6 +429,20 @@ fn debug_formatter_ir(
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
+    let template_references = params
+        .language
+        .to_js_file_source()
+        .map(|file_source| sfc::template_references(params.content, file_source))
+        .unwrap_or_default();
+    lint_script(params, template_references)
+}
+
+/// Lints a script, given the names referenced by the template of the Vue, Svelte or Astro
+/// component that embeds it.
+pub(crate) fn lint_script(
+    params: LintParams,
+    template_references: TemplateReferences,
+) -> LintResults {
     debug_span!("Linting JavaScript file", path =? params.path, language =? params.language)
         .in_scope(move || {
             let Some(file_source) = params
@@ -428,9 +457,10 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 };
             };
             let tree = params.parse.tree();
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsLanguage>(params.path, &params.language);
+            analyzer_options.suppressed_signals = params.include_suppressed;
 
             let rules = params
                 .workspace
@@ -461,14 +491,19 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 .iter()
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
+            let mut suppressed_diagnostics = Vec::new();
 
             info!("Analyze file {}", params.path.display());
             let (_, analyze_diagnostics) = analyze(
                 &tree,
                 filter,
-                analyzer_options,
+                &analyzer_options,
                 file_source,
-                params.manifest,
+                JsAnalyzerServices {
+                    manifest: params.manifest,
+                    module_graph: params.module_graph,
+                    template_references,
+                },
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -477,8 +512,6 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                             return ControlFlow::<Never>::Continue(());
                         }
 
-                        diagnostic_count += 1;
-
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
                         let severity = diagnostic
@@ -494,6 +527,17 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                                 },
                             );
 
+                        // The diagnostics silenced by suppression comments are only listed by the
+                        // reporters, they don't count towards the errors nor the maximum
+                        if diagnostic.tags().is_suppressed() {
+                            let error = diagnostic.with_severity(severity);
+                            suppressed_diagnostics
+                                .push(biome_diagnostics::serde::Diagnostic::new(error));
+                            return ControlFlow::<Never>::Continue(());
+                        }
+
+                        diagnostic_count += 1;
+
                         if severity >= Severity::Error {
                             errors += 1;
                         }
@@ -523,6 +567,8 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             );
             let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
 
+            diagnostics.extend(suppressed_diagnostics);
+
             LintResults {
                 diagnostics,
                 errors,
@@ -539,9 +585,11 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        module_graph,
         language,
         only,
         skip,
+        content,
     } = params;
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
         let tree = parse.tree();
@@ -553,6 +601,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                     .with_syntax_rules()
                     .with_linter_rules(&only, &skip, params.path.as_path())
                     .with_assists_rules(&only, &skip, params.path.as_path())
+                    .with_refactor_rules()
                     .finish();
 
             let filter = AnalysisFilter {
@@ -579,7 +628,11 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 filter,
                 &analyzer_options,
                 source_type,
-                manifest,
+                JsAnalyzerServices {
+                    manifest,
+                    module_graph,
+                    template_references: sfc::template_references(content, source_type),
+                },
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -642,16 +695,32 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+    // The unused suppression comments can only be trusted when all the lint rules run
+    let ignores_unused_suppressions = !filter.categories.contains(RuleCategory::Lint)
+        || !params.only.is_empty()
+        || !params.skip.is_empty();
+    let template_references = sfc::template_references(params.content, file_source);
     loop {
         let (action, _) = analyze(
             &tree,
             filter,
             &analyzer_options,
             file_source,
-            params.manifest.clone(),
+            JsAnalyzerServices {
+                manifest: params.manifest.clone(),
+                module_graph: params.module_graph.clone(),
+                template_references: template_references.clone(),
+            },
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
+                let is_unused_suppression = current_diagnostic.as_ref().is_some_and(|diagnostic| {
+                    diagnostic.category() == Some(category!("suppressions/unused"))
+                });
+                if is_unused_suppression && ignores_unused_suppressions {
+                    return ControlFlow::Continue(());
+                }
+
                 if let Some(diagnostic) = current_diagnostic.as_ref() {
                     if is_diagnostic_error(diagnostic, rules.as_deref()) {
                         errors += 1;
@@ -683,6 +752,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                                 return ControlFlow::Break(action);
                             }
                         }
+                        FixFileMode::RemoveUnusedSuppressions => {
+                            if is_unused_suppression {
+                                return ControlFlow::Break(action);
+                            }
+                        }
                     }
                 }
 
@@ -745,7 +819,7 @@ pub(crate) fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
 
@@ -845,7 +919,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.as_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        module_edits: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -857,6 +935,222 @@ fn rename(
     }
 }
 
+/// Returns the folding ranges of the file, with the JSX elements and the runs
+/// of imports
+pub(crate) fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    let root: AnyJsRoot = parse.tree();
+    let mut ranges = editor::folding_ranges(root.syntax(), |node| {
+        let element = JsxElement::cast_ref(node)?;
+        Some(TextRange::new(
+            element.opening_element().ok()?.range().end(),
+            element.closing_element().ok()?.range().start(),
+        ))
+    });
+
+    if let AnyJsRoot::JsModule(module) = &root {
+        let mut imports: Vec<TextRange> = Vec::new();
+        let mut import_runs = Vec::new();
+        for item in module.items() {
+            if let AnyJsModuleItem::JsImport(import) = item {
+                imports.push(import.range());
+            } else if !imports.is_empty() {
+                import_runs.push(std::mem::take(&mut imports));
+            }
+        }
+        import_runs.push(imports);
+        ranges.extend(
+            import_runs
+                .into_iter()
+                .filter(|run| run.len() > 1)
+                .map(|run| FoldingRange {
+                    range: run[0].cover(run[run.len() - 1]),
+                    kind: FoldingRangeKind::Imports,
+                }),
+        );
+        ranges.sort_by_key(|folding_range| folding_range.range.start());
+    }
+
+    ranges
+}
+
+/// Returns the declaration and the references of the binding at `symbol_at`,
+/// in source order
+fn find_references(parse: AnyParse, symbol_at: TextSize) -> Vec<SymbolReference> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    // The cursor can be between two tokens, e.g. `(a`, so both are tried
+    let Some(binding) = root
+        .syntax()
+        .token_at_offset(symbol_at)
+        .filter_map(|token| token.parent())
+        .find_map(|node| {
+            if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&node) {
+                model.try_as_binding(&binding)
+            } else if let Some(reference) = JsReferenceIdentifier::cast_ref(&node) {
+                model.binding(&reference)
+            } else if let Some(reference) = JsIdentifierAssignment::cast_ref(&node) {
+                model.binding(&reference)
+            } else if let Some(reference) = JsxReferenceIdentifier::cast_ref(&node) {
+                model.binding(&reference)
+            } else {
+                None
+            }
+        })
+    else {
+        return Vec::new();
+    };
+
+    let mut references = vec![SymbolReference {
+        range: binding.syntax().text_trimmed_range(),
+        kind: SymbolReferenceKind::Declaration,
+    }];
+    references.extend(binding.all_references().map(|reference| SymbolReference {
+        range: reference.syntax().text_trimmed_range(),
+        kind: if reference.is_write() {
+            SymbolReferenceKind::Write
+        } else {
+            SymbolReferenceKind::Read
+        },
+    }));
+    references.sort_by_key(|reference| reference.range.start());
+    references
+}
+
+/// Classifies the bindings of the file, their references and the references
+/// that don't resolve to a binding
+fn semantic_tokens(parse: AnyParse) -> Vec<SemanticToken> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let global_scope = model.global_scope();
+
+    let mut tokens = Vec::new();
+    for binding in model.all_bindings() {
+        let (kind, mut modifiers) = classify_binding(&binding);
+        // The names of the function declarations belong to the scope they're hoisted to
+        let scope = model
+            .scope_hoisted_to(binding.syntax())
+            .unwrap_or_else(|| binding.scope());
+        if scope != global_scope {
+            modifiers.push(SemanticTokenModifier::Local);
+        }
+
+        tokens.extend(binding.all_references().map(|reference| SemanticToken {
+            range: reference.syntax().text_trimmed_range(),
+            kind,
+            modifiers: modifiers.clone(),
+        }));
+        modifiers.insert(0, SemanticTokenModifier::Declaration);
+        tokens.push(SemanticToken {
+            range: binding.syntax().text_trimmed_range(),
+            kind,
+            modifiers,
+        });
+    }
+
+    for reference in model.all_unresolved_references() {
+        let usage = reference.tree();
+        let Ok(name) = usage.value_token() else {
+            continue;
+        };
+        let name = name.text_trimmed();
+        let kind = if usage.is_only_type() {
+            SemanticTokenKind::Type
+        } else {
+            SemanticTokenKind::Variable
+        };
+        let modifier = if is_js_global(name) || is_ts_global(name) {
+            SemanticTokenModifier::Global
+        } else {
+            SemanticTokenModifier::Unresolved
+        };
+        tokens.push(SemanticToken {
+            range: reference.range(),
+            kind,
+            modifiers: vec![modifier],
+        });
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens.dedup_by_key(|token| token.range.start());
+    tokens
+}
+
+/// Returns the kind of a binding and the modifiers that its declaration and
+/// its references share
+fn classify_binding(binding: &Binding) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    if binding.is_imported() {
+        return (
+            SemanticTokenKind::Variable,
+            vec![
+                SemanticTokenModifier::Readonly,
+                SemanticTokenModifier::Imported,
+            ],
+        );
+    }
+
+    let Some(mut declaration) = binding.tree().declaration() else {
+        return (SemanticTokenKind::Variable, Vec::new());
+    };
+    // The names of a destructuring pattern are declared by the variable or
+    // the parameter that holds the pattern
+    while let Some(parent) = declaration.parent_binding_pattern_declaration() {
+        declaration = parent;
+    }
+
+    match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            let is_const = declarator
+                .declaration()
+                .is_some_and(|declaration| declaration.is_const());
+            let modifiers = if is_const {
+                vec![SemanticTokenModifier::Readonly]
+            } else {
+                Vec::new()
+            };
+            (SemanticTokenKind::Variable, modifiers)
+        }
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsIndexSignatureParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => {
+            (SemanticTokenKind::Parameter, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => {
+            (SemanticTokenKind::TypeParameter, Vec::new())
+        }
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            (SemanticTokenKind::Function, Vec::new())
+        }
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => {
+            (SemanticTokenKind::Class, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsEnumMember(_) => (
+            SemanticTokenKind::EnumMember,
+            vec![SemanticTokenModifier::Readonly],
+        ),
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => (SemanticTokenKind::Enum, Vec::new()),
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => {
+            (SemanticTokenKind::Interface, Vec::new())
+        }
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => (SemanticTokenKind::Type, Vec::new()),
+        AnyJsBindingDeclaration::TsModuleDeclaration(_) => {
+            (SemanticTokenKind::Namespace, Vec::new())
+        }
+        _ => (SemanticTokenKind::Variable, Vec::new()),
+    }
+}
+
 pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
@@ -871,7 +1165,7 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         filter,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
-        None,
+        JsAnalyzerServices::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -2,8 +2,8 @@ use std::borrow::Cow;
 use std::ffi::OsStr;
 
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, ParseResult, SearchCapabilities,
+    editor, is_diagnostic_error, search, AnalyzerVisitorBuilder, CodeActionsParams,
+    DocumentFileSource, EditorCapabilities, ExtensionHandler, ParseResult, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -16,8 +16,8 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult,
-    PullActionsResult,
+    CodeAction, FixAction, FixFileMode, FixFileResult, FoldingRange, GetSyntaxTreeResult,
+    OrganizeImportsResult, PullActionsResult,
 };
 use crate::{extension_error, WorkspaceError};
 use biome_analyze::options::PreferredQuote;
@@ -140,10 +140,12 @@ impl ServiceLanguage for JsonLanguage {
             globals: vec![],
             preferred_quote: PreferredQuote::Double,
             jsx_runtime: Default::default(),
+            plugins: global.map(|g| g.plugins.clone()).unwrap_or_default(),
         };
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            suppressed_signals: false,
         }
     }
 }
@@ -164,6 +166,7 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                find_references: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -172,7 +175,14 @@ impl ExtensionHandler for JsonFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<JsonLanguage>),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -201,6 +211,7 @@ fn parse(
                 || optional_json_file_source.map_or(false, |x| x.allow_trailing_commas()),
                 |value| value,
             ),
+            grit_metavariables: false,
         };
         if let Some(overrides) = overrides {
             overrides.to_override_json_parser_options(biome_path, options)
@@ -246,7 +257,7 @@ fn format(
     path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     let options = settings.format_options::<JsonLanguage>(path, document_file_source);
 
@@ -328,9 +339,10 @@ fn lint(params: LintParams) -> LintResults {
             };
             let root: JsonRoot = params.parse.tree();
 
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsonLanguage>(params.path, &params.language);
+            analyzer_options.suppressed_signals = params.include_suppressed;
 
             let has_only_filter = !params.only.is_empty();
             let rules = params
@@ -380,10 +392,11 @@ fn lint(params: LintParams) -> LintResults {
                 .iter()
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
+            let mut suppressed_diagnostics = Vec::new();
             let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
 
             let (_, analyze_diagnostics) =
-                analyze(&root, filter, analyzer_options, file_source, |signal| {
+                analyze(&root, filter, &analyzer_options, file_source, |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
                             && diagnostic.category() == Some(category!("suppressions/unused"))
@@ -391,8 +404,6 @@ fn lint(params: LintParams) -> LintResults {
                             return ControlFlow::<Never>::Continue(());
                         }
 
-                        diagnostic_count += 1;
-
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
                         let severity = diagnostic
@@ -408,6 +419,17 @@ fn lint(params: LintParams) -> LintResults {
                                 },
                             );
 
+                        // The diagnostics silenced by suppression comments are only listed by the
+                        // reporters, they don't count towards the errors nor the maximum
+                        if diagnostic.tags().is_suppressed() {
+                            let error = diagnostic.with_severity(severity);
+                            suppressed_diagnostics
+                                .push(biome_diagnostics::serde::Diagnostic::new(error));
+                            return ControlFlow::<Never>::Continue(());
+                        }
+
+                        diagnostic_count += 1;
+
                         if severity <= Severity::Error {
                             errors += 1;
                         }
@@ -435,6 +457,8 @@ fn lint(params: LintParams) -> LintResults {
                     .collect::<Vec<_>>(),
             );
 
+            diagnostics.extend(suppressed_diagnostics);
+
             LintResults {
                 diagnostics,
                 errors,
@@ -450,6 +474,8 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
+        content: _,
         language,
         skip,
         only,
@@ -549,10 +575,21 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let analyzer_options = params
         .workspace
         .analyzer_options::<JsonLanguage>(params.biome_path, &params.document_file_source);
+    // The unused suppression comments can only be trusted when all the lint rules run
+    let ignores_unused_suppressions = !filter.categories.contains(RuleCategory::Lint)
+        || !params.only.is_empty()
+        || !params.skip.is_empty();
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
 
+            let is_unused_suppression = current_diagnostic.as_ref().is_some_and(|diagnostic| {
+                diagnostic.category() == Some(category!("suppressions/unused"))
+            });
+            if is_unused_suppression && ignores_unused_suppressions {
+                return ControlFlow::Continue(());
+            }
+
             if let Some(diagnostic) = current_diagnostic.as_ref() {
                 if is_diagnostic_error(diagnostic, rules.as_deref()) {
                     errors += 1;
@@ -584,6 +621,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             return ControlFlow::Break(action);
                         }
                     }
+                    FixFileMode::RemoveUnusedSuppressions => {
+                        if is_unused_suppression {
+                            return ControlFlow::Break(action);
+                        }
+                    }
                 }
             }
 
@@ -645,3 +687,7 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
         code: parse.syntax::<JsonLanguage>().to_string(),
     })
 }
+
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<JsonLanguage>(), |_| None)
+}
@@ -0,0 +1,257 @@
+use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_formatter::{format_node, format_node_with_embedded_code};
+use biome_markdown_parser::parse_markdown_with_cache;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument, MdFencedCodeBlock};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache, TextRange};
+use biome_string_case::StrOnlyExtension;
+
+use crate::{
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::{FileFeaturesResult, FoldingRange, GetSyntaxTreeResult},
+    WorkspaceError,
+};
+
+use super::{
+    editor, AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource,
+    EditorCapabilities, ExtensionHandler, Features, FormatterCapabilities, ParseResult,
+    ParserCapabilities, SearchCapabilities,
+};
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub enabled: Option<bool>,
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.markdown
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &biome_fs::BiomePath,
+        file_source: &super::DocumentFileSource,
+    ) -> Self::FormatOptions {
+        // The content of the container blocks is always aligned with spaces, so the indent style is ignored
+        let line_width = language
+            .and_then(|l| l.line_width)
+            .or(global.and_then(|g| g.line_width))
+            .unwrap_or_default();
+        let indent_width = language
+            .and_then(|l| l.indent_width)
+            .or(global.and_then(|g| g.indent_width))
+            .unwrap_or_default();
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+
+        let options =
+            MarkdownFormatOptions::new(file_source.to_markdown_file_source().unwrap_or_default())
+                .with_indent_width(indent_width)
+                .with_line_width(line_width)
+                .with_line_ending(line_ending);
+        if let Some(overrides) = overrides {
+            overrides.to_override_markdown_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&crate::settings::Settings>,
+        _linter: Option<&crate::settings::LinterSettings>,
+        _overrides: Option<&crate::settings::OverrideSettings>,
+        _language: Option<&Self::LinterSettings>,
+        path: &biome_fs::BiomePath,
+        _file_source: &super::DocumentFileSource,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions {
+            configuration: AnalyzerConfiguration::default(),
+            file_path: path.to_path_buf(),
+            suppressed_signals: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: None,
+                code_actions: None,
+                rename: None,
+                find_references: None,
+                fix_all: None,
+                organize_imports: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<MarkdownLanguage>),
+                semantic_tokens: None,
+            },
+        }
+    }
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_markdown_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    tracing::debug!("Format with the following options: \n{}", options);
+
+    let tree = parse.syntax();
+    let embedded_code = format_embedded_code(biome_path, &tree, settings);
+    let formatted = format_node_with_embedded_code(options, &tree, embedded_code)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Formats the code of the fenced code blocks whose info string names a language supported
+/// by Biome, with the formatter of that language.
+///
+/// The code blocks whose language has its formatter disabled, or whose code has syntax errors
+/// or can't be formatted, are left as they are.
+fn format_embedded_code(
+    biome_path: &BiomePath,
+    root: &MarkdownSyntaxNode,
+    settings: &WorkspaceSettingsHandle,
+) -> Vec<(TextRange, String)> {
+    let features = Features::new();
+    let mut cache = NodeCache::default();
+
+    root.descendants()
+        .filter_map(MdFencedCodeBlock::cast)
+        .filter_map(|block| {
+            let language = block.language()?;
+            let language = language.to_ascii_lowercase_cow();
+            let file_source = DocumentFileSource::from_language_id(&language)
+                .or(DocumentFileSource::from_extension(&*language));
+            if file_source == DocumentFileSource::Unknown {
+                return None;
+            }
+
+            let capabilities = features.get_file_source_capabilities(file_source);
+            if let Some(settings) = settings.settings() {
+                let file_features = FileFeaturesResult::new()
+                    .with_capabilities(&capabilities)
+                    .with_settings_and_language(settings, &file_source, biome_path);
+                if !file_features.supports_format() {
+                    return None;
+                }
+            }
+
+            let parse = capabilities.parser.parse?;
+            let format = capabilities.formatter.format?;
+
+            let code = block.content_text();
+            let parse = parse(
+                biome_path,
+                file_source,
+                &code,
+                settings.settings(),
+                &mut cache,
+            );
+            if parse.any_parse.has_errors() {
+                return None;
+            }
+
+            let printed = format(biome_path, &file_source, parse.any_parse, settings).ok()?;
+            Some((block.range(), printed.into_code()))
+        })
+        .collect()
+}
+
+/// Folds the fenced code blocks, from the end of the opening fence line to the end of the block.
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<MarkdownLanguage>(), |node| {
+        let block = MdFencedCodeBlock::cast_ref(node)?;
+        let start = match block.info() {
+            Some(info) => info.range().end(),
+            None => block.l_fence_token().ok()?.text_trimmed_range().end(),
+        };
+
+        Some(TextRange::new(start, block.range().end()))
+    })
+}
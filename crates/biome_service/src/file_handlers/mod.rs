@@ -5,13 +5,17 @@ use self::{
 use crate::diagnostics::{QueryDiagnostic, SearchError};
 pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
 use crate::file_handlers::graphql::GraphqlFileHandler;
-pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
-pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
+use crate::file_handlers::sfc::SingleFileComponent;
+pub use crate::file_handlers::svelte::SvelteFileHandler;
+pub use crate::file_handlers::vue::VueFileHandler;
 use crate::settings::Settings;
 use crate::workspace::{FixFileMode, OrganizeImportsResult};
 use crate::{
     settings::WorkspaceSettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{
+        FixFileResult, FoldingRange, GetSyntaxTreeResult, PullActionsResult, RenameResult,
+        SemanticToken, SymbolReference,
+    },
     WorkspaceError,
 };
 use biome_analyze::{
@@ -30,34 +34,40 @@ use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
 use biome_html_syntax::HtmlFileSource;
-use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{
-    EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
-};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextRange, TextSize};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
 use biome_parser::AnyParse;
-use biome_project::PackageJson;
+use biome_project::{ModuleGraph, PackageJson};
 use biome_rowan::{FileSourceError, NodeCache};
 use biome_string_case::StrLikeExtension;
+use biome_yaml_syntax::YamlFileSource;
 
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
 pub use javascript::JsFormatterSettings;
+use markdown::MarkdownFileHandler;
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::instrument;
+use yaml::YamlFileHandler;
 
 mod astro;
 mod css;
+mod editor;
 mod graphql;
 mod grit;
 mod html;
 mod javascript;
 mod json;
+mod markdown;
+mod sfc;
 mod svelte;
 mod unknown;
 mod vue;
+mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -70,6 +80,8 @@ pub enum DocumentFileSource {
     Graphql(GraphqlFileSource),
     Html(HtmlFileSource),
     Grit(GritFileSource),
+    Yaml(YamlFileSource),
+    Markdown(MarkdownFileSource),
     #[default]
     Unknown,
 }
@@ -110,6 +122,18 @@ impl From<GritFileSource> for DocumentFileSource {
     }
 }
 
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
 impl From<&Path> for DocumentFileSource {
     fn from(path: &Path) -> Self {
         Self::from_path(path)
@@ -131,6 +155,9 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName)
     }
@@ -155,6 +182,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         #[cfg(feature = "experimental-html")]
         if let Ok(file_source) = HtmlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
@@ -187,6 +220,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         #[cfg(feature = "experimental-html")]
         if let Ok(file_source) = HtmlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
@@ -296,6 +335,14 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_yaml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Yaml(_))
+    }
+
+    pub const fn is_markdown_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Markdown(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -338,18 +385,32 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            DocumentFileSource::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            DocumentFileSource::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
     pub fn can_parse(path: &Path, content: &str) -> bool {
         let file_source = DocumentFileSource::from(path);
         match file_source {
             DocumentFileSource::Js(js) => match js.as_embedding_kind() {
-                EmbeddingKind::Astro => ASTRO_FENCE.is_match(content),
-                EmbeddingKind::Vue => VUE_FENCE.is_match(content),
-                EmbeddingKind::Svelte => SVELTE_FENCE.is_match(content),
                 EmbeddingKind::None => true,
+                kind => SingleFileComponent::parse(content, *kind).has_script_or_style(),
             },
             DocumentFileSource::Css(_)
             | DocumentFileSource::Graphql(_)
-            | DocumentFileSource::Json(_) => true,
+            | DocumentFileSource::Json(_)
+            | DocumentFileSource::Yaml(_)
+            | DocumentFileSource::Markdown(_) => true,
             DocumentFileSource::Html(_) => cfg!(feature = "experimental-html"),
             DocumentFileSource::Grit(_) => cfg!(feature = "experimental-grit"),
             DocumentFileSource::Unknown => false,
@@ -385,6 +446,8 @@ impl biome_console::fmt::Display for DocumentFileSource {
             DocumentFileSource::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
             DocumentFileSource::Html(_) => fmt.write_markup(markup! { "HTML" }),
             DocumentFileSource::Grit(_) => fmt.write_markup(markup! { "Grit" }),
+            DocumentFileSource::Yaml(_) => fmt.write_markup(markup! { "YAML" }),
+            DocumentFileSource::Markdown(_) => fmt.write_markup(markup! { "Markdown" }),
             DocumentFileSource::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -393,12 +456,15 @@ impl biome_console::fmt::Display for DocumentFileSource {
 pub struct FixAllParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) fix_file_mode: FixFileMode,
-    pub(crate) workspace: WorkspaceSettingsHandle<'a>,
+    pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) document_file_source: DocumentFileSource,
+    /// The content of the whole document, including the template of Vue, Svelte and Astro files
+    pub(crate) content: &'a str,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) rule_categories: RuleCategories,
@@ -412,6 +478,7 @@ pub struct Capabilities {
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
     pub(crate) search: SearchCapabilities,
+    pub(crate) editor: EditorCapabilities,
 }
 
 #[derive(Clone)]
@@ -458,7 +525,13 @@ pub(crate) struct LintParams<'a> {
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
+    /// Whether the diagnostics silenced by suppression comments should be
+    /// returned, tagged as suppressed
+    pub(crate) include_suppressed: bool,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
+    /// The content of the whole document, including the template of Vue, Svelte and Astro files
+    pub(crate) content: &'a str,
 }
 
 pub(crate) struct LintResults {
@@ -473,15 +546,19 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
+    /// The content of the whole document, including the template of Vue, Svelte and Astro files
+    pub(crate) content: &'a str,
 }
 
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type FindReferences = fn(AnyParse, TextSize) -> Vec<SymbolReference>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
@@ -494,6 +571,8 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It finds the declaration and the references of a binding inside a file
+    pub(crate) find_references: Option<FindReferences>,
     /// It organizes imports
     pub(crate) organize_imports: Option<OrganizeImports>,
 }
@@ -502,7 +581,7 @@ type Format = fn(
     &BiomePath,
     &DocumentFileSource,
     AnyParse,
-    WorkspaceSettingsHandle,
+    &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError>;
 type FormatRange = fn(
     &BiomePath,
@@ -535,7 +614,16 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchOutcome, WorkspaceError>;
+
+/// The result of running a GritQL query against a single file
+#[derive(Default)]
+pub(crate) struct SearchOutcome {
+    /// The ranges of the file that matched the query
+    pub(crate) matches: Vec<TextRange>,
+    /// The new content of the file, if the query rewrote it
+    pub(crate) rewritten_code: Option<String>,
+}
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -543,6 +631,20 @@ pub(crate) struct SearchCapabilities {
     pub(crate) search: Option<Search>,
 }
 
+type FoldingRanges = fn(AnyParse, &str) -> Vec<FoldingRange>;
+type SelectionRanges = fn(AnyParse, &str, &[TextSize]) -> Vec<Vec<TextRange>>;
+type SemanticTokens = fn(AnyParse) -> Vec<SemanticToken>;
+
+#[derive(Default)]
+pub(crate) struct EditorCapabilities {
+    /// It computes the regions of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It computes the ranges that expand the selection at positions of a file
+    pub(crate) selection_ranges: Option<SelectionRanges>,
+    /// It classifies the identifiers of a file
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+}
+
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
     /// Capabilities that can applied to a file
@@ -564,6 +666,8 @@ pub(crate) struct Features {
     graphql: GraphqlFileHandler,
     html: HtmlFileHandler,
     grit: GritFileHandler,
+    yaml: YamlFileHandler,
+    markdown: MarkdownFileHandler,
 }
 
 impl Features {
@@ -578,6 +682,8 @@ impl Features {
             graphql: GraphqlFileHandler {},
             html: HtmlFileHandler {},
             grit: GritFileHandler {},
+            yaml: YamlFileHandler {},
+            markdown: MarkdownFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -588,7 +694,17 @@ impl Features {
         biome_path: &BiomePath,
         language_hint: DocumentFileSource,
     ) -> Capabilities {
-        match DocumentFileSource::from_path(biome_path).or(language_hint) {
+        self.get_file_source_capabilities(
+            DocumentFileSource::from_path(biome_path).or(language_hint),
+        )
+    }
+
+    /// Returns the [Capabilities] associated with a [DocumentFileSource]
+    pub(crate) fn get_file_source_capabilities(
+        &self,
+        file_source: DocumentFileSource,
+    ) -> Capabilities {
+        match file_source {
             DocumentFileSource::Js(source) => match source.as_embedding_kind() {
                 EmbeddingKind::Astro => self.astro.capabilities(),
                 EmbeddingKind::Vue => self.vue.capabilities(),
@@ -600,6 +716,8 @@ impl Features {
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
     }
@@ -627,65 +745,18 @@ pub(crate) fn is_diagnostic_error(
     severity >= Severity::Error
 }
 
-/// Parse the "lang" attribute from the opening tag of the "\<script\>" block in Svelte or Vue files.
-/// This function will return the language based on the existence or the value of the "lang" attribute.
-/// We use the JSX parser at the moment to parse the opening tag. So the opening tag should be first
-/// matched by regular expressions.
-///
-// TODO: We should change the parser when HTMLish languages are supported.
-pub(crate) fn parse_lang_from_script_opening_tag(
-    script_opening_tag: &str,
-) -> (Language, LanguageVariant) {
-    parse(
-        script_opening_tag,
-        JsFileSource::jsx(),
-        JsParserOptions::default(),
-    )
-    .try_tree()
-    .and_then(|tree| {
-        tree.as_js_module()?.items().into_iter().find_map(|item| {
-            let expression = item
-                .as_any_js_statement()?
-                .as_js_expression_statement()?
-                .expression()
-                .ok()?;
-            let tag = expression.as_jsx_tag_expression()?.tag().ok()?;
-            let opening_element = tag.as_jsx_element()?.opening_element().ok()?;
-            let lang_attribute = opening_element.attributes().find_by_name("lang").ok()??;
-            let attribute_value = lang_attribute.initializer()?.value().ok()?;
-            let attribute_inner_string =
-                attribute_value.as_jsx_string()?.inner_string_text().ok()?;
-            match attribute_inner_string.text() {
-                "ts" => Some((
-                    Language::TypeScript {
-                        definition_file: false,
-                    },
-                    LanguageVariant::Standard,
-                )),
-                "tsx" => Some((
-                    Language::TypeScript {
-                        definition_file: false,
-                    },
-                    LanguageVariant::Jsx,
-                )),
-                "jsx" => Some((Language::JavaScript, LanguageVariant::Jsx)),
-                "js" => Some((Language::JavaScript, LanguageVariant::Standard)),
-                _ => None,
-            }
-        })
-    })
-    .map_or((Language::JavaScript, LanguageVariant::Standard), |lang| {
-        lang
-    })
-}
-
 pub(crate) fn search(
     path: &BiomePath,
     _file_source: &DocumentFileSource,
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchOutcome, WorkspaceError> {
+    // Queries can only match files written in their own target language
+    if !query.language().is_compatible_with(parse.root()) {
+        return Ok(SearchOutcome::default());
+    }
+
     let (query_result, _logs) = query
         .execute(GritTargetFile {
             path: path.to_path_buf(),
@@ -695,46 +766,31 @@ pub(crate) fn search(
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = query_result
-        .into_iter()
-        .flat_map(|result| match result {
+    let mut matches = Vec::new();
+    let mut rewritten_code = None;
+    for result in query_result {
+        let ranges = match result {
             GritQueryResult::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
-
-    Ok(matches)
-}
+            // The query only runs on this file, and returns a single rewrite for it, with all
+            // the rewrites of the query applied
+            GritQueryResult::Rewrite(rewrite) => {
+                rewritten_code = Some(rewrite.rewritten.content);
+                rewrite.original.ranges
+            }
+            // Creating new files isn't supported by the search command
+            GritQueryResult::CreateFile(_) => Vec::new(),
+        };
+        matches.extend(
+            ranges
+                .into_iter()
+                .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into())),
+        );
+    }
 
-#[test]
-fn test_svelte_script_lang() {
-    const SVELTE_JS_SCRIPT_OPENING_TAG: &str = r#"<script>"#;
-    const SVELTE_TS_SCRIPT_OPENING_TAG: &str = r#"<script lang="ts">"#;
-    const SVELTE_CONTEXT_MODULE_JS_SCRIPT_OPENING_TAG: &str = r#"<script context="module">"#;
-    const SVELTE_CONTEXT_MODULE_TS_SCRIPT_OPENING_TAG: &str =
-        r#"<script context="module" lang="ts">"#;
-
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_JS_SCRIPT_OPENING_TAG)
-            .0
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_TS_SCRIPT_OPENING_TAG)
-            .0
-            .is_typescript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_CONTEXT_MODULE_JS_SCRIPT_OPENING_TAG)
-            .0
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_CONTEXT_MODULE_TS_SCRIPT_OPENING_TAG)
-            .0
-            .is_typescript()
-    );
+    Ok(SearchOutcome {
+        matches,
+        rewritten_code,
+    })
 }
 
 /// Type meant to register all the syntax rules for each language supported by Biome
@@ -1013,6 +1069,8 @@ struct AssistsVisitor<'a, 'b> {
     enabled_rules: Vec<RuleFilter<'a>>,
     disabled_rules: Vec<RuleFilter<'a>>,
     import_sorting: RuleFilter<'a>,
+    /// Whether the refactors, which act on a selection, are enabled
+    refactors: bool,
     path: &'b Path,
     only: &'b Vec<RuleSelector>,
     skip: &'b Vec<RuleSelector>,
@@ -1030,6 +1088,7 @@ impl<'a, 'b> AssistsVisitor<'a, 'b> {
             disabled_rules: vec![],
             settings,
             import_sorting: RuleFilter::Rule("source", "organizeImports"),
+            refactors: false,
             path,
             only,
             skip,
@@ -1040,6 +1099,16 @@ impl<'a, 'b> AssistsVisitor<'a, 'b> {
     where
         R: Rule<Options: Default, Query: Queryable<Language = L, Output: Clone>> + 'static,
     {
+        // The refactors aren't configurable, they are offered only when the
+        // code actions of a selection are requested
+        if R::Group::NAME == "refactor" {
+            if self.refactors {
+                self.enabled_rules
+                    .push(RuleFilter::Rule(R::Group::NAME, R::METADATA.name));
+            }
+            return;
+        }
+
         // We deem refactors **safe**, other assists aren't safe
         if R::Group::NAME != "source" {
             return;
@@ -1150,6 +1219,7 @@ pub(crate) struct AnalyzerVisitorBuilder<'a, 'b> {
     syntax: Option<SyntaxVisitor<'a>>,
     lint: Option<LintVisitor<'a, 'b>>,
     assists: Option<AssistsVisitor<'a, 'b>>,
+    refactors: bool,
     settings: Option<&'b Settings>,
 }
 
@@ -1160,6 +1230,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             syntax: None,
             lint: None,
             assists: None,
+            refactors: false,
         }
     }
 
@@ -1190,6 +1261,13 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
         self
     }
 
+    /// Enables the refactors of the assists, see [AnalyzerVisitorBuilder::with_assists_rules]
+    #[must_use]
+    pub(crate) fn with_refactor_rules(mut self) -> Self {
+        self.refactors = true;
+        self
+    }
+
     #[must_use]
     pub(crate) fn finish(self) -> (Vec<RuleFilter<'a>>, Vec<RuleFilter<'a>>) {
         let mut disabled_rules = vec![];
@@ -1213,6 +1291,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
         }
 
         if let Some(mut assists) = self.assists {
+            assists.refactors = self.refactors;
             biome_js_analyze::visit_registry(&mut assists);
             biome_css_analyze::visit_registry(&mut assists);
             biome_json_analyze::visit_registry(&mut assists);
@@ -1225,59 +1304,3 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
         (enabled_rules, disabled_rules)
     }
 }
-
-#[test]
-fn test_vue_script_lang() {
-    const VUE_JS_SCRIPT_OPENING_TAG: &str = r#"<script>"#;
-    const VUE_TS_SCRIPT_OPENING_TAG: &str = r#"<script lang="ts">"#;
-    const VUE_TSX_SCRIPT_OPENING_TAG: &str = r#"<script lang="tsx">"#;
-    const VUE_JSX_SCRIPT_OPENING_TAG: &str = r#"<script lang="jsx">"#;
-    const VUE_SETUP_JS_SCRIPT_OPENING_TAG: &str = r#"<script setup>"#;
-    const VUE_SETUP_TS_SCRIPT_OPENING_TAG: &str = r#"<script setup lang="ts">"#;
-
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_JS_SCRIPT_OPENING_TAG)
-            .0
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_JS_SCRIPT_OPENING_TAG)
-            .1
-            .is_standard()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_TS_SCRIPT_OPENING_TAG)
-            .0
-            .is_typescript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_TS_SCRIPT_OPENING_TAG)
-            .1
-            .is_standard()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_JSX_SCRIPT_OPENING_TAG)
-            .0
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_JSX_SCRIPT_OPENING_TAG)
-            .1
-            .is_jsx()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_TSX_SCRIPT_OPENING_TAG)
-            .0
-            .is_typescript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_SETUP_JS_SCRIPT_OPENING_TAG)
-            .0
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(VUE_SETUP_TS_SCRIPT_OPENING_TAG)
-            .0
-            .is_typescript()
-    );
-}
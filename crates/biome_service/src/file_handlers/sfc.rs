@@ -0,0 +1,1037 @@
+//! The single-file components of Vue, Svelte and Astro, parsed with the HTML parser.
+//!
+//! The JavaScript tooling of the workspace only sees the main script of a component. This module
+//! finds the scripts, the styles and the template of a component, so that the other scripts and
+//! the styles are linted too, and the names referenced by the template are exposed to the
+//! semantic model of the scripts.
+//!
+//! The names referenced by the template aren't references of the semantic model, because they
+//! don't have a node in the tree of the script. They are collected from:
+//! - the names of the components, such as `<Button>`;
+//! - the attributes: the directives of Vue and Svelte, and the expressions between braces of
+//!   Svelte and Astro;
+//! - the interpolations of the text: `{{ }}` in Vue, `{ }` in Svelte and Astro.
+//!
+//! The names assigned by the template, such as `count` in `count++`, `bind:value={count}` or
+//! `v-model="count"`, are collected apart from the names that the template reads. The rules that
+//! report the unused bindings and imports, `noUnusedVariables`, `noUnusedImports` and
+//! `useImportType`, take both into account. The rules that check the assignments of the bindings,
+//! such as `useConst`, take the assignments of the template into account.
+
+use super::html::{self, EmbeddedContent};
+use super::{editor, javascript, Features, LintParams, LintResults, ASTRO_FENCE};
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_css_syntax::CssFileSource;
+use biome_html_parser::parse_html;
+use biome_html_syntax::{
+    AnyHtmlElement, HtmlAttribute, HtmlContent, HtmlElement, HtmlOpeningElement, HtmlRoot,
+    HtmlSelfClosingElement, HtmlSyntaxNode,
+};
+use biome_js_parser::{parse_js_with_cache, JsParserOptions};
+use biome_js_semantic::TemplateReferences;
+use biome_js_syntax::{
+    EmbeddingKind, JsFileSource, JsIdentifierAssignment, JsLanguage, JsReferenceIdentifier,
+    JsSyntaxNode, JsxReferenceIdentifier, Language, LanguageVariant,
+};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize, WalkEvent};
+use biome_string_case::Case;
+
+/// A script of a component.
+struct Script {
+    /// The range of the code of the script
+    range: TextRange,
+    /// The language of the script, from the `lang` attribute of the `<script>` element
+    file_source: JsFileSource,
+}
+
+/// A Vue, Svelte or Astro component.
+pub(crate) struct SingleFileComponent {
+    kind: EmbeddingKind,
+    /// The HTML tree of the component. The tree of Astro components starts after the frontmatter.
+    root: HtmlSyntaxNode,
+    /// The offset of the HTML tree in the component
+    offset: TextSize,
+    /// The scripts of the component: the top-level `<script>` elements of Vue and Svelte
+    /// components, such as `<script>` and `<script setup>`, and the frontmatter of Astro
+    /// components.
+    ///
+    /// The first script is the main script, the one that the workspace parses, formats and fixes.
+    scripts: Vec<Script>,
+    /// The `<style>` elements written in CSS
+    styles: Vec<EmbeddedContent>,
+}
+
+impl SingleFileComponent {
+    pub(crate) fn parse(text: &str, kind: EmbeddingKind) -> Self {
+        let mut scripts = Vec::new();
+        let mut offset = TextSize::from(0);
+        if kind == EmbeddingKind::Astro {
+            let mut fences = ASTRO_FENCE.find_iter(text);
+            if let (Some(start), Some(end)) = (fences.next(), fences.next()) {
+                scripts.push(Script {
+                    range: TextRange::new(
+                        TextSize::from(start.end() as u32),
+                        TextSize::from(end.start() as u32),
+                    ),
+                    file_source: JsFileSource::astro(),
+                });
+                offset = TextSize::from(end.end() as u32);
+            }
+        }
+
+        let root = parse_html(&text[usize::from(offset)..]).syntax();
+
+        // The `<script>` elements nested in other elements, such as the ones of `<svelte:head>`,
+        // are run by the browser and aren't scripts of the component. The `<script>` elements
+        // of the template of Astro components are processed by the bundler.
+        if kind != EmbeddingKind::Astro {
+            let elements = HtmlRoot::cast_ref(&root).map(|root| root.html());
+            for element in elements.into_iter().flatten() {
+                if let AnyHtmlElement::HtmlElement(element) = element {
+                    if element.is_script_tag() {
+                        let (language, variant) = parse_lang_attribute(&element);
+                        scripts.push(Script {
+                            range: script_range(text, element.children().range() + offset),
+                            file_source: JsFileSource::from(language)
+                                .with_variant(variant)
+                                .with_embedding_kind(kind),
+                        });
+                    }
+                }
+            }
+        }
+
+        let styles = root
+            .descendants()
+            .filter_map(HtmlElement::cast)
+            .filter(|element| element.is_style_tag())
+            .filter(|element| {
+                element
+                    .find_attribute_by_name("lang")
+                    .and_then(|attribute| attribute.value())
+                    .map_or(true, |lang| lang.text() == "css")
+            })
+            .map(|element| EmbeddedContent {
+                element_range: element.range() + offset,
+                content_range: element.children().range() + offset,
+                file_source: CssFileSource::css().into(),
+            })
+            .collect();
+
+        Self {
+            kind,
+            root,
+            offset,
+            scripts,
+            styles,
+        }
+    }
+
+    /// Returns the range of the main script of the component, if it has one.
+    pub(crate) fn script(&self) -> Option<TextRange> {
+        self.scripts.first().map(|script| script.range)
+    }
+
+    /// Returns the file source of the main script of the component.
+    pub(crate) fn script_file_source(&self) -> JsFileSource {
+        self.scripts.first().map_or_else(
+            || JsFileSource::js_module().with_embedding_kind(self.kind),
+            |script| script.file_source,
+        )
+    }
+
+    /// Returns `true` if the component has a script or a style that Biome can process.
+    pub(crate) fn has_script_or_style(&self) -> bool {
+        !self.scripts.is_empty() || !self.styles.is_empty()
+    }
+
+    /// Returns the names that the script at `index` shares with the rest of the component: the
+    /// names referenced by the template, and by the other scripts of the component.
+    ///
+    /// The names that aren't bound by the script are harmless: they are resolved against the
+    /// top-level bindings of the script only.
+    pub(crate) fn template_references(&self, text: &str, index: usize) -> TemplateReferences {
+        let mut references = TemplateReferences::default();
+
+        for template in self.templates() {
+            self.collect_template_references(&template, text, &mut references);
+        }
+
+        // The scripts of a component share their top-level bindings, like `<script>` and
+        // `<script setup>` in Vue, or `<script context="module">` and `<script>` in Svelte
+        for (script_index, script) in self.scripts.iter().enumerate() {
+            if script_index != index {
+                let parse = biome_js_parser::parse(
+                    &text[script.range],
+                    script.file_source,
+                    JsParserOptions::default(),
+                );
+                collect_identifier_references(&parse.syntax(), &mut references);
+            }
+        }
+
+        if self.kind == EmbeddingKind::Svelte {
+            // `$count` reads the value of the store `count`, and `$count = 1` sets it
+            let stores: Vec<_> = references
+                .reads
+                .iter()
+                .chain(&references.writes)
+                .filter_map(|name| name.strip_prefix('$'))
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect();
+            references.reads.extend(stores);
+        }
+
+        references
+    }
+
+    /// Returns the roots of the template of the component: the top-level `<template>` elements
+    /// of Vue components, and the whole tree of Svelte and Astro components.
+    fn templates(&self) -> Vec<HtmlSyntaxNode> {
+        if self.kind != EmbeddingKind::Vue {
+            return vec![self.root.clone()];
+        }
+
+        HtmlRoot::cast_ref(&self.root)
+            .map(|root| root.html())
+            .into_iter()
+            .flatten()
+            .filter_map(|element| match element {
+                AnyHtmlElement::HtmlElement(element) if element.has_name("template") => {
+                    Some(element.into_syntax())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Collects the names referenced by the elements, the attributes and the interpolations of
+    /// the template. The `<script>` and `<style>` elements are skipped.
+    fn collect_template_references(
+        &self,
+        template: &HtmlSyntaxNode,
+        text: &str,
+        references: &mut TemplateReferences,
+    ) {
+        // The expressions of Astro templates contain JSX
+        let file_source = match self.kind {
+            EmbeddingKind::Astro => JsFileSource::tsx(),
+            _ => JsFileSource::ts(),
+        };
+        let (open, close) = match self.kind {
+            EmbeddingKind::Vue => ("{{", "}}"),
+            _ => ("{", "}"),
+        };
+        let source = &text[usize::from(self.offset)..];
+        // The end of the last interpolation. An interpolation can contain elements, whose text is
+        // part of the interpolation.
+        let mut interpolation_end = 0;
+
+        let mut preorder = template.preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+
+            if let Some(element) = HtmlElement::cast_ref(&node) {
+                if element.is_script_tag() || element.is_style_tag() {
+                    preorder.skip_subtree();
+                }
+            } else if let Some(element) = HtmlOpeningElement::cast_ref(&node) {
+                if let Ok(name) = element.name().and_then(|name| name.value_token()) {
+                    if let Some(component) = component_name(name.text_trimmed(), self.kind) {
+                        references.reads.insert(component);
+                    }
+                }
+            } else if let Some(element) = HtmlSelfClosingElement::cast_ref(&node) {
+                if let Ok(name) = element.name().and_then(|name| name.value_token()) {
+                    if let Some(component) = component_name(name.text_trimmed(), self.kind) {
+                        references.reads.insert(component);
+                    }
+                }
+            } else if let Some(attribute) = HtmlAttribute::cast_ref(&node) {
+                match self.kind {
+                    EmbeddingKind::Vue => {
+                        vue_attribute_references(&attribute, file_source, references);
+                    }
+                    kind => attribute_references(&attribute, kind, file_source, references),
+                }
+            } else if let Some(content) = HtmlContent::cast_ref(&node) {
+                let range = content.range();
+                let mut start = usize::from(range.start()).max(interpolation_end);
+                let end = usize::from(range.end());
+                while start < end {
+                    let Some(open_offset) = source[start..end].find(open) else {
+                        break;
+                    };
+                    let expression_start = start + open_offset + open.len();
+                    let Some(len) = find_closing_delimiter(&source[expression_start..], close)
+                    else {
+                        break;
+                    };
+                    let expression = &source[expression_start..expression_start + len];
+                    let expression = match self.kind {
+                        EmbeddingKind::Svelte => svelte_tag_expression(expression),
+                        _ => Some(expression),
+                    };
+                    if let Some(expression) = expression {
+                        collect_references(expression, file_source, references);
+                    }
+                    start = expression_start + len + close.len();
+                    interpolation_end = start;
+                }
+            }
+        }
+    }
+
+    /// Lints the scripts of the component other than the main script with the JavaScript linter,
+    /// and the `<style>` elements with the CSS linter.
+    ///
+    /// The ranges of the diagnostics start at the beginning of the component.
+    fn lint_embedded(&self, params: &LintParams) -> LintResults {
+        let text = params.content;
+        let features = Features::new();
+        let mut cache = NodeCache::default();
+        let mut results = LintResults {
+            diagnostics: Vec::new(),
+            errors: 0,
+            skipped_diagnostics: 0,
+        };
+
+        for (index, script) in self.scripts.iter().enumerate().skip(1) {
+            let parse = parse_js_with_cache(
+                &text[script.range],
+                script.file_source,
+                JsParserOptions::default(),
+                &mut cache,
+            );
+            let script_results = javascript::lint_script(
+                LintParams {
+                    parse: parse.into(),
+                    workspace: params.workspace,
+                    language: script.file_source.into(),
+                    max_diagnostics: params.max_diagnostics,
+                    path: params.path,
+                    only: params.only.clone(),
+                    skip: params.skip.clone(),
+                    categories: params.categories,
+                    include_suppressed: params.include_suppressed,
+                    manifest: params.manifest.clone(),
+                    module_graph: params.module_graph.clone(),
+                    content: text,
+                },
+                self.template_references(text, index),
+            );
+            merge_results(&mut results, script_results, script.range.start());
+        }
+
+        for style in &self.styles {
+            let capabilities = features.get_file_source_capabilities(style.file_source);
+            if !style
+                .features(&capabilities, params.path, params.workspace)
+                .supports_lint()
+            {
+                continue;
+            }
+            let Some(lint) = capabilities.analyzer.lint else {
+                continue;
+            };
+            let Some(parse) = style.parse(
+                text,
+                &capabilities,
+                params.path,
+                params.workspace,
+                &mut cache,
+            ) else {
+                continue;
+            };
+
+            let style_results = lint(LintParams {
+                parse,
+                workspace: params.workspace,
+                language: style.file_source,
+                max_diagnostics: params.max_diagnostics,
+                path: params.path,
+                only: params.only.clone(),
+                skip: params.skip.clone(),
+                categories: params.categories,
+                include_suppressed: params.include_suppressed,
+                manifest: params.manifest.clone(),
+                module_graph: params.module_graph.clone(),
+                content: &text[style.content_range],
+            });
+            merge_results(&mut results, style_results, style.content_range.start());
+        }
+
+        results
+    }
+}
+
+/// Lints the scripts of the component with the JavaScript linter, and its `<style>` elements with
+/// the CSS linter.
+///
+/// Unlike the code actions and the fixes, which apply to the main script, the ranges of the
+/// diagnostics start at the beginning of the component.
+pub(crate) fn lint(params: LintParams, kind: EmbeddingKind) -> LintResults {
+    let component = SingleFileComponent::parse(params.content, kind);
+    let script_start = component
+        .script()
+        .map_or(TextSize::from(0), |script| script.start());
+    let template_references = component.template_references(params.content, 0);
+    let embedded = component.lint_embedded(&params);
+
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+    merge_results(
+        &mut results,
+        javascript::lint_script(params, template_references),
+        script_start,
+    );
+    merge_results(&mut results, embedded, TextSize::from(0));
+    results
+}
+
+/// Returns the folding ranges of the component: the ones of its HTML tree and the ones of the tree
+/// of its main script, which is `parse`.
+pub(crate) fn folding_ranges(
+    parse: AnyParse,
+    text: &str,
+    kind: EmbeddingKind,
+) -> Vec<FoldingRange> {
+    let component = SingleFileComponent::parse(text, kind);
+    let mut ranges: Vec<FoldingRange> = html::tree_folding_ranges(&component.root)
+        .into_iter()
+        .map(|folding_range| FoldingRange {
+            range: folding_range.range + component.offset,
+            ..folding_range
+        })
+        .collect();
+    if let Some(script) = component.script() {
+        // The frontmatter of Astro components isn't part of the HTML tree
+        if kind == EmbeddingKind::Astro && text[script].contains('\n') {
+            ranges.push(FoldingRange {
+                range: script,
+                kind: FoldingRangeKind::Block,
+            });
+        }
+        ranges.extend(
+            javascript::folding_ranges(parse, &text[script])
+                .into_iter()
+                .map(|folding_range| FoldingRange {
+                    range: folding_range.range + script.start(),
+                    ..folding_range
+                }),
+        );
+    }
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
+/// Returns, for each position, the ranges that expand the selection in the component. In the main
+/// script, the ranges of its tree, which is `parse`, are followed by the ranges of the HTML tree
+/// that contain the script.
+pub(crate) fn selection_ranges(
+    parse: AnyParse,
+    text: &str,
+    positions: &[TextSize],
+    kind: EmbeddingKind,
+) -> Vec<Vec<TextRange>> {
+    let component = SingleFileComponent::parse(text, kind);
+    let script = component.script();
+    let script_root = parse.syntax::<JsLanguage>();
+    positions
+        .iter()
+        .map(|position| {
+            let mut ranges = Vec::new();
+            if let Some(script) = script.filter(|script| script.contains_inclusive(*position)) {
+                ranges.extend(
+                    editor::selection_ranges_at(&script_root, *position - script.start())
+                        .into_iter()
+                        .map(|range| range + script.start()),
+                );
+            }
+            if let Some(html_position) = position.checked_sub(component.offset) {
+                for range in editor::selection_ranges_at(&component.root, html_position) {
+                    let range = range + component.offset;
+                    // Each range contains the previous one
+                    if ranges
+                        .last()
+                        .map_or(true, |last| range != *last && range.contains_range(*last))
+                    {
+                        ranges.push(range);
+                    }
+                }
+            }
+            ranges
+        })
+        .collect()
+}
+
+/// Returns the names referenced by the template of the component that embeds the script, if the
+/// file is a Vue, Svelte or Astro component.
+pub(crate) fn template_references(content: &str, file_source: JsFileSource) -> TemplateReferences {
+    match file_source.as_embedding_kind() {
+        EmbeddingKind::None => TemplateReferences::default(),
+        kind => SingleFileComponent::parse(content, *kind).template_references(content, 0),
+    }
+}
+
+/// Adds the results of the linting of a part of the component, whose ranges start at `offset`.
+fn merge_results(results: &mut LintResults, other: LintResults, offset: TextSize) {
+    results.diagnostics.extend(
+        other
+            .diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.with_offset(offset)),
+    );
+    results.errors += other.errors;
+    results.skipped_diagnostics += other.skipped_diagnostics;
+}
+
+/// Returns the range of the script from the range of the content of the `<script>` element,
+/// without the line break that follows the opening tag.
+fn script_range(text: &str, content: TextRange) -> TextRange {
+    let line_break = if text[content].starts_with("\r\n") {
+        2
+    } else if text[content].starts_with('\n') {
+        1
+    } else {
+        0
+    };
+    TextRange::new(content.start() + TextSize::from(line_break), content.end())
+}
+
+/// Returns the language of the script from the `lang` attribute of the `<script>` element.
+fn parse_lang_attribute(element: &HtmlElement) -> (Language, LanguageVariant) {
+    let lang = element
+        .find_attribute_by_name("lang")
+        .and_then(|attribute| attribute.value());
+    match lang.as_ref().map(|lang| lang.text()) {
+        Some("ts") => (
+            Language::TypeScript {
+                definition_file: false,
+            },
+            LanguageVariant::Standard,
+        ),
+        Some("tsx") => (
+            Language::TypeScript {
+                definition_file: false,
+            },
+            LanguageVariant::Jsx,
+        ),
+        Some("jsx") => (Language::JavaScript, LanguageVariant::Jsx),
+        _ => (Language::JavaScript, LanguageVariant::Standard),
+    }
+}
+
+/// Returns the binding that an element refers to, if the element is a component.
+///
+/// The components start with an uppercase letter: `<Button>`, `<Icons.Check>`. Vue components
+/// are also written in kebab-case: `<my-button>` is `MyButton`.
+fn component_name(name: &str, kind: EmbeddingKind) -> Option<String> {
+    let name = name.split('.').next()?;
+    if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        Some(name.to_string())
+    } else if kind == EmbeddingKind::Vue && name.contains('-') {
+        Some(Case::Pascal.convert(name))
+    } else {
+        None
+    }
+}
+
+/// Returns the expressions between the `open` and `close` delimiters of the template.
+fn interpolations<'a>(template: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut expressions = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(open) {
+        let expression = &rest[start + open.len()..];
+        let Some(end) = find_closing_delimiter(expression, close) else {
+            break;
+        };
+        expressions.push(&expression[..end]);
+        rest = &expression[end + close.len()..];
+    }
+    expressions
+}
+
+/// Returns the offset of the `close` delimiter that ends the expression, skipping over the
+/// brackets and the strings of the expression.
+fn find_closing_delimiter(expression: &str, close: &str) -> Option<usize> {
+    let bytes = expression.as_bytes();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match quote {
+            Some(_) if byte == b'\\' => index += 1,
+            Some(quote_byte) if byte == quote_byte => quote = None,
+            Some(_) => {}
+            None if depth == 0 && bytes[index..].starts_with(close.as_bytes()) => {
+                return Some(index);
+            }
+            None => match byte {
+                b'\'' | b'"' | b'`' => quote = Some(byte),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                _ => {}
+            },
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns the expression of a Svelte tag, without the keyword of the blocks (`{#if ...}`) and of
+/// the special tags (`{@html ...}`).
+fn svelte_tag_expression(tag: &str) -> Option<&str> {
+    let tag = tag.trim_start();
+    if tag.starts_with('/') {
+        return None;
+    }
+    if tag.starts_with(':') {
+        return tag.strip_prefix(":else if ");
+    }
+    if !tag.starts_with(['#', '@']) {
+        return Some(tag);
+    }
+
+    let (keyword, expression) = tag.split_once(char::is_whitespace)?;
+    match keyword {
+        "#each" => Some(
+            expression
+                .split_once(" as ")
+                .map_or(expression, |(list, _)| list),
+        ),
+        "#await" => Some(
+            expression
+                .split_once(" then ")
+                .or_else(|| expression.split_once(" catch "))
+                .map_or(expression, |(promise, _)| promise),
+        ),
+        "#snippet" => None,
+        _ => Some(expression),
+    }
+}
+
+/// Collects the names referenced by the directives of a Vue element.
+///
+/// See: https://vuejs.org/api/built-in-directives.html
+fn vue_attribute_references(
+    attribute: &HtmlAttribute,
+    file_source: JsFileSource,
+    references: &mut TemplateReferences,
+) {
+    let Ok(name) = attribute.name().and_then(|name| name.value_token()) else {
+        return;
+    };
+    let name = name.text_trimmed();
+    let value = attribute.value();
+    let value = value.as_ref().map(|value| value.text());
+
+    // `<input ref="input">` refers to the binding `input` of `<script setup>`
+    if name == "ref" {
+        if let Some(value) = value {
+            references.reads.insert(value.trim().to_string());
+        }
+        return;
+    }
+
+    let (directive, argument) = if let Some(argument) = name.strip_prefix([':', '.']) {
+        ("bind", Some(argument))
+    } else if let Some(argument) = name.strip_prefix('@') {
+        ("on", Some(argument))
+    } else if name.starts_with('#') {
+        ("slot", None)
+    } else if let Some(directive) = name.strip_prefix("v-") {
+        match directive.split_once(':') {
+            Some((directive, argument)) => (directive, Some(argument)),
+            None => (directive, None),
+        }
+    } else {
+        return;
+    };
+    let directive = directive.split('.').next().unwrap_or(directive);
+
+    let mut value = value;
+    match directive {
+        // The value of `v-slot` declares bindings
+        "slot" | "else" | "pre" | "once" | "cloak" => return,
+        "for" => {
+            value = value.and_then(|value| {
+                value
+                    .split_once(" in ")
+                    .or_else(|| value.split_once(" of "))
+                    .map(|(_, list)| list)
+            });
+        }
+        "bind" | "on" | "if" | "else-if" | "show" | "model" | "html" | "text" | "memo" | "is" => {}
+        // `v-focus` refers to the directive `vFocus` of `<script setup>`
+        directive => {
+            references
+                .reads
+                .insert(format!("v{}", Case::Pascal.convert(directive)));
+        }
+    }
+
+    if let Some(argument) = argument {
+        if let Some(dynamic_argument) = argument
+            .strip_prefix('[')
+            .and_then(|argument| argument.split_once(']'))
+            .map(|(argument, _)| argument)
+        {
+            collect_references(dynamic_argument, file_source, references);
+        } else if directive == "bind" && value.is_none() {
+            // `:id` is a shorthand for `:id="id"`
+            let argument = argument.split('.').next().unwrap_or(argument);
+            references.reads.insert(Case::Camel.convert(argument));
+        }
+    }
+
+    if let Some(value) = value {
+        // `v-model="count"` assigns `count`
+        if directive == "model" {
+            collect_bound_references(value, file_source, references);
+        } else {
+            collect_references(value, file_source, references);
+        }
+    }
+}
+
+/// Collects the names referenced by an attribute of a Svelte or Astro element: the expressions
+/// between braces, and the directives of Svelte.
+///
+/// See: https://svelte.dev/docs/element-directives
+fn attribute_references(
+    attribute: &HtmlAttribute,
+    kind: EmbeddingKind,
+    file_source: JsFileSource,
+    references: &mut TemplateReferences,
+) {
+    let Ok(name) = attribute.name().and_then(|name| name.value_token()) else {
+        return;
+    };
+    let name = name.text_trimmed();
+
+    // `{src}` is a shorthand for `src={src}`, and `{...props}` spreads `props`
+    if let Some(expression) = name
+        .strip_prefix('{')
+        .and_then(|name| name.strip_suffix('}'))
+    {
+        collect_references(expression, file_source, references);
+        return;
+    }
+
+    let value = attribute
+        .initializer()
+        .and_then(|initializer| initializer.value().ok());
+    let mut is_binding = false;
+    if kind == EmbeddingKind::Svelte {
+        if let Some((directive, argument)) = name.split_once(':') {
+            let argument = argument.split('|').next().unwrap_or(argument);
+            match directive {
+                "use" | "transition" | "in" | "out" | "animate" => {
+                    references.reads.insert(argument.to_string());
+                }
+                // `bind:value` is a shorthand for `bind:value={value}`, which assigns `value`
+                "bind" if value.is_none() => {
+                    references.writes.insert(argument.to_string());
+                }
+                "bind" => is_binding = true,
+                // `class:active` is a shorthand for `class:active={active}`
+                "class" if value.is_none() => {
+                    references.reads.insert(argument.to_string());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let Some(value) = value else {
+        return;
+    };
+    let Ok(token) = value.value_token() else {
+        return;
+    };
+    let text = token.text_trimmed();
+    if let Some(expression) = text
+        .strip_prefix('{')
+        .and_then(|text| text.strip_suffix('}'))
+    {
+        if is_binding {
+            collect_bound_references(expression, file_source, references);
+        } else {
+            collect_references(expression, file_source, references);
+        }
+    } else if kind == EmbeddingKind::Svelte {
+        // The quoted values of Svelte can contain expressions: `class="item {active}"`
+        if let Ok(text) = value.inner_string_text() {
+            for expression in interpolations(text.text(), "{", "}") {
+                collect_references(expression, file_source, references);
+            }
+        }
+    }
+}
+
+/// Collects the names referenced by an expression of the template. The statements of the event
+/// handlers of Vue, such as `count++; emit('change')`, are accepted too.
+fn collect_references(
+    source: &str,
+    file_source: JsFileSource,
+    references: &mut TemplateReferences,
+) {
+    let source = source.trim();
+    let source = source.strip_prefix("...").unwrap_or(source);
+    if source.is_empty() {
+        return;
+    }
+
+    let mut parse = biome_js_parser::parse(
+        &format!("({source})"),
+        file_source,
+        JsParserOptions::default(),
+    );
+    if parse.has_errors() {
+        parse = biome_js_parser::parse(source, file_source, JsParserOptions::default());
+    }
+
+    collect_identifier_references(&parse.syntax(), references);
+}
+
+/// Collects the names referenced by the expression of a two-way binding, such as
+/// `bind:value={count}` in Svelte or `v-model="count"` in Vue. The template assigns the
+/// expression, which is `count` here, and only reads the objects of the member expressions.
+fn collect_bound_references(
+    source: &str,
+    file_source: JsFileSource,
+    references: &mut TemplateReferences,
+) {
+    let source = source.trim();
+    if source.is_empty() {
+        return;
+    }
+
+    let parse = biome_js_parser::parse(
+        &format!("({source}) = 0"),
+        file_source,
+        JsParserOptions::default(),
+    );
+    if parse.has_errors() {
+        collect_references(source, file_source, references);
+    } else {
+        collect_identifier_references(&parse.syntax(), references);
+    }
+}
+
+/// Collects the names referenced by the identifiers of a JavaScript tree. The names of the
+/// assignments, such as `count` in `count++` or `count = 0`, are collected as writes.
+fn collect_identifier_references(root: &JsSyntaxNode, references: &mut TemplateReferences) {
+    for node in root.descendants() {
+        let (name, names) = if let Some(reference) = JsReferenceIdentifier::cast_ref(&node) {
+            (reference.value_token(), &mut references.reads)
+        } else if let Some(reference) = JsxReferenceIdentifier::cast_ref(&node) {
+            (reference.value_token(), &mut references.reads)
+        } else if let Some(assignment) = JsIdentifierAssignment::cast_ref(&node) {
+            (assignment.name_token(), &mut references.writes)
+        } else {
+            continue;
+        };
+        if let Ok(name) = name {
+            names.insert(name.text_trimmed().to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustc_hash::FxHashSet;
+
+    /// Returns the names read and the names assigned by the template
+    fn references(text: &str, kind: EmbeddingKind) -> (Vec<String>, Vec<String>) {
+        let references = SingleFileComponent::parse(text, kind).template_references(text, 0);
+        let sorted = |names: FxHashSet<String>| {
+            let mut names: Vec<_> = names.into_iter().collect();
+            names.sort();
+            names
+        };
+        (sorted(references.reads), sorted(references.writes))
+    }
+
+    #[test]
+    fn test_vue_script_lang() {
+        let script_language = |text| {
+            SingleFileComponent::parse(text, EmbeddingKind::Vue)
+                .script_file_source()
+                .language()
+        };
+
+        assert!(script_language("<script>\n</script>").is_javascript());
+        assert!(script_language("<script lang=\"ts\">\n</script>").is_typescript());
+        assert!(script_language("<script lang=\"tsx\">\n</script>").is_typescript());
+        assert!(script_language("<script lang=\"jsx\">\n</script>").is_javascript());
+        assert!(script_language("<script setup>\n</script>").is_javascript());
+        assert!(script_language("<script setup lang=\"ts\">\n</script>").is_typescript());
+        assert!(
+            SingleFileComponent::parse("<script lang=\"jsx\">\n</script>", EmbeddingKind::Vue)
+                .script_file_source()
+                .variant()
+                .is_jsx()
+        );
+    }
+
+    #[test]
+    fn test_svelte_script_lang() {
+        let script_language = |text| {
+            SingleFileComponent::parse(text, EmbeddingKind::Svelte)
+                .script_file_source()
+                .language()
+        };
+
+        assert!(script_language("<script>\n</script>").is_javascript());
+        assert!(script_language("<script lang=\"ts\">\n</script>").is_typescript());
+        assert!(script_language("<script context=\"module\">\n</script>").is_javascript());
+        assert!(
+            script_language("<script context=\"module\" lang=\"ts\">\n</script>").is_typescript()
+        );
+    }
+
+    #[test]
+    fn finds_the_scripts_and_the_styles() {
+        let text = "<style>\nb {}\n</style>\n<template>\n  <div />\n</template>\n<script>\nexport default {};\n</script>\n<script setup>\nlet a = 1;\n</script>\n<style>\na {}\n</style>\n<style lang=\"scss\">\na {}\n</style>\n";
+        let component = SingleFileComponent::parse(text, EmbeddingKind::Vue);
+
+        let scripts: Vec<_> = component
+            .scripts
+            .iter()
+            .map(|script| &text[script.range])
+            .collect();
+        assert_eq!(scripts, ["export default {};\n", "let a = 1;\n"]);
+        assert_eq!(
+            component.script().map(|range| &text[range]),
+            Some("export default {};\n")
+        );
+        let styles: Vec<_> = component
+            .styles
+            .iter()
+            .map(|style| &text[style.content_range])
+            .collect();
+        assert_eq!(styles, ["\nb {}\n", "\na {}\n"]);
+    }
+
+    #[test]
+    fn ignores_the_nested_scripts() {
+        let text = "<svelte:head>\n  <script src=\"analytics.js\"></script>\n</svelte:head>\n<script>\nlet a = 1;\n</script>\n";
+        let component = SingleFileComponent::parse(text, EmbeddingKind::Svelte);
+
+        assert_eq!(
+            component.script().map(|range| &text[range]),
+            Some("let a = 1;\n")
+        );
+        assert_eq!(component.scripts.len(), 1);
+    }
+
+    #[test]
+    fn shares_the_references_of_the_scripts() {
+        let text = r#"<script>
+import A from "a";
+</script>
+<script setup>
+const b = B;
+</script>
+"#;
+        let component = SingleFileComponent::parse(text, EmbeddingKind::Vue);
+
+        let references = |index| {
+            let mut references: Vec<_> = component
+                .template_references(text, index)
+                .reads
+                .into_iter()
+                .collect();
+            references.sort();
+            references
+        };
+        assert_eq!(references(0), ["B"]);
+        assert!(references(1).is_empty());
+    }
+
+    #[test]
+    fn collects_the_references_of_vue_templates() {
+        let text = r#"<script setup>
+</script>
+<template>
+  <my-button :label="label" @click="count++" v-if="visible" v-focus :id ref="input">
+    {{ format(message) }}
+  </my-button>
+  <li v-for="item in items" :key="item.id">{{ item.name }}</li>
+  <Icons.Check #default="{ size }" />
+  <input v-model="query" />
+</template>
+"#;
+        let (reads, writes) = references(text, EmbeddingKind::Vue);
+        assert_eq!(
+            reads,
+            [
+                "Icons", "MyButton", "format", "id", "input", "item", "items", "label", "message",
+                "vFocus", "visible"
+            ]
+        );
+        assert_eq!(writes, ["count", "query"]);
+    }
+
+    #[test]
+    fn collects_the_references_of_svelte_templates() {
+        let text = r#"<script>
+</script>
+{#each items as item}
+  <Button on:click={() => select(item)} use:tooltip bind:value class:active>{$name}</Button>
+{:else if empty}
+  {@html content}
+{/each}
+<img {src} class="item {selected}" {...props} />
+<button on:click={() => count++}>{count}</button>
+<input bind:value={query} bind:checked={form.checked} />
+<svelte:head>
+  <script>
+    const ignored = window.analytics;
+  </script>
+</svelte:head>
+<style>
+  a { color: red; }
+</style>
+"#;
+        let (reads, writes) = references(text, EmbeddingKind::Svelte);
+        assert_eq!(
+            reads,
+            [
+                "$name", "Button", "active", "content", "count", "empty", "form", "item", "items",
+                "name", "props", "select", "selected", "src", "tooltip"
+            ]
+        );
+        assert_eq!(writes, ["count", "query", "value"]);
+    }
+
+    #[test]
+    fn collects_the_references_of_astro_templates() {
+        let text = r#"---
+import Card from "./Card.astro";
+---
+<Card title={title}>
+  {items.map((item) => <Item value={item} />)}
+</Card>
+"#;
+        let component = SingleFileComponent::parse(text, EmbeddingKind::Astro);
+        assert_eq!(
+            component.script().map(|range| &text[range]),
+            Some("\nimport Card from \"./Card.astro\";\n")
+        );
+        let (reads, writes) = references(text, EmbeddingKind::Astro);
+        assert_eq!(reads, ["Card", "Item", "item", "items", "title"]);
+        assert!(writes.is_empty());
+    }
+}
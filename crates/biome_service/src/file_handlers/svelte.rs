@@ -5,7 +5,7 @@ use crate::file_handlers::{
 };
 use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
+    DocumentFileSource, FixFileResult, FoldingRange, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
 use biome_formatter::Printed;
@@ -14,70 +14,51 @@ use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
-use regex::{Match, Regex};
-use std::sync::LazyLock;
 use tracing::debug;
 
-use super::{parse_lang_from_script_opening_tag, SearchCapabilities};
+use super::sfc::{self, SingleFileComponent};
+use super::{EditorCapabilities, SearchCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct SvelteFileHandler;
 
-// https://regex101.com/r/E4n4hh/6
-pub static SVELTE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
-});
-
 impl SvelteFileHandler {
     /// It extracts the JavaScript/TypeScript code contained in the script block of a Svelte file
     ///
     /// If there's no script block, an empty string is returned.
     pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
+        match Self::script(text) {
+            Some(script) => &text[script],
+            None => "",
         }
     }
 
     /// It takes the original content of a Svelte file, and new output of an Svelte file. The output is only the content contained inside the
     /// Svelte `<script>` tag. The function replaces `output` inside that `<script>`.
     pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
+        if let Some(script) = Self::script(input) {
             format!(
                 "{}{}{}",
-                &input[..script.start()],
+                &input[..usize::from(script.start())],
                 output,
-                &input[script.end()..]
+                &input[usize::from(script.end())..]
             )
         } else {
             input.to_string()
         }
     }
 
-    /// Returns the start byte offset of the Svelte `<script>` tag
+    /// Returns the start byte offset of the content of the Svelte `<script>` tag
     pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
+        Self::script(input).map(|script| script.start().into())
     }
 
-    fn matches_script(input: &str) -> Option<Match> {
-        SVELTE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+    fn script(input: &str) -> Option<TextRange> {
+        SingleFileComponent::parse(input, EmbeddingKind::Svelte).script()
     }
 
     pub fn file_source(text: &str) -> JsFileSource {
-        SVELTE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                let (language, variant) =
-                    parse_lang_from_script_opening_tag(captures.name("opening")?.as_str());
-                Some(
-                    JsFileSource::from(language)
-                        .with_variant(variant)
-                        .with_embedding_kind(EmbeddingKind::Svelte),
-                )
-            })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+        SingleFileComponent::parse(text, EmbeddingKind::Svelte).script_file_source()
     }
 }
 
@@ -94,6 +75,7 @@ impl ExtensionHandler for SvelteFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                find_references: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -104,6 +86,11 @@ impl ExtensionHandler for SvelteFileHandler {
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+                semantic_tokens: None,
+            },
         }
     }
 }
@@ -115,8 +102,9 @@ fn parse(
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = SvelteFileHandler::input(text);
-    let file_source = SvelteFileHandler::file_source(text);
+    let component = SingleFileComponent::parse(text, EmbeddingKind::Svelte);
+    let script = component.script().map_or("", |script| &text[script]);
+    let file_source = component.script_file_source();
 
     debug!("Parsing file with language {:?}", file_source);
 
@@ -133,7 +121,7 @@ fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
-    settings: WorkspaceSettingsHandle,
+    settings: &WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
     javascript::format(biome_path, document_file_source, parse, settings)
 }
@@ -158,7 +146,15 @@ pub(crate) fn format_on_type(
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    sfc::lint(params, EmbeddingKind::Svelte)
+}
+
+fn folding_ranges(parse: AnyParse, text: &str) -> Vec<FoldingRange> {
+    sfc::folding_ranges(parse, text, EmbeddingKind::Svelte)
+}
+
+fn selection_ranges(parse: AnyParse, text: &str, positions: &[TextSize]) -> Vec<Vec<TextRange>> {
+    sfc::selection_ranges(parse, text, positions, EmbeddingKind::Svelte)
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
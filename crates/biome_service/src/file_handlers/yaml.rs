@@ -0,0 +1,221 @@
+use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
+use biome_formatter::{BracketSpacing, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache, TextRange};
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_formatter::format_node;
+use biome_yaml_parser::parse_yaml_with_cache;
+use biome_yaml_syntax::{
+    AnyYamlValue, YamlArrayItem, YamlLanguage, YamlObjectMember, YamlRoot, YamlSyntaxNode,
+};
+
+use crate::{
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::{FoldingRange, GetSyntaxTreeResult},
+    WorkspaceError,
+};
+
+use super::{
+    editor, AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource,
+    EditorCapabilities, ExtensionHandler, FormatterCapabilities, ParseResult, ParserCapabilities,
+    SearchCapabilities,
+};
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct YamlFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub bracket_spacing: Option<BracketSpacing>,
+    pub enabled: Option<bool>,
+}
+
+impl ServiceLanguage for YamlLanguage {
+    type FormatterSettings = YamlFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = YamlFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.yaml
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &biome_fs::BiomePath,
+        file_source: &super::DocumentFileSource,
+    ) -> Self::FormatOptions {
+        // The indentation of YAML files is always made of spaces, so the indent style is ignored
+        let line_width = language
+            .and_then(|l| l.line_width)
+            .or(global.and_then(|g| g.line_width))
+            .unwrap_or_default();
+        let indent_width = language
+            .and_then(|l| l.indent_width)
+            .or(global.and_then(|g| g.indent_width))
+            .unwrap_or_default();
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+        let bracket_spacing = language
+            .and_then(|l| l.bracket_spacing)
+            .or(global.and_then(|g| g.bracket_spacing))
+            .unwrap_or_default();
+
+        let options = YamlFormatOptions::new(file_source.to_yaml_file_source().unwrap_or_default())
+            .with_indent_width(indent_width)
+            .with_line_width(line_width)
+            .with_line_ending(line_ending)
+            .with_bracket_spacing(bracket_spacing);
+        if let Some(overrides) = overrides {
+            overrides.to_override_yaml_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&crate::settings::Settings>,
+        _linter: Option<&crate::settings::LinterSettings>,
+        _overrides: Option<&crate::settings::OverrideSettings>,
+        _language: Option<&Self::LinterSettings>,
+        path: &biome_fs::BiomePath,
+        _file_source: &super::DocumentFileSource,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions {
+            configuration: AnalyzerConfiguration::default(),
+            file_path: path.to_path_buf(),
+            suppressed_signals: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct YamlFileHandler;
+
+impl ExtensionHandler for YamlFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: None,
+                code_actions: None,
+                rename: None,
+                find_references: None,
+                fix_all: None,
+                organize_imports: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(editor::selection_ranges::<YamlLanguage>),
+                semantic_tokens: None,
+            },
+        }
+    }
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_yaml_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: YamlSyntaxNode = parse.syntax();
+    let tree: YamlRoot = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<YamlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<YamlLanguage>(biome_path, document_file_source);
+
+    tracing::debug!("Format with the following options: \n{}", options);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Folds the block collections that are the value of a mapping entry or of a sequence entry,
+/// from the end of the key or of the `-` indicator to the end of the collection.
+fn folding_ranges(parse: AnyParse, _text: &str) -> Vec<FoldingRange> {
+    editor::folding_ranges(&parse.syntax::<YamlLanguage>(), |node| {
+        let (start, value) = if let Some(member) = YamlObjectMember::cast_ref(node) {
+            (
+                member.colon_token().ok()?.text_trimmed_range().end(),
+                member.value()?,
+            )
+        } else if let Some(item) = YamlArrayItem::cast_ref(node) {
+            (
+                item.minus_token().ok()?.text_trimmed_range().end(),
+                item.item()?,
+            )
+        } else {
+            return None;
+        };
+
+        match value {
+            AnyYamlValue::YamlObject(_) | AnyYamlValue::YamlArray(_) => {
+                Some(TextRange::new(start, value.range().end()))
+            }
+            _ => None,
+        }
+    })
+}
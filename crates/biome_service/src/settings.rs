@@ -1,6 +1,6 @@
 use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
 use crate::{Matcher, WorkspaceError};
-use biome_analyze::{AnalyzerOptions, AnalyzerRules};
+use biome_analyze::{AnalyzerOptions, AnalyzerPluginVec, AnalyzerRules};
 use biome_configuration::analyzer::assists::AssistsConfiguration;
 use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::javascript::JsxRuntime;
@@ -11,6 +11,7 @@ use biome_configuration::{
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
     PartialGraphqlConfiguration, PartialJavascriptConfiguration, PartialJsonConfiguration,
+    PartialMarkdownConfiguration, PartialYamlConfiguration, Plugins,
 };
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
@@ -20,7 +21,7 @@ use biome_diagnostics::Category;
 use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
 };
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, FileSystem};
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_grit_formatter::context::GritFormatOptions;
@@ -33,7 +34,12 @@ use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_plugin_loader::BiomePlugin;
 use biome_project::{NodeJsProject, PackageJson};
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
@@ -147,6 +153,13 @@ impl WorkspaceSettings {
         for key in keys_to_remove {
             self.data.remove(key)
         }
+
+        // The files of the removed project now belong to the enclosing project, if any
+        if self.data.get(self.current_project).is_none() {
+            if let Some(key) = self.get_project_key_for_path(workspace_path) {
+                self.current_project = key;
+            }
+        }
     }
 
     /// Checks if the current path belongs to a registered project.
@@ -157,23 +170,42 @@ impl WorkspaceSettings {
             return None;
         }
         trace!("Current key: {:?}", self.current_project);
-        let iter = self.data.iter();
-        for (key, path_to_settings) in iter {
-            trace!(
-                "Workspace path {:?}, file path {:?}",
-                path_to_settings.path,
-                path
-            );
-            trace!("Iter key: {:?}", key);
-            if key == self.current_project {
-                continue;
-            }
-            if path.strip_prefix(path_to_settings.path.as_path()).is_ok() {
-                trace!("Update workspace to {:?}", key);
-                return Some(key);
-            }
+        let key = self.get_project_key_for_path(path)?;
+        if key == self.current_project {
+            return None;
         }
-        None
+        trace!("Update workspace to {:?}", key);
+        Some(key)
+    }
+
+    /// Returns the key of the project registered with the folder `workspace_path`
+    pub fn get_project_key(&self, workspace_path: &Path) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .find(|(_, project)| project.path.as_path() == workspace_path)
+            .map(|(key, _)| key)
+    }
+
+    /// Returns the key of the nearest project that contains `path`.
+    ///
+    /// The projects can be nested, e.g. the packages of a monorepo that have
+    /// their own configuration file, so the project with the deepest folder wins.
+    pub fn get_project_key_for_path(&self, path: &Path) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .filter(|(_, project)| path.starts_with(project.path.as_path()))
+            .max_by_key(|(_, project)| project.path.components().count())
+            .map(|(key, _)| key)
+    }
+
+    /// Retrieves the settings of the project with the key `key`
+    pub fn get_settings(&self, key: ProjectKey) -> Option<&Settings> {
+        self.data.get(key).map(|data| &data.settings)
+    }
+
+    /// Retrieves a mutable reference of the settings of the project with the key `key`
+    pub fn get_settings_mut(&mut self, key: ProjectKey) -> Option<&mut Settings> {
+        self.data.get_mut(key).map(|data| &mut data.settings)
     }
 
     /// Checks if the current path belongs to a registered project.
@@ -201,6 +233,8 @@ pub struct Settings {
     pub assists: AssistsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// Analyzer plugins loaded from the configuration
+    pub plugins: AnalyzerPluginVec,
 }
 
 impl Settings {
@@ -268,6 +302,14 @@ impl Settings {
         if let Some(graphql) = configuration.graphql {
             self.languages.graphql = graphql.into()
         }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into()
+        }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into()
+        }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
         if let Some(overrides) = configuration.overrides {
@@ -278,6 +320,25 @@ impl Settings {
         Ok(())
     }
 
+    /// Loads the given plugins, replacing the ones loaded previously.
+    ///
+    /// The paths of the plugins are resolved against `base_path`, which is
+    /// the directory of the configuration file that lists them.
+    pub fn load_plugins(
+        &mut self,
+        fs: &dyn FileSystem,
+        plugins: &Plugins,
+        base_path: &Path,
+    ) -> Result<(), WorkspaceError> {
+        self.plugins = Vec::new();
+        for plugin in plugins.iter() {
+            let plugin = BiomePlugin::load(fs, plugin, base_path)?;
+            self.plugins.extend(plugin.analyzer_plugins);
+        }
+
+        Ok(())
+    }
+
     /// Retrieves the settings of the formatter
     pub fn formatter(&self) -> &FormatSettings {
         &self.formatter
@@ -301,6 +362,20 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for YAML files. It's disabled unless
+    /// it's enabled in the configuration.
+    pub fn yaml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.yaml.formatter.enabled.as_ref();
+        enabled != Some(&true)
+    }
+
+    /// Whether the formatter is disabled for Markdown files. It's disabled unless
+    /// it's enabled in the configuration.
+    pub fn markdown_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.markdown.formatter.enabled.as_ref();
+        enabled != Some(&true)
+    }
+
     /// Whether the linter is disabled for CSS files
     pub fn javascript_linter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.linter.enabled.as_ref();
@@ -567,6 +642,8 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
 }
 
 impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
@@ -669,6 +746,37 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
     }
 }
 
+impl From<PartialYamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: PartialYamlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+            language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
+        }
+
+        language_setting
+    }
+}
+
+impl From<PartialMarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: PartialMarkdownConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -805,17 +913,29 @@ fn to_file_settings(
 #[derive(Debug)]
 pub struct WorkspaceSettingsHandle<'a> {
     inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    /// The project whose settings are resolved
+    project_key: ProjectKey,
 }
 
 impl<'a> WorkspaceSettingsHandle<'a> {
     pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
-        Self {
-            inner: settings.read().unwrap(),
-        }
+        let inner = settings.read().unwrap();
+        let project_key = inner.get_current_project_key();
+        Self { inner, project_key }
+    }
+
+    /// Returns a handle on the settings of the nearest project that contains
+    /// `path`, or on the settings of the current project if there's none
+    pub(crate) fn for_path(settings: &'a RwLock<WorkspaceSettings>, path: &Path) -> Self {
+        let inner = settings.read().unwrap();
+        let project_key = inner
+            .get_project_key_for_path(path)
+            .unwrap_or_else(|| inner.get_current_project_key());
+        Self { inner, project_key }
     }
 
     pub(crate) fn settings(&self) -> Option<&Settings> {
-        self.inner.get_current_settings()
+        self.inner.get_settings(self.project_key)
     }
 }
 
@@ -835,7 +955,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.settings();
         let formatter = settings.map(|s| &s.formatter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -852,7 +972,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.settings();
         let linter = settings.map(|s| &s.linter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -1032,6 +1152,32 @@ impl OverrideSettings {
         options
     }
 
+    pub fn to_override_yaml_format_options(
+        &self,
+        path: &Path,
+        mut options: YamlFormatOptions,
+    ) -> YamlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_yaml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_markdown_format_options(
+        &self,
+        path: &Path,
+        mut options: MarkdownFormatOptions,
+    ) -> MarkdownFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_markdown_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Path,
@@ -1185,6 +1331,8 @@ pub struct OverrideSettingPattern {
     pub(crate) cached_grit_format_options: RwLock<Option<GritFormatOptions>>,
     pub(crate) cached_graphql_format_options: RwLock<Option<GraphqlFormatOptions>>,
     pub(crate) cached_html_format_options: RwLock<Option<HtmlFormatOptions>>,
+    pub(crate) cached_yaml_format_options: RwLock<Option<YamlFormatOptions>>,
+    pub(crate) cached_markdown_format_options: RwLock<Option<MarkdownFormatOptions>>,
     pub(crate) cached_js_parser_options: RwLock<Option<JsParserOptions>>,
     pub(crate) _cached_json_parser_options: RwLock<Option<JsonParserOptions>>,
     pub(crate) cached_css_parser_options: RwLock<Option<CssParserOptions>>,
@@ -1414,6 +1562,64 @@ impl OverrideSettingPattern {
         }
     }
 
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_yaml_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(bracket_spacing) = yaml_formatter.bracket_spacing.or(formatter.bracket_spacing)
+        {
+            options.set_bracket_spacing(bracket_spacing);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_yaml_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_markdown_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = markdown_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = markdown_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_markdown_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         if let Ok(readonly_cache) = self.cached_js_parser_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
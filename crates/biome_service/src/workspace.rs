@@ -63,7 +63,7 @@ use biome_configuration::PartialConfiguration;
 use biome_console::{markup, Markup, MarkupBuf};
 use biome_diagnostics::CodeSuggestion;
 use biome_formatter::Printed;
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, FileSystem, OsFileSystem};
 use biome_js_syntax::{TextRange, TextSize};
 use biome_text_edit::TextEdit;
 use core::str;
@@ -186,6 +186,10 @@ impl FileFeaturesResult {
                 !settings.formatter().enabled || settings.json_formatter_disabled()
             } else if file_source.is_css_like() {
                 !settings.formatter().enabled || settings.css_formatter_disabled()
+            } else if file_source.is_yaml_like() {
+                !settings.formatter().enabled || settings.yaml_formatter_disabled()
+            } else if file_source.is_markdown_like() {
+                !settings.formatter().enabled || settings.markdown_formatter_disabled()
             } else {
                 !settings.formatter().enabled
             };
@@ -512,6 +516,10 @@ pub struct UpdateSettingsParams {
     // @ematipico TODO: have a better data structure for this
     pub gitignore_matches: Vec<String>,
     pub workspace_directory: Option<PathBuf>,
+    /// The directory of the configuration file. The paths of the plugins are
+    /// relative to it. Defaults to the workspace directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configuration_directory: Option<PathBuf>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -550,6 +558,36 @@ impl From<(BiomePath, String)> for SetManifestForProjectParams {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateModuleGraphParams {
+    pub path: BiomePath,
+    /// The content of the file, that doesn't need to be open in the workspace
+    pub content: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RemoveFromModuleGraphParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetRuleConfigurationParams {
+    pub path: BiomePath,
+    pub group: String,
+    pub rule: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UsesModuleGraphParams {
+    pub path: BiomePath,
+    pub only: Vec<RuleSelector>,
+    pub skip: Vec<RuleSelector>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetSyntaxTreeParams {
@@ -604,6 +642,11 @@ pub struct PullDiagnosticsParams {
     pub max_diagnostics: u64,
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
+    /// Whether the diagnostics silenced by suppression comments should be
+    /// returned too. They are tagged as `suppressed`, and they aren't counted
+    /// in `errors` nor limited by `max_diagnostics`.
+    #[serde(default)]
+    pub include_suppressed: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -665,6 +708,8 @@ pub enum FixFileMode {
     SafeFixes,
     /// Applies [safe](biome_diagnostics::Applicability::Always) and [unsafe](biome_diagnostics::Applicability::MaybeIncorrect) fixes
     SafeAndUnsafeFixes,
+    /// Only removes the suppression comments that have no effect
+    RemoveUnusedSuppressions,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -717,6 +762,174 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// The edits of the other modules of the project that import the renamed
+    /// symbol, when the module exports it under its name
+    pub module_edits: Vec<ModuleRenameEdit>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ModuleRenameEdit {
+    /// The module that imports, or re-exports, the renamed symbol
+    pub path: BiomePath,
+    /// Range of the imported name in the module
+    pub range: TextRange,
+    /// The code that replaces the imported name. The symbol keeps its name
+    /// in the module, e.g. `import { a }` becomes `import { b as a }`.
+    pub new_text: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionResult {
+    /// Range of the declaration of the symbol, if it's declared in the file
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+    /// Whether the declaration of the symbol should be returned with its
+    /// references
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesResult {
+    /// The references of the symbol in the file, in source order
+    pub references: Vec<SymbolReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SymbolReference {
+    pub range: TextRange,
+    pub kind: SymbolReferenceKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SymbolReferenceKind {
+    /// The declaration of the symbol, e.g. `a` in `let a = 0`
+    Declaration,
+    /// A read of the symbol, e.g. `a` in `f(a)`
+    Read,
+    /// A write of the symbol, e.g. `a` in `a = 1`
+    Write,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesResult {
+    /// The regions of the file that can be folded, in source order
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    /// Range of the folded code. The range of a block is the code between
+    /// its delimiters, e.g. the code between `{` and `}`.
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FoldingRangeKind {
+    /// A block of code, e.g. a block statement, an object or a rule block
+    Block,
+    /// A multi-line comment or a run of single-line comments
+    Comment,
+    /// A run of import statements
+    Imports,
+    /// The code between a `#region` comment and an `#endregion` comment
+    Region,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesParams {
+    pub path: BiomePath,
+    pub positions: Vec<TextSize>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesResult {
+    /// For each position, the ranges of the syntax that contains it, from the
+    /// innermost to the outermost
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSemanticTokensParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSemanticTokensResult {
+    /// The classified identifiers of the file, in source order
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticToken {
+    /// Range of the name of the identifier
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenKind {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    TypeParameter,
+    Parameter,
+    Variable,
+    EnumMember,
+    Function,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenModifier {
+    /// The identifier declares the binding
+    Declaration,
+    /// The binding can't be reassigned, e.g. a `const` variable or an import
+    Readonly,
+    /// The binding is declared inside a function, a block or a class
+    Local,
+    /// The binding is imported from another module
+    Imported,
+    /// The identifier references a global of the environment, e.g. `console`
+    Global,
+    /// The identifier references a binding that isn't declared anywhere
+    Unresolved,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -805,6 +1018,8 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub file: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The edits produced by the rewrites of the pattern, if it contains any
+    pub rewrite: Option<TextEdit>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -893,6 +1108,31 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: SetManifestForProjectParams,
     ) -> Result<(), WorkspaceError>;
 
+    /// Collects the imports and exports of a file, and stores them in the
+    /// module graph of the project, so the rules can query them while
+    /// analyzing the other files
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError>;
+
+    /// Removes a file, or the files inside a directory, from the module graph
+    /// of the project, e.g. when they were deleted, so the rules and the
+    /// rename don't query them anymore
+    fn remove_from_module_graph(
+        &self,
+        params: RemoveFromModuleGraphParams,
+    ) -> Result<(), WorkspaceError>;
+
+    /// Checks whether the lint rules enabled for a file query the module
+    /// graph, so the graph is only built when it's needed
+    fn uses_module_graph(&self, params: UsesModuleGraphParams) -> Result<bool, WorkspaceError>;
+
+    /// Returns the configuration of the lint rule `group/rule` that applies
+    /// to a file, with its overrides, as written in the configuration file,
+    /// e.g. `"warn"` or `{ "level": "error", "options": {} }`
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<Option<serde_json::Value>, WorkspaceError>;
+
     /// Register a possible workspace project folder. Returns the key of said project. Use this key when you want to switch to different projects.
     fn register_project_folder(
         &self,
@@ -956,6 +1196,38 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Returns the declaration of the symbol at the given position of a file
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns the references of the symbol at the given position of a file
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
+    /// Returns the regions of a file that can be folded
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError>;
+
+    /// Returns the ranges that expand the selection at the given positions of
+    /// a file
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError>;
+
+    /// Returns the identifiers of a file, classified by the kind of binding
+    /// they refer to
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
@@ -985,12 +1257,18 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
 
 /// Convenience function for constructing a server instance of [Workspace]
 pub fn server() -> Box<dyn Workspace> {
-    Box::new(server::WorkspaceServer::new())
+    Box::new(server::WorkspaceServer::new(Box::<OsFileSystem>::default()))
+}
+
+/// Convenience function for constructing a server instance of [Workspace]
+/// that reads the files it needs, such as the plugins, from `fs`
+pub fn server_with_fs(fs: Box<dyn FileSystem>) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::new(fs))
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
 pub fn server_sync() -> Arc<dyn Workspace> {
-    Arc::new(server::WorkspaceServer::new())
+    Arc::new(server::WorkspaceServer::new(Box::<OsFileSystem>::default()))
 }
 
 /// Convenience function for constructing a client instance of [Workspace]
@@ -1050,6 +1328,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         max_diagnostics: u32,
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
+        include_suppressed: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
@@ -1057,6 +1336,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             max_diagnostics: max_diagnostics.into(),
             only,
             skip,
+            include_suppressed,
         })
     }
 
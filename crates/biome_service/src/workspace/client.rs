@@ -13,11 +13,16 @@ use std::{
 };
 
 use super::{
-    ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams,
-    SearchResults, SupportsFeatureParams, UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, FindReferencesParams, FindReferencesResult, FixFileParams,
+    FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    GetControlFlowGraphParams, GetFoldingRangesParams, GetFoldingRangesResult,
+    GetFormatterIRParams, GetRuleConfigurationParams, GetSelectionRangesParams,
+    GetSelectionRangesResult, GetSemanticTokensParams, GetSemanticTokensResult,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, GotoDefinitionParams, GotoDefinitionResult,
+    OpenFileParams, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
+    PullDiagnosticsResult, RemoveFromModuleGraphParams, RenameParams, RenameResult,
+    SearchPatternParams, SearchResults, SupportsFeatureParams, UpdateModuleGraphParams,
+    UpdateSettingsParams, UsesModuleGraphParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -125,6 +130,28 @@ where
         self.request("biome/set_manifest_for_project", params)
     }
 
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        self.request("biome/update_module_graph", params)
+    }
+
+    fn remove_from_module_graph(
+        &self,
+        params: RemoveFromModuleGraphParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/remove_from_module_graph", params)
+    }
+
+    fn uses_module_graph(&self, params: UsesModuleGraphParams) -> Result<bool, WorkspaceError> {
+        self.request("biome/uses_module_graph", params)
+    }
+
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<Option<serde_json::Value>, WorkspaceError> {
+        self.request("biome/get_rule_configuration", params)
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
@@ -200,6 +227,41 @@ where
         self.request("biome/rename", params)
     }
 
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.request("biome/goto_definition", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.request("biome/get_folding_ranges", params)
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        self.request("biome/get_selection_ranges", params)
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        self.request("biome/get_semantic_tokens", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -1,15 +1,21 @@
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ParsePatternParams,
-    ParsePatternResult, PatternId, ProjectKey, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
-    SearchPatternParams, SearchResults, SetManifestForProjectParams, SupportsFeatureParams,
-    UnregisterProjectFolderParams, UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FindReferencesParams,
+    FindReferencesResult, FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    GetControlFlowGraphParams, GetFoldingRangesParams, GetFoldingRangesResult,
+    GetFormatterIRParams, GetRuleConfigurationParams, GetSelectionRangesParams,
+    GetSelectionRangesResult, GetSemanticTokensParams, GetSemanticTokensResult,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, GotoDefinitionParams, GotoDefinitionResult,
+    ModuleRenameEdit, OpenFileParams, ParsePatternParams, ParsePatternResult, PatternId,
+    ProjectKey, PullActionsParams, PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult,
+    RegisterProjectFolderParams, RemoveFromModuleGraphParams, RenameResult, SearchPatternParams,
+    SearchResults, SetManifestForProjectParams, SupportsFeatureParams, SymbolReference,
+    SymbolReferenceKind, UnregisterProjectFolderParams, UpdateModuleGraphParams,
+    UpdateSettingsParams, UsesModuleGraphParams,
 };
 use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    AnalyzerVisitorBuilder, Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams,
+    LintParams, ParseResult, SearchOutcome,
 };
 use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
@@ -19,25 +25,33 @@ use crate::workspace::{
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::{AnalysisFilter, RuleCategoriesBuilder};
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
-use biome_fs::{BiomePath, ConfigName};
+use biome_fs::{BiomePath, ConfigName, FileSystem};
 use biome_grit_patterns::GritQuery;
-use biome_js_syntax::ModuleKind;
-use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
+use biome_js_analyze::utils::rename::RenameError;
+use biome_js_syntax::{AnyJsRoot, ModuleKind};
+use biome_json_parser::{parse_json, parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_project::{NodeJsProject, PackageJson, PackageType, Project};
-use biome_rowan::NodeCache;
+use biome_project::{
+    ImportedSymbol, Manifest, ModuleGraph, ModuleInfo, NodeJsProject, PackageJson, PackageType,
+    Project, TsConfigJson,
+};
+use biome_rowan::{NodeCache, TextSize};
+use biome_text_edit::TextEdit;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
+use rustc_hash::FxHashSet;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -56,6 +70,13 @@ pub(super) struct WorkspaceServer {
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
     patterns: DashMap<PatternId, GritQuery>,
+    /// The imports and exports of the JavaScript files of the project. The
+    /// modules are updated in place, while the analysis of other files
+    /// queries the graph.
+    module_graph: Arc<ModuleGraph>,
+    /// The file system used to read the files that aren't opened in the
+    /// workspace, such as the plugins
+    fs: Box<dyn FileSystem>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -82,7 +103,7 @@ impl WorkspaceServer {
     /// This is implemented as a crate-private method instead of using
     /// [Default] to disallow instances of [Workspace] from being created
     /// outside a [crate::App]
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(fs: Box<dyn FileSystem>) -> Self {
         Self {
             features: Features::new(),
             settings: RwLock::default(),
@@ -91,6 +112,8 @@ impl WorkspaceServer {
             current_project_path: RwLock::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
+            module_graph: Arc::default(),
+            fs,
         }
     }
 
@@ -99,6 +122,12 @@ impl WorkspaceServer {
         WorkspaceSettingsHandle::new(&self.settings)
     }
 
+    /// Provides a reference to the settings of the nearest project that
+    /// contains `path`
+    fn workspace_for(&self, path: &Path) -> WorkspaceSettingsHandle {
+        WorkspaceSettingsHandle::for_path(&self.settings, path)
+    }
+
     fn workspaces_mut(&self) -> WorkspaceSettingsHandleMut {
         WorkspaceSettingsHandleMut::new(&self.settings)
     }
@@ -151,6 +180,159 @@ impl WorkspaceServer {
         Ok(workspace.as_ref().get_current_manifest().cloned())
     }
 
+    /// Returns the module graph of the project
+    fn get_module_graph(&self) -> Arc<ModuleGraph> {
+        self.module_graph.clone()
+    }
+
+    /// Stores the imports and exports of the file in the module graph, if it's
+    /// a JavaScript file
+    fn update_module_info(&self, path: &Path, file_source: DocumentFileSource, parse: &AnyParse) {
+        if !file_source.is_javascript_like() {
+            return;
+        }
+
+        let info = ModuleInfo::from_js_root(&parse.tree::<AnyJsRoot>());
+        self.module_graph.insert(path, info);
+    }
+
+    /// Stores the `package.json`, `tsconfig.json` or `jsconfig.json` at `path`
+    /// in the module graph, to resolve the imports of the modules. Returns
+    /// `false` when the file isn't one of them.
+    fn update_module_graph_config(&self, path: &Path, content: &str) -> bool {
+        match path.file_name().and_then(OsStr::to_str) {
+            Some("package.json") => {
+                let parsed = parse_json(content, JsonParserOptions::default());
+                if let Some(manifest) =
+                    PackageJson::deserialize_manifest(&parsed.tree()).into_deserialized()
+                {
+                    self.module_graph.insert_package(path, manifest);
+                }
+            }
+            Some("tsconfig.json" | "jsconfig.json") => {
+                let options = JsonParserOptions::default()
+                    .with_allow_comments()
+                    .with_allow_trailing_commas();
+                let parsed = parse_json(content, options);
+                if let Some(tsconfig) =
+                    TsConfigJson::deserialize_manifest(&parsed.tree()).into_deserialized()
+                {
+                    self.module_graph.insert_tsconfig(path, tsconfig);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the declaration and the references of the symbol at
+    /// `symbol_at`, in source order
+    fn find_symbol_references(
+        &self,
+        path: &BiomePath,
+        symbol_at: TextSize,
+    ) -> Result<Vec<SymbolReference>, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(path);
+        let find_references = capabilities
+            .analyzer
+            .find_references
+            .ok_or_else(self.build_capability_error(path))?;
+
+        let parse = self.get_parse(path.clone())?;
+        Ok(find_references(parse, symbol_at))
+    }
+
+    /// Returns the edits of the imports of the symbol at `symbol_at` in the
+    /// other modules of the module graph, when the module exports the symbol
+    /// under its name. The modules that re-export all the exports of the
+    /// module with `export *` are followed.
+    ///
+    /// The rename is refused when a module imports the exports as a
+    /// namespace, because the uses of the namespace can't be tracked.
+    fn rename_imports(
+        &self,
+        path: &BiomePath,
+        symbol_at: TextSize,
+        new_name: &str,
+    ) -> Result<Vec<ModuleRenameEdit>, WorkspaceError> {
+        let Some(info) = self.module_graph.get(path) else {
+            return Ok(Vec::new());
+        };
+        let references = self.find_symbol_references(path, symbol_at)?;
+        // `export { a as b }` keeps exporting `b` when `a` is renamed
+        let Some(export) = info.exports.iter().find(|export| {
+            references
+                .iter()
+                .any(|reference| reference.range == export.range)
+        }) else {
+            return Ok(Vec::new());
+        };
+
+        let mut edits = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(exporter) = queue.pop() {
+            if !visited.insert(exporter.clone()) {
+                continue;
+            }
+            for (importer, import) in self.module_graph.importers(&exporter) {
+                if import.symbols.contains(&ImportedSymbol::Namespace) {
+                    return Err(WorkspaceError::RenameError(
+                        RenameError::ImportedAsNamespace {
+                            export_name: export.name.clone(),
+                            importer: importer.display().to_string(),
+                        },
+                    ));
+                }
+                if import.symbols.contains(&ImportedSymbol::All) {
+                    queue.push(importer.clone());
+                }
+                for specifier in &import.named_specifiers {
+                    if specifier.name != export.name {
+                        continue;
+                    }
+                    let new_text = if specifier.is_shorthand {
+                        format!("{new_name} as {}", export.name)
+                    } else {
+                        new_name.to_string()
+                    };
+                    edits.push(ModuleRenameEdit {
+                        path: BiomePath::new(&importer),
+                        range: specifier.range,
+                        new_text,
+                    });
+                }
+            }
+        }
+
+        edits.sort_by(|left, right| {
+            (left.path.as_path(), left.range.start())
+                .cmp(&(right.path.as_path(), right.range.start()))
+        });
+        Ok(edits)
+    }
+
+    /// Returns the file source of the file at `path`, or `file_source` when
+    /// provided. The `.js` files of CommonJS packages are scripts.
+    fn resolve_file_source(
+        &self,
+        path: &Path,
+        file_source: Option<DocumentFileSource>,
+    ) -> Result<DocumentFileSource, WorkspaceError> {
+        let mut source = file_source.unwrap_or(DocumentFileSource::from_path(path));
+        let manifest = self.get_current_manifest()?;
+
+        if let DocumentFileSource::Js(js) = &mut source {
+            if let Some(manifest) = manifest {
+                if manifest.r#type == Some(PackageType::Commonjs) && js.file_extension() == "js" {
+                    js.set_module_kind(ModuleKind::Script);
+                }
+            }
+        }
+
+        Ok(source)
+    }
+
     #[tracing::instrument(level = "trace", skip(self), fields(return))]
     fn get_source(&self, index: usize) -> Option<DocumentFileSource> {
         let file_sources = self.file_sources.read().unwrap();
@@ -237,7 +419,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(biome_path))?;
 
                 let size_limit = {
-                    let workspace = self.workspace();
+                    let workspace = self.workspace_for(biome_path);
                     let settings = workspace.settings();
                     let limit =
                         settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
@@ -254,7 +436,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let workspace = self.workspace();
+                let workspace = self.workspace_for(biome_path);
                 let Some(file_source) = self.get_source(document.file_source_index) else {
                     return Err(WorkspaceError::not_found());
                 };
@@ -273,6 +455,7 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
+                self.update_module_info(biome_path, language.unwrap_or(file_source), &any_parse);
                 Ok(entry.insert(any_parse).clone())
             }
         }
@@ -301,7 +484,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.workspace();
+        let settings = self.workspace_for(path);
         let settings = settings.settings();
         let Some(settings) = settings else {
             return false;
@@ -328,7 +511,7 @@ impl WorkspaceServer {
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
     fn is_ignored_by_feature_config(&self, path: &Path, feature: FeatureKind) -> bool {
-        let settings = self.workspace();
+        let settings = self.workspace_for(path);
         let settings = settings.settings();
         let Some(settings) = settings else {
             return false;
@@ -372,7 +555,7 @@ impl Workspace for WorkspaceServer {
         let capabilities = self.get_file_capabilities(&params.path);
         let language = DocumentFileSource::from_path(&params.path);
         let path = params.path.as_path();
-        let settings = self.workspace();
+        let settings = self.workspace_for(path);
         let settings = settings.settings();
         let mut file_features = FileFeaturesResult::new();
 
@@ -419,36 +602,43 @@ impl Workspace for WorkspaceServer {
     /// This function may panic if the internal settings mutex has been poisoned
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "trace", skip(self))]
-    fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
+    fn update_settings(&self, mut params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
+        let plugins = params.configuration.plugins.take();
+        let configuration_directory = params
+            .configuration_directory
+            .or_else(|| params.workspace_directory.clone())
+            .unwrap_or_default();
         let mut workspace = self.workspaces_mut();
-        workspace
-            .as_mut()
-            .get_current_settings_mut()
-            .merge_with_configuration(
-                params.configuration,
-                params.workspace_directory,
-                params.vcs_base_path,
-                params.gitignore_matches.as_slice(),
-            )?;
+        let workspace = workspace.as_mut();
+        // The settings of a nested project can be updated without making it
+        // the current project
+        let key = params
+            .workspace_directory
+            .as_deref()
+            .and_then(|directory| workspace.get_project_key(directory))
+            .unwrap_or_else(|| workspace.get_current_project_key());
+        let settings = workspace
+            .get_settings_mut(key)
+            .expect("You must have at least one workspace.");
+        settings.merge_with_configuration(
+            params.configuration,
+            params.workspace_directory,
+            params.vcs_base_path,
+            params.gitignore_matches.as_slice(),
+        )?;
+        // The plugins loaded previously are removed when the configuration doesn't list any
+        settings.load_plugins(
+            self.fs.as_ref(),
+            &plugins.unwrap_or_default(),
+            &configuration_directory,
+        )?;
 
         Ok(())
     }
     /// Add a new file to the workspace
     #[tracing::instrument(level = "trace", skip(self))]
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
-        let mut source = params
-            .document_file_source
-            .unwrap_or(DocumentFileSource::from_path(&params.path));
-        let manifest = self.get_current_manifest()?;
-
-        if let DocumentFileSource::Js(js) = &mut source {
-            if let Some(manifest) = manifest {
-                if manifest.r#type == Some(PackageType::Commonjs) && js.file_extension() == "js" {
-                    js.set_module_kind(ModuleKind::Script);
-                }
-            }
-        }
-
+        let source = self.resolve_file_source(&params.path, params.document_file_source)?;
         let index = self.set_source(source);
         self.syntax.remove(&params.path);
         self.documents.insert(
@@ -471,6 +661,7 @@ impl Workspace for WorkspaceServer {
         params: SetManifestForProjectParams,
     ) -> Result<(), WorkspaceError> {
         let index = self.set_source(JsonFileSource::json().into());
+        self.update_module_graph_config(&params.manifest_path, &params.content);
         self.syntax.remove(&params.manifest_path);
         self.documents.insert(
             params.manifest_path.clone(),
@@ -485,34 +676,121 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        if self.update_module_graph_config(&params.path, &params.content) {
+            return Ok(());
+        }
+
+        let file_source = self.resolve_file_source(&params.path, None)?;
+        if !file_source.is_javascript_like() {
+            return Ok(());
+        }
+
+        let capabilities = self.features.get_capabilities(&params.path, file_source);
+        let parse = capabilities
+            .parser
+            .parse
+            .ok_or_else(self.build_capability_error(&params.path))?;
+        let workspace = self.workspace_for(&params.path);
+        let ParseResult {
+            language,
+            any_parse,
+        } = parse(
+            &params.path,
+            file_source,
+            params.content.as_str(),
+            workspace.settings(),
+            &mut NodeCache::default(),
+        );
+        self.update_module_info(&params.path, language.unwrap_or(file_source), &any_parse);
+
+        Ok(())
+    }
+
+    fn remove_from_module_graph(
+        &self,
+        params: RemoveFromModuleGraphParams,
+    ) -> Result<(), WorkspaceError> {
+        self.module_graph.remove(&params.path);
+        Ok(())
+    }
+
+    fn uses_module_graph(&self, params: UsesModuleGraphParams) -> Result<bool, WorkspaceError> {
+        let file_source = self.resolve_file_source(&params.path, None)?;
+        if !file_source.is_javascript_like() {
+            return Ok(false);
+        }
+
+        let workspace = self.workspace_for(&params.path);
+        let Some(settings) = workspace.settings() else {
+            return Ok(false);
+        };
+        let linter_disabled = settings
+            .override_settings
+            .linter_disabled(&params.path)
+            .unwrap_or_else(|| !settings.linter().enabled || settings.javascript_linter_disabled());
+        if linter_disabled {
+            return Ok(false);
+        }
+
+        let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(Some(settings))
+            .with_linter_rules(&params.only, &params.skip, params.path.as_path())
+            .finish();
+        let filter = AnalysisFilter {
+            categories: RuleCategoriesBuilder::default().with_lint().build(),
+            enabled_rules: Some(enabled_rules.as_slice()),
+            disabled_rules: &disabled_rules,
+            range: None,
+        };
+
+        Ok(biome_js_analyze::uses_module_graph(&filter))
+    }
+
+    fn get_rule_configuration(
+        &self,
+        params: GetRuleConfigurationParams,
+    ) -> Result<Option<serde_json::Value>, WorkspaceError> {
+        let workspace = self.workspace_for(&params.path);
+        let Some(rules) = workspace
+            .settings()
+            .and_then(|settings| settings.as_linter_rules(&params.path))
+            .and_then(|rules| serde_json::to_value(rules.as_ref()).ok())
+        else {
+            return Ok(None);
+        };
+        Ok(rules
+            .get(&params.group)
+            .and_then(|group| group.get(&params.rule))
+            .cloned())
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
     ) -> Result<ProjectKey, WorkspaceError> {
-        let current_project_path = self.get_current_project_path();
+        // A folder is registered once, e.g. the configuration of a nested
+        // folder can be loaded again after a change
+        let registered_key = params
+            .path
+            .as_deref()
+            .and_then(|path| self.workspace().as_ref().get_project_key(path));
         debug!(
-            "Compare the current project with the new one {:?} {:?} {:?}",
-            current_project_path.as_deref(),
+            "Register the project {:?}, the current project is {:?}, registered as {:?}",
             params.path.as_ref(),
-            current_project_path.as_deref() != params.path.as_ref()
+            self.get_current_project_path().as_deref(),
+            registered_key
         );
 
-        let is_new_path = match (current_project_path.as_deref(), params.path.as_ref()) {
-            (Some(current_project_path), Some(params_path)) => current_project_path != params_path,
-            _ => true,
+        let path = params.path.unwrap_or_default();
+        let key = match registered_key {
+            Some(key) => key,
+            None => self.register_project(path.clone()),
         };
-
-        if is_new_path {
-            let path = params.path.unwrap_or_default();
-            let key = self.register_project(path.clone());
-            if params.set_as_current_workspace {
-                self.set_current_project(key);
-                self.set_current_project_path(BiomePath::new(path));
-            }
-            Ok(key)
-        } else {
-            Ok(self.workspace().as_ref().get_current_project_key())
+        if params.set_as_current_workspace {
+            self.set_current_project(key);
+            self.set_current_project_path(BiomePath::new(path));
         }
+        Ok(key)
     }
 
     fn unregister_project_folder(
@@ -563,7 +841,7 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -620,19 +898,25 @@ impl Workspace for WorkspaceServer {
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         let parse = self.get_parse(params.path.clone())?;
         let manifest = self.get_current_manifest()?;
+        let content = self.get_file_content(GetFileContentParams {
+            path: params.path.clone(),
+        })?;
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
                     let results = lint(LintParams {
                         parse,
-                        workspace: &self.workspace(),
+                        workspace: &self.workspace_for(&params.path),
                         max_diagnostics: params.max_diagnostics as u32,
                         path: &params.path,
                         only: params.only,
                         skip: params.skip,
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
+                        include_suppressed: params.include_suppressed,
                         manifest,
+                        module_graph: self.get_module_graph(),
+                        content: &content,
                     });
 
                     (
@@ -676,18 +960,23 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let manifest = self.get_current_manifest()?;
         let language = self.get_file_source(&params.path);
+        let content = self.get_file_content(GetFileContentParams {
+            path: params.path.clone(),
+        })?;
         Ok(code_actions(CodeActionsParams {
             parse,
             range: params.range,
             workspace: &workspace,
             path: &params.path,
             manifest,
+            module_graph: self.get_module_graph(),
             language,
             only: params.only,
             skip: params.skip,
+            content: &content,
         }))
     }
 
@@ -699,7 +988,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -709,7 +998,7 @@ impl Workspace for WorkspaceServer {
             }
         }
         let document_file_source = self.get_file_source(&params.path);
-        format(&params.path, &document_file_source, parse, workspace)
+        format(&params.path, &document_file_source, parse, &workspace)
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
@@ -718,7 +1007,7 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
 
@@ -744,7 +1033,7 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let settings = workspace.settings();
         let parse = self.get_parse(params.path.clone())?;
         if let Some(settings) = settings {
@@ -774,19 +1063,24 @@ impl Workspace for WorkspaceServer {
 
         let manifest = self.get_current_manifest()?;
         let language = self.get_file_source(&params.path);
+        let content = self.get_file_content(GetFileContentParams {
+            path: params.path.clone(),
+        })?;
         fix_all(FixAllParams {
             parse,
             // rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
             // filter,
-            workspace: self.workspace(),
+            workspace: &self.workspace_for(&params.path),
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            module_graph: self.get_module_graph(),
             document_file_source: language,
             only: params.only,
             skip: params.skip,
             rule_categories: params.rule_categories,
+            content: &content,
         })
     }
 
@@ -798,11 +1092,93 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone())?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let mut result = rename(
+            &params.path,
+            parse,
+            params.symbol_at,
+            params.new_name.clone(),
+        )?;
+        result.module_edits =
+            self.rename_imports(&params.path, params.symbol_at, &params.new_name)?;
 
         Ok(result)
     }
 
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let references = self.find_symbol_references(&params.path, params.symbol_at)?;
+        let range = references
+            .into_iter()
+            .find(|reference| reference.kind == SymbolReferenceKind::Declaration)
+            .map(|reference| reference.range);
+
+        Ok(GotoDefinitionResult { range })
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let mut references = self.find_symbol_references(&params.path, params.symbol_at)?;
+        if !params.include_declaration {
+            references.retain(|reference| reference.kind != SymbolReferenceKind::Declaration);
+        }
+
+        Ok(FindReferencesResult { references })
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .editor
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let content = self.get_file_content(GetFileContentParams { path: params.path })?;
+        Ok(GetFoldingRangesResult {
+            ranges: folding_ranges(parse, &content),
+        })
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .editor
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let content = self.get_file_content(GetFileContentParams { path: params.path })?;
+        Ok(GetSelectionRangesResult {
+            ranges: selection_ranges(parse, &content, &params.positions),
+        })
+    }
+
+    fn get_semantic_tokens(
+        &self,
+        params: GetSemanticTokensParams,
+    ) -> Result<GetSemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .editor
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSemanticTokensResult {
+            tokens: semantic_tokens(parse),
+        })
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -839,11 +1215,14 @@ impl Workspace for WorkspaceServer {
             .search
             .search
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+        let workspace = self.workspace_for(&params.path);
         let parse = self.get_parse(params.path.clone())?;
 
         let document_file_source = self.get_file_source(&params.path);
-        let matches = search(
+        let SearchOutcome {
+            matches,
+            rewritten_code,
+        } = search(
             &params.path,
             &document_file_source,
             parse,
@@ -851,9 +1230,21 @@ impl Workspace for WorkspaceServer {
             workspace,
         )?;
 
+        let rewrite = match rewritten_code {
+            Some(code) => {
+                let document = self
+                    .documents
+                    .get(&params.path)
+                    .ok_or_else(WorkspaceError::not_found)?;
+                Some(TextEdit::from_unicode_words(&document.content, &code))
+            }
+            None => None,
+        };
+
         Ok(SearchResults {
             file: params.path,
             matches,
+            rewrite,
         })
     }
 
@@ -451,7 +451,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 24] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
@@ -472,5 +472,10 @@ pub fn methods() -> [WorkspaceMethod; 19] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(goto_definition),
+        workspace_method!(find_references),
+        workspace_method!(get_folding_ranges),
+        workspace_method!(get_selection_ranges),
+        workspace_method!(get_semantic_tokens),
     ]
 }
@@ -2,13 +2,25 @@
 mod test {
     use biome_analyze::RuleCategories;
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
-    use biome_fs::BiomePath;
-    use biome_js_syntax::{JsFileSource, TextSize};
+    use biome_configuration::{
+        PartialConfiguration, PartialFormatterConfiguration, PluginConfiguration, Plugins,
+    };
+    use biome_diagnostics::Diagnostic;
+    use biome_formatter::{IndentStyle, IndentWidth};
+    use biome_fs::{BiomePath, MemoryFileSystem};
+    use biome_html_syntax::HtmlFileSource;
+    use biome_js_syntax::{JsFileSource, TextRange, TextSize};
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, server_with_fs, CodeAction, FileGuard, FindReferencesParams, FixFileMode,
+        FoldingRange, FoldingRangeKind, GetFoldingRangesParams, GetSelectionRangesParams,
+        GetSemanticTokensParams, GotoDefinitionParams, ModuleRenameEdit, OpenFileParams,
+        ParsePatternParams, RegisterProjectFolderParams, RenameParams, SemanticToken,
+        SemanticTokenKind, SemanticTokenModifier, SymbolReference, SymbolReferenceKind,
+        UpdateModuleGraphParams, UpdateSettingsParams, UsesModuleGraphParams,
     };
     use biome_service::Workspace;
+    use std::path::PathBuf;
     fn create_server() -> Box<dyn Workspace> {
         let workspace = server();
         workspace
@@ -243,9 +255,1049 @@ type User {
                 "useDeprecatedReason",
             )],
             vec![],
+            false,
         );
         assert!(result.is_ok());
         let diagnostics = result.unwrap().diagnostics;
         assert_eq!(diagnostics.len(), 1)
     }
+
+    #[test]
+    fn search_pattern_returns_rewrites() {
+        let workspace = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "console.log('hello');\nfoo();\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let pattern = workspace
+            .parse_pattern(ParsePatternParams {
+                pattern: "`console.log($message)` => `logger.info($message)`".into(),
+            })
+            .unwrap()
+            .pattern_id;
+        let result = file.search_pattern(&pattern).unwrap();
+
+        assert_eq!(result.matches.len(), 1);
+        let rewrite = result.rewrite.expect("the pattern to rewrite the file");
+        assert_eq!(
+            rewrite.new_string("console.log('hello');\nfoo();\n"),
+            "logger.info('hello');\nfoo();\n"
+        );
+    }
+
+    #[test]
+    fn search_pattern_applies_all_the_rewrites() {
+        let workspace = create_server();
+
+        let content = "console.log('hello');\nfoo();\nconsole.log('world');\n";
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: content.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let pattern = workspace
+            .parse_pattern(ParsePatternParams {
+                pattern: "`console.log($message)` => `logger.info($message)`".into(),
+            })
+            .unwrap()
+            .pattern_id;
+        let result = file.search_pattern(&pattern).unwrap();
+
+        assert_eq!(result.matches.len(), 2);
+        let rewrite = result.rewrite.expect("the pattern to rewrite the file");
+        assert_eq!(
+            rewrite.new_string(content),
+            "logger.info('hello');\nfoo();\nlogger.info('world');\n"
+        );
+    }
+
+    #[test]
+    fn finds_the_definition_and_the_references_of_a_binding() {
+        let workspace = create_server();
+
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "let a = 1;\na = 2;\nconsole.log(a);\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let definition = workspace
+            .goto_definition(GotoDefinitionParams {
+                path: BiomePath::new("file.js"),
+                symbol_at: TextSize::from(30),
+            })
+            .unwrap();
+        assert_eq!(
+            definition.range,
+            Some(TextRange::new(TextSize::from(4), TextSize::from(5)))
+        );
+
+        let result = workspace
+            .find_references(FindReferencesParams {
+                path: BiomePath::new("file.js"),
+                symbol_at: TextSize::from(4),
+                include_declaration: true,
+            })
+            .unwrap();
+        assert_eq!(
+            result.references,
+            vec![
+                SymbolReference {
+                    range: TextRange::new(TextSize::from(4), TextSize::from(5)),
+                    kind: SymbolReferenceKind::Declaration,
+                },
+                SymbolReference {
+                    range: TextRange::new(TextSize::from(11), TextSize::from(12)),
+                    kind: SymbolReferenceKind::Write,
+                },
+                SymbolReference {
+                    range: TextRange::new(TextSize::from(30), TextSize::from(31)),
+                    kind: SymbolReferenceKind::Read,
+                },
+            ]
+        );
+
+        let result = workspace
+            .find_references(FindReferencesParams {
+                path: BiomePath::new("file.js"),
+                symbol_at: TextSize::from(4),
+                include_declaration: false,
+            })
+            .unwrap();
+        assert_eq!(result.references.len(), 2);
+    }
+
+    #[test]
+    fn computes_the_folding_ranges_and_the_selection_ranges() {
+        let workspace = create_server();
+
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "import a from \"a\";\nimport b from \"b\";\n\n// one\n// two\nfunction f() {\n  return [\n    1,\n  ];\n}\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_folding_ranges(GetFoldingRangesParams {
+                path: BiomePath::new("file.js"),
+            })
+            .unwrap();
+        assert_eq!(
+            result.ranges,
+            vec![
+                FoldingRange {
+                    range: TextRange::new(TextSize::from(0), TextSize::from(37)),
+                    kind: FoldingRangeKind::Imports,
+                },
+                FoldingRange {
+                    range: TextRange::new(TextSize::from(39), TextSize::from(52)),
+                    kind: FoldingRangeKind::Comment,
+                },
+                FoldingRange {
+                    range: TextRange::new(TextSize::from(67), TextSize::from(91)),
+                    kind: FoldingRangeKind::Block,
+                },
+                FoldingRange {
+                    range: TextRange::new(TextSize::from(78), TextSize::from(88)),
+                    kind: FoldingRangeKind::Block,
+                },
+            ]
+        );
+
+        let result = workspace
+            .get_selection_ranges(GetSelectionRangesParams {
+                path: BiomePath::new("file.js"),
+                positions: vec![TextSize::from(83)],
+            })
+            .unwrap();
+        let ranges = &result.ranges[0];
+        assert_eq!(
+            ranges.first(),
+            Some(&TextRange::new(TextSize::from(83), TextSize::from(84)))
+        );
+        assert!(ranges.contains(&TextRange::new(TextSize::from(77), TextSize::from(89))));
+        assert!(ranges.contains(&TextRange::new(TextSize::from(53), TextSize::from(92))));
+
+        let range =
+            |start: u32, end: u32| TextRange::new(TextSize::from(start), TextSize::from(end));
+        // `#regional` isn't a region marker, so it doesn't capture `#endregion`
+        for (path, document_file_source, content, folding_ranges, position, selection_range) in [
+            (
+                "file.css",
+                None,
+                "/* #region colors */\n/* #regional */\na {\n  color: red;\n}\n/* #endregion */\n",
+                vec![
+                    (range(0, 73), FoldingRangeKind::Region),
+                    (range(40, 55), FoldingRangeKind::Block),
+                ],
+                50,
+                range(50, 53),
+            ),
+            (
+                "file.json",
+                None,
+                "{\n  \"a\": [\n    1\n  ]\n}\n",
+                vec![
+                    (range(1, 21), FoldingRangeKind::Block),
+                    (range(10, 19), FoldingRangeKind::Block),
+                ],
+                15,
+                range(15, 16),
+            ),
+            (
+                "file.graphql",
+                None,
+                "# region\n# regional\nquery {\n  a\n}\n# endregion\n",
+                vec![
+                    (range(0, 45), FoldingRangeKind::Region),
+                    (range(27, 32), FoldingRangeKind::Block),
+                ],
+                30,
+                range(30, 31),
+            ),
+            (
+                "file.html",
+                // The HTML files are only detected with the `experimental-html` feature
+                Some(DocumentFileSource::from(HtmlFileSource::html())),
+                "<div>\n  <p>a</p>\n</div>\n",
+                vec![(range(5, 17), FoldingRangeKind::Block)],
+                11,
+                range(11, 12),
+            ),
+        ] {
+            let _file = FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source,
+                },
+            )
+            .unwrap();
+
+            let result = workspace
+                .get_folding_ranges(GetFoldingRangesParams {
+                    path: BiomePath::new(path),
+                })
+                .unwrap();
+            let folding_ranges: Vec<_> = folding_ranges
+                .into_iter()
+                .map(|(range, kind)| FoldingRange { range, kind })
+                .collect();
+            assert_eq!(result.ranges, folding_ranges, "{path}");
+
+            let result = workspace
+                .get_selection_ranges(GetSelectionRangesParams {
+                    path: BiomePath::new(path),
+                    positions: vec![TextSize::from(position)],
+                })
+                .unwrap();
+            assert_eq!(result.ranges[0].first(), Some(&selection_range), "{path}");
+        }
+    }
+
+    #[test]
+    fn computes_the_folding_ranges_and_the_selection_ranges_of_components() {
+        let workspace = create_server();
+
+        let range =
+            |start: u32, end: u32| TextRange::new(TextSize::from(start), TextSize::from(end));
+        for (path, content, folding_ranges, position, selection_range, element_range) in [
+            (
+                "file.vue",
+                "<script>\nconst a = [\n  1,\n];\n</script>\n<template>\n  <div>{{ a }}</div>\n</template>\n",
+                vec![range(8, 29), range(20, 26), range(49, 71)],
+                23,
+                range(23, 24),
+                range(0, 38),
+            ),
+            (
+                "file.svelte",
+                "<script>\nconst a = [\n  1,\n];\n</script>\n<div>\n  {a}\n</div>\n",
+                vec![range(8, 29), range(20, 26), range(44, 51)],
+                23,
+                range(23, 24),
+                range(0, 38),
+            ),
+            (
+                "file.astro",
+                "---\nconst a = [\n  1,\n];\n---\n<div>\n  {a}\n</div>\n",
+                vec![range(3, 24), range(15, 21), range(33, 40)],
+                18,
+                range(18, 19),
+                // The frontmatter isn't part of the HTML tree
+                range(4, 23),
+            ),
+        ] {
+            let _file = FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap();
+
+            let result = workspace
+                .get_folding_ranges(GetFoldingRangesParams {
+                    path: BiomePath::new(path),
+                })
+                .unwrap();
+            let folding_ranges: Vec<_> = folding_ranges
+                .into_iter()
+                .map(|range| FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Block,
+                })
+                .collect();
+            assert_eq!(result.ranges, folding_ranges, "{path}");
+
+            let result = workspace
+                .get_selection_ranges(GetSelectionRangesParams {
+                    path: BiomePath::new(path),
+                    positions: vec![TextSize::from(position)],
+                })
+                .unwrap();
+            let ranges = &result.ranges[0];
+            assert_eq!(ranges.first(), Some(&selection_range), "{path}");
+            // The ranges of the script are followed by the ones of the elements that contain it, if any
+            assert!(ranges.contains(&element_range), "{path}: {ranges:?}");
+            for pair in ranges.windows(2) {
+                assert!(pair[1].contains_range(pair[0]), "{path}: {ranges:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn selects_the_ancestors_of_a_leading_comment() {
+        let workspace = create_server();
+
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "let a = 1;\n// one\nlet b = 2;\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_selection_ranges(GetSelectionRangesParams {
+                path: BiomePath::new("file.js"),
+                positions: vec![TextSize::from(14)],
+            })
+            .unwrap();
+        let ranges = &result.ranges[0];
+        assert_eq!(
+            ranges.first(),
+            Some(&TextRange::new(TextSize::from(11), TextSize::from(17)))
+        );
+        for pair in ranges.windows(2) {
+            assert!(pair[1].contains_range(pair[0]), "{ranges:?}");
+        }
+    }
+
+    #[test]
+    fn classifies_the_identifiers_with_the_semantic_model() {
+        let workspace = create_server();
+
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "import { x } from \"x\";\nconst a = 1;\nfunction f(p) {\n  let b = p;\n  console.log(a, b, x, y);\n}\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = workspace
+            .get_semantic_tokens(GetSemanticTokensParams {
+                path: BiomePath::new("file.js"),
+            })
+            .unwrap();
+
+        let token =
+            |start: u32, end: u32, kind, modifiers: &[SemanticTokenModifier]| SemanticToken {
+                range: TextRange::new(TextSize::from(start), TextSize::from(end)),
+                kind,
+                modifiers: modifiers.to_vec(),
+            };
+        use SemanticTokenKind::*;
+        use SemanticTokenModifier::*;
+        assert_eq!(
+            result.tokens,
+            vec![
+                token(9, 10, Variable, &[Declaration, Readonly, Imported]),
+                token(29, 30, Variable, &[Declaration, Readonly]),
+                token(45, 46, Function, &[Declaration]),
+                token(47, 48, Parameter, &[Declaration, Local]),
+                token(58, 59, Variable, &[Declaration, Local]),
+                token(62, 63, Parameter, &[Local]),
+                token(67, 74, Variable, &[Global]),
+                token(79, 80, Variable, &[Readonly]),
+                token(82, 83, Variable, &[Local]),
+                token(85, 86, Variable, &[Readonly, Imported]),
+                token(88, 89, Variable, &[Unresolved]),
+            ]
+        );
+    }
+
+    #[test]
+    fn renames_the_imports_of_an_exported_binding() {
+        let workspace = create_server();
+
+        let files = [
+            ("/project/a.js", "export function foo() {}\n"),
+            ("/project/b.js", "import { foo } from \"./a.js\";\nfoo();\n"),
+            ("/project/c.js", "import { foo as bar } from \"./a.js\";\n"),
+            ("/project/index.js", "export * from \"./a.js\";\n"),
+            ("/project/d.js", "export { foo } from \"./index.js\";\n"),
+        ];
+        let _files: Vec<_> = files
+            .into_iter()
+            .map(|(path, content)| {
+                workspace
+                    .update_module_graph(UpdateModuleGraphParams {
+                        path: BiomePath::new(path),
+                        content: content.into(),
+                    })
+                    .unwrap();
+                FileGuard::open(
+                    workspace.as_ref(),
+                    OpenFileParams {
+                        path: BiomePath::new(path),
+                        content: content.into(),
+                        version: 0,
+                        document_file_source: None,
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let result = workspace
+            .rename(RenameParams {
+                path: BiomePath::new("/project/a.js"),
+                symbol_at: TextSize::from(16),
+                new_name: "baz".to_string(),
+            })
+            .unwrap();
+
+        let edit = |path: &str, new_text: &str| ModuleRenameEdit {
+            path: BiomePath::new(path),
+            range: TextRange::new(TextSize::from(9), TextSize::from(12)),
+            new_text: new_text.to_string(),
+        };
+        assert_eq!(
+            result.module_edits,
+            vec![
+                edit("/project/b.js", "baz as foo"),
+                edit("/project/c.js", "baz"),
+                edit("/project/d.js", "baz as foo"),
+            ]
+        );
+    }
+
+    #[test]
+    fn refuses_to_rename_an_export_imported_as_a_namespace() {
+        let workspace = create_server();
+
+        let files = [
+            ("/project/a.js", "export function foo() {}\n"),
+            ("/project/b.js", "import { foo } from \"./a.js\";\nfoo();\n"),
+            ("/project/index.js", "export * from \"./a.js\";\n"),
+            (
+                "/project/c.js",
+                "import * as ns from \"./index.js\";\nns.foo();\n",
+            ),
+        ];
+        let _files: Vec<_> = files
+            .into_iter()
+            .map(|(path, content)| {
+                workspace
+                    .update_module_graph(UpdateModuleGraphParams {
+                        path: BiomePath::new(path),
+                        content: content.into(),
+                    })
+                    .unwrap();
+                FileGuard::open(
+                    workspace.as_ref(),
+                    OpenFileParams {
+                        path: BiomePath::new(path),
+                        content: content.into(),
+                        version: 0,
+                        document_file_source: None,
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let error = workspace
+            .rename(RenameParams {
+                path: BiomePath::new("/project/a.js"),
+                symbol_at: TextSize::from(16),
+                new_name: "baz".to_string(),
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the export \"foo\" can't be renamed because \"/project/c.js\" imports it through a namespace"
+        );
+    }
+
+    #[test]
+    fn uses_the_module_graph_only_when_a_rule_queries_it() {
+        let workspace = create_server();
+        let uses_module_graph = |path: &str, only: Vec<RuleSelector>| {
+            workspace
+                .uses_module_graph(UsesModuleGraphParams {
+                    path: BiomePath::new(path),
+                    only,
+                    skip: vec![],
+                })
+                .unwrap()
+        };
+
+        assert!(!uses_module_graph("file.js", vec![]));
+        assert!(uses_module_graph(
+            "file.js",
+            vec![RuleSelector::Rule(RuleGroup::Nursery, "noImportCycles")]
+        ));
+        assert!(!uses_module_graph(
+            "file.js",
+            vec![RuleSelector::Rule(RuleGroup::Suspicious, "noDebugger")]
+        ));
+        assert!(!uses_module_graph(
+            "file.json",
+            vec![RuleSelector::Rule(RuleGroup::Nursery, "noImportCycles")]
+        ));
+    }
+
+    #[test]
+    fn removes_the_unused_suppression_comments() {
+        const SOURCE: &str = "// biome-ignore lint/suspicious/noDebugger: not needed
+const a = 1;
+// biome-ignore lint/suspicious/noDebugger: needed
+debugger;
+const b = 2; // biome-ignore lint/style/useConst: not needed
+";
+
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = file
+            .fix_file(
+                FixFileMode::RemoveUnusedSuppressions,
+                false,
+                RuleCategories::all(),
+                vec![],
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(
+            result.code,
+            "const a = 1;
+// biome-ignore lint/suspicious/noDebugger: needed
+debugger;
+const b = 2;
+"
+        );
+        assert_eq!(result.actions.len(), 2);
+
+        // The other rules don't run, so their suppressions look unused
+        let result = file
+            .fix_file(
+                FixFileMode::RemoveUnusedSuppressions,
+                false,
+                RuleCategories::all(),
+                vec![RuleSelector::Rule(RuleGroup::Suspicious, "noDebugger")],
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(result.code, SOURCE);
+    }
+
+    fn apply_refactor(source: &str, range: TextRange, rule_name: &str) -> String {
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: source.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let result = file.pull_actions(Some(range), vec![], vec![]).unwrap();
+        let action = result
+            .actions
+            .into_iter()
+            .find(|action| {
+                action
+                    .rule_name
+                    .as_ref()
+                    .is_some_and(|(group, name)| group == "refactor" && name == rule_name)
+            })
+            .unwrap();
+        action.suggestion.suggestion.new_string(source)
+    }
+
+    #[test]
+    fn extracts_the_selected_expression_to_a_variable() {
+        const SOURCE: &str = "function log(price, quantity) {
+    console.log(price * quantity);
+}
+";
+        let start = SOURCE.find("price * quantity").unwrap();
+        let range = TextRange::at(
+            TextSize::from(start as u32),
+            TextSize::of("price * quantity"),
+        );
+
+        assert_eq!(
+            apply_refactor(SOURCE, range, "extractToVariable"),
+            "function log(price, quantity) {
+    const extracted = price * quantity;
+    console.log(extracted);
+}
+"
+        );
+    }
+
+    #[test]
+    fn inlines_the_selected_variable() {
+        const SOURCE: &str = "function log(price, quantity) {
+    const total = price * quantity;
+    console.log(total);
+}
+";
+        let start = SOURCE.find("total").unwrap();
+        let range = TextRange::at(TextSize::from(start as u32), TextSize::of("total"));
+
+        assert_eq!(
+            apply_refactor(SOURCE, range, "inlineVariable"),
+            "function log(price, quantity) {
+    console.log(price * quantity);
+}
+"
+        );
+    }
+
+    #[test]
+    fn offers_the_refactors_only_for_a_selection() {
+        const SOURCE: &str = "const greeting = \"Hello, \" + name;\n";
+
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let is_refactor = |action: &CodeAction| {
+            action
+                .rule_name
+                .as_ref()
+                .is_some_and(|(group, _)| group == "refactor")
+        };
+        let result = file.pull_actions(None, vec![], vec![]).unwrap();
+        assert!(!result.actions.iter().any(is_refactor));
+
+        let start = SOURCE.find("\"Hello").unwrap();
+        let range = TextRange::at(TextSize::from(start as u32), TextSize::of("\"Hello, \""));
+        let result = file.pull_actions(Some(range), vec![], vec![]).unwrap();
+        assert!(result.actions.iter().any(|action| {
+            action
+                .rule_name
+                .as_ref()
+                .is_some_and(|(_, name)| name == "convertToTemplateLiteral")
+        }));
+    }
+
+    #[test]
+    fn uses_the_settings_of_the_nearest_project() {
+        const SOURCE: &str = "function f() {\nreturn 1;\n}\n";
+
+        let workspace = server();
+        for (path, indent_width) in [("/project", 2), ("/project/packages/a", 8)] {
+            // The nested project doesn't replace the current project
+            workspace
+                .register_project_folder(RegisterProjectFolderParams {
+                    set_as_current_workspace: path == "/project",
+                    path: Some(PathBuf::from(path)),
+                })
+                .unwrap();
+            workspace
+                .update_settings(UpdateSettingsParams {
+                    configuration: PartialConfiguration {
+                        formatter: Some(PartialFormatterConfiguration {
+                            indent_style: Some(IndentStyle::Space),
+                            indent_width: Some(IndentWidth::try_from(indent_width).unwrap()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    vcs_base_path: None,
+                    gitignore_matches: vec![],
+                    workspace_directory: Some(PathBuf::from(path)),
+                    configuration_directory: None,
+                })
+                .unwrap();
+        }
+
+        for (path, indentation) in [
+            ("/project/src/file.js", "  "),
+            ("/project/packages/a/src/file.js", "        "),
+            ("/project/src/other.js", "  "),
+            ("/project/packages/a/file.js", "        "),
+        ] {
+            let file = FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: SOURCE.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap();
+            let printed = file.format_file().unwrap();
+            assert_eq!(
+                printed.as_code(),
+                format!("function f() {{\n{indentation}return 1;\n}}\n"),
+                "{path}"
+            );
+        }
+
+        // Registering a folder again doesn't create another project
+        let key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                set_as_current_workspace: false,
+                path: Some(PathBuf::from("/project")),
+            })
+            .unwrap();
+        let other_key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                set_as_current_workspace: false,
+                path: Some(PathBuf::from("/project")),
+            })
+            .unwrap();
+        assert_eq!(key, other_key);
+    }
+
+    #[test]
+    fn loads_the_plugins_relative_to_the_configuration_file() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/config/noRedColor.grit"),
+            "language css;\n\n`color: red`",
+        );
+        fs.insert(
+            PathBuf::from("/project/config/noNameField.grit"),
+            "language json;\n\n`\"name\": $name`",
+        );
+
+        let workspace = server_with_fs(Box::new(fs));
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                set_as_current_workspace: true,
+                path: Some(PathBuf::from("/project")),
+            })
+            .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: PartialConfiguration {
+                    plugins: Some(Plugins(vec![
+                        PluginConfiguration::Path("noRedColor.grit".to_string()),
+                        PluginConfiguration::Path("noNameField.grit".to_string()),
+                    ])),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                workspace_directory: Some(PathBuf::from("/project")),
+                configuration_directory: Some(PathBuf::from("/project/config")),
+            })
+            .unwrap();
+
+        for (path, content) in [
+            ("/project/file.css", "a { color: red; }"),
+            ("/project/file.json", r#"{ "name": "biome" }"#),
+        ] {
+            let file = FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    path: BiomePath::new(path),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap();
+            let diagnostics = file
+                .pull_diagnostics(RuleCategories::all(), 10, vec![], vec![], false)
+                .unwrap()
+                .diagnostics;
+            assert_eq!(diagnostics.len(), 1, "{path}");
+            assert_eq!(
+                diagnostics[0].category().map(|category| category.name()),
+                Some("plugin"),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn removes_the_plugins_missing_from_the_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/noRedColor.grit"),
+            "language css;\n\n`color: red`",
+        );
+
+        let workspace = server_with_fs(Box::new(fs));
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                set_as_current_workspace: true,
+                path: Some(PathBuf::from("/project")),
+            })
+            .unwrap();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("/project/file.css"),
+                content: "a { color: red; }".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        for (plugins, expected) in [
+            (
+                Some(Plugins(vec![PluginConfiguration::Path(
+                    "noRedColor.grit".to_string(),
+                )])),
+                1,
+            ),
+            (None, 0),
+        ] {
+            workspace
+                .update_settings(UpdateSettingsParams {
+                    configuration: PartialConfiguration {
+                        plugins,
+                        ..Default::default()
+                    },
+                    vcs_base_path: None,
+                    gitignore_matches: vec![],
+                    workspace_directory: Some(PathBuf::from("/project")),
+                    configuration_directory: Some(PathBuf::from("/project")),
+                })
+                .unwrap();
+
+            let diagnostics = file
+                .pull_diagnostics(RuleCategories::all(), 10, vec![], vec![], false)
+                .unwrap()
+                .diagnostics;
+            assert_eq!(diagnostics.len(), expected);
+        }
+    }
+
+    #[cfg(feature = "experimental-html")]
+    #[test]
+    fn formats_the_scripts_and_the_styles_of_html_files() {
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.html"),
+                content: "<div><script>const a   =  {b:1}</script><style>a{color:red}</style><script type=\"text/template\"><p> {{ a }} </p></script></div>\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let printed = file.format_file().unwrap();
+        assert_eq!(
+            printed.as_code(),
+            "<div>\n\t<script>\n\t\tconst a = { b: 1 };\n\t</script>\n\t<style>\n\t\ta {\n\t\t\tcolor: red;\n\t\t}\n\t</style>\n\t<script type=\"text/template\"><p> {{ a }} </p></script>\n</div>\n"
+        );
+    }
+
+    #[cfg(feature = "experimental-html")]
+    #[test]
+    fn lints_and_fixes_the_scripts_of_html_files() {
+        use biome_diagnostics::Diagnostic;
+
+        const SOURCE: &str = "<p>Text</p>\n<script>\n  debugger;\n</script>\n";
+
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.html"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let only = vec![RuleSelector::Rule(RuleGroup::Suspicious, "noDebugger")];
+        let diagnostics = file
+            .pull_diagnostics(RuleCategories::all(), 10, only.clone(), vec![], false)
+            .unwrap()
+            .diagnostics;
+        assert_eq!(diagnostics.len(), 1);
+        let start = SOURCE.find("debugger").unwrap() as u32;
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(
+                TextSize::from(start),
+                TextSize::of("debugger;")
+            ))
+        );
+
+        let actions = file
+            .pull_actions(None, only.clone(), vec![])
+            .unwrap()
+            .actions;
+        let action = actions
+            .iter()
+            .find(|action| action.rule_name.is_some())
+            .expect("a code action for the rule");
+        assert_eq!(
+            action.suggestion.suggestion.new_string(SOURCE),
+            "<p>Text</p>\n<script>\n</script>\n"
+        );
+
+        let result = file
+            .fix_file(
+                FixFileMode::SafeAndUnsafeFixes,
+                false,
+                RuleCategories::all(),
+                only,
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(result.code, "<p>Text</p>\n<script>\n</script>\n");
+        assert_eq!(result.actions.len(), 1);
+    }
+
+    #[test]
+    fn lints_the_templates_and_the_styles_of_vue_files() {
+        use biome_diagnostics::Diagnostic;
+
+        const SOURCE: &str = r#"<style>
+b { widht: 1px; }
+</style>
+<template>
+  <Child :count="count" @click="select" />
+</template>
+<script>
+import Shared from "./Shared.vue";
+</script>
+<script setup>
+import Child from "./Child.vue";
+import Unused from "./Unused.vue";
+const count = 1;
+const unused = 2;
+function select() {
+    return Shared;
+}
+</script>
+<style>
+a { colr: red; }
+</style>
+"#;
+
+        let workspace = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.vue"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let only = vec![
+            RuleSelector::Rule(RuleGroup::Correctness, "noUnusedImports"),
+            RuleSelector::Rule(RuleGroup::Correctness, "noUnusedVariables"),
+            RuleSelector::Rule(RuleGroup::Correctness, "noUnknownProperty"),
+        ];
+        let diagnostics = file
+            .pull_diagnostics(RuleCategories::all(), 10, only, vec![], false)
+            .unwrap()
+            .diagnostics;
+
+        // The diagnostics are relative to the start of the file
+        let mut spans: Vec<_> = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.location().span)
+            .map(|span| usize::from(span.start()))
+            .collect();
+        spans.sort_unstable();
+        assert_eq!(
+            spans,
+            [
+                SOURCE.find("widht").unwrap(),
+                SOURCE.find("Unused").unwrap(),
+                SOURCE.find("unused").unwrap(),
+                SOURCE.find("colr").unwrap(),
+            ]
+        );
+    }
 }
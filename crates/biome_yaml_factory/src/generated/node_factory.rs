@@ -7,6 +7,18 @@ use biome_yaml_syntax::{
     YamlSyntaxElement as SyntaxElement, YamlSyntaxNode as SyntaxNode,
     YamlSyntaxToken as SyntaxToken, *,
 };
+pub fn yaml_alias(value_token: SyntaxToken) -> YamlAlias {
+    YamlAlias::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_ALIAS,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_anchor(value_token: SyntaxToken) -> YamlAnchor {
+    YamlAnchor::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_ANCHOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn yaml_array(items: YamlArrayItemList) -> YamlArray {
     YamlArray::unwrap_cast(SyntaxNode::new_detached(
         YamlSyntaxKind::YAML_ARRAY,
@@ -27,35 +39,83 @@ pub fn yaml_array_inline(
         ],
     ))
 }
-pub fn yaml_array_item(minus_token: SyntaxToken, item: AnyYamlValue) -> YamlArrayItem {
-    YamlArrayItem::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_ARRAY_ITEM,
-        [
-            Some(SyntaxElement::Token(minus_token)),
-            Some(SyntaxElement::Node(item.into_syntax())),
-        ],
-    ))
+pub fn yaml_array_item(minus_token: SyntaxToken) -> YamlArrayItemBuilder {
+    YamlArrayItemBuilder {
+        minus_token,
+        item: None,
+    }
 }
-pub fn yaml_block_folded(r_angle_token: SyntaxToken, value: YamlBlockValue) -> YamlBlockFolded {
-    YamlBlockFolded::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_BLOCK_FOLDED,
-        [
-            Some(SyntaxElement::Token(r_angle_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
-        ],
-    ))
+pub struct YamlArrayItemBuilder {
+    minus_token: SyntaxToken,
+    item: Option<AnyYamlValue>,
+}
+impl YamlArrayItemBuilder {
+    pub fn with_item(mut self, item: AnyYamlValue) -> Self {
+        self.item = Some(item);
+        self
+    }
+    pub fn build(self) -> YamlArrayItem {
+        YamlArrayItem::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_ARRAY_ITEM,
+            [
+                Some(SyntaxElement::Token(self.minus_token)),
+                self.item
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_block_folded(r_angle_token: SyntaxToken) -> YamlBlockFoldedBuilder {
+    YamlBlockFoldedBuilder {
+        r_angle_token,
+        value: None,
+    }
+}
+pub struct YamlBlockFoldedBuilder {
+    r_angle_token: SyntaxToken,
+    value: Option<YamlBlockValue>,
+}
+impl YamlBlockFoldedBuilder {
+    pub fn with_value(mut self, value: YamlBlockValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlBlockFolded {
+        YamlBlockFolded::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_FOLDED,
+            [
+                Some(SyntaxElement::Token(self.r_angle_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_block_literal(bitwise_or_token: SyntaxToken) -> YamlBlockLiteralBuilder {
+    YamlBlockLiteralBuilder {
+        bitwise_or_token,
+        value: None,
+    }
 }
-pub fn yaml_block_literal(
+pub struct YamlBlockLiteralBuilder {
     bitwise_or_token: SyntaxToken,
-    value: YamlBlockValue,
-) -> YamlBlockLiteral {
-    YamlBlockLiteral::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_BLOCK_LITERAL,
-        [
-            Some(SyntaxElement::Token(bitwise_or_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
-        ],
-    ))
+    value: Option<YamlBlockValue>,
+}
+impl YamlBlockLiteralBuilder {
+    pub fn with_value(mut self, value: YamlBlockValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlBlockLiteral {
+        YamlBlockLiteral::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_LITERAL,
+            [
+                Some(SyntaxElement::Token(self.bitwise_or_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
 }
 pub fn yaml_block_value(yaml_block_value_token: SyntaxToken) -> YamlBlockValue {
     YamlBlockValue::unwrap_cast(SyntaxNode::new_detached(
@@ -69,16 +129,24 @@ pub fn yaml_boolean_value(value_token: SyntaxToken) -> YamlBooleanValue {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn yaml_document(body: AnyYamlValue) -> YamlDocumentBuilder {
+pub fn yaml_directive(value_token: SyntaxToken) -> YamlDirective {
+    YamlDirective::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_DIRECTIVE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_document(directives: YamlDirectiveList) -> YamlDocumentBuilder {
     YamlDocumentBuilder {
-        body,
+        directives,
         dashdashdash_token: None,
+        body: None,
         dotdotdot_token: None,
     }
 }
 pub struct YamlDocumentBuilder {
-    body: AnyYamlValue,
+    directives: YamlDirectiveList,
     dashdashdash_token: Option<SyntaxToken>,
+    body: Option<AnyYamlValue>,
     dotdotdot_token: Option<SyntaxToken>,
 }
 impl YamlDocumentBuilder {
@@ -86,6 +154,10 @@ impl YamlDocumentBuilder {
         self.dashdashdash_token = Some(dashdashdash_token);
         self
     }
+    pub fn with_body(mut self, body: AnyYamlValue) -> Self {
+        self.body = Some(body);
+        self
+    }
     pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
         self.dotdotdot_token = Some(dotdotdot_token);
         self
@@ -94,15 +166,60 @@ impl YamlDocumentBuilder {
         YamlDocument::unwrap_cast(SyntaxNode::new_detached(
             YamlSyntaxKind::YAML_DOCUMENT,
             [
+                Some(SyntaxElement::Node(self.directives.into_syntax())),
                 self.dashdashdash_token
                     .map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.body.into_syntax())),
+                self.body
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
                 self.dotdotdot_token
                     .map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
 }
+pub fn yaml_explicit_object_member(
+    question_mark_token: SyntaxToken,
+) -> YamlExplicitObjectMemberBuilder {
+    YamlExplicitObjectMemberBuilder {
+        question_mark_token,
+        key: None,
+        colon_token: None,
+        value: None,
+    }
+}
+pub struct YamlExplicitObjectMemberBuilder {
+    question_mark_token: SyntaxToken,
+    key: Option<AnyYamlValue>,
+    colon_token: Option<SyntaxToken>,
+    value: Option<AnyYamlValue>,
+}
+impl YamlExplicitObjectMemberBuilder {
+    pub fn with_key(mut self, key: AnyYamlValue) -> Self {
+        self.key = Some(key);
+        self
+    }
+    pub fn with_colon_token(mut self, colon_token: SyntaxToken) -> Self {
+        self.colon_token = Some(colon_token);
+        self
+    }
+    pub fn with_value(mut self, value: AnyYamlValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlExplicitObjectMember {
+        YamlExplicitObjectMember::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_EXPLICIT_OBJECT_MEMBER,
+            [
+                Some(SyntaxElement::Token(self.question_mark_token)),
+                self.key
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.colon_token.map(|token| SyntaxElement::Token(token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn yaml_identifier(value_token: SyntaxToken) -> YamlIdentifier {
     YamlIdentifier::unwrap_cast(SyntaxNode::new_detached(
         YamlSyntaxKind::YAML_IDENTIFIER,
@@ -127,20 +244,52 @@ pub fn yaml_object(members: YamlObjectMemberList) -> YamlObject {
         [Some(SyntaxElement::Node(members.into_syntax()))],
     ))
 }
-pub fn yaml_object_member(
-    key: YamlIdentifier,
-    colon_token: SyntaxToken,
-    value: AnyYamlValue,
-) -> YamlObjectMember {
-    YamlObjectMember::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_OBJECT_MEMBER,
+pub fn yaml_object_inline(
+    l_curly_token: SyntaxToken,
+    members: YamlObjectInlineMemberList,
+    r_curly_token: SyntaxToken,
+) -> YamlObjectInline {
+    YamlObjectInline::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_OBJECT_INLINE,
         [
-            Some(SyntaxElement::Node(key.into_syntax())),
-            Some(SyntaxElement::Token(colon_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
         ],
     ))
 }
+pub fn yaml_object_member(
+    key: YamlIdentifier,
+    colon_token: SyntaxToken,
+) -> YamlObjectMemberBuilder {
+    YamlObjectMemberBuilder {
+        key,
+        colon_token,
+        value: None,
+    }
+}
+pub struct YamlObjectMemberBuilder {
+    key: YamlIdentifier,
+    colon_token: SyntaxToken,
+    value: Option<AnyYamlValue>,
+}
+impl YamlObjectMemberBuilder {
+    pub fn with_value(mut self, value: AnyYamlValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlObjectMember {
+        YamlObjectMember::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_OBJECT_MEMBER,
+            [
+                Some(SyntaxElement::Node(self.key.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn yaml_root(documents: YamlDocumentList, eof_token: SyntaxToken) -> YamlRootBuilder {
     YamlRootBuilder {
         documents,
@@ -175,9 +324,41 @@ pub fn yaml_string_value(value_token: SyntaxToken) -> YamlStringValue {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn yaml_tag(value_token: SyntaxToken) -> YamlTag {
+    YamlTag::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_TAG,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_value_with_properties(properties: YamlPropertyList) -> YamlValueWithPropertiesBuilder {
+    YamlValueWithPropertiesBuilder {
+        properties,
+        value: None,
+    }
+}
+pub struct YamlValueWithPropertiesBuilder {
+    properties: YamlPropertyList,
+    value: Option<AnyYamlValue>,
+}
+impl YamlValueWithPropertiesBuilder {
+    pub fn with_value(mut self, value: AnyYamlValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlValueWithProperties {
+        YamlValueWithProperties::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_VALUE_WITH_PROPERTIES,
+            [
+                Some(SyntaxElement::Node(self.properties.into_syntax())),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn yaml_array_inline_list<I, S>(items: I, separators: S) -> YamlArrayInlineList
 where
-    I: IntoIterator<Item = AnyYamlScalar>,
+    I: IntoIterator<Item = AnyYamlValue>,
     I::IntoIter: ExactSizeIterator,
     S: IntoIterator<Item = YamlSyntaxToken>,
     S::IntoIter: ExactSizeIterator,
@@ -208,9 +389,21 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn yaml_directive_list<I>(items: I) -> YamlDirectiveList
+where
+    I: IntoIterator<Item = YamlDirective>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlDirectiveList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_DIRECTIVE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn yaml_document_list<I>(items: I) -> YamlDocumentList
 where
-    I: IntoIterator<Item = YamlDocument>,
+    I: IntoIterator<Item = AnyYamlDocument>,
     I::IntoIter: ExactSizeIterator,
 {
     YamlDocumentList::unwrap_cast(SyntaxNode::new_detached(
@@ -220,9 +413,30 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn yaml_object_inline_member_list<I, S>(items: I, separators: S) -> YamlObjectInlineMemberList
+where
+    I: IntoIterator<Item = AnyYamlObjectMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = YamlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    YamlObjectInlineMemberList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_OBJECT_INLINE_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
 pub fn yaml_object_member_list<I>(items: I) -> YamlObjectMemberList
 where
-    I: IntoIterator<Item = YamlObjectMember>,
+    I: IntoIterator<Item = AnyYamlObjectMember>,
     I::IntoIter: ExactSizeIterator,
 {
     YamlObjectMemberList::unwrap_cast(SyntaxNode::new_detached(
@@ -232,6 +446,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn yaml_property_list<I>(items: I) -> YamlPropertyList
+where
+    I: IntoIterator<Item = AnyYamlProperty>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlPropertyList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_PROPERTY_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn yaml_bogus<I>(slots: I) -> YamlBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
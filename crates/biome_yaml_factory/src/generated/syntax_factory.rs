@@ -17,6 +17,44 @@ impl SyntaxFactory for YamlSyntaxFactory {
             YAML_BOGUS | YAML_BOGUS_VALUE => {
                 RawSyntaxNode::new(kind, children.into_iter().map(Some))
             }
+            YAML_ALIAS => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == YAML_ALIAS {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_ALIAS.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_ALIAS, children)
+            }
+            YAML_ANCHOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == YAML_ANCHOR {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_ANCHOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_ANCHOR, children)
+            }
             YAML_ARRAY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -185,10 +223,36 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 }
                 slots.into_node(YAML_BOOLEAN_VALUE, children)
             }
+            YAML_DIRECTIVE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == YAML_DIRECTIVE {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_DIRECTIVE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_DIRECTIVE, children)
+            }
             YAML_DOCUMENT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if YamlDirectiveList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if element.kind() == T ! [---] {
                         slots.mark_present();
@@ -218,6 +282,46 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 }
                 slots.into_node(YAML_DOCUMENT, children)
             }
+            YAML_EXPLICIT_OBJECT_MEMBER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [?] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyYamlValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [:] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyYamlValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_EXPLICIT_OBJECT_MEMBER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_EXPLICIT_OBJECT_MEMBER, children)
+            }
             YAML_IDENTIFIER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -294,6 +398,39 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 }
                 slots.into_node(YAML_OBJECT, children)
             }
+            YAML_OBJECT_INLINE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['{'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if YamlObjectInlineMemberList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_OBJECT_INLINE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_OBJECT_INLINE, children)
+            }
             YAML_OBJECT_MEMBER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
@@ -379,21 +516,76 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 }
                 slots.into_node(YAML_STRING_VALUE, children)
             }
+            YAML_TAG => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == YAML_TAG {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(YAML_TAG.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(YAML_TAG, children)
+            }
+            YAML_VALUE_WITH_PROPERTIES => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if YamlPropertyList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyYamlValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_VALUE_WITH_PROPERTIES.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_VALUE_WITH_PROPERTIES, children)
+            }
             YAML_ARRAY_INLINE_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
-                AnyYamlScalar::can_cast,
+                AnyYamlValue::can_cast,
                 T ! [,],
                 true,
             ),
             YAML_ARRAY_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, YamlArrayItem::can_cast)
             }
+            YAML_DIRECTIVE_LIST => {
+                Self::make_node_list_syntax(kind, children, YamlDirective::can_cast)
+            }
             YAML_DOCUMENT_LIST => {
-                Self::make_node_list_syntax(kind, children, YamlDocument::can_cast)
+                Self::make_node_list_syntax(kind, children, AnyYamlDocument::can_cast)
             }
+            YAML_OBJECT_INLINE_MEMBER_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                AnyYamlObjectMember::can_cast,
+                T ! [,],
+                true,
+            ),
             YAML_OBJECT_MEMBER_LIST => {
-                Self::make_node_list_syntax(kind, children, YamlObjectMember::can_cast)
+                Self::make_node_list_syntax(kind, children, AnyYamlObjectMember::can_cast)
+            }
+            YAML_PROPERTY_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyYamlProperty::can_cast)
             }
             _ => unreachable!("Is {:?} a token?", kind),
         }
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use biome_diagnostics_categories::category;
+use biome_formatter::comments::{CommentKind, CommentStyle, Comments, SourceComment};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+use biome_yaml_syntax::YamlLanguage;
+
+pub type YamlComments = Comments<YamlLanguage>;
+
+#[derive(Default)]
+pub struct FormatYamlLeadingComment;
+
+impl FormatRule<SourceComment<YamlLanguage>> for FormatYamlLeadingComment {
+    type Context = YamlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<YamlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct YamlCommentStyle;
+
+impl CommentStyle for YamlCommentStyle {
+    type Language = YamlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, _)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+}
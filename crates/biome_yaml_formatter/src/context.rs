@@ -0,0 +1,157 @@
+use crate::YamlCommentStyle;
+use biome_formatter::{prelude::*, AttributePosition, BracketSpacing, IndentWidth};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+
+use crate::comments::{FormatYamlLeadingComment, YamlComments};
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct YamlFormatContext {
+    options: YamlFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<YamlComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl YamlFormatContext {
+    pub fn new(options: YamlFormatOptions, comments: YamlComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for YamlFormatContext {
+    type Options = YamlFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for YamlFormatContext {
+    type Language = YamlLanguage;
+    type Style = YamlCommentStyle;
+    type CommentRule = FormatYamlLeadingComment;
+
+    fn comments(&self) -> &YamlComments {
+        &self.comments
+    }
+}
+
+/// The options of the YAML formatter.
+///
+/// There's no indent style option, because YAML only allows spaces for indentation.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct YamlFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    bracket_spacing: BracketSpacing,
+    _file_source: YamlFileSource,
+}
+
+impl YamlFormatOptions {
+    pub fn new(file_source: YamlFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            indent_width: IndentWidth::default(),
+            line_ending: LineEnding::default(),
+            line_width: LineWidth::default(),
+            bracket_spacing: BracketSpacing::default(),
+        }
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_bracket_spacing(mut self, bracket_spacing: BracketSpacing) -> Self {
+        self.bracket_spacing = bracket_spacing;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_bracket_spacing(&mut self, bracket_spacing: BracketSpacing) {
+        self.bracket_spacing = bracket_spacing;
+    }
+}
+
+impl FormatOptions for YamlFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        // Tabs aren't allowed for indentation in YAML
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn attribute_position(&self) -> AttributePosition {
+        AttributePosition::default()
+    }
+
+    fn bracket_spacing(&self) -> BracketSpacing {
+        self.bracket_spacing
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for YamlFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style())?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())
+    }
+}
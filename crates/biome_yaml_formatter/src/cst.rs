@@ -0,0 +1,31 @@
+use biome_formatter::{Format, FormatOwnedWithRule, FormatRefWithRule, FormatResult};
+
+use crate::{AsFormat, IntoFormat, YamlFormatContext, YamlFormatter};
+use biome_yaml_syntax::{map_syntax_node, YamlSyntaxNode};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FormatYamlSyntaxNode;
+
+impl biome_formatter::FormatRule<YamlSyntaxNode> for FormatYamlSyntaxNode {
+    type Context = YamlFormatContext;
+
+    fn fmt(&self, node: &YamlSyntaxNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        map_syntax_node!(node.clone(), node => node.format().fmt(f))
+    }
+}
+
+impl AsFormat<YamlFormatContext> for YamlSyntaxNode {
+    type Format<'a> = FormatRefWithRule<'a, YamlSyntaxNode, FormatYamlSyntaxNode>;
+
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, FormatYamlSyntaxNode)
+    }
+}
+
+impl IntoFormat<YamlFormatContext> for YamlSyntaxNode {
+    type Format = FormatOwnedWithRule<YamlSyntaxNode, FormatYamlSyntaxNode>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatYamlSyntaxNode)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_rowan::TextSize;
+use biome_yaml_syntax::{AnyYamlValue, YamlSyntaxKind, YamlSyntaxNode};
+
+/// Returns `true` if the value is a block mapping or a block sequence,
+/// which must start on a new line when they are the value of a mapping entry.
+pub(crate) fn is_block_collection(value: &AnyYamlValue) -> bool {
+    matches!(
+        value,
+        AnyYamlValue::YamlObject(_) | AnyYamlValue::YamlArray(_)
+    )
+}
+
+/// Returns `true` if the node is the entry of a block sequence, possibly preceded by an anchor or a tag.
+///
+/// The content of the entries of block sequences is already aligned after the `- ` indicator,
+/// so the continuation lines of their scalars don't need to be indented further.
+pub(crate) fn is_sequence_entry(node: &YamlSyntaxNode) -> bool {
+    let mut parent = node.parent();
+
+    while let Some(node) = parent {
+        match node.kind() {
+            YamlSyntaxKind::YAML_VALUE_WITH_PROPERTIES => parent = node.parent(),
+            YamlSyntaxKind::YAML_ARRAY_ITEM => return true,
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+/// A line of a multiline scalar, with the number of empty lines that follow it.
+struct ScalarLine<'a> {
+    text: &'a str,
+    source_position: TextSize,
+    empty_lines_after: usize,
+}
+
+/// Formats the lines of a multiline scalar on separate lines, and indents them if `should_indent` is `true`.
+///
+/// The empty lines between the lines are significant, but consecutive hard line breaks collapse
+/// into a single one. For this reason, the line feeds of the empty lines are printed at the end
+/// of the preceding line, and the hard line break of the next line only adds the indentation.
+pub(crate) struct FormatScalarLines<'a> {
+    /// The first line, which is printed on the current line
+    first: &'a str,
+    first_position: TextSize,
+    empty_lines_after_first: usize,
+    lines: Vec<ScalarLine<'a>>,
+    should_indent: bool,
+}
+
+impl<'a> FormatScalarLines<'a> {
+    /// Creates the formatter of the lines of `text`, which starts at `position` in the source text.
+    ///
+    /// The first line is printed as is, and `strip_indentation` returns the content of the
+    /// other lines without their indentation. Lines without content are considered empty.
+    pub(crate) fn new(
+        text: &'a str,
+        position: TextSize,
+        strip_indentation: impl Fn(&'a str) -> &'a str,
+    ) -> Self {
+        let mut source_position = position;
+        let mut lines = text.split('\n').map(|line| {
+            let start = source_position;
+            source_position += TextSize::of(line) + TextSize::from(1);
+            (line.strip_suffix('\r').unwrap_or(line), start)
+        });
+
+        let (first, first_position) = lines.next().unwrap_or(("", position));
+        let mut result = Self {
+            first,
+            first_position,
+            empty_lines_after_first: 0,
+            lines: Vec::new(),
+            should_indent: true,
+        };
+
+        for (line, start) in lines {
+            let content = strip_indentation(line);
+            if content.is_empty() {
+                match result.lines.last_mut() {
+                    Some(last) => last.empty_lines_after += 1,
+                    None => result.empty_lines_after_first += 1,
+                }
+            } else {
+                let trimmed = TextSize::of(line) - TextSize::of(content);
+                result.lines.push(ScalarLine {
+                    text: content,
+                    source_position: start + trimmed,
+                    empty_lines_after: 0,
+                });
+            }
+        }
+
+        result
+    }
+
+    pub(crate) fn with_indent(mut self, should_indent: bool) -> Self {
+        self.should_indent = should_indent;
+        self
+    }
+}
+
+impl Format<YamlFormatContext> for FormatScalarLines<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let first = with_empty_lines(self.first, self.empty_lines_after_first);
+        if !first.is_empty() {
+            write!(f, [dynamic_text(&first, self.first_position)])?;
+        }
+
+        let lines = format_with(|f| {
+            for line in &self.lines {
+                let text = with_empty_lines(line.text, line.empty_lines_after);
+                write!(
+                    f,
+                    [hard_line_break(), dynamic_text(&text, line.source_position)]
+                )?;
+            }
+
+            Ok(())
+        });
+
+        if self.should_indent {
+            write!(f, [indent(&lines)])
+        } else {
+            write!(f, [lines])
+        }
+    }
+}
+
+/// Appends the line feeds of `count` empty lines to `text`. The first line feed ends the
+/// line of `text`, so that the following hard line break doesn't print a line feed anymore.
+fn with_empty_lines(text: &str, count: usize) -> String {
+    if count == 0 {
+        text.to_string()
+    } else {
+        std::format!("{text}{}", "\n".repeat(count + 1))
+    }
+}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod document;
+pub(crate) mod object_member;
+pub(crate) mod property;
+pub(crate) mod scalar;
+pub(crate) mod value;
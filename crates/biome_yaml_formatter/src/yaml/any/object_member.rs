@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlObjectMember;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlObjectMember;
+impl FormatRule<AnyYamlObjectMember> for FormatAnyYamlObjectMember {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlObjectMember, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlObjectMember::YamlExplicitObjectMember(node) => node.format().fmt(f),
+            AnyYamlObjectMember::YamlObjectMember(node) => node.format().fmt(f),
+        }
+    }
+}
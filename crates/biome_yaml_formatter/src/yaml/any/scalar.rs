@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlScalar;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlScalar;
+impl FormatRule<AnyYamlScalar> for FormatAnyYamlScalar {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlScalar::YamlBooleanValue(node) => node.format().fmt(f),
+            AnyYamlScalar::YamlNullValue(node) => node.format().fmt(f),
+            AnyYamlScalar::YamlNumberValue(node) => node.format().fmt(f),
+            AnyYamlScalar::YamlStringValue(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlValue;
+impl FormatRule<AnyYamlValue> for FormatAnyYamlValue {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlValue::AnyYamlScalar(node) => node.format().fmt(f),
+            AnyYamlValue::YamlAlias(node) => node.format().fmt(f),
+            AnyYamlValue::YamlArray(node) => node.format().fmt(f),
+            AnyYamlValue::YamlArrayInline(node) => node.format().fmt(f),
+            AnyYamlValue::YamlBlockFolded(node) => node.format().fmt(f),
+            AnyYamlValue::YamlBlockLiteral(node) => node.format().fmt(f),
+            AnyYamlValue::YamlBogusValue(node) => node.format().fmt(f),
+            AnyYamlValue::YamlObject(node) => node.format().fmt(f),
+            AnyYamlValue::YamlObjectInline(node) => node.format().fmt(f),
+            AnyYamlValue::YamlValueWithProperties(node) => node.format().fmt(f),
+        }
+    }
+}
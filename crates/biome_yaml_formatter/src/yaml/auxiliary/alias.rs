@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlAlias, YamlAliasFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlAlias;
+impl FormatNodeRule<YamlAlias> for FormatYamlAlias {
+    fn fmt_fields(&self, node: &YamlAlias, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlAliasFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
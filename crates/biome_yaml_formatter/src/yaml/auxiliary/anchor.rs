@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlAnchor, YamlAnchorFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlAnchor;
+impl FormatNodeRule<YamlAnchor> for FormatYamlAnchor {
+    fn fmt_fields(&self, node: &YamlAnchor, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlAnchorFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
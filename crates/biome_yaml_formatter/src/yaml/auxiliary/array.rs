@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlArray, YamlArrayFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlArray;
+impl FormatNodeRule<YamlArray> for FormatYamlArray {
+    fn fmt_fields(&self, node: &YamlArray, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlArrayFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
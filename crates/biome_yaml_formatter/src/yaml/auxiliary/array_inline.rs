@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{YamlArrayInline, YamlArrayInlineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlArrayInline;
+impl FormatNodeRule<YamlArrayInline> for FormatYamlArrayInline {
+    fn fmt_fields(&self, node: &YamlArrayInline, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlArrayInlineFields {
+            l_brack_token,
+            items,
+            r_brack_token,
+        } = node.as_fields();
+
+        let should_expand = f.comments().has_dangling_comments(node.syntax());
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                group(&soft_block_indent(&format_args![
+                    items.format(),
+                    format_dangling_comments(node.syntax())
+                ]))
+                .should_expand(should_expand),
+                line_suffix_boundary(),
+                r_brack_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlArrayInline,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Handled as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlArrayItem, YamlArrayItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlArrayItem;
+impl FormatNodeRule<YamlArrayItem> for FormatYamlArrayItem {
+    fn fmt_fields(&self, node: &YamlArrayItem, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlArrayItemFields { minus_token, item } = node.as_fields();
+
+        write!(f, [minus_token.format()])?;
+
+        if let Some(item) = item {
+            // The content of the entry is aligned after the `- ` indicator
+            write!(f, [space(), align(2, &format_or_verbatim(item.format()))])?;
+        }
+
+        Ok(())
+    }
+}
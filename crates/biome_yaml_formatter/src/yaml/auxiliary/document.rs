@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use crate::utils::is_block_collection;
+use biome_formatter::write;
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{YamlDocument, YamlDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            directives,
+            dashdashdash_token,
+            body,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        if !directives.is_empty() {
+            write!(f, [directives.format(), hard_line_break()])?;
+        }
+
+        if let Some(dashdashdash_token) = dashdashdash_token {
+            write!(f, [dashdashdash_token.format()])?;
+
+            if let Some(body) = &body {
+                // Block collections can't start on the line of the document marker,
+                // other values stay where they are
+                if is_block_collection(body) || body.syntax().has_leading_newline() {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [space()])?;
+                }
+            }
+        }
+
+        write!(f, [body.format()])?;
+
+        if let Some(dotdotdot_token) = dotdotdot_token {
+            write!(f, [hard_line_break(), dotdotdot_token.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlExplicitObjectMember, YamlExplicitObjectMemberFields, YamlSyntaxKind};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlExplicitObjectMember;
+impl FormatNodeRule<YamlExplicitObjectMember> for FormatYamlExplicitObjectMember {
+    fn fmt_fields(
+        &self,
+        node: &YamlExplicitObjectMember,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlExplicitObjectMemberFields {
+            question_mark_token,
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        let is_in_flow_mapping = node
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == YamlSyntaxKind::YAML_OBJECT_INLINE_MEMBER_LIST);
+
+        write!(f, [question_mark_token.format()])?;
+
+        if let Some(key) = key {
+            // Like the entries of block sequences, the key is aligned after the `? ` indicator
+            write!(f, [space(), align(2, &format_or_verbatim(key.format()))])?;
+        }
+
+        if let Some(colon_token) = colon_token {
+            // In block mappings, the `:` indicator must start its own line, at the column of the `?`
+            if is_in_flow_mapping {
+                write!(f, [space()])?;
+            } else {
+                write!(f, [hard_line_break()])?;
+            }
+
+            write!(f, [colon_token.format()])?;
+
+            if let Some(value) = value {
+                write!(f, [space(), align(2, &format_or_verbatim(value.format()))])?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlIdentifier, YamlIdentifierFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlIdentifier;
+impl FormatNodeRule<YamlIdentifier> for FormatYamlIdentifier {
+    fn fmt_fields(&self, node: &YamlIdentifier, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlIdentifierFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use biome_formatter::{write, FormatOptions};
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{YamlObjectInline, YamlObjectInlineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectInline;
+impl FormatNodeRule<YamlObjectInline> for FormatYamlObjectInline {
+    fn fmt_fields(&self, node: &YamlObjectInline, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlObjectInlineFields {
+            l_curly_token,
+            members,
+            r_curly_token,
+        } = node.as_fields();
+
+        let has_dangling_comments = f.comments().has_dangling_comments(node.syntax());
+
+        if members.is_empty() && !has_dangling_comments {
+            return write!(f, [l_curly_token.format(), r_curly_token.format()]);
+        }
+
+        let should_expand = members.syntax().has_leading_newline() || has_dangling_comments;
+        let content = format_with(|f| {
+            write!(
+                f,
+                [members.format(), format_dangling_comments(node.syntax())]
+            )
+        });
+
+        write!(f, [l_curly_token.format()])?;
+
+        if f.options().bracket_spacing().value() {
+            write!(
+                f,
+                [group(&soft_space_or_block_indent(&content)).should_expand(should_expand)]
+            )?;
+        } else {
+            write!(
+                f,
+                [group(&soft_block_indent(&content)).should_expand(should_expand)]
+            )?;
+        }
+
+        write!(f, [line_suffix_boundary(), r_curly_token.format()])
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlObjectInline,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Handled as part of `fmt_fields`
+        Ok(())
+    }
+}
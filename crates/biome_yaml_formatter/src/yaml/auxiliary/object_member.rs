@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::is_block_collection;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{YamlObjectMember, YamlObjectMemberFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectMember;
+impl FormatNodeRule<YamlObjectMember> for FormatYamlObjectMember {
+    fn fmt_fields(&self, node: &YamlObjectMember, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlObjectMemberFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        match value {
+            // Block collections always start on a new line, indented under their key
+            Some(value) if is_block_collection(&value) => write!(
+                f,
+                [indent(&format_args![
+                    hard_line_break(),
+                    format_or_verbatim(value.format())
+                ])]
+            ),
+            Some(value) => write!(f, [space(), format_or_verbatim(value.format())]),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlRoot, YamlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlRoot;
+impl FormatNodeRule<YamlRoot> for FormatYamlRoot {
+    fn fmt_fields(&self, node: &YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlRootFields {
+            bom_token,
+            documents,
+            eof_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), documents.format()])?;
+
+        // An empty file stays empty
+        if !documents.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        write!(f, [format_removed(&eof_token?)])
+    }
+}
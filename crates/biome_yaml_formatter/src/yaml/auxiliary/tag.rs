@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlTag, YamlTagFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlTag;
+impl FormatNodeRule<YamlTag> for FormatYamlTag {
+    fn fmt_fields(&self, node: &YamlTag, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlTagFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
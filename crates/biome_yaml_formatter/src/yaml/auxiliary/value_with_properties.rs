@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use crate::utils::is_block_collection;
+use biome_formatter::{format_args, write};
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{YamlSyntaxKind, YamlValueWithProperties, YamlValueWithPropertiesFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlValueWithProperties;
+impl FormatNodeRule<YamlValueWithProperties> for FormatYamlValueWithProperties {
+    fn fmt_fields(
+        &self,
+        node: &YamlValueWithProperties,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlValueWithPropertiesFields { properties, value } = node.as_fields();
+
+        write!(f, [properties.format()])?;
+
+        let Some(value) = value else {
+            return Ok(());
+        };
+
+        // The properties of a block collection that starts on the same line,
+        // such as `&anchor - item`, belong to its first entry, so its layout is kept.
+        if is_block_collection(&value) && value.syntax().has_leading_newline() {
+            let is_mapping_value = node
+                .syntax()
+                .parent()
+                .is_some_and(|parent| parent.kind() == YamlSyntaxKind::YAML_OBJECT_MEMBER);
+
+            if is_mapping_value {
+                write!(
+                    f,
+                    [indent(&format_args![hard_line_break(), value.format()])]
+                )
+            } else {
+                write!(f, [hard_line_break(), value.format()])
+            }
+        } else {
+            write!(f, [space(), value.format()])
+        }
+    }
+}
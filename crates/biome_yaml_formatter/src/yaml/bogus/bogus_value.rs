@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use biome_yaml_syntax::YamlBogusValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBogusValue;
+impl FormatBogusNodeRule<YamlBogusValue> for FormatYamlBogusValue {}
@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array_inline_list;
+pub(crate) mod array_item_list;
+pub(crate) mod directive_list;
+pub(crate) mod document_list;
+pub(crate) mod object_inline_member_list;
+pub(crate) mod object_member_list;
+pub(crate) mod property_list;
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_yaml_syntax::YamlObjectMemberList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectMemberList;
+impl FormatRule<YamlObjectMemberList> for FormatYamlObjectMemberList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlObjectMemberList, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for member in node {
+            join.entry(member.syntax(), &format_or_verbatim(member.format()));
+        }
+
+        join.finish()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use crate::utils::{is_sequence_entry, FormatScalarLines};
+use biome_formatter::write;
+use biome_rowan::{AstNode, TextSize};
+use biome_yaml_syntax::{YamlBlockValue, YamlBlockValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockValue;
+impl FormatNodeRule<YamlBlockValue> for FormatYamlBlockValue {
+    fn fmt_fields(&self, node: &YamlBlockValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockValueFields {
+            yaml_block_value_token,
+        } = node.as_fields();
+        let value_token = yaml_block_value_token?;
+        let text = value_token.text_trimmed();
+
+        // The header contains the chomping and indentation indicators, and an optional comment
+        let header_end = text.find('\n').unwrap_or(text.len());
+        let (header, content) = text.split_at(header_end);
+        let (indicators, comment) = match header.split_once('#') {
+            Some((indicators, comment)) => (indicators.trim(), Some(comment.trim_end())),
+            None => (header.trim(), None),
+        };
+
+        // An explicit indentation indicator depends on the original indentation of the content
+        if indicators.contains(|c: char| c.is_ascii_digit()) {
+            return format_verbatim_node(node.syntax()).fmt(f);
+        }
+
+        let position = value_token.text_trimmed_range().start();
+        let content_position = position + TextSize::of(header);
+        let header = match comment {
+            Some(comment) => std::format!("{indicators} #{comment}"),
+            None => indicators.to_string(),
+        };
+
+        // The content is re-indented relative to the indentation of its least indented line
+        let indentation = content
+            .split('\n')
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches(' ').len())
+            .min()
+            .unwrap_or(0);
+
+        let should_indent = !node
+            .syntax()
+            .parent()
+            .is_some_and(|parent| is_sequence_entry(&parent));
+        let lines = FormatScalarLines::new(content, content_position, |line| {
+            line.get(indentation..).unwrap_or_default()
+        })
+        .with_indent(should_indent);
+
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &format_with(|f| {
+                    if !header.is_empty() {
+                        write!(f, [dynamic_text(&header, position)])?;
+                    }
+                    write!(f, [lines])
+                })
+            )]
+        )
+    }
+}
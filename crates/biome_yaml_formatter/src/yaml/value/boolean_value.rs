@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBooleanValue, YamlBooleanValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBooleanValue;
+impl FormatNodeRule<YamlBooleanValue> for FormatYamlBooleanValue {
+    fn fmt_fields(&self, node: &YamlBooleanValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBooleanValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block_value;
+pub(crate) mod boolean_value;
+pub(crate) mod null_value;
+pub(crate) mod number_value;
+pub(crate) mod string_value;
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlNullValue, YamlNullValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlNullValue;
+impl FormatNodeRule<YamlNullValue> for FormatYamlNullValue {
+    fn fmt_fields(&self, node: &YamlNullValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlNullValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
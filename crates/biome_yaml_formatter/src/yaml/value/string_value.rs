@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use crate::utils::{is_sequence_entry, FormatScalarLines};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{YamlStringValue, YamlStringValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlStringValue;
+impl FormatNodeRule<YamlStringValue> for FormatYamlStringValue {
+    fn fmt_fields(&self, node: &YamlStringValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlStringValueFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+        let text = value_token.text_trimmed();
+
+        if !text.contains('\n') {
+            return write!(f, [value_token.format()]);
+        }
+
+        // The line breaks of multiline scalars are folded, so the indentation of their
+        // continuation lines can be changed. The trailing whitespaces of plain scalars
+        // are folded too, but those of quoted scalars may be escaped.
+        let is_plain = !text.starts_with(['"', '\'']);
+        let lines =
+            FormatScalarLines::new(text, value_token.text_trimmed_range().start(), |line| {
+                let line = line.trim_start_matches([' ', '\t']);
+                if is_plain {
+                    line.trim_end_matches([' ', '\t'])
+                } else {
+                    line
+                }
+            })
+            .with_indent(!is_sequence_entry(node.syntax()));
+
+        write!(f, [format_replaced(&value_token, &lines)])
+    }
+}
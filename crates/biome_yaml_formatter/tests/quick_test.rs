@@ -0,0 +1,45 @@
+use biome_formatter::LineWidth;
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_formatter::{format_node, YamlFormatLanguage};
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::YamlFileSource;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+key:   value
+list:
+- &anchor a
+-   b: c
+    d: e
+"#;
+    let parse = parse_yaml(src);
+    println!("{:#?}", parse);
+
+    let options = YamlFormatOptions::new(YamlFileSource::yaml())
+        .with_line_width(LineWidth::try_from(80).unwrap());
+    let doc = format_node(options.clone(), &parse.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    let root = &parse.syntax();
+    let language = language::YamlTestFormatLanguage;
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        root,
+        result.as_code(),
+        "quick_test",
+        &language,
+        YamlFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
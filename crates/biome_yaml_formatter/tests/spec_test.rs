@@ -0,0 +1,64 @@
+use biome_configuration::{PartialConfiguration, PartialYamlConfiguration, PartialYamlFormatter};
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_service::workspace::UpdateSettingsParams;
+use biome_yaml_formatter::{context::YamlFormatOptions, YamlFormatLanguage};
+use biome_yaml_syntax::YamlFileSource;
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_yaml_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.yaml` and the output file name is `{spec_name}.yaml.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `yaml/mapping` -> input: `tests/specs/yaml/mapping.yaml`, expected output: `tests/specs/yaml/mapping.yaml.snap`
+/// * `mapping` -> input: `tests/specs/mapping.yaml`, expected output: `tests/specs/mapping.yaml.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+
+    let settings = UpdateSettingsParams {
+        configuration: PartialConfiguration {
+            yaml: Some(PartialYamlConfiguration {
+                formatter: Some(PartialYamlFormatter {
+                    enabled: Some(true),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        },
+        vcs_base_path: None,
+        gitignore_matches: vec![],
+        workspace_directory: None,
+        configuration_directory: None,
+    };
+
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
+    else {
+        panic!("Failed to set up snapshot test");
+    };
+
+    let options = YamlFormatOptions::new(YamlFileSource::yaml());
+    let language = language::YamlTestFormatLanguage;
+
+    let snapshot = SpecSnapshot::new(
+        test_file,
+        test_directory,
+        language,
+        YamlFormatLanguage::new(options),
+    );
+
+    snapshot.test()
+}
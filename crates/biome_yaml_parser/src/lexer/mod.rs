@@ -38,7 +38,6 @@ pub(crate) struct YamlLexer<'src> {
     after_newline: bool,
 
     /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
-    #[allow(unused)]
     unicode_bom_length: usize,
 
     /// Byte offset of the current token from the start of the source
@@ -54,12 +53,32 @@ pub(crate) struct YamlLexer<'src> {
     /// diagnostics emitted during the parsing phase
     diagnostics: Vec<ParseDiagnostic>,
 
-    context: YamlLexContext,
+    /// The number of flow collections (`[...]` and `{...}`) that contain the next token.
+    ///
+    /// Inside flow collections, `,`, `[`, `]`, `{` and `}` are indicators and end plain scalars.
+    flow_depth: usize,
+
+    /// The column of the last mapping key, explicit mapping entry indicator (`?` or `:`)
+    /// or sequence entry indicator, if any.
+    ///
+    /// The lines of multi-line plain scalars and the content of block scalars
+    /// must be indented more than their parent key or entry:
+    ///
+    /// ```yaml
+    /// key: |
+    ///   content
+    /// other: first line
+    ///   second line
+    /// ```
+    parent_indent: Option<usize>,
+
+    /// `true` if the last token was a block scalar indicator (`|` or `>`), in which case
+    /// the next token is the header and the content of the block scalar.
+    after_block_scalar_indicator: bool,
 }
 
 impl<'source> YamlLexer<'source> {
     /// Creates a new lexer from the given string
-    #[allow(dead_code)]
     pub fn from_str(source: &'source str) -> Self {
         Self {
             source,
@@ -70,92 +89,134 @@ impl<'source> YamlLexer<'source> {
             current_kind: YamlSyntaxKind::EOF,
             current_flags: TokenFlags::empty(),
             diagnostics: vec![],
-            context: YamlLexContext::Regular,
+            flow_depth: 0,
+            parent_indent: None,
+            after_block_scalar_indicator: false,
         }
     }
 
-    fn current_char(&self) -> Option<u8> {
-        self.source.as_bytes().get(self.position).copied()
+    /// Returns the column of the byte at `position`, which is the number of bytes
+    /// between the start of its line and itself.
+    pub(crate) fn column_at(&self, position: usize) -> usize {
+        let line_start = self.source[..position]
+            .rfind(['\n', '\r'])
+            .map_or(0, |index| index + 1);
+        position - line_start
     }
 
-    fn peek_next_char(&self) -> Option<u8> {
-        self.source.as_bytes().get(self.position + 1).copied()
+    /// Records the key at `key_start` as the parent of the next scalars.
+    /// Keys inside flow collections are ignored, because flow scalars don't depend on indentation.
+    fn set_parent_indent(&mut self, key_start: usize) {
+        if self.flow_depth == 0 {
+            self.parent_indent = Some(self.column_at(key_start));
+        }
     }
 
-    fn is_next_char_whitespace(&self) -> bool {
-        matches!(self.peek_next_char(), Some(b' ' | b'\n'))
+    /// Returns `true` if the byte at `offset` from the current position is a whitespace,
+    /// a line break or the end of the file.
+    fn is_blank_at(&self, offset: usize) -> bool {
+        matches!(
+            self.byte_at(offset),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
     }
 
-    /// Consumes and returns the next token, if any
-    fn consume_token(&mut self) -> Option<Token> {
-        let start = self.text_position();
-        let char = self.current_char()?;
-        let kind = self.consume_token_in_context(char, self.context);
-        self.current_kind = kind;
-        let end = self.text_position();
-        Some(Token {
-            kind,
-            range: TextRange::new(start, end),
-        })
+    /// Returns `true` if the byte at `offset` from the current position is a flow indicator
+    /// and the lexer is inside a flow collection.
+    fn is_flow_indicator_at(&self, offset: usize) -> bool {
+        self.flow_depth > 0
+            && matches!(self.byte_at(offset), Some(b',' | b'[' | b']' | b'{' | b'}'))
     }
 
-    /// Consume a byte in the given context
-    fn consume_token_in_context(&mut self, current: u8, context: YamlLexContext) -> YamlSyntaxKind {
-        if self.position >= self.source.len() {
-            return YamlSyntaxKind::EOF;
-        }
+    /// Returns `true` if the `:` at `offset` from the current position is a mapping value indicator.
+    fn is_mapping_indicator_at(&self, offset: usize) -> bool {
+        debug_assert_eq!(self.byte_at(offset), Some(b':'));
+        self.is_blank_at(offset + 1) || self.is_flow_indicator_at(offset + 1)
+    }
 
-        let start = self.text_position();
+    /// Consumes and returns the next token, if any
+    fn consume_token(&mut self, current: u8) -> YamlSyntaxKind {
+        if self.after_block_scalar_indicator {
+            self.after_block_scalar_indicator = false;
+            if let Some(kind) = self.consume_block_scalar_content() {
+                return kind;
+            }
+        }
 
-        let kind = match current {
-            b'#' => self.consume_comment(),
-            b'-' => {
-                if self.is_next_char_whitespace() {
-                    self.consume_byte(T![-])
-                } else {
-                    self.consume_identifer_or_value()
-                }
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespaces(),
+            b'#' if self.is_at_comment_start() => self.consume_comment(),
+            b'-' if self.is_at_document_marker(b'-') => {
+                self.parent_indent = None;
+                self.advance(3);
+                T![---]
             }
-            b':' => {
-                if self.is_next_char_whitespace() {
-                    self.context = YamlLexContext::AfterIdent;
-                    self.consume_byte(T![:])
-                } else {
-                    self.consume_identifer_or_value()
-                }
+            b'.' if self.is_at_document_marker(b'.') => {
+                self.parent_indent = None;
+                self.advance(3);
+                T![...]
             }
-            b'\'' | b'"' => self.consume_string_literal(current),
-            b' ' => self.consume_newline_or_whitespaces(),
-            b'\n' => {
-                if self.context == YamlLexContext::AfterIdent {
-                    self.context = YamlLexContext::Regular;
-                }
-                self.consume_newline_or_whitespaces()
+            b'%' if self.flow_depth == 0 && self.column_at(self.position) == 0 => {
+                self.consume_directive()
             }
-            b'[' => self.consume_array_inline_start(),
-            b',' => {
-                if self.context == YamlLexContext::AfterInlineArray {
-                    self.consume_byte(T![,])
-                } else {
-                    self.consume_identifer_or_value()
-                }
+            b'-' if self.flow_depth == 0 && self.is_blank_at(1) => {
+                self.parent_indent = Some(self.column_at(self.position));
+                self.consume_byte(T![-])
+            }
+            b'?' if self.is_blank_at(1) || self.is_flow_indicator_at(1) => {
+                self.set_parent_indent(self.position);
+                self.consume_byte(T![?])
+            }
+            // The `:` of an explicit mapping entry is at the start of its line, like its `?`
+            b':' if self.after_newline && self.is_mapping_indicator_at(0) => {
+                self.set_parent_indent(self.position);
+                self.consume_byte(T![:])
             }
-            b']' => {
-                if self.context == YamlLexContext::AfterInlineArray {
-                    self.consume_array_inline_end()
-                } else {
-                    self.consume_identifer_or_value()
+            b':' if self.is_mapping_indicator_at(0) => self.consume_byte(T![:]),
+            // JSON-like keys can be directly followed by the `:` in flow collections: `{"key":value}`
+            b':' if self.flow_depth > 0 && self.current_kind == YamlSyntaxKind::YAML_IDENTIFIER => {
+                self.consume_byte(T![:])
+            }
+            b'[' => {
+                self.flow_depth += 1;
+                self.consume_byte(T!['['])
+            }
+            b'{' => {
+                self.flow_depth += 1;
+                self.consume_byte(T!['{'])
+            }
+            b']' if self.flow_depth > 0 => {
+                self.flow_depth -= 1;
+                self.consume_byte(T![']'])
+            }
+            b'}' if self.flow_depth > 0 => {
+                self.flow_depth -= 1;
+                self.consume_byte(T!['}'])
+            }
+            b',' if self.flow_depth > 0 => self.consume_byte(T![,]),
+            b'&' => self.consume_node_property(YamlSyntaxKind::YAML_ANCHOR),
+            b'*' => self.consume_node_property(YamlSyntaxKind::YAML_ALIAS),
+            b'!' => self.consume_node_property(YamlSyntaxKind::YAML_TAG),
+            b'|' if self.flow_depth == 0 => {
+                self.after_block_scalar_indicator = true;
+                self.consume_byte(T![|])
+            }
+            b'>' if self.flow_depth == 0 => {
+                self.after_block_scalar_indicator = true;
+                self.consume_byte(T![>])
+            }
+            b'\'' | b'"' => self.consume_quoted_scalar(current),
+            0xef if self.position == 0 => {
+                if let Some((bom, bom_size)) =
+                    self.consume_potential_bom(YamlSyntaxKind::UNICODE_BOM)
+                {
+                    self.unicode_bom_length = bom_size;
+                    return bom;
                 }
+                self.consume_plain_scalar()
             }
-            _ => match context {
-                YamlLexContext::Regular => self.consume_identifer_or_value(),
-                YamlLexContext::AfterIdent => self.consume_value(),
-                YamlLexContext::AfterInlineArray => self.consume_value(),
-            },
-        };
-
-        debug_assert!(self.text_position() > start, "Lexer did not advance");
-        kind
+            _ => self.consume_plain_scalar(),
+        }
     }
 
     /// Bumps the current byte and creates a lexed token of the passed in kind.
@@ -165,6 +226,24 @@ impl<'source> YamlLexer<'source> {
         tok
     }
 
+    /// A `#` only starts a comment when it's at the start of a line or preceded by a whitespace.
+    fn is_at_comment_start(&self) -> bool {
+        self.position == 0
+            || matches!(
+                self.source.as_bytes()[self.position - 1],
+                b' ' | b'\t' | b'\n' | b'\r'
+            )
+    }
+
+    /// Checks if the lexer is at a `---` or `...` document marker, which must be at the start of a line.
+    fn is_at_document_marker(&self, marker: u8) -> bool {
+        self.flow_depth == 0
+            && self.column_at(self.position) == 0
+            && self.byte_at(1) == Some(marker)
+            && self.byte_at(2) == Some(marker)
+            && self.is_blank_at(3)
+    }
+
     fn consume_comment(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'#');
         self.consume_until_newline();
@@ -172,99 +251,264 @@ impl<'source> YamlLexer<'source> {
     }
 
     fn consume_until_newline(&mut self) {
-        while let Some(c) = self.current_char() {
-            if c == b'\n' {
+        while let Some(c) = self.current_byte() {
+            if matches!(c, b'\n' | b'\r') {
                 break;
             }
-            self.advance(1);
+            self.advance_char_unchecked();
+        }
+    }
+
+    /// Lexes a directive, such as `%YAML 1.2` or `%TAG ! tag:example.com,2000:`
+    fn consume_directive(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'%');
+        let mut end = self.position + 1;
+        self.advance(1);
+        while let Some(c) = self.current_byte() {
+            match c {
+                b'\n' | b'\r' => break,
+                b' ' | b'\t' if self.byte_at(1) == Some(b'#') => break,
+                b' ' | b'\t' => self.advance(1),
+                _ => {
+                    self.advance_char_unchecked();
+                    end = self.position;
+                }
+            }
+        }
+        self.position = end;
+        YamlSyntaxKind::YAML_DIRECTIVE
+    }
+
+    /// Lexes an anchor (`&anchor`), an alias (`*anchor`) or a tag (`!!str`).
+    fn consume_node_property(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.advance(1);
+        while !self.is_blank_at(0) && !self.is_flow_indicator_at(0) {
+            self.advance_char_unchecked();
         }
-        self.context = YamlLexContext::Regular;
+        kind
     }
 
-    fn consume_string_literal(&mut self, quote: u8) -> YamlSyntaxKind {
+    fn consume_quoted_scalar(&mut self, quote: u8) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
         self.assert_byte(quote);
+        let start = self.position;
         self.advance(1);
 
-        let mut escape = false;
         loop {
-            match self.current_char() {
-                Some(b'\\') => {
-                    escape = true;
+            match self.current_byte() {
+                // Only double-quoted scalars support escape sequences
+                Some(b'\\') if quote == b'"' => {
                     self.advance(1);
+                    if !self.is_eof() {
+                        self.advance_char_unchecked();
+                    }
                 }
-                Some(c) if c == quote && !escape => {
-                    self.advance(1);
-                    break;
+                // A single quote is escaped by repeating it
+                Some(b'\'') if quote == b'\'' && self.peek_byte() == Some(b'\'') => {
+                    self.advance(2);
                 }
-                Some(_) => {
-                    escape = false;
+                Some(c) if c == quote => {
                     self.advance(1);
+                    break;
                 }
+                Some(_) => self.advance_char_unchecked(),
                 None => {
+                    let unterminated = ParseDiagnostic::new(
+                        "Missing closing quote",
+                        TextSize::from(start as u32)..self.text_position(),
+                    )
+                    .with_detail(self.text_position()..self.text_position(), "file ends here");
+                    self.diagnostics.push(unterminated);
                     break;
                 }
             }
         }
-        YamlSyntaxKind::YAML_STRING_VALUE
+
+        if self.is_followed_by_mapping_indicator() {
+            self.set_parent_indent(start);
+            YamlSyntaxKind::YAML_IDENTIFIER
+        } else {
+            YamlSyntaxKind::YAML_STRING_VALUE
+        }
+    }
+
+    /// Checks if the scalar that was just lexed is followed by a `:` on the same line,
+    /// meaning that the scalar is a mapping key.
+    fn is_followed_by_mapping_indicator(&self) -> bool {
+        let mut offset = 0;
+        while matches!(self.byte_at(offset), Some(b' ' | b'\t')) {
+            offset += 1;
+        }
+        self.byte_at(offset) == Some(b':')
+            && (self.flow_depth > 0 || self.is_mapping_indicator_at(offset))
     }
 
-    /// Consume a line up to the colon or the end of the line
-    fn consume_identifer_or_value(&mut self) -> YamlSyntaxKind {
+    /// Lexes a plain scalar, which spans up to a `: ` or ` #`, or the end of the line.
+    ///
+    /// Outside flow collections, a plain scalar continues on the following lines
+    /// when they are indented more than its parent.
+    fn consume_plain_scalar(&mut self) -> YamlSyntaxKind {
         let start = self.position;
-        let mut is_ident = false;
-        while let Some(c) = self.current_char() {
-            if c == b'\n' {
-                break;
-            }
-            if c == b':' && self.is_next_char_whitespace() {
-                is_ident = true;
-                break;
+        // The first character is always part of the scalar, even if it's an indicator
+        self.advance_char_unchecked();
+        let mut end = self.position;
+        let mut is_key = false;
+
+        while let Some(c) = self.current_byte() {
+            match c {
+                b'\n' | b'\r' => {
+                    if self.flow_depth > 0 || !self.is_at_plain_scalar_continuation() {
+                        break;
+                    }
+                    self.consume_newline();
+                }
+                b' ' | b'\t' => {
+                    if self.byte_at(1) == Some(b'#') {
+                        break;
+                    }
+                    self.advance(1);
+                }
+                b':' if self.is_mapping_indicator_at(0) => {
+                    is_key = true;
+                    break;
+                }
+                _ if self.is_flow_indicator_at(0) => break,
+                _ => {
+                    self.advance_char_unchecked();
+                    end = self.position;
+                }
             }
-            self.advance(1);
         }
-        if is_ident {
+
+        // Trailing whitespaces and line breaks aren't part of the scalar
+        self.position = end;
+
+        if is_key {
+            self.set_parent_indent(start);
             YamlSyntaxKind::YAML_IDENTIFIER
         } else {
-            let value = &self.source[start..self.position];
-            interpret_value(value)
+            interpret_value(&self.source[start..end])
         }
     }
 
-    fn consume_value(&mut self) -> YamlSyntaxKind {
-        let start = self.position;
-        while let Some(c) = self.current_char() {
-            if c == b'\n' {
-                break;
+    /// Checks if the line that follows the current line break continues a plain scalar.
+    fn is_at_plain_scalar_continuation(&self) -> bool {
+        let mut position = self.position;
+        loop {
+            let Some(line_start) = self.skip_line_break(position) else {
+                return false;
+            };
+            let content_start = self.skip_indentation(line_start);
+            match self.source.as_bytes().get(content_start) {
+                // Empty lines are folded in the scalar
+                Some(b'\n' | b'\r') => position = content_start,
+                Some(b'#') | None => return false,
+                Some(_) => {
+                    return self.is_content_line(line_start, content_start)
+                        && !self.has_mapping_indicator(content_start)
+                }
             }
-            if self.context == YamlLexContext::AfterInlineArray && (c == b',' || c == b']') {
-                break;
+        }
+    }
+
+    /// Checks if the line that starts at `line_start` contains a `: ` mapping indicator
+    fn has_mapping_indicator(&self, content_start: usize) -> bool {
+        let bytes = self.source.as_bytes();
+        let mut position = content_start;
+        while let Some(&c) = bytes.get(position) {
+            match c {
+                b'\n' | b'\r' => return false,
+                b':' if matches!(
+                    bytes.get(position + 1),
+                    None | Some(b' ' | b'\t' | b'\n' | b'\r')
+                ) =>
+                {
+                    return true
+                }
+                _ => position += 1,
             }
-            self.advance(1);
         }
-        let value = &self.source[start..self.position];
-        interpret_value(value)
+        false
+    }
+
+    /// Checks if the line that starts at `line_start`, with its content starting at
+    /// `content_start`, is indented more than the parent of the current scalar and
+    /// isn't a document marker.
+    fn is_content_line(&self, line_start: usize, content_start: usize) -> bool {
+        let indent = content_start - line_start;
+        if self
+            .parent_indent
+            .is_some_and(|parent_indent| indent <= parent_indent)
+        {
+            return false;
+        }
+
+        let line = &self.source.as_bytes()[line_start..];
+        let is_document_marker = (line.starts_with(b"---") || line.starts_with(b"..."))
+            && matches!(line.get(3), None | Some(b' ' | b'\t' | b'\n' | b'\r'));
+        !is_document_marker
     }
 
-    fn consume_array_inline_start(&mut self) -> YamlSyntaxKind {
-        self.assert_byte(b'[');
-        self.advance(1);
-        self.context = YamlLexContext::AfterInlineArray;
-        YamlSyntaxKind::L_BRACK
+    /// Returns the position after the line break at `position`,
+    /// or `None` if there's no line break at `position`.
+    fn skip_line_break(&self, position: usize) -> Option<usize> {
+        let bytes = self.source.as_bytes();
+        match bytes.get(position) {
+            Some(b'\r') if bytes.get(position + 1) == Some(&b'\n') => Some(position + 2),
+            Some(b'\n' | b'\r') => Some(position + 1),
+            _ => None,
+        }
     }
 
-    fn consume_array_inline_end(&mut self) -> YamlSyntaxKind {
-        self.assert_byte(b']');
-        self.advance(1);
-        self.context = YamlLexContext::Regular;
-        YamlSyntaxKind::R_BRACK
+    /// Returns the position of the first byte after the indentation of the line that starts at `line_start`.
+    fn skip_indentation(&self, line_start: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        let mut position = line_start;
+        while matches!(bytes.get(position), Some(b' ' | b'\t')) {
+            position += 1;
+        }
+        position
+    }
+
+    /// Lexes the header and the content of a block scalar, which follow its `|` or `>` indicator.
+    /// The content spans all the lines that are empty or indented more than the parent of the block scalar.
+    ///
+    /// Returns `None` if the block scalar has neither a header nor a content.
+    fn consume_block_scalar_content(&mut self) -> Option<YamlSyntaxKind> {
+        let start = self.position;
+
+        // The header contains the indentation and chomping indicators, and an optional comment
+        self.consume_until_newline();
+        let mut end = self.position;
+
+        let mut position = self.position;
+        while let Some(line_start) = self.skip_line_break(position) {
+            let content_start = self.skip_indentation(line_start);
+            match self.source.as_bytes().get(content_start) {
+                // Trailing empty lines aren't part of the content
+                Some(b'\n' | b'\r') => position = content_start,
+                Some(_) if self.is_content_line(line_start, content_start) => {
+                    self.position = content_start;
+                    self.consume_until_newline();
+                    position = self.position;
+                    end = position;
+                }
+                _ => break,
+            }
+        }
+
+        self.position = end;
+        (end > start).then_some(YamlSyntaxKind::YAML_BLOCK_VALUE)
     }
 }
 
-fn interpret_value(value: &str) -> YamlSyntaxKind {
+/// Returns the kind of the scalar with the given text
+pub(crate) fn interpret_value(value: &str) -> YamlSyntaxKind {
     match value {
-        "true" | "false" => YamlSyntaxKind::YAML_BOOLEAN_VALUE,
-        "null" => YamlSyntaxKind::YAML_NULL_VALUE,
+        "true" | "True" | "TRUE" | "false" | "False" | "FALSE" => {
+            YamlSyntaxKind::YAML_BOOLEAN_VALUE
+        }
+        "null" | "Null" | "NULL" | "~" => YamlSyntaxKind::YAML_NULL_VALUE,
         _ => value
             .parse::<f64>()
             .map_or(YamlSyntaxKind::YAML_STRING_VALUE, |_| {
@@ -304,10 +548,24 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_start
     }
 
-    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
         self.current_start = TextSize::from(self.position as u32);
         self.current_flags = TokenFlags::empty();
-        self.consume_token_in_context(self.current_char().unwrap_or(b'\0'), context)
+
+        let kind = match self.current_byte() {
+            Some(current) => self.consume_token(current),
+            None => YamlSyntaxKind::EOF,
+        };
+
+        self.current_flags
+            .set(TokenFlags::PRECEDING_LINE_BREAK, self.after_newline);
+        self.current_kind = kind;
+
+        if !kind.is_trivia() && kind != YamlSyntaxKind::COMMENT {
+            self.after_newline = false;
+        }
+
+        kind
     }
 
     fn has_preceding_line_break(&self) -> bool {
@@ -319,7 +577,7 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
     }
 
     fn rewind(&mut self, _checkpoint: biome_parser::lexer::LexerCheckpoint<Self::Kind>) {
-        todo!()
+        unimplemented!("YAML lexer doesn't support rewinding");
     }
 
     fn finish(self) -> Vec<ParseDiagnostic> {
@@ -362,47 +620,28 @@ impl Iterator for YamlLexer<'_> {
     type Item = Token;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.consume_token()
+        if self.is_eof() {
+            return None;
+        }
+
+        let kind = self.next_token(YamlLexContext::Regular);
+        Some(Token {
+            kind,
+            range: self.current_range(),
+        })
     }
 }
 
 impl FusedIterator for YamlLexer<'_> {}
 
 /// Context in which the lexer should lex the next token
+///
+/// The lexer keeps track of the flow collections and of the indentation of the
+/// mapping keys by itself, because they determine where plain and block scalars end.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum YamlLexContext {
     #[default]
     Regular,
-    /// The lexer has just lexed an identifier and is expecting a value to come next.
-    ///
-    /// After an identifier, a `: ` is expected to come next, and then the value. If the next token is another identifier, it must come after a newline. Otherwise, values can remain on the same line.
-    /// However, it is possible to have a nested mapping on the same line, but the nested mapping must be enclosed in `{}`.
-    ///
-    /// Valid:
-    /// ```yaml
-    /// foo:
-    ///   bar: baz
-    /// ```
-    /// ```yaml
-    /// foo: 5
-    /// ```
-    /// ```yaml
-    /// foo: { bar: baz }
-    /// ```
-    /// Invalid:
-    /// ```yaml
-    /// foo: bar: baz
-    /// #       ^ invalid syntax, mapping values not allowed here
-    /// ```
-    AfterIdent,
-    /// The lexer has lexed an inline array and is expecting a value or the end of the array to come next.
-    ///
-    /// In this context, commas are allowed to separate values. Normally, commas are simply treated as part of a value.
-    /// A newline is not allowed in this context. The newline must come after the array has been closed with `]`
-    /// ```yaml
-    /// foo: [1, 2, 3]
-    /// ```
-    AfterInlineArray,
 }
 
 impl LexContext for YamlLexContext {
@@ -425,6 +664,7 @@ mod test {
         assert_eq!(interpret_value("true"), YamlSyntaxKind::YAML_BOOLEAN_VALUE);
         assert_eq!(interpret_value("false"), YamlSyntaxKind::YAML_BOOLEAN_VALUE);
         assert_eq!(interpret_value("null"), YamlSyntaxKind::YAML_NULL_VALUE);
+        assert_eq!(interpret_value("~"), YamlSyntaxKind::YAML_NULL_VALUE);
         assert_eq!(interpret_value("foo"), YamlSyntaxKind::YAML_STRING_VALUE);
         assert_eq!(interpret_value("1"), YamlSyntaxKind::YAML_NUMBER_VALUE);
         assert_eq!(interpret_value("1.0"), YamlSyntaxKind::YAML_NUMBER_VALUE);
@@ -263,3 +263,128 @@ fn lex_array_inline_invalid() {
         YAML_STRING_VALUE:2,
     );
 }
+
+#[test]
+fn lex_object_inline() {
+    assert_lex!(
+        "{a: 1, b: c}",
+        L_CURLY:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_NUMBER_VALUE:1,
+        COMMA:1,
+        WHITESPACE:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:1,
+        R_CURLY:1,
+    );
+}
+
+#[test]
+fn lex_quoted_key() {
+    assert_lex!(
+        "'it''s': \"a \\\" b\"",
+        YAML_IDENTIFIER:7,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:8,
+    );
+}
+
+#[test]
+fn lex_comment_after_value() {
+    assert_lex!(
+        "a#b # comment",
+        YAML_STRING_VALUE:3,
+        WHITESPACE:1,
+        COMMENT:9,
+    );
+}
+
+#[test]
+fn lex_documents() {
+    assert_lex!(
+        "%YAML 1.2\n---\na\n...\n",
+        YAML_DIRECTIVE:9,
+        NEWLINE:1,
+        DOC_START:3,
+        NEWLINE:1,
+        YAML_STRING_VALUE:1,
+        NEWLINE:1,
+        DOC_END:3,
+        NEWLINE:1,
+    );
+}
+
+#[test]
+fn lex_anchor_alias_tag() {
+    assert_lex!(
+        "a: &x !!str b\nc: *x",
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_ANCHOR:2,
+        WHITESPACE:1,
+        YAML_TAG:5,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:1,
+        NEWLINE:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_ALIAS:2,
+    );
+}
+
+#[test]
+fn lex_block_scalar() {
+    assert_lex!(
+        "a: |-\n  foo\n\n  bar\n\nb: c",
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        PIPE:1,
+        YAML_BLOCK_VALUE:14,
+        NEWLINE:1,
+        NEWLINE:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:1,
+    );
+}
+
+#[test]
+fn lex_multiline_plain_scalar() {
+    assert_lex!(
+        "a: foo\n  bar\nb: c",
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:9,
+        NEWLINE:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:1,
+    );
+}
+
+#[test]
+fn lex_explicit_mapping_key() {
+    assert_lex!(
+        "? foo\n  bar\n: baz\n?qux",
+        QUESTION:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:9,
+        NEWLINE:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:3,
+        NEWLINE:1,
+        YAML_STRING_VALUE:4,
+    );
+}
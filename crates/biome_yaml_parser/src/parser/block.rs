@@ -0,0 +1,290 @@
+use crate::parser::flow::{parse_flow_mapping, parse_flow_sequence};
+use crate::parser::parse_error::{
+    block_collection_on_same_line, expected_mapping_entry, expected_sequence_entry,
+};
+use crate::parser::{
+    parse_alias, parse_block_scalar, parse_identifier, parse_scalar, YamlParser, YamlPropertyList,
+};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::{self, *};
+use biome_parser::{token_set, Parser, TokenSet};
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+
+/// The parent of a node in block context, which determines how much the node must be indented.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum BlockParent {
+    /// The node is the body of a document.
+    Document,
+    /// The node is the value of a mapping entry whose key is at the given column.
+    MappingValue(usize),
+    /// The node is an entry of a sequence whose `-` indicator is at the given column.
+    SequenceItem(usize),
+    /// The node is the key or the value of an explicit mapping entry whose `?` indicator
+    /// is at the given column.
+    ExplicitMappingEntry(usize),
+}
+
+/// The tokens that can start a node in block context
+const BLOCK_NODE_START_SET: TokenSet<YamlSyntaxKind> = token_set![
+    YAML_STRING_VALUE,
+    YAML_NUMBER_VALUE,
+    YAML_BOOLEAN_VALUE,
+    YAML_NULL_VALUE,
+    YAML_ALIAS,
+    YAML_ANCHOR,
+    YAML_TAG,
+    YAML_IDENTIFIER,
+    T![-],
+    T![?],
+    T!['['],
+    T!['{'],
+    T![|],
+    T![>],
+];
+
+/// Checks if the parser is at a node that belongs to `parent`.
+///
+/// A node that starts on the same line as its parent always belongs to it. Otherwise,
+/// the node must be indented more than its parent, with the exception of sequences
+/// that are the value of a mapping entry, which can be at the same column as the key:
+///
+/// ```yaml
+/// key:
+/// - item
+/// ```
+fn is_at_block_node(p: &mut YamlParser, parent: BlockParent) -> bool {
+    if !p.at_ts(BLOCK_NODE_START_SET) {
+        return false;
+    }
+
+    if !p.has_preceding_line_break() {
+        return true;
+    }
+
+    let column = p.current_column();
+    match parent {
+        BlockParent::Document => true,
+        BlockParent::MappingValue(indent) => column > indent || (p.at(T![-]) && column == indent),
+        BlockParent::SequenceItem(indent) | BlockParent::ExplicitMappingEntry(indent) => {
+            column > indent
+        }
+    }
+}
+
+/// Parses a node in block context, where the structure of the collections is determined by indentation.
+pub(crate) fn parse_block_node(p: &mut YamlParser, parent: BlockParent) -> ParsedSyntax {
+    if !is_at_block_node(p, parent) {
+        return Absent;
+    }
+
+    match p.cur() {
+        YAML_ANCHOR | YAML_TAG => parse_value_with_properties(p, parent),
+        T![-] | T![?] | YAML_IDENTIFIER => {
+            if matches!(parent, BlockParent::MappingValue(_)) && !p.has_preceding_line_break() {
+                p.error(block_collection_on_same_line(p, p.cur_range()));
+            }
+
+            if p.at(T![-]) {
+                parse_block_sequence(p)
+            } else {
+                parse_block_mapping(p)
+            }
+        }
+        T!['['] => parse_flow_sequence(p),
+        T!['{'] => parse_flow_mapping(p),
+        T![|] | T![>] => parse_block_scalar(p),
+        YAML_ALIAS => parse_alias(p),
+        _ => parse_scalar(p),
+    }
+}
+
+/// Parses a node preceded by an anchor and/or a tag.
+///
+/// ```yaml
+/// key: &anchor !!str value
+/// other: !!map
+///   nested: value
+/// ```
+fn parse_value_with_properties(p: &mut YamlParser, parent: BlockParent) -> ParsedSyntax {
+    let m = p.start();
+
+    YamlPropertyList.parse_list(p);
+    parse_block_node(p, parent).ok();
+
+    Present(m.complete(p, YAML_VALUE_WITH_PROPERTIES))
+}
+
+/// The tokens that can start a block mapping entry
+const BLOCK_MAPPING_ENTRY_START_SET: TokenSet<YamlSyntaxKind> = token_set![YAML_IDENTIFIER, T![?]];
+
+/// Parses a block mapping, whose keys or `?` indicators are all at the same column.
+///
+/// ```yaml
+/// key: value
+/// other:
+///   nested: value
+/// ? explicit key
+/// : value
+/// ```
+fn parse_block_mapping(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at_ts(BLOCK_MAPPING_ENTRY_START_SET) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    YamlObjectMemberList {
+        indent: p.current_column(),
+    }
+    .parse_list(p);
+
+    Present(m.complete(p, YAML_OBJECT))
+}
+
+struct YamlObjectMemberList {
+    /// The column of the keys and `?` indicators of the mapping
+    indent: usize,
+}
+
+impl ParseNodeList for YamlObjectMemberList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_OBJECT_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_block_mapping_member(p, self.indent)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at_ts(BLOCK_MAPPING_ENTRY_START_SET) || p.current_column() != self.indent
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, BLOCK_MAPPING_ENTRY_START_SET)
+                .enable_recovery_on_line_break(),
+            expected_mapping_entry,
+        )
+    }
+}
+
+fn parse_block_mapping_member(p: &mut YamlParser, indent: usize) -> ParsedSyntax {
+    if p.at(T![?]) {
+        return parse_block_explicit_mapping_member(p, indent);
+    }
+
+    if !p.at(YAML_IDENTIFIER) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_identifier(p).ok();
+    p.expect(T![:]);
+    parse_block_node(p, BlockParent::MappingValue(indent)).ok();
+
+    Present(m.complete(p, YAML_OBJECT_MEMBER))
+}
+
+/// Parses a mapping entry with an explicit key, which can be any node, including a collection.
+/// The `:` indicator of the value must be at the same column as the `?` indicator.
+/// Both the key and the value are optional.
+///
+/// ```yaml
+/// ? - complex
+///   - key
+/// : value
+/// ? key without value
+/// ```
+fn parse_block_explicit_mapping_member(p: &mut YamlParser, indent: usize) -> ParsedSyntax {
+    if !p.at(T![?]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![?]);
+    parse_block_node(p, BlockParent::ExplicitMappingEntry(indent)).ok();
+
+    if p.at(T![:]) && (!p.has_preceding_line_break() || p.current_column() == indent) {
+        p.bump(T![:]);
+        parse_block_node(p, BlockParent::ExplicitMappingEntry(indent)).ok();
+    }
+
+    Present(m.complete(p, YAML_EXPLICIT_OBJECT_MEMBER))
+}
+
+/// Parses a block sequence, whose `-` indicators are all at the same column.
+///
+/// ```yaml
+/// - item
+/// - key: value
+///   other: value
+/// - - nested
+/// ```
+fn parse_block_sequence(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T![-]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    YamlArrayItemList {
+        indent: p.current_column(),
+    }
+    .parse_list(p);
+
+    Present(m.complete(p, YAML_ARRAY))
+}
+
+struct YamlArrayItemList {
+    /// The column of the `-` indicators of the sequence
+    indent: usize,
+}
+
+impl ParseNodeList for YamlArrayItemList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_ARRAY_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_block_sequence_item(p, self.indent)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![-]) || p.current_column() != self.indent
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, token_set![T![-]])
+                .enable_recovery_on_line_break(),
+            expected_sequence_entry,
+        )
+    }
+}
+
+fn parse_block_sequence_item(p: &mut YamlParser, indent: usize) -> ParsedSyntax {
+    if !p.at(T![-]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![-]);
+    parse_block_node(p, BlockParent::SequenceItem(indent)).ok();
+
+    Present(m.complete(p, YAML_ARRAY_ITEM))
+}
@@ -0,0 +1,124 @@
+use crate::parser::block::{parse_block_node, BlockParent};
+use crate::parser::parse_error::expected_document;
+use crate::parser::YamlParser;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::{self, *};
+use biome_parser::{token_set, Parser};
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+
+pub(crate) struct YamlDocumentList {
+    /// Whether the previous document was explicitly terminated by the `...` marker.
+    /// The first document doesn't have a previous document.
+    previous_ended: bool,
+}
+
+impl Default for YamlDocumentList {
+    fn default() -> Self {
+        Self {
+            previous_ended: true,
+        }
+    }
+}
+
+impl ParseNodeList for YamlDocumentList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_DOCUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        // A document that isn't terminated must be followed by the start of the next one
+        if !self.previous_ended && !p.at_ts(token_set![T![---], T![...], YAML_DIRECTIVE]) {
+            return Absent;
+        }
+
+        let (document, has_end) = parse_document(p);
+        self.previous_ended = has_end;
+        document
+    }
+
+    fn is_at_list_end(&self, _p: &mut Self::Parser<'_>) -> bool {
+        false
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, token_set![T![---], T![...], YAML_DIRECTIVE]),
+            expected_document,
+        )
+    }
+}
+
+/// Parses a document, with its directives and its optional start and end markers.
+///
+/// ```yaml
+/// %YAML 1.2
+/// ---
+/// key: value
+/// ...
+/// ```
+///
+/// Returns whether the document is terminated by the end marker.
+fn parse_document(p: &mut YamlParser) -> (ParsedSyntax, bool) {
+    let m = p.start();
+
+    let has_directives = p.at(YAML_DIRECTIVE);
+    YamlDirectiveList.parse_list(p);
+
+    // The start marker is required after directives
+    let has_start = if has_directives {
+        p.expect(T![---])
+    } else {
+        p.eat(T![---])
+    };
+
+    let has_body = parse_block_node(p, BlockParent::Document).is_present();
+    let has_end = p.eat(T![...]);
+
+    if !has_directives && !has_start && !has_body && !has_end {
+        m.abandon(p);
+        return (Absent, false);
+    }
+
+    (Present(m.complete(p, YAML_DOCUMENT)), has_end)
+}
+
+struct YamlDirectiveList;
+
+impl ParseNodeList for YamlDirectiveList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_DIRECTIVE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !p.at(YAML_DIRECTIVE) {
+            return Absent;
+        }
+
+        let m = p.start();
+        p.bump(YAML_DIRECTIVE);
+        Present(m.complete(p, YAML_DIRECTIVE))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(YAML_DIRECTIVE)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, token_set![YAML_DIRECTIVE, T![---]]),
+            expected_document,
+        )
+    }
+}
@@ -0,0 +1,188 @@
+use crate::lexer::interpret_value;
+use crate::parser::parse_error::{expected_mapping_entry, expected_value};
+use crate::parser::{parse_alias, parse_identifier, parse_scalar, YamlParser, YamlPropertyList};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::{self, *};
+use biome_parser::{token_set, Parser, TokenSet};
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+
+/// The tokens that end the entries of flow collections
+const FLOW_RECOVERY_SET: TokenSet<YamlSyntaxKind> = token_set![T![,], T![']'], T!['}']];
+
+/// Parses a node in flow context, where indentation doesn't matter.
+fn parse_flow_node(p: &mut YamlParser) -> ParsedSyntax {
+    match p.cur() {
+        YAML_ANCHOR | YAML_TAG => {
+            let m = p.start();
+
+            YamlPropertyList.parse_list(p);
+            parse_flow_node(p).ok();
+
+            Present(m.complete(p, YAML_VALUE_WITH_PROPERTIES))
+        }
+        T!['['] => parse_flow_sequence(p),
+        T!['{'] => parse_flow_mapping(p),
+        YAML_ALIAS => parse_alias(p),
+        _ => parse_scalar(p),
+    }
+}
+
+/// Parses a flow sequence
+///
+/// ```yaml
+/// [a, "b", 1, [nested]]
+/// ```
+pub(crate) fn parse_flow_sequence(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['[']);
+    YamlArrayInlineList.parse_list(p);
+    p.expect(T![']']);
+
+    Present(m.complete(p, YAML_ARRAY_INLINE))
+}
+
+struct YamlArrayInlineList;
+
+impl ParseSeparatedList for YamlArrayInlineList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_ARRAY_INLINE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_flow_node(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS_VALUE, FLOW_RECOVERY_SET),
+            expected_value,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses a flow mapping
+///
+/// ```yaml
+/// { key: value, "other": [a, b], ? [explicit, key] : value }
+/// ```
+pub(crate) fn parse_flow_mapping(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['{']);
+    YamlObjectInlineMemberList.parse_list(p);
+    p.expect(T!['}']);
+
+    Present(m.complete(p, YAML_OBJECT_INLINE))
+}
+
+struct YamlObjectInlineMemberList;
+
+impl ParseSeparatedList for YamlObjectInlineMemberList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_OBJECT_INLINE_MEMBER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_flow_mapping_member(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, FLOW_RECOVERY_SET),
+            expected_mapping_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+fn parse_flow_mapping_member(p: &mut YamlParser) -> ParsedSyntax {
+    if p.at(T![?]) {
+        return parse_flow_explicit_mapping_member(p);
+    }
+
+    if !p.at(YAML_IDENTIFIER) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_identifier(p).ok();
+    p.expect(T![:]);
+    parse_flow_node(p).ok();
+
+    Present(m.complete(p, YAML_OBJECT_MEMBER))
+}
+
+/// Parses a flow mapping entry with an explicit key. Both the key and the value are optional.
+///
+/// ```yaml
+/// { ? key : value, ? [complex, key] : value, ? key without value }
+/// ```
+fn parse_flow_explicit_mapping_member(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T![?]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![?]);
+    if p.at(YAML_IDENTIFIER) {
+        // The lexer sees a key followed by its `:`, but the key of an explicit entry is a node
+        let m = p.start();
+        let kind = interpret_value(p.cur_text());
+        p.bump_remap(kind);
+        m.complete(p, kind);
+    } else {
+        parse_flow_node(p).ok();
+    }
+
+    if p.eat(T![:]) {
+        parse_flow_node(p).ok();
+    }
+
+    Present(m.complete(p, YAML_EXPLICIT_OBJECT_MEMBER))
+}
@@ -0,0 +1,200 @@
+mod block;
+mod document;
+mod flow;
+mod parse_error;
+
+use crate::token_source::YamlTokenSource;
+use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::event::Event;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::{ParsedSyntax::*, *};
+use biome_parser::token_source::Trivia;
+use biome_parser::ParserContext;
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+use document::YamlDocumentList;
+
+pub(crate) struct YamlParser<'source> {
+    context: ParserContext<YamlSyntaxKind>,
+    source: YamlTokenSource<'source>,
+}
+
+impl<'source> YamlParser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            context: ParserContext::default(),
+            source: YamlTokenSource::from_str(source),
+        }
+    }
+
+    /// Returns the column of the current token
+    pub fn current_column(&self) -> usize {
+        self.source.current_column()
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        Vec<Event<YamlSyntaxKind>>,
+        Vec<ParseDiagnostic>,
+        Vec<Trivia>,
+    ) {
+        let (trivia, lexer_diagnostics) = self.source.finish();
+        let (events, parse_diagnostics) = self.context.finish();
+
+        let diagnostics = merge_diagnostics(lexer_diagnostics, parse_diagnostics);
+
+        (events, diagnostics, trivia)
+    }
+}
+
+impl<'source> Parser for YamlParser<'source> {
+    type Kind = YamlSyntaxKind;
+    type Source = YamlTokenSource<'source>;
+
+    fn context(&self) -> &ParserContext<Self::Kind> {
+        &self.context
+    }
+
+    fn context_mut(&mut self) -> &mut ParserContext<Self::Kind> {
+        &mut self.context
+    }
+
+    fn source(&self) -> &Self::Source {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+pub(crate) fn parse_root(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(UNICODE_BOM);
+
+    YamlDocumentList::default().parse_list(p);
+
+    p.expect(EOF);
+
+    m.complete(p, YAML_ROOT)
+}
+
+/// The tokens that can start a scalar
+const SCALAR_SET: TokenSet<YamlSyntaxKind> = token_set![
+    YAML_STRING_VALUE,
+    YAML_NUMBER_VALUE,
+    YAML_BOOLEAN_VALUE,
+    YAML_NULL_VALUE,
+];
+
+/// The tokens that can start a node property
+const PROPERTY_SET: TokenSet<YamlSyntaxKind> = token_set![YAML_ANCHOR, YAML_TAG];
+
+#[inline]
+fn is_at_scalar(p: &mut YamlParser) -> bool {
+    p.at_ts(SCALAR_SET)
+}
+
+/// Parses a scalar, such as `value`, `"value"`, `1.5`, `true` or `null`
+#[inline]
+fn parse_scalar(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_scalar(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let kind = p.cur();
+    p.bump(kind);
+
+    Present(m.complete(p, kind))
+}
+
+/// Parses an alias, such as `*anchor`
+#[inline]
+fn parse_alias(p: &mut YamlParser) -> ParsedSyntax {
+    parse_literal(p, YAML_ALIAS)
+}
+
+/// Parses the key of a mapping entry
+#[inline]
+fn parse_identifier(p: &mut YamlParser) -> ParsedSyntax {
+    parse_literal(p, YAML_IDENTIFIER)
+}
+
+/// Parses a node that only wraps a token of the same kind
+#[inline]
+fn parse_literal(p: &mut YamlParser, kind: YamlSyntaxKind) -> ParsedSyntax {
+    if !p.at(kind) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(kind);
+
+    Present(m.complete(p, kind))
+}
+
+struct YamlPropertyList;
+
+impl ParseNodeList for YamlPropertyList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const LIST_KIND: Self::Kind = YAML_PROPERTY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        let kind = p.cur();
+        parse_literal(p, kind)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at_ts(PROPERTY_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, PROPERTY_SET),
+            parse_error::expected_property,
+        )
+    }
+}
+
+/// Checks if the parser is at a block scalar, a `|` literal or a `>` folded scalar
+#[inline]
+fn is_at_block_scalar(p: &mut YamlParser) -> bool {
+    p.at(T![|]) || p.at(T![>])
+}
+
+/// Parses a block scalar
+///
+/// ```yaml
+/// literal: |
+///   line breaks
+///   are kept
+/// folded: >-
+///   line breaks
+///   are folded
+/// ```
+fn parse_block_scalar(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_block_scalar(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let kind = if p.eat(T![|]) {
+        YAML_BLOCK_LITERAL
+    } else {
+        p.bump(T![>]);
+        YAML_BLOCK_FOLDED
+    };
+    parse_literal(p, YAML_BLOCK_VALUE).ok();
+
+    Present(m.complete(p, kind))
+}
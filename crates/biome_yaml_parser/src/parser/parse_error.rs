@@ -0,0 +1,36 @@
+use crate::parser::YamlParser;
+use biome_parser::{
+    diagnostic::{expected_any, expected_node, ParseDiagnostic},
+    Parser,
+};
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_document(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("document", range, p).with_hint(
+        "Documents that don't end with '...' must be followed by the '---' marker of the next document.",
+    )
+}
+
+pub(crate) fn expected_property(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["anchor", "tag"], range, p)
+}
+
+pub(crate) fn expected_value(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("value", range, p)
+}
+
+pub(crate) fn expected_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mapping entry", range, p)
+}
+
+pub(crate) fn expected_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("sequence entry", range, p)
+}
+
+pub(crate) fn block_collection_on_same_line(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "A block collection can't start on the same line as its key.",
+        range,
+    )
+    .with_hint("Move the collection on a new line and indent it.")
+}
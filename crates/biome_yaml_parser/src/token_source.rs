@@ -0,0 +1,106 @@
+use crate::lexer::{YamlLexContext, YamlLexer};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::Lexer;
+use biome_parser::prelude::TokenSource;
+use biome_parser::token_source::Trivia;
+use biome_rowan::{TextRange, TriviaPieceKind};
+use biome_yaml_syntax::YamlSyntaxKind;
+use biome_yaml_syntax::YamlSyntaxKind::EOF;
+
+/// The token source of the YAML parser.
+///
+/// The lexer of YAML tracks the indentation and the flow collections of the document,
+/// which can't be restored from a checkpoint. For this reason, the token source
+/// doesn't support lookahead and the parser relies on the columns of the tokens instead.
+pub(crate) struct YamlTokenSource<'source> {
+    lexer: YamlLexer<'source>,
+    trivia_list: Vec<Trivia>,
+    preceding_line_break: bool,
+}
+
+impl<'source> YamlTokenSource<'source> {
+    pub fn from_str(source: &'source str) -> Self {
+        let mut source = Self {
+            lexer: YamlLexer::from_str(source),
+            trivia_list: Vec::new(),
+            preceding_line_break: false,
+        };
+
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        let mut trailing = !first_token;
+        self.preceding_line_break = false;
+
+        loop {
+            let kind = self.lexer.next_token(YamlLexContext::Regular);
+
+            match TriviaPieceKind::try_from(kind) {
+                Err(_) => {
+                    // Not trivia
+                    break;
+                }
+                Ok(trivia_kind) => {
+                    if trivia_kind.is_newline() {
+                        trailing = false;
+                        self.preceding_line_break = true;
+                    }
+
+                    self.trivia_list
+                        .push(Trivia::new(trivia_kind, self.current_range(), trailing));
+                }
+            }
+        }
+    }
+
+    /// Returns the column of the current token, which is the number of bytes
+    /// between the start of its line and itself.
+    pub fn current_column(&self) -> usize {
+        self.lexer
+            .column_at(usize::from(self.current_range().start()))
+    }
+}
+
+impl<'source> TokenSource for YamlTokenSource<'source> {
+    type Kind = YamlSyntaxKind;
+
+    fn current(&self) -> Self::Kind {
+        self.lexer.current()
+    }
+
+    fn current_range(&self) -> TextRange {
+        self.lexer.current_range()
+    }
+
+    fn text(&self) -> &str {
+        self.lexer.source()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.preceding_line_break
+    }
+
+    fn bump(&mut self) {
+        if self.current() != EOF {
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn skip_as_trivia(&mut self) {
+        if self.current() != EOF {
+            self.trivia_list.push(Trivia::new(
+                TriviaPieceKind::Skipped,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia_list, self.lexer.finish())
+    }
+}
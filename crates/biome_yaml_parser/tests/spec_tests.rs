@@ -0,0 +1,13 @@
+#![allow(non_snake_case)]
+
+mod spec_test;
+
+mod ok {
+    //! Tests that are valid YAML
+    tests_macros::gen_tests! {"tests/yaml_test_suite/ok/**/*.yaml", crate::spec_test::run, "ok"}
+}
+
+mod error {
+    //! Tests that must fail because they are not valid YAML
+    tests_macros::gen_tests! {"tests/yaml_test_suite/error/**/*.yaml", crate::spec_test::run, "error"}
+}
@@ -1,8 +1,8 @@
 use biome_rowan::FileSourceError;
+use biome_string_case::StrLikeExtension;
 use std::{ffi::OsStr, path::Path};
-use biome_string_case::StrOnlyExtension;
 
-#[cfg_attr(feature = "schema", derive(schemars::YamlSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
@@ -35,7 +35,7 @@ impl YamlFileSource {
         let file_name = path
             .file_name()
             .and_then(OsStr::to_str)
-            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
+            .ok_or(FileSourceError::MissingFileName)?;
         if Self::is_well_known_yaml_file(file_name) {
             return Ok(Self::yaml());
         }
@@ -48,9 +48,21 @@ impl YamlFileSource {
         match extension.as_encoded_bytes() {
             // https://github.com/github-linguist/linguist/blob/4ac734c15a96f9e16fd12330d0cb8de82274f700/lib/linguist/languages.yml#L8070-L8079
             // https://yaml.org/spec/1.2.2/
-            b"yaml" | b"yml" | b"eyaml" | b"eyml" | b"cff" | b"yaml-tmlanguage"
-            | b"yaml-tmpreferences" | b"yaml-tmtheme" | b"mir" | b"reek" | b"rviz"
-            | b"sublime-syntax" | b"syntax" | b"yaml.sed" | b"yml.mysql" => Ok(Self::yaml()),
+            b"yaml"
+            | b"yml"
+            | b"eyaml"
+            | b"eyml"
+            | b"cff"
+            | b"yaml-tmlanguage"
+            | b"yaml-tmpreferences"
+            | b"yaml-tmtheme"
+            | b"mir"
+            | b"reek"
+            | b"rviz"
+            | b"sublime-syntax"
+            | b"syntax"
+            | b"yaml.sed"
+            | b"yml.mysql" => Ok(Self::yaml()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -75,12 +87,7 @@ impl TryFrom<&Path> for YamlFileSource {
     type Error = FileSourceError;
 
     fn try_from(path: &Path) -> Result<Self, Self::Error> {
-        let file_name = path
-            .file_name()
-            .and_then(OsStr::to_str)
-            .ok_or_else(|| FileSourceError::MissingFileName)?;
-
-        if let Ok(file_source) = Self::try_from_well_known(file_name) {
+        if let Ok(file_source) = Self::try_from_well_known(path) {
             return Ok(file_source);
         }
 
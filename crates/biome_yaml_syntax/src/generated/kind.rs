@@ -20,6 +20,7 @@ pub enum YamlSyntaxKind {
     L_BRACK,
     R_BRACK,
     DASH,
+    QUESTION,
     PERCENT,
     STAR,
     HASH,
@@ -40,6 +41,10 @@ pub enum YamlSyntaxKind {
     YAML_NULL_VALUE,
     YAML_BLOCK_VALUE,
     YAML_IDENTIFIER,
+    YAML_ANCHOR,
+    YAML_ALIAS,
+    YAML_TAG,
+    YAML_DIRECTIVE,
     NEWLINE,
     WHITESPACE,
     IDENT,
@@ -47,11 +52,17 @@ pub enum YamlSyntaxKind {
     YAML_ROOT,
     YAML_DOCUMENT_LIST,
     YAML_DOCUMENT,
+    YAML_DIRECTIVE_LIST,
+    YAML_VALUE_WITH_PROPERTIES,
+    YAML_PROPERTY_LIST,
     YAML_ARRAY_INLINE,
     YAML_ARRAY_INLINE_LIST,
     YAML_OBJECT,
     YAML_OBJECT_MEMBER,
+    YAML_EXPLICIT_OBJECT_MEMBER,
     YAML_OBJECT_MEMBER_LIST,
+    YAML_OBJECT_INLINE,
+    YAML_OBJECT_INLINE_MEMBER_LIST,
     YAML_ARRAY,
     YAML_ARRAY_ITEM,
     YAML_ARRAY_ITEM_LIST,
@@ -66,24 +77,28 @@ use self::YamlSyntaxKind::*;
 impl YamlSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            COLON | COMMA | L_CURLY | R_CURLY | L_BRACK | R_BRACK | DASH | PERCENT | STAR
-            | HASH | BANG | AT | SHL | AMP | PIPE | R_ANGLE | TILDE | BACKTICK | DOC_START
-            | DOC_END => true,
+            COLON | COMMA | L_CURLY | R_CURLY | L_BRACK | R_BRACK | DASH | QUESTION | PERCENT
+            | STAR | HASH | BANG | AT | SHL | AMP | PIPE | R_ANGLE | TILDE | BACKTICK
+            | DOC_START | DOC_END => true,
             _ => false,
         }
     }
     pub const fn is_literal(self) -> bool {
         match self {
             YAML_STRING_VALUE | YAML_NUMBER_VALUE | YAML_BOOLEAN_VALUE | YAML_NULL_VALUE
-            | YAML_BLOCK_VALUE | YAML_IDENTIFIER => true,
+            | YAML_BLOCK_VALUE | YAML_IDENTIFIER | YAML_ANCHOR | YAML_ALIAS | YAML_TAG
+            | YAML_DIRECTIVE => true,
             _ => false,
         }
     }
     pub const fn is_list(self) -> bool {
         match self {
             YAML_DOCUMENT_LIST
+            | YAML_DIRECTIVE_LIST
+            | YAML_PROPERTY_LIST
             | YAML_ARRAY_INLINE_LIST
             | YAML_OBJECT_MEMBER_LIST
+            | YAML_OBJECT_INLINE_MEMBER_LIST
             | YAML_ARRAY_ITEM_LIST => true,
             _ => false,
         }
@@ -104,6 +119,7 @@ impl YamlSyntaxKind {
             L_BRACK => "[",
             R_BRACK => "]",
             DASH => "-",
+            QUESTION => "?",
             PERCENT => "%",
             STAR => "*",
             HASH => "#",
@@ -126,4 +142,4 @@ impl YamlSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [:] => { $ crate :: YamlSyntaxKind :: COLON } ; [,] => { $ crate :: YamlSyntaxKind :: COMMA } ; ['{'] => { $ crate :: YamlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: YamlSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: YamlSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: YamlSyntaxKind :: R_BRACK } ; [-] => { $ crate :: YamlSyntaxKind :: DASH } ; [%] => { $ crate :: YamlSyntaxKind :: PERCENT } ; [*] => { $ crate :: YamlSyntaxKind :: STAR } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; [!] => { $ crate :: YamlSyntaxKind :: BANG } ; [@] => { $ crate :: YamlSyntaxKind :: AT } ; [<<] => { $ crate :: YamlSyntaxKind :: SHL } ; [&] => { $ crate :: YamlSyntaxKind :: AMP } ; [|] => { $ crate :: YamlSyntaxKind :: PIPE } ; [>] => { $ crate :: YamlSyntaxKind :: R_ANGLE } ; [~] => { $ crate :: YamlSyntaxKind :: TILDE } ; ['`'] => { $ crate :: YamlSyntaxKind :: BACKTICK } ; [---] => { $ crate :: YamlSyntaxKind :: DOC_START } ; [...] => { $ crate :: YamlSyntaxKind :: DOC_END } ; [null] => { $ crate :: YamlSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: YamlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: YamlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: YamlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; }
+macro_rules ! T { [:] => { $ crate :: YamlSyntaxKind :: COLON } ; [,] => { $ crate :: YamlSyntaxKind :: COMMA } ; ['{'] => { $ crate :: YamlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: YamlSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: YamlSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: YamlSyntaxKind :: R_BRACK } ; [-] => { $ crate :: YamlSyntaxKind :: DASH } ; [?] => { $ crate :: YamlSyntaxKind :: QUESTION } ; [%] => { $ crate :: YamlSyntaxKind :: PERCENT } ; [*] => { $ crate :: YamlSyntaxKind :: STAR } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; [!] => { $ crate :: YamlSyntaxKind :: BANG } ; [@] => { $ crate :: YamlSyntaxKind :: AT } ; [<<] => { $ crate :: YamlSyntaxKind :: SHL } ; [&] => { $ crate :: YamlSyntaxKind :: AMP } ; [|] => { $ crate :: YamlSyntaxKind :: PIPE } ; [>] => { $ crate :: YamlSyntaxKind :: R_ANGLE } ; [~] => { $ crate :: YamlSyntaxKind :: TILDE } ; ['`'] => { $ crate :: YamlSyntaxKind :: BACKTICK } ; [---] => { $ crate :: YamlSyntaxKind :: DOC_START } ; [...] => { $ crate :: YamlSyntaxKind :: DOC_END } ; [null] => { $ crate :: YamlSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: YamlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: YamlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: YamlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; }
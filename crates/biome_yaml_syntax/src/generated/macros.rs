@@ -16,6 +16,14 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::YamlSyntaxNode::kind(&node) {
+                $crate::YamlSyntaxKind::YAML_ALIAS => {
+                    let $pattern = unsafe { $crate::YamlAlias::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_ANCHOR => {
+                    let $pattern = unsafe { $crate::YamlAnchor::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_ARRAY => {
                     let $pattern = unsafe { $crate::YamlArray::new_unchecked(node) };
                     $body
@@ -44,10 +52,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlBooleanValue::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_DIRECTIVE => {
+                    let $pattern = unsafe { $crate::YamlDirective::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_DOCUMENT => {
                     let $pattern = unsafe { $crate::YamlDocument::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_EXPLICIT_OBJECT_MEMBER => {
+                    let $pattern = unsafe { $crate::YamlExplicitObjectMember::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_IDENTIFIER => {
                     let $pattern = unsafe { $crate::YamlIdentifier::new_unchecked(node) };
                     $body
@@ -64,6 +80,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlObject::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_OBJECT_INLINE => {
+                    let $pattern = unsafe { $crate::YamlObjectInline::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_OBJECT_MEMBER => {
                     let $pattern = unsafe { $crate::YamlObjectMember::new_unchecked(node) };
                     $body
@@ -76,6 +96,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlStringValue::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_TAG => {
+                    let $pattern = unsafe { $crate::YamlTag::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_VALUE_WITH_PROPERTIES => {
+                    let $pattern = unsafe { $crate::YamlValueWithProperties::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_BOGUS => {
                     let $pattern = unsafe { $crate::YamlBogus::new_unchecked(node) };
                     $body
@@ -92,14 +120,27 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlArrayItemList::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_DIRECTIVE_LIST => {
+                    let $pattern = unsafe { $crate::YamlDirectiveList::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_DOCUMENT_LIST => {
                     let $pattern = unsafe { $crate::YamlDocumentList::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_OBJECT_INLINE_MEMBER_LIST => {
+                    let $pattern =
+                        unsafe { $crate::YamlObjectInlineMemberList::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_OBJECT_MEMBER_LIST => {
                     let $pattern = unsafe { $crate::YamlObjectMemberList::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_PROPERTY_LIST => {
+                    let $pattern = unsafe { $crate::YamlPropertyList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
@@ -23,6 +23,76 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlAlias {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlAlias {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlAliasFields {
+        YamlAliasFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for YamlAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlAliasFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlAnchor {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlAnchor {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlAnchorFields {
+        YamlAnchorFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for YamlAnchor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlAnchorFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlArray {
     pub(crate) syntax: SyntaxNode,
 }
@@ -125,8 +195,8 @@ impl YamlArrayItem {
     pub fn minus_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn item(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn item(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlArrayItem {
@@ -140,7 +210,7 @@ impl Serialize for YamlArrayItem {
 #[derive(Serialize)]
 pub struct YamlArrayItemFields {
     pub minus_token: SyntaxResult<SyntaxToken>,
-    pub item: SyntaxResult<AnyYamlValue>,
+    pub item: Option<AnyYamlValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockFolded {
@@ -165,8 +235,8 @@ impl YamlBlockFolded {
     pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<YamlBlockValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn value(&self) -> Option<YamlBlockValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlBlockFolded {
@@ -180,7 +250,7 @@ impl Serialize for YamlBlockFolded {
 #[derive(Serialize)]
 pub struct YamlBlockFoldedFields {
     pub r_angle_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<YamlBlockValue>,
+    pub value: Option<YamlBlockValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockLiteral {
@@ -205,8 +275,8 @@ impl YamlBlockLiteral {
     pub fn bitwise_or_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<YamlBlockValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn value(&self) -> Option<YamlBlockValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlBlockLiteral {
@@ -220,7 +290,7 @@ impl Serialize for YamlBlockLiteral {
 #[derive(Serialize)]
 pub struct YamlBlockLiteralFields {
     pub bitwise_or_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<YamlBlockValue>,
+    pub value: Option<YamlBlockValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockValue {
@@ -293,6 +363,41 @@ pub struct YamlBooleanValueFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlDirective {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlDirective {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlDirectiveFields {
+        YamlDirectiveFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for YamlDirective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlDirectiveFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlDocument {
     pub(crate) syntax: SyntaxNode,
 }
@@ -308,19 +413,23 @@ impl YamlDocument {
     }
     pub fn as_fields(&self) -> YamlDocumentFields {
         YamlDocumentFields {
+            directives: self.directives(),
             dashdashdash_token: self.dashdashdash_token(),
             body: self.body(),
             dotdotdot_token: self.dotdotdot_token(),
         }
     }
+    pub fn directives(&self) -> YamlDirectiveList {
+        support::list(&self.syntax, 0usize)
+    }
     pub fn dashdashdash_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
+        support::token(&self.syntax, 1usize)
     }
-    pub fn body(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn body(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 2usize)
     }
     pub fn dotdotdot_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for YamlDocument {
@@ -333,11 +442,62 @@ impl Serialize for YamlDocument {
 }
 #[derive(Serialize)]
 pub struct YamlDocumentFields {
+    pub directives: YamlDirectiveList,
     pub dashdashdash_token: Option<SyntaxToken>,
-    pub body: SyntaxResult<AnyYamlValue>,
+    pub body: Option<AnyYamlValue>,
     pub dotdotdot_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlExplicitObjectMember {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlExplicitObjectMember {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlExplicitObjectMemberFields {
+        YamlExplicitObjectMemberFields {
+            question_mark_token: self.question_mark_token(),
+            key: self.key(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+        }
+    }
+    pub fn question_mark_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn key(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 2usize)
+    }
+    pub fn value(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 3usize)
+    }
+}
+impl Serialize for YamlExplicitObjectMember {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlExplicitObjectMemberFields {
+    pub question_mark_token: SyntaxResult<SyntaxToken>,
+    pub key: Option<AnyYamlValue>,
+    pub colon_token: Option<SyntaxToken>,
+    pub value: Option<AnyYamlValue>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlIdentifier {
     pub(crate) syntax: SyntaxNode,
 }
@@ -478,6 +638,51 @@ pub struct YamlObjectFields {
     pub members: YamlObjectMemberList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlObjectInline {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlObjectInline {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlObjectInlineFields {
+        YamlObjectInlineFields {
+            l_curly_token: self.l_curly_token(),
+            members: self.members(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn members(&self) -> YamlObjectInlineMemberList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for YamlObjectInline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlObjectInlineFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub members: YamlObjectInlineMemberList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlObjectMember {
     pub(crate) syntax: SyntaxNode,
 }
@@ -504,8 +709,8 @@ impl YamlObjectMember {
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn value(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn value(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 2usize)
     }
 }
 impl Serialize for YamlObjectMember {
@@ -520,7 +725,7 @@ impl Serialize for YamlObjectMember {
 pub struct YamlObjectMemberFields {
     pub key: SyntaxResult<YamlIdentifier>,
     pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<AnyYamlValue>,
+    pub value: Option<AnyYamlValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlRoot {
@@ -602,6 +807,138 @@ impl Serialize for YamlStringValue {
 pub struct YamlStringValueFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlTag {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlTag {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlTagFields {
+        YamlTagFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for YamlTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlTagFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlValueWithProperties {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlValueWithProperties {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlValueWithPropertiesFields {
+        YamlValueWithPropertiesFields {
+            properties: self.properties(),
+            value: self.value(),
+        }
+    }
+    pub fn properties(&self) -> YamlPropertyList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 1usize)
+    }
+}
+impl Serialize for YamlValueWithProperties {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlValueWithPropertiesFields {
+    pub properties: YamlPropertyList,
+    pub value: Option<AnyYamlValue>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyYamlDocument {
+    YamlBogus(YamlBogus),
+    YamlDocument(YamlDocument),
+}
+impl AnyYamlDocument {
+    pub fn as_yaml_bogus(&self) -> Option<&YamlBogus> {
+        match &self {
+            AnyYamlDocument::YamlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_document(&self) -> Option<&YamlDocument> {
+        match &self {
+            AnyYamlDocument::YamlDocument(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyYamlObjectMember {
+    YamlExplicitObjectMember(YamlExplicitObjectMember),
+    YamlObjectMember(YamlObjectMember),
+}
+impl AnyYamlObjectMember {
+    pub fn as_yaml_explicit_object_member(&self) -> Option<&YamlExplicitObjectMember> {
+        match &self {
+            AnyYamlObjectMember::YamlExplicitObjectMember(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_object_member(&self) -> Option<&YamlObjectMember> {
+        match &self {
+            AnyYamlObjectMember::YamlObjectMember(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyYamlProperty {
+    YamlAnchor(YamlAnchor),
+    YamlTag(YamlTag),
+}
+impl AnyYamlProperty {
+    pub fn as_yaml_anchor(&self) -> Option<&YamlAnchor> {
+        match &self {
+            AnyYamlProperty::YamlAnchor(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_tag(&self) -> Option<&YamlTag> {
+        match &self {
+            AnyYamlProperty::YamlTag(item) => Some(item),
+            _ => None,
+        }
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlScalar {
     YamlBooleanValue(YamlBooleanValue),
@@ -638,10 +975,15 @@ impl AnyYamlScalar {
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlValue {
     AnyYamlScalar(AnyYamlScalar),
+    YamlAlias(YamlAlias),
     YamlArray(YamlArray),
     YamlArrayInline(YamlArrayInline),
+    YamlBlockFolded(YamlBlockFolded),
+    YamlBlockLiteral(YamlBlockLiteral),
     YamlBogusValue(YamlBogusValue),
     YamlObject(YamlObject),
+    YamlObjectInline(YamlObjectInline),
+    YamlValueWithProperties(YamlValueWithProperties),
 }
 impl AnyYamlValue {
     pub fn as_any_yaml_scalar(&self) -> Option<&AnyYamlScalar> {
@@ -650,6 +992,12 @@ impl AnyYamlValue {
             _ => None,
         }
     }
+    pub fn as_yaml_alias(&self) -> Option<&YamlAlias> {
+        match &self {
+            AnyYamlValue::YamlAlias(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_array(&self) -> Option<&YamlArray> {
         match &self {
             AnyYamlValue::YamlArray(item) => Some(item),
@@ -662,21 +1010,127 @@ impl AnyYamlValue {
             _ => None,
         }
     }
-    pub fn as_yaml_bogus_value(&self) -> Option<&YamlBogusValue> {
+    pub fn as_yaml_block_folded(&self) -> Option<&YamlBlockFolded> {
         match &self {
-            AnyYamlValue::YamlBogusValue(item) => Some(item),
+            AnyYamlValue::YamlBlockFolded(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_yaml_object(&self) -> Option<&YamlObject> {
+    pub fn as_yaml_block_literal(&self) -> Option<&YamlBlockLiteral> {
         match &self {
-            AnyYamlValue::YamlObject(item) => Some(item),
+            AnyYamlValue::YamlBlockLiteral(item) => Some(item),
             _ => None,
         }
     }
-}
-impl AstNode for YamlArray {
-    type Language = Language;
+    pub fn as_yaml_bogus_value(&self) -> Option<&YamlBogusValue> {
+        match &self {
+            AnyYamlValue::YamlBogusValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_object(&self) -> Option<&YamlObject> {
+        match &self {
+            AnyYamlValue::YamlObject(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_object_inline(&self) -> Option<&YamlObjectInline> {
+        match &self {
+            AnyYamlValue::YamlObjectInline(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_value_with_properties(&self) -> Option<&YamlValueWithProperties> {
+        match &self {
+            AnyYamlValue::YamlValueWithProperties(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for YamlAlias {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ALIAS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_ALIAS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlAlias")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlAlias> for SyntaxNode {
+    fn from(n: YamlAlias) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlAlias> for SyntaxElement {
+    fn from(n: YamlAlias) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlAnchor {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ANCHOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_ANCHOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlAnchor")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlAnchor> for SyntaxNode {
+    fn from(n: YamlAnchor) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlAnchor> for SyntaxElement {
+    fn from(n: YamlAnchor) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlArray {
+    type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
         SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ARRAY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
@@ -787,7 +1241,7 @@ impl std::fmt::Debug for YamlArrayItem {
                 "minus_token",
                 &support::DebugSyntaxResult(self.minus_token()),
             )
-            .field("item", &support::DebugSyntaxResult(self.item()))
+            .field("item", &support::DebugOptionalElement(self.item()))
             .finish()
     }
 }
@@ -829,7 +1283,7 @@ impl std::fmt::Debug for YamlBlockFolded {
                 "r_angle_token",
                 &support::DebugSyntaxResult(self.r_angle_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &support::DebugOptionalElement(self.value()))
             .finish()
     }
 }
@@ -871,7 +1325,7 @@ impl std::fmt::Debug for YamlBlockLiteral {
                 "bitwise_or_token",
                 &support::DebugSyntaxResult(self.bitwise_or_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &support::DebugOptionalElement(self.value()))
             .finish()
     }
 }
@@ -967,6 +1421,47 @@ impl From<YamlBooleanValue> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for YamlDirective {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DIRECTIVE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_DIRECTIVE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlDirective")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlDirective> for SyntaxNode {
+    fn from(n: YamlDirective) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlDirective> for SyntaxElement {
+    fn from(n: YamlDirective) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for YamlDocument {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -991,11 +1486,12 @@ impl AstNode for YamlDocument {
 impl std::fmt::Debug for YamlDocument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("YamlDocument")
+            .field("directives", &self.directives())
             .field(
                 "dashdashdash_token",
                 &support::DebugOptionalElement(self.dashdashdash_token()),
             )
-            .field("body", &support::DebugSyntaxResult(self.body()))
+            .field("body", &support::DebugOptionalElement(self.body()))
             .field(
                 "dotdotdot_token",
                 &support::DebugOptionalElement(self.dotdotdot_token()),
@@ -1013,6 +1509,53 @@ impl From<YamlDocument> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for YamlExplicitObjectMember {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_EXPLICIT_OBJECT_MEMBER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_EXPLICIT_OBJECT_MEMBER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlExplicitObjectMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlExplicitObjectMember")
+            .field(
+                "question_mark_token",
+                &support::DebugSyntaxResult(self.question_mark_token()),
+            )
+            .field("key", &support::DebugOptionalElement(self.key()))
+            .field(
+                "colon_token",
+                &support::DebugOptionalElement(self.colon_token()),
+            )
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlExplicitObjectMember> for SyntaxNode {
+    fn from(n: YamlExplicitObjectMember) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlExplicitObjectMember> for SyntaxElement {
+    fn from(n: YamlExplicitObjectMember) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for YamlIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1174,6 +1717,52 @@ impl From<YamlObject> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for YamlObjectInline {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_OBJECT_INLINE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_OBJECT_INLINE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlObjectInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlObjectInline")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("members", &self.members())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlObjectInline> for SyntaxNode {
+    fn from(n: YamlObjectInline) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlObjectInline> for SyntaxElement {
+    fn from(n: YamlObjectInline) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for YamlObjectMember {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1203,7 +1792,7 @@ impl std::fmt::Debug for YamlObjectMember {
                 "colon_token",
                 &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &support::DebugOptionalElement(self.value()))
             .finish()
     }
 }
@@ -1291,14 +1880,276 @@ impl std::fmt::Debug for YamlStringValue {
             .finish()
     }
 }
-impl From<YamlStringValue> for SyntaxNode {
-    fn from(n: YamlStringValue) -> SyntaxNode {
-        n.syntax
+impl From<YamlStringValue> for SyntaxNode {
+    fn from(n: YamlStringValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlStringValue> for SyntaxElement {
+    fn from(n: YamlStringValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlTag {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_TAG as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_TAG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlTag")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlTag> for SyntaxNode {
+    fn from(n: YamlTag) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlTag> for SyntaxElement {
+    fn from(n: YamlTag) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlValueWithProperties {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_VALUE_WITH_PROPERTIES as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_VALUE_WITH_PROPERTIES
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlValueWithProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlValueWithProperties")
+            .field("properties", &self.properties())
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlValueWithProperties> for SyntaxNode {
+    fn from(n: YamlValueWithProperties) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlValueWithProperties> for SyntaxElement {
+    fn from(n: YamlValueWithProperties) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl From<YamlBogus> for AnyYamlDocument {
+    fn from(node: YamlBogus) -> AnyYamlDocument {
+        AnyYamlDocument::YamlBogus(node)
+    }
+}
+impl From<YamlDocument> for AnyYamlDocument {
+    fn from(node: YamlDocument) -> AnyYamlDocument {
+        AnyYamlDocument::YamlDocument(node)
+    }
+}
+impl AstNode for AnyYamlDocument {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = YamlBogus::KIND_SET.union(YamlDocument::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_BOGUS | YAML_DOCUMENT)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_BOGUS => AnyYamlDocument::YamlBogus(YamlBogus { syntax }),
+            YAML_DOCUMENT => AnyYamlDocument::YamlDocument(YamlDocument { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyYamlDocument::YamlBogus(it) => &it.syntax,
+            AnyYamlDocument::YamlDocument(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyYamlDocument::YamlBogus(it) => it.syntax,
+            AnyYamlDocument::YamlDocument(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyYamlDocument::YamlBogus(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlDocument::YamlDocument(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlDocument> for SyntaxNode {
+    fn from(n: AnyYamlDocument) -> SyntaxNode {
+        match n {
+            AnyYamlDocument::YamlBogus(it) => it.into(),
+            AnyYamlDocument::YamlDocument(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlDocument> for SyntaxElement {
+    fn from(n: AnyYamlDocument) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<YamlExplicitObjectMember> for AnyYamlObjectMember {
+    fn from(node: YamlExplicitObjectMember) -> AnyYamlObjectMember {
+        AnyYamlObjectMember::YamlExplicitObjectMember(node)
+    }
+}
+impl From<YamlObjectMember> for AnyYamlObjectMember {
+    fn from(node: YamlObjectMember) -> AnyYamlObjectMember {
+        AnyYamlObjectMember::YamlObjectMember(node)
+    }
+}
+impl AstNode for AnyYamlObjectMember {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        YamlExplicitObjectMember::KIND_SET.union(YamlObjectMember::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_EXPLICIT_OBJECT_MEMBER | YAML_OBJECT_MEMBER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_EXPLICIT_OBJECT_MEMBER => {
+                AnyYamlObjectMember::YamlExplicitObjectMember(YamlExplicitObjectMember { syntax })
+            }
+            YAML_OBJECT_MEMBER => {
+                AnyYamlObjectMember::YamlObjectMember(YamlObjectMember { syntax })
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyYamlObjectMember::YamlExplicitObjectMember(it) => &it.syntax,
+            AnyYamlObjectMember::YamlObjectMember(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyYamlObjectMember::YamlExplicitObjectMember(it) => it.syntax,
+            AnyYamlObjectMember::YamlObjectMember(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlObjectMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyYamlObjectMember::YamlExplicitObjectMember(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlObjectMember::YamlObjectMember(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlObjectMember> for SyntaxNode {
+    fn from(n: AnyYamlObjectMember) -> SyntaxNode {
+        match n {
+            AnyYamlObjectMember::YamlExplicitObjectMember(it) => it.into(),
+            AnyYamlObjectMember::YamlObjectMember(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlObjectMember> for SyntaxElement {
+    fn from(n: AnyYamlObjectMember) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<YamlAnchor> for AnyYamlProperty {
+    fn from(node: YamlAnchor) -> AnyYamlProperty {
+        AnyYamlProperty::YamlAnchor(node)
+    }
+}
+impl From<YamlTag> for AnyYamlProperty {
+    fn from(node: YamlTag) -> AnyYamlProperty {
+        AnyYamlProperty::YamlTag(node)
+    }
+}
+impl AstNode for AnyYamlProperty {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = YamlAnchor::KIND_SET.union(YamlTag::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_ANCHOR | YAML_TAG)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_ANCHOR => AnyYamlProperty::YamlAnchor(YamlAnchor { syntax }),
+            YAML_TAG => AnyYamlProperty::YamlTag(YamlTag { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyYamlProperty::YamlAnchor(it) => &it.syntax,
+            AnyYamlProperty::YamlTag(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyYamlProperty::YamlAnchor(it) => it.syntax,
+            AnyYamlProperty::YamlTag(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyYamlProperty::YamlAnchor(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlProperty::YamlTag(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlProperty> for SyntaxNode {
+    fn from(n: AnyYamlProperty) -> SyntaxNode {
+        match n {
+            AnyYamlProperty::YamlAnchor(it) => it.into(),
+            AnyYamlProperty::YamlTag(it) => it.into(),
+        }
     }
 }
-impl From<YamlStringValue> for SyntaxElement {
-    fn from(n: YamlStringValue) -> SyntaxElement {
-        n.syntax.into()
+impl From<AnyYamlProperty> for SyntaxElement {
+    fn from(n: AnyYamlProperty) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
     }
 }
 impl From<YamlBooleanValue> for AnyYamlScalar {
@@ -1386,6 +2237,11 @@ impl From<AnyYamlScalar> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlAlias> for AnyYamlValue {
+    fn from(node: YamlAlias) -> AnyYamlValue {
+        AnyYamlValue::YamlAlias(node)
+    }
+}
 impl From<YamlArray> for AnyYamlValue {
     fn from(node: YamlArray) -> AnyYamlValue {
         AnyYamlValue::YamlArray(node)
@@ -1396,6 +2252,16 @@ impl From<YamlArrayInline> for AnyYamlValue {
         AnyYamlValue::YamlArrayInline(node)
     }
 }
+impl From<YamlBlockFolded> for AnyYamlValue {
+    fn from(node: YamlBlockFolded) -> AnyYamlValue {
+        AnyYamlValue::YamlBlockFolded(node)
+    }
+}
+impl From<YamlBlockLiteral> for AnyYamlValue {
+    fn from(node: YamlBlockLiteral) -> AnyYamlValue {
+        AnyYamlValue::YamlBlockLiteral(node)
+    }
+}
 impl From<YamlBogusValue> for AnyYamlValue {
     fn from(node: YamlBogusValue) -> AnyYamlValue {
         AnyYamlValue::YamlBogusValue(node)
@@ -1406,26 +2272,56 @@ impl From<YamlObject> for AnyYamlValue {
         AnyYamlValue::YamlObject(node)
     }
 }
+impl From<YamlObjectInline> for AnyYamlValue {
+    fn from(node: YamlObjectInline) -> AnyYamlValue {
+        AnyYamlValue::YamlObjectInline(node)
+    }
+}
+impl From<YamlValueWithProperties> for AnyYamlValue {
+    fn from(node: YamlValueWithProperties) -> AnyYamlValue {
+        AnyYamlValue::YamlValueWithProperties(node)
+    }
+}
 impl AstNode for AnyYamlValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyYamlScalar::KIND_SET
+        .union(YamlAlias::KIND_SET)
         .union(YamlArray::KIND_SET)
         .union(YamlArrayInline::KIND_SET)
+        .union(YamlBlockFolded::KIND_SET)
+        .union(YamlBlockLiteral::KIND_SET)
         .union(YamlBogusValue::KIND_SET)
-        .union(YamlObject::KIND_SET);
+        .union(YamlObject::KIND_SET)
+        .union(YamlObjectInline::KIND_SET)
+        .union(YamlValueWithProperties::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            YAML_ARRAY | YAML_ARRAY_INLINE | YAML_BOGUS_VALUE | YAML_OBJECT => true,
+            YAML_ALIAS
+            | YAML_ARRAY
+            | YAML_ARRAY_INLINE
+            | YAML_BLOCK_FOLDED
+            | YAML_BLOCK_LITERAL
+            | YAML_BOGUS_VALUE
+            | YAML_OBJECT
+            | YAML_OBJECT_INLINE
+            | YAML_VALUE_WITH_PROPERTIES => true,
             k if AnyYamlScalar::can_cast(k) => true,
             _ => false,
         }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            YAML_ALIAS => AnyYamlValue::YamlAlias(YamlAlias { syntax }),
             YAML_ARRAY => AnyYamlValue::YamlArray(YamlArray { syntax }),
             YAML_ARRAY_INLINE => AnyYamlValue::YamlArrayInline(YamlArrayInline { syntax }),
+            YAML_BLOCK_FOLDED => AnyYamlValue::YamlBlockFolded(YamlBlockFolded { syntax }),
+            YAML_BLOCK_LITERAL => AnyYamlValue::YamlBlockLiteral(YamlBlockLiteral { syntax }),
             YAML_BOGUS_VALUE => AnyYamlValue::YamlBogusValue(YamlBogusValue { syntax }),
             YAML_OBJECT => AnyYamlValue::YamlObject(YamlObject { syntax }),
+            YAML_OBJECT_INLINE => AnyYamlValue::YamlObjectInline(YamlObjectInline { syntax }),
+            YAML_VALUE_WITH_PROPERTIES => {
+                AnyYamlValue::YamlValueWithProperties(YamlValueWithProperties { syntax })
+            }
             _ => {
                 if let Some(any_yaml_scalar) = AnyYamlScalar::cast(syntax) {
                     return Some(AnyYamlValue::AnyYamlScalar(any_yaml_scalar));
@@ -1437,19 +2333,29 @@ impl AstNode for AnyYamlValue {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            AnyYamlValue::YamlAlias(it) => &it.syntax,
             AnyYamlValue::YamlArray(it) => &it.syntax,
             AnyYamlValue::YamlArrayInline(it) => &it.syntax,
+            AnyYamlValue::YamlBlockFolded(it) => &it.syntax,
+            AnyYamlValue::YamlBlockLiteral(it) => &it.syntax,
             AnyYamlValue::YamlBogusValue(it) => &it.syntax,
             AnyYamlValue::YamlObject(it) => &it.syntax,
+            AnyYamlValue::YamlObjectInline(it) => &it.syntax,
+            AnyYamlValue::YamlValueWithProperties(it) => &it.syntax,
             AnyYamlValue::AnyYamlScalar(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            AnyYamlValue::YamlAlias(it) => it.syntax,
             AnyYamlValue::YamlArray(it) => it.syntax,
             AnyYamlValue::YamlArrayInline(it) => it.syntax,
+            AnyYamlValue::YamlBlockFolded(it) => it.syntax,
+            AnyYamlValue::YamlBlockLiteral(it) => it.syntax,
             AnyYamlValue::YamlBogusValue(it) => it.syntax,
             AnyYamlValue::YamlObject(it) => it.syntax,
+            AnyYamlValue::YamlObjectInline(it) => it.syntax,
+            AnyYamlValue::YamlValueWithProperties(it) => it.syntax,
             AnyYamlValue::AnyYamlScalar(it) => it.into_syntax(),
         }
     }
@@ -1458,10 +2364,15 @@ impl std::fmt::Debug for AnyYamlValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AnyYamlValue::AnyYamlScalar(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlAlias(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlArray(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlArrayInline(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlBlockFolded(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlBlockLiteral(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlBogusValue(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlObject(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlObjectInline(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlValueWithProperties(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1469,10 +2380,15 @@ impl From<AnyYamlValue> for SyntaxNode {
     fn from(n: AnyYamlValue) -> SyntaxNode {
         match n {
             AnyYamlValue::AnyYamlScalar(it) => it.into(),
+            AnyYamlValue::YamlAlias(it) => it.into(),
             AnyYamlValue::YamlArray(it) => it.into(),
             AnyYamlValue::YamlArrayInline(it) => it.into(),
+            AnyYamlValue::YamlBlockFolded(it) => it.into(),
+            AnyYamlValue::YamlBlockLiteral(it) => it.into(),
             AnyYamlValue::YamlBogusValue(it) => it.into(),
             AnyYamlValue::YamlObject(it) => it.into(),
+            AnyYamlValue::YamlObjectInline(it) => it.into(),
+            AnyYamlValue::YamlValueWithProperties(it) => it.into(),
         }
     }
 }
@@ -1482,6 +2398,21 @@ impl From<AnyYamlValue> for SyntaxElement {
         node.into()
     }
 }
+impl std::fmt::Display for AnyYamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyYamlObjectMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyYamlProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyYamlScalar {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1492,6 +2423,16 @@ impl std::fmt::Display for AnyYamlValue {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for YamlArray {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1527,11 +2468,21 @@ impl std::fmt::Display for YamlBooleanValue {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for YamlDocument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlExplicitObjectMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for YamlIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1552,6 +2503,11 @@ impl std::fmt::Display for YamlObject {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlObjectInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for YamlObjectMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1567,6 +2523,16 @@ impl std::fmt::Display for YamlStringValue {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlValueWithProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct YamlBogus {
     syntax: SyntaxNode,
@@ -1733,7 +2699,7 @@ impl Serialize for YamlArrayInlineList {
 }
 impl AstSeparatedList for YamlArrayInlineList {
     type Language = Language;
-    type Node = AnyYamlScalar;
+    type Node = AnyYamlValue;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -1748,15 +2714,15 @@ impl Debug for YamlArrayInlineList {
     }
 }
 impl IntoIterator for YamlArrayInlineList {
-    type Item = SyntaxResult<AnyYamlScalar>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlScalar>;
+    type Item = SyntaxResult<AnyYamlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for &YamlArrayInlineList {
-    type Item = SyntaxResult<AnyYamlScalar>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlScalar>;
+    type Item = SyntaxResult<AnyYamlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -1844,6 +2810,88 @@ impl IntoIterator for YamlArrayItemList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlDirectiveList {
+    syntax_list: SyntaxList,
+}
+impl YamlDirectiveList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlDirectiveList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DIRECTIVE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_DIRECTIVE_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlDirectiveList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlDirectiveList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for YamlDirectiveList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for YamlDirectiveList {
+    type Language = Language;
+    type Node = YamlDirective;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for YamlDirectiveList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlDirectiveList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &YamlDirectiveList {
+    type Item = YamlDirective;
+    type IntoIter = AstNodeListIterator<Language, YamlDirective>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for YamlDirectiveList {
+    type Item = YamlDirective;
+    type IntoIter = AstNodeListIterator<Language, YamlDirective>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct YamlDocumentList {
     syntax_list: SyntaxList,
 }
@@ -1897,7 +2945,7 @@ impl Serialize for YamlDocumentList {
 }
 impl AstNodeList for YamlDocumentList {
     type Language = Language;
-    type Node = YamlDocument;
+    type Node = AnyYamlDocument;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -1912,15 +2960,97 @@ impl Debug for YamlDocumentList {
     }
 }
 impl IntoIterator for &YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    type Item = AnyYamlDocument;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlDocument>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    type Item = AnyYamlDocument;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlDocument>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlObjectInlineMemberList {
+    syntax_list: SyntaxList,
+}
+impl YamlObjectInlineMemberList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlObjectInlineMemberList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_OBJECT_INLINE_MEMBER_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_OBJECT_INLINE_MEMBER_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlObjectInlineMemberList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlObjectInlineMemberList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for YamlObjectInlineMemberList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for YamlObjectInlineMemberList {
+    type Language = Language;
+    type Node = AnyYamlObjectMember;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for YamlObjectInlineMemberList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlObjectInlineMemberList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for YamlObjectInlineMemberList {
+    type Item = SyntaxResult<AnyYamlObjectMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlObjectMember>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &YamlObjectInlineMemberList {
+    type Item = SyntaxResult<AnyYamlObjectMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlObjectMember>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -1979,7 +3109,7 @@ impl Serialize for YamlObjectMemberList {
 }
 impl AstNodeList for YamlObjectMemberList {
     type Language = Language;
-    type Node = YamlObjectMember;
+    type Node = AnyYamlObjectMember;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -1994,15 +3124,97 @@ impl Debug for YamlObjectMemberList {
     }
 }
 impl IntoIterator for &YamlObjectMemberList {
-    type Item = YamlObjectMember;
-    type IntoIter = AstNodeListIterator<Language, YamlObjectMember>;
+    type Item = AnyYamlObjectMember;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlObjectMember>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for YamlObjectMemberList {
-    type Item = YamlObjectMember;
-    type IntoIter = AstNodeListIterator<Language, YamlObjectMember>;
+    type Item = AnyYamlObjectMember;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlObjectMember>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlPropertyList {
+    syntax_list: SyntaxList,
+}
+impl YamlPropertyList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlPropertyList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_PROPERTY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_PROPERTY_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlPropertyList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlPropertyList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for YamlPropertyList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for YamlPropertyList {
+    type Language = Language;
+    type Node = AnyYamlProperty;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for YamlPropertyList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlPropertyList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &YamlPropertyList {
+    type Item = AnyYamlProperty;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlProperty>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for YamlPropertyList {
+    type Item = AnyYamlProperty;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlProperty>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
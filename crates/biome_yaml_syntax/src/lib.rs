@@ -1,3 +1,4 @@
+mod file_source;
 #[macro_use]
 mod generated;
 mod syntax_node;
@@ -5,6 +6,7 @@ mod syntax_node;
 pub use self::generated::*;
 use biome_rowan::RawSyntaxKind;
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::YamlFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for YamlSyntaxKind {
@@ -41,6 +41,10 @@ export interface FileFeaturesResult {
 }
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
+	/**
+	 * The directory of the configuration file. The paths of the plugins are relative to it. Defaults to the workspace directory.
+	 */
+	configuration_directory?: string;
 	gitignore_matches: string[];
 	vcs_base_path?: string;
 	workspace_directory?: string;
@@ -89,6 +93,10 @@ export interface PartialConfiguration {
 	 * The configuration for the linter
 	 */
 	linter?: PartialLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: PartialMarkdownConfiguration;
 	/**
 	 * The configuration of the import sorting
 	 */
@@ -97,10 +105,20 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	* A list of paths to GritQL plugins, whose matches are reported as lint diagnostics.
+
+The diagnostics of all the plugins have the same `plugin` category, so they can't be selected per plugin. They are only suppressed by the comments that suppress all the lint rules, such as `// biome-ignore lint: <explanation>`. 
+	 */
+	plugins?: Plugins;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: PartialVcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: PartialYamlConfiguration;
 }
 export interface PartialAssistsConfiguration {
 	/**
@@ -294,6 +312,15 @@ export interface PartialLinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface PartialMarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: PartialMarkdownFormatter;
+}
 export interface PartialOrganizeImports {
 	/**
 	 * Enables the organization of imports
@@ -309,6 +336,7 @@ export interface PartialOrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+export type Plugins = PluginConfiguration[];
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -336,6 +364,15 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface PartialYamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: PartialYamlFormatter;
+}
 export interface Actions {
 	source?: Source;
 }
@@ -634,6 +671,27 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface PartialMarkdownFormatter {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The size of the indentation applied to Markdown files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Css language
@@ -672,7 +730,33 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+export type PluginConfiguration = string | PluginWithOptions;
 export type VcsClientKind = "git";
+/**
+ * Options that changes how the YAML formatter behaves
+ */
+export interface PartialYamlFormatter {
+	/**
+	 * Whether to insert spaces around brackets in flow mappings. Defaults to true.
+	 */
+	bracketSpacing?: BracketSpacing;
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -1274,6 +1358,10 @@ export interface Nursery {
 	 * Prevent usage of \<img> element in a Next.js project.
 	 */
 	noImgElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow import cycles.
+	 */
+	noImportCycles?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows the use of irregular whitespace characters.
 	 */
@@ -1330,6 +1418,10 @@ export interface Nursery {
 	 * Disallow unknown type selectors.
 	 */
 	noUnknownTypeSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow importing names that aren't exported by the imported module.
+	 */
+	noUnresolvedImports?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -2006,6 +2098,20 @@ export interface OverrideOrganizeImportsConfiguration {
 	 */
 	enabled?: boolean;
 }
+export interface PluginWithOptions {
+	/**
+	 * The severity of the diagnostics emitted by the plugin. Defaults to `error`.
+	 */
+	level?: RulePlainConfiguration;
+	/**
+	 * A message that replaces the default message of the diagnostics emitted by the plugin
+	 */
+	message?: string;
+	/**
+	 * The path of the plugin, relative to the configuration file
+	 */
+	path: string;
+}
 export type RuleAssistConfiguration = "on" | "off";
 export type RuleFixConfiguration_for_Null =
 	| RulePlainConfiguration
@@ -2659,7 +2765,9 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Yaml: YamlFileSource }
+	| { Markdown: MarkdownFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -2686,6 +2794,8 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export interface YamlFileSource {}
+export interface MarkdownFileSource {}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "JavaScript"
@@ -2704,9 +2814,9 @@ export type LanguageVersion = "ES2022" | "ESNext";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Biome supports plain CSS, and aims to be compatible with the latest Recommendation level standards, and the SCSS syntax of Sass. 
 	 */
-export type CssVariant = "Standard";
+export type CssVariant = "Standard" | "Scss";
 /**
  * The style of GraphQL contained in the file.
  */
@@ -2747,6 +2857,10 @@ export interface GetFormatterIRParams {
 }
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
+	/**
+	 * Whether the diagnostics silenced by suppression comments should be returned too. They are tagged as `suppressed`, and they aren't counted in `errors` nor limited by `max_diagnostics`.
+	 */
+	include_suppressed?: boolean;
 	max_diagnostics: number;
 	only: RuleCode[];
 	path: BiomePath;
@@ -2919,6 +3033,7 @@ export type Category =
 	| "lint/nursery/noHeadElement"
 	| "lint/nursery/noHeadImportInDocument"
 	| "lint/nursery/noImgElement"
+	| "lint/nursery/noImportCycles"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidGridAreas"
@@ -2948,6 +3063,7 @@ export type Category =
 	| "lint/nursery/noUnknownTypeSelector"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnresolvedImports"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringRaw"
@@ -3107,7 +3223,9 @@ export type Category =
 	| "organizeImports"
 	| "assists"
 	| "migrate"
+	| "baseline"
 	| "deserialize"
+	| "plugin"
 	| "project"
 	| "search"
 	| "internalError/io"
@@ -3176,7 +3294,8 @@ export type DiagnosticTag =
 	| "internal"
 	| "unnecessaryCode"
 	| "deprecatedCode"
-	| "verbose";
+	| "verbose"
+	| "suppressed";
 /**
  * The category for a log advice, defines how the message should be presented to the user.
  */
@@ -3323,7 +3442,10 @@ export interface FixFileParams {
 /**
  * Which fixes should be applied during the analyzing phase
  */
-export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export type FixFileMode =
+	| "SafeFixes"
+	| "SafeAndUnsafeFixes"
+	| "RemoveUnusedSuppressions";
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -3362,11 +3484,120 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * The edits of the other modules of the project that import the renamed symbol, when the module exports it under its name
+	 */
+	module_edits: ModuleRenameEdit[];
 	/**
 	 * Range of source code modified by this rename operation
 	 */
 	range: TextRange;
 }
+export interface ModuleRenameEdit {
+	/**
+	 * The code that replaces the imported name. The symbol keeps its name in the module, e.g. `import { a }` becomes `import { b as a }`.
+	 */
+	new_text: string;
+	/**
+	 * The module that imports, or re-exports, the renamed symbol
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the imported name in the module
+	 */
+	range: TextRange;
+}
+export interface GotoDefinitionParams {
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Range of the declaration of the symbol, if it's declared in the file
+	 */
+	range?: TextRange;
+}
+export interface FindReferencesParams {
+	/**
+	 * Whether the declaration of the symbol should be returned with its references
+	 */
+	include_declaration: boolean;
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface FindReferencesResult {
+	/**
+	 * The references of the symbol in the file, in source order
+	 */
+	references: SymbolReference[];
+}
+export interface SymbolReference {
+	kind: SymbolReferenceKind;
+	range: TextRange;
+}
+export type SymbolReferenceKind = "Declaration" | "Read" | "Write";
+export interface GetFoldingRangesParams {
+	path: BiomePath;
+}
+export interface GetFoldingRangesResult {
+	/**
+	 * The regions of the file that can be folded, in source order
+	 */
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * Range of the folded code. The range of a block is the code between its delimiters, e.g. the code between `{` and `}`.
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "Block" | "Comment" | "Imports" | "Region";
+export interface GetSelectionRangesParams {
+	path: BiomePath;
+	positions: TextSize[];
+}
+export interface GetSelectionRangesResult {
+	/**
+	 * For each position, the ranges of the syntax that contains it, from the innermost to the outermost
+	 */
+	ranges: TextRange[][];
+}
+export interface GetSemanticTokensParams {
+	path: BiomePath;
+}
+export interface GetSemanticTokensResult {
+	/**
+	 * The classified identifiers of the file, in source order
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	kind: SemanticTokenKind;
+	modifiers: SemanticTokenModifier[];
+	/**
+	 * Range of the name of the identifier
+	 */
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "Namespace"
+	| "Type"
+	| "Class"
+	| "Enum"
+	| "Interface"
+	| "TypeParameter"
+	| "Parameter"
+	| "Variable"
+	| "EnumMember"
+	| "Function";
+export type SemanticTokenModifier =
+	| "Declaration"
+	| "Readonly"
+	| "Local"
+	| "Imported"
+	| "Global"
+	| "Unresolved";
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<FileFeaturesResult>;
@@ -3394,6 +3625,17 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
+	getFoldingRanges(
+		params: GetFoldingRangesParams,
+	): Promise<GetFoldingRangesResult>;
+	getSelectionRanges(
+		params: GetSelectionRangesParams,
+	): Promise<GetSelectionRangesResult>;
+	getSemanticTokens(
+		params: GetSemanticTokensParams,
+	): Promise<GetSemanticTokensResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -3455,6 +3697,21 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
+		getFoldingRanges(params) {
+			return transport.request("biome/get_folding_ranges", params);
+		},
+		getSelectionRanges(params) {
+			return transport.request("biome/get_selection_ranges", params);
+		},
+		getSemanticTokens(params) {
+			return transport.request("biome/get_semantic_tokens", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -3,7 +3,7 @@ use biome_analyze::options::JsxRuntime;
 use biome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleCategoriesBuilder};
 use biome_css_formatter::context::{CssFormatContext, CssFormatOptions};
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssRoot, CssSyntaxNode};
+use biome_css_syntax::{CssFileSource, CssRoot, CssSyntaxNode};
 use biome_formatter::{FormatResult, Formatted, PrintResult, Printed};
 use biome_graphql_formatter::context::{GraphqlFormatContext, GraphqlFormatOptions};
 use biome_graphql_syntax::GraphqlSyntaxNode;
@@ -194,7 +194,7 @@ impl Analyze {
                     filter,
                     &options,
                     JsFileSource::default(),
-                    None,
+                    Default::default(),
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
@@ -211,7 +211,7 @@ impl Analyze {
                     ..AnalysisFilter::default()
                 };
                 let options = AnalyzerOptions::default();
-                biome_css_analyze::analyze(root, filter, &options, |event| {
+                biome_css_analyze::analyze(root, filter, &options, CssFileSource::css(), |event| {
                     black_box(event.diagnostic());
                     black_box(event.actions());
                     ControlFlow::<Never>::Continue(())
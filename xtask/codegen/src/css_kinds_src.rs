@@ -40,6 +40,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("#{", "HASH_L_CURLY"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -116,6 +118,16 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "ridge",
         "inset",
         "outset",
+        // SCSS
+        "mixin",
+        "include",
+        "content",
+        "extend",
+        "use",
+        "forward",
+        "with",
+        "show",
+        "hide",
         // HERE: Add new regular keywords _above_ here. Be sure to also add them
         // to `consume_identifier` in `biome_css_parser/src/lexer/mod.rs` as well.
         // CSS-wide keywords
@@ -508,5 +520,39 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_BOGUS_UNICODE_RANGE_VALUE",
         // Grit metavariable
         "CSS_METAVARIABLE",
+        // SCSS nodes
+        "SCSS_IDENTIFIER",
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_NESTING_DECLARATION",
+        "SCSS_PLACEHOLDER_SELECTOR",
+        "SCSS_MAP_EXPRESSION",
+        "SCSS_MAP_EXPRESSION_PAIR_LIST",
+        "SCSS_MAP_EXPRESSION_PAIR",
+        "SCSS_QUALIFIED_NAME",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT_VALUE",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_ARGUMENT",
+        "SCSS_ARGUMENT_NAME",
+        "SCSS_CONTENT_AT_RULE",
+        "SCSS_EXTEND_AT_RULE",
+        "SCSS_USE_AT_RULE",
+        "SCSS_USE_AS_CLAUSE",
+        "SCSS_USE_ALL_NAMESPACE",
+        "SCSS_MODULE_CONFIGURATION",
+        "SCSS_FORWARD_AT_RULE",
+        "SCSS_FORWARD_AS_CLAUSE",
+        "SCSS_FORWARD_VISIBILITY_CLAUSE",
+        "SCSS_FORWARD_MEMBER_LIST",
+        "SCSS_INTERPOLATION",
+        "SCSS_INTERPOLATED_IDENTIFIER",
+        "SCSS_INTERPOLATED_IDENTIFIER_ITEM_LIST",
     ],
 };
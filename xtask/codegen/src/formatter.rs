@@ -556,9 +556,12 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
     Grit,
     Graphql,
     Html,
+    Yaml,
+    Markdown,
 }
 
 impl NodeDialect {
@@ -569,9 +572,12 @@ impl NodeDialect {
             NodeDialect::Jsx,
             NodeDialect::Json,
             NodeDialect::Css,
+            NodeDialect::Scss,
             NodeDialect::Grit,
             NodeDialect::Graphql,
             NodeDialect::Html,
+            NodeDialect::Yaml,
+            NodeDialect::Markdown,
         ]
     }
 
@@ -586,9 +592,12 @@ impl NodeDialect {
             NodeDialect::Jsx => "jsx",
             NodeDialect::Json => "json",
             NodeDialect::Css => "css",
+            NodeDialect::Scss => "scss",
             NodeDialect::Grit => "grit",
             NodeDialect::Graphql => "graphql",
             NodeDialect::Html => "html",
+            NodeDialect::Yaml => "yaml",
+            NodeDialect::Markdown => "markdown",
         }
     }
 
@@ -599,9 +608,12 @@ impl NodeDialect {
             "Ts" => NodeDialect::Ts,
             "Json" => NodeDialect::Json,
             "Css" => NodeDialect::Css,
+            "Scss" => NodeDialect::Scss,
             "Grit" => NodeDialect::Grit,
             "Graphql" => NodeDialect::Graphql,
             "Html" => NodeDialect::Html,
+            "Yaml" => NodeDialect::Yaml,
+            "Md" => NodeDialect::Markdown,
             _ => {
                 eprintln!("missing prefix {name}");
                 NodeDialect::Js
@@ -824,8 +836,10 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
-            LanguageKind::Yaml => NodeConcept::Auxiliary,
+            LanguageKind::Yaml => match name {
+                _ if name.ends_with("Value") => NodeConcept::Value,
+                _ => NodeConcept::Auxiliary,
+            },
         }
     }
 }
@@ -891,7 +905,7 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatter",
             LanguageKind::Html => "HtmlFormatter",
             LanguageKind::Yaml => "YamlFormatter",
-            LanguageKind::Markdown => "DemoFormatter",
+            LanguageKind::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -906,7 +920,7 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatContext",
             LanguageKind::Html => "HtmlFormatContext",
             LanguageKind::Yaml => "YamlFormatContext",
-            LanguageKind::Markdown => "DemoFormatterContext",
+            LanguageKind::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
@@ -202,8 +202,12 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         &mode,
         RuleCategory::Lint,
     )?;
+    // The refactors act on the selection of the editor, so they can't be
+    // enabled in the configuration
+    let mut assists_groups = assists_visitor.groups;
+    assists_groups.remove("refactor");
     generate_for_groups(
-        assists_visitor.groups,
+        assists_groups,
         assists_config_root.as_path(),
         push_rules_directory.as_path(),
         &mode,
@@ -684,7 +684,8 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
-                    ("$", LanguageKind::Graphql) => "dollar",
+                    ("$", LanguageKind::Graphql | LanguageKind::Css) => "dollar",
+                    ("#{", LanguageKind::Css) => "hash_l_curly",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     ("---", LanguageKind::Yaml) => "dashdashdash",
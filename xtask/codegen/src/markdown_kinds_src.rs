@@ -3,32 +3,31 @@ use crate::kind_src::KindsSrc;
 // 对Token进行分组，生成is_xx 方法
 pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
     punct: &[
-        ("<", "L_ANGLE"),
         (">", "R_ANGLE"),
         ("(", "L_PAREN"),
         (")", "R_PAREN"),
         ("[", "L_BRACK"),
         ("]", "R_BRACK"),
-        ("/", "SLASH"),
-        ("=", "EQ"),
         ("!", "BANG"),
+        (":", "COLON"),
+        ("|", "PIPE"),
         ("-", "MINUS"),
+        ("+", "PLUS"),
         ("*", "STAR"),
-        ("`", "BACKTICK"),
-        ("~", "TILDE"),
-        ("   ", "WHITESPACE3"),
+        ("**", "DOUBLE_STAR"),
         ("_", "UNDERSCORE"),
+        ("__", "DOUBLE_UNDERSCORE"),
         ("#", "HASH"),
     ],
     keywords: &["null"],
     literals: &[
         "MD_HARD_LINE_LITERAL",
-        "MD_SOFT_BREAK_LITERAL",
         "MD_TEXTUAL_LITERAL",
-        "MD_STRING_LITERAL",
-        "MD_INDENT_CHUNK_LITERAL",
+        "MD_INLINE_CODE_LITERAL",
+        "MD_FENCE_LITERAL",
+        "MD_ORDERED_LIST_MARKER_LITERAL",
+        "MD_SETEXT_UNDERLINE_LITERAL",
         "MD_THEMATIC_BREAK_LITERAL",
-        "MD_ERROR_LITERAL",
     ],
     tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "TAB"],
     nodes: &[
@@ -38,30 +37,36 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         // node
         "MD_DOCUMENT",
         "MD_BLOCK_LIST",
+        "MD_THEMATIC_BREAK_BLOCK",
         "MD_HASH_LIST",
         "MD_HASH",
         "MD_HEADER",
+        "MD_SETEXT_HEADER",
         "MD_INDENT_CODE_BLOCK",
         "MD_FENCED_CODE_BLOCK",
+        "MD_CODE_LINE_LIST",
         "MD_HTML_BLOCK",
         "MD_LINK_BLOCK",
+        "MD_TABLE",
+        "MD_TABLE_ROW_LIST",
+        "MD_TABLE_ROW",
+        "MD_TABLE_CELL_LIST",
+        "MD_TABLE_CELL",
         "MD_QUOTE",
-        "MD_ORDER_LIST_ITEM",
         "MD_BULLET_LIST_ITEM",
         "MD_BULLET_LIST",
+        "MD_BULLET",
+        "MD_ORDER_LIST_ITEM",
         "MD_ORDER_LIST",
+        "MD_ORDER_BULLET",
         "MD_PARAGRAPH",
-        "MD_PARAGRAPH_ITEM_LIST",
+        "MD_INLINE_ITEM_LIST",
         "MD_INLINE_CODE",
         "MD_INLINE_EMPHASIS",
+        "MD_INLINE_ITALIC",
         "MD_INLINE_LINK",
         "MD_INLINE_IMAGE",
         "MD_HARD_LINE",
-        "MD_SOFT_BREAK",
         "MD_TEXTUAL",
-        "MD_SETEXT_HEADER",
-        "MD_STRING",
-        "MD_INDENT",
-        "MD_THEMATIC_BREAK_BLOCK",
     ],
 };
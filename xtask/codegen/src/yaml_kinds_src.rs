@@ -9,6 +9,7 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         ("[", "L_BRACK"),
         ("]", "R_BRACK"),
         ("-", "DASH"),
+        ("?", "QUESTION"),
         ("%", "PERCENT"),
         ("*", "STAR"),
         ("#", "HASH"),
@@ -31,17 +32,27 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         "YAML_NULL_VALUE",
         "YAML_BLOCK_VALUE",
         "YAML_IDENTIFIER",
+        "YAML_ANCHOR",
+        "YAML_ALIAS",
+        "YAML_TAG",
+        "YAML_DIRECTIVE",
     ],
     tokens: &["NEWLINE", "WHITESPACE", "IDENT", "COMMENT"],
     nodes: &[
         "YAML_ROOT",
         "YAML_DOCUMENT_LIST",
         "YAML_DOCUMENT",
+        "YAML_DIRECTIVE_LIST",
+        "YAML_VALUE_WITH_PROPERTIES",
+        "YAML_PROPERTY_LIST",
         "YAML_ARRAY_INLINE",
         "YAML_ARRAY_INLINE_LIST",
         "YAML_OBJECT",
         "YAML_OBJECT_MEMBER",
+        "YAML_EXPLICIT_OBJECT_MEMBER",
         "YAML_OBJECT_MEMBER_LIST",
+        "YAML_OBJECT_INLINE",
+        "YAML_OBJECT_INLINE_MEMBER_LIST",
         "YAML_ARRAY",
         "YAML_ARRAY_ITEM",
         "YAML_ARRAY_ITEM_LIST",
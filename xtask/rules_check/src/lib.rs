@@ -264,35 +264,43 @@ fn assert_lint(
                         ..Default::default()
                     },
                     file_path: PathBuf::from(&file_path),
+                    suppressed_signals: false,
                 };
-                biome_js_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
-                    if let Some(mut diag) = signal.diagnostic() {
-                        let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                biome_js_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    file_source,
+                    Default::default(),
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
                                 "If you see this error, it means you need to run cargo codegen-configuration",
                             );
 
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diag = diag.add_code_suggestion(action.into());
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diag
-                            .with_severity(severity)
-                            .with_file_path(&file_path)
-                            .with_file_source_code(code);
-                        let res = write_diagnostic(code, error);
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(&file_path)
+                                .with_file_source_code(code);
+                            let res = write_diagnostic(code, error);
 
-                        // Abort the analysis on error
-                        if let Err(err) = res {
-                            eprintln!("Error: {err}");
-                            return ControlFlow::Break(err);
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
                         }
-                    }
 
-                    ControlFlow::Continue(())
-                });
+                        ControlFlow::Continue(())
+                    },
+                );
             }
         }
         DocumentFileSource::Json(file_source) => {
@@ -346,7 +354,7 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Css(..) => {
+        DocumentFileSource::Css(file_source) => {
             let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
 
             if parse.has_errors() {
@@ -367,7 +375,7 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_css_analyze::analyze(&root, filter, &options, |signal| {
+                biome_css_analyze::analyze(&root, filter, &options, file_source, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -450,6 +458,8 @@ fn assert_lint(
         }
         DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
+        DocumentFileSource::Yaml(..) => todo!("YAML analysis is not yet supported"),
+        DocumentFileSource::Markdown(..) => todo!("Markdown analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests
         DocumentFileSource::Unknown => {}